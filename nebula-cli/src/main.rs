@@ -11,6 +11,9 @@
 //! # 下载磁力链接
 //! nebula download "magnet:?xt=urn:btih:..." -o ~/Downloads
 //!
+//! # 制作种子并立即做种
+//! nebula create-torrent ./build -t udp://tracker.example:6969/announce --seed
+//!
 //! # 显示帮助信息
 //! nebula --help
 //! ```
@@ -19,8 +22,9 @@ use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 use console::style;
 use indicatif::{ProgressBar, ProgressStyle};
-use nebula_core::{DownloadEvent, DownloadManager, ManagerConfig, Progress};
-use std::path::PathBuf;
+use nebula_core::torrent_creator::TorrentCreateOptions;
+use nebula_core::{DownloadEvent, DownloadManager, ManagerConfig, Progress, VideoStage};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tracing::Level;
@@ -57,6 +61,40 @@ enum Commands {
         verbose: bool,
     },
 
+    /// 从本地文件或目录制作种子
+    CreateTorrent {
+        /// 要分享的文件或目录
+        input: PathBuf,
+
+        /// 生成的 .torrent 文件路径（默认为 <名称>.torrent）
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Tracker 地址（可多次指定）
+        #[arg(short, long = "tracker")]
+        trackers: Vec<String>,
+
+        /// Web Seed 地址（可多次指定）
+        #[arg(short, long = "web-seed")]
+        web_seeds: Vec<String>,
+
+        /// 分块大小（KiB，2 的幂），默认自动选择
+        #[arg(long)]
+        piece_size: Option<u64>,
+
+        /// 私有种子
+        #[arg(long)]
+        private: bool,
+
+        /// 注释
+        #[arg(short, long)]
+        comment: Option<String>,
+
+        /// 制作完成后立即做种
+        #[arg(long)]
+        seed: bool,
+    },
+
     /// 显示版本信息
     Version,
 }
@@ -89,6 +127,26 @@ async fn main() -> Result<()> {
         } => {
             download_command(&source, output, verbose).await?;
         }
+        Commands::CreateTorrent {
+            input,
+            output,
+            trackers,
+            web_seeds,
+            piece_size,
+            private,
+            comment,
+            seed,
+        } => {
+            let options = TorrentCreateOptions {
+                piece_size: piece_size.map(|kib| kib * 1024),
+                private,
+                trackers,
+                web_seeds,
+                comment,
+                ..Default::default()
+            };
+            create_torrent_command(&input, output, options, seed).await?;
+        }
         Commands::Version => {
             println!(
                 "{} {} - {}",
@@ -164,7 +222,7 @@ async fn download_command(source: &str, output: Option<PathBuf>, verbose: bool)
     let pb = ProgressBar::new(100);
     pb.set_style(
         ProgressStyle::default_bar()
-            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {percent}% ({bytes}/{total_bytes}) @ {bytes_per_sec} ETA: {eta}")
+            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {percent}% ({bytes}/{total_bytes}) @ {bytes_per_sec} ETA: {eta} {msg}")
             .unwrap()
             .progress_chars("█▉▊▋▌▍▎▏  "),
     );
@@ -178,12 +236,34 @@ async fn download_command(source: &str, output: Option<PathBuf>, verbose: bool)
                     Ok(DownloadEvent::ProgressUpdated { task_id: tid, progress }) if tid == task_id => {
                         update_progress_bar(&pb, &progress);
                     }
-                    Ok(DownloadEvent::MetadataReceived { task_id: tid, name, total_size, file_count }) if tid == task_id => {
+                    Ok(DownloadEvent::MetadataReceived {
+                        task_id: tid,
+                        name,
+                        total_size,
+                        file_count,
+                        mime_type,
+                        supports_resume,
+                        duration_secs,
+                        ..
+                    }) if tid == task_id => {
                         println!("{} 元数据已获取:", style("ℹ").blue());
                         println!("  名称: {}", style(&name).white().bold());
-                        println!("  大小: {}", format_bytes(total_size));
+                        if total_size > 0 {
+                            println!("  大小: {}", format_bytes(total_size));
+                        } else {
+                            println!("  大小: 未知");
+                        }
+                        if let Some(mime) = mime_type {
+                            println!("  类型: {}", mime);
+                        }
+                        if let Some(secs) = duration_secs {
+                            println!("  时长: {}:{:02}", secs / 60, secs % 60);
+                        }
+                        println!("  断点续传: {}", if supports_resume { "支持" } else { "不支持" });
                         println!("  文件数: {}\n", file_count);
-                        pb.set_length(total_size);
+                        if total_size > 0 {
+                            pb.set_length(total_size);
+                        }
                     }
                     Ok(DownloadEvent::TaskCompleted { task_id: tid, .. }) if tid == task_id => {
                         pb.finish_with_message("下载完成!");
@@ -199,6 +279,25 @@ async fn download_command(source: &str, output: Option<PathBuf>, verbose: bool)
                     Ok(DownloadEvent::PeerUpdate { task_id: tid, connected_peers, total_peers }) if tid == task_id && verbose => {
                         pb.set_message(format!("Peers: {}/{}", connected_peers, total_peers));
                     }
+                    Ok(DownloadEvent::TaskStalled { task_id: tid, diagnostics, .. }) if tid == task_id => {
+                        pb.set_message(format!(
+                            "等待 Peer ({} 秒, Tracker {}/{}, 发现 Peer {})",
+                            diagnostics.stalled_secs,
+                            diagnostics.trackers_reached,
+                            diagnostics.trackers_total,
+                            diagnostics.peers_seen
+                        ));
+                    }
+                    Ok(DownloadEvent::VideoStageChanged { task_id: tid, stage }) if tid == task_id => {
+                        let stage = match stage {
+                            VideoStage::Video => "下载视频",
+                            VideoStage::Audio => "下载音频",
+                            VideoStage::Merging => "合并音视频",
+                            VideoStage::ExtractingAudio => "提取音频",
+                            VideoStage::PostProcessing => "后处理",
+                        };
+                        pb.set_message(stage);
+                    }
                     _ => {}
                 }
             }
@@ -218,6 +317,72 @@ async fn download_command(source: &str, output: Option<PathBuf>, verbose: bool)
     Ok(())
 }
 
+/// 执行制作种子命令
+async fn create_torrent_command(
+    input: &Path,
+    output: Option<PathBuf>,
+    options: TorrentCreateOptions,
+    seed: bool,
+) -> Result<()> {
+    let input = input
+        .canonicalize()
+        .with_context(|| format!("找不到输入路径: {:?}", input))?;
+
+    let mut config = ManagerConfig::default();
+    if let Some(parent) = input.parent() {
+        config.download_dir = parent.to_path_buf();
+    }
+    let manager = DownloadManager::new(config)
+        .await
+        .context("初始化下载管理器失败")?;
+
+    let name = options.name.clone().unwrap_or_else(|| {
+        input
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "torrent".to_string())
+    });
+    let torrent_path = output.unwrap_or_else(|| PathBuf::from(format!("{}.torrent", name)));
+
+    println!("{} 正在计算分块哈希...", style("⏳").cyan());
+    let created = manager
+        .create_torrent(&input, &torrent_path, options)
+        .await
+        .context("制作种子失败")?;
+
+    println!("{} 种子已生成: {:?}", style("✓").green().bold(), torrent_path);
+    println!("  名称: {}", style(&created.name).white().bold());
+    println!("  大小: {}", format_bytes(created.total_size));
+    println!("  文件数: {}", created.file_count);
+    println!(
+        "  分块: {} x {}",
+        created.piece_count,
+        format_bytes(created.piece_size)
+    );
+    println!("  Info Hash: {}", created.info_hash_hex());
+    println!("  磁力链接: {}", created.magnet);
+
+    if !seed {
+        return Ok(());
+    }
+
+    let task_id = manager
+        .seed_torrent(torrent_path, &input)
+        .await
+        .context("开始做种失败")?;
+    println!(
+        "\n{} 正在做种 (任务 {})，按 Ctrl+C 停止",
+        style("⇡").green(),
+        task_id.short()
+    );
+
+    tokio::signal::ctrl_c().await?;
+    manager.cancel(task_id, false).await?;
+    println!("{} 已停止做种", style("✓").green());
+
+    Ok(())
+}
+
 /// 更新进度条
 fn update_progress_bar(pb: &ProgressBar, progress: &Progress) {
     if progress.total_size > 0 {
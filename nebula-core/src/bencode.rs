@@ -0,0 +1,255 @@
+//! Bencode 编解码模块
+//!
+//! BitTorrent 使用的序列化格式，用于解析 Tracker 响应、.torrent 文件等。
+
+use std::collections::BTreeMap;
+
+/// 列表和字典的最大嵌套层数，超过时视为无效输入（防止恶意数据耗尽栈空间）
+const MAX_DEPTH: usize = 100;
+
+/// Bencode 值
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    /// 整数 `i42e`
+    Int(i64),
+    /// 字节串 `4:spam`
+    Bytes(Vec<u8>),
+    /// 列表 `l...e`
+    List(Vec<Value>),
+    /// 字典 `d...e`（键按字节序排列）
+    Dict(BTreeMap<Vec<u8>, Value>),
+}
+
+impl Value {
+    /// 从字符串创建字节串值
+    pub fn string(s: &str) -> Self {
+        Value::Bytes(s.as_bytes().to_vec())
+    }
+
+    /// 获取整数值
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Value::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// 获取字节串
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Value::Bytes(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// 获取 UTF-8 字符串
+    pub fn as_str(&self) -> Option<&str> {
+        self.as_bytes().and_then(|b| std::str::from_utf8(b).ok())
+    }
+
+    /// 获取列表
+    pub fn as_list(&self) -> Option<&[Value]> {
+        match self {
+            Value::List(l) => Some(l),
+            _ => None,
+        }
+    }
+
+    /// 获取字典
+    pub fn as_dict(&self) -> Option<&BTreeMap<Vec<u8>, Value>> {
+        match self {
+            Value::Dict(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    /// 按键获取字典中的值
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.as_dict().and_then(|d| d.get(key.as_bytes()))
+    }
+
+    /// 编码为字节
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode_into(&mut out);
+        out
+    }
+
+    fn encode_into(&self, out: &mut Vec<u8>) {
+        match self {
+            Value::Int(i) => {
+                out.push(b'i');
+                out.extend_from_slice(i.to_string().as_bytes());
+                out.push(b'e');
+            }
+            Value::Bytes(b) => {
+                out.extend_from_slice(b.len().to_string().as_bytes());
+                out.push(b':');
+                out.extend_from_slice(b);
+            }
+            Value::List(l) => {
+                out.push(b'l');
+                for v in l {
+                    v.encode_into(out);
+                }
+                out.push(b'e');
+            }
+            Value::Dict(d) => {
+                out.push(b'd');
+                for (k, v) in d {
+                    out.extend_from_slice(k.len().to_string().as_bytes());
+                    out.push(b':');
+                    out.extend_from_slice(k);
+                    v.encode_into(out);
+                }
+                out.push(b'e');
+            }
+        }
+    }
+}
+
+/// 解析 Bencode 数据
+///
+/// 要求输入恰好是一个完整的值，末尾不能有多余字节
+pub fn decode(data: &[u8]) -> Option<Value> {
+    let (value, rest) = decode_prefix(data)?;
+    if rest.is_empty() {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// 解析输入开头的一个 Bencode 值，返回值和剩余字节
+///
+/// 嵌套超过 [`MAX_DEPTH`] 层时返回 None
+pub fn decode_prefix(data: &[u8]) -> Option<(Value, &[u8])> {
+    decode_nested(data, 0)
+}
+
+/// 解析 `depth` 层嵌套中的一个值
+fn decode_nested(data: &[u8], depth: usize) -> Option<(Value, &[u8])> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+    match *data.first()? {
+        b'i' => {
+            let end = data.iter().position(|&b| b == b'e')?;
+            let num = std::str::from_utf8(&data[1..end]).ok()?.parse().ok()?;
+            Some((Value::Int(num), &data[end + 1..]))
+        }
+        b'l' => {
+            let mut rest = &data[1..];
+            let mut list = Vec::new();
+            while *rest.first()? != b'e' {
+                let (value, r) = decode_nested(rest, depth + 1)?;
+                list.push(value);
+                rest = r;
+            }
+            Some((Value::List(list), &rest[1..]))
+        }
+        b'd' => {
+            let mut rest = &data[1..];
+            let mut dict = BTreeMap::new();
+            while *rest.first()? != b'e' {
+                let (key, r) = decode_nested(rest, depth + 1)?;
+                let key = match key {
+                    Value::Bytes(k) => k,
+                    _ => return None,
+                };
+                let (value, r) = decode_nested(r, depth + 1)?;
+                dict.insert(key, value);
+                rest = r;
+            }
+            Some((Value::Dict(dict), &rest[1..]))
+        }
+        b'0'..=b'9' => {
+            let colon = data.iter().position(|&b| b == b':')?;
+            let len: usize = std::str::from_utf8(&data[..colon]).ok()?.parse().ok()?;
+            let start = colon + 1;
+            let end = start.checked_add(len)?;
+            if end > data.len() {
+                return None;
+            }
+            Some((Value::Bytes(data[start..end].to_vec()), &data[end..]))
+        }
+        _ => None,
+    }
+}
+
+/// 解析字典，返回每个键及其值的原始字节
+///
+/// 值不会被重新编码，用于在修改 .torrent 文件时保证 info 字典（及其哈希）不变
+pub fn dict_entries_raw(data: &[u8]) -> Option<Vec<(Vec<u8>, &[u8])>> {
+    if *data.first()? != b'd' {
+        return None;
+    }
+
+    let mut rest = &data[1..];
+    let mut entries = Vec::new();
+    while *rest.first()? != b'e' {
+        let (key, r) = decode_prefix(rest)?;
+        let key = match key {
+            Value::Bytes(k) => k,
+            _ => return None,
+        };
+        let (_, after) = decode_prefix(r)?;
+        let raw = &r[..r.len() - after.len()];
+        entries.push((key, raw));
+        rest = after;
+    }
+    Some(entries)
+}
+
+/// 由原始键值对重新组装字典
+pub fn encode_dict_raw(entries: &[(Vec<u8>, &[u8])]) -> Vec<u8> {
+    let mut out = vec![b'd'];
+    for (key, raw) in entries {
+        out.extend_from_slice(key.len().to_string().as_bytes());
+        out.push(b':');
+        out.extend_from_slice(key);
+        out.extend_from_slice(raw);
+    }
+    out.push(b'e');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let raw = b"d8:completei5e10:incompletei3e8:intervali1800e5:peersl4:spamee";
+        let value = decode(raw).unwrap();
+        assert_eq!(value.get("complete").and_then(Value::as_int), Some(5));
+        assert_eq!(value.get("interval").and_then(Value::as_int), Some(1800));
+        assert_eq!(value.encode(), raw.to_vec());
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(decode(b"").is_none());
+        assert!(decode(b"i12").is_none());
+        assert!(decode(b"5:abc").is_none());
+        assert!(decode(b"i1ei2e").is_none());
+
+        // 嵌套过深的输入被拒绝而不是栈溢出
+        let deep = [vec![b'l'; 100_000], vec![b'e'; 100_000]].concat();
+        assert!(decode(&deep).is_none());
+        let nested = [vec![b'l'; MAX_DEPTH], vec![b'e'; MAX_DEPTH]].concat();
+        assert!(decode(&nested).is_some());
+    }
+
+    #[test]
+    fn test_dict_entries_raw() {
+        // info 字典的键故意未排序，原样保留才能保证哈希不变
+        let raw = b"d8:announce3:abc4:infod4:name1:x6:lengthi1eee";
+        let entries = dict_entries_raw(raw).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].1, b"d4:name1:x6:lengthi1ee");
+
+        let kept: Vec<_> = entries.into_iter().filter(|(k, _)| k != b"announce").collect();
+        assert_eq!(encode_dict_raw(&kept), b"d4:infod4:name1:x6:lengthi1eee".to_vec());
+    }
+}
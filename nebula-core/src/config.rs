@@ -2,6 +2,9 @@
 //!
 //! 定义下载管理器和各协议的配置选项。
 
+use crate::extractor::SiteRule;
+use crate::task::DuplicatePolicy;
+
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -20,8 +23,24 @@ pub struct ManagerConfig {
     /// BitTorrent 协议配置
     pub torrent: TorrentConfig,
 
+    /// 视频下载配置
+    #[serde(default)]
+    pub video: VideoConfig,
+
+    /// 外部工具（yt-dlp、ffmpeg）配置
+    #[serde(default)]
+    pub tools: ToolsConfig,
+
+    /// 站点凭据配置
+    #[serde(default)]
+    pub credentials: CredentialConfig,
+
     /// 自动重试配置
     pub retry: RetryConfig,
+
+    /// 重复任务的默认处理策略
+    #[serde(default)]
+    pub duplicate_policy: DuplicatePolicy,
 }
 
 impl Default for ManagerConfig {
@@ -34,7 +53,11 @@ impl Default for ManagerConfig {
             max_concurrent_tasks: 5,
             http: HttpConfig::default(),
             torrent: TorrentConfig::default(),
+            video: VideoConfig::default(),
+            tools: ToolsConfig::default(),
+            credentials: CredentialConfig::default(),
             retry: RetryConfig::default(),
+            duplicate_policy: DuplicatePolicy::default(),
         }
     }
 }
@@ -81,9 +104,10 @@ impl Default for HttpConfig {
 
 /// BitTorrent 配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TorrentConfig {
     /// 监听端口（用于接收 Peer 连接）
-    /// 如果为 None，则使用随机端口
+    /// 如果为 None，则使用 6881-6889 中第一个可用的端口
     pub listen_port: Option<u16>,
 
     /// 是否启用 DHT
@@ -110,17 +134,46 @@ pub struct TorrentConfig {
     /// 例如 2.0 表示上传量达到下载量的 2 倍后停止
     pub seed_ratio_limit: Option<f64>,
 
-    /// 额外的 Tracker 列表
+    /// 额外的 Tracker 列表（始终注入，不参与健康检查）
     pub extra_trackers: Vec<String>,
 
+    /// 从远程列表中注入的 Tracker 数量上限（按健康度排序取前 N 个）
+    pub max_injected_trackers: usize,
+
+    /// Tracker 探测超时时间（秒）
+    pub tracker_probe_timeout_secs: u64,
+
+    /// 磁力链接元数据获取超时时间（秒）
+    ///
+    /// 超时后任务进入停滞状态，并重新查询 DHT 和 Tracker
+    pub metadata_timeout_secs: u64,
+
+    /// 下载数据时多久没有进展视为停滞（秒）
+    pub stall_timeout_secs: u64,
+
+    /// 持续停滞多久后判定任务失败（秒），None 表示一直等待
+    pub stall_fail_after_secs: Option<u64>,
+
+    /// 是否在 Peer 不足时使用 Web Seed (BEP 19)
+    pub enable_web_seeds: bool,
+
+    /// 下载持续过慢多久后改用 Web Seed（秒）
+    pub web_seed_delay_secs: u64,
+
+    /// 低于该速度（字节/秒）视为下载过慢
+    pub web_seed_min_speed: u64,
+
     /// 是否启用顺序下载（边下边播需要）
     pub sequential_download: bool,
+
+    /// IP 过滤配置
+    pub ip_filter: IpFilterConfig,
 }
 
 impl Default for TorrentConfig {
     fn default() -> Self {
         Self {
-            listen_port: None, // 随机端口
+            listen_port: None, // 6881-6889 中第一个可用的端口
             enable_dht: true,
             enable_upnp: true,
             enable_pex: true,
@@ -129,11 +182,129 @@ impl Default for TorrentConfig {
             max_peers: 100,
             seed_ratio_limit: Some(2.0),
             extra_trackers: vec![],
+            max_injected_trackers: 20,
+            tracker_probe_timeout_secs: 5,
+            metadata_timeout_secs: 120,
+            stall_timeout_secs: 180,
+            stall_fail_after_secs: None,
+            enable_web_seeds: true,
+            web_seed_delay_secs: 30,
+            web_seed_min_speed: 50 * 1024, // 50 KB/s
             sequential_download: true, // 默认开启，支持边下边播
+            ip_filter: IpFilterConfig::default(),
+        }
+    }
+}
+
+/// IP 过滤配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IpFilterConfig {
+    /// 是否启用 IP 过滤
+    pub enabled: bool,
+
+    /// 本地列表文件（eMule ipfilter.dat、PeerGuardian P2P 或 CIDR 列表，可为 gzip 压缩）
+    pub files: Vec<PathBuf>,
+
+    /// 订阅的远程列表地址（可选）
+    pub subscription_url: Option<String>,
+
+    /// 订阅列表的更新间隔（小时）
+    pub update_interval_hours: u64,
+}
+
+impl Default for IpFilterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            files: vec![],
+            subscription_url: None,
+            update_interval_hours: 24,
         }
     }
 }
 
+/// 视频下载配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VideoConfig {
+    /// 输出文件名模板（不含扩展名）
+    ///
+    /// 支持的占位符：`{title}` 标题、`{uploader}` 作者、`{date}` 发布日期、
+    /// `{index}` 分 P 序号、`{resolution}` 分辨率、`{id}` 视频 ID
+    pub output_template: String,
+
+    /// 自定义视频站点规则，与内置规则同名时替换内置规则
+    pub sites: Vec<SiteRule>,
+
+    /// 禁用的站点名称（内置或自定义规则，不区分大小写）
+    pub disabled_sites: Vec<String>,
+
+    /// 站点规则无法识别的 HTTP 链接是否询问 yt-dlp（会增加添加任务的耗时）
+    pub probe_unknown_urls: bool,
+
+    /// Bilibili 链接始终使用原生下载（不经过 yt-dlp）
+    ///
+    /// 关闭时只在没有 yt-dlp 的环境（如移动端）使用原生下载
+    pub native_bilibili: bool,
+}
+
+impl Default for VideoConfig {
+    fn default() -> Self {
+        Self {
+            output_template: "{title} [{id}]".to_string(),
+            sites: vec![],
+            disabled_sites: vec![],
+            probe_unknown_urls: false,
+            native_bilibili: false,
+        }
+    }
+}
+
+/// 外部工具配置
+///
+/// 未指定路径时依次查找数据目录中自动更新的版本、应用内嵌版本和系统安装版本
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ToolsConfig {
+    /// yt-dlp 可执行文件路径
+    pub yt_dlp_path: Option<PathBuf>,
+
+    /// ffmpeg 可执行文件路径
+    pub ffmpeg_path: Option<PathBuf>,
+}
+
+/// 站点凭据配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CredentialConfig {
+    /// 是否为匹配的站点自动注入已保存的凭据
+    pub enabled: bool,
+
+    /// 凭据保险库主密钥的来源
+    pub key_source: KeySource,
+}
+
+impl Default for CredentialConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            key_source: KeySource::default(),
+        }
+    }
+}
+
+/// 凭据保险库主密钥的来源
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeySource {
+    /// 系统钥匙串（未启用 `os-keyring` 特性或钥匙串不可用时使用密钥文件）
+    #[default]
+    Keyring,
+    /// 数据目录中的密钥文件
+    File,
+    /// 由用户口令派生，启动后需要调用 `unlock_credentials` 解锁
+    Passphrase,
+}
+
 /// 自动重试配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RetryConfig {
@@ -177,4 +348,14 @@ mod tests {
         let parsed: ManagerConfig = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed.max_concurrent_tasks, config.max_concurrent_tasks);
     }
+
+    #[test]
+    fn test_torrent_config_missing_fields_use_defaults() {
+        let parsed: TorrentConfig =
+            serde_json::from_str(r#"{"enable_dht": false, "max_peers": 50}"#).unwrap();
+        assert!(!parsed.enable_dht);
+        assert_eq!(parsed.max_peers, 50);
+        assert_eq!(parsed.max_injected_trackers, 20);
+        assert_eq!(parsed.tracker_probe_timeout_secs, 5);
+    }
 }
@@ -101,6 +101,28 @@ pub enum NebulaError {
     #[error("没有可用的 Peer")]
     NoPeersAvailable,
 
+    // ===== 外部工具相关错误 =====
+    /// 未找到外部工具（yt-dlp、ffmpeg）
+    #[error("未找到 {0}，请安装或在设置中指定路径")]
+    ToolNotFound(String),
+
+    /// 外部工具更新失败
+    #[error("工具更新失败: {0}")]
+    ToolUpdateFailed(String),
+
+    // ===== 账号相关错误 =====
+    /// 登录已失效，需要重新登录
+    #[error("登录已失效，请重新登录: {0}")]
+    LoginExpired(String),
+
+    /// 凭据保险库未解锁（使用口令加密时需要先解锁）
+    #[error("凭据保险库未解锁")]
+    VaultLocked,
+
+    /// 凭据保险库读写或加解密失败
+    #[error("凭据保险库错误: {0}")]
+    CredentialError(String),
+
     // ===== 配置相关错误 =====
     /// 配置无效
     #[error("配置无效: {0}")]
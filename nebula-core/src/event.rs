@@ -2,9 +2,10 @@
 //!
 //! 定义下载过程中的各类事件，用于向上层通知下载进度、状态变化等。
 
-use crate::task::TaskId;
+use crate::task::{TaskId, TaskStatus};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 /// 下载事件枚举
 ///
@@ -15,6 +16,8 @@ pub enum DownloadEvent {
     TaskAdded {
         task_id: TaskId,
         name: String,
+        /// 缩略图地址（视频任务）
+        thumbnail: Option<String>,
     },
 
     /// 任务开始下载
@@ -52,20 +55,46 @@ pub enum DownloadEvent {
         error: String,
     },
 
-    /// 任务已取消/删除
+    /// 任务已从任务表中移除（取消只发送 [`DownloadEvent::StatusChanged`]）
     TaskRemoved {
         task_id: TaskId,
     },
 
-    /// BitTorrent 特有：元数据已获取（磁力链接解析完成）
+    /// 元数据已获取（HTTP 响应头、视频信息或磁力链接解析完成）
     MetadataReceived {
         task_id: TaskId,
-        /// 种子名称
+        /// 文件名、视频标题或种子名称
         name: String,
-        /// 总大小（字节）
+        /// 总大小（字节），未知时为 0
         total_size: u64,
         /// 文件数量
         file_count: usize,
+        /// MIME 类型
+        mime_type: Option<String>,
+        /// 是否支持断点续传
+        supports_resume: bool,
+        /// 缩略图地址
+        thumbnail: Option<String>,
+        /// 时长（秒）
+        duration_secs: Option<u64>,
+    },
+
+    /// BitTorrent 特有：正在校验磁盘上的已有数据
+    CheckProgress {
+        task_id: TaskId,
+        /// 已校验字节数
+        checked_bytes: u64,
+        /// 总大小（字节）
+        total_bytes: u64,
+    },
+
+    /// BitTorrent 特有：数据校验完成
+    CheckCompleted {
+        task_id: TaskId,
+        /// 校验通过的字节数
+        valid_bytes: u64,
+        /// 总大小（字节）
+        total_bytes: u64,
     },
 
     /// BitTorrent 特有：Peer 连接状态变化
@@ -76,6 +105,120 @@ pub enum DownloadEvent {
         /// 总发现 Peer 数
         total_peers: usize,
     },
+
+    /// BitTorrent 特有：长时间没有进展（找不到 Peer 或收不到数据）
+    ///
+    /// 停滞期间会定期重复发送，附带最新的诊断信息
+    TaskStalled {
+        task_id: TaskId,
+        /// 停滞所处阶段
+        phase: StallPhase,
+        /// 诊断信息
+        diagnostics: StallDiagnostics,
+    },
+
+    /// BitTorrent 特有：停滞后重新开始有进展
+    StallRecovered {
+        task_id: TaskId,
+        /// 停滞所处阶段
+        phase: StallPhase,
+    },
+
+    /// 视频特有：进入新的下载阶段（视频流、音频流、合并、后处理）
+    VideoStageChanged {
+        task_id: TaskId,
+        /// 新阶段
+        stage: VideoStage,
+    },
+
+    /// 最终输出文件已确定（视频合并、移动完成后）
+    OutputFileResolved {
+        task_id: TaskId,
+        /// 输出文件路径
+        path: PathBuf,
+    },
+
+    /// 任务状态发生变化（每次状态变化只发送一次）
+    StatusChanged {
+        task_id: TaskId,
+        /// 原状态
+        from: TaskStatus,
+        /// 新状态
+        to: TaskStatus,
+    },
+
+    /// 账号事件：Bilibili 登录已失效，保存的 Cookie 已删除，需要重新扫码登录
+    BilibiliLoginExpired {
+        /// 失效原因
+        reason: String,
+    },
+}
+
+impl DownloadEvent {
+    /// 事件所属的任务 ID，账号等与任务无关的事件返回 None
+    pub fn task_id(&self) -> Option<TaskId> {
+        match self {
+            DownloadEvent::TaskAdded { task_id, .. }
+            | DownloadEvent::TaskStarted { task_id }
+            | DownloadEvent::ProgressUpdated { task_id, .. }
+            | DownloadEvent::TaskPaused { task_id }
+            | DownloadEvent::TaskResumed { task_id }
+            | DownloadEvent::TaskCompleted { task_id, .. }
+            | DownloadEvent::TaskFailed { task_id, .. }
+            | DownloadEvent::TaskRemoved { task_id }
+            | DownloadEvent::MetadataReceived { task_id, .. }
+            | DownloadEvent::CheckProgress { task_id, .. }
+            | DownloadEvent::CheckCompleted { task_id, .. }
+            | DownloadEvent::PeerUpdate { task_id, .. }
+            | DownloadEvent::TaskStalled { task_id, .. }
+            | DownloadEvent::StallRecovered { task_id, .. }
+            | DownloadEvent::VideoStageChanged { task_id, .. }
+            | DownloadEvent::OutputFileResolved { task_id, .. }
+            | DownloadEvent::StatusChanged { task_id, .. } => Some(*task_id),
+            DownloadEvent::BilibiliLoginExpired { .. } => None,
+        }
+    }
+}
+
+/// 停滞所处阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StallPhase {
+    /// 获取元数据（磁力链接）
+    Metadata,
+    /// 下载数据
+    Payload,
+}
+
+/// 视频下载阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VideoStage {
+    /// 下载视频流（或音视频合一的文件）
+    Video,
+    /// 下载音频流
+    Audio,
+    /// 合并音视频
+    Merging,
+    /// 提取音频（转码为目标格式）
+    ExtractingAudio,
+    /// 其他后处理（转码、嵌入字幕等）
+    PostProcessing,
+}
+
+/// 停滞诊断信息
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StallDiagnostics {
+    /// 已停滞时长（秒）
+    pub stalled_secs: u64,
+    /// DHT 路由表节点数（DHT 未启用时为 None）
+    pub dht_nodes: Option<usize>,
+    /// Tracker 总数
+    pub trackers_total: usize,
+    /// 成功连接的 Tracker 数
+    pub trackers_reached: usize,
+    /// 发现的 Peer 数
+    pub peers_seen: usize,
+    /// 已连接的 Peer 数
+    pub peers_connected: usize,
 }
 
 /// 下载进度信息
@@ -0,0 +1,319 @@
+//! 视频站点识别模块
+//!
+//! 按站点规则判断链接是否交给 yt-dlp 处理。规则按解析后的主机名匹配域名及其子域名，
+//! 并可限定路径，避免 `https://example.com/x.com-backup.zip` 或 `?ref=youtube.com`
+//! 这类链接被误判为视频。
+//!
+//! 内置规则覆盖常见视频网站，用户可在 [`VideoConfig`] 中添加或禁用规则；
+//! 规则无法识别的链接还可以询问 yt-dlp（参见 `VideoHandler::probe_extractor`）。
+
+use crate::config::VideoConfig;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+use url::Url;
+
+/// 内置站点规则：(名称, 域名, 路径模式)
+const BUILTIN_SITES: &[(&str, &[&str], &[&str])] = &[
+    (
+        "YouTube",
+        &["youtube.com", "youtube-nocookie.com"],
+        &[
+            "/watch*",
+            "/shorts/*",
+            "/live/*",
+            "/embed/*",
+            "/playlist*",
+            "/@*",
+            "/channel/*",
+            "/c/*",
+            "/user/*",
+        ],
+    ),
+    ("YouTube", &["youtu.be"], &[]),
+    (
+        "Bilibili",
+        &["bilibili.com"],
+        &[
+            "/video/*",
+            "/bangumi/play/*",
+            "/cheese/play/*",
+            "/list/*",
+            "/medialist/*",
+            "/festival/*",
+            "/audio/*",
+        ],
+    ),
+    (
+        "Bilibili",
+        &["space.bilibili.com", "live.bilibili.com", "b23.tv"],
+        &[],
+    ),
+    (
+        "Twitter",
+        &["twitter.com", "x.com"],
+        &["/*/status/*", "/i/broadcasts/*", "/i/spaces/*"],
+    ),
+    ("TikTok", &["tiktok.com"], &["/@*/video/*", "/t/*"]),
+    ("TikTok", &["vm.tiktok.com", "vt.tiktok.com"], &[]),
+    ("Douyin", &["douyin.com"], &["/video/*", "/note/*"]),
+    ("Douyin", &["v.douyin.com"], &[]),
+    ("Vimeo", &["vimeo.com"], &[]),
+    (
+        "Dailymotion",
+        &["dailymotion.com"],
+        &["/video/*", "/playlist/*"],
+    ),
+    ("Dailymotion", &["dai.ly"], &[]),
+    ("Twitch", &["twitch.tv"], &[]),
+    (
+        "Instagram",
+        &["instagram.com"],
+        &["/p/*", "/reel/*", "/reels/*", "/tv/*"],
+    ),
+    (
+        "Facebook",
+        &["facebook.com"],
+        &["/watch*", "/*/videos/*", "/reel/*"],
+    ),
+    ("Facebook", &["fb.watch"], &[]),
+    (
+        "Niconico",
+        &["nicovideo.jp"],
+        &["/watch/*", "/mylist/*", "/series/*"],
+    ),
+    ("Niconico", &["nico.ms"], &[]),
+];
+
+/// 视频站点规则
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SiteRule {
+    /// 站点名称，禁用规则时按名称匹配（不区分大小写）
+    pub name: String,
+
+    /// 域名，同时匹配其子域名
+    pub domains: Vec<String>,
+
+    /// 路径模式，`*` 匹配任意字符
+    ///
+    /// 为空时匹配除首页外的所有路径
+    #[serde(default)]
+    pub paths: Vec<String>,
+}
+
+impl SiteRule {
+    /// 创建匹配指定域名所有路径的规则
+    pub fn new(name: impl Into<String>, domains: Vec<String>) -> Self {
+        Self {
+            name: name.into(),
+            domains,
+            paths: vec![],
+        }
+    }
+
+    /// 限定路径模式
+    pub fn with_paths(mut self, paths: Vec<String>) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    /// 检查链接是否匹配该规则
+    pub fn matches(&self, url: &Url) -> bool {
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+        let host = host.trim_end_matches('.');
+        let domain_matches = self.domains.iter().any(|domain| {
+            let domain = domain.trim().trim_start_matches('.').to_lowercase();
+            host == domain
+                || host
+                    .strip_suffix(domain.as_str())
+                    .is_some_and(|sub| sub.ends_with('.'))
+        });
+        if !domain_matches {
+            return false;
+        }
+
+        let path = url.path();
+        if self.paths.is_empty() {
+            return path != "/";
+        }
+        self.paths.iter().any(|pattern| glob_match(pattern, path))
+    }
+}
+
+/// 视频站点注册表
+#[derive(Debug, Clone)]
+pub struct ExtractorRegistry {
+    rules: Vec<SiteRule>,
+}
+
+impl Default for ExtractorRegistry {
+    fn default() -> Self {
+        Self {
+            rules: builtin_rules(),
+        }
+    }
+}
+
+impl ExtractorRegistry {
+    /// 只包含内置规则的注册表
+    pub fn builtin() -> &'static Self {
+        static BUILTIN: OnceLock<ExtractorRegistry> = OnceLock::new();
+        BUILTIN.get_or_init(Self::default)
+    }
+
+    /// 根据配置创建注册表
+    ///
+    /// 自定义规则与内置规则同名时替换内置规则，之后移除所有被禁用的规则
+    pub fn new(config: &VideoConfig) -> Self {
+        let same_name = |a: &str, b: &str| a.eq_ignore_ascii_case(b);
+
+        let mut rules: Vec<SiteRule> = builtin_rules()
+            .into_iter()
+            .filter(|rule| {
+                !config
+                    .sites
+                    .iter()
+                    .any(|site| same_name(&site.name, &rule.name))
+            })
+            .collect();
+        rules.extend(config.sites.iter().cloned());
+        rules.retain(|rule| {
+            !config
+                .disabled_sites
+                .iter()
+                .any(|name| same_name(name, &rule.name))
+        });
+
+        Self { rules }
+    }
+
+    /// 所有生效的规则
+    pub fn rules(&self) -> &[SiteRule] {
+        &self.rules
+    }
+
+    /// 查找匹配链接的规则，只匹配 http/https 链接
+    pub fn match_url(&self, url: &str) -> Option<&SiteRule> {
+        let url = Url::parse(url.trim()).ok()?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return None;
+        }
+        self.rules.iter().find(|rule| rule.matches(&url))
+    }
+
+    /// 检查链接是否属于支持的视频网站
+    pub fn is_video_url(&self, url: &str) -> bool {
+        self.match_url(url).is_some()
+    }
+}
+
+/// 内置规则列表
+fn builtin_rules() -> Vec<SiteRule> {
+    let to_strings = |items: &[&str]| items.iter().map(|s| s.to_string()).collect();
+    BUILTIN_SITES
+        .iter()
+        .map(|(name, domains, paths)| {
+            SiteRule::new(*name, to_strings(domains)).with_paths(to_strings(paths))
+        })
+        .collect()
+}
+
+/// 简单的通配符匹配，`*` 匹配任意长度的任意字符
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let text = text.as_bytes();
+    let (mut p, mut t) = (0, 0);
+    // 最近一个 `*` 的位置及其当前匹配到的文本位置
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            star = Some((p, t));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == text[t] {
+            p += 1;
+            t += 1;
+        } else if let Some((star_p, star_t)) = star {
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&b| b == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_rules() {
+        let registry = ExtractorRegistry::builtin();
+        let site = |url: &str| registry.match_url(url).map(|rule| rule.name.as_str());
+
+        assert_eq!(
+            site("https://www.youtube.com/watch?v=dQw4w9WgXcQ"),
+            Some("YouTube")
+        );
+        assert_eq!(site("https://youtu.be/dQw4w9WgXcQ"), Some("YouTube"));
+        assert_eq!(site("https://m.youtube.com/shorts/abc"), Some("YouTube"));
+        assert_eq!(
+            site("https://www.bilibili.com/video/BV1xx411c7mD?p=2"),
+            Some("Bilibili")
+        );
+        assert_eq!(site("https://b23.tv/abc123"), Some("Bilibili"));
+        assert_eq!(site("https://x.com/user/status/123"), Some("Twitter"));
+        assert_eq!(site("HTTPS://WWW.NICOVIDEO.JP/watch/sm9"), Some("Niconico"));
+
+        // 子串、查询参数和首页都不应匹配
+        assert_eq!(site("https://example.com/x.com-backup.zip"), None);
+        assert_eq!(site("https://example.com/file.zip?ref=youtube.com"), None);
+        assert_eq!(site("https://notyoutube.com/watch?v=1"), None);
+        assert_eq!(site("https://youtube.com.evil.example/watch?v=1"), None);
+        assert_eq!(site("https://www.bilibili.com/"), None);
+        assert_eq!(site("https://x.com/user"), None);
+        assert_eq!(site("ftp://youtube.com/watch"), None);
+    }
+
+    #[test]
+    fn test_config_rules() {
+        let config = VideoConfig {
+            sites: vec![
+                SiteRule::new("PeerTube", vec!["videos.example.org".to_string()])
+                    .with_paths(vec!["/w/*".to_string()]),
+                SiteRule::new("vimeo", vec!["vimeo.com".to_string()])
+                    .with_paths(vec!["/channels/*".to_string()]),
+            ],
+            disabled_sites: vec!["twitter".to_string()],
+            ..Default::default()
+        };
+        let registry = ExtractorRegistry::new(&config);
+
+        assert!(registry.is_video_url("https://videos.example.org/w/abc"));
+        assert!(!registry.is_video_url("https://videos.example.org/about"));
+        assert!(!registry.is_video_url("https://x.com/user/status/123"));
+        assert!(registry.is_video_url("https://vimeo.com/channels/staffpicks"));
+        assert!(!registry.is_video_url("https://vimeo.com/76979871"));
+        assert_eq!(
+            registry
+                .rules()
+                .iter()
+                .filter(|rule| rule.name.eq_ignore_ascii_case("vimeo"))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("/watch*", "/watch"));
+        assert!(glob_match("/*/status/*", "/user/status/123"));
+        assert!(glob_match("/@*/video/*", "/@name/video/1"));
+        assert!(!glob_match("/*/status/*", "/user/likes"));
+        assert!(!glob_match("/video/*", "/videos/1"));
+        assert!(glob_match("*", ""));
+    }
+}
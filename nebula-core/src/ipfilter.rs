@@ -0,0 +1,528 @@
+//! IP 过滤模块
+//!
+//! 加载 IP 黑名单并应用到 BitTorrent Peer 连接，支持以下格式：
+//! - eMule `ipfilter.dat`: `001.002.003.000 - 001.002.003.255 , 000 , 描述`
+//! - PeerGuardian P2P: `描述:1.2.3.0-1.2.3.255`
+//! - CIDR / 单个地址: `1.2.3.0/24`、`2001:db8::/32`、`1.2.3.4`
+//!
+//! 支持订阅远程列表（可为 gzip 压缩），定期在后台自动更新。
+//!
+//! 过滤分两层生效：
+//! - 引擎黑名单：IPv4 范围写成 P2P 文件，由 BitTorrent 引擎拒绝所有来源的连接。
+//!   引擎只在创建 Session 时读取该文件，IPv4 范围变化后会重新创建 Session
+//! - Nebula 交给引擎的 Peer（磁力链接中的 Peer、用户添加的 Tracker 返回的 Peer）
+//!   会先经过完整的过滤器（包括 IPv6 范围），列表更新后立即生效
+
+use crate::config::IpFilterConfig;
+use crate::error::{NebulaError, Result};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::io::Read;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::fs;
+use tracing::{debug, info, warn};
+
+/// 订阅列表缓存文件名
+const SUBSCRIPTION_CACHE_FILENAME: &str = "ipfilter_subscription.txt";
+
+/// 提供给 BitTorrent 引擎的合并黑名单文件名
+const BLOCKLIST_FILENAME: &str = "ipfilter.p2p";
+
+/// eMule 格式中访问级别不高于该值的范围会被屏蔽
+const EMULE_BLOCK_LEVEL: u32 = 127;
+
+/// 下载订阅列表的超时时间
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// IP 过滤统计
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IpFilterStats {
+    /// 合并后的 IP 范围数量
+    pub range_count: usize,
+    /// Nebula 交给引擎前拒绝的 Peer 数量（按地址去重）
+    ///
+    /// 只包括磁力链接中的 Peer 和 Nebula 代为通告获得的 Peer；引擎从 DHT、PEX
+    /// 和 Tracker 获得的 Peer 由引擎按黑名单拒绝，引擎不提供这部分的计数
+    pub blocked_handoff_peers: u64,
+    /// 订阅列表最近一次更新时间
+    pub last_updated: Option<DateTime<Utc>>,
+}
+
+/// IP 过滤器
+///
+/// 范围在加载时排序合并，查询为二分查找
+#[derive(Debug, Default)]
+pub struct IpFilter {
+    /// IPv4 范围（闭区间）
+    v4: Vec<(u32, u32)>,
+    /// IPv6 范围（闭区间）
+    v6: Vec<(u128, u128)>,
+    /// 已拒绝的 Peer 地址
+    blocked: Mutex<HashSet<IpAddr>>,
+}
+
+impl IpFilter {
+    /// 解析黑名单文本，格式按行自动识别，无法识别的行会被跳过
+    pub fn parse(text: &str) -> Self {
+        let mut filter = Self::default();
+        filter.extend(text);
+        filter
+    }
+
+    /// 追加黑名单文本中的规则
+    pub fn extend(&mut self, text: &str) {
+        let mut skipped = 0usize;
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with("//") {
+                continue;
+            }
+            match parse_line(line) {
+                Some(ParsedLine::Block(IpAddr::V4(start), IpAddr::V4(end))) => {
+                    self.v4.push((u32::from(start), u32::from(end)));
+                }
+                Some(ParsedLine::Block(IpAddr::V6(start), IpAddr::V6(end))) => {
+                    self.v6.push((u128::from(start), u128::from(end)));
+                }
+                Some(ParsedLine::Allow) => {}
+                _ => skipped += 1,
+            }
+        }
+        if skipped > 0 {
+            debug!("IP 过滤列表中有 {} 行无法识别", skipped);
+        }
+        self.v4 = merge_ranges(std::mem::take(&mut self.v4), |v| v.saturating_add(1));
+        self.v6 = merge_ranges(std::mem::take(&mut self.v6), |v| v.saturating_add(1));
+    }
+
+    /// 合并后的范围数量
+    pub fn len(&self) -> usize {
+        self.v4.len() + self.v6.len()
+    }
+
+    /// 是否没有任何规则
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 检查地址是否在黑名单中
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match ip {
+            IpAddr::V4(v4) => range_contains(&self.v4, u32::from(v4)),
+            IpAddr::V6(v6) => match v6.to_ipv4_mapped() {
+                Some(v4) => range_contains(&self.v4, u32::from(v4)),
+                None => range_contains(&self.v6, u128::from(v6)),
+            },
+        }
+    }
+
+    /// 检查 Peer 是否应被拒绝，命中时计入屏蔽统计
+    pub fn check_peer(&self, ip: IpAddr) -> bool {
+        if !self.contains(ip) {
+            return false;
+        }
+        if let Ok(mut blocked) = self.blocked.lock() {
+            blocked.insert(ip);
+        }
+        true
+    }
+
+    /// 经 [`check_peer`](Self::check_peer) 拒绝的 Peer 数量（按地址去重）
+    pub fn blocked_count(&self) -> u64 {
+        self.blocked.lock().map(|b| b.len() as u64).unwrap_or(0)
+    }
+
+    /// 导出的引擎黑名单是否与 `other` 相同（只比较 IPv4 范围）
+    pub fn same_blocklist(&self, other: &IpFilter) -> bool {
+        self.v4 == other.v4
+    }
+
+    /// 导出为 PeerGuardian P2P 格式
+    ///
+    /// BitTorrent 引擎的黑名单只识别 IPv4 范围，IPv6 规则不会导出
+    pub fn to_p2p(&self) -> String {
+        let mut out = String::with_capacity(self.v4.len() * 32);
+        for (start, end) in &self.v4 {
+            out.push_str(&format!(
+                "nebula:{}-{}\n",
+                Ipv4Addr::from(*start),
+                Ipv4Addr::from(*end)
+            ));
+        }
+        out
+    }
+}
+
+/// 单行解析结果
+enum ParsedLine {
+    /// 屏蔽范围
+    Block(IpAddr, IpAddr),
+    /// eMule 格式中允许访问的范围
+    Allow,
+}
+
+/// 解析一行规则，依次尝试各种格式
+fn parse_line(line: &str) -> Option<ParsedLine> {
+    parse_emule(line)
+        .or_else(|| parse_cidr(line))
+        .or_else(|| parse_p2p(line))
+}
+
+/// eMule: "start - end , level , desc"
+fn parse_emule(line: &str) -> Option<ParsedLine> {
+    let mut parts = line.splitn(3, ',');
+    let (start, end) = parse_range(parts.next()?)?;
+    let level: u32 = parts.next()?.trim().parse().ok()?;
+    Some(if level <= EMULE_BLOCK_LEVEL {
+        ParsedLine::Block(start, end)
+    } else {
+        ParsedLine::Allow
+    })
+}
+
+/// CIDR 或单个地址（IPv6 地址本身含 ':'，需先于 P2P 格式判断）
+fn parse_cidr(line: &str) -> Option<ParsedLine> {
+    let (start, end) = match line.split_once('/') {
+        Some((addr, prefix)) => {
+            let addr: IpAddr = addr.trim().parse().ok()?;
+            let prefix: u8 = prefix.trim().parse().ok()?;
+            cidr_range(addr, prefix)?
+        }
+        None => {
+            let addr: IpAddr = line.parse().ok()?;
+            (addr, addr)
+        }
+    };
+    Some(ParsedLine::Block(start, end))
+}
+
+/// P2P: "desc:start-end"（描述中可能含 ':'，取最后一个）
+fn parse_p2p(line: &str) -> Option<ParsedLine> {
+    let (_, range) = line.rsplit_once(':')?;
+    let (start, end) = parse_range(range)?;
+    Some(ParsedLine::Block(start, end))
+}
+
+/// 解析 "start - end" 形式的地址范围
+fn parse_range(s: &str) -> Option<(IpAddr, IpAddr)> {
+    let (start, end) = s.split_once('-')?;
+    let start = parse_ip(start.trim())?;
+    let end = parse_ip(end.trim())?;
+    match (start, end) {
+        (IpAddr::V4(a), IpAddr::V4(b)) if a <= b => Some((start, end)),
+        (IpAddr::V6(a), IpAddr::V6(b)) if a <= b => Some((start, end)),
+        _ => None,
+    }
+}
+
+/// 解析地址，兼容 eMule 列表中带前导零的 IPv4（如 `001.002.003.004`）
+fn parse_ip(s: &str) -> Option<IpAddr> {
+    if let Ok(addr) = s.parse() {
+        return Some(addr);
+    }
+    let octets: Vec<u8> = s
+        .split('.')
+        .map(|p| p.parse().ok())
+        .collect::<Option<_>>()?;
+    let octets: [u8; 4] = octets.try_into().ok()?;
+    Some(IpAddr::V4(Ipv4Addr::from(octets)))
+}
+
+/// 计算 CIDR 对应的地址范围
+fn cidr_range(addr: IpAddr, prefix: u8) -> Option<(IpAddr, IpAddr)> {
+    match addr {
+        IpAddr::V4(v4) if prefix <= 32 => {
+            let mask = u32::MAX.checked_shl(32 - prefix as u32).unwrap_or(0);
+            let start = u32::from(v4) & mask;
+            Some((
+                IpAddr::V4(Ipv4Addr::from(start)),
+                IpAddr::V4(Ipv4Addr::from(start | !mask)),
+            ))
+        }
+        IpAddr::V6(v6) if prefix <= 128 => {
+            let mask = u128::MAX.checked_shl(128 - prefix as u32).unwrap_or(0);
+            let start = u128::from(v6) & mask;
+            Some((
+                IpAddr::V6(Ipv6Addr::from(start)),
+                IpAddr::V6(Ipv6Addr::from(start | !mask)),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// 排序并合并重叠或相邻的范围，`succ` 返回下一个地址
+fn merge_ranges<T: Ord + Copy>(mut ranges: Vec<(T, T)>, succ: impl Fn(T) -> T) -> Vec<(T, T)> {
+    ranges.sort_unstable();
+    let mut merged: Vec<(T, T)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        if let Some(last) = merged.last_mut() {
+            if start <= succ(last.1) {
+                last.1 = last.1.max(end);
+                continue;
+            }
+        }
+        merged.push((start, end));
+    }
+    merged
+}
+
+/// 在已合并的范围中二分查找
+fn range_contains<T: Ord + Copy>(ranges: &[(T, T)], value: T) -> bool {
+    let idx = ranges.partition_point(|(start, _)| *start <= value);
+    idx > 0 && value <= ranges[idx - 1].1
+}
+
+/// IP 过滤列表管理器
+///
+/// 负责合并本地列表与订阅列表，并生成 BitTorrent 引擎使用的黑名单文件
+pub struct IpFilterManager {
+    /// 配置
+    config: IpFilterConfig,
+    /// 缓存目录
+    cache_dir: PathBuf,
+}
+
+impl IpFilterManager {
+    /// 创建新的 IP 过滤列表管理器
+    pub fn new(config: IpFilterConfig, cache_dir: PathBuf) -> Self {
+        Self { config, cache_dir }
+    }
+
+    /// 订阅列表的更新间隔
+    pub fn update_interval(&self) -> Duration {
+        Duration::from_secs(self.config.update_interval_hours.max(1) * 60 * 60)
+    }
+
+    /// 引擎黑名单文件路径
+    pub fn blocklist_path(&self) -> PathBuf {
+        self.cache_dir.join(BLOCKLIST_FILENAME)
+    }
+
+    /// 加载本地列表和订阅列表缓存
+    ///
+    /// 不访问网络，订阅列表由 [`update_subscription`](Self::update_subscription) 更新
+    pub async fn load(&self) -> Result<IpFilter> {
+        let mut filter = IpFilter::default();
+        for path in &self.config.files {
+            match read_list(path).await {
+                Ok(text) => filter.extend(&text),
+                Err(e) => warn!("读取 IP 过滤列表失败 {:?}: {}", path, e),
+            }
+        }
+        if self.config.subscription_url.is_some() {
+            if let Ok(text) = fs::read_to_string(self.subscription_cache_path()).await {
+                filter.extend(&text);
+            }
+        }
+
+        info!("IP 过滤已加载 {} 个范围", filter.len());
+        Ok(filter)
+    }
+
+    /// 将过滤器写入引擎黑名单文件
+    pub async fn write_blocklist(&self, filter: &IpFilter) -> Result<PathBuf> {
+        fs::create_dir_all(&self.cache_dir).await?;
+        let path = self.blocklist_path();
+        fs::write(&path, filter.to_p2p()).await?;
+        Ok(path)
+    }
+
+    /// 下载订阅列表并更新缓存
+    pub async fn update_subscription(&self) -> Result<()> {
+        let Some(url) = &self.config.subscription_url else {
+            return Ok(());
+        };
+
+        debug!("更新 IP 过滤订阅: {}", url);
+        let client = reqwest::Client::builder()
+            .timeout(DOWNLOAD_TIMEOUT)
+            .build()
+            .map_err(|e| NebulaError::Internal(e.to_string()))?;
+        let response = client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| NebulaError::NetworkError(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(NebulaError::HttpError {
+                status_code: response.status().as_u16(),
+                message: format!("下载 IP 过滤列表失败: {}", url),
+            });
+        }
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| NebulaError::NetworkError(e.to_string()))?;
+        let text = decode_list(&bytes)?;
+
+        // 内容中没有任何可识别的规则时不覆盖旧缓存
+        if IpFilter::parse(&text).is_empty() {
+            return Err(NebulaError::InvalidConfig(format!(
+                "IP 过滤订阅中没有可识别的规则: {}",
+                url
+            )));
+        }
+
+        fs::create_dir_all(&self.cache_dir).await?;
+        fs::write(self.subscription_cache_path(), text).await?;
+        info!("IP 过滤订阅已更新");
+        Ok(())
+    }
+
+    /// 订阅列表最近一次更新时间
+    pub async fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.config.subscription_url.as_ref()?;
+        let modified = fs::metadata(self.subscription_cache_path())
+            .await
+            .ok()?
+            .modified()
+            .ok()?;
+        Some(modified.into())
+    }
+
+    /// 订阅列表是否需要更新
+    pub async fn subscription_is_stale(&self) -> bool {
+        if self.config.subscription_url.is_none() {
+            return false;
+        }
+        match self.last_updated().await {
+            Some(at) => {
+                let age = Utc::now().signed_duration_since(at);
+                age.to_std().map(|d| d >= self.update_interval()).unwrap_or(false)
+            }
+            None => true,
+        }
+    }
+
+    fn subscription_cache_path(&self) -> PathBuf {
+        self.cache_dir.join(SUBSCRIPTION_CACHE_FILENAME)
+    }
+}
+
+/// 读取本地列表文件
+async fn read_list(path: &Path) -> Result<String> {
+    let bytes = fs::read(path).await.map_err(|e| NebulaError::IoError {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    decode_list(&bytes)
+}
+
+/// 解码列表内容，自动识别 gzip 压缩
+fn decode_list(bytes: &[u8]) -> Result<String> {
+    if bytes.starts_with(&[0x1f, 0x8b]) {
+        let mut text = String::new();
+        flate2::read::GzDecoder::new(bytes)
+            .read_to_string(&mut text)
+            .map_err(|e| NebulaError::InvalidConfig(format!("解压 IP 过滤列表失败: {}", e)))?;
+        Ok(text)
+    } else {
+        Ok(String::from_utf8_lossy(bytes).into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_formats() {
+        let filter = IpFilter::parse(
+            "# comment\n\
+             001.002.003.000 - 001.002.003.255 , 000 , eMule range\n\
+             010.000.000.000 - 010.255.255.255 , 200 , allowed\n\
+             Some Org, Inc: a/b:5.6.7.0-5.6.7.10\n\
+             192.168.0.0/16\n\
+             8.8.8.8\n\
+             2001:db8::/32\n\
+             garbage line\n",
+        );
+
+        assert!(filter.contains(ip("1.2.3.128")));
+        assert!(!filter.contains(ip("10.1.1.1")));
+        assert!(filter.contains(ip("5.6.7.10")));
+        assert!(!filter.contains(ip("5.6.7.11")));
+        assert!(filter.contains(ip("192.168.44.1")));
+        assert!(filter.contains(ip("8.8.8.8")));
+        assert!(!filter.contains(ip("8.8.4.4")));
+        assert!(filter.contains(ip("2001:db8:1::1")));
+        assert!(!filter.contains(ip("2001:db9::1")));
+        assert!(filter.contains(ip("::ffff:1.2.3.4")));
+    }
+
+    #[test]
+    fn test_merge_and_export() {
+        let filter = IpFilter::parse("1.0.0.0/24\n1.0.1.0/24\n1.0.0.5\n2.0.0.0/8\n::1\n");
+        assert_eq!(filter.len(), 3);
+        assert_eq!(
+            filter.to_p2p(),
+            "nebula:1.0.0.0-1.0.1.255\nnebula:2.0.0.0-2.255.255.255\n"
+        );
+    }
+
+    #[test]
+    fn test_blocked_count() {
+        let filter = IpFilter::parse("1.2.3.0/24\n");
+        assert!(filter.check_peer(ip("1.2.3.4")));
+        assert!(filter.check_peer(ip("1.2.3.4")));
+        assert!(filter.check_peer(ip("1.2.3.5")));
+        assert!(!filter.check_peer(ip("4.3.2.1")));
+        assert_eq!(filter.blocked_count(), 2);
+    }
+
+    #[test]
+    fn test_same_blocklist() {
+        let filter = IpFilter::parse("1.2.3.0/24\n");
+        assert!(filter.same_blocklist(&IpFilter::parse("test:1.2.3.0-1.2.3.255\n")));
+        assert!(filter.same_blocklist(&IpFilter::parse("1.2.3.0/24\n2001:db8::/32\n")));
+        assert!(!filter.same_blocklist(&IpFilter::parse("1.2.3.0/23\n")));
+    }
+
+    #[tokio::test]
+    async fn test_load_local_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let list = dir.path().join("ipfilter.dat");
+        fs::write(&list, "001.002.003.000 - 001.002.003.255 , 000 , test\n")
+            .await
+            .unwrap();
+
+        let config = IpFilterConfig {
+            enabled: true,
+            files: vec![list],
+            ..Default::default()
+        };
+        let manager = IpFilterManager::new(config, dir.path().to_path_buf());
+        let filter = manager.load().await.unwrap();
+        assert!(filter.contains(ip("1.2.3.4")));
+
+        let path = manager.write_blocklist(&filter).await.unwrap();
+        let content = fs::read_to_string(path).await.unwrap();
+        assert_eq!(content, "nebula:1.2.3.0-1.2.3.255\n");
+    }
+
+    #[tokio::test]
+    async fn test_load_does_not_fetch_subscription() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = IpFilterConfig {
+            enabled: true,
+            subscription_url: Some("http://127.0.0.1:9/ipfilter.dat".to_string()),
+            ..Default::default()
+        };
+        let manager = IpFilterManager::new(config, dir.path().to_path_buf());
+        assert!(manager.subscription_is_stale().await);
+
+        let filter = manager.load().await.unwrap();
+        assert!(filter.is_empty());
+        assert!(manager.last_updated().await.is_none());
+    }
+}
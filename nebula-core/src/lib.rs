@@ -38,20 +38,43 @@
 //! - [`task`]: 下载任务定义和状态管理
 //! - [`protocol`]: 协议处理模块（HTTP、BitTorrent）
 //! - [`event`]: 事件系统，用于进度通知
+//! - [`extractor`]: 视频站点识别
 //! - [`config`]: 配置管理
+//! - [`trackers`]: Tracker 列表获取与健康探测
+//! - [`bencode`]: Bencode 编解码
+//! - [`ipfilter`]: IP 黑名单过滤
+//! - [`torrent_creator`]: 种子制作
+//! - [`metainfo`]: 种子元数据解析
+//! - [`magnet`]: 磁力链接解析
+//! - [`tools`]: 外部工具（yt-dlp、ffmpeg）查找与更新
+//! - [`vault`]: 站点凭据保险库
 //! - [`error`]: 统一错误类型
 
+pub mod bencode;
 pub mod config;
 pub mod error;
 pub mod event;
+pub mod extractor;
+pub mod ipfilter;
+pub mod magnet;
 pub mod manager;
+pub mod metainfo;
 pub mod protocol;
 pub mod task;
+pub mod tools;
+pub mod torrent_creator;
 pub mod trackers;
+pub mod vault;
 
 // 重新导出常用类型，方便外部使用
 pub use config::ManagerConfig;
 pub use error::{NebulaError, Result};
-pub use event::{DownloadEvent, Progress};
+pub use event::{DownloadEvent, Progress, StallDiagnostics, StallPhase, VideoStage};
+pub use magnet::MagnetLink;
 pub use manager::DownloadManager;
-pub use task::{DownloadSource, DownloadTask, TaskId, TaskStatus};
+pub use metainfo::{TorrentFile, TorrentMetainfo};
+pub use task::{
+    AddTaskOptions, AudioExtraction, AudioFormat, DownloadSource, DownloadTask, DuplicatePolicy,
+    FormatPreference, PlaylistOptions, TaskId, TaskStatus, VideoCodec, VideoContainer,
+    VideoOptions,
+};
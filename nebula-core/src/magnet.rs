@@ -0,0 +1,305 @@
+//! 磁力链接解析模块
+//!
+//! 将 `magnet:?` 链接解析为结构化的 [`MagnetLink`]，支持：
+//! - v1 info hash（`urn:btih:`，十六进制或 Base32）
+//! - v2 info hash（`urn:btmh:`，SHA-256 multihash）
+//! - 显示名称 `dn`、Tracker `tr`、Web Seed `ws`、Peer 地址 `x.pe`、
+//!   精确大小 `xl`、文件选择 `so` (BEP 53)
+
+use crate::error::{NebulaError, Result};
+
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+/// `so` 参数展开后允许的最大文件数量
+const MAX_SELECT_ONLY: usize = 65536;
+
+/// SHA-256 multihash 前缀（哈希类型 0x12，长度 0x20）
+const SHA256_MULTIHASH_PREFIX: &str = "1220";
+
+/// 解析后的磁力链接
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MagnetLink {
+    /// v1 info hash (SHA-1)
+    pub info_hash: Option<[u8; 20]>,
+    /// v2 info hash (SHA-256)
+    pub info_hash_v2: Option<[u8; 32]>,
+    /// 显示名称（已 URL 解码）
+    pub display_name: Option<String>,
+    /// Tracker 列表
+    pub trackers: Vec<String>,
+    /// Web Seed 地址列表
+    pub web_seeds: Vec<String>,
+    /// Peer 地址提示（`host:port`）
+    pub peers: Vec<String>,
+    /// 内容精确大小（字节）
+    pub exact_length: Option<u64>,
+    /// 只下载的文件序号 (BEP 53)
+    pub select_only: Vec<usize>,
+}
+
+impl MagnetLink {
+    /// 解析磁力链接
+    ///
+    /// 至少需要一个 `urn:btih:` 或 `urn:btmh:` 类型的 `xt` 参数，
+    /// 其他不认识的参数会被忽略
+    pub fn parse(uri: &str) -> Result<Self> {
+        let invalid = |reason: &str| NebulaError::InvalidMagnet(reason.to_string());
+
+        let url = url::Url::parse(uri.trim()).map_err(|e| invalid(&e.to_string()))?;
+        if url.scheme() != "magnet" {
+            return Err(invalid("不是 magnet: 链接"));
+        }
+
+        let mut link = Self::default();
+        for (key, value) in url.query_pairs() {
+            let value = value.trim();
+            match key.as_ref() {
+                // 多个同类参数可以写成 xt.1、tr.2 等形式
+                k if k == "xt" || k.starts_with("xt.") => link.parse_exact_topic(value)?,
+                "dn" if !value.is_empty() => link.display_name = Some(value.to_string()),
+                k if k == "tr" || k.starts_with("tr.") => push_unique(&mut link.trackers, value),
+                k if k == "ws" || k.starts_with("ws.") => push_unique(&mut link.web_seeds, value),
+                "x.pe" => push_unique(&mut link.peers, value),
+                "xl" => {
+                    link.exact_length = Some(value.parse().map_err(|_| invalid("xl 参数无效"))?)
+                }
+                "so" => link.select_only = parse_select_only(value)?,
+                _ => {}
+            }
+        }
+
+        if link.info_hash.is_none() && link.info_hash_v2.is_none() {
+            return Err(invalid(
+                "缺少 BitTorrent info hash (xt=urn:btih 或 urn:btmh)",
+            ));
+        }
+        Ok(link)
+    }
+
+    /// 解析 `xt` 参数
+    fn parse_exact_topic(&mut self, value: &str) -> Result<()> {
+        let lower = value.to_ascii_lowercase();
+        if let Some(hash) = lower.strip_prefix("urn:btih:") {
+            let bytes = match hash.len() {
+                40 => decode_hex(hash),
+                32 => decode_base32(hash),
+                _ => None,
+            };
+            let bytes = bytes
+                .ok_or_else(|| NebulaError::InvalidMagnet(format!("btih 格式无效: {}", hash)))?;
+            self.info_hash = Some(bytes.try_into().expect("btih 长度已检查"));
+        } else if let Some(hash) = lower.strip_prefix("urn:btmh:") {
+            let bytes = hash
+                .strip_prefix(SHA256_MULTIHASH_PREFIX)
+                .filter(|digest| digest.len() == 64)
+                .and_then(decode_hex)
+                .ok_or_else(|| NebulaError::InvalidMagnet(format!("btmh 格式无效: {}", hash)))?;
+            self.info_hash_v2 = Some(bytes.try_into().expect("btmh 长度已检查"));
+        }
+        Ok(())
+    }
+
+    /// 用于识别同一种子的 info hash 字符串（优先 v1）
+    pub fn info_hash_hex(&self) -> String {
+        match (&self.info_hash, &self.info_hash_v2) {
+            (Some(v1), _) => encode_hex(v1),
+            (None, Some(v2)) => encode_hex(v2),
+            (None, None) => String::new(),
+        }
+    }
+
+    /// 可以直接连接的 Peer 地址（无法解析的地址会被跳过）
+    ///
+    /// 主机名通过异步 DNS 查询并发解析，不会阻塞运行时
+    pub async fn peer_addrs(&self) -> Vec<SocketAddr> {
+        let lookups = self.peers.iter().map(|peer| async move {
+            tokio::net::lookup_host(peer.as_str()).await.ok()?.next()
+        });
+        futures::future::join_all(lookups)
+            .await
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// 重新生成磁力链接
+    ///
+    /// `with_trackers` 为 false 时不包含 Tracker，由调用方另行指定
+    pub fn to_uri(&self, with_trackers: bool) -> String {
+        let encode =
+            |s: &str| url::form_urlencoded::byte_serialize(s.as_bytes()).collect::<String>();
+
+        let mut params = Vec::new();
+        if let Some(hash) = &self.info_hash {
+            params.push(format!("xt=urn:btih:{}", encode_hex(hash)));
+        }
+        if let Some(hash) = &self.info_hash_v2 {
+            params.push(format!(
+                "xt=urn:btmh:{}{}",
+                SHA256_MULTIHASH_PREFIX,
+                encode_hex(hash)
+            ));
+        }
+        if let Some(name) = &self.display_name {
+            params.push(format!("dn={}", encode(name)));
+        }
+        if let Some(length) = self.exact_length {
+            params.push(format!("xl={}", length));
+        }
+        if with_trackers {
+            params.extend(self.trackers.iter().map(|t| format!("tr={}", encode(t))));
+        }
+        params.extend(self.web_seeds.iter().map(|s| format!("ws={}", encode(s))));
+        params.extend(self.peers.iter().map(|p| format!("x.pe={}", encode(p))));
+        format!("magnet:?{}", params.join("&"))
+    }
+}
+
+impl FromStr for MagnetLink {
+    type Err = NebulaError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+fn push_unique(list: &mut Vec<String>, value: &str) {
+    if !value.is_empty() && !list.iter().any(|v| v == value) {
+        list.push(value.to_string());
+    }
+}
+
+/// 解析 `so` 参数，如 `0,2,4-6`
+fn parse_select_only(value: &str) -> Result<Vec<usize>> {
+    let invalid = || NebulaError::InvalidMagnet(format!("so 参数无效: {}", value));
+
+    let mut indices = Vec::new();
+    for part in value.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (start, end) = match part.split_once('-') {
+            Some((start, end)) => (start.parse(), end.parse()),
+            None => (part.parse(), part.parse()),
+        };
+        let (start, end): (usize, usize) =
+            (start.map_err(|_| invalid())?, end.map_err(|_| invalid())?);
+        if start > end || indices.len() + (end - start) >= MAX_SELECT_ONLY {
+            return Err(invalid());
+        }
+        indices.extend(start..=end);
+    }
+    indices.sort_unstable();
+    indices.dedup();
+    Ok(indices)
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) || !s.is_ascii() {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// RFC 4648 Base32 解码（无填充）
+fn decode_base32(s: &str) -> Option<Vec<u8>> {
+    let mut bits = 0u64;
+    let mut bit_count = 0u32;
+    let mut out = Vec::with_capacity(s.len() * 5 / 8);
+    for c in s.bytes() {
+        let value = match c.to_ascii_uppercase() {
+            c @ b'A'..=b'Z' => c - b'A',
+            c @ b'2'..=b'7' => c - b'2' + 26,
+            _ => return None,
+        };
+        bits = (bits << 5) | value as u64;
+        bit_count += 5;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            out.push((bits >> bit_count) as u8);
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH_HEX: &str = "c12fe1c06bba254a9dc9f519b335aa7c1367a88a";
+    const HASH_BASE32: &str = "YEX6DQDLXISUVHOJ6UM3GNNKPQJWPKEK";
+
+    #[tokio::test]
+    async fn test_parse_full() {
+        let uri = format!(
+            "magnet:?xt=urn:btih:{}&dn=%E6%B5%8B%E8%AF%95+file&xl=1024\
+             &tr=udp%3A%2F%2Ft1%3A80%2Fannounce&tr.1=http://t2/announce&tr=udp%3A%2F%2Ft1%3A80%2Fannounce\
+             &ws=http%3A%2F%2Fseed%2Ffile&x.pe=127.0.0.1:6881&so=0,2,4-6",
+            HASH_HEX
+        );
+        let link = MagnetLink::parse(&uri).unwrap();
+        assert_eq!(link.info_hash_hex(), HASH_HEX);
+        assert_eq!(link.display_name.as_deref(), Some("测试 file"));
+        assert_eq!(link.exact_length, Some(1024));
+        assert_eq!(
+            link.trackers,
+            vec!["udp://t1:80/announce", "http://t2/announce"]
+        );
+        assert_eq!(link.web_seeds, vec!["http://seed/file"]);
+        assert_eq!(link.peer_addrs().await, vec!["127.0.0.1:6881".parse().unwrap()]);
+        assert_eq!(link.select_only, vec![0, 2, 4, 5, 6]);
+
+        let rebuilt = MagnetLink::parse(&link.to_uri(true)).unwrap();
+        assert_eq!(rebuilt.select_only, Vec::<usize>::new());
+        assert_eq!(rebuilt.trackers, link.trackers);
+        assert!(MagnetLink::parse(&link.to_uri(false))
+            .unwrap()
+            .trackers
+            .is_empty());
+    }
+
+    #[test]
+    fn test_parse_hash_formats() {
+        let hex = MagnetLink::parse(&format!("magnet:?xt=urn:btih:{}", HASH_HEX)).unwrap();
+        let base32 = MagnetLink::parse(&format!("magnet:?xt=urn:btih:{}", HASH_BASE32)).unwrap();
+        assert_eq!(hex.info_hash, base32.info_hash);
+
+        let v2_hex = "a".repeat(64);
+        let v2 = MagnetLink::parse(&format!("magnet:?xt=urn:btmh:1220{}", v2_hex)).unwrap();
+        assert_eq!(v2.info_hash, None);
+        assert_eq!(v2.info_hash_hex(), v2_hex);
+
+        let hybrid = MagnetLink::parse(&format!(
+            "magnet:?xt=urn:btih:{}&xt=urn:btmh:1220{}",
+            HASH_HEX, v2_hex
+        ))
+        .unwrap();
+        assert_eq!(hybrid.info_hash_hex(), HASH_HEX);
+        assert!(hybrid.info_hash_v2.is_some());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for uri in [
+            "http://example.com/?xt=urn:btih:c12fe1c06bba254a9dc9f519b335aa7c1367a88a",
+            "magnet:?dn=name",
+            "magnet:?xt=urn:btih:abc123",
+            "magnet:?xt=urn:btih:z12fe1c06bba254a9dc9f519b335aa7c1367a88a",
+            "magnet:?xt=urn:btmh:1114aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "magnet:?xt=urn:btih:c12fe1c06bba254a9dc9f519b335aa7c1367a88a&so=5-2",
+            "magnet:?xt=urn:btih:c12fe1c06bba254a9dc9f519b335aa7c1367a88a&xl=abc",
+        ] {
+            assert!(
+                matches!(MagnetLink::parse(uri), Err(NebulaError::InvalidMagnet(_))),
+                "{}",
+                uri
+            );
+        }
+    }
+}
@@ -2,25 +2,42 @@
 //!
 //! 统一管理所有下载任务，提供高层 API 供上层应用调用。
 
-use crate::config::ManagerConfig;
+use crate::config::{KeySource, ManagerConfig};
 use crate::error::{NebulaError, Result};
 use crate::event::{DownloadEvent, Progress};
-use crate::protocol::bilibili::BilibiliAuth;
+use crate::extractor::ExtractorRegistry;
+use crate::ipfilter::IpFilterStats;
+use crate::magnet::MagnetLink;
+use crate::metainfo::TorrentMetainfo;
+use crate::protocol::bilibili::{BilibiliAccount, BilibiliAuth, SessionStatus};
+use crate::protocol::ftp::FtpHandler;
 use crate::protocol::http::HttpHandler;
-use crate::protocol::torrent::TorrentHandler;
-use crate::protocol::video::VideoHandler;
+use crate::protocol::torrent::{PeerInfo, TorrentHandler, TorrentTracker};
+use crate::protocol::video::{
+    parse_playlist_items, PlaylistInfo, VideoArchive, VideoHandler, VideoInfo,
+};
 use crate::protocol::ProtocolHandler;
-use crate::task::{DownloadSource, DownloadTask, TaskId, TaskStatus};
+use crate::task::{
+    AddTaskOptions, DownloadSource, DownloadTask, DuplicatePolicy, PlaylistOptions, TaskId,
+    TaskStatus, VideoOptions,
+};
+use crate::tools::{Tool, ToolInfo, ToolManager, ToolUpdate};
+use crate::torrent_creator::{self, CreatedTorrent, TorrentCreateOptions};
+use crate::vault::{self, CredentialVault, FileKeyStore, VaultKey};
 
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tokio::sync::{broadcast, RwLock};
+use tokio::sync::{broadcast, mpsc, RwLock};
 use tracing::{error, info, warn};
 
 /// 事件通道容量
 const EVENT_CHANNEL_CAPACITY: usize = 1024;
 
+/// 检查 Bilibili 登录状态（并在需要时刷新 Cookie）的间隔
+const BILIBILI_SESSION_CHECK_INTERVAL: std::time::Duration =
+    std::time::Duration::from_secs(12 * 60 * 60);
+
 /// 下载管理器
 ///
 /// 核心入口，管理所有下载任务的生命周期
@@ -60,14 +77,37 @@ pub struct DownloadManager {
     /// HTTP 下载处理器
     http_handler: Arc<HttpHandler>,
 
+    /// FTP 下载处理器
+    ftp_handler: Arc<FtpHandler>,
+
     /// BitTorrent 下载处理器 (可选，初始化失败时为 None)
     torrent_handler: Option<Arc<TorrentHandler>>,
 
-    /// Bilibili 认证管理器 (用于高码率下载)
+    /// 视频下载处理器 (未找到 yt-dlp 时视频相关操作返回错误)
+    video_handler: Arc<VideoHandler>,
+
+    /// 外部工具管理器
+    tools: Arc<ToolManager>,
+
+    /// Bilibili 认证管理器
     bilibili_auth: Arc<BilibiliAuth>,
 
-    /// 事件广播发送端
-    event_tx: broadcast::Sender<DownloadEvent>,
+    /// 站点凭据保险库
+    credential_vault: Arc<CredentialVault>,
+
+    /// 视频站点规则
+    extractors: ExtractorRegistry,
+
+    /// 已下载视频记录，用于播放列表只下载新条目
+    video_archive: Arc<VideoArchive>,
+
+    /// 事件上报发送端：所有事件先经事件消费协程合并到任务表，再广播给订阅者
+    ///
+    /// 使用无界队列，订阅者滞后时任务状态也不会丢失事件
+    event_tx: mpsc::UnboundedSender<DownloadEvent>,
+
+    /// 事件广播发送端（订阅者使用）
+    subscribers: broadcast::Sender<DownloadEvent>,
 }
 
 impl DownloadManager {
@@ -87,15 +127,44 @@ impl DownloadManager {
             info!("已创建下载目录: {:?}", config.download_dir);
         }
 
+        let data_dir = config.download_dir.join(".nebula");
+
+        // 打开站点凭据保险库（口令加密时保持锁定，等待 unlock_credentials）
+        let credential_vault = Arc::new(CredentialVault::new(&data_dir));
+        let vault_key = match config.credentials.key_source {
+            KeySource::Keyring => Some(VaultKey::Store(vault::default_key_store(&data_dir, "vault"))),
+            KeySource::File => Some(VaultKey::Store(Arc::new(FileKeyStore::new(
+                data_dir.join("vault_key.bin"),
+            )))),
+            KeySource::Passphrase => None,
+        };
+        if let Some(key) = vault_key {
+            if let Err(e) = credential_vault.unlock(key).await {
+                warn!("凭据保险库解锁失败，下载时不会注入站点凭据: {}", e);
+            }
+        }
+
         // 创建 HTTP 处理器
-        let http_handler = Arc::new(HttpHandler::new(config.http.clone())?);
+        let mut http_handler = HttpHandler::new(config.http.clone())?;
+        if config.credentials.enabled {
+            http_handler = http_handler.with_credential_vault(Arc::clone(&credential_vault));
+        }
+        let http_handler = Arc::new(http_handler);
+
+        // 创建 FTP 处理器
+        let mut ftp_handler = FtpHandler::new(&config.http);
+        if config.credentials.enabled {
+            ftp_handler = ftp_handler.with_credential_vault(Arc::clone(&credential_vault));
+        }
+        let ftp_handler = Arc::new(ftp_handler);
 
         // 创建 BitTorrent 处理器 (可选)
-        let data_dir = config.download_dir.join(".nebula");
         let torrent_handler = match TorrentHandler::new(config.torrent.clone(), data_dir.clone()).await {
             Ok(handler) => {
                 info!("BitTorrent 处理器初始化成功");
-                Some(Arc::new(handler))
+                let handler = Arc::new(handler);
+                handler.spawn_ip_filter_updater();
+                Some(handler)
             }
             Err(e) => {
                 warn!("BitTorrent 处理器初始化失败，磁力链接下载将不可用: {}", e);
@@ -103,35 +172,120 @@ impl DownloadManager {
             }
         };
 
+        let video_archive = Arc::new(VideoArchive::load(data_dir.join("video_archive.txt")));
+
+        let tools = Arc::new(ToolManager::new(config.tools.clone(), data_dir.clone()));
+        let extractors = ExtractorRegistry::new(&config.video);
+
         // 创建 Bilibili 认证管理器
         let bilibili_auth = Arc::new(BilibiliAuth::new(data_dir));
 
+        // 创建视频处理器
+        let mut video_handler = VideoHandler::new(config.download_dir.clone(), &tools)
+            .with_bilibili_auth(Arc::clone(&bilibili_auth))
+            .with_http_handler(Arc::clone(&http_handler))
+            .with_native_bilibili(config.video.native_bilibili)
+            .with_output_template(config.video.output_template.clone());
+        if config.credentials.enabled {
+            video_handler = video_handler.with_credential_vault(Arc::clone(&credential_vault));
+        }
+        let video_handler = Arc::new(video_handler);
+
         // 创建事件通道
-        let (event_tx, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let (subscribers, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
 
         info!("下载管理器初始化完成");
 
-        Ok(Self {
+        let manager = Self {
             config,
             tasks: Arc::new(RwLock::new(HashMap::new())),
             http_handler,
+            ftp_handler,
             torrent_handler,
+            video_handler,
+            tools,
             bilibili_auth,
+            credential_vault,
+            extractors,
+            video_archive,
             event_tx,
-        })
+            subscribers,
+        };
+        manager.spawn_event_consumer(event_rx);
+        manager.spawn_bilibili_session_check();
+        Ok(manager)
+    }
+
+    /// 启动事件消费协程，将上报的所有事件合并到任务表，然后广播给订阅者
+    ///
+    /// 任务的状态、进度和时间戳以此为准，`get_task`/`list_tasks` 直接读取任务表。
+    /// 播放列表条目变化时同时汇总父任务，视频下载完成时写入已下载记录
+    fn spawn_event_consumer(&self, mut receiver: mpsc::UnboundedReceiver<DownloadEvent>) {
+        let tasks = Arc::clone(&self.tasks);
+        let video_archive = Arc::clone(&self.video_archive);
+        let subscribers = self.subscribers.clone();
+
+        tokio::spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                let (changed, archive_key) = {
+                    let mut tasks = tasks.write().await;
+                    let Some(task) = event.task_id().and_then(|id| tasks.get_mut(&id)) else {
+                        let _ = subscribers.send(event);
+                        continue;
+                    };
+                    let mut changed: Vec<_> = task.apply_event(&event).into_iter().collect();
+
+                    let completed = changed.iter().any(|e| {
+                        matches!(e, DownloadEvent::StatusChanged { to: TaskStatus::Completed, .. })
+                    });
+                    let archive_key = match task.source {
+                        DownloadSource::Video { .. } if completed => task.duplicate_key(),
+                        _ => None,
+                    };
+
+                    if let Some(parent_id) = task.parent_id {
+                        changed.extend(refresh_parent(&mut tasks, parent_id));
+                        if matches!(event, DownloadEvent::ProgressUpdated { .. }) {
+                            if let Some(parent) = tasks.get(&parent_id) {
+                                changed.push(DownloadEvent::ProgressUpdated {
+                                    task_id: parent_id,
+                                    progress: parent.progress.clone(),
+                                });
+                            }
+                        }
+                    }
+                    (changed, archive_key)
+                };
+
+                let _ = subscribers.send(event);
+                for changed in changed {
+                    let _ = subscribers.send(changed);
+                }
+                if let Some(key) = archive_key {
+                    if let Err(e) = video_archive.insert(&key).await {
+                        warn!("写入已下载视频记录失败: {}", e);
+                    }
+                }
+            }
+        });
     }
 
-    /// 添加视频下载任务（支持指定画质）
+    /// 添加视频下载任务（支持指定画质和字幕、弹幕等附加内容）
     pub async fn add_video_task(
         &self,
         url: &str,
         format_id: Option<String>,
         save_path: PathBuf,
+        title: Option<String>,
+        thumbnail: Option<String>,
+        options: VideoOptions,
     ) -> Result<TaskId> {
         // 强制使用 Video 来源
         let download_source = DownloadSource::Video {
             url: url.to_string(),
             format_id,
+            options,
         };
         let protocol_name = download_source.protocol_name();
 
@@ -145,19 +299,26 @@ impl DownloadManager {
         };
 
         // 创建任务
-        let task = DownloadTask::new(download_source.clone(), actual_save_path.clone());
+        let mut task = DownloadTask::new(download_source.clone(), actual_save_path.clone());
+        if let Some(title) = title.filter(|t| !t.trim().is_empty()) {
+            task = task.with_name(title);
+        }
+        task.thumbnail = thumbnail;
         let task_id = task.id;
 
         // 注册任务
+        if let Some(existing) = self
+            .register_task(&task, self.config.duplicate_policy)
+            .await?
         {
-            let mut tasks = self.tasks.write().await;
-            tasks.insert(task_id, task.clone());
+            return Ok(existing);
         }
 
         // 发送任务添加事件
         let _ = self.event_tx.send(DownloadEvent::TaskAdded {
             task_id,
             name: task.name.clone(),
+            thumbnail: task.thumbnail.clone(),
         });
 
         // 根据协议类型选择处理器并开始下载
@@ -165,17 +326,177 @@ impl DownloadManager {
         // 实际上，与其复制代码，不如让 add_task 内部逻辑复用。
         // 但为了简单，还是直接调用 start_download_handler 逻辑。
         // 让我们看看 add_task 是怎么实现的。
-        self.start_download(task_id, download_source, actual_save_path).await?;
+        self.start_download(task_id, download_source, actual_save_path, None)
+            .await?;
 
         Ok(task_id)
     }
 
+    /// 识别来源类型
+    ///
+    /// 视频网站按配置的站点规则识别；启用 `probe_unknown_urls` 时，
+    /// 规则无法识别的 HTTP 链接再询问 yt-dlp
+    async fn detect_source(&self, source: &str) -> DownloadSource {
+        let detected = DownloadSource::detect_with(source, &self.extractors);
+        let DownloadSource::Http { url } = &detected else {
+            return detected;
+        };
+        if !self.config.video.probe_unknown_urls || !self.video_handler.is_available() {
+            return detected;
+        }
+
+        match self.video_handler.probe_extractor(url).await {
+            Ok(Some(extractor)) => {
+                info!("yt-dlp 提取器 {} 可处理链接: {}", extractor, url);
+                DownloadSource::Video {
+                    url: url.clone(),
+                    format_id: None,
+                    options: VideoOptions::default(),
+                }
+            }
+            Ok(None) => detected,
+            Err(e) => {
+                warn!("询问 yt-dlp 提取器失败，按普通链接下载: {}", e);
+                detected
+            }
+        }
+    }
+
+    /// 检查链接是否属于配置的视频网站
+    pub fn is_video_url(&self, url: &str) -> bool {
+        self.extractors.is_video_url(url)
+    }
+
+    /// 询问 yt-dlp 由哪个提取器处理该链接，参见 [`VideoHandler::probe_extractor`]
+    pub async fn probe_extractor(&self, url: &str) -> Result<Option<String>> {
+        self.video_handler()?.probe_extractor(url).await
+    }
+
+    /// 获取视频信息和可用格式
+    pub async fn get_video_info(&self, url: &str) -> Result<VideoInfo> {
+        self.video_handler_for(url)?.get_video_info(url).await
+    }
+
+    /// 展开播放列表、频道或多 P 视频，列出其中的条目
+    pub async fn expand_playlist(&self, url: &str) -> Result<PlaylistInfo> {
+        self.video_handler()?.get_playlist_info(url).await
+    }
+
+    /// 添加播放列表下载任务
+    ///
+    /// 创建一个父任务，每个选中的条目作为一个子任务依次下载。
+    /// 已经在任务列表中的条目不会重复添加；`only_new` 时跳过以前下载过的条目
+    pub async fn add_playlist_task(
+        &self,
+        url: &str,
+        save_path: PathBuf,
+        options: PlaylistOptions,
+    ) -> Result<TaskId> {
+        let playlist = self.expand_playlist(url).await?;
+        info!(
+            "添加播放列表任务: {} ({} 个条目)",
+            playlist.title,
+            playlist.entries.len()
+        );
+
+        let save_path = if save_path.as_os_str().is_empty() {
+            self.config.download_dir.clone()
+        } else {
+            save_path
+        };
+
+        let mut entries = playlist.entries;
+        if let Some(items) = &options.items {
+            let selected = parse_playlist_items(items, entries.len())?;
+            entries = entries
+                .into_iter()
+                .enumerate()
+                .filter(|(i, _)| selected.binary_search(i).is_ok())
+                .map(|(_, entry)| entry)
+                .collect();
+        }
+
+        // 创建父任务
+        let source = DownloadSource::Playlist {
+            url: url.to_string(),
+            format_id: options.format_id.clone(),
+        };
+        let mut parent = DownloadTask::new(source, save_path.clone()).with_name(playlist.title);
+        let parent_id = parent.id;
+
+        // 创建子任务，跳过已下载过或已在列表中的条目
+        let mut children = Vec::new();
+        for entry in entries {
+            let source = DownloadSource::Video {
+                url: entry.url,
+                format_id: options.format_id.clone(),
+                options: options.video.clone(),
+            };
+            let mut child = DownloadTask::new(source, save_path.clone()).with_name(entry.title);
+            child.parent_id = Some(parent_id);
+
+            if options.only_new {
+                if let Some(key) = child.duplicate_key() {
+                    if self.video_archive.contains(&key).await {
+                        continue;
+                    }
+                }
+            }
+            if self.tasks.read().await.values().any(|t| {
+                t.status != TaskStatus::Cancelled && child.is_duplicate_of(t)
+            }) {
+                continue;
+            }
+            children.push(child);
+        }
+
+        if children.is_empty() {
+            return Err(NebulaError::InvalidConfig("播放列表中没有需要下载的条目".to_string()));
+        }
+
+        parent.children = children.iter().map(|c| c.id).collect();
+        if let Some(existing) = self
+            .register_task(&parent, self.config.duplicate_policy)
+            .await?
+        {
+            return Ok(existing);
+        }
+
+        {
+            let mut tasks = self.tasks.write().await;
+            for child in &children {
+                tasks.insert(child.id, child.clone());
+            }
+        }
+
+        let _ = self.event_tx.send(DownloadEvent::TaskAdded {
+            task_id: parent_id,
+            name: parent.name.clone(),
+            thumbnail: None,
+        });
+        for child in &children {
+            let _ = self.event_tx.send(DownloadEvent::TaskAdded {
+                task_id: child.id,
+                name: child.name.clone(),
+                thumbnail: None,
+            });
+        }
+
+        self.start_download(parent_id, parent.source, save_path, None)
+            .await?;
+
+        Ok(parent_id)
+    }
+
     /// 启动下载处理逻辑 (内部辅助函数)
+    ///
+    /// `only_files` 仅对 BitTorrent 任务有效
     async fn start_download(
         &self,
         task_id: TaskId,
         download_source: DownloadSource,
         actual_save_path: PathBuf,
+        only_files: Option<Vec<usize>>,
     ) -> Result<()> {
         let event_tx = self.event_tx.clone();
         let tasks = Arc::clone(&self.tasks);
@@ -184,32 +505,31 @@ impl DownloadManager {
             DownloadSource::Http { .. } => {
                 let handler = Arc::clone(&self.http_handler);
                 tokio::spawn(async move {
-                    // 更新任务状态
+                    // 执行下载，处理器获取文件信息后发送 TaskStarted
+                    if let Err(e) = handler
+                        .start(task_id, &download_source, actual_save_path, event_tx.clone())
+                        .await
                     {
-                        let mut tasks_guard = tasks.write().await;
-                        if let Some(task) = tasks_guard.get_mut(&task_id) {
-                            task.status = TaskStatus::Downloading;
-                        }
+                        error!("HTTP 下载失败: {}", e);
+                        let _ = event_tx.send(DownloadEvent::TaskFailed {
+                            task_id,
+                            error: e.to_string(),
+                        });
                     }
-
-                    // 执行下载
+                });
+            }
+            DownloadSource::Ftp { .. } => {
+                let handler = Arc::clone(&self.ftp_handler);
+                tokio::spawn(async move {
                     if let Err(e) = handler
                         .start(task_id, &download_source, actual_save_path, event_tx.clone())
                         .await
                     {
-                        error!("HTTP 下载失败: {}", e);
+                        error!("FTP 下载失败: {}", e);
                         let _ = event_tx.send(DownloadEvent::TaskFailed {
                             task_id,
                             error: e.to_string(),
                         });
-                        
-                        let mut tasks_guard = tasks.write().await;
-                        if let Some(task) = tasks_guard.get_mut(&task_id) {
-                            task.status = TaskStatus::Failed {
-                                error: e.to_string(),
-                                retry_count: 0
-                            };
-                        }
                     }
                 });
             }
@@ -222,100 +542,74 @@ impl DownloadManager {
                         ));
                     }
                 };
+                let http_handler = Arc::clone(&self.http_handler);
                 tokio::spawn(async move {
-                    // 更新任务状态
-                    {
-                        let mut tasks_guard = tasks.write().await;
-                        if let Some(task) = tasks_guard.get_mut(&task_id) {
-                            task.status = TaskStatus::FetchingMetadata;
+                    // 导入已有数据的任务保持校验状态
+                    let checking = tasks
+                        .read()
+                        .await
+                        .get(&task_id)
+                        .is_some_and(|task| task.status == TaskStatus::Checking);
+                    if !checking {
+                        if let Err(e) = set_task_status(
+                            &tasks,
+                            &event_tx,
+                            task_id,
+                            TaskStatus::FetchingMetadata,
+                        )
+                        .await
+                        {
+                            warn!("任务未能开始: {}", e);
+                            return;
                         }
                     }
 
-                    // 执行下载
-                    if let Err(e) = handler
-                        .start(task_id, &download_source, actual_save_path, event_tx.clone())
-                        .await
-                    {
+                    // 执行下载，随后监控停滞并在 Peer 不足时使用 Web Seed
+                    let result = async {
+                        handler
+                            .start_with_files(
+                                task_id,
+                                &download_source,
+                                actual_save_path,
+                                only_files,
+                                event_tx.clone(),
+                            )
+                            .await?;
+                        let (_, stall) = tokio::join!(
+                            handler.run_web_seeds(task_id, http_handler),
+                            handler.watch_stall(task_id)
+                        );
+                        stall
+                    }
+                    .await;
+
+                    if let Err(e) = result {
                         error!("BitTorrent 下载失败: {}", e);
                         let _ = event_tx.send(DownloadEvent::TaskFailed {
                             task_id,
                             error: e.to_string(),
                         });
-
-                        // 更新任务状态
-                        let mut tasks_guard = tasks.write().await;
-                        if let Some(task) = tasks_guard.get_mut(&task_id) {
-                            task.status = TaskStatus::Failed {
-                                error: e.to_string(),
-                                retry_count: 0
-                            };
-                        }
                     }
                 });
             }
-            DownloadSource::Video {
-                url,
-                format_id,
-            } => {
-                // 创建临时的 VideoHandler
-                let handler = VideoHandler::new(actual_save_path.clone())?;
-                let url = url.clone();
-                let format_id = format_id.clone();
-                let event_tx_clone = event_tx.clone();
-                let bilibili_auth = Arc::clone(&self.bilibili_auth);
-                
+            DownloadSource::Video { url, .. } => {
+                let handler = Arc::clone(self.video_handler_for(url)?);
                 tokio::spawn(async move {
-                     // 更新任务状态
-                    {
-                        let mut tasks_guard = tasks.write().await;
-                        if let Some(task) = tasks_guard.get_mut(&task_id) {
-                            task.status = TaskStatus::Downloading;
-                        }
-                    }
-                    
-                    // 导出 Bilibili cookies (如果已登录)
-                    let cookies_path = bilibili_auth
-                        .export_cookies_for_ytdlp()
+                    if let Err(e) = handler
+                        .start(task_id, &download_source, actual_save_path, event_tx.clone())
                         .await
-                        .ok()
-                        .flatten();
-                    
-                    // 创建 mpsc channel 适配 VideoHandler
-                    let (tx, mut rx) = tokio::sync::mpsc::channel(100);
-                    
-                    // 转发事件
-                    let event_tx_clone_2 = event_tx_clone.clone();
-                    tokio::spawn(async move {
-                        while let Some(event) = rx.recv().await {
-                            let _ = event_tx_clone_2.send(event);
-                        }
-                    });
-
-                    if let Err(e) = handler.download_video(
-                        &url,
-                        format_id.as_deref(),
-                        cookies_path.as_ref(),
-                        tx,
-                        task_id
-                    ).await {
+                    {
                         error!("视频下载失败: {}", e);
-                         let _ = event_tx_clone.send(DownloadEvent::TaskFailed {
+                        let _ = event_tx.send(DownloadEvent::TaskFailed {
                             task_id,
                             error: e.to_string(),
                         });
-                        
-                        let mut tasks_guard = tasks.write().await;
-                        if let Some(task) = tasks_guard.get_mut(&task_id) {
-                            task.status = TaskStatus::Failed {
-                                error: e.to_string(),
-                                retry_count: 0
-                            };
-                        }
                     }
                 });
             }
-            _ => {
-                warn!("未实现的协议: {}", download_source.protocol_name());
+            DownloadSource::Playlist { .. } => {
+                let handler = Arc::clone(self.video_handler()?);
+                tokio::spawn(run_playlist(tasks, handler, event_tx, task_id));
             }
         }
         
@@ -333,40 +627,230 @@ impl DownloadManager {
     /// # 返回
     /// 新创建的任务 ID
     pub async fn add_task(&self, source: &str, save_path: PathBuf) -> Result<TaskId> {
+        self.add_task_with_options(source, save_path, AddTaskOptions::default())
+            .await
+    }
+
+    /// 添加下载任务（带可选参数）
+    ///
+    /// # 参数
+    /// - `source`: 下载来源（URL、磁力链接或 torrent 文件路径）
+    /// - `save_path`: 保存路径（目录或文件路径）
+    /// - `options`: 可选参数，如导入已有数据、只下载部分文件
+    ///
+    /// # 返回
+    /// 新创建的任务 ID
+    pub async fn add_task_with_options(
+        &self,
+        source: &str,
+        save_path: PathBuf,
+        options: AddTaskOptions,
+    ) -> Result<TaskId> {
         // 自动识别来源类型
-        let download_source = DownloadSource::detect(source);
+        let download_source = self.detect_source(source).await;
         let protocol_name = download_source.protocol_name();
 
         info!("添加下载任务: {} (协议: {})", source, protocol_name);
 
-        // 确定实际保存路径
-        let actual_save_path = if save_path.as_os_str().is_empty() {
-            self.config.download_dir.clone()
-        } else {
-            save_path
+        let is_torrent = matches!(
+            download_source,
+            DownloadSource::Magnet { .. } | DownloadSource::Torrent { .. }
+        );
+        if options.only_files.is_some() && !is_torrent {
+            return Err(NebulaError::UnsupportedProtocol(format!(
+                "{} 任务不支持选择文件",
+                protocol_name
+            )));
+        }
+
+        // 磁力链接无效时直接拒绝；未指定文件时使用链接中的 so 参数
+        let mut only_files = options.only_files;
+        if let DownloadSource::Magnet { uri, .. } = &download_source {
+            let magnet = MagnetLink::parse(uri)?;
+            if only_files.is_none() && !magnet.select_only.is_empty() {
+                only_files = Some(magnet.select_only);
+            }
+        }
+
+        // 确定实际保存路径，导入已有数据时使用数据所在目录
+        let importing = options.existing_data.is_some();
+        let actual_save_path = match options.existing_data {
+            Some(existing) => {
+                if !is_torrent {
+                    return Err(NebulaError::UnsupportedProtocol(format!(
+                        "{} 任务不支持导入已有数据",
+                        protocol_name
+                    )));
+                }
+                if !existing.is_dir() {
+                    return Err(NebulaError::IoError {
+                        path: existing,
+                        message: "已有数据目录不存在".to_string(),
+                    });
+                }
+                info!("导入已有数据: {:?}", existing);
+                existing
+            }
+            None if save_path.as_os_str().is_empty() => self.config.download_dir.clone(),
+            None => save_path,
         };
 
         // 创建任务
-        let task = DownloadTask::new(download_source.clone(), actual_save_path.clone());
+        let mut task = DownloadTask::new(download_source.clone(), actual_save_path.clone());
+        if importing {
+            task.transition(TaskStatus::Checking)?;
+        }
+        task.only_files = only_files.clone();
+        task.info_hash = source_info_hash(&download_source).await?;
         let task_id = task.id;
 
         // 注册任务
-        {
-            let mut tasks = self.tasks.write().await;
-            tasks.insert(task_id, task.clone());
+        let policy = options
+            .duplicate_policy
+            .unwrap_or(self.config.duplicate_policy);
+        if let Some(existing) = self.register_task(&task, policy).await? {
+            return Ok(existing);
         }
 
         // 发送任务添加事件
         let _ = self.event_tx.send(DownloadEvent::TaskAdded {
             task_id,
             name: task.name.clone(),
+            thumbnail: None,
         });
 
-        self.start_download(task_id, download_source, actual_save_path).await?;
-        
+        self.start_download(task_id, download_source, actual_save_path, only_files)
+            .await?;
+
         Ok(task_id)
     }
 
+    /// 注册新任务，并按 `policy` 处理重复任务
+    ///
+    /// # 返回
+    /// 新任务注册成功时返回 None；策略为 [`DuplicatePolicy::ReturnExisting`]
+    /// 且存在重复任务时返回已有任务的 ID
+    async fn register_task(
+        &self,
+        task: &DownloadTask,
+        policy: DuplicatePolicy,
+    ) -> Result<Option<TaskId>> {
+        loop {
+            let existing = {
+                let mut tasks = self.tasks.write().await;
+                let duplicate = tasks
+                    .values()
+                    .find(|t| t.status != TaskStatus::Cancelled && task.is_duplicate_of(t));
+                match duplicate {
+                    Some(existing) => existing.clone(),
+                    None => {
+                        tasks.insert(task.id, task.clone());
+                        return Ok(None);
+                    }
+                }
+            };
+
+            match policy {
+                DuplicatePolicy::Reject => {
+                    return Err(NebulaError::TaskAlreadyExists(format!(
+                        "{} ({})",
+                        existing.name, existing.id
+                    )));
+                }
+                DuplicatePolicy::ReturnExisting => {
+                    info!("任务已存在，返回已有任务: {}", existing.id);
+                    return Ok(Some(existing.id));
+                }
+                DuplicatePolicy::Restart => {
+                    info!("任务已存在，取消后重新开始: {}", existing.id);
+                    if !existing.status.is_finished() {
+                        self.cancel(existing.id, false).await?;
+                    }
+                    // 与手动移除相同：发送 TaskRemoved，播放列表条目一并移除
+                    self.remove_task(existing.id).await?;
+                }
+            }
+        }
+    }
+
+
+    /// 从本地文件或目录制作种子，并写入 `torrent_path`
+    ///
+    /// # 参数
+    /// - `input`: 要分享的文件或目录
+    /// - `torrent_path`: 生成的 .torrent 文件路径
+    /// - `options`: 制作选项
+    pub async fn create_torrent(
+        &self,
+        input: &Path,
+        torrent_path: &Path,
+        options: TorrentCreateOptions,
+    ) -> Result<CreatedTorrent> {
+        info!("制作种子: {:?}", input);
+        let created = torrent_creator::create_torrent(input, options).await?;
+        created.write_to(torrent_path).await?;
+        info!(
+            "种子已生成: {:?} (info hash: {})",
+            torrent_path,
+            created.info_hash_hex()
+        );
+        Ok(created)
+    }
+
+    /// 为本地已有的内容做种
+    ///
+    /// # 参数
+    /// - `torrent_path`: .torrent 文件路径
+    /// - `content`: 种子对应的文件或目录（即制作种子时的输入）
+    ///
+    /// 单文件种子的名称必须与文件名相同（制作时未指定其他名称），
+    /// 否则引擎找不到已有的数据，返回 [`NebulaError::InvalidConfig`]
+    ///
+    /// # 返回
+    /// 新创建的任务 ID
+    pub async fn seed_torrent(&self, torrent_path: PathBuf, content: &Path) -> Result<TaskId> {
+        // 单文件种子的内容位于所在目录下的 <种子名称>，多文件种子的内容位于目录本身
+        let save_path = if content.is_dir() {
+            content.to_path_buf()
+        } else {
+            let meta = TorrentMetainfo::from_bytes(&tokio::fs::read(&torrent_path).await?)?;
+            if !meta.multi_file && content.file_name() != Some(std::ffi::OsStr::new(&meta.name)) {
+                return Err(NebulaError::InvalidConfig(format!(
+                    "种子名称 {} 与文件名不一致，无法做种: {:?}",
+                    meta.name, content
+                )));
+            }
+            content
+                .parent()
+                .map(|p| p.to_path_buf())
+                .unwrap_or_else(|| self.config.download_dir.clone())
+        };
+
+        info!("开始做种: {:?}", torrent_path);
+
+        let download_source = DownloadSource::Torrent { path: torrent_path };
+        let mut task = DownloadTask::new(download_source.clone(), save_path.clone());
+        task.info_hash = source_info_hash(&download_source).await?;
+        let task_id = task.id;
+
+        if let Some(existing) = self
+            .register_task(&task, self.config.duplicate_policy)
+            .await?
+        {
+            return Ok(existing);
+        }
+
+        let _ = self.event_tx.send(DownloadEvent::TaskAdded {
+            task_id,
+            name: task.name.clone(),
+            thumbnail: None,
+        });
+
+        self.start_download(task_id, download_source, save_path, None)
+            .await?;
+
+        Ok(task_id)
+    }
 
     /// 暂停下载任务
     pub async fn pause(&self, task_id: TaskId) -> Result<()> {
@@ -389,6 +873,9 @@ impl DownloadManager {
             DownloadSource::Http { .. } => {
                 self.http_handler.pause(task_id).await?;
             }
+            DownloadSource::Ftp { .. } => {
+                self.ftp_handler.pause(task_id).await?;
+            }
             DownloadSource::Magnet { .. } | DownloadSource::Torrent { .. } => {
                 if let Some(ref handler) = self.torrent_handler {
                     handler.pause(task_id).await?;
@@ -396,17 +883,24 @@ impl DownloadManager {
                     return Err(NebulaError::UnsupportedProtocol("BitTorrent 未初始化".to_string()));
                 }
             }
-            _ => return Err(NebulaError::UnsupportedProtocol("Unsupported".to_string())),
-        }
-
-        // 更新任务状态
-        {
-            let mut tasks = self.tasks.write().await;
-            if let Some(task) = tasks.get_mut(&task_id) {
-                task.status = TaskStatus::Paused;
+            DownloadSource::Video { .. } => {
+                self.video_handler.pause(task_id).await?;
+            }
+            DownloadSource::Playlist { .. } => {
+                // 暂停正在下载的条目，后续条目等待父任务恢复
+                let handler = self.video_handler()?;
+                for child_id in self.children_in(&task, TaskStatus::can_pause).await {
+                    if handler.pause(child_id).await.is_ok() {
+                        let _ = self.set_status(child_id, TaskStatus::Paused).await;
+                        let _ = self.event_tx.send(DownloadEvent::TaskPaused { task_id: child_id });
+                    }
+                }
             }
         }
 
+        // 处理器确认暂停后再更新任务状态
+        self.set_status(task_id, TaskStatus::Paused).await?;
+
         let _ = self.event_tx.send(DownloadEvent::TaskPaused { task_id });
 
         Ok(())
@@ -433,6 +927,9 @@ impl DownloadManager {
             DownloadSource::Http { .. } => {
                 self.http_handler.resume(task_id).await?;
             }
+            DownloadSource::Ftp { .. } => {
+                self.ftp_handler.resume(task_id).await?;
+            }
             DownloadSource::Magnet { .. } | DownloadSource::Torrent { .. } => {
                 if let Some(ref handler) = self.torrent_handler {
                     handler.resume(task_id).await?;
@@ -440,17 +937,22 @@ impl DownloadManager {
                     return Err(NebulaError::UnsupportedProtocol("BitTorrent 未初始化".to_string()));
                 }
             }
-            _ => return Err(NebulaError::UnsupportedProtocol("Unsupported".to_string())),
-        }
-
-        // 更新任务状态
-        {
-            let mut tasks = self.tasks.write().await;
-            if let Some(task) = tasks.get_mut(&task_id) {
-                task.status = TaskStatus::Downloading;
+            DownloadSource::Video { .. } => {
+                self.video_handler.resume(task_id).await?;
+            }
+            DownloadSource::Playlist { .. } => {
+                let handler = self.video_handler()?;
+                for child_id in self.children_in(&task, TaskStatus::can_resume).await {
+                    if handler.resume(child_id).await.is_ok() {
+                        let _ = self.set_status(child_id, TaskStatus::Downloading).await;
+                        let _ = self.event_tx.send(DownloadEvent::TaskResumed { task_id: child_id });
+                    }
+                }
             }
         }
 
+        self.set_status(task_id, TaskStatus::Downloading).await?;
+
         let _ = self.event_tx.send(DownloadEvent::TaskResumed { task_id });
 
         Ok(())
@@ -461,30 +963,133 @@ impl DownloadManager {
     /// # 参数
     /// - `task_id`: 任务 ID
     /// - `delete_files`: 是否删除已下载的文件
+    ///
+    /// 任务保留在任务表中，状态变为 [`TaskStatus::Cancelled`]（发送
+    /// [`DownloadEvent::StatusChanged`]）；使用 [`Self::remove_task`] 从列表中移除
+    ///
+    /// 取消播放列表时，未结束的条目一并取消
     pub async fn cancel(&self, task_id: TaskId, delete_files: bool) -> Result<()> {
+        let source = self.set_status(task_id, TaskStatus::Cancelled).await?;
+        self.stop_in_handler(task_id, &source, delete_files).await;
+
+        if let DownloadSource::Playlist { .. } = source {
+            let children = match self.get_task(task_id).await {
+                Some(task) => self.children_in(&task, |s| !s.is_finished()).await,
+                None => Vec::new(),
+            };
+            for child_id in children {
+                if let Ok(source) = self.set_status(child_id, TaskStatus::Cancelled).await {
+                    self.stop_in_handler(child_id, &source, delete_files).await;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 重新开始已完成或失败的任务
+    ///
+    /// 这是已结束的任务回到下载状态的唯一途径，已下载的文件会保留并重新校验。
+    /// 重新开始播放列表时，失败的条目会重新下载
+    pub async fn restart(&self, task_id: TaskId) -> Result<()> {
         let task = {
             let mut tasks = self.tasks.write().await;
-            tasks.remove(&task_id)
+            let task = tasks
+                .get_mut(&task_id)
+                .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
+            if let Some(changed) = task.restart()? {
+                let _ = self.event_tx.send(changed);
+            }
+            let task = task.clone();
+
+            for child_id in &task.children {
+                if let Some(child) = tasks.get_mut(child_id) {
+                    if matches!(child.status, TaskStatus::Failed { .. }) {
+                        if let Ok(Some(changed)) = child.restart() {
+                            let _ = self.event_tx.send(changed);
+                        }
+                    }
+                }
+            }
+            task
         };
 
-        let task = task.ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
+        self.stop_in_handler(task_id, &task.source, false).await;
+        self.start_download(task_id, task.source, task.save_path, task.only_files)
+            .await
+    }
 
-        // 根据协议类型调用对应处理器
-        match &task.source {
+    /// 从任务表中移除已结束（完成、失败或取消）的任务
+    ///
+    /// 每个被移除的任务发送一次 [`DownloadEvent::TaskRemoved`]
+    pub async fn remove_task(&self, task_id: TaskId) -> Result<()> {
+        let mut tasks = self.tasks.write().await;
+        let task = tasks
+            .get(&task_id)
+            .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
+        if !task.status.is_finished() {
+            return Err(NebulaError::InvalidTaskState {
+                current: task.status.description(),
+                action: "移除".to_string(),
+            });
+        }
+        // 播放列表的已结束条目一并移除
+        let children: Vec<_> = task
+            .children
+            .iter()
+            .filter(|id| tasks.get(id).is_some_and(|t| t.status.is_finished()))
+            .copied()
+            .collect();
+        for child_id in children {
+            tasks.remove(&child_id);
+            let _ = self.event_tx.send(DownloadEvent::TaskRemoved { task_id: child_id });
+        }
+        tasks.remove(&task_id);
+        let _ = self.event_tx.send(DownloadEvent::TaskRemoved { task_id });
+        Ok(())
+    }
+
+    /// 校验并更新任务状态，状态改变时发送 [`DownloadEvent::StatusChanged`]
+    ///
+    /// # 返回
+    /// 任务的下载来源
+    async fn set_status(&self, task_id: TaskId, to: TaskStatus) -> Result<DownloadSource> {
+        set_task_status(&self.tasks, &self.event_tx, task_id, to).await
+    }
+
+    /// 播放列表中状态满足 `filter` 的子任务
+    async fn children_in(
+        &self,
+        task: &DownloadTask,
+        filter: impl Fn(&TaskStatus) -> bool,
+    ) -> Vec<TaskId> {
+        let tasks = self.tasks.read().await;
+        task.children
+            .iter()
+            .filter(|id| tasks.get(id).is_some_and(|t| filter(&t.status)))
+            .copied()
+            .collect()
+    }
+
+    /// 通知处理器停止任务
+    async fn stop_in_handler(&self, task_id: TaskId, source: &DownloadSource, delete_files: bool) {
+        match source {
             DownloadSource::Http { .. } => {
                 let _ = self.http_handler.cancel(task_id, delete_files).await;
             }
+            DownloadSource::Ftp { .. } => {
+                let _ = self.ftp_handler.cancel(task_id, delete_files).await;
+            }
             DownloadSource::Magnet { .. } | DownloadSource::Torrent { .. } => {
                 if let Some(ref handler) = self.torrent_handler {
                     let _ = handler.cancel(task_id, delete_files).await;
                 }
             }
+            DownloadSource::Video { .. } => {
+                let _ = self.video_handler.cancel(task_id, delete_files).await;
+            }
             _ => {}
         }
-
-        let _ = self.event_tx.send(DownloadEvent::TaskRemoved { task_id });
-
-        Ok(())
     }
 
     /// 获取任务信息
@@ -493,6 +1098,13 @@ impl DownloadManager {
         tasks.get(&task_id).cloned()
     }
 
+    /// 获取任务在本地的文件或目录路径
+    ///
+    /// 视频任务在下载完成后为实际输出的文件
+    pub async fn get_task_path(&self, task_id: TaskId) -> Option<PathBuf> {
+        self.get_task(task_id).await.map(|task| task.local_path())
+    }
+
     /// 获取所有任务列表
     pub async fn list_tasks(&self) -> Vec<DownloadTask> {
         let tasks = self.tasks.read().await;
@@ -510,6 +1122,7 @@ impl DownloadManager {
 
         match &task.source {
             DownloadSource::Http { .. } => self.http_handler.get_progress(task_id).await,
+            DownloadSource::Ftp { .. } => self.ftp_handler.get_progress(task_id).await,
             DownloadSource::Magnet { .. } | DownloadSource::Torrent { .. } => {
                 if let Some(ref handler) = self.torrent_handler {
                     handler.get_progress(task_id).await
@@ -517,15 +1130,159 @@ impl DownloadManager {
                     Err(NebulaError::UnsupportedProtocol("BitTorrent 未初始化".to_string()))
                 }
             }
-            _ => Err(NebulaError::UnsupportedProtocol("Unsupported".to_string())),
+            DownloadSource::Video { .. } => self.video_handler.get_progress(task_id).await,
+            // 播放列表的进度由事件消费协程汇总
+            DownloadSource::Playlist { .. } => Ok(task.progress),
+        }
+    }
+
+    /// 获取视频处理器
+    fn video_handler(&self) -> Result<&Arc<VideoHandler>> {
+        if !self.video_handler.is_available() {
+            return Err(NebulaError::ToolNotFound(Tool::YtDlp.name().to_string()));
+        }
+        Ok(&self.video_handler)
+    }
+
+    /// 获取能下载该链接的视频处理器（没有 yt-dlp 时 Bilibili 链接使用原生下载）
+    fn video_handler_for(&self, url: &str) -> Result<&Arc<VideoHandler>> {
+        if !self.video_handler.can_download(url) {
+            return Err(NebulaError::ToolNotFound(Tool::YtDlp.name().to_string()));
+        }
+        Ok(&self.video_handler)
+    }
+
+    /// 获取 BitTorrent 任务对应的处理器
+    async fn torrent_handler_for(&self, task_id: TaskId) -> Result<&Arc<TorrentHandler>> {
+        let source = {
+            let tasks = self.tasks.read().await;
+            tasks
+                .get(&task_id)
+                .map(|t| t.source.clone())
+                .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?
+        };
+
+        match source {
+            DownloadSource::Magnet { .. } | DownloadSource::Torrent { .. } => self
+                .torrent_handler
+                .as_ref()
+                .ok_or_else(|| NebulaError::UnsupportedProtocol("BitTorrent 未初始化".to_string())),
+            _ => Err(NebulaError::UnsupportedProtocol(
+                "该任务不是 BitTorrent 任务".to_string(),
+            )),
+        }
+    }
+
+    /// 仅解析种子元数据，不开始下载
+    ///
+    /// 磁力链接会从 Peer 获取元数据（可能需要一段时间），返回真实的名称、
+    /// 文件列表和大小。确认后可通过 [`AddTaskOptions::with_only_files`] 选择文件下载，
+    /// 此时不会再次获取元数据
+    pub async fn resolve_torrent(&self, source: &str) -> Result<TorrentMetainfo> {
+        let download_source = DownloadSource::detect(source);
+        self.torrent_handler
+            .as_ref()
+            .ok_or_else(|| NebulaError::UnsupportedProtocol("BitTorrent 未初始化".to_string()))?
+            .resolve(&download_source)
+            .await
+    }
+
+    /// 将已解析的元数据导出为 .torrent 文件（不需要添加任务）
+    pub async fn export_resolved_torrent(&self, source: &str, path: &Path) -> Result<()> {
+        self.resolve_torrent(source).await?.write_to(path).await
+    }
+
+    /// 将种子任务导出为 .torrent 文件，包含任务当前的 Tracker 列表
+    pub async fn export_torrent(&self, task_id: TaskId, path: &Path) -> Result<()> {
+        self.torrent_handler_for(task_id)
+            .await?
+            .export_torrent(task_id, path)
+            .await
+    }
+
+    /// 强制重新校验种子任务的已下载数据
+    ///
+    /// 校验期间任务状态为 [`TaskStatus::Checking`]，
+    /// 进度通过 [`DownloadEvent::CheckProgress`] 通知
+    pub async fn recheck(&self, task_id: TaskId) -> Result<()> {
+        let handler = self.torrent_handler_for(task_id).await?;
+
+        let previous = self
+            .get_task(task_id)
+            .await
+            .map(|task| task.status)
+            .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
+        self.set_status(task_id, TaskStatus::Checking).await?;
+
+        let result = handler.recheck(task_id).await;
+        if result.is_err() {
+            // 校验未能开始，恢复原状态
+            let _ = self.set_status(task_id, previous).await;
         }
+        result
+    }
+
+    /// 获取种子任务的 Tracker 列表及状态
+    ///
+    /// # 参数
+    /// - `task_id`: 任务 ID
+    /// - `refresh`: 是否立即向 Tracker 重新查询
+    pub async fn get_torrent_trackers(
+        &self,
+        task_id: TaskId,
+        refresh: bool,
+    ) -> Result<Vec<TorrentTracker>> {
+        let handler = self.torrent_handler_for(task_id).await?;
+        if refresh {
+            handler.refresh_trackers(task_id).await
+        } else {
+            handler.get_trackers(task_id).await
+        }
+    }
+
+    /// 获取种子任务当前连接的 Peer 列表
+    pub async fn get_torrent_peers(&self, task_id: TaskId) -> Result<Vec<PeerInfo>> {
+        self.torrent_handler_for(task_id)
+            .await?
+            .get_peers(task_id)
+            .await
+    }
+
+    /// 为种子任务添加 Tracker
+    pub async fn add_torrent_trackers(&self, task_id: TaskId, urls: Vec<String>) -> Result<()> {
+        self.torrent_handler_for(task_id)
+            .await?
+            .add_trackers(task_id, urls)
+            .await
+    }
+
+    /// 从种子任务移除 Tracker
+    pub async fn remove_torrent_trackers(&self, task_id: TaskId, urls: Vec<String>) -> Result<()> {
+        self.torrent_handler_for(task_id)
+            .await?
+            .remove_trackers(task_id, urls)
+            .await
+    }
+
+    /// 获取 IP 过滤统计（未启用 IP 过滤或 BitTorrent 不可用时返回 None）
+    pub async fn ip_filter_stats(&self) -> Option<IpFilterStats> {
+        self.torrent_handler.as_ref()?.ip_filter_stats().await
+    }
+
+    /// 立即更新 IP 过滤订阅列表
+    pub async fn update_ip_filter(&self) -> Result<IpFilterStats> {
+        self.torrent_handler
+            .as_ref()
+            .ok_or_else(|| NebulaError::UnsupportedProtocol("BitTorrent 未初始化".to_string()))?
+            .update_ip_filter()
+            .await
     }
 
     /// 订阅下载事件流
     ///
     /// 返回一个接收器，可用于监听所有下载事件
     pub fn subscribe(&self) -> broadcast::Receiver<DownloadEvent> {
-        self.event_tx.subscribe()
+        self.subscribers.subscribe()
     }
 
     /// 获取当前配置
@@ -538,6 +1295,73 @@ impl DownloadManager {
         &self.config.download_dir
     }
 
+    /// 查询外部工具（yt-dlp、ffmpeg）的路径和版本
+    ///
+    /// 未找到的工具不包含在结果中
+    pub async fn tool_status(&self) -> Vec<ToolInfo> {
+        let mut infos = Vec::new();
+        for tool in Tool::ALL {
+            if let Some(info) = self.tools.inspect(tool).await {
+                infos.push(info);
+            }
+        }
+        infos
+    }
+
+    /// 检查 yt-dlp 是否有新版本
+    pub async fn check_yt_dlp_update(&self) -> Result<ToolUpdate> {
+        self.tools.check_yt_dlp_update().await
+    }
+
+    /// 下载并安装最新版 yt-dlp，之后启动的视频下载使用新版本
+    pub async fn update_yt_dlp(&self) -> Result<ToolInfo> {
+        let info = self.tools.update_yt_dlp().await?;
+        self.video_handler.refresh_tools(&self.tools);
+        Ok(info)
+    }
+
+    /// 检查 Bilibili 登录状态，Cookie 临近过期时刷新
+    ///
+    /// 登录已失效时发送 [`DownloadEvent::BilibiliLoginExpired`]
+    pub async fn check_bilibili_session(&self) -> Result<SessionStatus> {
+        check_bilibili_session(&self.bilibili_auth, &self.event_tx).await
+    }
+
+    /// 获取已登录的 Bilibili 账号信息，未登录时返回 None
+    pub async fn bilibili_account(&self) -> Result<Option<BilibiliAccount>> {
+        self.bilibili_auth.account_info().await
+    }
+
+    /// 站点凭据保险库
+    pub fn credential_vault(&self) -> &Arc<CredentialVault> {
+        &self.credential_vault
+    }
+
+    /// 用口令解锁凭据保险库
+    ///
+    /// 配置为口令加密（[`KeySource::Passphrase`]）时，启动后需要调用此方法才会注入站点凭据；
+    /// 还没有保存过凭据时用该口令新建保险库
+    pub async fn unlock_credentials(&self, passphrase: &str) -> Result<()> {
+        self.credential_vault
+            .unlock(VaultKey::Passphrase(passphrase.to_string()))
+            .await
+    }
+
+    /// 启动时以及之后定期检查 Bilibili 登录状态
+    fn spawn_bilibili_session_check(&self) {
+        let auth = Arc::clone(&self.bilibili_auth);
+        let event_tx = self.event_tx.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(BILIBILI_SESSION_CHECK_INTERVAL);
+            loop {
+                interval.tick().await;
+                if let Err(e) = check_bilibili_session(&auth, &event_tx).await {
+                    warn!("检查 B站登录状态失败: {}", e);
+                }
+            }
+        });
+    }
+
     /// 获取活跃任务数量
     pub async fn active_task_count(&self) -> usize {
         let tasks = self.tasks.read().await;
@@ -545,6 +1369,117 @@ impl DownloadManager {
     }
 }
 
+/// 检查 Bilibili 登录状态，登录已失效时发送事件
+async fn check_bilibili_session(
+    auth: &BilibiliAuth,
+    event_tx: &mpsc::UnboundedSender<DownloadEvent>,
+) -> Result<SessionStatus> {
+    let status = auth.refresh_if_needed().await?;
+    if status == SessionStatus::Expired {
+        let _ = event_tx.send(DownloadEvent::BilibiliLoginExpired {
+            reason: "Cookie 已过期或账号已在其他设备退出".to_string(),
+        });
+    }
+    Ok(status)
+}
+
+/// 获取 BitTorrent 来源的 info hash（十六进制）
+///
+/// 磁力链接无效时返回错误；种子文件无法读取时返回 None，由处理器报告具体错误
+async fn source_info_hash(source: &DownloadSource) -> Result<Option<String>> {
+    match source {
+        DownloadSource::Magnet { uri, .. } => Ok(Some(MagnetLink::parse(uri)?.info_hash_hex())),
+        DownloadSource::Torrent { path } => Ok(tokio::fs::read(path)
+            .await
+            .ok()
+            .and_then(|data| TorrentMetainfo::from_bytes(&data).ok())
+            .map(|meta| meta.info_hash_hex())),
+        _ => Ok(None),
+    }
+}
+
+/// 校验并更新任务状态，状态改变时发送 [`DownloadEvent::StatusChanged`]
+///
+/// # 返回
+/// 任务的下载来源
+async fn set_task_status(
+    tasks: &RwLock<HashMap<TaskId, DownloadTask>>,
+    event_tx: &mpsc::UnboundedSender<DownloadEvent>,
+    task_id: TaskId,
+    to: TaskStatus,
+) -> Result<DownloadSource> {
+    let mut tasks = tasks.write().await;
+    let task = tasks
+        .get_mut(&task_id)
+        .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
+    if let Some(changed) = task.transition(to)? {
+        let _ = event_tx.send(changed);
+    }
+    Ok(task.source.clone())
+}
+
+/// 根据子任务重新汇总播放列表父任务，状态改变时返回 [`DownloadEvent::StatusChanged`]
+fn refresh_parent(
+    tasks: &mut HashMap<TaskId, DownloadTask>,
+    parent_id: TaskId,
+) -> Option<DownloadEvent> {
+    let children: Vec<DownloadTask> = tasks
+        .get(&parent_id)?
+        .children
+        .iter()
+        .filter_map(|id| tasks.get(id).cloned())
+        .collect();
+    tasks.get_mut(&parent_id)?.refresh_from_children(&children)
+}
+
+/// 依次下载播放列表中等待中的条目
+///
+/// 父任务暂停时在两个条目之间等待，父任务取消后停止
+async fn run_playlist(
+    tasks: Arc<RwLock<HashMap<TaskId, DownloadTask>>>,
+    handler: Arc<VideoHandler>,
+    event_tx: mpsc::UnboundedSender<DownloadEvent>,
+    parent_id: TaskId,
+) {
+    let children = match tasks.read().await.get(&parent_id) {
+        Some(parent) => parent.children.clone(),
+        None => return,
+    };
+
+    for child_id in children {
+        let child = loop {
+            let (parent_status, child) = {
+                let tasks = tasks.read().await;
+                (
+                    tasks.get(&parent_id).map(|t| t.status.clone()),
+                    tasks.get(&child_id).cloned(),
+                )
+            };
+            match parent_status {
+                Some(TaskStatus::Paused) => {
+                    tokio::time::sleep(std::time::Duration::from_secs(1)).await
+                }
+                Some(TaskStatus::Cancelled) | None => return,
+                Some(_) => break child,
+            }
+        };
+
+        let Some(child) = child.filter(|c| c.status == TaskStatus::Pending) else {
+            continue;
+        };
+        if let Err(e) = handler
+            .start(child_id, &child.source, child.save_path, event_tx.clone())
+            .await
+        {
+            error!("播放列表条目下载失败: {}", e);
+            let _ = event_tx.send(DownloadEvent::TaskFailed {
+                task_id: child_id,
+                error: e.to_string(),
+            });
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -555,4 +1490,146 @@ mod tests {
         let manager = DownloadManager::new(config).await;
         assert!(manager.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_existing_data_requires_torrent() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ManagerConfig {
+            download_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        };
+        let manager = DownloadManager::new(config).await.unwrap();
+
+        let options = AddTaskOptions::new().with_existing_data(dir.path().to_path_buf());
+        let result = manager
+            .add_task_with_options("https://example.com/file.zip", PathBuf::new(), options)
+            .await;
+        assert!(matches!(result, Err(NebulaError::UnsupportedProtocol(_))));
+
+        let options = AddTaskOptions::new().with_existing_data(dir.path().join("missing"));
+        let result = manager
+            .add_task_with_options("/path/to/file.torrent", PathBuf::new(), options)
+            .await;
+        assert!(matches!(result, Err(NebulaError::IoError { .. })));
+        assert!(manager.list_tasks().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_seed_renamed_single_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ManagerConfig {
+            download_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        };
+        let manager = DownloadManager::new(config).await.unwrap();
+
+        let input = dir.path().join("data.bin");
+        std::fs::write(&input, vec![7u8; 4096]).unwrap();
+        let torrent_path = dir.path().join("data.torrent");
+        let options = TorrentCreateOptions {
+            name: Some("renamed.bin".to_string()),
+            ..Default::default()
+        };
+        manager
+            .create_torrent(&input, &torrent_path, options)
+            .await
+            .unwrap();
+
+        let result = manager.seed_torrent(torrent_path, &input).await;
+        assert!(matches!(result, Err(NebulaError::InvalidConfig(_))));
+        assert!(manager.list_tasks().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_magnet_validation_and_dedup() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ManagerConfig {
+            download_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        };
+        let manager = DownloadManager::new(config).await.unwrap();
+
+        let result = manager
+            .add_task("magnet:?xt=urn:btih:abc123&dn=x", PathBuf::new())
+            .await;
+        assert!(matches!(result, Err(NebulaError::InvalidMagnet(_))));
+        assert!(manager.list_tasks().await.is_empty());
+
+        let source = DownloadSource::detect(
+            "magnet:?xt=urn:btih:YEX6DQDLXISUVHOJ6UM3GNNKPQJWPKEK&dn=x",
+        );
+        let mut task = DownloadTask::new(source.clone(), PathBuf::new());
+        task.info_hash = source_info_hash(&source).await.unwrap();
+        assert_eq!(
+            task.info_hash.as_deref(),
+            Some("c12fe1c06bba254a9dc9f519b335aa7c1367a88a")
+        );
+        let policy = DuplicatePolicy::Reject;
+        assert_eq!(manager.register_task(&task, policy).await.unwrap(), None);
+
+        let mut duplicate = DownloadTask::new(source, PathBuf::new());
+        duplicate.info_hash = task.info_hash.clone();
+        let result = manager.register_task(&duplicate, policy).await;
+        assert!(matches!(result, Err(NebulaError::TaskAlreadyExists(_))));
+
+        let result = manager
+            .register_task(&duplicate, DuplicatePolicy::ReturnExisting)
+            .await;
+        assert_eq!(result.unwrap(), Some(task.id));
+
+        let mut events = manager.subscribe();
+        let result = manager
+            .register_task(&duplicate, DuplicatePolicy::Restart)
+            .await;
+        assert_eq!(result.unwrap(), None);
+        let tasks = manager.list_tasks().await;
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].id, duplicate.id);
+        loop {
+            match next_event(&mut events).await {
+                DownloadEvent::TaskRemoved { task_id } if task_id == task.id => break,
+                _ => continue,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancel_and_remove_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ManagerConfig {
+            download_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        };
+        let manager = DownloadManager::new(config).await.unwrap();
+        let source = DownloadSource::detect("https://example.com/file.zip");
+        let task = DownloadTask::new(source, dir.path().to_path_buf());
+        manager
+            .register_task(&task, DuplicatePolicy::Reject)
+            .await
+            .unwrap();
+
+        let mut events = manager.subscribe();
+        manager.cancel(task.id, false).await.unwrap();
+        assert!(matches!(
+            next_event(&mut events).await,
+            DownloadEvent::StatusChanged { to: TaskStatus::Cancelled, .. }
+        ));
+        assert_eq!(manager.list_tasks().await.len(), 1);
+
+        // 取消之后没有其他事件，下一个就是 TaskRemoved
+        manager.remove_task(task.id).await.unwrap();
+        assert!(matches!(
+            next_event(&mut events).await,
+            DownloadEvent::TaskRemoved { task_id } if task_id == task.id
+        ));
+        assert!(manager.list_tasks().await.is_empty());
+    }
+
+    /// 等待下一个广播的事件（事件经消费协程转发，不是立即可读）
+    async fn next_event(events: &mut broadcast::Receiver<DownloadEvent>) -> DownloadEvent {
+        tokio::time::timeout(std::time::Duration::from_secs(1), events.recv())
+            .await
+            .expect("等待事件超时")
+            .unwrap()
+    }
 }
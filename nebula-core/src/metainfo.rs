@@ -0,0 +1,329 @@
+//! 种子元数据模块
+//!
+//! 解析 .torrent 文件或 info 字典，提取名称、文件列表、Tracker 等信息，
+//! 并可重新生成 .torrent 文件（info 字典原样保留，保证 info hash 不变）。
+
+use crate::bencode::{self, Value};
+use crate::error::{NebulaError, Result};
+
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::collections::BTreeMap;
+use std::path::{Component, Path};
+
+/// 种子中的单个文件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TorrentFile {
+    /// 文件序号（用于选择下载的文件）
+    pub index: usize,
+    /// 种子内的相对路径，以 `/` 分隔
+    pub path: String,
+    /// 文件大小（字节）
+    pub length: u64,
+    /// 文件在整个种子数据中的起始偏移（字节）
+    pub offset: u64,
+}
+
+/// 种子元数据
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TorrentMetainfo {
+    /// info hash (v1)
+    pub info_hash: [u8; 20],
+    /// 种子名称
+    pub name: String,
+    /// 总大小（字节）
+    pub total_size: u64,
+    /// 分块大小（字节）
+    pub piece_length: u64,
+    /// 文件列表（单文件种子只有一项）
+    pub files: Vec<TorrentFile>,
+    /// 是否为多文件种子（文件路径不含种子名称）
+    #[serde(default)]
+    pub multi_file: bool,
+    /// 是否为私有种子
+    pub private: bool,
+    /// Tracker 列表
+    pub trackers: Vec<String>,
+    /// Web Seed 地址列表 (BEP 19)
+    pub web_seeds: Vec<String>,
+    /// 原始 info 字典
+    info_bytes: Vec<u8>,
+}
+
+impl TorrentMetainfo {
+    /// 解析 .torrent 文件内容，也接受单独的 info 字典
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let entries = bencode::dict_entries_raw(data)
+            .ok_or_else(|| NebulaError::TorrentParseError("不是有效的 bencode 字典".to_string()))?;
+
+        let info_raw = entries
+            .iter()
+            .find(|(k, _)| k == b"info")
+            .map(|(_, raw)| *raw);
+        let (info_bytes, top) = match info_raw {
+            Some(raw) => (raw, bencode::decode(data)),
+            None => (data, None),
+        };
+
+        let mut meta = Self::from_info(info_bytes)?;
+        if let Some(top) = top {
+            meta.trackers = announce_urls(&top);
+            meta.web_seeds = web_seed_urls(&top);
+        }
+        Ok(meta)
+    }
+
+    /// 解析 info 字典
+    fn from_info(info_bytes: &[u8]) -> Result<Self> {
+        let info = bencode::decode(info_bytes)
+            .ok_or_else(|| NebulaError::TorrentParseError("info 字典解析失败".to_string()))?;
+        let parse_error = |field: &str| NebulaError::TorrentParseError(format!("缺少字段: {}", field));
+
+        let name = info
+            .get("name.utf-8")
+            .or_else(|| info.get("name"))
+            .and_then(Value::as_str)
+            .ok_or_else(|| parse_error("name"))?
+            .to_string();
+        let piece_length = info
+            .get("piece length")
+            .and_then(Value::as_int)
+            .filter(|&n| n > 0)
+            .ok_or_else(|| parse_error("piece length"))? as u64;
+        let private = info.get("private").and_then(Value::as_int) == Some(1);
+
+        let mut files = Vec::new();
+        let mut offset = 0u64;
+        let file_list = info.get("files").and_then(Value::as_list);
+        match file_list {
+            Some(list) => {
+                for (index, entry) in list.iter().enumerate() {
+                    let length = entry
+                        .get("length")
+                        .and_then(Value::as_int)
+                        .filter(|&n| n >= 0)
+                        .ok_or_else(|| parse_error("files.length"))? as u64;
+                    let components: Vec<&str> = entry
+                        .get("path.utf-8")
+                        .or_else(|| entry.get("path"))
+                        .and_then(Value::as_list)
+                        .ok_or_else(|| parse_error("files.path"))?
+                        .iter()
+                        .map(|c| c.as_str().ok_or_else(|| parse_error("files.path")))
+                        .collect::<Result<_>>()?;
+                    files.push(TorrentFile {
+                        index,
+                        path: components.join("/"),
+                        length,
+                        offset,
+                    });
+                    offset += length;
+                }
+            }
+            None => {
+                let length = info
+                    .get("length")
+                    .and_then(Value::as_int)
+                    .filter(|&n| n >= 0)
+                    .ok_or_else(|| parse_error("length"))? as u64;
+                files.push(TorrentFile {
+                    index: 0,
+                    path: name.clone(),
+                    length,
+                    offset: 0,
+                });
+                offset = length;
+            }
+        }
+        // 文件会写入保存目录下的这些路径，拒绝越出保存目录的路径
+        if let Some(file) = files.iter().find(|f| !is_relative_path(&f.path)) {
+            return Err(NebulaError::TorrentParseError(format!(
+                "无效的文件路径: {}",
+                file.path
+            )));
+        }
+
+        Ok(Self {
+            info_hash: Sha1::digest(info_bytes).into(),
+            name,
+            total_size: offset,
+            piece_length,
+            files,
+            multi_file: file_list.is_some(),
+            private,
+            trackers: Vec::new(),
+            web_seeds: Vec::new(),
+            info_bytes: info_bytes.to_vec(),
+        })
+    }
+
+    /// info hash 的十六进制表示
+    pub fn info_hash_hex(&self) -> String {
+        self.info_hash.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// 分块数量
+    pub fn piece_count(&self) -> u64 {
+        self.total_size.div_ceil(self.piece_length)
+    }
+
+    /// 检查文件序号是否有效
+    pub fn validate_file_indices(&self, indices: &[usize]) -> Result<()> {
+        if indices.is_empty() {
+            return Err(NebulaError::InvalidConfig("至少需要选择一个文件".to_string()));
+        }
+        match indices.iter().find(|&&i| i >= self.files.len()) {
+            Some(i) => Err(NebulaError::InvalidConfig(format!(
+                "文件序号超出范围: {} (共 {} 个文件)",
+                i,
+                self.files.len()
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// 生成 .torrent 文件内容
+    pub fn to_torrent_bytes(&self) -> Vec<u8> {
+        let mut top = BTreeMap::new();
+        if let Some(first) = self.trackers.first() {
+            top.insert(b"announce".to_vec(), Value::string(first).encode());
+        }
+        if self.trackers.len() > 1 {
+            let tiers = self
+                .trackers
+                .iter()
+                .map(|t| Value::List(vec![Value::string(t)]))
+                .collect();
+            top.insert(b"announce-list".to_vec(), Value::List(tiers).encode());
+        }
+        if !self.web_seeds.is_empty() {
+            let seeds = self.web_seeds.iter().map(|s| Value::string(s)).collect();
+            top.insert(b"url-list".to_vec(), Value::List(seeds).encode());
+        }
+        top.insert(b"info".to_vec(), self.info_bytes.clone());
+
+        let entries: Vec<(Vec<u8>, &[u8])> =
+            top.iter().map(|(k, v)| (k.clone(), v.as_slice())).collect();
+        bencode::encode_dict_raw(&entries)
+    }
+
+    /// 写入 .torrent 文件
+    pub async fn write_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(path, self.to_torrent_bytes())
+            .await
+            .map_err(|e| NebulaError::IoError {
+                path: path.to_path_buf(),
+                message: e.to_string(),
+            })
+    }
+}
+
+/// 读取种子中的 Tracker（announce-list 优先，其次 announce）
+fn announce_urls(torrent: &Value) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    let tiers = torrent.get("announce-list").and_then(Value::as_list);
+    for tier in tiers.unwrap_or_default() {
+        for url in tier.as_list().unwrap_or_default() {
+            if let Some(url) = url.as_str() {
+                if !urls.iter().any(|u| u == url) {
+                    urls.push(url.to_string());
+                }
+            }
+        }
+    }
+    if let Some(url) = torrent.get("announce").and_then(Value::as_str) {
+        if !urls.iter().any(|u| u == url) {
+            urls.push(url.to_string());
+        }
+    }
+    urls
+}
+
+/// 读取种子中的 Web Seed（url-list 可以是单个字符串或列表）
+fn web_seed_urls(torrent: &Value) -> Vec<String> {
+    match torrent.get("url-list") {
+        Some(Value::List(list)) => list
+            .iter()
+            .filter_map(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+            .collect(),
+        Some(value) => value
+            .as_str()
+            .filter(|s| !s.is_empty())
+            .map(|s| vec![s.to_string()])
+            .unwrap_or_default(),
+        None => Vec::new(),
+    }
+}
+
+/// 是否为只由普通组成部分构成的相对路径（不含 `..`、根目录、盘符和 `\`）
+pub(crate) fn is_relative_path(path: &str) -> bool {
+    !path.is_empty()
+        && !path.contains('\\')
+        && Path::new(path)
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MULTI: &[u8] = b"d8:announce9:http://a/13:announce-listll9:http://b/el9:http://a/ee8:url-list12:http://seed/4:infod5:filesld6:lengthi3e4:pathl1:x5:a.txteed6:lengthi5e4:pathl5:b.txteee4:name3:dir12:piece lengthi16384e6:pieces20:aaaaaaaaaaaaaaaaaaaaee";
+
+    #[test]
+    fn test_parse_multi_file() {
+        let meta = TorrentMetainfo::from_bytes(MULTI).unwrap();
+        assert_eq!(meta.name, "dir");
+        assert_eq!(meta.total_size, 8);
+        assert_eq!(meta.piece_count(), 1);
+        assert_eq!(meta.files[0].path, "x/a.txt");
+        assert_eq!(meta.files[1].offset, 3);
+        assert!(meta.multi_file);
+        assert_eq!(meta.trackers, vec!["http://b/", "http://a/"]);
+        assert_eq!(meta.web_seeds, vec!["http://seed/"]);
+
+        assert!(meta.validate_file_indices(&[1]).is_ok());
+        assert!(meta.validate_file_indices(&[2]).is_err());
+        assert!(meta.validate_file_indices(&[]).is_err());
+    }
+
+    #[test]
+    fn test_info_only_and_rebuild() {
+        let entries = bencode::dict_entries_raw(MULTI).unwrap();
+        let info = entries.iter().find(|(k, _)| k == b"info").unwrap().1;
+
+        let mut meta = TorrentMetainfo::from_bytes(info).unwrap();
+        assert!(meta.trackers.is_empty());
+        let expected: [u8; 20] = Sha1::digest(info).into();
+        assert_eq!(meta.info_hash, expected);
+
+        meta.trackers = vec!["udp://t/announce".to_string()];
+        let rebuilt = TorrentMetainfo::from_bytes(&meta.to_torrent_bytes()).unwrap();
+        assert_eq!(rebuilt.info_hash, meta.info_hash);
+        assert_eq!(rebuilt.trackers, meta.trackers);
+    }
+
+    #[test]
+    fn test_reject_unsafe_paths() {
+        let multi = |path: &str| {
+            format!(
+                "d5:filesld6:lengthi1e4:pathl{}:{}eee4:name3:dir12:piece lengthi16384e6:pieces20:aaaaaaaaaaaaaaaaaaaae",
+                path.len(),
+                path
+            )
+        };
+        for path in ["..", "/", ".", "a\\b"] {
+            let result = TorrentMetainfo::from_bytes(multi(path).as_bytes());
+            assert!(matches!(result, Err(NebulaError::TorrentParseError(_))), "{}", path);
+        }
+        assert!(TorrentMetainfo::from_bytes(multi("a.txt").as_bytes()).is_ok());
+
+        let single = b"d6:lengthi1e4:name5:../x12:piece lengthi16384e6:pieces20:aaaaaaaaaaaaaaaaaaaae";
+        assert!(TorrentMetainfo::from_bytes(single).is_err());
+    }
+}
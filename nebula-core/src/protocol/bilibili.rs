@@ -1,26 +1,45 @@
 //! Bilibili 登录模块
 //!
-//! 支持扫码登录获取 Cookie，用于下载高码率视频
+//! 支持扫码登录获取 Cookie，用于下载高码率视频。
+//! Cookie 临近过期时按 B站网页端的流程刷新，并可查询账号信息（昵称、大会员状态）
 
 use crate::error::{NebulaError, Result};
-use aes_gcm::{
-    aead::{Aead, KeyInit},
-    Aes256Gcm, Nonce,
-};
-use rand::RngCore;
+use crate::vault::{self, KeyStore};
+use reqwest::header::{HeaderValue, COOKIE};
+use rsa::pkcs8::DecodePublicKey;
+use rsa::{Oaep, RsaPublicKey};
 use serde::{Deserialize, Serialize};
+use sha2::Sha256;
 use std::path::PathBuf;
+use std::sync::Arc;
 use tokio::fs;
 use tracing::{debug, info, warn};
 
 /// B站登录 API 基础 URL
 const BILIBILI_PASSPORT_URL: &str = "https://passport.bilibili.com";
 
+/// B站 API 基础 URL
+const BILIBILI_API_URL: &str = "https://api.bilibili.com";
+
+/// 生成 CorrespondPath 的 RSA 公钥（B站网页端内置）
+const CORRESPOND_PUBLIC_KEY: &str = "-----BEGIN PUBLIC KEY-----
+MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQDLgd2OAkcGVtoE3ThUREbio0Eg
+Uc/prcajMKXvkCKFCWhJYJcLkcM2DKKcSeFpD/j6Boy538YXnR6VhcuUJOhH2x71
+nzPjfdTcqMz7djHum0qSZA0AyCBDABUqCrfNgCiJ00Ra7GmRj+YCK1NJEuewlb40
+JNrRuoEUXpabUzGB8QIDAQAB
+-----END PUBLIC KEY-----";
+
+/// 接口返回的未登录错误码
+const CODE_NOT_LOGGED_IN: i64 = -101;
+
+/// 刷新 Cookie 接口返回的 refresh_csrf 错误或刷新 token 已失效错误码
+const CODE_REFRESH_TOKEN_INVALID: i64 = 86095;
+
 /// Cookie 文件名
 const COOKIE_FILENAME: &str = "bilibili_cookies.enc";
 
-/// 加密密钥文件名
-const KEY_FILENAME: &str = "bilibili_key.bin";
+/// 密钥存储名称（密钥文件为 `bilibili_key.bin`）
+const KEY_STORE_NAME: &str = "bilibili";
 
 /// 二维码生成响应
 #[derive(Debug, Deserialize)]
@@ -78,7 +97,7 @@ pub enum LoginStatus {
 }
 
 /// Cookie 信息 (加密存储)
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BilibiliCookie {
     /// SESSDATA
     pub sessdata: String,
@@ -86,16 +105,124 @@ pub struct BilibiliCookie {
     pub bili_jct: String,
     /// DedeUserID
     pub dede_user_id: String,
+    /// 刷新 token（刷新 Cookie 时使用，旧版本保存的 Cookie 没有）
+    #[serde(default)]
+    pub refresh_token: String,
     /// 创建时间
     pub created_at: i64,
 }
 
+impl BilibiliCookie {
+    /// 请求头中的 Cookie 值
+    pub fn header_value(&self) -> String {
+        format!(
+            "SESSDATA={}; bili_jct={}; DedeUserID={}",
+            self.sessdata, self.bili_jct, self.dede_user_id
+        )
+    }
+}
+
+/// 登录会话状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionStatus {
+    /// 未登录
+    NotLoggedIn,
+    /// Cookie 有效，无需刷新
+    Valid,
+    /// Cookie 已刷新
+    Refreshed,
+    /// 登录已失效（已删除保存的 Cookie），需要重新扫码登录
+    Expired,
+}
+
+/// B站账号信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BilibiliAccount {
+    /// 用户 ID
+    pub mid: u64,
+    /// 昵称
+    pub username: String,
+    /// 头像 URL
+    pub avatar: String,
+    /// 等级
+    pub level: u32,
+    /// 是否为有效的大会员
+    pub vip_active: bool,
+    /// 大会员类型：0 无、1 月度大会员、2 年度及以上大会员
+    pub vip_type: u32,
+    /// 大会员到期时间（Unix 时间戳，秒）
+    pub vip_expires_at: Option<i64>,
+    /// 大会员标签（如「年度大会员」）
+    pub vip_label: Option<String>,
+}
+
+/// 接口通用响应
+#[derive(Debug, Deserialize)]
+struct ApiResponse<T> {
+    code: i64,
+    #[serde(default)]
+    message: String,
+    data: Option<T>,
+}
+
+/// Cookie 刷新检查数据
+#[derive(Debug, Deserialize)]
+struct CookieInfoData {
+    /// 是否需要刷新
+    refresh: bool,
+    /// 服务器时间戳（毫秒）
+    timestamp: i64,
+}
+
+/// Cookie 刷新结果
+#[derive(Debug, Deserialize)]
+struct CookieRefreshData {
+    /// 新的刷新 token
+    refresh_token: String,
+}
+
+/// 账号导航信息
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NavData {
+    is_login: bool,
+    #[serde(default)]
+    mid: u64,
+    #[serde(default)]
+    uname: String,
+    #[serde(default)]
+    face: String,
+    level_info: Option<LevelInfo>,
+    #[serde(default)]
+    vip_status: u32,
+    #[serde(default)]
+    vip_type: u32,
+    /// 大会员到期时间（毫秒）
+    #[serde(default)]
+    vip_due_date: i64,
+    #[serde(rename = "vip_label")]
+    vip_label: Option<VipLabel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LevelInfo {
+    current_level: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct VipLabel {
+    #[serde(default)]
+    text: String,
+}
+
 /// Bilibili 登录管理器
 pub struct BilibiliAuth {
     /// 数据目录
     data_dir: PathBuf,
     /// HTTP 客户端
     client: reqwest::Client,
+    /// Cookie 加密密钥的存储
+    key_store: Arc<dyn KeyStore>,
 }
 
 impl BilibiliAuth {
@@ -106,7 +233,19 @@ impl BilibiliAuth {
             .build()
             .unwrap_or_default();
 
-        Self { data_dir, client }
+        let key_store = vault::default_key_store(&data_dir, KEY_STORE_NAME);
+
+        Self {
+            data_dir,
+            client,
+            key_store,
+        }
+    }
+
+    /// 设置 Cookie 加密密钥的存储
+    pub fn with_key_store(mut self, key_store: Arc<dyn KeyStore>) -> Self {
+        self.key_store = key_store;
+        self
     }
 
     /// 生成登录二维码
@@ -177,13 +316,13 @@ impl BilibiliAuth {
         match data.code {
             0 => {
                 // 登录成功，从 cookies 提取信息
-                if let Some(cookie) = self.parse_cookies(&cookies) {
+                if let Some(cookie) = self.parse_cookies(&cookies, &data.refresh_token) {
                     self.save_cookie(&cookie).await?;
                     info!("B站登录成功");
                     Ok(LoginStatus::Success)
                 } else {
                     // 尝试从 URL 解析 cookie
-                    if let Some(cookie) = self.parse_url_cookies(&data.url) {
+                    if let Some(cookie) = self.parse_url_cookies(&data.url, &data.refresh_token) {
                         self.save_cookie(&cookie).await?;
                         info!("B站登录成功 (从 URL 解析)");
                         Ok(LoginStatus::Success)
@@ -200,7 +339,7 @@ impl BilibiliAuth {
     }
 
     /// 解析 Cookie 字符串
-    fn parse_cookies(&self, cookies: &[String]) -> Option<BilibiliCookie> {
+    fn parse_cookies(&self, cookies: &[String], refresh_token: &str) -> Option<BilibiliCookie> {
         let mut sessdata = None;
         let mut bili_jct = None;
         let mut dede_user_id = None;
@@ -220,6 +359,7 @@ impl BilibiliAuth {
                 sessdata: s,
                 bili_jct: b,
                 dede_user_id: d,
+                refresh_token: refresh_token.to_string(),
                 created_at: chrono::Utc::now().timestamp(),
             }),
             _ => None,
@@ -227,7 +367,7 @@ impl BilibiliAuth {
     }
 
     /// 从登录 URL 解析 Cookie
-    fn parse_url_cookies(&self, url: &str) -> Option<BilibiliCookie> {
+    fn parse_url_cookies(&self, url: &str, refresh_token: &str) -> Option<BilibiliCookie> {
         let url = url::Url::parse(url).ok()?;
         let params: std::collections::HashMap<_, _> = url.query_pairs().collect();
 
@@ -239,6 +379,7 @@ impl BilibiliAuth {
             sessdata,
             bili_jct,
             dede_user_id,
+            refresh_token: refresh_token.to_string(),
             created_at: chrono::Utc::now().timestamp(),
         })
     }
@@ -254,22 +395,8 @@ impl BilibiliAuth {
         let plaintext = serde_json::to_vec(cookie)
             .map_err(|e| NebulaError::Internal(format!("序列化 Cookie 失败: {}", e)))?;
 
-        // 生成随机 nonce
-        let mut nonce_bytes = [0u8; 12];
-        rand::rng().fill_bytes(&mut nonce_bytes);
-        let nonce = Nonce::from_slice(&nonce_bytes);
-
-        // 加密
-        let cipher = Aes256Gcm::new_from_slice(&key)
-            .map_err(|e| NebulaError::Internal(format!("创建加密器失败: {}", e)))?;
-
-        let ciphertext = cipher
-            .encrypt(nonce, plaintext.as_ref())
-            .map_err(|e| NebulaError::Internal(format!("加密失败: {}", e)))?;
-
-        // 保存 nonce + ciphertext
-        let mut data = nonce_bytes.to_vec();
-        data.extend(ciphertext);
+        // 加密，保存 nonce + ciphertext
+        let data = vault::seal(&key, &plaintext)?;
 
         let cookie_path = self.data_dir.join(COOKIE_FILENAME);
         fs::write(&cookie_path, data).await?;
@@ -289,26 +416,9 @@ impl BilibiliAuth {
         // 读取加密数据
         let data = fs::read(&cookie_path).await?;
 
-        if data.len() < 12 {
-            warn!("Cookie 文件格式错误");
-            return Ok(None);
-        }
-
-        // 分离 nonce 和 ciphertext
-        let nonce_bytes = &data[..12];
-        let ciphertext = &data[12..];
-        let nonce = Nonce::from_slice(nonce_bytes);
-
-        // 加载密钥
+        // 加载密钥并解密
         let key = self.get_or_create_key().await?;
-
-        // 解密
-        let cipher = Aes256Gcm::new_from_slice(&key)
-            .map_err(|e| NebulaError::Internal(format!("创建解密器失败: {}", e)))?;
-
-        let plaintext = cipher
-            .decrypt(nonce, ciphertext)
-            .map_err(|e| NebulaError::Internal(format!("解密失败: {}", e)))?;
+        let plaintext = vault::unseal(&key, &data)?;
 
         // 反序列化
         let cookie: BilibiliCookie = serde_json::from_slice(&plaintext)
@@ -318,23 +428,14 @@ impl BilibiliAuth {
     }
 
     /// 获取或创建加密密钥
-    async fn get_or_create_key(&self) -> Result<[u8; 32]> {
-        let key_path = self.data_dir.join(KEY_FILENAME);
-
-        if key_path.exists() {
-            let key_data = fs::read(&key_path).await?;
-            if key_data.len() == 32 {
-                let mut key = [0u8; 32];
-                key.copy_from_slice(&key_data);
-                return Ok(key);
-            }
+    async fn get_or_create_key(&self) -> Result<vault::MasterKey> {
+        if let Some(key) = self.key_store.load()? {
+            return Ok(key);
         }
 
         // 生成新密钥
-        let mut key = [0u8; 32];
-        rand::rng().fill_bytes(&mut key);
-
-        fs::write(&key_path, &key).await?;
+        let key = vault::generate_key();
+        self.key_store.store(&key)?;
         debug!("生成新的加密密钥");
 
         Ok(key)
@@ -355,6 +456,188 @@ impl BilibiliAuth {
         Ok(())
     }
 
+    /// 检查 Cookie 是否需要刷新，需要时刷新
+    ///
+    /// 服务器确认登录已失效时删除保存的 Cookie 并返回 [`SessionStatus::Expired`]
+    pub async fn refresh_if_needed(&self) -> Result<SessionStatus> {
+        let Some(cookie) = self.load_cookie().await? else {
+            return Ok(SessionStatus::NotLoggedIn);
+        };
+
+        let url = format!(
+            "{}/x/passport-login/web/cookie/info?csrf={}",
+            BILIBILI_PASSPORT_URL, cookie.bili_jct
+        );
+        let response: ApiResponse<CookieInfoData> = self.get_json(&url, &cookie).await?;
+        if response.code == CODE_NOT_LOGGED_IN {
+            return self.expire("Cookie 已失效").await;
+        }
+        let info = Self::api_data(response, "检查 Cookie 状态失败")?;
+        if !info.refresh {
+            debug!("B站 Cookie 无需刷新");
+            return Ok(SessionStatus::Valid);
+        }
+
+        if cookie.refresh_token.is_empty() {
+            // 旧版本登录时没有保存刷新 token，无法刷新
+            warn!("B站 Cookie 需要刷新，但没有刷新 token");
+            return Ok(SessionStatus::Valid);
+        }
+        match self.refresh_cookie(&cookie, info.timestamp).await {
+            Ok(()) => Ok(SessionStatus::Refreshed),
+            Err(NebulaError::LoginExpired(reason)) => self.expire(&reason).await,
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 刷新 Cookie：获取 refresh_csrf、换取新 Cookie，再确认使旧的刷新 token 失效
+    async fn refresh_cookie(&self, cookie: &BilibiliCookie, timestamp: i64) -> Result<()> {
+        info!("刷新 B站 Cookie");
+
+        // 1. 通过 CorrespondPath 页面获取 refresh_csrf
+        let path = correspond_path(timestamp)?;
+        let html = self
+            .client
+            .get(format!("https://www.bilibili.com/correspond/1/{}", path))
+            .header(COOKIE, cookie_header(cookie)?)
+            .send()
+            .await
+            .map_err(|e| NebulaError::NetworkError(e.to_string()))?
+            .text()
+            .await
+            .map_err(|e| NebulaError::NetworkError(e.to_string()))?;
+        let refresh_csrf = parse_refresh_csrf(&html)
+            .ok_or_else(|| NebulaError::Internal("无法获取 refresh_csrf".to_string()))?;
+
+        // 2. 用刷新 token 换取新 Cookie
+        let response = self
+            .client
+            .post(format!(
+                "{}/x/passport-login/web/cookie/refresh",
+                BILIBILI_PASSPORT_URL
+            ))
+            .header(COOKIE, cookie_header(cookie)?)
+            .form(&[
+                ("csrf", cookie.bili_jct.as_str()),
+                ("refresh_csrf", refresh_csrf.as_str()),
+                ("source", "main_web"),
+                ("refresh_token", cookie.refresh_token.as_str()),
+            ])
+            .send()
+            .await
+            .map_err(|e| NebulaError::NetworkError(e.to_string()))?;
+        let cookies: Vec<String> = response
+            .cookies()
+            .map(|c| format!("{}={}", c.name(), c.value()))
+            .collect();
+        let response: ApiResponse<CookieRefreshData> = response
+            .json()
+            .await
+            .map_err(|e| NebulaError::Internal(format!("解析响应失败: {}", e)))?;
+        if is_login_expired(response.code) {
+            return Err(NebulaError::LoginExpired(format!(
+                "刷新 Cookie 失败: {}",
+                response.message
+            )));
+        }
+        let data = Self::api_data(response, "刷新 Cookie 失败")?;
+        let refreshed = self
+            .parse_cookies(&cookies, &data.refresh_token)
+            .ok_or_else(|| NebulaError::Internal("无法解析刷新后的 Cookie".to_string()))?;
+        self.save_cookie(&refreshed).await?;
+
+        // 3. 确认刷新，使旧的刷新 token 失效（失败不影响新 Cookie 使用）
+        let confirm = self
+            .client
+            .post(format!(
+                "{}/x/passport-login/web/confirm/refresh",
+                BILIBILI_PASSPORT_URL
+            ))
+            .header(COOKIE, cookie_header(&refreshed)?)
+            .form(&[
+                ("csrf", refreshed.bili_jct.as_str()),
+                ("refresh_token", cookie.refresh_token.as_str()),
+            ])
+            .send()
+            .await;
+        match confirm {
+            Ok(response) => match response.json::<ApiResponse<serde_json::Value>>().await {
+                Ok(r) if r.code == 0 => {}
+                Ok(r) => warn!("确认刷新 Cookie 失败: {}", r.message),
+                Err(e) => warn!("确认刷新 Cookie 失败: {}", e),
+            },
+            Err(e) => warn!("确认刷新 Cookie 失败: {}", e),
+        }
+
+        info!("B站 Cookie 已刷新");
+        Ok(())
+    }
+
+    /// 获取账号信息，未登录或登录已失效时返回 None
+    pub async fn account_info(&self) -> Result<Option<BilibiliAccount>> {
+        let Some(cookie) = self.load_cookie().await? else {
+            return Ok(None);
+        };
+
+        let url = format!("{}/x/web-interface/nav", BILIBILI_API_URL);
+        let response: ApiResponse<NavData> = self.get_json(&url, &cookie).await?;
+        if response.code == CODE_NOT_LOGGED_IN {
+            return Ok(None);
+        }
+        let nav = Self::api_data(response, "获取账号信息失败")?;
+        if !nav.is_login {
+            return Ok(None);
+        }
+
+        Ok(Some(BilibiliAccount {
+            mid: nav.mid,
+            username: nav.uname,
+            avatar: nav.face,
+            level: nav.level_info.map(|l| l.current_level).unwrap_or(0),
+            vip_active: nav.vip_status == 1,
+            vip_type: nav.vip_type,
+            vip_expires_at: (nav.vip_due_date > 0).then_some(nav.vip_due_date / 1000),
+            vip_label: nav.vip_label.map(|l| l.text).filter(|t| !t.is_empty()),
+        }))
+    }
+
+    /// 携带 Cookie 请求 JSON 接口
+    async fn get_json<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        cookie: &BilibiliCookie,
+    ) -> Result<ApiResponse<T>> {
+        self.client
+            .get(url)
+            .header(COOKIE, cookie_header(cookie)?)
+            .send()
+            .await
+            .map_err(|e| NebulaError::NetworkError(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| NebulaError::Internal(format!("解析响应失败: {}", e)))
+    }
+
+    /// 取出接口数据，`code` 不为 0 时返回错误
+    fn api_data<T>(response: ApiResponse<T>, context: &str) -> Result<T> {
+        if response.code != 0 {
+            return Err(NebulaError::Internal(format!(
+                "{}: {}",
+                context, response.message
+            )));
+        }
+        response
+            .data
+            .ok_or_else(|| NebulaError::Internal(format!("{}: 数据为空", context)))
+    }
+
+    /// 登录已失效：删除保存的 Cookie
+    async fn expire(&self, reason: &str) -> Result<SessionStatus> {
+        warn!("B站登录已失效: {}", reason);
+        self.logout().await?;
+        Ok(SessionStatus::Expired)
+    }
+
     /// 导出 Cookie 为 Netscape 格式 (用于 yt-dlp)
     pub async fn export_cookies_for_ytdlp(&self) -> Result<Option<PathBuf>> {
         let cookie = match self.load_cookie().await? {
@@ -380,10 +663,78 @@ impl BilibiliAuth {
     }
 }
 
+/// 刷新 Cookie 的错误码是否表示登录已失效
+///
+/// 只有 -101 未登录和 86095 刷新 token 失效需要删除 Cookie，
+/// 其他错误（如风控、服务器繁忙）保留 Cookie，下次再尝试刷新
+fn is_login_expired(code: i64) -> bool {
+    matches!(code, CODE_NOT_LOGGED_IN | CODE_REFRESH_TOKEN_INVALID)
+}
+
+/// Cookie 请求头
+fn cookie_header(cookie: &BilibiliCookie) -> Result<HeaderValue> {
+    HeaderValue::from_str(&cookie.header_value())
+        .map_err(|e| NebulaError::Internal(format!("Cookie 格式错误: {}", e)))
+}
+
+/// 生成 CorrespondPath：用 RSA-OAEP (SHA-256) 加密 `refresh_{毫秒时间戳}` 后转为十六进制
+fn correspond_path(timestamp: i64) -> Result<String> {
+    let key = RsaPublicKey::from_public_key_pem(CORRESPOND_PUBLIC_KEY)
+        .map_err(|e| NebulaError::Internal(format!("加载公钥失败: {}", e)))?;
+    let encrypted = key
+        .encrypt(
+            &mut rsa::rand_core::OsRng,
+            Oaep::new::<Sha256>(),
+            format!("refresh_{}", timestamp).as_bytes(),
+        )
+        .map_err(|e| NebulaError::Internal(format!("生成 CorrespondPath 失败: {}", e)))?;
+    Ok(encrypted.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+/// 从 CorrespondPath 页面中取出 `<div id="1-name">` 的内容
+fn parse_refresh_csrf(html: &str) -> Option<String> {
+    let start = html.find(r#"<div id="1-name">"#)? + r#"<div id="1-name">"#.len();
+    let end = html[start..].find("</div>")?;
+    let csrf = html[start..start + end].trim();
+    (!csrf.is_empty()).then(|| csrf.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_cookie_refresh_helpers() {
+        let html =
+            r#"<html><body><div id="1-name">b0cc8411ded2f9db2cff2edb3123acac</div></body></html>"#;
+        assert_eq!(
+            parse_refresh_csrf(html).as_deref(),
+            Some("b0cc8411ded2f9db2cff2edb3123acac")
+        );
+        assert_eq!(parse_refresh_csrf("<div id=\"1-name\"></div>"), None);
+
+        assert!(is_login_expired(-101));
+        assert!(is_login_expired(86095));
+        assert!(!is_login_expired(-400));
+        assert!(!is_login_expired(-412));
+
+        // 1024 位 RSA 密钥加密结果为 128 字节
+        let path = correspond_path(1684466082008).unwrap();
+        assert_eq!(path.len(), 256);
+        assert!(path.chars().all(|c| c.is_ascii_hexdigit()));
+
+        // 旧版本保存的 Cookie 没有刷新 token
+        let cookie: BilibiliCookie = serde_json::from_str(
+            r#"{"sessdata":"s","bili_jct":"j","dede_user_id":"1","created_at":0}"#,
+        )
+        .unwrap();
+        assert!(cookie.refresh_token.is_empty());
+        assert_eq!(
+            cookie.header_value(),
+            "SESSDATA=s; bili_jct=j; DedeUserID=1"
+        );
+    }
+
     #[tokio::test]
     async fn test_auth_manager_creation() {
         let auth = BilibiliAuth::new(PathBuf::from("/tmp/test_bilibili"));
@@ -0,0 +1,1374 @@
+//! Bilibili 原生视频下载
+//!
+//! 不依赖 yt-dlp 直接下载 Bilibili 视频：
+//! - 解析 BV/av 号、番剧 ep/ss 链接和 b23.tv 短链接
+//! - 使用已登录的 [`BilibiliCookie`](super::bilibili::BilibiliCookie) 调用 playurl 接口（WBI 签名）
+//! - 按画质偏好选择 DASH 视频流和音频流（大会员可选 4K/HDR/杜比视界）
+//! - 通过 HTTP 引擎分段下载，已完成的分段记录在旁路文件中，中断后可继续
+//! - 使用 ffmpeg 合并音视频或转换音频
+
+use super::bilibili::BilibiliAuth;
+use super::http::HttpHandler;
+use super::video::{VideoFormat, VideoInfo, LOUDNORM_FILTER};
+use crate::error::{NebulaError, Result};
+use crate::task::{AudioExtraction, AudioFormat, FormatPreference, VideoCodec, VideoContainer};
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use futures::StreamExt;
+use md5::{Digest, Md5};
+use reqwest::header::{HeaderMap, HeaderValue, COOKIE, REFERER, USER_AGENT};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::io::Read;
+use std::path::Path;
+use std::process::Stdio;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::fs::{self, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tokio::sync::Mutex;
+use tracing::{debug, info, warn};
+
+/// B站 API 基础 URL
+const BILIBILI_API_URL: &str = "https://api.bilibili.com";
+
+/// 请求 CDN 时必须携带的 Referer
+const BILIBILI_REFERER: &str = "https://www.bilibili.com/";
+
+/// 浏览器 User-Agent（API 和 CDN 会拒绝非浏览器请求）
+const BROWSER_USER_AGENT: &str =
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0 Safari/537.36";
+
+/// playurl 请求的流格式：DASH + HDR + 4K + 杜比音频 + 杜比视界 + 8K + AV1
+const FNVAL_ALL_DASH: u32 = 4048;
+
+/// 请求的最高画质（8K），实际画质由账号权限决定
+const QN_MAX: u32 = 127;
+
+/// HDR 真彩画质
+const QN_HDR: u32 = 125;
+
+/// 杜比视界画质
+const QN_DOLBY_VISION: u32 = 126;
+
+/// Hi-Res 无损音频流 ID
+const AUDIO_ID_FLAC: u32 = 30251;
+
+/// 杜比全景声音频流 ID
+const AUDIO_ID_DOLBY: u32 = 30250;
+
+/// WBI 签名的混淆表
+const MIXIN_KEY_ENC_TAB: [usize; 64] = [
+    46, 47, 18, 2, 53, 8, 23, 32, 15, 50, 10, 31, 58, 3, 45, 35, 27, 43, 5, 49, 33, 9, 42, 19, 29,
+    28, 14, 39, 12, 38, 41, 13, 37, 48, 7, 16, 24, 55, 40, 61, 26, 17, 0, 1, 60, 51, 30, 4, 22, 25,
+    54, 21, 56, 59, 6, 63, 57, 62, 11, 36, 20, 34, 44, 52,
+];
+
+/// 单个分段的最大尝试次数（依次使用主地址和备用地址）
+const CHUNK_ATTEMPTS: usize = 3;
+
+/// 分段下载的最小分段大小
+const MIN_CHUNK_SIZE: u64 = 1024 * 1024;
+
+/// 记录已完成分段的旁路文件后缀
+const CHUNKS_SUFFIX: &str = ".chunks";
+
+/// B站链接
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BilibiliLink {
+    /// 普通视频（BV 号或 av 号），`page` 为分 P 序号（从 1 开始）
+    Video { id: VideoRef, page: u32 },
+    /// 番剧、影视的单集（ep 号）
+    Episode(u64),
+    /// 番剧、影视的整季（ss 号），下载第一集
+    Season(u64),
+    /// b23.tv 短链接，需要跟随跳转后再解析
+    Short(String),
+}
+
+/// 视频编号
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VideoRef {
+    Bvid(String),
+    Aid(u64),
+}
+
+impl BilibiliLink {
+    /// 解析 B站链接，不是可下载的视频链接时返回 None
+    pub fn parse(url: &str) -> Option<Self> {
+        let parsed = url::Url::parse(url.trim()).ok()?;
+        let host = parsed.host_str()?.to_lowercase();
+        if host == "b23.tv" || host.ends_with(".b23.tv") {
+            return Some(Self::Short(url.trim().to_string()));
+        }
+        if host != "bilibili.com" && !host.ends_with(".bilibili.com") {
+            return None;
+        }
+
+        let page = parsed
+            .query_pairs()
+            .find(|(k, _)| k == "p")
+            .and_then(|(_, v)| v.parse().ok())
+            .filter(|&p| p > 0)
+            .unwrap_or(1);
+        let segments: Vec<&str> = parsed
+            .path_segments()
+            .map(|s| s.filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default();
+
+        match segments.as_slice() {
+            ["video", id, ..] => {
+                let id = VideoRef::parse(id)?;
+                Some(Self::Video { id, page })
+            }
+            ["bangumi", "play", id, ..] => {
+                let lower = id.to_lowercase();
+                if let Some(ep) = lower.strip_prefix("ep") {
+                    ep.parse().ok().map(Self::Episode)
+                } else if let Some(ss) = lower.strip_prefix("ss") {
+                    ss.parse().ok().map(Self::Season)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl VideoRef {
+    /// 解析 `BV1xx411c7mD` 或 `av170001`
+    fn parse(id: &str) -> Option<Self> {
+        if id.len() == 12 && id[..2].eq_ignore_ascii_case("bv") {
+            return Some(Self::Bvid(format!("BV{}", &id[2..])));
+        }
+        let aid = id.strip_prefix("av").or_else(|| id.strip_prefix("AV"))?;
+        aid.parse().ok().map(Self::Aid)
+    }
+
+    /// 作为 API 查询参数
+    fn query(&self) -> (&'static str, String) {
+        match self {
+            Self::Bvid(bvid) => ("bvid", bvid.clone()),
+            Self::Aid(aid) => ("aid", aid.to_string()),
+        }
+    }
+}
+
+/// 解析后的视频（普通视频的一个分 P 或番剧的一集）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BilibiliVideo {
+    pub bvid: String,
+    pub aid: u64,
+    pub cid: u64,
+    /// 番剧单集 ID（普通视频为 None）
+    pub ep_id: Option<u64>,
+    pub title: String,
+    pub uploader: Option<String>,
+    pub thumbnail: Option<String>,
+    pub description: Option<String>,
+    /// 时长（秒）
+    pub duration: Option<u64>,
+    /// 发布时间（Unix 时间戳）
+    pub pubdate: Option<i64>,
+    /// 分 P 序号（从 1 开始）
+    pub page: u32,
+    /// 分 P 总数
+    pub page_count: u32,
+}
+
+impl BilibiliVideo {
+    /// 视频 ID，多 P 视频带分 P 后缀（与 yt-dlp 一致）
+    pub fn id(&self) -> String {
+        match self.ep_id {
+            Some(ep) => format!("ep{}", ep),
+            None if self.page > 1 => format!("{}_p{}", self.bvid, self.page),
+            None => self.bvid.clone(),
+        }
+    }
+
+    /// 转换为通用的视频信息
+    pub fn to_video_info(&self, play: &PlayUrl, url: &str) -> VideoInfo {
+        let upload_date = self
+            .pubdate
+            .and_then(|ts| Utc.timestamp_opt(ts, 0).single())
+            .map(|at| at.format("%Y%m%d").to_string());
+        let resolution = select_video(play, None, &FormatPreference::default())
+            .map(|stream| format!("{}x{}", stream.width, stream.height));
+        VideoInfo {
+            id: self.id(),
+            title: self.title.clone(),
+            description: self.description.clone(),
+            thumbnail: self.thumbnail.clone(),
+            duration: self.duration,
+            uploader: self.uploader.clone(),
+            formats: play.formats(),
+            webpage_url: url.to_string(),
+            upload_date,
+            resolution,
+            playlist_index: (self.page_count > 1).then_some(self.page as usize),
+        }
+    }
+}
+
+/// DASH 流
+#[derive(Debug, Clone, PartialEq)]
+pub struct DashStream {
+    /// 画质（视频流为 qn，音频流为 302xx）
+    pub id: u32,
+    /// 主地址和备用地址
+    pub urls: Vec<String>,
+    /// 码率（bps）
+    pub bandwidth: u64,
+    /// 编码 ID：7 AVC、12 HEVC、13 AV1
+    pub codec_id: u32,
+    /// 编码字符串，如 `avc1.640032`
+    pub codecs: String,
+    pub width: u32,
+    pub height: u32,
+    pub frame_rate: Option<f64>,
+}
+
+impl DashStream {
+    /// 视频编码
+    pub fn codec(&self) -> Option<VideoCodec> {
+        match self.codec_id {
+            7 => Some(VideoCodec::H264),
+            12 => Some(VideoCodec::H265),
+            13 => Some(VideoCodec::Av1),
+            _ => None,
+        }
+    }
+
+    /// 视频流的格式 ID，如 `80-h264`
+    pub fn format_id(&self) -> String {
+        match self.codec() {
+            Some(codec) => format!("{}-{}", self.id, codec.name()),
+            None => self.id.to_string(),
+        }
+    }
+}
+
+/// playurl 接口返回的可用流
+#[derive(Debug, Clone, Default)]
+pub struct PlayUrl {
+    /// 视频流（按画质、编码区分）
+    pub video: Vec<DashStream>,
+    /// 音频流（包括杜比全景声和 Hi-Res 无损）
+    pub audio: Vec<DashStream>,
+}
+
+impl PlayUrl {
+    /// 转换为通用的格式列表
+    pub fn formats(&self) -> Vec<VideoFormat> {
+        let videos = self.video.iter().map(|stream| VideoFormat {
+            format_id: stream.format_id(),
+            ext: "mp4".to_string(),
+            resolution: Some(format!("{}x{}", stream.width, stream.height)),
+            filesize: None,
+            vcodec: Some(stream.codecs.clone()),
+            acodec: Some("none".to_string()),
+            format_note: Some(quality_name(stream.id).to_string()),
+            fps: stream.frame_rate,
+            tbr: Some(stream.bandwidth as f64 / 1000.0),
+            dynamic_range: Some(
+                match stream.id {
+                    QN_HDR => "HDR10",
+                    QN_DOLBY_VISION => "DV",
+                    _ => "SDR",
+                }
+                .to_string(),
+            ),
+            language: None,
+            audio_only: false,
+            video_only: true,
+        });
+        let audios = self.audio.iter().map(|stream| VideoFormat {
+            format_id: stream.id.to_string(),
+            ext: "m4a".to_string(),
+            resolution: None,
+            filesize: None,
+            vcodec: Some("none".to_string()),
+            acodec: Some(stream.codecs.clone()),
+            format_note: Some(quality_name(stream.id).to_string()),
+            fps: None,
+            tbr: Some(stream.bandwidth as f64 / 1000.0),
+            dynamic_range: None,
+            language: None,
+            audio_only: true,
+            video_only: false,
+        });
+        videos.chain(audios).collect()
+    }
+
+    /// 查找与 `stream` 画质和编码相同的流，用于地址过期后换用新地址
+    pub fn matching(&self, stream: &DashStream) -> Option<&DashStream> {
+        self.video
+            .iter()
+            .chain(&self.audio)
+            .find(|s| s.id == stream.id && s.codec_id == stream.codec_id)
+    }
+}
+
+/// DASH 流地址是否已过期（CDN 对过期的签名地址返回 403 或 404）
+pub fn is_url_expired(error: &NebulaError) -> bool {
+    matches!(
+        error,
+        NebulaError::HttpError {
+            status_code: 403 | 404,
+            ..
+        }
+    )
+}
+
+/// 画质名称
+pub fn quality_name(id: u32) -> &'static str {
+    match id {
+        127 => "8K 超高清",
+        126 => "杜比视界",
+        125 => "HDR 真彩",
+        120 => "4K 超清",
+        116 => "1080P 60帧",
+        112 => "1080P 高码率",
+        80 => "1080P 高清",
+        74 => "720P 60帧",
+        64 => "720P 高清",
+        32 => "480P 清晰",
+        16 => "360P 流畅",
+        6 => "240P 极速",
+        30216 => "64K",
+        30232 => "132K",
+        30280 => "192K",
+        AUDIO_ID_DOLBY => "杜比全景声",
+        AUDIO_ID_FLAC => "Hi-Res 无损",
+        _ => "未知画质",
+    }
+}
+
+/// 选择视频流
+///
+/// `format_id` 为 `80` 或 `80-h264` 形式时优先选择对应的流；否则按偏好过滤分辨率、帧率，
+/// 未开启 HDR 时排除 HDR 和杜比视界，取画质最高的流，同画质优先偏好的编码。
+/// 没有满足条件的流时取画质最低的流
+pub fn select_video<'a>(
+    play: &'a PlayUrl,
+    format_id: Option<&str>,
+    pref: &FormatPreference,
+) -> Option<&'a DashStream> {
+    if let Some((qn, codec)) = format_id.and_then(parse_format_id) {
+        let exact = play
+            .video
+            .iter()
+            .filter(|s| s.id == qn)
+            .max_by_key(|s| (codec.is_none() || s.codec() == codec, s.bandwidth));
+        if exact.is_some() {
+            return exact;
+        }
+    }
+
+    play.video
+        .iter()
+        .filter(|s| pref.max_height.is_none_or(|h| s.height.min(s.width) <= h))
+        .filter(|s| {
+            pref.max_fps
+                .is_none_or(|fps| s.frame_rate.is_none_or(|f| f <= fps as f64 + 0.5))
+        })
+        .filter(|s| pref.prefer_hdr || !matches!(s.id, QN_HDR | QN_DOLBY_VISION))
+        .max_by_key(|s| {
+            let codec_rank = match (s.codec(), pref.video_codec) {
+                (Some(codec), Some(preferred)) if codec == preferred => 2,
+                (Some(_), _) => 1,
+                (None, _) => 0,
+            };
+            (s.id, codec_rank, s.bandwidth)
+        })
+        .or_else(|| play.video.iter().min_by_key(|s| (s.id, s.bandwidth)))
+}
+
+/// 选择音频流，取码率最高的流
+///
+/// 杜比全景声和 Hi-Res 无损只在容器支持时选择
+pub fn select_audio(play: &PlayUrl, allow_dolby: bool, allow_flac: bool) -> Option<&DashStream> {
+    play.audio
+        .iter()
+        .filter(|s| allow_dolby || s.id != AUDIO_ID_DOLBY)
+        .filter(|s| allow_flac || s.id != AUDIO_ID_FLAC)
+        .max_by_key(|s| s.bandwidth)
+}
+
+/// 根据格式 ID 选择音频流，`format_id` 不是音频流 ID 时返回 None
+pub fn audio_by_format_id<'a>(play: &'a PlayUrl, format_id: &str) -> Option<&'a DashStream> {
+    let id: u32 = format_id.parse().ok()?;
+    play.audio.iter().find(|s| s.id == id)
+}
+
+/// 解析 `80` 或 `80-h264` 形式的格式 ID
+fn parse_format_id(format_id: &str) -> Option<(u32, Option<VideoCodec>)> {
+    let (qn, codec) = match format_id.split_once('-') {
+        Some((qn, codec)) => (qn, Some(VideoCodec::from_name(codec)?)),
+        None => (format_id, None),
+    };
+    let qn: u32 = qn.parse().ok()?;
+    // 音频流 ID 不是视频画质
+    (qn < 30000).then_some((qn, codec))
+}
+
+/// 合并输出的容器扩展名（WebM 无法容纳 B站的编码，改用 MKV）
+pub fn container_extension(container: VideoContainer) -> &'static str {
+    match container {
+        VideoContainer::Webm => VideoContainer::Mkv.extension(),
+        other => other.extension(),
+    }
+}
+
+// ===== API 客户端 =====
+
+/// 接口通用响应（番剧接口的数据字段为 `result`）
+#[derive(Debug, Deserialize)]
+struct ApiResponse<T> {
+    code: i64,
+    #[serde(default)]
+    message: String,
+    #[serde(alias = "result")]
+    data: Option<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ViewData {
+    bvid: String,
+    aid: u64,
+    title: String,
+    pic: Option<String>,
+    desc: Option<String>,
+    pubdate: Option<i64>,
+    owner: Option<Owner>,
+    #[serde(default)]
+    pages: Vec<PageData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Owner {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PageData {
+    cid: u64,
+    page: u32,
+    #[serde(default)]
+    part: String,
+    duration: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SeasonData {
+    title: String,
+    cover: Option<String>,
+    evaluate: Option<String>,
+    up_info: Option<UpInfo>,
+    #[serde(default)]
+    episodes: Vec<EpisodeData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpInfo {
+    uname: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct EpisodeData {
+    id: u64,
+    aid: u64,
+    #[serde(default)]
+    bvid: String,
+    cid: u64,
+    #[serde(default)]
+    title: String,
+    long_title: Option<String>,
+    cover: Option<String>,
+    /// 时长（毫秒）
+    duration: Option<u64>,
+    pub_time: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NavData {
+    wbi_img: WbiImg,
+}
+
+#[derive(Debug, Deserialize)]
+struct WbiImg {
+    img_url: String,
+    sub_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PlayUrlData {
+    dash: Option<DashData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DashData {
+    #[serde(default)]
+    video: Vec<RawStream>,
+    audio: Option<Vec<RawStream>>,
+    dolby: Option<DolbyData>,
+    flac: Option<FlacData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DolbyData {
+    audio: Option<Vec<RawStream>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FlacData {
+    audio: Option<RawStream>,
+}
+
+/// 接口返回的 DASH 流（同一字段同时有驼峰和下划线两种写法）
+#[derive(Debug, Deserialize)]
+struct RawStream {
+    id: u32,
+    #[serde(rename = "baseUrl")]
+    base_url_camel: Option<String>,
+    #[serde(rename = "base_url")]
+    base_url_snake: Option<String>,
+    #[serde(rename = "backupUrl")]
+    backup_url_camel: Option<Vec<String>>,
+    #[serde(rename = "backup_url")]
+    backup_url_snake: Option<Vec<String>>,
+    #[serde(default)]
+    bandwidth: u64,
+    #[serde(default)]
+    codecid: u32,
+    #[serde(default)]
+    codecs: String,
+    #[serde(default)]
+    width: u32,
+    #[serde(default)]
+    height: u32,
+    #[serde(rename = "frameRate")]
+    frame_rate_camel: Option<String>,
+    #[serde(rename = "frame_rate")]
+    frame_rate_snake: Option<String>,
+}
+
+impl From<RawStream> for DashStream {
+    fn from(raw: RawStream) -> Self {
+        let mut urls: Vec<String> = raw
+            .base_url_camel
+            .or(raw.base_url_snake)
+            .into_iter()
+            .collect();
+        urls.extend(
+            raw.backup_url_camel
+                .or(raw.backup_url_snake)
+                .unwrap_or_default(),
+        );
+        let frame_rate = raw
+            .frame_rate_camel
+            .or(raw.frame_rate_snake)
+            .and_then(|rate| parse_frame_rate(&rate));
+        Self {
+            id: raw.id,
+            urls,
+            bandwidth: raw.bandwidth,
+            codec_id: raw.codecid,
+            codecs: raw.codecs,
+            width: raw.width,
+            height: raw.height,
+            frame_rate,
+        }
+    }
+}
+
+impl From<DashData> for PlayUrl {
+    fn from(dash: DashData) -> Self {
+        let mut audio: Vec<DashStream> = dash
+            .audio
+            .unwrap_or_default()
+            .into_iter()
+            .map(DashStream::from)
+            .collect();
+        if let Some(dolby) = dash.dolby.and_then(|d| d.audio) {
+            audio.extend(dolby.into_iter().map(DashStream::from));
+        }
+        if let Some(flac) = dash.flac.and_then(|f| f.audio) {
+            audio.push(flac.into());
+        }
+        Self {
+            video: dash.video.into_iter().map(DashStream::from).collect(),
+            audio,
+        }
+    }
+}
+
+/// 解析帧率：`29.970` 或 `30000/1001`
+fn parse_frame_rate(rate: &str) -> Option<f64> {
+    match rate.split_once('/') {
+        Some((num, den)) => {
+            let den: f64 = den.parse().ok()?;
+            (den > 0.0).then_some(num.parse::<f64>().ok()? / den)
+        }
+        None => rate.parse().ok(),
+    }
+}
+
+/// Bilibili API 客户端
+pub struct BilibiliClient {
+    /// HTTP 客户端
+    client: reqwest::Client,
+    /// 认证管理器（已登录时携带 Cookie）
+    auth: Option<Arc<BilibiliAuth>>,
+    /// WBI 混淆密钥及其获取日期（每天更新）
+    wbi_key: Mutex<Option<(String, NaiveDate)>>,
+}
+
+impl BilibiliClient {
+    /// 创建新的客户端
+    pub fn new(auth: Option<Arc<BilibiliAuth>>) -> Self {
+        let client = reqwest::Client::builder()
+            .user_agent(BROWSER_USER_AGENT)
+            .build()
+            .unwrap_or_default();
+        Self {
+            client,
+            auth,
+            wbi_key: Mutex::new(None),
+        }
+    }
+
+    /// 请求 API 和 CDN 时携带的请求头（Referer、User-Agent 和登录 Cookie）
+    pub async fn headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(REFERER, HeaderValue::from_static(BILIBILI_REFERER));
+        headers.insert(USER_AGENT, HeaderValue::from_static(BROWSER_USER_AGENT));
+        if let Some(auth) = &self.auth {
+            if let Ok(Some(cookie)) = auth.load_cookie().await {
+                if let Ok(value) = HeaderValue::from_str(&cookie.header_value()) {
+                    headers.insert(COOKIE, value);
+                }
+            }
+        }
+        headers
+    }
+
+    /// 请求 JSON 接口，`code` 不为 0 时返回错误
+    async fn get_json<T: DeserializeOwned>(&self, url: &str, query: &str) -> Result<T> {
+        let full_url = if query.is_empty() {
+            url.to_string()
+        } else {
+            format!("{}?{}", url, query)
+        };
+        debug!("请求 B站接口: {}", full_url);
+
+        let response: ApiResponse<T> = self
+            .client
+            .get(&full_url)
+            .headers(self.headers().await)
+            .send()
+            .await
+            .map_err(|e| NebulaError::NetworkError(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| NebulaError::Internal(format!("解析 B站接口响应失败: {}", e)))?;
+
+        if response.code != 0 {
+            return Err(NebulaError::Internal(format!(
+                "B站接口返回错误 {}: {}",
+                response.code, response.message
+            )));
+        }
+        response
+            .data
+            .ok_or_else(|| NebulaError::Internal("B站接口返回数据为空".to_string()))
+    }
+
+    /// 解析链接对应的视频
+    pub async fn resolve(&self, url: &str) -> Result<BilibiliVideo> {
+        let link = BilibiliLink::parse(url)
+            .ok_or_else(|| NebulaError::InvalidUrl(format!("不是 B站视频链接: {}", url)))?;
+        let link = match link {
+            BilibiliLink::Short(short) => {
+                // 跟随 b23.tv 的跳转得到完整链接
+                let response = self
+                    .client
+                    .get(&short)
+                    .send()
+                    .await
+                    .map_err(|e| NebulaError::NetworkError(e.to_string()))?;
+                let target = response.url().to_string();
+                debug!("短链接 {} 跳转到 {}", short, target);
+                match BilibiliLink::parse(&target) {
+                    Some(BilibiliLink::Short(_)) | None => {
+                        return Err(NebulaError::InvalidUrl(format!(
+                            "短链接没有指向 B站视频: {}",
+                            target
+                        )))
+                    }
+                    Some(link) => link,
+                }
+            }
+            link => link,
+        };
+
+        match link {
+            BilibiliLink::Video { id, page } => self.resolve_video(&id, page).await,
+            BilibiliLink::Episode(ep_id) => {
+                self.resolve_episode(&format!("ep_id={}", ep_id), Some(ep_id))
+                    .await
+            }
+            BilibiliLink::Season(season_id) => {
+                self.resolve_episode(&format!("season_id={}", season_id), None)
+                    .await
+            }
+            BilibiliLink::Short(_) => unreachable!("短链接已在上面展开"),
+        }
+    }
+
+    /// 获取普通视频的信息
+    async fn resolve_video(&self, id: &VideoRef, page: u32) -> Result<BilibiliVideo> {
+        let (key, value) = id.query();
+        let view: ViewData = self
+            .get_json(
+                &format!("{}/x/web-interface/view", BILIBILI_API_URL),
+                &format!("{}={}", key, value),
+            )
+            .await?;
+
+        let page_count = view.pages.len() as u32;
+        let part = view
+            .pages
+            .iter()
+            .find(|p| p.page == page)
+            .or_else(|| view.pages.first())
+            .ok_or_else(|| NebulaError::Internal(format!("视频没有可下载的分 P: {}", view.bvid)))?;
+
+        // 多 P 视频的标题带上分 P 名称
+        let title = if page_count > 1 && !part.part.is_empty() {
+            format!("{} - P{} {}", view.title, part.page, part.part)
+        } else {
+            view.title
+        };
+        Ok(BilibiliVideo {
+            bvid: view.bvid,
+            aid: view.aid,
+            cid: part.cid,
+            ep_id: None,
+            title,
+            uploader: view.owner.map(|o| o.name),
+            thumbnail: view.pic,
+            description: view.desc.filter(|d| !d.is_empty()),
+            duration: part.duration,
+            pubdate: view.pubdate,
+            page: part.page,
+            page_count,
+        })
+    }
+
+    /// 获取番剧单集的信息，未指定单集时取第一集
+    async fn resolve_episode(&self, query: &str, ep_id: Option<u64>) -> Result<BilibiliVideo> {
+        let season: SeasonData = self
+            .get_json(&format!("{}/pgc/view/web/season", BILIBILI_API_URL), query)
+            .await?;
+
+        let (index, episode) = season
+            .episodes
+            .iter()
+            .enumerate()
+            .find(|(_, ep)| ep_id.is_none_or(|id| ep.id == id))
+            .ok_or_else(|| NebulaError::Internal(format!("没有找到剧集: {}", query)))?;
+
+        let number = match episode.title.parse::<u32>() {
+            Ok(n) => format!("第{}话", n),
+            Err(_) => episode.title.clone(),
+        };
+        let title = [
+            season.title.as_str(),
+            number.as_str(),
+            episode.long_title.as_deref().unwrap_or(""),
+        ]
+        .iter()
+        .filter(|s| !s.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+        Ok(BilibiliVideo {
+            bvid: episode.bvid.clone(),
+            aid: episode.aid,
+            cid: episode.cid,
+            ep_id: Some(episode.id),
+            title,
+            uploader: season.up_info.map(|up| up.uname),
+            thumbnail: episode.cover.clone().or(season.cover),
+            description: season.evaluate.filter(|d| !d.is_empty()),
+            duration: episode.duration.map(|ms| ms / 1000),
+            pubdate: episode.pub_time,
+            page: index as u32 + 1,
+            page_count: season.episodes.len() as u32,
+        })
+    }
+
+    /// 获取可用的 DASH 流
+    pub async fn play_url(&self, video: &BilibiliVideo) -> Result<PlayUrl> {
+        let data: PlayUrlData = match video.ep_id {
+            Some(ep_id) => {
+                let query = format!(
+                    "ep_id={}&cid={}&qn={}&fnval={}&fnver=0&fourk=1",
+                    ep_id, video.cid, QN_MAX, FNVAL_ALL_DASH
+                );
+                self.get_json(
+                    &format!("{}/pgc/player/web/playurl", BILIBILI_API_URL),
+                    &query,
+                )
+                .await?
+            }
+            None => {
+                let params = [
+                    ("bvid", video.bvid.clone()),
+                    ("cid", video.cid.to_string()),
+                    ("qn", QN_MAX.to_string()),
+                    ("fnval", FNVAL_ALL_DASH.to_string()),
+                    ("fnver", "0".to_string()),
+                    ("fourk", "1".to_string()),
+                ];
+                let mixin_key = self.wbi_mixin_key().await?;
+                let query = sign_wbi(&params, &mixin_key, Utc::now().timestamp());
+                self.get_json(
+                    &format!("{}/x/player/wbi/playurl", BILIBILI_API_URL),
+                    &query,
+                )
+                .await?
+            }
+        };
+
+        let play: PlayUrl = data
+            .dash
+            .ok_or_else(|| {
+                NebulaError::Internal("该视频没有 DASH 流，可能需要大会员或已下架".to_string())
+            })?
+            .into();
+        if play.video.is_empty() && play.audio.is_empty() {
+            return Err(NebulaError::Internal("没有可下载的视频流".to_string()));
+        }
+        Ok(play)
+    }
+
+    /// 获取 WBI 混淆密钥（每天更新一次）
+    async fn wbi_mixin_key(&self) -> Result<String> {
+        let today = Utc::now().date_naive();
+        let mut cached = self.wbi_key.lock().await;
+        if let Some((key, date)) = cached.as_ref() {
+            if *date == today {
+                return Ok(key.clone());
+            }
+        }
+
+        // 未登录时 nav 接口的 code 为 -101，但仍返回 wbi_img
+        let response: ApiResponse<NavData> = self
+            .client
+            .get(format!("{}/x/web-interface/nav", BILIBILI_API_URL))
+            .headers(self.headers().await)
+            .send()
+            .await
+            .map_err(|e| NebulaError::NetworkError(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| NebulaError::Internal(format!("解析 WBI 密钥失败: {}", e)))?;
+        let wbi = response
+            .data
+            .ok_or_else(|| NebulaError::Internal("获取 WBI 密钥失败".to_string()))?
+            .wbi_img;
+
+        let key = mixin_key(&key_from_url(&wbi.img_url), &key_from_url(&wbi.sub_url));
+        *cached = Some((key.clone(), today));
+        Ok(key)
+    }
+
+    /// 下载弹幕 XML
+    pub async fn danmaku_xml(&self, cid: u64) -> Result<Vec<u8>> {
+        let url = format!("https://comment.bilibili.com/{}.xml", cid);
+        let bytes = self
+            .client
+            .get(&url)
+            .headers(self.headers().await)
+            .send()
+            .await
+            .map_err(|e| NebulaError::NetworkError(e.to_string()))?
+            .bytes()
+            .await
+            .map_err(|e| NebulaError::NetworkError(e.to_string()))?;
+
+        // 弹幕接口返回未声明编码的 raw deflate 数据
+        if bytes.trim_ascii_start().starts_with(b"<") {
+            return Ok(bytes.to_vec());
+        }
+        let mut xml = Vec::new();
+        flate2::read::DeflateDecoder::new(bytes.as_ref())
+            .read_to_end(&mut xml)
+            .map_err(|e| NebulaError::Internal(format!("解压弹幕失败: {}", e)))?;
+        Ok(xml)
+    }
+
+    /// 下载封面图片
+    pub async fn fetch_bytes(&self, url: &str) -> Result<Vec<u8>> {
+        let response = self
+            .client
+            .get(url)
+            .headers(self.headers().await)
+            .send()
+            .await
+            .map_err(|e| NebulaError::NetworkError(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(NebulaError::HttpError {
+                status_code: response.status().as_u16(),
+                message: format!("下载失败: {}", url),
+            });
+        }
+        Ok(response
+            .bytes()
+            .await
+            .map_err(|e| NebulaError::NetworkError(e.to_string()))?
+            .to_vec())
+    }
+}
+
+// ===== WBI 签名 =====
+
+/// 从 `https://i0.hdslb.com/bfs/wbi/<key>.png` 中取出密钥
+fn key_from_url(url: &str) -> String {
+    let file = url.rsplit('/').next().unwrap_or_default();
+    file.split('.').next().unwrap_or_default().to_string()
+}
+
+/// 按混淆表打乱 img_key + sub_key，取前 32 位
+fn mixin_key(img_key: &str, sub_key: &str) -> String {
+    let raw: Vec<char> = format!("{}{}", img_key, sub_key).chars().collect();
+    MIXIN_KEY_ENC_TAB
+        .iter()
+        .filter_map(|&i| raw.get(i))
+        .take(32)
+        .collect()
+}
+
+/// 对查询参数进行 WBI 签名，返回带 `w_rid` 的查询字符串
+///
+/// 参数加入 `wts` 后按键名排序，值中去掉 `!'()*`，编码后拼接混淆密钥计算 MD5
+fn sign_wbi(params: &[(&str, String)], mixin_key: &str, wts: i64) -> String {
+    let mut params: Vec<(&str, String)> = params
+        .iter()
+        .map(|(k, v)| (*k, v.chars().filter(|c| !"!'()*".contains(*c)).collect()))
+        .collect();
+    params.push(("wts", wts.to_string()));
+    params.sort_by(|a, b| a.0.cmp(b.0));
+
+    let query = params
+        .iter()
+        .map(|(k, v)| format!("{}={}", encode_component(k), encode_component(v)))
+        .collect::<Vec<_>>()
+        .join("&");
+    let digest = Md5::digest(format!("{}{}", query, mixin_key).as_bytes());
+    let w_rid: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}&w_rid={}", query, w_rid)
+}
+
+/// 百分号编码（与 JavaScript 的 `encodeURIComponent` 一致，空格编码为 `%20`）
+fn encode_component(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+// ===== 分段下载与合并 =====
+
+/// 分段下载一个 DASH 流到 `path`
+///
+/// 已完成的分段记录在 `<path>.chunks` 中，暂停（丢弃该 future）或程序退出后再次调用时
+/// 只下载剩余分段。每完成一个分段把其大小累加到 `downloaded`
+pub async fn fetch_stream(
+    http: &HttpHandler,
+    stream: &DashStream,
+    size: u64,
+    path: &Path,
+    headers: &HeaderMap,
+    downloaded: &AtomicU64,
+) -> Result<()> {
+    let chunk_size = http.config().chunk_size.max(MIN_CHUNK_SIZE);
+    let connections = http.config().max_connections_per_file.max(1);
+    let chunk_count = size.div_ceil(chunk_size);
+    let chunk_range = |index: u64| index * chunk_size..((index + 1) * chunk_size).min(size);
+
+    let sidecar = chunks_path(path);
+    let mut done = HashSet::new();
+    if path.exists() {
+        match fs::read_to_string(&sidecar).await {
+            Ok(text) => done.extend(
+                text.lines()
+                    .filter_map(|line| line.trim().parse::<u64>().ok()),
+            ),
+            // 没有记录且文件大小正确说明上次已经下载完成
+            Err(_) => {
+                let len = fs::metadata(path).await.map(|m| m.len()).unwrap_or(0);
+                if len == size {
+                    downloaded.fetch_add(size, Ordering::Relaxed);
+                    return Ok(());
+                }
+            }
+        }
+    } else {
+        let _ = fs::remove_file(&sidecar).await;
+    }
+
+    let already: u64 = done
+        .iter()
+        .filter(|&&i| i < chunk_count)
+        .map(|&i| chunk_range(i).end - chunk_range(i).start)
+        .sum();
+    downloaded.fetch_add(already, Ordering::Relaxed);
+
+    let record = Mutex::new(
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&sidecar)
+            .await?,
+    );
+    let pending: Vec<u64> = (0..chunk_count).filter(|i| !done.contains(i)).collect();
+    debug!(
+        "下载 DASH 流: {:?} ({} 个分段，剩余 {} 个)",
+        path,
+        chunk_count,
+        pending.len()
+    );
+
+    let mut results = futures::stream::iter(pending)
+        .map(|index| {
+            let range = chunk_range(index);
+            let record = &record;
+            async move {
+                let mut last_error = None;
+                for url in stream
+                    .urls
+                    .iter()
+                    .cycle()
+                    .take(stream.urls.len() * CHUNK_ATTEMPTS)
+                {
+                    match http
+                        .fetch_range_with_headers(url, range.clone(), path, range.start, headers)
+                        .await
+                    {
+                        Ok(written) => {
+                            downloaded.fetch_add(written, Ordering::Relaxed);
+                            record
+                                .lock()
+                                .await
+                                .write_all(format!("{}\n", index).as_bytes())
+                                .await?;
+                            return Ok(());
+                        }
+                        Err(e) => {
+                            debug!("分段 {} 下载失败，尝试下一个地址: {}", index, e);
+                            last_error = Some(e);
+                        }
+                    }
+                }
+                Err(last_error
+                    .unwrap_or_else(|| NebulaError::Internal("DASH 流没有可用地址".to_string())))
+            }
+        })
+        .buffer_unordered(connections);
+
+    while let Some(result) = results.next().await {
+        result?;
+    }
+    drop(results);
+    drop(record);
+    let _ = fs::remove_file(&sidecar).await;
+    Ok(())
+}
+
+/// 记录已完成分段的旁路文件路径
+fn chunks_path(path: &Path) -> std::path::PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(CHUNKS_SUFFIX);
+    name.into()
+}
+
+/// ffmpeg 合并音视频流（不转码）的参数
+pub fn merge_args(video: &Path, audio: Option<&Path>, output: &Path) -> Vec<String> {
+    let mut args = vec![
+        "-y".to_string(),
+        "-loglevel".to_string(),
+        "error".to_string(),
+        "-i".to_string(),
+        video.to_string_lossy().to_string(),
+    ];
+    if let Some(audio) = audio {
+        args.push("-i".to_string());
+        args.push(audio.to_string_lossy().to_string());
+        args.extend(["-map", "0:v:0", "-map", "1:a:0"].map(String::from));
+    }
+    args.extend(["-c", "copy"].map(String::from));
+    if output.extension().is_some_and(|ext| ext == "mp4") {
+        // 杜比视界、FLAC 等在 MP4 中需要允许非标准特性
+        args.extend(["-movflags", "+faststart", "-strict", "unofficial"].map(String::from));
+    }
+    args.push(output.to_string_lossy().to_string());
+    args
+}
+
+/// ffmpeg 转换音频的参数
+pub fn audio_convert_args(input: &Path, output: &Path, audio: &AudioExtraction) -> Vec<String> {
+    let mut args = vec![
+        "-y".to_string(),
+        "-loglevel".to_string(),
+        "error".to_string(),
+        "-i".to_string(),
+        input.to_string_lossy().to_string(),
+        "-vn".to_string(),
+    ];
+    if audio.normalize {
+        args.push("-af".to_string());
+        args.push(LOUDNORM_FILTER.to_string());
+    }
+
+    let bitrate = audio.bitrate_kbps.map(|kbps| format!("{}k", kbps));
+    let codec: &[&str] = match audio.format {
+        AudioFormat::Mp3 => &["-c:a", "libmp3lame"],
+        // B站的音频流本身就是 AAC，无需转码时直接复制
+        AudioFormat::M4a if !audio.normalize && bitrate.is_none() => &["-c:a", "copy"],
+        AudioFormat::M4a => &["-c:a", "aac"],
+        AudioFormat::Opus => &["-c:a", "libopus"],
+        AudioFormat::Flac => &["-c:a", "flac"],
+    };
+    args.extend(codec.iter().map(|s| s.to_string()));
+    match (audio.format, bitrate) {
+        (AudioFormat::Flac, _) => {}
+        (_, Some(bitrate)) => args.extend(["-b:a".to_string(), bitrate]),
+        (AudioFormat::Mp3, None) => args.extend(["-q:a", "0"].map(String::from)),
+        _ => {}
+    }
+    args.push(output.to_string_lossy().to_string());
+    args
+}
+
+/// 执行 ffmpeg
+pub async fn run_ffmpeg(ffmpeg: &Path, args: &[String]) -> Result<()> {
+    info!("执行: {:?} {:?}", ffmpeg, args);
+    let output = Command::new(ffmpeg)
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .await
+        .map_err(|e| NebulaError::Internal(format!("执行 ffmpeg 失败: {}", e)))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        warn!("ffmpeg 失败: {}", stderr);
+        return Err(NebulaError::Internal(format!(
+            "ffmpeg 处理失败: {}",
+            stderr.lines().next().unwrap_or("")
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_link() {
+        assert_eq!(
+            BilibiliLink::parse("https://www.bilibili.com/video/BV1xx411c7mD?p=2"),
+            Some(BilibiliLink::Video {
+                id: VideoRef::Bvid("BV1xx411c7mD".to_string()),
+                page: 2
+            })
+        );
+        assert_eq!(
+            BilibiliLink::parse("https://m.bilibili.com/video/av170001/"),
+            Some(BilibiliLink::Video {
+                id: VideoRef::Aid(170001),
+                page: 1
+            })
+        );
+        assert_eq!(
+            BilibiliLink::parse("https://www.bilibili.com/bangumi/play/ep123456"),
+            Some(BilibiliLink::Episode(123456))
+        );
+        assert_eq!(
+            BilibiliLink::parse("https://www.bilibili.com/bangumi/play/ss789"),
+            Some(BilibiliLink::Season(789))
+        );
+        assert!(matches!(
+            BilibiliLink::parse("https://b23.tv/abc123"),
+            Some(BilibiliLink::Short(_))
+        ));
+        assert_eq!(BilibiliLink::parse("https://space.bilibili.com/123"), None);
+        assert_eq!(
+            BilibiliLink::parse("https://www.youtube.com/watch?v=x"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_wbi_sign() {
+        let key = mixin_key(
+            &key_from_url("https://i0.hdslb.com/bfs/wbi/7cd084941338484aae1ad9425b84077c.png"),
+            &key_from_url("https://i0.hdslb.com/bfs/wbi/4932caff0ff746eab6f01bf08b70ac45.png"),
+        );
+        assert_eq!(key, "ea1db124af3c7062474693fa704f4ff8");
+
+        let params = [
+            ("foo", "114".to_string()),
+            ("bar", "514".to_string()),
+            ("zab", "1919810".to_string()),
+        ];
+        assert_eq!(
+            sign_wbi(&params, &key, 1702204169),
+            "bar=514&foo=114&wts=1702204169&zab=1919810&w_rid=8f6f2b5b3d485fe1886cec6a0be8c5d4"
+        );
+
+        // 值中的 !'()* 被去掉，空格和非 ASCII 字符按 encodeURIComponent 编码
+        let params = [
+            ("foo", "one one four".to_string()),
+            ("bar", "五一四".to_string()),
+            ("baz", "1919810".to_string()),
+            ("x", "a!b'c(d)e*f".to_string()),
+        ];
+        assert_eq!(
+            sign_wbi(&params, &key, 1702204169),
+            "bar=%E4%BA%94%E4%B8%80%E5%9B%9B&baz=1919810&foo=one%20one%20four&wts=1702204169&x=abcdef&w_rid=b257789bd05ecf89447caafee185ac64"
+        );
+    }
+
+    #[test]
+    fn test_select_streams() {
+        let stream = |id: u32, codec_id: u32, height: u32, bandwidth: u64| DashStream {
+            id,
+            urls: vec![format!("https://cdn/{}-{}", id, codec_id)],
+            bandwidth,
+            codec_id,
+            codecs: String::new(),
+            width: height * 16 / 9,
+            height,
+            frame_rate: Some(30.0),
+        };
+        let play = PlayUrl {
+            video: vec![
+                stream(126, 12, 2160, 20_000_000),
+                stream(120, 7, 2160, 15_000_000),
+                stream(120, 12, 2160, 10_000_000),
+                stream(80, 7, 1080, 3_000_000),
+                stream(80, 13, 1080, 1_500_000),
+                stream(32, 7, 480, 500_000),
+            ],
+            audio: vec![
+                stream(30280, 0, 0, 192_000),
+                stream(AUDIO_ID_DOLBY, 0, 0, 448_000),
+                stream(AUDIO_ID_FLAC, 0, 0, 1_500_000),
+            ],
+        };
+
+        // 默认排除杜比视界，同画质优先 H.264
+        let pref = FormatPreference::default();
+        assert_eq!(
+            select_video(&play, None, &pref).unwrap().format_id(),
+            "120-h264"
+        );
+
+        let hdr = pref
+            .clone()
+            .with_hdr(true)
+            .with_video_codec(Some(VideoCodec::H265));
+        assert_eq!(
+            select_video(&play, None, &hdr).unwrap().format_id(),
+            "126-h265"
+        );
+
+        let capped = pref
+            .clone()
+            .with_max_height(1080)
+            .with_video_codec(Some(VideoCodec::Av1));
+        assert_eq!(
+            select_video(&play, None, &capped).unwrap().format_id(),
+            "80-av01"
+        );
+
+        // 指定格式 ID，以及没有满足条件的流时取最低画质
+        assert_eq!(
+            select_video(&play, Some("80"), &pref).unwrap().format_id(),
+            "80-h264"
+        );
+        assert_eq!(
+            select_video(&play, Some("120-h265"), &pref)
+                .unwrap()
+                .format_id(),
+            "120-h265"
+        );
+        let tiny = pref.with_max_height(144);
+        assert_eq!(
+            select_video(&play, None, &tiny).unwrap().format_id(),
+            "32-h264"
+        );
+
+        assert_eq!(select_audio(&play, false, false).unwrap().id, 30280);
+        assert_eq!(select_audio(&play, true, false).unwrap().id, AUDIO_ID_DOLBY);
+        assert_eq!(select_audio(&play, true, true).unwrap().id, AUDIO_ID_FLAC);
+        assert_eq!(audio_by_format_id(&play, "30280").unwrap().id, 30280);
+        assert!(audio_by_format_id(&play, "80-h264").is_none());
+
+        // 刷新地址时按画质和编码找回同一个流
+        let old = stream(80, 13, 1080, 0);
+        assert_eq!(play.matching(&old).unwrap().format_id(), "80-av01");
+        assert_eq!(play.matching(&stream(30280, 0, 0, 0)).unwrap().id, 30280);
+        assert!(play.matching(&stream(64, 7, 720, 0)).is_none());
+    }
+
+    #[test]
+    fn test_url_expired() {
+        let status = |status_code| NebulaError::HttpError {
+            status_code,
+            message: String::new(),
+        };
+        assert!(is_url_expired(&status(403)));
+        assert!(is_url_expired(&status(404)));
+        assert!(!is_url_expired(&status(500)));
+        assert!(!is_url_expired(&NebulaError::NetworkError(String::new())));
+    }
+
+    #[test]
+    fn test_ffmpeg_args() {
+        let args = merge_args(
+            Path::new("/d/a.video.m4s"),
+            Some(Path::new("/d/a.audio.m4s")),
+            Path::new("/d/a.mp4"),
+        );
+        assert_eq!(
+            args.join(" "),
+            "-y -loglevel error -i /d/a.video.m4s -i /d/a.audio.m4s -map 0:v:0 -map 1:a:0 \
+             -c copy -movflags +faststart -strict unofficial /d/a.mp4"
+        );
+
+        let m4a = AudioExtraction::new(AudioFormat::M4a);
+        assert!(audio_convert_args(Path::new("a"), Path::new("b.m4a"), &m4a)
+            .windows(2)
+            .any(|w| w == ["-c:a", "copy"]));
+        let mp3 = AudioExtraction::new(AudioFormat::Mp3)
+            .with_bitrate(192)
+            .with_normalize(true);
+        let args = audio_convert_args(Path::new("a"), Path::new("b.mp3"), &mp3).join(" ");
+        assert!(args.contains(&format!("-af {}", LOUDNORM_FILTER)));
+        assert!(args.ends_with("-c:a libmp3lame -b:a 192k b.mp3"));
+    }
+}
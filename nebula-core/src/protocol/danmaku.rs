@@ -0,0 +1,374 @@
+//! Bilibili 弹幕转换模块
+//!
+//! 将 yt-dlp 下载的 XML 弹幕转换为 ASS 字幕：滚动弹幕从右向左移动，
+//! 顶部和底部弹幕居中固定显示。同一行放不下时丢弃该条弹幕，避免互相遮挡。
+
+use std::path::Path;
+
+use crate::error::Result;
+
+/// 弹幕样式
+#[derive(Debug, Clone)]
+pub struct DanmakuStyle {
+    /// 画面宽度（ASS PlayResX）
+    pub width: u32,
+    /// 画面高度（ASS PlayResY）
+    pub height: u32,
+    /// 标准字号（对应 Bilibili 字号 25）
+    pub font_size: u32,
+    /// 字体
+    pub font_name: String,
+    /// 滚动弹幕在屏幕上停留的时间（秒）
+    pub scroll_secs: f64,
+    /// 顶部/底部弹幕显示的时间（秒）
+    pub fixed_secs: f64,
+    /// 滚动弹幕最多占用的屏幕高度比例
+    pub scroll_area: f64,
+    /// 不透明度 (0.0 - 1.0)
+    pub opacity: f64,
+}
+
+impl Default for DanmakuStyle {
+    fn default() -> Self {
+        Self {
+            width: 1920,
+            height: 1080,
+            font_size: 48,
+            font_name: "sans-serif".to_string(),
+            scroll_secs: 10.0,
+            fixed_secs: 5.0,
+            scroll_area: 0.75,
+            opacity: 0.8,
+        }
+    }
+}
+
+/// 弹幕显示方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DanmakuMode {
+    /// 从右向左滚动
+    Scroll,
+    /// 底部固定
+    Bottom,
+    /// 顶部固定
+    Top,
+}
+
+/// 一条弹幕
+#[derive(Debug, Clone)]
+struct Danmaku {
+    /// 出现时间（秒）
+    time: f64,
+    mode: DanmakuMode,
+    /// Bilibili 字号（标准为 25）
+    size: u32,
+    /// RGB 颜色
+    color: u32,
+    text: String,
+}
+
+/// 解析 Bilibili XML 弹幕
+///
+/// 每条弹幕形如 `<d p="时间,模式,字号,颜色,...">内容</d>`，高级弹幕和代码弹幕会被忽略
+fn parse_xml(xml: &str) -> Vec<Danmaku> {
+    let mut comments = Vec::new();
+    let mut rest = xml;
+
+    while let Some(start) = rest.find("<d p=\"") {
+        rest = &rest[start + 6..];
+        let Some(attr_end) = rest.find('"') else {
+            break;
+        };
+        let attrs = &rest[..attr_end];
+        rest = &rest[attr_end..];
+        let Some(text_start) = rest.find('>') else {
+            break;
+        };
+        rest = &rest[text_start + 1..];
+        let Some(text_end) = rest.find("</d>") else {
+            break;
+        };
+        let text = unescape_xml(&rest[..text_end]);
+        rest = &rest[text_end + 4..];
+
+        let mut fields = attrs.split(',');
+        let time = fields.next().and_then(|v| v.parse::<f64>().ok());
+        let mode = fields.next().and_then(|v| v.parse::<u8>().ok());
+        let size = fields.next().and_then(|v| v.parse::<u32>().ok());
+        let color = fields.next().and_then(|v| v.parse::<u32>().ok());
+        let (Some(time), Some(mode)) = (time, mode) else {
+            continue;
+        };
+        let mode = match mode {
+            1..=3 | 6 => DanmakuMode::Scroll,
+            4 => DanmakuMode::Bottom,
+            5 => DanmakuMode::Top,
+            _ => continue,
+        };
+        if text.trim().is_empty() {
+            continue;
+        }
+
+        comments.push(Danmaku {
+            time,
+            mode,
+            size: size.unwrap_or(25),
+            color: color.unwrap_or(0xFFFFFF),
+            text,
+        });
+    }
+
+    comments.sort_by(|a, b| a.time.total_cmp(&b.time));
+    comments
+}
+
+/// 还原 XML 实体
+fn unescape_xml(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        result.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 转义 ASS 中有特殊含义的字符
+fn escape_ass(text: &str) -> String {
+    text.replace('\\', "＼")
+        .replace('{', "｛")
+        .replace('}', "｝")
+        .replace("\r\n", "\\N")
+        .replace('\n', "\\N")
+}
+
+/// 格式化 ASS 时间 `H:MM:SS.cc`
+fn format_time(secs: f64) -> String {
+    let centis = (secs.max(0.0) * 100.0).round() as u64;
+    format!(
+        "{}:{:02}:{:02}.{:02}",
+        centis / 360_000,
+        centis / 6000 % 60,
+        centis / 100 % 60,
+        centis % 100
+    )
+}
+
+/// 估算文本宽度：全角字符按一个字号计算，半角字符按半个字号计算
+fn text_width(text: &str, font_size: f64) -> f64 {
+    text.lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| if c.is_ascii() { 0.5 } else { 1.0 })
+                .sum::<f64>()
+        })
+        .fold(0.0, f64::max)
+        * font_size
+}
+
+/// 滚动弹幕所在行的占用情况
+#[derive(Debug, Clone, Copy, Default)]
+struct ScrollRow {
+    /// 上一条弹幕尾部完全进入屏幕的时间
+    tail_visible_at: f64,
+    /// 上一条弹幕离开屏幕的时间
+    leaves_at: f64,
+}
+
+/// 将 XML 弹幕转换为 ASS 字幕
+///
+/// # 返回
+/// ASS 字幕内容和实际写入的弹幕数量
+pub fn xml_to_ass(xml: &str, style: &DanmakuStyle) -> (String, usize) {
+    let width = style.width as f64;
+    let height = style.height as f64;
+    let line_height = style.font_size as f64 * 1.2;
+    let scroll_rows = ((height * style.scroll_area) / line_height).max(1.0) as usize;
+    let fixed_rows = (height / 2.0 / line_height).max(1.0) as usize;
+
+    let mut scroll = vec![ScrollRow::default(); scroll_rows];
+    let mut top = vec![0.0f64; fixed_rows];
+    let mut bottom = vec![0.0f64; fixed_rows];
+
+    let alpha = ((1.0 - style.opacity.clamp(0.0, 1.0)) * 255.0).round() as u8;
+    let mut ass = format!(
+        "[Script Info]\n\
+         ScriptType: v4.00+\n\
+         PlayResX: {width}\n\
+         PlayResY: {height}\n\
+         WrapStyle: 2\n\
+         ScaledBorderAndShadow: yes\n\
+         \n\
+         [V4+ Styles]\n\
+         Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, \
+         BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, \
+         BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\n\
+         Style: Danmaku,{font},{size},&H{alpha:02X}FFFFFF,&H{alpha:02X}FFFFFF,&H{alpha:02X}000000,\
+         &H{alpha:02X}000000,0,0,0,0,100,100,0,0,1,1,0,7,0,0,0,1\n\
+         \n\
+         [Events]\n\
+         Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n",
+        width = style.width,
+        height = style.height,
+        font = style.font_name,
+        size = style.font_size,
+    );
+
+    let mut count = 0;
+    for comment in parse_xml(xml) {
+        let font_size = style.font_size as f64 * comment.size as f64 / 25.0;
+        let text_w = text_width(&comment.text, font_size);
+        let start = comment.time;
+
+        let (end, position) = match comment.mode {
+            DanmakuMode::Scroll => {
+                let speed = (width + text_w) / style.scroll_secs;
+                // 行空闲：上一条已完全进入屏幕，且在本条到达左边缘前离开
+                let reaches_left = start + width / speed;
+                let Some(row) = scroll
+                    .iter()
+                    .position(|r| r.tail_visible_at <= start && r.leaves_at <= reaches_left)
+                else {
+                    continue;
+                };
+                scroll[row] = ScrollRow {
+                    tail_visible_at: start + text_w / speed,
+                    leaves_at: start + style.scroll_secs,
+                };
+                let y = row as f64 * line_height;
+                (
+                    start + style.scroll_secs,
+                    format!(
+                        "\\move({:.0},{:.0},{:.0},{:.0})",
+                        width,
+                        y,
+                        -text_w,
+                        y
+                    ),
+                )
+            }
+            DanmakuMode::Top | DanmakuMode::Bottom => {
+                let rows = if comment.mode == DanmakuMode::Top {
+                    &mut top
+                } else {
+                    &mut bottom
+                };
+                let Some(row) = rows.iter().position(|&free_at| free_at <= start) else {
+                    continue;
+                };
+                rows[row] = start + style.fixed_secs;
+                let position = if comment.mode == DanmakuMode::Top {
+                    format!("\\an8\\pos({:.0},{:.0})", width / 2.0, row as f64 * line_height)
+                } else {
+                    format!(
+                        "\\an2\\pos({:.0},{:.0})",
+                        width / 2.0,
+                        height - row as f64 * line_height
+                    )
+                };
+                (start + style.fixed_secs, position)
+            }
+        };
+
+        let mut overrides = position;
+        if comment.size != 25 {
+            overrides.push_str(&format!("\\fs{:.0}", font_size));
+        }
+        if comment.color != 0xFFFFFF {
+            // ASS 颜色顺序为 BGR
+            let (r, g, b) = (
+                (comment.color >> 16) & 0xFF,
+                (comment.color >> 8) & 0xFF,
+                comment.color & 0xFF,
+            );
+            overrides.push_str(&format!("\\c&H{:02X}{:02X}{:02X}&", b, g, r));
+        }
+
+        ass.push_str(&format!(
+            "Dialogue: 2,{},{},Danmaku,,0000,0000,0000,,{{{}}}{}\n",
+            format_time(start),
+            format_time(end),
+            overrides,
+            escape_ass(&comment.text)
+        ));
+        count += 1;
+    }
+
+    (ass, count)
+}
+
+/// 转换弹幕文件，返回写入的弹幕数量
+pub async fn convert_file(xml_path: &Path, ass_path: &Path, style: &DanmakuStyle) -> Result<usize> {
+    let xml = tokio::fs::read_to_string(xml_path).await?;
+    let (ass, count) = xml_to_ass(&xml, style);
+    tokio::fs::write(ass_path, ass).await?;
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xml_to_ass() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?><i>
+            <d p="1.5,1,25,16777215,1700000000,0,abc,1">第一条 &amp; {测试}</d>
+            <d p="1.6,1,25,16711680,1700000000,0,abc,2">红色弹幕</d>
+            <d p="3.0,5,36,16777215,1700000000,0,abc,3">顶部</d>
+            <d p="4.0,4,25,16777215,1700000000,0,abc,4">底部</d>
+            <d p="5.0,7,25,16777215,1700000000,0,abc,5">[高级弹幕]</d>
+            <d p="0.5,1,25,16777215,1700000000,0,abc,6">最早</d>
+        </i>"#;
+
+        let (ass, count) = xml_to_ass(xml, &DanmakuStyle::default());
+        assert_eq!(count, 5);
+        assert!(ass.contains("PlayResX: 1920"));
+
+        let dialogues: Vec<_> = ass.lines().filter(|l| l.starts_with("Dialogue:")).collect();
+        assert!(dialogues[0].ends_with("最早"));
+        assert!(dialogues[0].starts_with("Dialogue: 2,0:00:00.50,0:00:10.50,"));
+        // 转义后的文本不会被当作 ASS 标签
+        assert!(dialogues[1].ends_with("第一条 & ｛测试｝"));
+        // 会追上前一条的滚动弹幕放到下一行，短弹幕可以继续使用第一行
+        assert!(dialogues[1].contains("\\move(1920,58,"));
+        assert!(dialogues[2].contains("\\move(1920,0,"));
+        assert!(dialogues[2].contains("\\c&H0000FF&"));
+        assert!(dialogues[3].contains("\\an8\\pos(960,0)\\fs69"));
+        assert!(dialogues[4].contains("\\an2\\pos(960,1080)"));
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(0.0), "0:00:00.00");
+        assert_eq!(format_time(61.255), "0:01:01.26");
+        assert_eq!(format_time(3725.0), "1:02:05.00");
+    }
+}
@@ -4,24 +4,29 @@
 //! - 断点续传（Range 请求）
 //! - 多线程分块下载
 //! - 自动重试
+//! - 按字节范围获取数据（供 BitTorrent Web Seed 使用）
 
 use super::{FileInfo, ProtocolHandler};
 use crate::config::HttpConfig;
 use crate::error::{NebulaError, Result};
 use crate::event::{DownloadEvent, Progress};
 use crate::task::{DownloadSource, TaskId};
+use crate::vault::CredentialVault;
 
 use async_trait::async_trait;
 use futures::StreamExt;
-use reqwest::header::{ACCEPT_RANGES, CONTENT_LENGTH, CONTENT_TYPE, RANGE};
+use reqwest::header::{
+    HeaderMap, ACCEPT_RANGES, CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE, RANGE,
+};
 use reqwest::Client;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::fs::{File, OpenOptions};
-use tokio::io::AsyncWriteExt;
-use tokio::sync::{broadcast, Mutex, RwLock};
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+use tokio::sync::{mpsc, Mutex, RwLock};
 use tracing::{debug, info};
 
 #[allow(dead_code)]
@@ -42,10 +47,12 @@ struct HttpTask {
 pub struct HttpHandler {
     /// HTTP 客户端
     client: Client,
-    #[allow(dead_code)]
+    /// 配置
     config: HttpConfig,
     /// 活跃任务映射表
     tasks: Arc<RwLock<HashMap<TaskId, Arc<Mutex<HttpTask>>>>>,
+    /// 站点凭据保险库（为匹配的主机添加 Cookie、Authorization 等请求头）
+    vault: Option<Arc<CredentialVault>>,
 }
 
 impl HttpHandler {
@@ -71,14 +78,37 @@ impl HttpHandler {
             client,
             config,
             tasks: Arc::new(RwLock::new(HashMap::new())),
+            vault: None,
         })
     }
 
+    /// 设置站点凭据保险库
+    pub fn with_credential_vault(mut self, vault: Arc<CredentialVault>) -> Self {
+        self.vault = Some(vault);
+        self
+    }
+
+    /// 获取当前配置
+    pub fn config(&self) -> &HttpConfig {
+        &self.config
+    }
+
+    /// 保险库中与链接主机匹配的凭据请求头，`extra` 中的同名请求头优先
+    async fn request_headers(&self, url: &str, extra: &HeaderMap) -> HeaderMap {
+        let mut headers = match &self.vault {
+            Some(vault) => vault.http_headers(url).await,
+            None => HeaderMap::new(),
+        };
+        headers.extend(extra.clone());
+        headers
+    }
+
     /// 获取远程文件信息
     pub async fn get_file_info(&self, url: &str) -> Result<FileInfo> {
         let response = self
             .client
             .head(url)
+            .headers(self.request_headers(url, &HeaderMap::new()).await)
             .send()
             .await
             .map_err(|e| NebulaError::NetworkError(e.to_string()))?;
@@ -128,28 +158,152 @@ impl HttpHandler {
         })
     }
 
+    /// 获取 URL 的指定字节范围，写入文件 `path` 的 `offset` 处
+    ///
+    /// 文件不存在时会被创建，已有内容不会被截断。
+    ///
+    /// # 返回
+    /// 写入的字节数（等于范围长度）
+    pub async fn fetch_range(
+        &self,
+        url: &str,
+        range: Range<u64>,
+        path: &Path,
+        offset: u64,
+    ) -> Result<u64> {
+        self.fetch_range_with_headers(url, range, path, offset, &HeaderMap::new())
+            .await
+    }
+
+    /// 同 [`fetch_range`](Self::fetch_range)，附加请求头（如 CDN 要求的 Referer）
+    pub async fn fetch_range_with_headers(
+        &self,
+        url: &str,
+        range: Range<u64>,
+        path: &Path,
+        offset: u64,
+        headers: &HeaderMap,
+    ) -> Result<u64> {
+        let expected = range.end.saturating_sub(range.start);
+        if expected == 0 {
+            return Ok(0);
+        }
+
+        let response = self
+            .client
+            .get(url)
+            .headers(self.request_headers(url, headers).await)
+            .header(RANGE, format!("bytes={}-{}", range.start, range.end - 1))
+            .send()
+            .await
+            .map_err(|e| NebulaError::NetworkError(e.to_string()))?;
+
+        // 服务器忽略 Range 时只有从头获取才能使用返回的数据
+        let status = response.status();
+        let usable = status == reqwest::StatusCode::PARTIAL_CONTENT
+            || (status.is_success() && range.start == 0);
+        if !usable {
+            return Err(NebulaError::HttpError {
+                status_code: status.as_u16(),
+                message: format!("范围请求失败: {}", status),
+            });
+        }
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .await?;
+        file.seek(std::io::SeekFrom::Start(offset)).await?;
+
+        let mut stream = response.bytes_stream();
+        let mut written = 0u64;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| NebulaError::NetworkError(e.to_string()))?;
+            let remaining = (expected - written) as usize;
+            let chunk = &chunk[..chunk.len().min(remaining)];
+            file.write_all(chunk).await?;
+            written += chunk.len() as u64;
+            if written >= expected {
+                break;
+            }
+        }
+        file.flush().await?;
+
+        if written < expected {
+            return Err(NebulaError::NetworkError(format!(
+                "数据不完整: 期望 {} 字节，实际 {} 字节",
+                expected, written
+            )));
+        }
+        debug!("已获取 {} 字节: {}", written, url);
+        Ok(written)
+    }
+
+    /// 通过只请求第一个字节获取远程文件大小（用于不支持 HEAD 的 CDN）
+    ///
+    /// # 返回
+    /// 文件大小，服务器不支持范围请求时为 None
+    pub async fn probe_size(&self, url: &str, headers: &HeaderMap) -> Result<Option<u64>> {
+        let response = self
+            .client
+            .get(url)
+            .headers(self.request_headers(url, headers).await)
+            .header(RANGE, "bytes=0-0")
+            .send()
+            .await
+            .map_err(|e| NebulaError::NetworkError(e.to_string()))?;
+
+        if response.status() != reqwest::StatusCode::PARTIAL_CONTENT {
+            if !response.status().is_success() {
+                return Err(NebulaError::HttpError {
+                    status_code: response.status().as_u16(),
+                    message: format!("获取文件大小失败: {}", response.status()),
+                });
+            }
+            return Ok(None);
+        }
+
+        // Content-Range: bytes 0-0/12345
+        Ok(response
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.rsplit('/').next())
+            .and_then(|total| total.trim().parse().ok()))
+    }
+
+    /// 注册任务，同一文件只能由一个任务写入
+    async fn register_task(&self, task_id: TaskId, task: &Arc<Mutex<HttpTask>>) -> Result<()> {
+        let save_path = task.lock().await.save_path.clone();
+        let mut tasks = self.tasks.write().await;
+        for (other_id, other) in tasks.iter() {
+            if *other_id != task_id && other.lock().await.save_path == save_path {
+                return Err(NebulaError::TaskAlreadyExists(format!(
+                    "{} ({})",
+                    save_path.display(),
+                    other_id
+                )));
+            }
+        }
+        tasks.insert(task_id, Arc::clone(task));
+        Ok(())
+    }
+
     /// 执行单线程下载（带断点续传）
     async fn download_single_thread(
         &self,
         task_id: TaskId,
         url: &str,
-        save_path: PathBuf,
-        event_tx: broadcast::Sender<DownloadEvent>,
+        task: &Mutex<HttpTask>,
+        event_tx: mpsc::UnboundedSender<DownloadEvent>,
         file_info: FileInfo,
     ) -> Result<()> {
-        let task = Arc::new(Mutex::new(HttpTask {
-            task_id,
-            paused: false,
-            cancelled: false,
-            progress: Progress::new(file_info.size.unwrap_or(0), 0),
-            save_path: save_path.clone(),
-        }));
-
-        // 注册任务
-        {
-            let mut tasks = self.tasks.write().await;
-            tasks.insert(task_id, Arc::clone(&task));
-        }
+        let save_path = task.lock().await.save_path.clone();
 
         // 检查是否有已下载的部分（断点续传）
         let existing_size = if save_path.exists() {
@@ -190,7 +344,10 @@ impl HttpHandler {
         };
 
         // 构建请求（支持 Range）
-        let mut request = self.client.get(url);
+        let mut request = self
+            .client
+            .get(url)
+            .headers(self.request_headers(url, &HeaderMap::new()).await);
         let start_offset = if existing_size > 0 && file_info.supports_resume {
             request = request.header(RANGE, format!("bytes={}-", existing_size));
             existing_size
@@ -274,12 +431,6 @@ impl HttpHandler {
             completed_at: chrono::Utc::now(),
         });
 
-        // 移除任务
-        {
-            let mut tasks = self.tasks.write().await;
-            tasks.remove(&task_id);
-        }
-
         Ok(())
     }
 }
@@ -291,7 +442,7 @@ impl ProtocolHandler for HttpHandler {
         task_id: TaskId,
         source: &DownloadSource,
         save_path: PathBuf,
-        event_tx: broadcast::Sender<DownloadEvent>,
+        event_tx: mpsc::UnboundedSender<DownloadEvent>,
     ) -> Result<()> {
         let url = match source {
             DownloadSource::Http { url } => url.clone(),
@@ -311,9 +462,43 @@ impl ProtocolHandler for HttpHandler {
             save_path
         };
 
-        // 执行下载
-        self.download_single_thread(task_id, &url, final_path, event_tx, file_info)
-            .await
+        let name = final_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| file_info.name.clone());
+        let _ = event_tx.send(DownloadEvent::MetadataReceived {
+            task_id,
+            name,
+            total_size: file_info.size.unwrap_or(0),
+            file_count: 1,
+            mime_type: file_info.mime_type.clone(),
+            supports_resume: file_info.supports_resume,
+            thumbnail: None,
+            duration_secs: None,
+        });
+
+        let task = Arc::new(Mutex::new(HttpTask {
+            task_id,
+            paused: false,
+            cancelled: false,
+            progress: Progress::new(file_info.size.unwrap_or(0), 0),
+            save_path: final_path.clone(),
+        }));
+        self.register_task(task_id, &task).await?;
+        let _ = event_tx.send(DownloadEvent::OutputFileResolved {
+            task_id,
+            path: final_path,
+        });
+
+        // 执行下载，无论结果如何都移除任务（已取消的任务已被移除）
+        let result = self
+            .download_single_thread(task_id, &url, &task, event_tx, file_info)
+            .await;
+        let mut tasks = self.tasks.write().await;
+        if tasks.get(&task_id).is_some_and(|t| Arc::ptr_eq(t, &task)) {
+            tasks.remove(&task_id);
+        }
+        result
     }
 
     async fn pause(&self, task_id: TaskId) -> Result<()> {
@@ -379,4 +564,27 @@ mod tests {
         let handler = HttpHandler::new(config);
         assert!(handler.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_register_rejects_same_file() {
+        let handler = HttpHandler::new(HttpConfig::default()).unwrap();
+        let task = |task_id| {
+            Arc::new(Mutex::new(HttpTask {
+                task_id,
+                paused: false,
+                cancelled: false,
+                progress: Progress::default(),
+                save_path: PathBuf::from("/downloads/a.zip"),
+            }))
+        };
+
+        let (first, second) = (TaskId::new(), TaskId::new());
+        handler.register_task(first, &task(first)).await.unwrap();
+        // 同一任务重新开始时允许再次注册
+        handler.register_task(first, &task(first)).await.unwrap();
+        assert!(matches!(
+            handler.register_task(second, &task(second)).await,
+            Err(NebulaError::TaskAlreadyExists(_))
+        ));
+    }
 }
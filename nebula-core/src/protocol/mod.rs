@@ -3,16 +3,20 @@
 //! 提供不同下载协议的统一抽象和具体实现。
 
 pub mod bilibili;
+pub mod bilibili_video;
+pub mod danmaku;
+pub mod ftp;
 pub mod http;
 pub mod torrent;
 pub mod video;
+pub mod webseed;
 
 use crate::error::Result;
 use crate::event::{DownloadEvent, Progress};
 use crate::task::{DownloadSource, TaskId};
 use async_trait::async_trait;
 use std::path::PathBuf;
-use tokio::sync::broadcast;
+use tokio::sync::mpsc;
 
 /// 协议处理器 trait
 ///
@@ -31,7 +35,7 @@ pub trait ProtocolHandler: Send + Sync {
         task_id: TaskId,
         source: &DownloadSource,
         save_path: PathBuf,
-        event_tx: broadcast::Sender<DownloadEvent>,
+        event_tx: mpsc::UnboundedSender<DownloadEvent>,
     ) -> Result<()>;
 
     /// 暂停下载
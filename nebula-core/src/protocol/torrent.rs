@@ -5,45 +5,349 @@
 //! - .torrent 文件下载
 //! - DHT 网络
 //! - 顺序下载（边下边播）
+//! - Tracker / Peer 状态查看，运行中增删 Tracker
+//! - IP 黑名单过滤
+//! - 仅解析元数据（不下载内容），选择文件后再开始下载
+//! - Peer 不足时通过 Web Seed (BEP 19) 下载
+//! - 停滞检测：重新查询 DHT / Tracker，可选超时判定失败
 
-use super::ProtocolHandler;
+use super::http::HttpHandler;
+use super::{webseed, ProtocolHandler};
+use crate::bencode;
 use crate::config::TorrentConfig;
 use crate::error::{NebulaError, Result};
-use crate::event::{DownloadEvent, Progress};
+use crate::event::{DownloadEvent, Progress, StallDiagnostics, StallPhase};
+use crate::ipfilter::{IpFilter, IpFilterManager, IpFilterStats};
+use crate::magnet::MagnetLink;
+use crate::metainfo::{is_relative_path, TorrentMetainfo};
 use crate::task::{DownloadSource, TaskId};
+use crate::trackers::{AnnounceEvent, AnnounceRequest, TrackerManager};
 
 use async_trait::async_trait;
-use librqbit::{AddTorrent, AddTorrentOptions, AddTorrentResponse, Session, SessionOptions};
-use std::collections::HashMap;
-use std::path::PathBuf;
-use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::{broadcast, RwLock};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use librqbit::api::PeerStatsFilter;
+use librqbit::{
+    AddTorrent, AddTorrentOptions, AddTorrentResponse, Id20, ManagedTorrentHandle, Session,
+    SessionOptions, TorrentStats, TorrentStatsState,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::net::SocketAddr;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, PoisonError};
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, Mutex, RwLock};
 use tracing::{debug, info, warn};
 
-#[allow(dead_code)]
+/// Tracker 状态定时刷新间隔
+const TRACKER_REFRESH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// 同时进行的 scrape 请求数量上限
+const SCRAPE_CONCURRENCY: usize = 8;
+
+/// Web Seed 检查间隔
+const WEB_SEED_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Web Seed 连续失败次数上限，超过后不再尝试
+const WEB_SEED_MAX_FAILURES: u32 = 3;
+
+/// 停滞检查间隔
+const STALL_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// 计算 Peer 速度时两次采样的间隔
+const PEER_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 超过该时长的 Peer 采样不再用于计算速度
+const PEER_SAMPLE_MAX_AGE: Duration = Duration::from_secs(30);
+
+/// 未配置监听端口时引擎依次尝试的端口范围
+const DEFAULT_LISTEN_PORTS: Range<u16> = 6881..6890;
+
+/// 本地 peer_id 前缀（Azureus 风格：客户端代码 NB，版本 0.1.0.0）
+const PEER_ID_PREFIX: &[u8; 8] = b"-NB0100-";
+
+/// 停滞时首次重新通告后的等待时间，之后每次加倍
+const REANNOUNCE_INITIAL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 停滞时重新通告的最长间隔
+const REANNOUNCE_MAX_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// 重新创建 Session 的最大尝试次数（旧 Session 的监听端口可能尚未释放）
+const SESSION_RESTART_ATTEMPTS: u32 = 3;
+
+/// 可替换的引擎 Session，协程每次使用时重新读取
+type SessionSlot = Arc<std::sync::RwLock<Arc<Session>>>;
+
+/// Tracker 来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrackerSource {
+    /// 种子文件或磁力链接自带
+    Torrent,
+    /// 从远程 Tracker 列表注入
+    Injected,
+    /// 用户手动添加
+    User,
+}
+
+/// Tracker 状态
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TrackerStatus {
+    /// 尚未联系
+    NotContacted,
+    /// 正在查询
+    Updating,
+    /// 工作正常
+    Working,
+    /// 查询失败
+    Error(String),
+}
+
+/// 种子的单个 Tracker 信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TorrentTracker {
+    /// Tracker 地址
+    pub url: String,
+    /// 来源
+    pub source: TrackerSource,
+    /// 当前状态
+    pub status: TrackerStatus,
+    /// 做种者数量
+    pub seeders: Option<u32>,
+    /// 下载者数量
+    pub leechers: Option<u32>,
+    /// 已完成下载次数
+    pub completed: Option<u32>,
+    /// 最近一次查询时间
+    pub last_updated: Option<DateTime<Utc>>,
+}
+
+impl TorrentTracker {
+    fn new(url: String, source: TrackerSource) -> Self {
+        Self {
+            url,
+            source,
+            status: TrackerStatus::NotContacted,
+            seeders: None,
+            leechers: None,
+            completed: None,
+            last_updated: None,
+        }
+    }
+}
+
+/// 已连接 Peer 的信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeerInfo {
+    /// 地址 (ip:port)
+    pub address: String,
+    /// 客户端名称（来自 peer_id 或扩展握手的 `v` 字段）
+    ///
+    /// librqbit 目前不公开 Peer 的 peer_id 和扩展握手，始终为 None
+    pub client: Option<String>,
+    /// 连接状态标志（如 live、connecting、queued）
+    pub flags: String,
+    /// 从该 Peer 下载的速度（字节/秒）
+    pub download_speed: u64,
+    /// 向该 Peer 上传的速度（字节/秒）
+    pub upload_speed: u64,
+    /// 从该 Peer 累计下载（字节）
+    pub downloaded: u64,
+    /// 向该 Peer 累计上传（字节）
+    pub uploaded: u64,
+    /// 对方的完成度 (0.0 - 100.0)，由对方的 bitfield 计算
+    ///
+    /// librqbit 目前不公开 Peer 的 bitfield，始终为 None
+    pub progress: Option<f64>,
+}
+
+/// 上一次采样的 Peer 计数器，用于计算速度
+struct PeerSample {
+    downloaded: u64,
+    uploaded: u64,
+    at: Instant,
+}
+
 struct TorrentTask {
     /// librqbit 内部任务 ID
     handle_id: usize,
     /// 我们的任务 ID
+    #[allow(dead_code)]
     task_id: TaskId,
     /// 保存路径
     save_path: PathBuf,
     /// 种子名称（用于显示）
+    #[allow(dead_code)]
     name: String,
+    /// 原始来源（重新添加到引擎时使用）
+    source: DownloadSource,
+    /// 种子 info hash
+    info_hash: [u8; 20],
+    /// 只下载这些文件（None 表示全部）
+    only_files: Option<Vec<usize>>,
+    /// 种子元数据（包含 Web Seed 列表）
+    metainfo: TorrentMetainfo,
+    /// Tracker 列表及状态
+    trackers: Vec<TorrentTracker>,
+    /// 添加种子时交给引擎的 Tracker（由引擎通告）
+    engine_trackers: HashSet<String>,
+    /// 由我们通告过的 Tracker（停止时需要发送 stopped）
+    announced: HashSet<String>,
+    /// 上一次采样的 Peer 计数器
+    peer_samples: HashMap<String, PeerSample>,
+    /// 正在重新添加到引擎（期间跳过进度监控）
+    restarting: bool,
+    /// 从 Web Seed 获取、尚未由引擎校验的字节数（计入显示的进度）
+    web_seed_bytes: u64,
+    /// 事件发送端（重新启动进度监控时使用）
+    event_tx: mpsc::UnboundedSender<DownloadEvent>,
+    /// 进度监控协程是否在运行
+    monitor_active: bool,
+}
+
+/// 引擎在 Tracker 上登记的本地 Peer
+#[derive(Debug, Clone, Copy)]
+struct LocalPeer {
+    peer_id: [u8; 20],
+    port: u16,
+}
+
+impl LocalPeer {
+    /// 按 Session 当前的监听端口生成
+    ///
+    /// 引擎没有在监听端口时返回 None，此时不代为通告，避免登记无法连接的 Peer
+    fn of(session: &Session, peer_id: [u8; 20]) -> Option<Self> {
+        let port = session.tcp_listen_port();
+        if port.is_none() {
+            debug!("引擎没有监听端口，跳过 Tracker 通告");
+        }
+        Some(Self {
+            peer_id,
+            port: port?,
+        })
+    }
+
+    /// 按种子当前的统计生成 announce 请求
+    fn request(
+        &self,
+        info_hash: [u8; 20],
+        stats: &TorrentStats,
+        event: AnnounceEvent,
+    ) -> AnnounceRequest {
+        AnnounceRequest {
+            info_hash,
+            peer_id: self.peer_id,
+            port: self.port,
+            uploaded: stats.uploaded_bytes,
+            downloaded: stats.progress_bytes,
+            left: stats.total_bytes.saturating_sub(stats.progress_bytes),
+            event,
+        }
+    }
+}
+
+/// 一次停滞检查的结果
+#[derive(Debug, PartialEq, Eq)]
+enum StallCheck {
+    /// 有新数据
+    Progressing,
+    /// 停滞后重新有了新数据
+    Recovered,
+    /// 没有新数据，但未超过停滞时限
+    Waiting,
+    /// 超过停滞时限没有新数据，`since` 为停滞开始时间
+    Stalled { since: Instant },
+}
+
+/// 下载停滞检测
+///
+/// 超过 `timeout` 没有新数据时报告停滞，之后每隔 `timeout` 再报告一次；
+/// 停滞期间的重新通告按指数退避
+struct StallDetector {
+    timeout: Duration,
+    last_bytes: u64,
+    last_progress: Instant,
+    stalled_since: Option<Instant>,
+    next_announce: Option<Instant>,
+    announce_interval: Duration,
+}
+
+impl StallDetector {
+    fn new(timeout: Duration, now: Instant) -> Self {
+        Self {
+            timeout,
+            last_bytes: 0,
+            last_progress: now,
+            stalled_since: None,
+            next_announce: None,
+            announce_interval: REANNOUNCE_INITIAL_INTERVAL,
+        }
+    }
+
+    /// 暂停或校验期间重新计时
+    fn reset(&mut self, now: Instant) {
+        self.last_progress = now;
+    }
+
+    /// 记录当前已下载的字节数
+    fn observe(&mut self, bytes: u64, now: Instant) -> StallCheck {
+        if bytes > self.last_bytes {
+            self.last_bytes = bytes;
+            self.last_progress = now;
+            self.next_announce = None;
+            self.announce_interval = REANNOUNCE_INITIAL_INTERVAL;
+            return match self.stalled_since.take() {
+                Some(_) => StallCheck::Recovered,
+                None => StallCheck::Progressing,
+            };
+        }
+        if now.duration_since(self.last_progress) < self.timeout {
+            return StallCheck::Waiting;
+        }
+
+        let since = *self.stalled_since.get_or_insert(self.last_progress);
+        self.last_progress = now;
+        StallCheck::Stalled { since }
+    }
+
+    /// 是否应当重新通告，每次通告后等待时间加倍
+    fn should_reannounce(&mut self, now: Instant) -> bool {
+        if self.next_announce.is_some_and(|at| now < at) {
+            return false;
+        }
+        self.next_announce = Some(now + self.announce_interval);
+        self.announce_interval = (self.announce_interval * 2).min(REANNOUNCE_MAX_INTERVAL);
+        true
+    }
 }
 
 /// BitTorrent 协议处理器
 pub struct TorrentHandler {
-    /// librqbit Session
-    session: Arc<Session>,
+    /// librqbit Session（IP 过滤列表更新后整体替换）
+    session: SessionSlot,
+    /// 数据存储目录（重新创建 Session 时使用）
+    data_dir: PathBuf,
+    /// 向引擎添加或重新添加种子时持有，避免与替换 Session 交错
+    engine_lock: Mutex<()>,
     /// 配置
-    #[allow(dead_code)]
     config: TorrentConfig,
     /// 任务映射：TaskId -> TorrentTask
     tasks: Arc<RwLock<HashMap<TaskId, TorrentTask>>>,
-    /// Tracker 列表（初始化时获取）
-    trackers: Vec<String>,
+    /// 注入到新种子的 Tracker 列表（后台探测完成后更新）
+    trackers: Arc<RwLock<Vec<String>>>,
+    /// Tracker 管理器（用于 scrape 查询）
+    tracker_manager: Arc<TrackerManager>,
+    /// IP 过滤列表管理器（未启用时为 None）
+    ip_filter_manager: Option<Arc<IpFilterManager>>,
+    /// 当前生效的 IP 过滤器
+    ip_filter: Arc<RwLock<Option<Arc<IpFilter>>>>,
+    /// 已解析的种子元数据缓存：info hash -> 元数据
+    metadata: RwLock<HashMap<[u8; 20], TorrentMetainfo>>,
+    /// 正在获取元数据的任务（取消后从中移除）
+    resolving: RwLock<HashSet<TaskId>>,
+    /// 引擎使用的 peer_id（我们代为通告时使用同一个）
+    peer_id: [u8; 20],
 }
 
 impl TorrentHandler {
@@ -56,219 +360,1676 @@ impl TorrentHandler {
         // 确保数据目录存在
         tokio::fs::create_dir_all(&data_dir).await?;
 
-        // 获取远程 Tracker 列表
-        let tracker_manager = crate::trackers::TrackerManager::new(data_dir.clone());
-        let trackers = tracker_manager.get_trackers().await;
+        // 先按缓存的健康状况选出 Tracker，探测在后台进行，不阻塞启动
+        let tracker_manager = TrackerManager::new(data_dir.clone())
+            .with_probe_timeout(Duration::from_secs(config.tracker_probe_timeout_secs));
+        let trackers = injected_trackers(
+            &config,
+            tracker_manager
+                .cached_healthy_trackers(config.max_injected_trackers)
+                .await,
+        );
         info!("已加载 {} 个 Tracker", trackers.len());
 
-        // 构建 Session 配置
-        let session_opts = SessionOptions {
-            disable_dht: !config.enable_dht,
-            disable_dht_persistence: false,
-            ..Default::default()
+        // 加载本地缓存的 IP 过滤列表，合并后写成引擎可读取的黑名单文件；
+        // 过期的订阅在后台更新，不阻塞启动
+        let mut ip_filter = None;
+        let mut blocklist_url = None;
+        let ip_filter_manager = if config.ip_filter.enabled {
+            let manager = IpFilterManager::new(config.ip_filter.clone(), data_dir.clone());
+            let filter = manager.load().await?;
+            let path = manager.write_blocklist(&filter).await?;
+            blocklist_url = url::Url::from_file_path(&path).ok().map(|u| u.to_string());
+            ip_filter = Some(Arc::new(filter));
+            Some(Arc::new(manager))
+        } else {
+            None
+        };
+
+        // peer_id 由我们生成，代为通告时才能与引擎一致
+        let peer_id = generate_peer_id();
+        let session = create_session(&config, &data_dir, peer_id, blocklist_url).await?;
+
+        info!("BitTorrent 引擎已初始化");
+
+        let handler = Self {
+            session: Arc::new(std::sync::RwLock::new(session)),
+            data_dir,
+            engine_lock: Mutex::new(()),
+            config,
+            tasks: Arc::new(RwLock::new(HashMap::new())),
+            trackers: Arc::new(RwLock::new(trackers)),
+            tracker_manager: Arc::new(tracker_manager),
+            ip_filter_manager,
+            ip_filter: Arc::new(RwLock::new(ip_filter)),
+            metadata: RwLock::new(HashMap::new()),
+            resolving: RwLock::new(HashSet::new()),
+            peer_id,
+        };
+        handler.spawn_tracker_probe();
+        Ok(handler)
+    }
+
+    /// 在后台探测 Tracker 健康状况，完成后按新的排名更新之后添加的种子使用的 Tracker
+    fn spawn_tracker_probe(&self) {
+        let tracker_manager = Arc::clone(&self.tracker_manager);
+        let trackers = Arc::clone(&self.trackers);
+        let config = self.config.clone();
+
+        tokio::spawn(async move {
+            tracker_manager.refresh_health().await;
+            let ranked = tracker_manager
+                .cached_healthy_trackers(config.max_injected_trackers)
+                .await;
+            let updated = injected_trackers(&config, ranked);
+            debug!("Tracker 探测完成，更新为 {} 个 Tracker", updated.len());
+            *trackers.write().await = updated;
+        });
+    }
+
+    /// 获取 IP 过滤统计（未启用时返回 None）
+    pub async fn ip_filter_stats(&self) -> Option<IpFilterStats> {
+        let manager = self.ip_filter_manager.as_ref()?;
+        let filter = self.ip_filter.read().await.clone()?;
+        Some(IpFilterStats {
+            range_count: filter.len(),
+            blocked_handoff_peers: filter.blocked_count(),
+            last_updated: manager.last_updated().await,
+        })
+    }
+
+    /// 立即更新 IP 过滤订阅并重新加载列表
+    ///
+    /// 新列表立即用于 Nebula 交给引擎的 Peer；引擎黑名单有变化时重新创建
+    /// Session，运行中的种子会被重新添加
+    pub async fn update_ip_filter(&self) -> Result<IpFilterStats> {
+        let manager = self
+            .ip_filter_manager
+            .as_ref()
+            .ok_or_else(|| NebulaError::InvalidConfig("IP 过滤未启用".to_string()))?;
+        manager.update_subscription().await?;
+        self.reload_ip_filter(manager).await?;
+        self.ip_filter_stats()
+            .await
+            .ok_or_else(|| NebulaError::Internal("IP 过滤未加载".to_string()))
+    }
+
+    /// 启动订阅列表定时更新协程，处理器释放后退出
+    pub fn spawn_ip_filter_updater(self: &Arc<Self>) {
+        let Some(manager) = self.ip_filter_manager.clone() else {
+            return;
+        };
+        let handler = Arc::downgrade(self);
+
+        tokio::spawn(async move {
+            // 启动时只读取了本地缓存，订阅已过期时立即更新
+            let mut update = manager.subscription_is_stale().await;
+            loop {
+                if update {
+                    let Some(handler) = handler.upgrade() else {
+                        return;
+                    };
+                    let result = match manager.update_subscription().await {
+                        Ok(()) => handler.reload_ip_filter(&manager).await,
+                        Err(e) => Err(e),
+                    };
+                    if let Err(e) = result {
+                        warn!("IP 过滤订阅更新失败: {}", e);
+                    }
+                }
+                tokio::time::sleep(manager.update_interval()).await;
+                update = true;
+            }
+        });
+    }
+
+    /// 重新加载 IP 过滤列表并写入引擎黑名单文件
+    ///
+    /// 引擎只在创建 Session 时读取黑名单，IPv4 范围有变化时重新创建 Session
+    async fn reload_ip_filter(&self, manager: &IpFilterManager) -> Result<()> {
+        let filter = manager.load().await?;
+        let path = manager.write_blocklist(&filter).await?;
+        let changed = self
+            .ip_filter
+            .read()
+            .await
+            .as_ref()
+            .is_none_or(|old| !old.same_blocklist(&filter));
+        *self.ip_filter.write().await = Some(Arc::new(filter));
+
+        if changed {
+            let blocklist_url = url::Url::from_file_path(&path).ok().map(|u| u.to_string());
+            self.restart_session(blocklist_url).await?;
+        }
+        Ok(())
+    }
+
+    /// 当前的引擎 Session
+    fn session(&self) -> Arc<Session> {
+        current_session(&self.session)
+    }
+
+    /// 以新的黑名单重新创建引擎 Session，并将所有种子重新添加到新的 Session
+    ///
+    /// 已下载的数据会保留，由引擎重新校验。新 Session 无法创建时
+    /// 所有种子任务都会失败
+    async fn restart_session(&self, blocklist_url: Option<String>) -> Result<()> {
+        let _engine = self.engine_lock.lock().await;
+        info!("IP 过滤列表已变化，重新创建 BitTorrent Session");
+
+        let detached: Vec<(TaskId, usize)> = {
+            let mut tasks = self.tasks.write().await;
+            tasks
+                .values_mut()
+                .map(|task| {
+                    task.restarting = true;
+                    (task.task_id, task.handle_id)
+                })
+                .collect()
+        };
+        let old = self.session();
+        for (_, handle_id) in &detached {
+            let _ = old.delete((*handle_id).into(), false).await;
+        }
+        // 旧 Session 停止后才会释放监听端口
+        old.stop().await;
+        drop(old);
+
+        let mut created = None;
+        for attempt in 1..=SESSION_RESTART_ATTEMPTS {
+            match create_session(&self.config, &self.data_dir, self.peer_id, blocklist_url.clone())
+                .await
+            {
+                Ok(session) => {
+                    created = Some(session);
+                    break;
+                }
+                Err(e) => {
+                    warn!("重新创建 Session 失败 (第 {} 次): {}", attempt, e);
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                }
+            }
+        }
+        let Some(session) = created else {
+            let mut tasks = self.tasks.write().await;
+            for (task_id, _) in detached {
+                if let Some(task) = tasks.remove(&task_id) {
+                    let _ = task.event_tx.send(DownloadEvent::TaskFailed {
+                        task_id,
+                        error: "BitTorrent 引擎重新启动失败".to_string(),
+                    });
+                }
+            }
+            return Err(NebulaError::Internal(
+                "重新创建 BitTorrent Session 失败".to_string(),
+            ));
+        };
+        *self.session.write().unwrap_or_else(PoisonError::into_inner) = session;
+
+        for (task_id, _) in detached {
+            let _ = self.reattach(task_id).await;
+        }
+        Ok(())
+    }
+
+    /// 解析种子元数据，不下载任何内容
+    ///
+    /// 磁力链接通过 DHT / Tracker 从 Peer 获取 info 字典，结果会被缓存，
+    /// 随后添加同一种子时直接使用缓存的元数据。超过 `metadata_timeout_secs`
+    /// 仍未获取到时返回 [`NebulaError::NoPeersAvailable`]
+    pub async fn resolve(&self, source: &DownloadSource) -> Result<TorrentMetainfo> {
+        match source {
+            DownloadSource::Torrent { path } => {
+                TorrentMetainfo::from_bytes(&read_torrent_file(path).await?)
+            }
+            DownloadSource::Magnet { uri, .. } => {
+                if let Some(meta) = self.cached_metadata(source).await {
+                    return Ok(meta);
+                }
+
+                info!("正在解析磁力链接元数据: {}", uri);
+                let magnet = MagnetLink::parse(uri)?;
+                let trackers = self.initial_trackers(source).await?;
+                let add_opts = AddTorrentOptions {
+                    list_only: true,
+                    trackers: Some(trackers.clone()),
+                    initial_peers: non_empty(
+                        filter_peers(&self.ip_filter, magnet.peer_addrs().await).await,
+                    ),
+                    ..Default::default()
+                };
+                let timeout = Duration::from_secs(self.config.metadata_timeout_secs);
+                let response = tokio::time::timeout(
+                    timeout,
+                    self.session()
+                        .add_torrent(AddTorrent::from_url(magnet.to_uri(false)), Some(add_opts)),
+                )
+                .await
+                .map_err(|_| NebulaError::NoPeersAvailable)?
+                .map_err(|e| NebulaError::Internal(format!("获取元数据失败: {}", e)))?;
+
+                let AddTorrentResponse::ListOnly(list) = response else {
+                    return Err(NebulaError::Internal("意外的添加种子响应".to_string()));
+                };
+                let mut meta = TorrentMetainfo::from_bytes(&list.torrent_bytes)?;
+                meta.trackers = trackers;
+                info!(
+                    "元数据已获取: {} ({} 个文件, {} 字节)",
+                    meta.name,
+                    meta.files.len(),
+                    meta.total_size
+                );
+
+                self.metadata
+                    .write()
+                    .await
+                    .insert(meta.info_hash, meta.clone());
+                Ok(meta)
+            }
+            _ => Err(NebulaError::UnsupportedProtocol(
+                "非 BitTorrent 来源".to_string(),
+            )),
+        }
+    }
+
+    /// 导出任务的种子元数据为 .torrent 文件
+    pub async fn export_torrent(&self, task_id: TaskId, path: &Path) -> Result<()> {
+        let (info_hash, source, trackers) = {
+            let tasks = self.tasks.read().await;
+            let task = tasks
+                .get(&task_id)
+                .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
+            (
+                task.info_hash,
+                task.source.clone(),
+                task.trackers.iter().map(|t| t.url.clone()).collect(),
+            )
+        };
+
+        let cached = self.metadata.read().await.get(&info_hash).cloned();
+        let mut meta = match cached {
+            Some(meta) => meta,
+            None => self.resolve(&source).await?,
+        };
+        meta.trackers = trackers;
+        meta.write_to(path).await
+    }
+
+    /// 获取元数据，超时后报告停滞并重新查询 DHT 和 Tracker
+    ///
+    /// # 返回
+    /// 任务在此期间被取消时返回 None
+    async fn resolve_until_found(
+        &self,
+        task_id: TaskId,
+        source: &DownloadSource,
+        event_tx: &mpsc::UnboundedSender<DownloadEvent>,
+    ) -> Result<Option<TorrentMetainfo>> {
+        let started = Instant::now();
+        let fail_after = self.config.stall_fail_after_secs.map(Duration::from_secs);
+        let mut stalled = false;
+
+        loop {
+            match self.resolve(source).await {
+                Ok(meta) => {
+                    if stalled {
+                        let _ = event_tx.send(DownloadEvent::StallRecovered {
+                            task_id,
+                            phase: StallPhase::Metadata,
+                        });
+                    }
+                    return Ok(Some(meta));
+                }
+                Err(NebulaError::NoPeersAvailable) => {}
+                Err(e) => return Err(e),
+            }
+
+            if !self.resolving.read().await.contains(&task_id) {
+                return Ok(None);
+            }
+
+            stalled = true;
+            let diagnostics = self.metadata_diagnostics(source, started.elapsed()).await;
+            warn!(
+                "获取元数据停滞: {} (DHT 节点 {:?}, Tracker {}/{})",
+                task_id,
+                diagnostics.dht_nodes,
+                diagnostics.trackers_reached,
+                diagnostics.trackers_total
+            );
+            let _ = event_tx.send(DownloadEvent::TaskStalled {
+                task_id,
+                phase: StallPhase::Metadata,
+                diagnostics,
+            });
+
+            if fail_after.is_some_and(|limit| started.elapsed() >= limit) {
+                return Err(NebulaError::NoPeersAvailable);
+            }
+            // 重新添加磁力链接会重新进行 DHT 查询并向 Tracker 通告
+            info!("重新查询 DHT 和 Tracker: {}", task_id);
+        }
+    }
+
+    /// 获取元数据阶段的诊断信息（向 Tracker 查询做种情况）
+    async fn metadata_diagnostics(
+        &self,
+        source: &DownloadSource,
+        stalled: Duration,
+    ) -> StallDiagnostics {
+        let trackers = self.initial_trackers(source).await.unwrap_or_default();
+        let info_hash = match source {
+            DownloadSource::Magnet { uri, .. } => {
+                MagnetLink::parse(uri).ok().and_then(|m| m.info_hash)
+            }
+            _ => None,
+        };
+
+        let (trackers_reached, peers_seen) = match info_hash {
+            Some(info_hash) => {
+                let tracker_manager = &self.tracker_manager;
+                let results: Vec<_> = futures::stream::iter(trackers.clone())
+                    .map(|url| async move { tracker_manager.scrape(&url, &info_hash).await })
+                    .buffer_unordered(SCRAPE_CONCURRENCY)
+                    .collect()
+                    .await;
+                let stats: Vec<_> = results.into_iter().flatten().collect();
+                let peers = stats
+                    .iter()
+                    .map(|s| (s.seeders + s.leechers) as usize)
+                    .max()
+                    .unwrap_or(0);
+                (stats.len(), peers)
+            }
+            None => (0, 0),
+        };
+
+        StallDiagnostics {
+            stalled_secs: stalled.as_secs(),
+            dht_nodes: self.dht_nodes(),
+            trackers_total: trackers.len(),
+            trackers_reached,
+            peers_seen,
+            peers_connected: 0,
+        }
+    }
+
+    /// DHT 路由表节点数
+    fn dht_nodes(&self) -> Option<usize> {
+        self.session()
+            .get_dht()
+            .map(|dht| dht.stats().routing_table_size)
+    }
+
+    /// 停滞检测循环，任务移除或下载完成时返回
+    ///
+    /// 超过 `stall_timeout_secs` 没有新数据时发送 [`DownloadEvent::TaskStalled`]，
+    /// 没有连接的 Peer 时向所有 Tracker 重新通告，获得的 Peer 直接交给运行中的种子
+    /// （不重新添加种子），通告间隔按指数退避。
+    /// 设置了 `stall_fail_after_secs` 时，持续停滞超过该时长后移除种子并返回
+    /// [`NebulaError::NoPeersAvailable`]
+    pub async fn watch_stall(&self, task_id: TaskId) -> Result<()> {
+        let timeout = Duration::from_secs(self.config.stall_timeout_secs);
+        let fail_after = self.config.stall_fail_after_secs.map(Duration::from_secs);
+        let mut detector = StallDetector::new(timeout, Instant::now());
+
+        loop {
+            tokio::time::sleep(STALL_CHECK_INTERVAL).await;
+
+            let (handle_id, restarting, event_tx) = {
+                let tasks = self.tasks.read().await;
+                match tasks.get(&task_id) {
+                    Some(task) => (task.handle_id, task.restarting, task.event_tx.clone()),
+                    None => return Ok(()),
+                }
+            };
+            if restarting {
+                continue;
+            }
+            let Some(handle) = self.session().get(handle_id.into()) else {
+                continue;
+            };
+
+            let stats = handle.stats();
+            if stats.finished {
+                return Ok(());
+            }
+            // 暂停或校验中不计入停滞
+            let Some(live) = &stats.live else {
+                detector.reset(Instant::now());
+                continue;
+            };
+
+            let since = match detector.observe(stats.progress_bytes, Instant::now()) {
+                StallCheck::Progressing | StallCheck::Waiting => continue,
+                StallCheck::Recovered => {
+                    info!("下载已恢复: {}", task_id);
+                    let _ = event_tx.send(DownloadEvent::StallRecovered {
+                        task_id,
+                        phase: StallPhase::Payload,
+                    });
+                    continue;
+                }
+                StallCheck::Stalled { since } => since,
+            };
+
+            let (trackers_total, trackers_reached) = {
+                let tasks = self.tasks.read().await;
+                tasks.get(&task_id).map_or((0, 0), |task| {
+                    let reached = task
+                        .trackers
+                        .iter()
+                        .filter(|t| t.status == TrackerStatus::Working)
+                        .count();
+                    (task.trackers.len(), reached)
+                })
+            };
+            let diagnostics = StallDiagnostics {
+                stalled_secs: since.elapsed().as_secs(),
+                dht_nodes: self.dht_nodes(),
+                trackers_total,
+                trackers_reached,
+                peers_seen: live.snapshot.peer_stats.seen,
+                peers_connected: live.snapshot.peer_stats.live,
+            };
+            warn!(
+                "下载停滞: {} (已连接 Peer {}, 发现 Peer {})",
+                task_id, diagnostics.peers_connected, diagnostics.peers_seen
+            );
+            let no_peers = diagnostics.peers_connected == 0;
+            let _ = event_tx.send(DownloadEvent::TaskStalled {
+                task_id,
+                phase: StallPhase::Payload,
+                diagnostics,
+            });
+
+            if fail_after.is_some_and(|limit| since.elapsed() >= limit) {
+                warn!("停滞超时，任务失败: {}", task_id);
+                if let Some(task) = self.tasks.write().await.remove(&task_id) {
+                    self.announce_stopped(&task, task.announced.iter().cloned().collect());
+                }
+                let _ = self.session().delete(handle_id.into(), false).await;
+                return Err(NebulaError::NoPeersAvailable);
+            }
+
+            if no_peers && detector.should_reannounce(Instant::now()) {
+                if let Some(local) = self.local_peer() {
+                    info!("重新向 Tracker 通告: {}", task_id);
+                    announce_trackers(
+                        &self.session(),
+                        &self.tasks,
+                        &self.tracker_manager,
+                        &self.ip_filter,
+                        task_id,
+                        local,
+                        false,
+                    )
+                    .await;
+                }
+            }
+        }
+    }
+
+    /// 查找缓存的磁力链接元数据
+    async fn cached_metadata(&self, source: &DownloadSource) -> Option<TorrentMetainfo> {
+        let DownloadSource::Magnet { uri, .. } = source else {
+            return None;
+        };
+        let info_hash = MagnetLink::parse(uri).ok()?.info_hash?;
+        self.metadata.read().await.get(&info_hash).cloned()
+    }
+
+    /// 种子自带的 Tracker 在前，注入的 Tracker 在后
+    async fn initial_trackers(&self, source: &DownloadSource) -> Result<Vec<String>> {
+        let mut trackers = embedded_trackers(source).await?;
+        for url in self.trackers.read().await.iter() {
+            if !trackers.contains(url) {
+                trackers.push(url.clone());
+            }
+        }
+        Ok(trackers)
+    }
+
+    /// 添加种子任务，`only_files` 指定只下载的文件序号
+    pub async fn start_with_files(
+        &self,
+        task_id: TaskId,
+        source: &DownloadSource,
+        save_path: PathBuf,
+        only_files: Option<Vec<usize>>,
+        event_tx: mpsc::UnboundedSender<DownloadEvent>,
+    ) -> Result<()> {
+        info!("开始 BitTorrent 下载: {:?}", source);
+
+        // 先获取元数据，确定真实的名称和大小，并校验文件选择
+        self.resolving.write().await.insert(task_id);
+        let resolved = self.resolve_until_found(task_id, source, &event_tx).await;
+        self.resolving.write().await.remove(&task_id);
+        let Some(mut meta) = resolved? else {
+            info!("获取元数据期间任务已取消: {}", task_id);
+            return Ok(());
+        };
+        if let Some(indices) = &only_files {
+            meta.validate_file_indices(indices)?;
+        }
+        if let DownloadSource::Magnet { uri, .. } = source {
+            for seed in MagnetLink::parse(uri)?.web_seeds {
+                if !meta.web_seeds.contains(&seed) {
+                    meta.web_seeds.push(seed);
+                }
+            }
+        }
+
+        let embedded = embedded_trackers(source).await?;
+        let mut trackers: Vec<TorrentTracker> = Vec::new();
+        for url in self.initial_trackers(source).await? {
+            let origin = if embedded.contains(&url) {
+                TrackerSource::Torrent
+            } else {
+                TrackerSource::Injected
+            };
+            push_tracker(&mut trackers, url, origin);
+        }
+
+        // 持有到任务注册完成，避免种子被添加到即将替换的 Session
+        let engine = self.engine_lock.lock().await;
+        let tracker_urls: Vec<String> = trackers.iter().map(|t| t.url.clone()).collect();
+        let (handle_id, handle) = self
+            .add_to_session(source, &save_path, tracker_urls.clone(), only_files.clone())
+            .await?;
+
+        // 引擎中已有同一种子且属于其他任务时，不能重复管理
+        let duplicate = self
+            .tasks
+            .read()
+            .await
+            .values()
+            .find(|t| t.handle_id == handle_id && t.task_id != task_id)
+            .map(|t| t.task_id);
+        if let Some(existing) = duplicate {
+            return Err(NebulaError::TaskAlreadyExists(format!(
+                "{} ({})",
+                meta.name, existing
+            )));
+        }
+
+        // 发送元数据接收事件（所选文件的大小）
+        let total_size = match &only_files {
+            Some(indices) => indices.iter().map(|&i| meta.files[i].length).sum(),
+            None => meta.total_size,
+        };
+        let _ = event_tx.send(DownloadEvent::MetadataReceived {
+            task_id,
+            name: meta.name.clone(),
+            total_size,
+            file_count: only_files.as_ref().map_or(meta.files.len(), |f| f.len()),
+            mime_type: None,
+            supports_resume: true,
+            thumbnail: None,
+            duration_secs: None,
+        });
+
+        // 注册任务映射
+        {
+            let mut tasks = self.tasks.write().await;
+            tasks.insert(
+                task_id,
+                TorrentTask {
+                    handle_id,
+                    task_id,
+                    save_path,
+                    name: meta.name.clone(),
+                    source: source.clone(),
+                    info_hash: handle.info_hash().0,
+                    only_files,
+                    metainfo: meta,
+                    trackers,
+                    engine_trackers: tracker_urls.into_iter().collect(),
+                    announced: HashSet::new(),
+                    peer_samples: HashMap::new(),
+                    restarting: false,
+                    web_seed_bytes: 0,
+                    event_tx: event_tx.clone(),
+                    monitor_active: false,
+                },
+            );
+        }
+        drop(engine);
+
+        // 发送开始事件
+        let _ = event_tx.send(DownloadEvent::TaskStarted { task_id });
+
+        // 启动进度监控任务
+        self.spawn_progress_monitor(task_id, event_tx);
+
+        Ok(())
+    }
+
+    /// 将种子添加到 librqbit Session
+    ///
+    /// 来源自带的 Tracker 会被去掉，统一由 `trackers` 指定，
+    /// 这样用户删除的 Tracker 才不会被引擎继续使用
+    async fn add_to_session(
+        &self,
+        source: &DownloadSource,
+        save_path: &Path,
+        trackers: Vec<String>,
+        only_files: Option<Vec<usize>>,
+    ) -> Result<(usize, ManagedTorrentHandle)> {
+        // 根据来源类型添加种子，已解析过的磁力链接直接使用缓存的元数据
+        let mut initial_peers = None;
+        let add_torrent = match source {
+            DownloadSource::Magnet { uri, .. } => {
+                let magnet = MagnetLink::parse(uri)?;
+                let peers = filter_peers(&self.ip_filter, magnet.peer_addrs().await).await;
+                initial_peers = non_empty(peers);
+                match self.cached_metadata(source).await {
+                    Some(meta) => {
+                        let content = meta.to_torrent_bytes();
+                        AddTorrent::from_bytes(strip_torrent_trackers(&content).unwrap_or(content))
+                    }
+                    None => AddTorrent::from_url(magnet.to_uri(false)),
+                }
+            }
+            DownloadSource::Torrent { path } => {
+                let content = read_torrent_file(path).await?;
+                AddTorrent::from_bytes(strip_torrent_trackers(&content).unwrap_or(content))
+            }
+            _ => {
+                return Err(NebulaError::UnsupportedProtocol(
+                    "非 BitTorrent 来源".to_string(),
+                ))
+            }
+        };
+
+        // 构建添加选项
+        let add_opts = AddTorrentOptions {
+            output_folder: Some(save_path.to_string_lossy().to_string()),
+            overwrite: true,
+            only_files,
+            trackers: non_empty(trackers),
+            initial_peers,
+            ..Default::default()
+        };
+
+        // 添加种子到 Session
+        let response = self
+            .session()
+            .add_torrent(add_torrent, Some(add_opts))
+            .await
+            .map_err(|e| NebulaError::Internal(format!("添加种子失败: {}", e)))?;
+
+        match response {
+            AddTorrentResponse::Added(id, handle) => {
+                info!("种子已添加: id={}", id);
+                Ok((id, handle))
+            }
+            AddTorrentResponse::AlreadyManaged(id, handle) => {
+                warn!("种子已存在: id={}", id);
+                Ok((id, handle))
+            }
+            AddTorrentResponse::ListOnly(_) => {
+                Err(NebulaError::Internal("意外的 ListOnly 响应".to_string()))
+            }
+        }
+    }
+
+    /// 以当前的 Tracker 列表将种子重新添加到引擎
+    ///
+    /// 已下载的数据会保留，由引擎重新校验。同一任务同时只能有一次重新添加；
+    /// 种子已移除但无法重新添加时，任务会被移除并发送 [`DownloadEvent::TaskFailed`]
+    async fn restart_torrent(&self, task_id: TaskId) -> Result<()> {
+        let _engine = self.engine_lock.lock().await;
+        let handle_id = {
+            let mut tasks = self.tasks.write().await;
+            let task = tasks
+                .get_mut(&task_id)
+                .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
+            if task.restarting {
+                return Err(NebulaError::InvalidTaskState {
+                    current: "正在重新添加".to_string(),
+                    action: "重新添加".to_string(),
+                });
+            }
+            task.restarting = true;
+            task.handle_id
+        };
+
+        if let Err(e) = self.session().delete(handle_id.into(), false).await {
+            // 种子仍在引擎中，原句柄继续有效
+            if let Some(task) = self.tasks.write().await.get_mut(&task_id) {
+                task.restarting = false;
+            }
+            return Err(NebulaError::Internal(format!("移除种子失败: {}", e)));
+        }
+        self.reattach(task_id).await
+    }
+
+    /// 将已从引擎移除的种子按当前的 Tracker 列表重新添加，并清除重新添加标记
+    ///
+    /// 重新添加期间任务已被取消时移除新添加的种子；无法重新添加时
+    /// 任务会被移除并发送 [`DownloadEvent::TaskFailed`]
+    async fn reattach(&self, task_id: TaskId) -> Result<()> {
+        let Some((source, save_path, tracker_urls, only_files)) =
+            self.tasks.read().await.get(&task_id).map(|task| {
+                (
+                    task.source.clone(),
+                    task.save_path.clone(),
+                    task.trackers.iter().map(|t| t.url.clone()).collect::<Vec<_>>(),
+                    task.only_files.clone(),
+                )
+            })
+        else {
+            return Ok(());
+        };
+        let added = self
+            .add_to_session(&source, &save_path, tracker_urls.clone(), only_files)
+            .await;
+
+        let mut tasks = self.tasks.write().await;
+        match added {
+            Ok((new_id, _)) => match tasks.get_mut(&task_id) {
+                Some(task) => {
+                    task.restarting = false;
+                    task.handle_id = new_id;
+                    task.engine_trackers = tracker_urls.into_iter().collect();
+                    task.peer_samples.clear();
+                    task.web_seed_bytes = 0;
+                    Ok(())
+                }
+                None => {
+                    // 重新添加期间任务已被取消
+                    drop(tasks);
+                    let _ = self.session().delete(new_id.into(), false).await;
+                    Ok(())
+                }
+            },
+            Err(e) => {
+                // 原句柄已失效，任务无法继续
+                warn!("重新添加种子失败，任务失败: {} ({})", task_id, e);
+                if let Some(task) = tasks.remove(&task_id) {
+                    let _ = task.event_tx.send(DownloadEvent::TaskFailed {
+                        task_id,
+                        error: e.to_string(),
+                    });
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// 强制重新校验已下载的数据
+    ///
+    /// 种子会被重新添加到引擎，由引擎逐块校验磁盘上的数据，
+    /// 校验进度通过 [`DownloadEvent::CheckProgress`] 通知
+    pub async fn recheck(&self, task_id: TaskId) -> Result<()> {
+        info!("重新校验种子: {}", task_id);
+        self.restart_torrent(task_id).await?;
+        self.ensure_progress_monitor(task_id).await;
+        Ok(())
+    }
+
+    /// 进度监控已退出时重新启动（例如下载完成后重新校验）
+    async fn ensure_progress_monitor(&self, task_id: TaskId) {
+        let event_tx = {
+            let tasks = self.tasks.read().await;
+            tasks
+                .get(&task_id)
+                .filter(|t| !t.monitor_active)
+                .map(|t| t.event_tx.clone())
+        };
+        if let Some(event_tx) = event_tx {
+            self.spawn_progress_monitor(task_id, event_tx);
+        }
+    }
+
+    /// Web Seed 守护循环，任务移除、下载完成或没有可用的 Web Seed 时返回
+    ///
+    /// 连接的 Peer 为 0 或下载速度持续低于 `web_seed_min_speed` 超过
+    /// `web_seed_delay_secs` 后，尚未下载的分块通过 HTTP 从 Web Seed 获取，
+    /// 之后由引擎校验
+    pub async fn run_web_seeds(&self, task_id: TaskId, http: Arc<HttpHandler>) {
+        if !self.config.enable_web_seeds {
+            return;
+        }
+
+        let delay = Duration::from_secs(self.config.web_seed_delay_secs);
+        let mut slow_since: Option<Instant> = None;
+        let mut failures = 0;
+
+        loop {
+            tokio::time::sleep(WEB_SEED_CHECK_INTERVAL).await;
+
+            let (handle_id, restarting) = {
+                let tasks = self.tasks.read().await;
+                match tasks.get(&task_id) {
+                    Some(task) if !task.metainfo.web_seeds.is_empty() => {
+                        (task.handle_id, task.restarting)
+                    }
+                    _ => return,
+                }
+            };
+            if restarting {
+                continue;
+            }
+            let Some(handle) = self.session().get(handle_id.into()) else {
+                continue;
+            };
+
+            let stats = handle.stats();
+            if stats.finished {
+                return;
+            }
+
+            // 暂停或校验中不计入
+            let slow = stats.live.as_ref().is_some_and(|live| {
+                let download_speed = (live.download_speed.mbps * 1024.0 * 1024.0 / 8.0) as u64;
+                live.snapshot.peer_stats.live == 0
+                    || download_speed < self.config.web_seed_min_speed
+            });
+            if !slow {
+                slow_since = None;
+                continue;
+            }
+            if slow_since.get_or_insert_with(Instant::now).elapsed() < delay {
+                continue;
+            }
+            slow_since = None;
+
+            match self.fetch_from_web_seeds(task_id, &http).await {
+                Ok(bytes) => {
+                    info!("Web Seed 下载完成: {} ({} 字节)", task_id, bytes);
+                    failures = 0;
+                }
+                Err(e) => {
+                    failures += 1;
+                    warn!("Web Seed 下载失败 ({}/{}): {}", failures, WEB_SEED_MAX_FAILURES, e);
+                    if failures >= WEB_SEED_MAX_FAILURES {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    /// 通过 Web Seed 获取尚未下载的分块，然后由引擎重新校验
+    ///
+    /// 只请求引擎还没有的分块对应的文件范围。下载期间种子留在引擎中，
+    /// 已获取的字节计入进度；每个范围之前检查任务是否已取消或暂停。
+    /// 引擎不知道外部写入的数据，获取结束后种子会被重新添加，由引擎校验
+    async fn fetch_from_web_seeds(&self, task_id: TaskId, http: &HttpHandler) -> Result<u64> {
+        let (handle_id, meta, save_path, selected) = {
+            let tasks = self.tasks.read().await;
+            let task = tasks
+                .get(&task_id)
+                .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
+            let selected: Vec<usize> = match &task.only_files {
+                Some(indices) => indices.clone(),
+                None => (0..task.metainfo.files.len()).collect(),
+            };
+            (task.handle_id, task.metainfo.clone(), task.save_path.clone(), selected)
+        };
+        let handle = self
+            .session()
+            .get(handle_id.into())
+            .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
+
+        let have: Vec<bool> = handle
+            .with_chunk_tracker(|chunks| {
+                let have = chunks.get_have_pieces();
+                (0..have.len()).map(|i| have[i]).collect()
+            })
+            .map_err(|e| NebulaError::Internal(format!("读取分块状态失败: {}", e)))?;
+        let ranges = webseed::missing_ranges(&meta, &have, &selected);
+        if ranges.is_empty() {
+            return Ok(0);
+        }
+
+        info!("通过 Web Seed 下载 {} 个范围: {}", ranges.len(), meta.name);
+        self.ensure_progress_monitor(task_id).await;
+        let mut fetched = 0;
+        let mut result = Ok(());
+        for range in &ranges {
+            if !self.web_seed_can_continue(task_id, &handle).await {
+                info!("任务已取消或暂停，停止 Web Seed 下载: {}", task_id);
+                if let Some(task) = self.tasks.write().await.get_mut(&task_id) {
+                    task.web_seed_bytes = 0;
+                }
+                return Ok(fetched);
+            }
+            match webseed::fetch_range(http, &meta.web_seeds, &meta, &save_path, range).await {
+                Ok(bytes) => {
+                    fetched += bytes;
+                    if let Some(task) = self.tasks.write().await.get_mut(&task_id) {
+                        task.web_seed_bytes = fetched;
+                    }
+                }
+                Err(e) => {
+                    result = Err(e);
+                    break;
+                }
+            }
+        }
+
+        // 失败前已获取的数据同样交给引擎校验
+        if fetched > 0 {
+            self.restart_torrent(task_id).await?;
+            self.ensure_progress_monitor(task_id).await;
+        }
+        result.map(|()| fetched)
+    }
+
+    /// Web Seed 下载能否继续：任务仍在，种子仍是 `handle` 且没有暂停
+    async fn web_seed_can_continue(&self, task_id: TaskId, handle: &ManagedTorrentHandle) -> bool {
+        let handle_id = match self.tasks.read().await.get(&task_id) {
+            Some(task) if !task.restarting => task.handle_id,
+            _ => return false,
         };
+        // Session 被替换后引擎句柄会变化
+        self.session()
+            .get(handle_id.into())
+            .is_some_and(|current| Arc::ptr_eq(&current, handle))
+            && !matches!(handle.stats().state, TorrentStatsState::Paused)
+    }
 
-        // 创建 Session - 返回的已经是 Arc<Session>
-        let session = Session::new_with_opts(data_dir, session_opts)
-            .await
-            .map_err(|e| NebulaError::Internal(format!("创建 BitTorrent Session 失败: {}", e)))?;
-
-        info!("BitTorrent 引擎已初始化");
+    /// 获取种子的 Tracker 列表及状态
+    pub async fn get_trackers(&self, task_id: TaskId) -> Result<Vec<TorrentTracker>> {
+        let tasks = self.tasks.read().await;
+        tasks
+            .get(&task_id)
+            .map(|t| t.trackers.clone())
+            .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))
+    }
 
-        Ok(Self {
-            session,
-            config,
-            tasks: Arc::new(RwLock::new(HashMap::new())),
-            trackers,
-        })
+    /// 立即向所有 Tracker 查询做种/下载人数
+    pub async fn refresh_trackers(&self, task_id: TaskId) -> Result<Vec<TorrentTracker>> {
+        scrape_trackers(&self.tasks, &self.tracker_manager, task_id).await;
+        self.get_trackers(task_id).await
     }
 
-    /// 添加种子任务
-    async fn add_torrent(
-        &self,
-        task_id: TaskId,
-        source: &DownloadSource,
-        save_path: PathBuf,
-        event_tx: broadcast::Sender<DownloadEvent>,
-    ) -> Result<()> {
-        // 构建添加选项，附加远程 Tracker 列表
-        let add_opts = AddTorrentOptions {
-            output_folder: Some(save_path.to_string_lossy().to_string()),
-            overwrite: true,
-            trackers: if self.trackers.is_empty() {
-                None
-            } else {
-                Some(self.trackers.clone())
-            },
-            ..Default::default()
-        };
+    /// 为运行中的种子添加 Tracker
+    ///
+    /// 种子不会被重新添加到引擎：新的 Tracker 由我们以引擎的 peer_id 和监听端口
+    /// 定期通告，获得的 Peer 直接交给运行中的种子
+    pub async fn add_trackers(&self, task_id: TaskId, urls: Vec<String>) -> Result<()> {
+        for url in &urls {
+            validate_tracker_url(url)?;
+        }
 
-        // 根据来源类型添加种子
-        let add_torrent = match source {
-            DownloadSource::Magnet { uri, .. } => AddTorrent::from_url(uri.as_str()),
-            DownloadSource::Torrent { path } => {
-                let content = tokio::fs::read(path).await.map_err(|e| NebulaError::IoError {
-                    path: path.clone(),
-                    message: e.to_string(),
-                })?;
-                AddTorrent::from_bytes(content)
-            }
-            _ => {
-                return Err(NebulaError::UnsupportedProtocol(
-                    "非 BitTorrent 来源".to_string(),
-                ))
+        let changed = {
+            let mut tasks = self.tasks.write().await;
+            let task = tasks
+                .get_mut(&task_id)
+                .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
+            let before = task.trackers.len();
+            for url in urls {
+                push_tracker(&mut task.trackers, url, TrackerSource::User);
             }
+            task.trackers.len() != before
         };
 
-        // 添加种子到 Session
-        let response = self
-            .session
-            .add_torrent(add_torrent, Some(add_opts))
-            .await
-            .map_err(|e| NebulaError::Internal(format!("添加种子失败: {}", e)))?;
+        if changed {
+            info!("Tracker 列表已更新: {}", task_id);
+            self.spawn_tracker_announce(task_id);
+            self.spawn_tracker_refresh(task_id);
+        }
+        Ok(())
+    }
 
-        let (handle_id, handle) = match response {
-            AddTorrentResponse::Added(id, handle) => {
-                info!("种子已添加: id={}", id);
-                (id, handle)
-            }
-            AddTorrentResponse::AlreadyManaged(id, handle) => {
-                warn!("种子已存在: id={}", id);
-                (id, handle)
-            }
-            AddTorrentResponse::ListOnly(_) => {
-                return Err(NebulaError::Internal("意外的 ListOnly 响应".to_string()));
+    /// 从运行中的种子移除 Tracker
+    ///
+    /// 由我们通告过的 Tracker 会收到 stopped；移除了添加种子时交给引擎的 Tracker 时，
+    /// 种子会以新的 Tracker 列表重新添加到引擎，引擎才会停止向它们通告
+    pub async fn remove_trackers(&self, task_id: TaskId, urls: Vec<String>) -> Result<()> {
+        let restart = {
+            let mut tasks = self.tasks.write().await;
+            let task = tasks
+                .get_mut(&task_id)
+                .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
+            let before = task.trackers.len();
+            task.trackers.retain(|t| !urls.contains(&t.url));
+            if task.trackers.len() == before {
+                return Ok(());
             }
+
+            let stopped: Vec<String> = urls
+                .iter()
+                .filter(|url| task.announced.remove(*url))
+                .cloned()
+                .collect();
+            self.announce_stopped(task, stopped);
+            urls.iter().any(|url| task.engine_trackers.contains(url))
         };
 
-        // 从 stats 获取基本信息
-        let stats = handle.stats();
-        let total_size = stats.total_bytes;
-        
-        // 从源获取名称，或使用 info_hash
-        let name = match source {
-            DownloadSource::Magnet { display_name, .. } => {
-                display_name.clone().unwrap_or_else(|| format!("torrent-{}", handle_id))
-            }
-            DownloadSource::Torrent { path } => {
-                path.file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("unknown")
-                    .to_string()
-            }
-            _ => format!("torrent-{}", handle_id),
+        info!("Tracker 列表已更新: {}", task_id);
+        if restart {
+            self.restart_torrent(task_id).await?;
+            self.ensure_progress_monitor(task_id).await;
+        }
+        Ok(())
+    }
+
+    /// 获取当前连接的 Peer 列表
+    ///
+    /// 速度按两次采样之间的计数器差值计算，没有最近的采样时先采样一次，
+    /// 等待 [`PEER_SAMPLE_INTERVAL`] 后再采样
+    pub async fn get_peers(&self, task_id: TaskId) -> Result<Vec<PeerInfo>> {
+        let fresh = {
+            let tasks = self.tasks.read().await;
+            let task = tasks
+                .get(&task_id)
+                .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
+            task.peer_samples
+                .values()
+                .next()
+                .is_some_and(|sample| sample.at.elapsed() < PEER_SAMPLE_MAX_AGE)
         };
+        if !fresh && !self.sample_peers(task_id).await?.is_empty() {
+            tokio::time::sleep(PEER_SAMPLE_INTERVAL).await;
+        }
+        self.sample_peers(task_id).await
+    }
 
-        // 发送元数据接收事件
-        let _ = event_tx.send(DownloadEvent::MetadataReceived {
-            task_id,
-            name: name.clone(),
-            total_size,
-            file_count: 1, // 简化处理，不再获取详细文件列表
-        });
+    /// 采样 Peer 计数器，按与上一次采样的差值计算速度
+    async fn sample_peers(&self, task_id: TaskId) -> Result<Vec<PeerInfo>> {
+        let mut tasks = self.tasks.write().await;
+        let task = tasks
+            .get_mut(&task_id)
+            .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
 
-        // 注册任务映射
-        {
-            let mut tasks = self.tasks.write().await;
-            tasks.insert(
-                task_id,
-                TorrentTask {
-                    handle_id,
-                    task_id,
-                    save_path,
-                    name,
+        let handle = self
+            .session()
+            .get(task.handle_id.into())
+            .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
+        let Some(live) = handle.live() else {
+            // 种子尚未进入下载状态（如正在校验或已暂停）
+            return Ok(Vec::new());
+        };
+
+        let snapshot = live.per_peer_stats_snapshot(PeerStatsFilter::default());
+        let now = Instant::now();
+        let mut samples = HashMap::with_capacity(snapshot.peers.len());
+        let mut peers = Vec::with_capacity(snapshot.peers.len());
+
+        for (address, stats) in snapshot.peers {
+            let downloaded = stats.counters.fetched_bytes;
+            let uploaded = stats.counters.uploaded_bytes;
+
+            let (download_speed, upload_speed) = match task.peer_samples.get(&address) {
+                Some(prev) => {
+                    let elapsed = now.duration_since(prev.at).as_secs_f64();
+                    if elapsed > 0.0 {
+                        (
+                            (downloaded.saturating_sub(prev.downloaded) as f64 / elapsed) as u64,
+                            (uploaded.saturating_sub(prev.uploaded) as f64 / elapsed) as u64,
+                        )
+                    } else {
+                        (0, 0)
+                    }
+                }
+                None => (0, 0),
+            };
+
+            samples.insert(
+                address.clone(),
+                PeerSample {
+                    downloaded,
+                    uploaded,
+                    at: now,
                 },
             );
+            peers.push(PeerInfo {
+                address,
+                client: None,
+                flags: stats.state.to_string(),
+                download_speed,
+                upload_speed,
+                downloaded,
+                uploaded,
+                progress: None,
+            });
         }
 
-        // 发送开始事件
-        let _ = event_tx.send(DownloadEvent::TaskStarted { task_id });
+        task.peer_samples = samples;
+        peers.sort_by_key(|p| std::cmp::Reverse(p.download_speed));
+        Ok(peers)
+    }
 
-        // 启动进度监控任务
-        self.spawn_progress_monitor(task_id, handle_id, event_tx);
+    /// 在后台刷新 Tracker 状态
+    fn spawn_tracker_refresh(&self, task_id: TaskId) {
+        let tasks = Arc::clone(&self.tasks);
+        let tracker_manager = Arc::clone(&self.tracker_manager);
+        tokio::spawn(async move {
+            scrape_trackers(&tasks, &tracker_manager, task_id).await;
+        });
+    }
 
-        Ok(())
+    /// 在后台向引擎不知道的 Tracker 通告
+    fn spawn_tracker_announce(&self, task_id: TaskId) {
+        let Some(local) = self.local_peer() else {
+            return;
+        };
+        let session = self.session();
+        let tasks = Arc::clone(&self.tasks);
+        let tracker_manager = Arc::clone(&self.tracker_manager);
+        let ip_filter = Arc::clone(&self.ip_filter);
+        tokio::spawn(async move {
+            announce_trackers(
+                &session,
+                &tasks,
+                &tracker_manager,
+                &ip_filter,
+                task_id,
+                local,
+                true,
+            )
+            .await;
+        });
+    }
+
+    /// 引擎在 Tracker 上登记的本地 Peer
+    fn local_peer(&self) -> Option<LocalPeer> {
+        LocalPeer::of(&self.session(), self.peer_id)
+    }
+
+    /// 在后台向 `urls` 发送 stopped，让 Tracker 移除本地 Peer
+    fn announce_stopped(&self, task: &TorrentTask, urls: Vec<String>) {
+        if urls.is_empty() {
+            return;
+        }
+        let (Some(local), Some(handle)) =
+            (self.local_peer(), self.session().get(task.handle_id.into()))
+        else {
+            return;
+        };
+        let request = local.request(task.info_hash, &handle.stats(), AnnounceEvent::Stopped);
+        let tracker_manager = Arc::clone(&self.tracker_manager);
+        tokio::spawn(async move {
+            futures::stream::iter(urls)
+                .for_each_concurrent(SCRAPE_CONCURRENCY, |url| {
+                    let tracker_manager = &tracker_manager;
+                    let request = &request;
+                    async move {
+                        if let Err(e) = tracker_manager.announce(&url, request).await {
+                            debug!("Tracker stopped 通告失败 {}: {}", url, e);
+                        }
+                    }
+                })
+                .await;
+        });
     }
 
     /// 启动进度监控协程
     fn spawn_progress_monitor(
         &self,
         task_id: TaskId,
-        handle_id: usize,
-        event_tx: broadcast::Sender<DownloadEvent>,
+        event_tx: mpsc::UnboundedSender<DownloadEvent>,
     ) {
         let session = Arc::clone(&self.session);
         let tasks = Arc::clone(&self.tasks);
+        let tracker_manager = Arc::clone(&self.tracker_manager);
+        let ip_filter = Arc::clone(&self.ip_filter);
+        let peer_id = self.peer_id;
 
         tokio::spawn(async move {
-            loop {
-                // 检查任务是否仍然存在
-                {
-                    let tasks_guard = tasks.read().await;
-                    if !tasks_guard.contains_key(&task_id) {
-                        debug!("任务已移除，停止监控: {}", task_id);
-                        break;
-                    }
+            if let Some(task) = tasks.write().await.get_mut(&task_id) {
+                task.monitor_active = true;
+            }
+
+            monitor_progress(
+                &session,
+                &tasks,
+                &tracker_manager,
+                &ip_filter,
+                task_id,
+                peer_id,
+                &event_tx,
+            )
+            .await;
+
+            if let Some(task) = tasks.write().await.get_mut(&task_id) {
+                task.monitor_active = false;
+            }
+        });
+    }
+}
+
+/// 进度监控循环，任务移除、下载完成或没有事件接收者时返回
+async fn monitor_progress(
+    session: &SessionSlot,
+    tasks: &Arc<RwLock<HashMap<TaskId, TorrentTask>>>,
+    tracker_manager: &Arc<TrackerManager>,
+    ip_filter: &Arc<RwLock<Option<Arc<IpFilter>>>>,
+    task_id: TaskId,
+    peer_id: [u8; 20],
+    event_tx: &mpsc::UnboundedSender<DownloadEvent>,
+) {
+    let mut last_tracker_refresh: Option<Instant> = None;
+    let mut checking = false;
+
+    loop {
+        // 检查任务是否仍然存在，并取得当前的引擎句柄
+        let (handle_id, web_seed_bytes) = {
+            let tasks_guard = tasks.read().await;
+            match tasks_guard.get(&task_id) {
+                Some(task) if task.restarting => (None, 0),
+                Some(task) => (Some(task.handle_id), task.web_seed_bytes),
+                None => {
+                    debug!("任务已移除，停止监控: {}", task_id);
+                    return;
                 }
+            }
+        };
 
-                // 获取种子状态
-                if let Some(handle) = session.get(handle_id.into()) {
-                    let stats = handle.stats();
+        // 定期刷新 Tracker 状态，并向引擎不知道的 Tracker 通告
+        if last_tracker_refresh
+            .map(|t| t.elapsed() >= TRACKER_REFRESH_INTERVAL)
+            .unwrap_or(true)
+        {
+            last_tracker_refresh = Some(Instant::now());
+            // Session 可能已被替换，监听端口每次重新读取
+            let session = current_session(session);
+            let local = LocalPeer::of(&session, peer_id);
+            let tasks = Arc::clone(tasks);
+            let tracker_manager = Arc::clone(tracker_manager);
+            let ip_filter = Arc::clone(ip_filter);
+            tokio::spawn(async move {
+                scrape_trackers(&tasks, &tracker_manager, task_id).await;
+                if let Some(local) = local {
+                    announce_trackers(
+                        &session,
+                        &tasks,
+                        &tracker_manager,
+                        &ip_filter,
+                        task_id,
+                        local,
+                        true,
+                    )
+                    .await;
+                }
+            });
+        }
 
-                    // 从 stats 获取进度信息
-                    let total_bytes = stats.total_bytes;
-                    let downloaded_bytes = stats.progress_bytes;
+        // 正在重新添加到引擎，稍后再查询
+        let Some(handle_id) = handle_id else {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            continue;
+        };
 
-                    let mut progress = Progress::new(total_bytes, downloaded_bytes);
+        // 获取种子状态
+        let Some(handle) = current_session(session).get(handle_id.into()) else {
+            warn!("找不到种子句柄: {}", handle_id);
+            return;
+        };
+        let stats = handle.stats();
 
-                    // 从 live stats 获取速度信息
-                    if let Some(ref live) = stats.live {
-                        let download_speed = (live.download_speed.mbps * 1024.0 * 1024.0 / 8.0) as u64;
-                        let upload_speed = (live.upload_speed.mbps * 1024.0 * 1024.0 / 8.0) as u64;
-                        progress.update_speed(download_speed, upload_speed);
+        // 从 stats 获取进度信息
+        let total_bytes = stats.total_bytes;
+        let downloaded_bytes = stats.progress_bytes;
 
-                        // 发送 Peer 更新事件
-                        let connected_peers = live.snapshot.peer_stats.live as usize;
-                        let total_peers = live.snapshot.peer_stats.seen as usize;
-                        let _ = event_tx.send(DownloadEvent::PeerUpdate {
-                            task_id,
-                            connected_peers,
-                            total_peers,
-                        });
-                    }
+        // 引擎正在校验磁盘上的已有数据，此时 progress_bytes 为已校验的字节数
+        if matches!(stats.state, TorrentStatsState::Initializing) {
+            checking = true;
+            if event_tx
+                .send(DownloadEvent::CheckProgress {
+                    task_id,
+                    checked_bytes: downloaded_bytes,
+                    total_bytes,
+                })
+                .is_err()
+            {
+                return; // 没有接收者了
+            }
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            continue;
+        }
+        if checking {
+            checking = false;
+            info!("种子校验完成: {} (有效数据 {} 字节)", task_id, downloaded_bytes);
+            let _ = event_tx.send(DownloadEvent::CheckCompleted {
+                task_id,
+                valid_bytes: downloaded_bytes,
+                total_bytes,
+            });
+        }
 
-                    // 发送进度事件
-                    if event_tx
-                        .send(DownloadEvent::ProgressUpdated {
-                            task_id,
-                            progress: progress.clone(),
-                        })
-                        .is_err()
-                    {
-                        break; // 没有接收者了
-                    }
+        let mut progress = Progress::new(total_bytes, downloaded_bytes);
+        let completed = progress.is_completed();
+        // Web Seed 获取的数据在引擎校验前也计入进度，但不据此判断完成
+        if web_seed_bytes > 0 {
+            progress.update_downloaded((downloaded_bytes + web_seed_bytes).min(total_bytes));
+        }
 
-                    // 检查是否完成
-                    if progress.is_completed() {
-                        info!("种子下载完成: {}", task_id);
-                        let _ = event_tx.send(DownloadEvent::TaskCompleted {
-                            task_id,
-                            completed_at: chrono::Utc::now(),
-                        });
-                        break;
+        // 从 live stats 获取速度信息
+        if let Some(ref live) = stats.live {
+            let download_speed = (live.download_speed.mbps * 1024.0 * 1024.0 / 8.0) as u64;
+            let upload_speed = (live.upload_speed.mbps * 1024.0 * 1024.0 / 8.0) as u64;
+            progress.update_speed(download_speed, upload_speed);
+
+            // 发送 Peer 更新事件
+            let connected_peers = live.snapshot.peer_stats.live;
+            let total_peers = live.snapshot.peer_stats.seen;
+            let _ = event_tx.send(DownloadEvent::PeerUpdate {
+                task_id,
+                connected_peers,
+                total_peers,
+            });
+        }
+
+        // 发送进度事件
+        if event_tx
+            .send(DownloadEvent::ProgressUpdated {
+                task_id,
+                progress: progress.clone(),
+            })
+            .is_err()
+        {
+            return; // 没有接收者了
+        }
+
+        // 检查是否完成
+        if completed {
+            info!("种子下载完成: {}", task_id);
+            let _ = event_tx.send(DownloadEvent::TaskCompleted {
+                task_id,
+                completed_at: chrono::Utc::now(),
+            });
+            return;
+        }
+
+        // 每秒更新一次
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
+
+/// 向任务的所有 Tracker 发送 scrape 请求并更新状态
+async fn scrape_trackers(
+    tasks: &RwLock<HashMap<TaskId, TorrentTask>>,
+    tracker_manager: &TrackerManager,
+    task_id: TaskId,
+) {
+    let (info_hash, urls) = {
+        let mut tasks_guard = tasks.write().await;
+        let Some(task) = tasks_guard.get_mut(&task_id) else {
+            return;
+        };
+        for tracker in &mut task.trackers {
+            tracker.status = TrackerStatus::Updating;
+        }
+        (
+            task.info_hash,
+            task.trackers.iter().map(|t| t.url.clone()).collect::<Vec<_>>(),
+        )
+    };
+
+    let results: Vec<_> = futures::stream::iter(urls)
+        .map(|url| async move {
+            let result = tracker_manager.scrape(&url, &info_hash).await;
+            (url, result)
+        })
+        .buffer_unordered(SCRAPE_CONCURRENCY)
+        .collect()
+        .await;
+
+    let mut tasks_guard = tasks.write().await;
+    let Some(task) = tasks_guard.get_mut(&task_id) else {
+        return;
+    };
+    let now = Utc::now();
+    for (url, result) in results {
+        // 查询期间 Tracker 可能已被用户移除
+        let Some(tracker) = task.trackers.iter_mut().find(|t| t.url == url) else {
+            continue;
+        };
+        tracker.last_updated = Some(now);
+        match result {
+            Ok(stats) => {
+                tracker.status = TrackerStatus::Working;
+                tracker.seeders = Some(stats.seeders);
+                tracker.leechers = Some(stats.leechers);
+                tracker.completed = Some(stats.completed);
+            }
+            Err(e) => {
+                debug!("Tracker 查询失败 {}: {}", url, e);
+                tracker.status = TrackerStatus::Error(e.to_string());
+            }
+        }
+    }
+}
+
+/// 向 Tracker 通告，并将获得的 Peer 交给运行中的种子
+///
+/// 引擎只使用添加种子时传入的 Tracker，运行中添加的 Tracker 由我们以引擎的
+/// peer_id 和监听端口代为通告（`only_extra` 为 true 时只通告这些 Tracker）；
+/// 首次通告发送 started。命中 IP 过滤规则的 Peer 不会交给引擎
+async fn announce_trackers(
+    session: &Session,
+    tasks: &RwLock<HashMap<TaskId, TorrentTask>>,
+    tracker_manager: &TrackerManager,
+    ip_filter: &RwLock<Option<Arc<IpFilter>>>,
+    task_id: TaskId,
+    local: LocalPeer,
+    only_extra: bool,
+) {
+    let (handle_id, info_hash, urls) = {
+        let tasks_guard = tasks.read().await;
+        let Some(task) = tasks_guard.get(&task_id) else {
+            return;
+        };
+        let urls: Vec<(String, bool)> = task
+            .trackers
+            .iter()
+            .filter(|t| !only_extra || !task.engine_trackers.contains(&t.url))
+            .map(|t| (t.url.clone(), !task.announced.contains(&t.url)))
+            .collect();
+        (task.handle_id, task.info_hash, urls)
+    };
+    if urls.is_empty() {
+        return;
+    }
+    // 暂停或校验中的种子不接收 Peer
+    let Some(handle) = session.get(handle_id.into()) else {
+        return;
+    };
+    let Some(live) = handle.live() else {
+        return;
+    };
+    let stats = handle.stats();
+
+    match tasks.write().await.get_mut(&task_id) {
+        Some(task) => task.announced.extend(urls.iter().map(|(url, _)| url.clone())),
+        None => return,
+    }
+    let peers: Vec<SocketAddr> = futures::stream::iter(urls)
+        .map(|(url, first)| {
+            let event = if first {
+                AnnounceEvent::Started
+            } else {
+                AnnounceEvent::None
+            };
+            let request = local.request(info_hash, &stats, event);
+            async move {
+                match tracker_manager.announce(&url, &request).await {
+                    Ok(peers) => peers,
+                    Err(e) => {
+                        debug!("Tracker 通告失败 {}: {}", url, e);
+                        Vec::new()
                     }
-                } else {
-                    warn!("找不到种子句柄: {}", handle_id);
-                    break;
                 }
+            }
+        })
+        .buffer_unordered(SCRAPE_CONCURRENCY)
+        .concat()
+        .await;
 
-                // 每秒更新一次
-                tokio::time::sleep(Duration::from_secs(1)).await;
+    let added = filter_peers(ip_filter, peers)
+        .await
+        .into_iter()
+        .filter(|&addr| live.add_peer_if_not_seen(addr).unwrap_or(false))
+        .count();
+    debug!("Tracker 通告新增 {} 个 Peer: {}", added, task_id);
+}
+
+/// 删除种子元数据中列出的文件，并清理因此变空的子目录
+///
+/// 保存目录本身和其中的其他内容不受影响
+async fn delete_torrent_files(save_path: &Path, meta: &TorrentMetainfo) {
+    let mut dirs = BTreeSet::new();
+    for file in &meta.files {
+        // 拒绝越出保存目录的路径
+        if !is_relative_path(&file.path) {
+            warn!("跳过无效的文件路径: {}", file.path);
+            continue;
+        }
+        let relative = Path::new(&file.path);
+
+        let path = save_path.join(relative);
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => warn!("删除文件失败 {:?}: {}", path, e),
+        }
+        for dir in relative.ancestors().skip(1) {
+            if !dir.as_os_str().is_empty() {
+                dirs.insert(save_path.join(dir));
             }
-        });
+        }
+    }
+
+    // 由深到浅删除，仍有其他内容的目录会删除失败并保留
+    for dir in dirs.iter().rev() {
+        let _ = tokio::fs::remove_dir(dir).await;
+    }
+}
+
+/// 去掉命中 IP 过滤规则的 Peer，并计入屏蔽统计
+async fn filter_peers(
+    ip_filter: &RwLock<Option<Arc<IpFilter>>>,
+    peers: Vec<SocketAddr>,
+) -> Vec<SocketAddr> {
+    match ip_filter.read().await.clone() {
+        Some(filter) => peers
+            .into_iter()
+            .filter(|addr| !filter.check_peer(addr.ip()))
+            .collect(),
+        None => peers,
+    }
+}
+
+/// 创建引擎 Session，`blocklist_url` 为引擎黑名单文件
+///
+/// peer_id 由我们生成，代为通告时才能与引擎一致
+async fn create_session(
+    config: &TorrentConfig,
+    data_dir: &Path,
+    peer_id: [u8; 20],
+    blocklist_url: Option<String>,
+) -> Result<Arc<Session>> {
+    let listen_ports = match config.listen_port {
+        Some(port) => port..port.saturating_add(1),
+        None => DEFAULT_LISTEN_PORTS,
+    };
+    let session_opts = SessionOptions {
+        disable_dht: !config.enable_dht,
+        disable_dht_persistence: false,
+        peer_id: Some(Id20::new(peer_id)),
+        listen_port_range: Some(listen_ports),
+        enable_upnp_port_forwarding: config.enable_upnp,
+        blocklist_url,
+        ..Default::default()
+    };
+
+    // 返回的已经是 Arc<Session>
+    Session::new_with_opts(data_dir.to_path_buf(), session_opts)
+        .await
+        .map_err(|e| NebulaError::Internal(format!("创建 BitTorrent Session 失败: {}", e)))
+}
+
+/// 读取当前的 Session
+fn current_session(slot: &SessionSlot) -> Arc<Session> {
+    Arc::clone(&slot.read().unwrap_or_else(PoisonError::into_inner))
+}
+
+/// 注入到新种子的 Tracker 列表
+///
+/// 配置中的额外 Tracker 在前，按健康度排名的远程 Tracker 在后
+fn injected_trackers(config: &TorrentConfig, ranked: Vec<String>) -> Vec<String> {
+    let mut trackers = config.extra_trackers.clone();
+    for tracker in ranked {
+        if !trackers.contains(&tracker) {
+            trackers.push(tracker);
+        }
+    }
+    trackers
+}
+
+/// 添加 Tracker（已存在则忽略）
+fn push_tracker(trackers: &mut Vec<TorrentTracker>, url: String, source: TrackerSource) {
+    if !trackers.iter().any(|t| t.url == url) {
+        trackers.push(TorrentTracker::new(url, source));
+    }
+}
+
+/// 检查 Tracker 地址是否有效
+fn validate_tracker_url(url: &str) -> Result<()> {
+    let parsed = url::Url::parse(url)?;
+    match parsed.scheme() {
+        "udp" | "http" | "https" if parsed.host_str().is_some() => Ok(()),
+        _ => Err(NebulaError::InvalidUrl(format!("无效的 Tracker 地址: {}", url))),
+    }
+}
+
+/// 读取 .torrent 文件
+async fn read_torrent_file(path: &Path) -> Result<Vec<u8>> {
+    tokio::fs::read(path).await.map_err(|e| NebulaError::IoError {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })
+}
+
+/// 获取来源自带的 Tracker（磁力链接的 `tr` 参数或种子文件的 announce 列表）
+async fn embedded_trackers(source: &DownloadSource) -> Result<Vec<String>> {
+    match source {
+        DownloadSource::Magnet { uri, .. } => Ok(MagnetLink::parse(uri)?.trackers),
+        DownloadSource::Torrent { path } => Ok(TorrentMetainfo::from_bytes(
+            &read_torrent_file(path).await?,
+        )
+        .map(|meta| meta.trackers)
+        .unwrap_or_default()),
+        _ => Ok(Vec::new()),
     }
 }
 
+/// 生成本地 peer_id：固定前缀加 12 个随机字母数字
+fn generate_peer_id() -> [u8; 20] {
+    const CHARSET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    let mut peer_id = [0u8; 20];
+    peer_id[..8].copy_from_slice(PEER_ID_PREFIX);
+    for b in &mut peer_id[8..] {
+        *b = CHARSET[rand::random_range(0..CHARSET.len())];
+    }
+    peer_id
+}
+
+/// 空列表转换为 None
+fn non_empty<T>(list: Vec<T>) -> Option<Vec<T>> {
+    if list.is_empty() {
+        None
+    } else {
+        Some(list)
+    }
+}
+
+/// 去掉种子文件中的 announce / announce-list，info 字典原样保留
+fn strip_torrent_trackers(content: &[u8]) -> Option<Vec<u8>> {
+    let entries = bencode::dict_entries_raw(content)?;
+    let kept: Vec<_> = entries
+        .into_iter()
+        .filter(|(k, _)| k != b"announce" && k != b"announce-list")
+        .collect();
+    Some(bencode::encode_dict_raw(&kept))
+}
+
 #[async_trait]
 impl ProtocolHandler for TorrentHandler {
     async fn start(
@@ -276,19 +2037,20 @@ impl ProtocolHandler for TorrentHandler {
         task_id: TaskId,
         source: &DownloadSource,
         save_path: PathBuf,
-        event_tx: broadcast::Sender<DownloadEvent>,
+        event_tx: mpsc::UnboundedSender<DownloadEvent>,
     ) -> Result<()> {
-        info!("开始 BitTorrent 下载: {:?}", source);
-        self.add_torrent(task_id, source, save_path, event_tx).await
+        self.start_with_files(task_id, source, save_path, None, event_tx)
+            .await
     }
 
     async fn pause(&self, task_id: TaskId) -> Result<()> {
         // 注意：librqbit 当前版本 pause/start 是 pub(crate)，无法直接调用
-        // 暂时记录日志，后续版本可能会开放 API
+        // 返回错误，避免管理器把仍在下载的任务标记为已暂停
         let tasks = self.tasks.read().await;
         if tasks.contains_key(&task_id) {
-            warn!("BitTorrent 暂停功能当前不可用: {}", task_id);
-            Ok(())
+            Err(NebulaError::UnsupportedProtocol(
+                "BitTorrent 暂停功能当前不可用".to_string(),
+            ))
         } else {
             Err(NebulaError::TaskNotFound(task_id.to_string()))
         }
@@ -298,43 +2060,58 @@ impl ProtocolHandler for TorrentHandler {
         // 注意：librqbit 当前版本 pause/start 是 pub(crate)，无法直接调用
         let tasks = self.tasks.read().await;
         if tasks.contains_key(&task_id) {
-            warn!("BitTorrent 恢复功能当前不可用: {}", task_id);
-            Ok(())
+            Err(NebulaError::UnsupportedProtocol(
+                "BitTorrent 恢复功能当前不可用".to_string(),
+            ))
         } else {
             Err(NebulaError::TaskNotFound(task_id.to_string()))
         }
     }
 
     async fn cancel(&self, task_id: TaskId, delete_files: bool) -> Result<()> {
-        let mut tasks = self.tasks.write().await;
-        if let Some(task) = tasks.remove(&task_id) {
-            // 删除已下载的文件
-            if delete_files && task.save_path.exists() {
-                let _ = tokio::fs::remove_dir_all(&task.save_path).await;
-            }
+        // 仍在获取元数据的任务
+        if self.resolving.write().await.remove(&task_id) {
             info!("种子已取消: {}", task_id);
-            Ok(())
-        } else {
-            Err(NebulaError::TaskNotFound(task_id.to_string()))
+            return Ok(());
+        }
+
+        let task = self
+            .tasks
+            .write()
+            .await
+            .remove(&task_id)
+            .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
+
+        self.announce_stopped(&task, task.announced.iter().cloned().collect());
+
+        // 先从引擎移除，避免删除后文件又被写回
+        if let Err(e) = self.session().delete(task.handle_id.into(), false).await {
+            warn!("从引擎移除种子失败: {}", e);
+        }
+        // 只删除种子自身的文件，保存目录通常是共用的下载目录
+        if delete_files {
+            delete_torrent_files(&task.save_path, &task.metainfo).await;
         }
+        info!("种子已取消: {}", task_id);
+        Ok(())
     }
 
     async fn get_progress(&self, task_id: TaskId) -> Result<Progress> {
         let tasks = self.tasks.read().await;
         if let Some(task) = tasks.get(&task_id) {
-            if let Some(handle) = self.session.get(task.handle_id.into()) {
+            if let Some(handle) = self.session().get(task.handle_id.into()) {
                 let stats = handle.stats();
                 let total_bytes = stats.total_bytes;
                 let downloaded_bytes = stats.progress_bytes;
-                
+
                 let mut progress = Progress::new(total_bytes, downloaded_bytes);
-                
+
                 if let Some(ref live) = stats.live {
                     let download_speed = (live.download_speed.mbps * 1024.0 * 1024.0 / 8.0) as u64;
                     let upload_speed = (live.upload_speed.mbps * 1024.0 * 1024.0 / 8.0) as u64;
                     progress.update_speed(download_speed, upload_speed);
                 }
-                
+
                 Ok(progress)
             } else {
                 Err(NebulaError::TaskNotFound(task_id.to_string()))
@@ -349,5 +2126,103 @@ impl ProtocolHandler for TorrentHandler {
 mod tests {
     use super::*;
 
-    // 注意：BitTorrent 测试需要网络访问，通常作为集成测试运行
+    // 注意：BitTorrent 下载测试需要网络访问，通常作为集成测试运行
+
+    #[test]
+    fn test_strip_torrent_trackers() {
+        let content = b"d8:announce9:http://a/13:announce-listll9:http://b/el9:http://a/ee4:infod4:name1:xee";
+        let stripped = strip_torrent_trackers(content).unwrap();
+        assert_eq!(stripped, b"d4:infod4:name1:xee".to_vec());
+    }
+
+    #[test]
+    fn test_generate_peer_id() {
+        let peer_id = generate_peer_id();
+        assert_eq!(&peer_id[..8], PEER_ID_PREFIX);
+        assert!(peer_id[8..].iter().all(u8::is_ascii_alphanumeric));
+        assert_ne!(generate_peer_id(), peer_id);
+    }
+
+    #[test]
+    fn test_validate_tracker_url() {
+        assert!(validate_tracker_url("udp://tracker.example:6969/announce").is_ok());
+        assert!(validate_tracker_url("https://tracker.example/announce").is_ok());
+        assert!(validate_tracker_url("ftp://tracker.example/").is_err());
+        assert!(validate_tracker_url("not a url").is_err());
+    }
+
+    #[test]
+    fn test_stall_detection() {
+        let start = Instant::now();
+        let timeout = Duration::from_secs(60);
+        let mut detector = StallDetector::new(timeout, start);
+
+        assert_eq!(detector.observe(100, start), StallCheck::Progressing);
+        let at = start + Duration::from_secs(30);
+        assert_eq!(detector.observe(100, at), StallCheck::Waiting);
+        let at = start + Duration::from_secs(61);
+        assert_eq!(detector.observe(100, at), StallCheck::Stalled { since: start });
+
+        // 停滞期间每隔 timeout 报告一次，开始时间不变
+        let at = start + Duration::from_secs(90);
+        assert_eq!(detector.observe(100, at), StallCheck::Waiting);
+        let at = start + Duration::from_secs(122);
+        assert_eq!(detector.observe(100, at), StallCheck::Stalled { since: start });
+
+        let at = start + Duration::from_secs(130);
+        assert_eq!(detector.observe(200, at), StallCheck::Recovered);
+        assert_eq!(detector.observe(300, at), StallCheck::Progressing);
+    }
+
+    #[test]
+    fn test_stall_paused_does_not_count() {
+        let start = Instant::now();
+        let mut detector = StallDetector::new(Duration::from_secs(60), start);
+        detector.reset(start + Duration::from_secs(100));
+        let at = start + Duration::from_secs(120);
+        assert_eq!(detector.observe(0, at), StallCheck::Waiting);
+    }
+
+    #[test]
+    fn test_reannounce_backoff() {
+        let start = Instant::now();
+        let mut detector = StallDetector::new(Duration::from_secs(60), start);
+        let secs = |n| start + Duration::from_secs(n);
+
+        assert!(detector.should_reannounce(secs(0)));
+        assert!(!detector.should_reannounce(secs(59)));
+        assert!(detector.should_reannounce(secs(60)));
+        // 间隔加倍为 120 秒
+        assert!(!detector.should_reannounce(secs(179)));
+        assert!(detector.should_reannounce(secs(180)));
+
+        // 有新数据后退避重置
+        detector.observe(1, secs(200));
+        assert!(detector.should_reannounce(secs(200)));
+        assert!(detector.should_reannounce(secs(260)));
+
+        for n in 0..20 {
+            detector.should_reannounce(secs(300 + n * 3600));
+        }
+        assert_eq!(detector.announce_interval, REANNOUNCE_MAX_INTERVAL);
+    }
+
+    #[tokio::test]
+    async fn test_delete_torrent_files_keeps_other_content() {
+        let meta = TorrentMetainfo::from_bytes(
+            b"d4:infod5:filesld6:lengthi3e4:pathl1:x5:a.txteed6:lengthi5e4:pathl5:b.txteee4:name3:dir12:piece lengthi16384e6:pieces20:aaaaaaaaaaaaaaaaaaaaee",
+        )
+        .unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        tokio::fs::create_dir_all(dir.path().join("x")).await.unwrap();
+        for name in ["x/a.txt", "b.txt", "other.txt"] {
+            tokio::fs::write(dir.path().join(name), b"data").await.unwrap();
+        }
+
+        delete_torrent_files(dir.path(), &meta).await;
+
+        assert!(!dir.path().join("x").exists());
+        assert!(!dir.path().join("b.txt").exists());
+        assert!(dir.path().join("other.txt").exists());
+    }
 }
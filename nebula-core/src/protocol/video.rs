@@ -2,17 +2,33 @@
 //!
 //! 通过 yt-dlp 支持 Bilibili、YouTube 等 1000+ 网站
 
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
-use tokio::io::{AsyncBufReadExt, BufReader};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::process::Command;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch, RwLock};
 use tracing::{debug, error, info, warn};
 
+use super::bilibili::BilibiliAuth;
+use super::bilibili_video::{self as native, BilibiliClient, BilibiliLink};
+use super::danmaku::{self, DanmakuStyle};
+use super::http::HttpHandler;
+use super::ProtocolHandler;
+use crate::config::VideoConfig;
 use crate::error::{NebulaError, Result};
-use crate::event::{DownloadEvent, Progress};
-use crate::task::TaskId;
+use crate::event::{DownloadEvent, Progress, VideoStage};
+use crate::task::{
+    AudioExtraction, AudioFormat, DownloadSource, FormatPreference, TaskId, VideoContainer,
+    VideoOptions,
+};
+use crate::tools::{Tool, ToolManager};
+use crate::vault::{CredentialVault, YtDlpCredentials};
 
 /// 视频格式信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +40,18 @@ pub struct VideoFormat {
     pub vcodec: Option<String>,
     pub acodec: Option<String>,
     pub format_note: Option<String>,
+    /// 帧率
+    pub fps: Option<f64>,
+    /// 总码率（kbps）
+    pub tbr: Option<f64>,
+    /// 动态范围（SDR、HDR10、DV 等）
+    pub dynamic_range: Option<String>,
+    /// 音轨语言
+    pub language: Option<String>,
+    /// 只有音频
+    pub audio_only: bool,
+    /// 只有视频（下载时会自动合并最佳音频）
+    pub video_only: bool,
 }
 
 /// 视频信息
@@ -37,6 +65,244 @@ pub struct VideoInfo {
     pub uploader: Option<String>,
     pub formats: Vec<VideoFormat>,
     pub webpage_url: String,
+    /// 发布日期（YYYYMMDD）
+    pub upload_date: Option<String>,
+    /// 默认格式的分辨率
+    pub resolution: Option<String>,
+    /// 在播放列表（分 P）中的序号
+    pub playlist_index: Option<usize>,
+}
+
+impl VideoInfo {
+    /// 估算下载大小：指定格式时取该格式大小，否则取已知的最大格式
+    ///
+    /// 提取音频时取最大的纯音频格式
+    pub fn estimated_size(&self, format_id: Option<&str>) -> Option<u64> {
+        match format_id {
+            Some(id) if AudioExtraction::from_format_id(id).is_some() => self
+                .formats
+                .iter()
+                .filter(|f| f.vcodec.as_deref() == Some("none"))
+                .filter_map(|f| f.filesize)
+                .max(),
+            Some(id) => self
+                .formats
+                .iter()
+                .find(|f| f.format_id == id)
+                .and_then(|f| f.filesize),
+            None => self.formats.iter().filter_map(|f| f.filesize).max(),
+        }
+    }
+
+    /// 按模板生成输出文件名（不含扩展名）
+    ///
+    /// 没有值的占位符替换为空，结果经过文件名清理；
+    /// 分 P 序号优先取 URL 中的 `p` 参数
+    pub fn output_name(&self, template: &str, format_id: Option<&str>, url: &str) -> String {
+        let resolution = match format_id {
+            Some(id) => self
+                .formats
+                .iter()
+                .find(|f| f.format_id == id)
+                .and_then(|f| f.resolution.clone()),
+            None => self.resolution.clone(),
+        };
+        let date = self.upload_date.as_deref().map(|date| match date.len() {
+            8 => format!("{}-{}-{}", &date[..4], &date[4..6], &date[6..]),
+            _ => date.to_string(),
+        });
+        let index = part_index(url).or(self.playlist_index);
+
+        let fields = [
+            ("{title}", Some(self.title.clone())),
+            ("{uploader}", self.uploader.clone()),
+            ("{date}", date),
+            ("{index}", index.map(|i| i.to_string())),
+            ("{resolution}", resolution),
+            ("{id}", Some(self.id.clone())),
+        ];
+        let mut name = template.to_string();
+        for (placeholder, value) in fields {
+            let value = value.map(|v| sanitize_filename(&v)).unwrap_or_default();
+            name = name.replace(placeholder, &value);
+        }
+
+        // 去掉空占位符留下的括号
+        for empty in ["[]", "()", "【】"] {
+            name = name.replace(empty, "");
+        }
+        let name = sanitize_filename(&name);
+        if name.is_empty() {
+            sanitize_filename(&self.id)
+        } else {
+            name
+        }
+    }
+}
+
+/// 输出文件名的最大字节数（留出扩展名和分片后缀的空间）
+const MAX_FILE_NAME_BYTES: usize = 200;
+
+/// 清理文件名：替换路径分隔符和系统保留字符，合并空白，去掉首尾的空格和点，并限制长度
+pub fn sanitize_filename(name: &str) -> String {
+    let replaced: String = name
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => ' ',
+            c => c,
+        })
+        .collect();
+    let collapsed = replaced.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut name = collapsed
+        .trim_matches(|c: char| c == '.' || c == ' ' || c == '-' || c == '_')
+        .to_string();
+
+    if name.len() > MAX_FILE_NAME_BYTES {
+        let mut end = MAX_FILE_NAME_BYTES;
+        while !name.is_char_boundary(end) {
+            end -= 1;
+        }
+        name.truncate(end);
+        name = name.trim_end().to_string();
+    }
+    name
+}
+
+/// URL 中的分 P 序号（如 Bilibili 的 `?p=2`）
+fn part_index(url: &str) -> Option<usize> {
+    url::Url::parse(url)
+        .ok()?
+        .query_pairs()
+        .find(|(key, _)| key == "p")
+        .and_then(|(_, value)| value.parse().ok())
+}
+
+/// 播放列表条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaylistEntry {
+    /// 在播放列表中的序号（从 1 开始）
+    pub index: usize,
+    pub id: String,
+    pub title: String,
+    pub url: String,
+    pub duration: Option<u64>,
+    pub uploader: Option<String>,
+}
+
+/// 播放列表信息（播放列表、频道、合集或多 P 视频）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaylistInfo {
+    pub id: String,
+    pub title: String,
+    pub uploader: Option<String>,
+    pub webpage_url: String,
+    pub entries: Vec<PlaylistEntry>,
+}
+
+/// yt-dlp `--flat-playlist` JSON 输出结构
+#[derive(Debug, Deserialize)]
+struct YtDlpPlaylist {
+    id: Option<String>,
+    title: Option<String>,
+    uploader: Option<String>,
+    webpage_url: Option<String>,
+    extractor_key: Option<String>,
+    entries: Option<Vec<YtDlpEntry>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct YtDlpEntry {
+    id: Option<String>,
+    title: Option<String>,
+    url: Option<String>,
+    webpage_url: Option<String>,
+    duration: Option<f64>,
+    uploader: Option<String>,
+}
+
+/// 解析播放列表条目选择，如 `1-3,5,8-`
+///
+/// 序号从 1 开始，返回去重并排序后的 0 起始下标
+pub fn parse_playlist_items(spec: &str, count: usize) -> Result<Vec<usize>> {
+    let invalid = || NebulaError::InvalidConfig(format!("无效的播放列表条目选择: {}", spec));
+    let parse = |s: &str| s.trim().parse::<usize>().map_err(|_| invalid());
+
+    let mut selected = Vec::new();
+    for part in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (start, end) = match part.split_once('-') {
+            Some((start, "")) => (parse(start)?, count),
+            Some((start, end)) => (parse(start)?, parse(end)?),
+            None => {
+                let index = parse(part)?;
+                (index, index)
+            }
+        };
+        if start == 0 || start > end {
+            return Err(invalid());
+        }
+        selected.extend((start..=end.min(count)).map(|i| i - 1));
+    }
+
+    if selected.is_empty() {
+        return Err(invalid());
+    }
+    selected.sort_unstable();
+    selected.dedup();
+    Ok(selected)
+}
+
+/// 已下载视频记录
+///
+/// 每行一个视频的去重键，用于播放列表「只下载新条目」
+#[derive(Debug)]
+pub struct VideoArchive {
+    path: PathBuf,
+    keys: RwLock<HashSet<String>>,
+}
+
+impl VideoArchive {
+    /// 从文件加载记录，文件不存在时为空
+    pub fn load(path: PathBuf) -> Self {
+        let keys = std::fs::read_to_string(&path)
+            .map(|content| {
+                content
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            path,
+            keys: RwLock::new(keys),
+        }
+    }
+
+    /// 是否已下载过
+    pub async fn contains(&self, key: &str) -> bool {
+        self.keys.read().await.contains(key)
+    }
+
+    /// 记录一个已下载的视频
+    pub async fn insert(&self, key: &str) -> Result<()> {
+        if !self.keys.write().await.insert(key.to_string()) {
+            return Ok(());
+        }
+
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await?;
+        file.write_all(format!("{}\n", key).as_bytes()).await?;
+        Ok(())
+    }
 }
 
 /// yt-dlp JSON 输出结构
@@ -50,6 +316,9 @@ struct YtDlpInfo {
     uploader: Option<String>,
     formats: Option<Vec<YtDlpFormat>>,
     webpage_url: Option<String>,
+    upload_date: Option<String>,
+    resolution: Option<String>,
+    playlist_index: Option<usize>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -62,172 +331,377 @@ struct YtDlpFormat {
     vcodec: Option<String>,
     acodec: Option<String>,
     format_note: Option<String>,
+    fps: Option<f64>,
+    tbr: Option<f64>,
+    dynamic_range: Option<String>,
+    language: Option<String>,
 }
 
-/// 视频下载处理器
-pub struct VideoHandler {
-    yt_dlp_path: PathBuf,
-    output_dir: PathBuf,
+/// 视频任务的控制指令
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VideoControl {
+    /// 正常下载
+    Run,
+    /// 暂停（结束 yt-dlp 进程，恢复时重新启动）
+    Pause,
+    /// 取消
+    Cancel {
+        /// 是否删除已下载的文件
+        delete_files: bool,
+    },
 }
 
-impl VideoHandler {
-    /// 创建新的视频处理器
-    pub fn new(output_dir: PathBuf) -> Result<Self> {
-        // 查找 yt-dlp 路径
-        let yt_dlp_path = Self::find_yt_dlp()?;
-        info!("Found yt-dlp at: {:?}", yt_dlp_path);
-
-        Ok(Self {
-            yt_dlp_path,
-            output_dir,
-        })
-    }
-
-    /// 查找 yt-dlp 可执行文件
-    /// 优先查找应用内嵌版本，然后查找系统安装版本
-    fn find_yt_dlp() -> Result<PathBuf> {
-        // 1. 首先查找应用 bundle 内的 yt-dlp
-        if let Ok(exe_path) = std::env::current_exe() {
-            // macOS: xxx.app/Contents/MacOS/nebula_app -> ../Resources/yt-dlp
-            // Windows: target/release/nebula_app.exe -> ./yt-dlp.exe
-            
-            #[cfg(target_os = "macos")]
-            {
-                if let Some(macos_dir) = exe_path.parent() {
-                    let bundle_path = macos_dir.parent()
-                        .map(|contents| contents.join("Resources").join("yt-dlp"));
-                    if let Some(p) = bundle_path {
-                        if p.exists() {
-                            info!("使用内嵌 yt-dlp (macOS): {:?}", p);
-                            return Ok(p);
-                        }
-                    }
-                }
-            }
+/// 进行中的视频任务
+struct VideoTask {
+    /// 控制指令发送端
+    control: watch::Sender<VideoControl>,
+    /// 进度跟踪（汇总 yt-dlp 输出的进度）
+    tracker: Arc<RwLock<ProgressTracker>>,
+    /// 保存目录
+    save_path: PathBuf,
+    /// 字幕、弹幕等附加内容
+    options: VideoOptions,
+    /// 输出文件名（不含扩展名），未知时为 None
+    output_name: RwLock<Option<String>>,
+}
 
-            #[cfg(target_os = "windows")]
-            {
-                if let Some(app_dir) = exe_path.parent() {
-                    let p = app_dir.join("yt-dlp.exe");
-                    if p.exists() {
-                        info!("使用内嵌 yt-dlp (Windows): {:?}", p);
-                        return Ok(p);
-                    }
+impl VideoTask {
+    /// 删除该视频已下载的文件（包括未完成的分片）
+    async fn remove_files(&self) {
+        let Some(output_name) = self.output_name.read().await.clone() else {
+            warn!("输出文件名未知，无法清理已下载的文件");
+            return;
+        };
+        let Ok(mut entries) = tokio::fs::read_dir(&self.save_path).await else {
+            return;
+        };
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if is_video_output(&name, &output_name) {
+                match tokio::fs::remove_file(entry.path()).await {
+                    Ok(()) => info!("已删除文件: {:?}", entry.path()),
+                    Err(e) => warn!("删除文件失败: {:?} - {}", entry.path(), e),
                 }
             }
         }
+    }
+}
 
-        // 2. 查找常见系统路径
-        let paths = [
-            "/opt/homebrew/bin/yt-dlp",
-            "/usr/local/bin/yt-dlp",
-            "/usr/bin/yt-dlp",
-        ];
+/// 判断文件是否为 yt-dlp 以 `output_name` 为文件名输出的文件或其分片
+fn is_video_output(file_name: &str, output_name: &str) -> bool {
+    file_name
+        .strip_prefix(output_name)
+        .is_some_and(|rest| rest.starts_with('.'))
+}
 
-        for path in paths {
-            let p = PathBuf::from(path);
-            if p.exists() {
-                return Ok(p);
-            }
-        }
+/// 下载进度模板输出的行前缀
+const PROGRESS_PREFIX: &str = "[nebula]";
 
-        // 3. 尝试 which 命令
-        if let Ok(output) = std::process::Command::new("which")
-            .arg("yt-dlp")
-            .output()
-        {
-            if output.status.success() {
-                let path = String::from_utf8_lossy(&output.stdout)
-                    .trim()
-                    .to_string();
-                if !path.is_empty() {
-                    return Ok(PathBuf::from(path));
-                }
-            }
-        }
+/// 后处理进度模板输出的行前缀
+const POSTPROCESS_PREFIX: &str = "[nebula-pp]";
 
-        Err(NebulaError::Internal(
-            "yt-dlp 未找到".to_string(),
-        ))
+/// 最终输出文件路径的行前缀
+const OUTPUT_FILE_PREFIX: &str = "[nebula-file]";
+
+/// 询问 yt-dlp 提取器的超时时间
+const PROBE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 原生下载时 DASH 地址连续过期后重新获取地址的最多次数
+const MAX_URL_REFRESHES: u32 = 3;
+
+/// EBU R128 响度标准化滤镜（-16 LUFS，适合语音和播客）
+pub(crate) const LOUDNORM_FILTER: &str = "loudnorm=I=-16:TP=-1.5:LRA=11";
+
+/// 解析 `--print after_move:` 输出的最终文件路径
+fn parse_output_file(line: &str) -> Option<PathBuf> {
+    let path = line.strip_prefix(OUTPUT_FILE_PREFIX)?.trim();
+    (!path.is_empty() && path != "NA").then(|| PathBuf::from(path))
+}
+
+/// yt-dlp 进度模板中的 `%(progress)j`
+#[derive(Debug, Deserialize)]
+struct YtDlpProgress {
+    status: String,
+    filename: Option<String>,
+    downloaded_bytes: Option<f64>,
+    total_bytes: Option<f64>,
+    total_bytes_estimate: Option<f64>,
+    speed: Option<f64>,
+    eta: Option<f64>,
+    fragment_index: Option<u64>,
+    fragment_count: Option<u64>,
+    /// 后处理器名称（仅后处理进度）
+    postprocessor: Option<String>,
+}
+
+/// 解析后的 yt-dlp 输出行
+#[derive(Debug)]
+enum ProgressLine {
+    /// 下载进度
+    Download {
+        stage: VideoStage,
+        progress: YtDlpProgress,
+    },
+    /// 进入合并或后处理阶段
+    PostProcess(VideoStage),
+}
+
+/// 解析 yt-dlp 的一行输出
+fn parse_progress_line(line: &str) -> Option<ProgressLine> {
+    if let Some(rest) = line.strip_prefix(PROGRESS_PREFIX) {
+        let mut parts = rest.trim_start().splitn(3, ' ');
+        let vcodec = parts.next()?;
+        let acodec = parts.next()?;
+        let progress: YtDlpProgress = serde_json::from_str(parts.next()?).ok()?;
+        let has_video = !matches!(vcodec, "none" | "NA" | "");
+        let has_audio = !matches!(acodec, "none" | "NA" | "");
+        let stage = if has_audio && !has_video {
+            VideoStage::Audio
+        } else {
+            VideoStage::Video
+        };
+        return Some(ProgressLine::Download { stage, progress });
     }
 
-    /// 查找 ffmpeg 可执行文件
-    fn find_ffmpeg() -> Option<PathBuf> {
-        // 1. 查找常见系统路径
-        let paths = [
-            "/opt/homebrew/bin/ffmpeg",
-            "/usr/local/bin/ffmpeg",
-            "/usr/bin/ffmpeg",
-            "C:\\Program Files\\ffmpeg\\bin\\ffmpeg.exe", // Windows 常见路径
-        ];
+    if let Some(rest) = line.strip_prefix(POSTPROCESS_PREFIX) {
+        let progress: YtDlpProgress = serde_json::from_str(rest.trim()).ok()?;
+        return Some(ProgressLine::PostProcess(
+            match progress.postprocessor.as_deref() {
+                Some("Merger") => VideoStage::Merging,
+                Some("ExtractAudio") => VideoStage::ExtractingAudio,
+                _ => VideoStage::PostProcessing,
+            },
+        ));
+    }
+
+    // 旧版本 yt-dlp 不输出后处理进度，根据日志判断合并阶段
+    line.starts_with("[Merger]")
+        .then_some(ProgressLine::PostProcess(VideoStage::Merging))
+}
 
-        for path in paths {
-            let p = PathBuf::from(path);
-            if p.exists() {
-                debug!("找到 ffmpeg: {:?}", p);
-                return Some(p);
+/// 汇总 yt-dlp 输出的进度
+///
+/// 分别下载视频流和音频流时，总进度包含已完成的流
+#[derive(Debug, Default)]
+struct ProgressTracker {
+    /// 当前总进度
+    progress: Progress,
+    /// 当前阶段
+    stage: Option<VideoStage>,
+    /// 已完成的文件及其大小（按文件名记录，恢复下载后不会重复计算）
+    finished: HashMap<String, u64>,
+}
+
+impl ProgressTracker {
+    /// 处理一行 yt-dlp 输出
+    ///
+    /// # 返回
+    /// 进度是否更新，以及阶段变化后的新阶段
+    fn feed(&mut self, line: &str) -> (bool, Option<VideoStage>) {
+        match parse_progress_line(line) {
+            Some(ProgressLine::Download { stage, progress }) => {
+                let stage = self.set_stage(stage);
+                self.update(progress);
+                (true, stage)
             }
+            Some(ProgressLine::PostProcess(stage)) => (false, self.set_stage(stage)),
+            None => (false, None),
         }
+    }
 
-        // 2. 尝试 which / where 命令
-        let cmd = if cfg!(windows) { "where" } else { "which" };
-        if let Ok(output) = std::process::Command::new(cmd)
-            .arg("ffmpeg")
-            .output()
-        {
-            if output.status.success() {
-                let path = String::from_utf8_lossy(&output.stdout)
-                    .trim()
-                    .lines()
-                    .next() // where 可能返回多行
-                    .map(|s| s.to_string())
-                    .unwrap_or_default();
-                    
-                if !path.is_empty() {
-                    let p = PathBuf::from(path);
-                    debug!("通过 {} 找到 ffmpeg: {:?}", cmd, p);
-                    return Some(p);
+    fn set_stage(&mut self, stage: VideoStage) -> Option<VideoStage> {
+        (self.stage != Some(stage)).then(|| {
+            self.stage = Some(stage);
+            stage
+        })
+    }
+
+    fn update(&mut self, p: YtDlpProgress) {
+        let file = p.filename.unwrap_or_default();
+        let downloaded = p.downloaded_bytes.unwrap_or(0.0) as u64;
+        let mut total = p.total_bytes.or(p.total_bytes_estimate).map(|t| t as u64);
+
+        // 分片下载没有总大小时，按已完成的分片比例估算
+        if total.is_none() {
+            if let (Some(index), Some(count)) = (p.fragment_index, p.fragment_count) {
+                if index > 0 && count >= index {
+                    total = Some(downloaded * count / index);
                 }
             }
         }
 
-        warn!("未找到 ffmpeg，视频合并可能失败");
-        None
-    }
-
-    /// 检查 URL 是否为支持的视频网站
-    pub fn is_video_url(url: &str) -> bool {
-        let video_domains = [
-            "youtube.com",
-            "youtu.be",
-            "bilibili.com",
-            "b23.tv",
-            "twitter.com",
-            "x.com",
-            "tiktok.com",
-            "douyin.com",
-            "vimeo.com",
-            "dailymotion.com",
-            "twitch.tv",
-            "instagram.com",
-            "facebook.com",
-            "nicovideo.jp",
-        ];
+        let (current_done, current_total) = if p.status == "finished" {
+            let size = total.unwrap_or(downloaded).max(downloaded);
+            self.finished.insert(file.clone(), size);
+            (size, size)
+        } else {
+            (downloaded, total.unwrap_or(0).max(downloaded))
+        };
+        let previous: u64 = self
+            .finished
+            .iter()
+            .filter(|(name, _)| **name != file)
+            .map(|(_, size)| size)
+            .sum();
+
+        let mut progress = Progress::new(previous + current_total, previous + current_done);
+        progress.download_speed = p.speed.unwrap_or(0.0) as u64;
+        progress.eta_secs = p.eta.map(|eta| eta as u64);
+        self.progress = progress;
+    }
+}
+
+/// 视频下载处理器
+pub struct VideoHandler {
+    /// yt-dlp 路径（更新工具后可刷新）
+    yt_dlp_path: Mutex<Option<PathBuf>>,
+    /// ffmpeg 路径
+    ffmpeg_path: Mutex<Option<PathBuf>>,
+    output_dir: PathBuf,
+    /// Bilibili 认证管理器（已登录时导出 cookies 给 yt-dlp）
+    bilibili_auth: Option<Arc<BilibiliAuth>>,
+    /// Bilibili 接口客户端（原生下载）
+    bilibili: BilibiliClient,
+    /// HTTP 引擎（原生下载时分段下载 DASH 流）
+    http: Option<Arc<HttpHandler>>,
+    /// Bilibili 链接始终使用原生下载，否则只在没有 yt-dlp 时使用
+    native_bilibili: bool,
+    /// 输出文件名模板
+    output_template: String,
+    /// 站点凭据保险库（为匹配的站点传入 cookies 和账号参数）
+    vault: Option<Arc<CredentialVault>>,
+    /// 活跃任务映射表
+    tasks: RwLock<HashMap<TaskId, Arc<VideoTask>>>,
+}
+
+impl VideoHandler {
+    /// 创建新的视频处理器
+    ///
+    /// 未找到 yt-dlp 时仍会创建，相关操作返回 [`NebulaError::ToolNotFound`]，
+    /// 安装或更新后调用 [`refresh_tools`](Self::refresh_tools) 即可使用
+    pub fn new(output_dir: PathBuf, tools: &ToolManager) -> Self {
+        let handler = Self {
+            yt_dlp_path: Mutex::new(None),
+            ffmpeg_path: Mutex::new(None),
+            output_dir,
+            bilibili_auth: None,
+            bilibili: BilibiliClient::new(None),
+            http: None,
+            native_bilibili: false,
+            output_template: VideoConfig::default().output_template,
+            vault: None,
+            tasks: RwLock::new(HashMap::new()),
+        };
+        handler.refresh_tools(tools);
+        handler
+    }
+
+    /// 设置 Bilibili 认证管理器，用于登录态下载高码率视频
+    pub fn with_bilibili_auth(mut self, auth: Arc<BilibiliAuth>) -> Self {
+        self.bilibili = BilibiliClient::new(Some(Arc::clone(&auth)));
+        self.bilibili_auth = Some(auth);
+        self
+    }
+
+    /// 设置 HTTP 引擎，启用不依赖 yt-dlp 的 Bilibili 原生下载
+    pub fn with_http_handler(mut self, http: Arc<HttpHandler>) -> Self {
+        self.http = Some(http);
+        self
+    }
+
+    /// Bilibili 链接始终使用原生下载（默认只在没有 yt-dlp 时使用）
+    pub fn with_native_bilibili(mut self, enabled: bool) -> Self {
+        self.native_bilibili = enabled;
+        self
+    }
+
+    /// 设置输出文件名模板，参见 [`VideoConfig::output_template`]
+    pub fn with_output_template(mut self, template: impl Into<String>) -> Self {
+        self.output_template = template.into();
+        self
+    }
+
+    /// 设置站点凭据保险库
+    pub fn with_credential_vault(mut self, vault: Arc<CredentialVault>) -> Self {
+        self.vault = Some(vault);
+        self
+    }
+
+    /// 默认保存目录
+    pub fn output_dir(&self) -> &Path {
+        &self.output_dir
+    }
+
+    /// 当前使用的 yt-dlp 路径，未找到时返回 [`NebulaError::ToolNotFound`]
+    fn yt_dlp(&self) -> Result<PathBuf> {
+        self.yt_dlp_path
+            .lock()
+            .ok()
+            .and_then(|path| path.clone())
+            .ok_or_else(|| NebulaError::ToolNotFound(Tool::YtDlp.name().to_string()))
+    }
+
+    /// 当前使用的 ffmpeg 路径
+    fn ffmpeg(&self) -> Option<PathBuf> {
+        self.ffmpeg_path.lock().ok().and_then(|path| path.clone())
+    }
+
+    /// 是否已找到 yt-dlp
+    pub fn is_available(&self) -> bool {
+        self.yt_dlp().is_ok()
+    }
+
+    /// 是否能下载该链接（有 yt-dlp，或者可以使用 Bilibili 原生下载）
+    pub fn can_download(&self, url: &str) -> bool {
+        self.is_available() || self.use_native(url)
+    }
 
-        video_domains.iter().any(|domain| url.contains(domain))
+    /// 该链接是否使用 Bilibili 原生下载
+    fn use_native(&self, url: &str) -> bool {
+        self.http.is_some()
+            && BilibiliLink::parse(url).is_some()
+            && (self.native_bilibili || !self.is_available())
+    }
+
+    /// 重新查找 yt-dlp 和 ffmpeg，用于更新或安装工具之后
+    ///
+    /// 已在运行的下载不受影响，之后启动的 yt-dlp 进程使用新路径
+    pub fn refresh_tools(&self, tools: &ToolManager) {
+        let yt_dlp = tools.locate(Tool::YtDlp).map(|(path, _)| path);
+        let ffmpeg = tools.locate(Tool::Ffmpeg).map(|(path, _)| path);
+        match &yt_dlp {
+            Some(path) => info!("使用 yt-dlp: {:?}", path),
+            None => warn!("未找到 yt-dlp，视频下载将不可用"),
+        }
+        if ffmpeg.is_none() {
+            warn!("未找到 ffmpeg，视频合并可能失败");
+        }
+        if let Ok(mut path) = self.yt_dlp_path.lock() {
+            *path = yt_dlp;
+        }
+        if let Ok(mut path) = self.ffmpeg_path.lock() {
+            *path = ffmpeg;
+        }
     }
 
     /// 获取视频信息
     pub async fn get_video_info(&self, url: &str) -> Result<VideoInfo> {
         info!("获取视频信息: {}", url);
 
-        let output = Command::new(&self.yt_dlp_path)
-            .args([
-                "-j",
-                "--no-warnings",
-                "--no-playlist",
-                url,
-            ])
+        if self.use_native(url) {
+            let video = self.bilibili.resolve(url).await?;
+            let play = self.bilibili.play_url(&video).await?;
+            let mut info = video.to_video_info(&play, url);
+            info.formats.extend(audio_choices());
+            return Ok(info);
+        }
+
+        // 凭据的临时文件在 yt-dlp 退出后删除
+        let credentials = self.vault_credentials(url).await;
+        let output = Command::new(self.yt_dlp()?)
+            .args(["-j", "--no-warnings", "--no-playlist"])
+            .args(credential_args(&credentials))
+            .arg(url)
             .output()
             .await
             .map_err(|e| NebulaError::Internal(format!("执行 yt-dlp 失败: {}", e)))?;
@@ -247,20 +721,30 @@ impl VideoHandler {
             .formats
             .unwrap_or_default()
             .into_iter()
-            .filter(|f| {
-                // 过滤掉只有音频或只有视频的格式
-                f.vcodec.as_ref().map(|v| v != "none").unwrap_or(false)
-                    || f.acodec.as_ref().map(|a| a != "none").unwrap_or(false)
-            })
-            .map(|f| VideoFormat {
-                format_id: f.format_id,
-                ext: f.ext.unwrap_or_else(|| "mp4".to_string()),
-                resolution: f.resolution,
-                filesize: f.filesize.or(f.filesize_approx),
-                vcodec: f.vcodec,
-                acodec: f.acodec,
-                format_note: f.format_note,
+            .filter_map(|f| {
+                let has_video = f.vcodec.as_deref().is_some_and(|v| v != "none");
+                let has_audio = f.acodec.as_deref().is_some_and(|a| a != "none");
+                // 过滤掉既没有视频也没有音频的格式（如故事板预览图）
+                if !has_video && !has_audio {
+                    return None;
+                }
+                Some(VideoFormat {
+                    format_id: f.format_id,
+                    ext: f.ext.unwrap_or_else(|| "mp4".to_string()),
+                    resolution: f.resolution,
+                    filesize: f.filesize.or(f.filesize_approx),
+                    vcodec: f.vcodec,
+                    acodec: f.acodec,
+                    format_note: f.format_note,
+                    fps: f.fps,
+                    tbr: f.tbr,
+                    dynamic_range: f.dynamic_range,
+                    language: f.language,
+                    audio_only: !has_video,
+                    video_only: !has_audio,
+                })
             })
+            .chain(audio_choices())
             .collect();
 
         Ok(VideoInfo {
@@ -272,37 +756,123 @@ impl VideoHandler {
             uploader: info.uploader,
             formats,
             webpage_url: info.webpage_url.unwrap_or_else(|| url.to_string()),
+            upload_date: info.upload_date,
+            resolution: info.resolution,
+            playlist_index: info.playlist_index,
         })
     }
 
-    /// 下载视频
+    /// 展开播放列表、频道或多 P 视频，只列出条目而不解析每个视频
+    ///
+    /// 单个视频会被当作只有一个条目的播放列表
+    pub async fn get_playlist_info(&self, url: &str) -> Result<PlaylistInfo> {
+        info!("展开播放列表: {}", url);
+
+        // 凭据的临时文件在 yt-dlp 退出后删除
+        let credentials = self.vault_credentials(url).await;
+        let output = Command::new(self.yt_dlp()?)
+            .args(["-J", "--flat-playlist", "--no-warnings"])
+            .args(credential_args(&credentials))
+            .arg(url)
+            .output()
+            .await
+            .map_err(|e| NebulaError::Internal(format!("执行 yt-dlp 失败: {}", e)))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(NebulaError::Internal(format!(
+                "获取播放列表失败: {}",
+                stderr
+            )));
+        }
+
+        let playlist: YtDlpPlaylist = serde_json::from_slice(&output.stdout)
+            .map_err(|e| NebulaError::Internal(format!("解析播放列表失败: {}", e)))?;
+        Ok(playlist_info(playlist, url))
+    }
+
+    /// 询问 yt-dlp 由哪个提取器处理该链接
     ///
-    /// # 参数
-    /// - `url`: 视频 URL
-    /// - `format_id`: 可选的格式 ID
-    /// - `cookies_path`: 可选的 cookies 文件路径（用于登录态下载高码率）
-    /// - `event_tx`: 事件发送通道
-    /// - `task_id`: 任务 ID
-    pub async fn download_video(
+    /// 用于站点规则无法识别的链接。yt-dlp 不支持或只能由通用提取器（Generic）
+    /// 处理时返回 None
+    pub async fn probe_extractor(&self, url: &str) -> Result<Option<String>> {
+        debug!("询问 yt-dlp 提取器: {}", url);
+
+        let output = Command::new(self.yt_dlp()?)
+            .args(["-J", "--flat-playlist", "--no-warnings", url])
+            .kill_on_drop(true)
+            .output();
+        let output = tokio::time::timeout(PROBE_TIMEOUT, output)
+            .await
+            .map_err(|_| NebulaError::Timeout(format!("yt-dlp 识别链接超时: {}", url)))?
+            .map_err(|e| NebulaError::Internal(format!("执行 yt-dlp 失败: {}", e)))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("Unsupported URL") {
+                return Ok(None);
+            }
+            return Err(NebulaError::Internal(format!(
+                "yt-dlp 识别链接失败: {}",
+                stderr.trim()
+            )));
+        }
+
+        let playlist: YtDlpPlaylist = serde_json::from_slice(&output.stdout)
+            .map_err(|e| NebulaError::Internal(format!("解析 yt-dlp 输出失败: {}", e)))?;
+        Ok(playlist
+            .extractor_key
+            .filter(|key| !key.eq_ignore_ascii_case("generic")))
+    }
+
+    /// 保险库中与链接主机匹配的 yt-dlp 凭据
+    async fn vault_credentials(&self, url: &str) -> YtDlpCredentials {
+        let Some(vault) = &self.vault else {
+            return YtDlpCredentials::default();
+        };
+        vault.yt_dlp_credentials(url).await.unwrap_or_else(|e| {
+            warn!("导出站点凭据失败: {}", e);
+            YtDlpCredentials::default()
+        })
+    }
+
+    /// 构造 yt-dlp 下载参数
+    #[allow(clippy::too_many_arguments)]
+    fn download_args(
         &self,
         url: &str,
         format_id: Option<&str>,
         cookies_path: Option<&PathBuf>,
-        event_tx: mpsc::Sender<DownloadEvent>,
-        task_id: TaskId,
-    ) -> Result<PathBuf> {
-        info!("开始下载视频: {} (format: {:?}, cookies: {:?})", url, format_id, cookies_path.is_some());
-
-        let output_template = self
-            .output_dir
-            .join("%(id)s.%(ext)s")
-            .to_string_lossy()
-            .to_string();
+        credential_args: &[String],
+        save_path: &Path,
+        output_name: Option<&str>,
+        options: &VideoOptions,
+    ) -> Vec<String> {
+        // 文件名已按模板生成时需要转义 yt-dlp 模板中的 %，否则使用视频 ID
+        let file_name = match output_name {
+            Some(name) => format!("{}.%(ext)s", name.replace('%', "%%")),
+            None => "%(id)s.%(ext)s".to_string(),
+        };
+        let output_template = save_path.join(file_name).to_string_lossy().to_string();
 
         let mut args = vec![
             "--newline".to_string(),
             "--no-warnings".to_string(),
             "--no-playlist".to_string(),
+            // 以 JSON 输出进度，附带当前下载流的编码用于区分视频/音频
+            "--progress-template".to_string(),
+            format!(
+                "download:{} %(info.vcodec|none)s %(info.acodec|none)s %(progress)j",
+                PROGRESS_PREFIX
+            ),
+            "--progress-template".to_string(),
+            format!("postprocess:{} %(progress)j", POSTPROCESS_PREFIX),
+            // 输出合并、移动后的最终文件路径；--print 隐含 --quiet，需要显式保留进度输出
+            "--print".to_string(),
+            format!("after_move:{} %(filepath)s", OUTPUT_FILE_PREFIX),
+            "--progress".to_string(),
+            // 重新启动时继续下载 .part 文件
+            "--continue".to_string(),
             "-o".to_string(),
             output_template,
         ];
@@ -315,201 +885,1085 @@ impl VideoHandler {
                 info!("使用 cookies 文件: {:?}", cookie_file);
             }
         }
+        args.extend_from_slice(credential_args);
 
         // 显式指定 ffmpeg 路径
-        if let Some(ffmpeg_path) = Self::find_ffmpeg() {
+        if let Some(ffmpeg_path) = self.ffmpeg() {
              args.push("--ffmpeg-location".to_string());
              args.push(ffmpeg_path.to_string_lossy().to_string());
         }
 
-        args.push("--merge-output-format".to_string());
-        args.push("mp4".to_string());
+        args.extend(extra_content_args(options));
 
-        if let Some(fmt) = format_id {
-            // 用户指定了格式（通常是 video-only）
-            // 自动附加 +bestaudio 以合并音频，如果合并失败则回退到该格式本身
-            args.push("-f".to_string());
-            args.push(format!("{}+bestaudio/best", fmt));
-        } else {
-            // 不指定 -f，让 yt-dlp 自动选择
-            // 优先选择 H.264 编码 (兼容性最好)，其次是分辨率
-            args.push("-S".to_string());
-            args.push("vcodec:h264,res,acodec:m4a".to_string());
+        match audio_extraction(format_id, options) {
+            Some(audio) => args.extend(audio_args(&audio)),
+            None => args.extend(format_args(format_id, &options.format)),
         }
-        
-        info!("YT-DLP Arguments (v7-cookies): {:?}", args);
 
         args.push(url.to_string());
+        args
+    }
 
-        info!("执行: {:?} {:?}", self.yt_dlp_path, args);
-
-        let mut child = Command::new(&self.yt_dlp_path)
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .map_err(|e| NebulaError::Internal(format!("启动 yt-dlp 失败: {}", e)))?;
-
-        // 发送开始事件
-        let _ = event_tx
-            .send(DownloadEvent::TaskStarted { task_id })
-            .await;
-
-        // 读取标准输出（进度信息）
-        let stdout = child.stdout.take();
-        let stderr = child.stderr.take();
-        
-        let task_id_clone = task_id;
-        let event_tx_clone = event_tx.clone();
-
-        // 异步读取 stdout
-        let stdout_handle = if let Some(stdout) = stdout {
-            Some(tokio::spawn(async move {
-                let reader = BufReader::new(stdout);
-                let mut lines = reader.lines();
-                
-                while let Ok(Some(line)) = lines.next_line().await {
-                    debug!("yt-dlp stdout: {}", line);
-                    // 解析进度
-                    if line.contains("[download]") && line.contains("%") {
-                        if let Some(progress) = Self::parse_progress(&line) {
-                            let _ = event_tx_clone
-                                .send(DownloadEvent::ProgressUpdated {
-                                    task_id: task_id_clone,
-                                    progress,
-                                })
-                                .await;
+    /// 下载视频，直到完成、失败或被取消
+    ///
+    /// 暂停时结束 yt-dlp 进程，恢复时以相同参数重新启动，由 yt-dlp 继续未完成的文件
+    async fn run(
+        &self,
+        task_id: TaskId,
+        task: &VideoTask,
+        mut control: watch::Receiver<VideoControl>,
+        url: &str,
+        format_id: Option<&str>,
+        event_tx: &mpsc::UnboundedSender<DownloadEvent>,
+    ) -> Result<()> {
+        if self.use_native(url) {
+            return self
+                .run_native_bilibili(task_id, task, control, url, format_id, event_tx)
+                .await;
+        }
+        info!("开始下载视频: {} (format: {:?})", url, format_id);
+
+        // 先获取视频信息，用于上报标题、大小等元数据（失败不影响下载）
+        let mut output_name = None;
+        match self.get_video_info(url).await {
+            Ok(video) => {
+                let name = video.output_name(&self.output_template, format_id, url);
+                *task.output_name.write().await = Some(name.clone());
+                output_name = Some(name);
+                let mime_type = match audio_extraction(format_id, &task.options) {
+                    Some(audio) => audio.format.mime_type(),
+                    None => "video/mp4",
+                };
+                let _ = event_tx.send(DownloadEvent::MetadataReceived {
+                    task_id,
+                    total_size: video.estimated_size(format_id).unwrap_or(0),
+                    name: video.title,
+                    file_count: 1,
+                    mime_type: Some(mime_type.to_string()),
+                    supports_resume: true,
+                    thumbnail: video.thumbnail,
+                    duration_secs: video.duration,
+                });
+            }
+            Err(e) => warn!("获取视频元数据失败，继续下载: {}", e),
+        }
+
+        // 优先使用保险库中该站点的 cookies，否则导出 Bilibili cookies (如果已登录)；
+        // 保险库凭据的临时文件在本函数返回（yt-dlp 已退出）时删除
+        let credentials = self.vault_credentials(url).await;
+        let cookies_path = match (credentials.cookies_file.clone(), &self.bilibili_auth) {
+            (Some(path), _) => Some(path),
+            (None, Some(auth)) => auth.export_cookies_for_ytdlp().await.ok().flatten(),
+            (None, None) => None,
+        };
+        let args = self.download_args(
+            url,
+            format_id,
+            cookies_path.as_ref(),
+            &credentials.args,
+            &task.save_path,
+            output_name.as_deref(),
+            &task.options,
+        );
+        let yt_dlp = self.yt_dlp()?;
+        info!("执行: {:?} {:?}", yt_dlp, redact_args(&args));
+
+        let mut started = false;
+        loop {
+            if !Self::wait_for_run(task, &mut control).await {
+                return Ok(());
+            }
+
+            let mut child = Command::new(&yt_dlp)
+                .args(&args)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .kill_on_drop(true)
+                .spawn()
+                .map_err(|e| NebulaError::Internal(format!("启动 yt-dlp 失败: {}", e)))?;
+
+            // 发送开始事件
+            if !started {
+                started = true;
+                let _ = event_tx.send(DownloadEvent::TaskStarted { task_id });
+            }
+
+            // 异步读取 stdout（进度模板输出的 JSON）
+            let stdout_handle = child.stdout.take().map(|stdout| {
+                let event_tx = event_tx.clone();
+                let tracker = Arc::clone(&task.tracker);
+                tokio::spawn(async move {
+                    let mut lines = BufReader::new(stdout).lines();
+                    while let Ok(Some(line)) = lines.next_line().await {
+                        debug!("yt-dlp stdout: {}", line);
+                        if let Some(path) = parse_output_file(&line) {
+                            info!("视频已保存: {:?}", path);
+                            let _ = event_tx.send(DownloadEvent::OutputFileResolved { task_id, path });
+                            continue;
+                        }
+                        let (progress, stage) = {
+                            let mut tracker = tracker.write().await;
+                            let (updated, stage) = tracker.feed(&line);
+                            (updated.then(|| tracker.progress.clone()), stage)
+                        };
+                        if let Some(stage) = stage {
+                            let _ = event_tx
+                                .send(DownloadEvent::VideoStageChanged { task_id, stage });
+                        }
+                        if let Some(progress) = progress {
+                            let _ = event_tx
+                                .send(DownloadEvent::ProgressUpdated { task_id, progress });
                         }
                     }
+                })
+            });
+
+            // 异步读取 stderr
+            let stderr_handle = child.stderr.take().map(|stderr| {
+                tokio::spawn(async move {
+                    let mut lines = BufReader::new(stderr).lines();
+                    let mut output = String::new();
+                    while let Ok(Some(line)) = lines.next_line().await {
+                        debug!("yt-dlp stderr: {}", line);
+                        output.push_str(&line);
+                        output.push('\n');
+                    }
+                    output
+                })
+            });
+
+            // 等待子进程结束，或在暂停/取消时结束子进程
+            let status = tokio::select! {
+                status = child.wait() => Some(status.map_err(|e| {
+                    NebulaError::Internal(format!("等待 yt-dlp 失败: {}", e))
+                })?),
+                _ = Self::stop_requested(&mut control) => {
+                    let _ = child.kill().await;
+                    None
                 }
-            }))
-        } else {
-            None
-        };
+            };
 
-        // 异步读取 stderr
-        let stderr_output = if let Some(stderr) = stderr {
-            let reader = BufReader::new(stderr);
-            let mut lines = reader.lines();
-            let mut output = String::new();
-            
-            while let Ok(Some(line)) = lines.next_line().await {
-                debug!("yt-dlp stderr: {}", line);
-                output.push_str(&line);
-                output.push('\n');
+            if let Some(handle) = stdout_handle {
+                let _ = handle.await;
             }
-            output
-        } else {
-            String::new()
-        };
-
-        // 等待 stdout 读取完成
-        if let Some(handle) = stdout_handle {
-            let _ = handle.await;
-        }
+            let stderr_output = match stderr_handle {
+                Some(handle) => handle.await.unwrap_or_default(),
+                None => String::new(),
+            };
 
-        // 等待子进程完成
-        let status = child
-            .wait()
-            .await
-            .map_err(|e| NebulaError::Internal(format!("等待 yt-dlp 失败: {}", e)))?;
+            let Some(status) = status else {
+                // 已暂停或取消，回到循环开头处理
+                let progress = &mut task.tracker.write().await.progress;
+                progress.download_speed = 0;
+                progress.eta_secs = None;
+                continue;
+            };
 
-        if status.success() {
-            info!("视频下载完成");
-            let _ = event_tx
-                .send(DownloadEvent::TaskCompleted {
+            if status.success() {
+                info!("视频下载完成");
+                if task.options.danmaku {
+                    if let Some(name) = &output_name {
+                        Self::convert_danmaku(&task.save_path, name).await;
+                    }
+                }
+                let _ = event_tx.send(DownloadEvent::TaskCompleted {
                     task_id,
                     completed_at: chrono::Utc::now(),
-                })
-                .await;
-            Ok(self.output_dir.clone())
-        } else {
+                });
+                return Ok(());
+            }
+
             let error_msg = if stderr_output.is_empty() {
                 "视频下载失败".to_string()
             } else {
                 format!("视频下载失败: {}", stderr_output.lines().next().unwrap_or(""))
             };
             error!("{}", error_msg);
-            let _ = event_tx
-                .send(DownloadEvent::TaskFailed {
-                    task_id,
-                    error: error_msg.clone(),
-                })
-                .await;
-            Err(NebulaError::Internal(error_msg))
+            return Err(NebulaError::Internal(error_msg));
         }
     }
 
-    /// 解析 yt-dlp 进度输出
-    fn parse_progress(line: &str) -> Option<Progress> {
-        // [download]  45.2% of 100.00MiB at 5.00MiB/s ETA 00:10
-        let parts: Vec<&str> = line.split_whitespace().collect();
+    /// 不使用 yt-dlp 下载 Bilibili 视频
+    ///
+    /// 通过 HTTP 引擎分段下载 DASH 视频流和音频流，再用 ffmpeg 合并。
+    /// 暂停时中断下载，已完成的分段在恢复后不会重新下载。
+    /// DASH 地址带有时效签名，恢复下载或返回 403/404 时重新调用 playurl 获取新地址
+    async fn run_native_bilibili(
+        &self,
+        task_id: TaskId,
+        task: &VideoTask,
+        mut control: watch::Receiver<VideoControl>,
+        url: &str,
+        format_id: Option<&str>,
+        event_tx: &mpsc::UnboundedSender<DownloadEvent>,
+    ) -> Result<()> {
+        info!("原生下载 Bilibili 视频: {} (format: {:?})", url, format_id);
+        let http = self
+            .http
+            .clone()
+            .ok_or_else(|| NebulaError::Internal("HTTP 引擎未初始化".to_string()))?;
+
+        let video = self.bilibili.resolve(url).await?;
+        let play = self.bilibili.play_url(&video).await?;
+        let info = video.to_video_info(&play, url);
+        let name = info.output_name(&self.output_template, format_id, url);
+        *task.output_name.write().await = Some(name.clone());
+
+        // 选择要下载的流：提取音频或指定音频流时只下载音频
+        let options = &task.options;
+        let extraction = audio_extraction(format_id, options);
+        let explicit_audio = format_id.and_then(|id| native::audio_by_format_id(&play, id));
+        let audio_only = extraction.is_some() || explicit_audio.is_some();
+        let ext = native::container_extension(options.format.container);
+        let video_stream = match audio_only {
+            true => None,
+            false => Some(
+                native::select_video(&play, format_id, &options.format)
+                    .ok_or_else(|| NebulaError::Internal("没有可下载的视频流".to_string()))?,
+            ),
+        };
+        let allow_flac = match &extraction {
+            Some(audio) => audio.format == AudioFormat::Flac,
+            None => ext == "mkv",
+        };
+        let audio_stream =
+            explicit_audio.or_else(|| native::select_audio(&play, !audio_only, allow_flac));
+        if audio_only && audio_stream.is_none() {
+            return Err(NebulaError::Internal("没有可下载的音频流".to_string()));
+        }
+
+        // 除 M4A 直接保存外，提取音频都需要 ffmpeg 转码
+        let ffmpeg = self.ffmpeg();
+        let needs_transcode = extraction.as_ref().is_some_and(|audio| {
+            audio.format != AudioFormat::M4a || audio.normalize || audio.bitrate_kbps.is_some()
+        });
+        if needs_transcode && ffmpeg.is_none() {
+            return Err(NebulaError::ToolNotFound(Tool::Ffmpeg.name().to_string()));
+        }
+
+        let headers = self.bilibili.headers().await;
+        let mut parts = Vec::new();
+        for (stage, stream, suffix) in [
+            (VideoStage::Video, video_stream, "video"),
+            (VideoStage::Audio, audio_stream, "audio"),
+        ] {
+            let Some(stream) = stream else { continue };
+            let url = stream
+                .urls
+                .first()
+                .ok_or_else(|| NebulaError::Internal("DASH 流没有可用地址".to_string()))?;
+            let size = http
+                .probe_size(url, &headers)
+                .await?
+                .ok_or_else(|| NebulaError::Internal("无法获取 DASH 流大小".to_string()))?;
+            let path = task.save_path.join(format!("{}.{}.m4s", name, suffix));
+            parts.push((stage, stream.clone(), path, size));
+        }
+        let total_size: u64 = parts.iter().map(|(_, _, _, size)| size).sum();
+
+        let mime_type = match &extraction {
+            Some(audio) => audio.format.mime_type(),
+            None if audio_only => "audio/mp4",
+            None => "video/mp4",
+        };
+        let _ = event_tx.send(DownloadEvent::MetadataReceived {
+            task_id,
+            total_size,
+            name: info.title.clone(),
+            file_count: 1,
+            mime_type: Some(mime_type.to_string()),
+            supports_resume: true,
+            thumbnail: info.thumbnail.clone(),
+            duration_secs: info.duration,
+        });
+
+        let downloaded = Arc::new(AtomicU64::new(0));
+        let mut started = false;
+        let mut refreshes = 0;
+        loop {
+            if !Self::wait_for_run(task, &mut control).await {
+                return Ok(());
+            }
+            if started {
+                // 恢复下载或地址过期后重新进入循环，地址可能已经失效
+                self.refresh_stream_urls(&video, &mut parts).await?;
+            } else {
+                started = true;
+                let _ = event_tx.send(DownloadEvent::TaskStarted { task_id });
+            }
 
-        let mut percentage = 0.0;
-        let mut total_size = 0u64;
-        let mut download_speed = 0u64;
-        let mut eta_secs = None;
+            // 每次启动时由 fetch_stream 重新累加已完成的分段
+            downloaded.store(0, Ordering::Relaxed);
+            let ticker = tokio::spawn(Self::report_progress(
+                task_id,
+                Arc::clone(&task.tracker),
+                Arc::clone(&downloaded),
+                total_size,
+                event_tx.clone(),
+            ));
+            let download = async {
+                for (stage, stream, path, size) in &parts {
+                    if let Some(stage) = task.tracker.write().await.set_stage(*stage) {
+                        let _ = event_tx.send(DownloadEvent::VideoStageChanged { task_id, stage });
+                    }
+                    native::fetch_stream(&http, stream, *size, path, &headers, &downloaded).await?;
+                }
+                Ok::<_, NebulaError>(())
+            };
+            let result = tokio::select! {
+                result = download => Some(result),
+                _ = Self::stop_requested(&mut control) => None,
+            };
+            ticker.abort();
 
-        for (i, part) in parts.iter().enumerate() {
-            if part.ends_with('%') {
-                if let Ok(p) = part.trim_end_matches('%').parse::<f64>() {
-                    percentage = p;
+            match result {
+                Some(Err(e)) if native::is_url_expired(&e) && refreshes < MAX_URL_REFRESHES => {
+                    warn!("DASH 地址已过期，重新获取: {}", e);
+                    refreshes += 1;
+                }
+                Some(result) => {
+                    result?;
+                    break;
                 }
-            } else if part.contains("MiB") || part.contains("GiB") || part.contains("KiB") {
-                if i > 0 && parts.get(i - 1) == Some(&"of") {
-                    total_size = Self::parse_size(part);
-                } else if i > 0 && parts.get(i - 1) == Some(&"at") {
-                    download_speed = Self::parse_size(part);
+                None => {
+                    // 已暂停或取消，回到循环开头处理
+                    refreshes = 0;
+                    let progress = &mut task.tracker.write().await.progress;
+                    progress.download_speed = 0;
+                    progress.eta_secs = None;
                 }
-            } else if i > 0 && parts.get(i - 1) == Some(&"ETA") {
-                eta_secs = Self::parse_eta(part);
             }
         }
+        let progress = Progress::new(total_size, total_size);
+        task.tracker.write().await.progress = progress.clone();
+        let _ = event_tx.send(DownloadEvent::ProgressUpdated { task_id, progress });
+
+        let video_path = parts
+            .iter()
+            .find(|(stage, ..)| *stage == VideoStage::Video)
+            .map(|(_, _, path, _)| path.clone());
+        let audio_path = parts
+            .iter()
+            .find(|(stage, ..)| *stage == VideoStage::Audio)
+            .map(|(_, _, path, _)| path.clone());
+        let output_path = |ext: &str| task.save_path.join(format!("{}.{}", name, ext));
+        let set_stage = |stage: VideoStage| async move {
+            if let Some(stage) = task.tracker.write().await.set_stage(stage) {
+                let _ = event_tx.send(DownloadEvent::VideoStageChanged { task_id, stage });
+            }
+        };
 
-        let downloaded_size = (total_size as f64 * percentage / 100.0) as u64;
+        let output = match (video_path, audio_path, ffmpeg) {
+            (Some(video), audio, Some(ffmpeg)) => {
+                set_stage(VideoStage::Merging).await;
+                let output = output_path(ext);
+                native::run_ffmpeg(&ffmpeg, &native::merge_args(&video, audio.as_deref(), &output))
+                    .await?;
+                let _ = tokio::fs::remove_file(&video).await;
+                if let Some(audio) = audio {
+                    let _ = tokio::fs::remove_file(&audio).await;
+                }
+                output
+            }
+            (Some(video), audio, None) => {
+                warn!("未找到 ffmpeg，视频流和音频流分别保存");
+                let output = output_path("mp4");
+                tokio::fs::rename(&video, &output).await?;
+                if let Some(audio) = audio {
+                    tokio::fs::rename(&audio, output_path("m4a")).await?;
+                }
+                output
+            }
+            (None, Some(audio), ffmpeg) => {
+                let (output, args) = match &extraction {
+                    Some(extraction) if needs_transcode => {
+                        set_stage(VideoStage::ExtractingAudio).await;
+                        let output = output_path(extraction.format.extension());
+                        let args = native::audio_convert_args(&audio, &output, extraction);
+                        (output, Some(args))
+                    }
+                    _ => {
+                        let output = output_path("m4a");
+                        let args = native::merge_args(&audio, None, &output);
+                        (output, Some(args).filter(|_| ffmpeg.is_some()))
+                    }
+                };
+                match (args, ffmpeg) {
+                    (Some(args), Some(ffmpeg)) => {
+                        native::run_ffmpeg(&ffmpeg, &args).await?;
+                        let _ = tokio::fs::remove_file(&audio).await;
+                    }
+                    _ => tokio::fs::rename(&audio, &output).await?,
+                }
+                output
+            }
+            (None, None, _) => return Err(NebulaError::Internal("没有下载任何流".to_string())),
+        };
+        info!("视频已保存: {:?}", output);
 
-        Some(Progress {
-            total_size,
-            downloaded_size,
-            download_speed,
-            upload_speed: 0,
-            eta_secs,
-            percentage,
-        })
+        self.write_native_extras(task, &video, &info, &name).await;
+
+        let _ = event_tx.send(DownloadEvent::OutputFileResolved {
+            task_id,
+            path: output,
+        });
+        let _ = event_tx.send(DownloadEvent::TaskCompleted {
+            task_id,
+            completed_at: chrono::Utc::now(),
+        });
+        Ok(())
     }
 
-    fn parse_size(s: &str) -> u64 {
-        let s = s.trim();
-        if s.ends_with("GiB") || s.ends_with("GiB/s") {
-            let num = s.trim_end_matches("GiB").trim_end_matches("/s");
-            num.parse::<f64>().unwrap_or(0.0) as u64 * 1024 * 1024 * 1024
-        } else if s.ends_with("MiB") || s.ends_with("MiB/s") {
-            let num = s.trim_end_matches("MiB").trim_end_matches("/s");
-            num.parse::<f64>().unwrap_or(0.0) as u64 * 1024 * 1024
-        } else if s.ends_with("KiB") || s.ends_with("KiB/s") {
-            let num = s.trim_end_matches("KiB").trim_end_matches("/s");
-            num.parse::<f64>().unwrap_or(0.0) as u64 * 1024
-        } else {
-            0
+    /// 重新调用 playurl，把每个流的地址换成同画质、同编码的新地址
+    async fn refresh_stream_urls(
+        &self,
+        video: &native::BilibiliVideo,
+        parts: &mut [(VideoStage, native::DashStream, PathBuf, u64)],
+    ) -> Result<()> {
+        let play = self.bilibili.play_url(video).await?;
+        for (_, stream, _, _) in parts.iter_mut() {
+            let fresh = play.matching(stream).ok_or_else(|| {
+                NebulaError::Internal(format!("重新获取地址后找不到画质 {} 的流", stream.id))
+            })?;
+            stream.urls = fresh.urls.clone();
+        }
+        debug!("已刷新 {} 个 DASH 流的地址", parts.len());
+        Ok(())
+    }
+
+    /// 原生下载时每秒汇报一次进度，直到被中止
+    async fn report_progress(
+        task_id: TaskId,
+        tracker: Arc<RwLock<ProgressTracker>>,
+        downloaded: Arc<AtomicU64>,
+        total_size: u64,
+        event_tx: mpsc::UnboundedSender<DownloadEvent>,
+    ) {
+        let mut last = (Instant::now(), downloaded.load(Ordering::Relaxed));
+        let mut interval = tokio::time::interval(Duration::from_secs(1));
+        loop {
+            interval.tick().await;
+            let current = downloaded.load(Ordering::Relaxed).min(total_size);
+            let elapsed = last.0.elapsed().as_secs_f64();
+            let mut progress = Progress::new(total_size, current);
+            if elapsed > 0.0 {
+                progress.download_speed = (current.saturating_sub(last.1) as f64 / elapsed) as u64;
+            }
+            progress.eta_secs = (total_size - current).checked_div(progress.download_speed);
+            last = (Instant::now(), current);
+
+            tracker.write().await.progress = progress.clone();
+            let _ = event_tx.send(DownloadEvent::ProgressUpdated { task_id, progress });
+        }
+    }
+
+    /// 原生下载完成后保存弹幕、封面和视频信息（失败只记录警告）
+    async fn write_native_extras(
+        &self,
+        task: &VideoTask,
+        video: &native::BilibiliVideo,
+        info: &VideoInfo,
+        name: &str,
+    ) {
+        let options = &task.options;
+        if options.danmaku {
+            let path = task.save_path.join(format!("{}.danmaku.xml", name));
+            match self.bilibili.danmaku_xml(video.cid).await {
+                Ok(xml) => match tokio::fs::write(&path, xml).await {
+                    Ok(()) => Self::convert_danmaku(&task.save_path, name).await,
+                    Err(e) => warn!("保存弹幕失败: {}", e),
+                },
+                Err(e) => warn!("下载弹幕失败: {}", e),
+            }
+        }
+
+        if options.write_thumbnail {
+            if let Some(url) = &info.thumbnail {
+                let ext = Path::new(url)
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .unwrap_or("jpg");
+                let path = task.save_path.join(format!("{}.{}", name, ext));
+                let result = match self.bilibili.fetch_bytes(url).await {
+                    Ok(bytes) => tokio::fs::write(&path, bytes).await.map_err(Into::into),
+                    Err(e) => Err(e),
+                };
+                if let Err(e) = result {
+                    warn!("下载封面失败: {}", e);
+                }
+            }
+        }
+
+        if options.write_info_json {
+            let path = task.save_path.join(format!("{}.info.json", name));
+            match serde_json::to_vec_pretty(info) {
+                Ok(json) => {
+                    if let Err(e) = tokio::fs::write(&path, json).await {
+                        warn!("保存视频信息失败: {}", e);
+                    }
+                }
+                Err(e) => warn!("序列化视频信息失败: {}", e),
+            }
+        }
+
+        if !options.subtitle_langs.is_empty()
+            || options.embed_metadata
+            || options.embed_thumbnail
+            || options.embed_chapters
+        {
+            warn!("原生下载暂不支持字幕和嵌入元数据，已忽略这些选项");
+        }
+    }
+
+    /// 把下载的 XML 弹幕转换为 ASS 字幕，转换成功后删除 XML 文件
+    async fn convert_danmaku(save_path: &Path, output_name: &str) {
+        let xml_path = save_path.join(format!("{}.danmaku.xml", output_name));
+        if !xml_path.exists() {
+            warn!("没有找到弹幕文件: {:?}", xml_path);
+            return;
+        }
+        let ass_path = xml_path.with_extension("ass");
+        match danmaku::convert_file(&xml_path, &ass_path, &DanmakuStyle::default()).await {
+            Ok(count) => {
+                info!("已转换 {} 条弹幕: {:?}", count, ass_path);
+                let _ = tokio::fs::remove_file(&xml_path).await;
+            }
+            Err(e) => warn!("弹幕转换失败: {}", e),
+        }
+    }
+
+    /// 暂停期间等待恢复或取消
+    ///
+    /// # 返回
+    /// 是否继续下载（已取消时返回 false，需要时会删除已下载的文件）
+    async fn wait_for_run(task: &VideoTask, control: &mut watch::Receiver<VideoControl>) -> bool {
+        loop {
+            let current = *control.borrow_and_update();
+            match current {
+                VideoControl::Run => return true,
+                VideoControl::Pause => {}
+                VideoControl::Cancel { delete_files } => {
+                    if delete_files {
+                        task.remove_files().await;
+                    }
+                    return false;
+                }
+            }
+            if control.changed().await.is_err() {
+                return false;
+            }
+        }
+    }
+
+    /// 等待暂停或取消指令
+    async fn stop_requested(control: &mut watch::Receiver<VideoControl>) {
+        while control.changed().await.is_ok() {
+            if *control.borrow() != VideoControl::Run {
+                return;
+            }
         }
+        std::future::pending::<()>().await
+    }
+
+    /// 获取任务
+    async fn task(&self, task_id: TaskId) -> Result<Arc<VideoTask>> {
+        self.tasks
+            .read()
+            .await
+            .get(&task_id)
+            .cloned()
+            .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))
     }
+}
+
+/// 画质列表中的音频提取选项，`format_id` 形如 `audio:mp3`
+fn audio_choices() -> impl Iterator<Item = VideoFormat> {
+    AudioFormat::ALL.into_iter().map(|format| VideoFormat {
+        format_id: AudioExtraction::new(format).format_id(),
+        ext: format.extension().to_string(),
+        resolution: Some("audio only".to_string()),
+        filesize: None,
+        vcodec: Some("none".to_string()),
+        acodec: Some(format.extension().to_string()),
+        format_note: Some(format!("仅音频 ({})", format.extension().to_uppercase())),
+        fps: None,
+        tbr: None,
+        dynamic_range: None,
+        language: None,
+        audio_only: true,
+        video_only: false,
+    })
+}
 
-    fn parse_eta(s: &str) -> Option<u64> {
-        // 格式: MM:SS 或 HH:MM:SS
-        let parts: Vec<&str> = s.split(':').collect();
-        match parts.len() {
-            2 => {
-                let mins: u64 = parts[0].parse().ok()?;
-                let secs: u64 = parts[1].parse().ok()?;
-                Some(mins * 60 + secs)
+/// 画质选择对应的 yt-dlp 参数（`-f`、`-S` 和合并容器）
+///
+/// 指定 `format_id` 时只下载该格式，纯视频格式自动合并最佳音频；
+/// 否则按偏好生成筛选条件，并逐级放宽到最佳可用格式
+fn format_args(format_id: Option<&str>, pref: &FormatPreference) -> Vec<String> {
+    let audio = match &pref.audio_language {
+        Some(lang) => format!("(ba[language^={}]/ba)", lang),
+        None => "ba".to_string(),
+    };
+
+    let selector = match format_id {
+        Some(id) => format!(
+            "bv[format_id={id}]+{audio}/b[format_id={id}]/{id}",
+            id = id,
+            audio = audio
+        ),
+        None => {
+            let mut filters = String::new();
+            if let Some(height) = pref.max_height {
+                filters.push_str(&format!("[height<=?{}]", height));
+            }
+            if let Some(fps) = pref.max_fps {
+                filters.push_str(&format!("[fps<=?{}]", fps));
             }
-            3 => {
-                let hours: u64 = parts[0].parse().ok()?;
-                let mins: u64 = parts[1].parse().ok()?;
-                let secs: u64 = parts[2].parse().ok()?;
-                Some(hours * 3600 + mins * 60 + secs)
+            if let Some(size) = pref.max_filesize {
+                filters.push_str(&format!("[filesize<?{}]", size));
+            }
+            if filters.is_empty() {
+                format!("bv*+{}/b", audio)
+            } else {
+                format!("bv*{f}+{a}/b{f}/bv*+{a}/b", f = filters, a = audio)
             }
-            _ => None,
         }
+    };
+
+    let mut sort = Vec::new();
+    if let Some(codec) = pref.video_codec {
+        sort.push(format!("vcodec:{}", codec.name()));
+    }
+    sort.push(if pref.prefer_hdr { "hdr" } else { "hdr:sdr" }.to_string());
+    match pref.max_height {
+        Some(height) => sort.push(format!("res:{}", height)),
+        None => sort.push("res".to_string()),
+    }
+    sort.push("fps".to_string());
+    if pref.container == VideoContainer::Mp4 {
+        sort.push("acodec:m4a".to_string());
+    }
+
+    vec![
+        "-f".to_string(),
+        selector,
+        "-S".to_string(),
+        sort.join(","),
+        "--merge-output-format".to_string(),
+        pref.container.extension().to_string(),
+    ]
+}
+
+/// 任务的音频提取设置：优先使用选项中的设置，其次是画质列表中选择的音频选项
+fn audio_extraction(format_id: Option<&str>, options: &VideoOptions) -> Option<AudioExtraction> {
+    options
+        .audio
+        .clone()
+        .or_else(|| format_id.and_then(AudioExtraction::from_format_id))
+}
+
+/// 提取音频对应的 yt-dlp 参数
+fn audio_args(audio: &AudioExtraction) -> Vec<String> {
+    let mut args = vec![
+        "-f".to_string(),
+        "bestaudio/best".to_string(),
+        "--extract-audio".to_string(),
+        "--audio-format".to_string(),
+        audio.format.extension().to_string(),
+        "--audio-quality".to_string(),
+    ];
+    match audio.bitrate_kbps {
+        Some(kbps) if !audio.format.is_lossless() => args.push(format!("{}K", kbps)),
+        _ => args.push("0".to_string()),
+    }
+    if audio.normalize {
+        args.push("--postprocessor-args".to_string());
+        args.push(format!("ExtractAudio:-af {}", LOUDNORM_FILTER));
+    }
+    args
+}
+
+/// 查询视频信息时传给 yt-dlp 的站点凭据参数
+fn credential_args(credentials: &YtDlpCredentials) -> Vec<String> {
+    let mut args = Vec::new();
+    if let Some(cookie_file) = &credentials.cookies_file {
+        args.push("--cookies".to_string());
+        args.push(cookie_file.to_string_lossy().to_string());
+    }
+    args.extend_from_slice(&credentials.args);
+    args
+}
+
+/// 隐藏 yt-dlp 参数中的密码和认证请求头，用于日志输出
+fn redact_args(args: &[String]) -> Vec<&str> {
+    let mut redacted = Vec::with_capacity(args.len());
+    let mut secret_next = false;
+    for arg in args {
+        redacted.push(if secret_next { "***" } else { arg.as_str() });
+        secret_next = matches!(arg.as_str(), "--password" | "--add-header");
+    }
+    redacted
+}
+
+/// 字幕、弹幕、封面等附加内容对应的 yt-dlp 参数
+fn extra_content_args(options: &VideoOptions) -> Vec<String> {
+    let mut args = Vec::new();
+
+    // Bilibili 弹幕以名为 danmaku 的 XML 字幕提供
+    let mut langs = options.subtitle_langs.clone();
+    if options.danmaku {
+        langs.push("danmaku".to_string());
+    } else if langs.iter().any(|lang| lang == "all") {
+        langs.push("-danmaku".to_string());
+    }
+    if !langs.is_empty() {
+        args.push("--write-subs".to_string());
+        if options.auto_subtitles {
+            args.push("--write-auto-subs".to_string());
+        }
+        args.push("--sub-langs".to_string());
+        args.push(langs.join(","));
+    }
+
+    let flags = [
+        (options.write_thumbnail, "--write-thumbnail"),
+        (options.write_info_json, "--write-info-json"),
+        (options.embed_metadata, "--embed-metadata"),
+        (options.embed_thumbnail, "--embed-thumbnail"),
+        (options.embed_chapters, "--embed-chapters"),
+    ];
+    args.extend(
+        flags
+            .into_iter()
+            .filter(|(enabled, _)| *enabled)
+            .map(|(_, flag)| flag.to_string()),
+    );
+    args
+}
+
+/// 把 yt-dlp 的播放列表输出转换为 PlaylistInfo
+fn playlist_info(playlist: YtDlpPlaylist, url: &str) -> PlaylistInfo {
+    let webpage_url = playlist
+        .webpage_url
+        .clone()
+        .unwrap_or_else(|| url.to_string());
+    let title = playlist.title.clone().unwrap_or_else(|| webpage_url.clone());
+
+    let entries = match playlist.entries {
+        Some(entries) => entries
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, entry)| {
+                let url = entry
+                    .webpage_url
+                    .or(entry.url)
+                    .filter(|u| u.starts_with("http"))?;
+                Some(PlaylistEntry {
+                    index: i + 1,
+                    id: entry.id.unwrap_or_else(|| url.clone()),
+                    title: entry.title.unwrap_or_else(|| url.clone()),
+                    url,
+                    duration: entry.duration.map(|d| d as u64),
+                    uploader: entry.uploader,
+                })
+            })
+            .collect(),
+        // 单个视频
+        None => vec![PlaylistEntry {
+            index: 1,
+            id: playlist.id.clone().unwrap_or_else(|| webpage_url.clone()),
+            title: title.clone(),
+            url: webpage_url.clone(),
+            duration: None,
+            uploader: playlist.uploader.clone(),
+        }],
+    };
+
+    PlaylistInfo {
+        id: playlist.id.unwrap_or_else(|| webpage_url.clone()),
+        title,
+        uploader: playlist.uploader,
+        webpage_url,
+        entries,
+    }
+}
+
+#[async_trait]
+impl ProtocolHandler for VideoHandler {
+    async fn start(
+        &self,
+        task_id: TaskId,
+        source: &DownloadSource,
+        save_path: PathBuf,
+        event_tx: mpsc::UnboundedSender<DownloadEvent>,
+    ) -> Result<()> {
+        let (url, format_id, options) = match source {
+            DownloadSource::Video {
+                url,
+                format_id,
+                options,
+            } => (url.clone(), format_id.clone(), options.clone()),
+            _ => return Err(NebulaError::UnsupportedProtocol("非视频来源".to_string())),
+        };
+
+        let (control, control_rx) = watch::channel(VideoControl::Run);
+        let task = Arc::new(VideoTask {
+            control,
+            tracker: Arc::new(RwLock::new(ProgressTracker::default())),
+            save_path,
+            options,
+            output_name: RwLock::new(None),
+        });
+        self.tasks.write().await.insert(task_id, Arc::clone(&task));
+
+        self.run(task_id, &task, control_rx, &url, format_id.as_deref(), &event_tx)
+            .await
+    }
+
+    async fn pause(&self, task_id: TaskId) -> Result<()> {
+        let task = self.task(task_id).await?;
+        task.control.send_replace(VideoControl::Pause);
+        info!("视频任务已暂停: {}", task_id);
+        Ok(())
+    }
+
+    async fn resume(&self, task_id: TaskId) -> Result<()> {
+        let task = self.task(task_id).await?;
+        task.control.send_replace(VideoControl::Run);
+        info!("视频任务已恢复: {}", task_id);
+        Ok(())
+    }
+
+    async fn cancel(&self, task_id: TaskId, delete_files: bool) -> Result<()> {
+        let task = self
+            .tasks
+            .write()
+            .await
+            .remove(&task_id)
+            .ok_or_else(|| NebulaError::TaskNotFound(task_id.to_string()))?;
+
+        // 下载已结束时没有进程处理取消指令，直接清理文件
+        let finished = task.control.receiver_count() == 0;
+        task.control.send_replace(VideoControl::Cancel { delete_files });
+        if finished && delete_files {
+            task.remove_files().await;
+        }
+        info!("视频任务已取消: {}", task_id);
+        Ok(())
+    }
+
+    async fn get_progress(&self, task_id: TaskId) -> Result<Progress> {
+        let task = self.task(task_id).await?;
+        let progress = task.tracker.read().await.progress.clone();
+        Ok(progress)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::VideoCodec;
+
+    #[test]
+    fn test_progress_tracker() {
+        let mut tracker = ProgressTracker::default();
+
+        let video = r#"[nebula] avc1.640028 none {"status": "downloading", "filename": "a.f137.mp4", "downloaded_bytes": 2621440, "total_bytes": 10485760, "speed": 2621440.5, "eta": 3}"#;
+        assert_eq!(tracker.feed(video), (true, Some(VideoStage::Video)));
+        assert_eq!(tracker.progress.downloaded_size, 2621440);
+        assert_eq!(tracker.progress.download_speed, 2621440);
+        assert_eq!(tracker.progress.eta_secs, Some(3));
+        assert_eq!(tracker.progress.percentage, 25.0);
+
+        let done = r#"[nebula] avc1.640028 none {"status": "finished", "filename": "a.f137.mp4", "downloaded_bytes": 10485760, "total_bytes": 10485760}"#;
+        assert_eq!(tracker.feed(done), (true, None));
+
+        // 音频流按分片估算大小，总进度包含已完成的视频流
+        let audio = r#"[nebula] none mp4a.40.2 {"status": "downloading", "filename": "a.f140.m4a", "downloaded_bytes": 1000, "total_bytes": null, "fragment_index": 1, "fragment_count": 4}"#;
+        assert_eq!(tracker.feed(audio), (true, Some(VideoStage::Audio)));
+        assert_eq!(tracker.progress.total_size, 10485760 + 4000);
+        assert_eq!(tracker.progress.downloaded_size, 10485760 + 1000);
+
+        let merge = r#"[nebula-pp] {"status": "started", "postprocessor": "Merger"}"#;
+        assert_eq!(tracker.feed(merge), (false, Some(VideoStage::Merging)));
+        assert_eq!(tracker.feed("[download] 45.0% of ~100.00MiB"), (false, None));
+
+        let file = "[nebula-file] /downloads/标题 [BV1xx].mp4";
+        assert_eq!(parse_output_file(file), Some(PathBuf::from("/downloads/标题 [BV1xx].mp4")));
+        assert!(tracker.feed(file).1.is_none());
+
+        assert!(is_video_output("BV1xx.mp4", "BV1xx"));
+        assert!(is_video_output("BV1xx.f137.mp4.part", "BV1xx"));
+        assert!(!is_video_output("BV1xxy.mp4", "BV1xx"));
+    }
+
+    #[test]
+    fn test_extra_content_args() {
+        assert!(extra_content_args(&VideoOptions::default()).is_empty());
+
+        let options = VideoOptions::new()
+            .with_subtitles(vec!["zh-Hans".to_string(), "en".to_string()], true)
+            .with_danmaku(true)
+            .with_embed(true, false, true);
+        assert_eq!(
+            extra_content_args(&options),
+            [
+                "--write-subs",
+                "--write-auto-subs",
+                "--sub-langs",
+                "zh-Hans,en,danmaku",
+                "--embed-metadata",
+                "--embed-chapters",
+            ]
+        );
+
+        // 下载全部字幕时不包含弹幕
+        let options = VideoOptions::new().with_subtitles(vec!["all".to_string()], false);
+        assert_eq!(
+            extra_content_args(&options),
+            ["--write-subs", "--sub-langs", "all,-danmaku"]
+        );
+    }
+
+    #[test]
+    fn test_format_args() {
+        let args = format_args(None, &FormatPreference::default());
+        assert_eq!(
+            args,
+            [
+                "-f",
+                "bv*+ba/b",
+                "-S",
+                "vcodec:h264,hdr:sdr,res,fps,acodec:m4a",
+                "--merge-output-format",
+                "mp4"
+            ]
+        );
+
+        let pref = FormatPreference::new()
+            .with_max_height(1080)
+            .with_max_fps(30)
+            .with_video_codec(Some(VideoCodec::Av1))
+            .with_container(VideoContainer::Mkv)
+            .with_hdr(true)
+            .with_audio_language("ja");
+        let args = format_args(None, &pref);
+        assert_eq!(
+            args[1],
+            "bv*[height<=?1080][fps<=?30]+(ba[language^=ja]/ba)\
+             /b[height<=?1080][fps<=?30]/bv*+(ba[language^=ja]/ba)/b"
+        );
+        assert_eq!(args[3], "vcodec:av01,hdr,res:1080,fps");
+        assert_eq!(args[5], "mkv");
+
+        // 指定格式时纯视频格式合并音频，完整格式和纯音频格式直接下载
+        let args = format_args(Some("137"), &FormatPreference::default());
+        assert_eq!(args[1], "bv[format_id=137]+ba/b[format_id=137]/137");
+    }
+
+    #[test]
+    fn test_audio_extraction() {
+        let options = VideoOptions::default();
+        let audio = audio_extraction(Some("audio:opus"), &options).unwrap();
+        assert_eq!(audio.format, AudioFormat::Opus);
+        assert!(audio_extraction(Some("137"), &options).is_none());
+        assert!(audio_extraction(Some("audio:wav"), &options).is_none());
+
+        let audio = AudioExtraction::new(AudioFormat::Mp3)
+            .with_bitrate(192)
+            .with_normalize(true);
+        let options = VideoOptions::new().with_audio(audio.clone());
+        assert_eq!(audio_extraction(Some("137"), &options), Some(audio.clone()));
+
+        let args = audio_args(&audio);
+        assert_eq!(
+            args[..7],
+            [
+                "-f",
+                "bestaudio/best",
+                "--extract-audio",
+                "--audio-format",
+                "mp3",
+                "--audio-quality",
+                "192K"
+            ]
+        );
+        assert!(args.iter().any(|a| a.starts_with("ExtractAudio:-af loudnorm")));
+
+        // 无损格式忽略码率
+        let flac = AudioExtraction::new(AudioFormat::Flac).with_bitrate(320);
+        assert_eq!(audio_args(&flac).last().unwrap(), "0");
+
+        let ids: Vec<_> = audio_choices().map(|f| f.format_id).collect();
+        assert_eq!(ids, ["audio:mp3", "audio:m4a", "audio:opus", "audio:flac"]);
+
+        let line = r#"[nebula-pp] {"status": "started", "postprocessor": "ExtractAudio"}"#;
+        let mut tracker = ProgressTracker::default();
+        assert_eq!(tracker.feed(line), (false, Some(VideoStage::ExtractingAudio)));
+    }
+
+    #[test]
+    fn test_output_name() {
+        let video = VideoInfo {
+            id: "BV1xx411c7mD".to_string(),
+            title: "【4K】 标题: 第一集 / 测试?".to_string(),
+            description: None,
+            thumbnail: None,
+            duration: None,
+            uploader: Some("某UP主".to_string()),
+            formats: vec![],
+            webpage_url: String::new(),
+            upload_date: Some("20240105".to_string()),
+            resolution: Some("1920x1080".to_string()),
+            playlist_index: None,
+        };
+        let url = "https://www.bilibili.com/video/BV1xx411c7mD?p=2";
+
+        assert_eq!(
+            video.output_name("{title} [{id}]", None, url),
+            "【4K】 标题_ 第一集 _ 测试 [BV1xx411c7mD]"
+        );
+        assert_eq!(
+            video.output_name("{date} {uploader} - P{index} {title} ({resolution})", None, url),
+            "2024-01-05 某UP主 - P2 【4K】 标题_ 第一集 _ 测试 (1920x1080)"
+        );
+        let no_uploader = VideoInfo {
+            uploader: None,
+            ..video.clone()
+        };
+        assert_eq!(
+            no_uploader.output_name("{title} [{uploader}]", None, ""),
+            video.output_name("{title}", None, "")
+        );
+        assert_eq!(video.output_name("{uploader}/../", None, ""), "某UP主");
+        assert_eq!(video.output_name("...", None, ""), "BV1xx411c7mD");
+
+        let long = sanitize_filename(&"视".repeat(100));
+        assert!(long.len() <= MAX_FILE_NAME_BYTES);
+        assert!(long.chars().all(|c| c == '视'));
+    }
+
+    #[test]
+    fn test_playlist_items() {
+        assert_eq!(parse_playlist_items("1-3,5", 10).unwrap(), vec![0, 1, 2, 4]);
+        assert_eq!(parse_playlist_items("8-, 2", 10).unwrap(), vec![1, 7, 8, 9]);
+        assert_eq!(parse_playlist_items("3,3,1-2", 10).unwrap(), vec![0, 1, 2]);
+        assert_eq!(parse_playlist_items("9-20", 10).unwrap(), vec![8, 9]);
+        assert!(parse_playlist_items("0", 10).is_err());
+        assert!(parse_playlist_items("5-2", 10).is_err());
+        assert!(parse_playlist_items("abc", 10).is_err());
+        assert!(parse_playlist_items("11-", 10).is_err());
+
+        let json = r#"{"id": "PL1", "title": "合集", "entries": [
+            {"id": "a", "title": "第一集", "url": "https://www.youtube.com/watch?v=a", "duration": 61.5},
+            {"id": "b", "title": "私享视频", "url": null},
+            {"id": "c", "title": "第三集", "url": "https://www.youtube.com/watch?v=c"}
+        ]}"#;
+        let info = playlist_info(serde_json::from_str(json).unwrap(), "https://example.com/list");
+        assert_eq!(info.title, "合集");
+        assert_eq!(info.webpage_url, "https://example.com/list");
+        let indexes: Vec<_> = info.entries.iter().map(|e| e.index).collect();
+        assert_eq!(indexes, vec![1, 3]);
+        assert_eq!(info.entries[0].duration, Some(61));
     }
 }
@@ -0,0 +1,211 @@
+//! BitTorrent Web Seed (BEP 19) 支持
+//!
+//! 当 Peer 不足或下载过慢时，通过 HTTP 从 Web Seed 获取尚未下载的分块，
+//! 直接写入种子的保存目录，随后由引擎重新校验数据。
+
+use super::http::HttpHandler;
+use crate::error::{NebulaError, Result};
+use crate::metainfo::{is_relative_path, TorrentFile, TorrentMetainfo};
+
+use std::ops::Range;
+use std::path::Path;
+use tracing::{debug, warn};
+
+/// 合并相邻分块后单个请求的最大长度（字节）
+const MAX_RANGE_LEN: u64 = 16 * 1024 * 1024;
+
+/// 种子中某个文件内的字节范围
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileRange {
+    /// 文件序号
+    pub file: usize,
+    /// 文件内的字节范围
+    pub range: Range<u64>,
+}
+
+/// 计算文件在 Web Seed 上的地址 (BEP 19)
+///
+/// - 单文件种子：地址以 `/` 结尾时追加种子名称，否则地址即为文件地址
+/// - 多文件种子：地址 + 种子名称 + 文件相对路径
+pub fn file_url(seed: &str, meta: &TorrentMetainfo, file: &TorrentFile) -> String {
+    if !meta.multi_file {
+        return if seed.ends_with('/') {
+            format!("{}{}", seed, encode_segment(&meta.name))
+        } else {
+            seed.to_string()
+        };
+    }
+
+    let mut url = seed.to_string();
+    if !url.ends_with('/') {
+        url.push('/');
+    }
+    url.push_str(&encode_segment(&meta.name));
+    for component in file.path.split('/') {
+        url.push('/');
+        url.push_str(&encode_segment(component));
+    }
+    url
+}
+
+/// 计算 `selected` 文件中尚未下载的分块对应的文件范围
+///
+/// `have[i]` 表示第 i 个分块已由引擎校验。同一文件中相邻的范围会合并，
+/// 合并后不超过 [`MAX_RANGE_LEN`]
+pub fn missing_ranges(meta: &TorrentMetainfo, have: &[bool], selected: &[usize]) -> Vec<FileRange> {
+    let mut wanted = vec![false; meta.files.len()];
+    for &index in selected {
+        if let Some(w) = wanted.get_mut(index) {
+            *w = true;
+        }
+    }
+
+    let mut ranges: Vec<FileRange> = Vec::new();
+    for piece in 0..meta.piece_count() {
+        if have.get(piece as usize).copied().unwrap_or(false) {
+            continue;
+        }
+        let start = piece * meta.piece_length;
+        let end = (start + meta.piece_length).min(meta.total_size);
+        // 文件按偏移排列，从第一个与分块重叠的文件开始
+        let first = meta
+            .files
+            .partition_point(|f| f.offset + f.length <= start);
+        for file in meta.files[first..].iter().take_while(|f| f.offset < end) {
+            let lo = start.max(file.offset);
+            let hi = end.min(file.offset + file.length);
+            if !wanted[file.index] || lo >= hi {
+                continue;
+            }
+            let range = lo - file.offset..hi - file.offset;
+            match ranges.last_mut() {
+                Some(last)
+                    if last.file == file.index
+                        && last.range.end == range.start
+                        && range.end - last.range.start <= MAX_RANGE_LEN =>
+                {
+                    last.range.end = range.end;
+                }
+                _ => ranges.push(FileRange {
+                    file: file.index,
+                    range,
+                }),
+            }
+        }
+    }
+    ranges
+}
+
+/// 从 Web Seed 获取文件范围，写入 `save_path` 下对应文件的相同位置
+///
+/// 依次尝试各个 Web Seed，全部失败时返回最后一个错误。
+///
+/// # 返回
+/// 下载的字节数
+pub async fn fetch_range(
+    http: &HttpHandler,
+    seeds: &[String],
+    meta: &TorrentMetainfo,
+    save_path: &Path,
+    range: &FileRange,
+) -> Result<u64> {
+    let file = meta
+        .files
+        .get(range.file)
+        .ok_or_else(|| NebulaError::Internal(format!("文件序号超出范围: {}", range.file)))?;
+    // 元数据可能来自缓存而非解析结果，写入前再检查一次
+    if !is_relative_path(&file.path) {
+        return Err(NebulaError::TorrentParseError(format!(
+            "无效的文件路径: {}",
+            file.path
+        )));
+    }
+    let path = save_path.join(&file.path);
+
+    let mut last_error = None;
+    for seed in seeds {
+        let url = file_url(seed, meta, file);
+        match http
+            .fetch_range(&url, range.range.clone(), &path, range.range.start)
+            .await
+        {
+            Ok(bytes) => {
+                debug!("已从 Web Seed 获取: {} {:?}", file.path, range.range);
+                return Ok(bytes);
+            }
+            Err(e) => {
+                warn!("Web Seed 下载失败: {} - {}", url, e);
+                last_error = Some(e);
+            }
+        }
+    }
+    Err(last_error.unwrap_or_else(|| NebulaError::Internal("没有可用的 Web Seed".to_string())))
+}
+
+/// 对 URL 路径片段进行百分号编码
+fn encode_segment(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_url() {
+        let multi = TorrentMetainfo::from_bytes(
+            b"d8:url-list12:http://seed/4:infod5:filesld6:lengthi3e4:pathl1:x7:a b.txteee4:name3:dir12:piece lengthi16384e6:pieces20:aaaaaaaaaaaaaaaaaaaaee",
+        )
+        .unwrap();
+        assert_eq!(
+            file_url("http://seed/", &multi, &multi.files[0]),
+            "http://seed/dir/x/a%20b.txt"
+        );
+        assert_eq!(
+            file_url("http://seed", &multi, &multi.files[0]),
+            "http://seed/dir/x/a%20b.txt"
+        );
+
+        let single = TorrentMetainfo::from_bytes(
+            b"d4:infod6:lengthi5e4:name7:iso.img12:piece lengthi16384e6:pieces20:aaaaaaaaaaaaaaaaaaaaee",
+        )
+        .unwrap();
+        assert_eq!(
+            file_url("http://mirror/pub/", &single, &single.files[0]),
+            "http://mirror/pub/iso.img"
+        );
+        assert_eq!(
+            file_url("http://mirror/pub/x.img", &single, &single.files[0]),
+            "http://mirror/pub/x.img"
+        );
+    }
+
+    #[test]
+    fn test_missing_ranges() {
+        // 分块 4 字节，文件 a: 0..6，b: 6..8（b 未选择），c: 8..18
+        let meta = TorrentMetainfo::from_bytes(
+            b"d5:filesld6:lengthi6e4:pathl1:aeed6:lengthi2e4:pathl1:beed6:lengthi10e4:pathl1:ceee4:name3:dir12:piece lengthi4e6:pieces100:aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaae",
+        )
+        .unwrap();
+        let have = [true, false, false, true, false];
+        let ranges = missing_ranges(&meta, &have, &[0, 2]);
+        assert_eq!(
+            ranges,
+            vec![
+                FileRange { file: 0, range: 4..6 },
+                FileRange { file: 2, range: 0..4 },
+                FileRange { file: 2, range: 8..10 },
+            ]
+        );
+
+        assert!(missing_ranges(&meta, &[true; 5], &[0, 1, 2]).is_empty());
+    }
+}
@@ -2,10 +2,14 @@
 //!
 //! 定义下载任务的核心数据结构，包括任务 ID、状态、来源类型等。
 
-use crate::event::Progress;
+use crate::error::{NebulaError, Result};
+use crate::event::{DownloadEvent, Progress, StallPhase};
+use crate::extractor::ExtractorRegistry;
+use crate::magnet::MagnetLink;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use tracing::debug;
 use uuid::Uuid;
 
 /// 任务唯一标识符
@@ -21,7 +25,7 @@ impl TaskId {
     }
 
     /// 从字符串解析任务 ID
-    pub fn from_string(s: &str) -> Result<Self, uuid::Error> {
+    pub fn from_string(s: &str) -> std::result::Result<Self, uuid::Error> {
         Ok(Self(Uuid::parse_str(s)?))
     }
 
@@ -85,6 +89,17 @@ pub enum DownloadSource {
         url: String,
         /// 选择的画质 ID
         format_id: Option<String>,
+        /// 字幕、弹幕、封面等附加内容
+        #[serde(default)]
+        options: VideoOptions,
+    },
+
+    /// 视频播放列表、频道或多 P 视频（父任务，每个条目是一个子任务）
+    Playlist {
+        /// 播放列表页面 URL
+        url: String,
+        /// 子任务使用的画质 ID
+        format_id: Option<String>,
     },
 }
 
@@ -96,29 +111,32 @@ impl DownloadSource {
     /// - `magnet:?` -> Magnet
     /// - `ftp://` -> FTP
     /// - 其他（假设为本地文件路径）-> Torrent
+    ///
+    /// 视频网站按内置站点规则识别，使用自定义规则时调用 [`detect_with`](Self::detect_with)
     pub fn detect(source: &str) -> Self {
+        Self::detect_with(source, ExtractorRegistry::builtin())
+    }
+
+    /// 从 URL 字符串识别来源类型，按指定的站点规则识别视频网站
+    pub fn detect_with(source: &str, extractors: &ExtractorRegistry) -> Self {
         let source_lower = source.to_lowercase();
 
         if source_lower.starts_with("magnet:?") {
-            // 解析磁力链接中的 dn (display name) 参数
-            let display_name = source
-                .split('&')
-                .find(|s| s.starts_with("dn=") || s.starts_with("&dn="))
-                .map(|s| {
-                    s.trim_start_matches("dn=")
-                        .trim_start_matches("&dn=")
-                        .to_string()
-                });
+            // 解析磁力链接中的 dn (display name) 参数，链接是否有效由添加任务时检查
+            let display_name = MagnetLink::parse(source)
+                .ok()
+                .and_then(|magnet| magnet.display_name);
 
             Self::Magnet {
                 uri: source.to_string(),
                 display_name,
             }
-        } else if Self::is_video_url(source) {
+        } else if extractors.is_video_url(source) {
             // 视频网站检测必须在普通 HTTP 之前！
             Self::Video {
                 url: source.to_string(),
                 format_id: None,
+                options: VideoOptions::default(),
             }
         } else if source_lower.starts_with("http://") || source_lower.starts_with("https://") {
             Self::Http {
@@ -136,28 +154,6 @@ impl DownloadSource {
         }
     }
 
-    /// 检查 URL 是否为支持的视频网站
-    fn is_video_url(url: &str) -> bool {
-        let video_domains = [
-            "youtube.com",
-            "youtu.be",
-            "bilibili.com",
-            "b23.tv",
-            "twitter.com",
-            "x.com",
-            "tiktok.com",
-            "douyin.com",
-            "vimeo.com",
-            "dailymotion.com",
-            "twitch.tv",
-            "instagram.com",
-            "facebook.com",
-            "nicovideo.jp",
-        ];
-
-        video_domains.iter().any(|domain| url.contains(domain))
-    }
-
     /// 获取来源的显示名称
     pub fn display_name(&self) -> String {
         match self {
@@ -182,15 +178,13 @@ impl DownloadSource {
                 .to_string(),
             Self::Ftp { url } => url.rsplit('/').next().unwrap_or("FTP 文件").to_string(),
             Self::Video { url, .. } => {
-                // 从视频 URL 提取标识
-                if url.contains("bilibili.com") || url.contains("b23.tv") {
-                    "Bilibili 视频".to_string()
-                } else if url.contains("youtube.com") || url.contains("youtu.be") {
-                    "YouTube 视频".to_string()
-                } else {
-                    "视频下载".to_string()
+                // 从视频 URL 提取站点名称
+                match ExtractorRegistry::builtin().match_url(url) {
+                    Some(site) => format!("{} 视频", site.name),
+                    None => "视频下载".to_string(),
                 }
             }
+            Self::Playlist { .. } => "播放列表".to_string(),
         }
     }
 
@@ -202,6 +196,7 @@ impl DownloadSource {
             Self::Torrent { .. } => "BitTorrent",
             Self::Ftp { .. } => "FTP",
             Self::Video { .. } => "Video",
+            Self::Playlist { .. } => "Playlist",
         }
     }
 }
@@ -215,6 +210,12 @@ pub enum TaskStatus {
     /// 正在获取元数据（仅磁力链接）
     FetchingMetadata,
 
+    /// 正在校验已有数据（仅 BitTorrent）
+    Checking,
+
+    /// 长时间没有进展，正在重新寻找 Peer（仅 BitTorrent）
+    Stalled,
+
     /// 下载中
     Downloading,
 
@@ -244,7 +245,11 @@ impl TaskStatus {
     pub fn is_active(&self) -> bool {
         matches!(
             self,
-            TaskStatus::Downloading | TaskStatus::FetchingMetadata | TaskStatus::Seeding
+            TaskStatus::Downloading
+                | TaskStatus::FetchingMetadata
+                | TaskStatus::Checking
+                | TaskStatus::Stalled
+                | TaskStatus::Seeding
         )
     }
 
@@ -252,7 +257,10 @@ impl TaskStatus {
     pub fn can_pause(&self) -> bool {
         matches!(
             self,
-            TaskStatus::Downloading | TaskStatus::FetchingMetadata | TaskStatus::Pending
+            TaskStatus::Downloading
+                | TaskStatus::FetchingMetadata
+                | TaskStatus::Stalled
+                | TaskStatus::Pending
         )
     }
 
@@ -269,11 +277,72 @@ impl TaskStatus {
         )
     }
 
+    /// 检查能否从当前状态转移到 `to`
+    ///
+    /// 同一状态之间总是允许（失败信息可以更新）。已完成或失败的任务
+    /// 不能直接回到下载状态，只能通过 [`DownloadTask::restart`] 重新开始；
+    /// 已取消是终止状态。
+    pub fn can_transition_to(&self, to: &TaskStatus) -> bool {
+        use TaskStatus::*;
+
+        if std::mem::discriminant(self) == std::mem::discriminant(to) {
+            return !matches!(self, Cancelled);
+        }
+        match self {
+            Pending => matches!(
+                to,
+                FetchingMetadata
+                    | Checking
+                    | Downloading
+                    | Paused
+                    | Completed
+                    | Failed { .. }
+                    | Cancelled
+            ),
+            FetchingMetadata => matches!(
+                to,
+                Checking | Downloading | Stalled | Paused | Completed | Failed { .. } | Cancelled
+            ),
+            Checking => matches!(
+                to,
+                FetchingMetadata
+                    | Downloading
+                    | Stalled
+                    | Paused
+                    | Completed
+                    | Seeding
+                    | Failed { .. }
+                    | Cancelled
+            ),
+            Stalled => matches!(
+                to,
+                FetchingMetadata | Downloading | Paused | Completed | Failed { .. } | Cancelled
+            ),
+            Downloading => matches!(
+                to,
+                Checking | Stalled | Paused | Completed | Seeding | Failed { .. } | Cancelled
+            ),
+            Paused => matches!(
+                to,
+                FetchingMetadata | Checking | Downloading | Failed { .. } | Cancelled
+            ),
+            Completed => matches!(to, Checking | Seeding | Cancelled),
+            Seeding => matches!(
+                to,
+                Checking | Completed | Paused | Failed { .. } | Cancelled
+            ),
+            Failed { .. } => matches!(to, Cancelled),
+            Cancelled => false,
+        }
+    }
+
     /// 获取状态的中文描述
     pub fn description(&self) -> String {
         match self {
             TaskStatus::Pending => "等待中".to_string(),
             TaskStatus::FetchingMetadata => "获取元数据".to_string(),
+            TaskStatus::Checking => "校验中".to_string(),
+            TaskStatus::Stalled => "等待 Peer".to_string(),
             TaskStatus::Downloading => "下载中".to_string(),
             TaskStatus::Paused => "已暂停".to_string(),
             TaskStatus::Completed => "已完成".to_string(),
@@ -292,6 +361,450 @@ impl std::fmt::Display for TaskStatus {
     }
 }
 
+/// 重复任务处理策略
+///
+/// 添加任务时，规范化后的 URL、种子 info hash 或视频 ID 相同视为重复任务。
+/// HTTP 任务的实际文件名要到开始下载后才能确定，此时若已有任务在写入
+/// 同一文件，后开始的任务会以 `NebulaError::TaskAlreadyExists` 失败
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DuplicatePolicy {
+    /// 拒绝添加，返回 `NebulaError::TaskAlreadyExists`
+    #[default]
+    Reject,
+    /// 不创建新任务，直接返回已有任务的 ID
+    ReturnExisting,
+    /// 取消已有任务（保留已下载的文件），重新开始下载
+    Restart,
+}
+
+/// 视频任务的附加内容
+///
+/// 附加文件与视频同名保存，如 `标题.zh-Hans.srt`、`标题.danmaku.ass`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VideoOptions {
+    /// 下载字幕的语言（如 `zh-Hans`、`en`，`all` 表示全部），为空时不下载字幕
+    pub subtitle_langs: Vec<String>,
+
+    /// 没有人工字幕时下载自动生成的字幕
+    pub auto_subtitles: bool,
+
+    /// 下载 Bilibili 弹幕并转换为 ASS 字幕
+    pub danmaku: bool,
+
+    /// 下载封面图
+    pub write_thumbnail: bool,
+
+    /// 保存视频信息 `.info.json`
+    pub write_info_json: bool,
+
+    /// 把标题、作者等元数据嵌入视频文件
+    pub embed_metadata: bool,
+
+    /// 把封面嵌入视频文件
+    pub embed_thumbnail: bool,
+
+    /// 把章节嵌入视频文件
+    pub embed_chapters: bool,
+
+    /// 只提取音频（None 时按 `format_id` 判断，见 [`AudioExtraction::from_format_id`]）
+    pub audio: Option<AudioExtraction>,
+
+    /// 未指定 `format_id` 时的画质偏好
+    pub format: FormatPreference,
+}
+
+impl VideoOptions {
+    /// 创建默认选项（不下载任何附加内容）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 下载指定语言的字幕，`auto` 表示没有人工字幕时使用自动生成的字幕
+    pub fn with_subtitles(mut self, langs: Vec<String>, auto: bool) -> Self {
+        self.subtitle_langs = langs;
+        self.auto_subtitles = auto;
+        self
+    }
+
+    /// 下载弹幕
+    pub fn with_danmaku(mut self, danmaku: bool) -> Self {
+        self.danmaku = danmaku;
+        self
+    }
+
+    /// 下载封面图
+    pub fn with_thumbnail(mut self, write_thumbnail: bool) -> Self {
+        self.write_thumbnail = write_thumbnail;
+        self
+    }
+
+    /// 保存 `.info.json`
+    pub fn with_info_json(mut self, write_info_json: bool) -> Self {
+        self.write_info_json = write_info_json;
+        self
+    }
+
+    /// 嵌入元数据、封面和章节
+    pub fn with_embed(mut self, metadata: bool, thumbnail: bool, chapters: bool) -> Self {
+        self.embed_metadata = metadata;
+        self.embed_thumbnail = thumbnail;
+        self.embed_chapters = chapters;
+        self
+    }
+
+    /// 只提取音频
+    pub fn with_audio(mut self, audio: AudioExtraction) -> Self {
+        self.audio = Some(audio);
+        self
+    }
+
+    /// 指定画质偏好
+    pub fn with_format(mut self, format: FormatPreference) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+/// 视频编码
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VideoCodec {
+    H264,
+    H265,
+    Vp9,
+    Av1,
+}
+
+impl VideoCodec {
+    /// 编码名称（与 yt-dlp 排序字段的取值一致）
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::H264 => "h264",
+            Self::H265 => "h265",
+            Self::Vp9 => "vp9",
+            Self::Av1 => "av01",
+        }
+    }
+
+    /// 按名称解析，支持常见别名（如 `avc`、`hevc`、`av1`）
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "h264" | "avc" | "avc1" => Some(Self::H264),
+            "h265" | "hevc" | "hvc1" => Some(Self::H265),
+            "vp9" | "vp09" => Some(Self::Vp9),
+            "av1" | "av01" => Some(Self::Av1),
+            _ => None,
+        }
+    }
+}
+
+/// 视频容器格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VideoContainer {
+    Mp4,
+    Mkv,
+    Webm,
+}
+
+impl VideoContainer {
+    /// 文件扩展名
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Mp4 => "mp4",
+            Self::Mkv => "mkv",
+            Self::Webm => "webm",
+        }
+    }
+
+    /// 按扩展名解析
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext.to_ascii_lowercase().as_str() {
+            "mp4" => Some(Self::Mp4),
+            "mkv" => Some(Self::Mkv),
+            "webm" => Some(Self::Webm),
+            _ => None,
+        }
+    }
+}
+
+/// 画质偏好
+///
+/// 条件都是上限或偏好：没有满足条件的格式时回退到最佳可用格式，而不是下载失败
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FormatPreference {
+    /// 最大高度（如 1080），None 表示不限制
+    pub max_height: Option<u32>,
+
+    /// 优先的视频编码
+    pub video_codec: Option<VideoCodec>,
+
+    /// 输出容器
+    pub container: VideoContainer,
+
+    /// 优先选择 HDR（否则优先 SDR，兼容性更好）
+    pub prefer_hdr: bool,
+
+    /// 最大帧率，None 表示不限制
+    pub max_fps: Option<u32>,
+
+    /// 音轨语言（如 `ja`、`zh`，按前缀匹配）
+    pub audio_language: Option<String>,
+
+    /// 单个流的最大文件大小（字节），大小未知的格式不受限制
+    pub max_filesize: Option<u64>,
+}
+
+impl Default for FormatPreference {
+    fn default() -> Self {
+        Self {
+            max_height: None,
+            video_codec: Some(VideoCodec::H264), // 兼容性最好
+            container: VideoContainer::Mp4,
+            prefer_hdr: false,
+            max_fps: None,
+            audio_language: None,
+            max_filesize: None,
+        }
+    }
+}
+
+impl FormatPreference {
+    /// 创建默认偏好
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 限制最大高度
+    pub fn with_max_height(mut self, height: u32) -> Self {
+        self.max_height = Some(height);
+        self
+    }
+
+    /// 指定优先的视频编码
+    pub fn with_video_codec(mut self, codec: Option<VideoCodec>) -> Self {
+        self.video_codec = codec;
+        self
+    }
+
+    /// 指定输出容器
+    pub fn with_container(mut self, container: VideoContainer) -> Self {
+        self.container = container;
+        self
+    }
+
+    /// 优先选择 HDR
+    pub fn with_hdr(mut self, prefer_hdr: bool) -> Self {
+        self.prefer_hdr = prefer_hdr;
+        self
+    }
+
+    /// 限制最大帧率
+    pub fn with_max_fps(mut self, fps: u32) -> Self {
+        self.max_fps = Some(fps);
+        self
+    }
+
+    /// 指定音轨语言
+    pub fn with_audio_language(mut self, language: impl Into<String>) -> Self {
+        self.audio_language = Some(language.into());
+        self
+    }
+
+    /// 限制单个流的文件大小
+    pub fn with_max_filesize(mut self, bytes: u64) -> Self {
+        self.max_filesize = Some(bytes);
+        self
+    }
+}
+
+/// 音频提取的目标格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AudioFormat {
+    #[default]
+    Mp3,
+    M4a,
+    Opus,
+    Flac,
+}
+
+impl AudioFormat {
+    /// 所有支持的格式
+    pub const ALL: [AudioFormat; 4] = [Self::Mp3, Self::M4a, Self::Opus, Self::Flac];
+
+    /// 文件扩展名，同时也是 yt-dlp `--audio-format` 的取值
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Mp3 => "mp3",
+            Self::M4a => "m4a",
+            Self::Opus => "opus",
+            Self::Flac => "flac",
+        }
+    }
+
+    /// MIME 类型
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Mp3 => "audio/mpeg",
+            Self::M4a => "audio/mp4",
+            Self::Opus => "audio/ogg",
+            Self::Flac => "audio/flac",
+        }
+    }
+
+    /// 是否为无损格式（无损格式不使用码率设置）
+    pub fn is_lossless(&self) -> bool {
+        matches!(self, Self::Flac)
+    }
+
+    /// 按扩展名解析
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|f| f.extension().eq_ignore_ascii_case(ext))
+    }
+}
+
+/// 音频提取设置
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AudioExtraction {
+    /// 目标格式
+    pub format: AudioFormat,
+
+    /// 码率（kbps），None 表示使用最佳质量
+    pub bitrate_kbps: Option<u32>,
+
+    /// 响度标准化（EBU R128）
+    pub normalize: bool,
+}
+
+impl AudioExtraction {
+    /// 画质列表中音频选项的 `format_id` 前缀
+    pub const FORMAT_ID_PREFIX: &'static str = "audio:";
+
+    /// 提取为指定格式
+    pub fn new(format: AudioFormat) -> Self {
+        Self {
+            format,
+            ..Default::default()
+        }
+    }
+
+    /// 指定码率
+    pub fn with_bitrate(mut self, kbps: u32) -> Self {
+        self.bitrate_kbps = Some(kbps);
+        self
+    }
+
+    /// 启用响度标准化
+    pub fn with_normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// 该设置在画质列表中的 `format_id`，如 `audio:mp3`
+    pub fn format_id(&self) -> String {
+        format!("{}{}", Self::FORMAT_ID_PREFIX, self.format.extension())
+    }
+
+    /// 从画质列表中选择的 `format_id` 解析，普通画质返回 None
+    pub fn from_format_id(format_id: &str) -> Option<Self> {
+        format_id
+            .strip_prefix(Self::FORMAT_ID_PREFIX)
+            .and_then(AudioFormat::from_extension)
+            .map(Self::new)
+    }
+}
+
+/// 添加播放列表时的可选参数
+#[derive(Debug, Clone, Default)]
+pub struct PlaylistOptions {
+    /// 要下载的条目，如 `1-3,5,8-`（序号从 1 开始，None 表示全部）
+    pub items: Option<String>,
+
+    /// 子任务使用的画质 ID
+    pub format_id: Option<String>,
+
+    /// 只下载之前没有下载过的条目
+    pub only_new: bool,
+
+    /// 每个条目的附加内容
+    pub video: VideoOptions,
+}
+
+impl PlaylistOptions {
+    /// 创建默认选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 指定要下载的条目
+    pub fn with_items(mut self, items: impl Into<String>) -> Self {
+        self.items = Some(items.into());
+        self
+    }
+
+    /// 指定画质
+    pub fn with_format_id(mut self, format_id: impl Into<String>) -> Self {
+        self.format_id = Some(format_id.into());
+        self
+    }
+
+    /// 只下载新条目
+    pub fn with_only_new(mut self, only_new: bool) -> Self {
+        self.only_new = only_new;
+        self
+    }
+
+    /// 指定每个条目的附加内容
+    pub fn with_video_options(mut self, video: VideoOptions) -> Self {
+        self.video = video;
+        self
+    }
+}
+
+/// 添加任务时的可选参数
+#[derive(Debug, Clone, Default)]
+pub struct AddTaskOptions {
+    /// 已有数据所在目录（仅 BitTorrent）
+    ///
+    /// 设置后将直接使用该目录下的已有文件，由引擎逐块校验，
+    /// 只下载缺失或损坏的部分。多文件种子应指向种子内容所在的目录
+    pub existing_data: Option<PathBuf>,
+
+    /// 只下载指定序号的文件（仅 BitTorrent，序号见 `TorrentMetainfo::files`）
+    pub only_files: Option<Vec<usize>>,
+
+    /// 重复任务处理策略（None 表示使用 `ManagerConfig::duplicate_policy`）
+    pub duplicate_policy: Option<DuplicatePolicy>,
+}
+
+impl AddTaskOptions {
+    /// 创建默认选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 指定已有数据所在目录
+    pub fn with_existing_data(mut self, path: PathBuf) -> Self {
+        self.existing_data = Some(path);
+        self
+    }
+
+    /// 指定只下载的文件序号
+    pub fn with_only_files(mut self, indices: Vec<usize>) -> Self {
+        self.only_files = Some(indices);
+        self
+    }
+
+    /// 指定重复任务处理策略
+    pub fn with_duplicate_policy(mut self, policy: DuplicatePolicy) -> Self {
+        self.duplicate_policy = Some(policy);
+        self
+    }
+}
+
 /// 下载任务结构体
 ///
 /// 代表一个独立的下载任务，包含所有相关信息
@@ -326,6 +839,29 @@ pub struct DownloadTask {
 
     /// 任务优先级 (1-10，数字越大优先级越高)
     pub priority: u8,
+
+    /// BitTorrent 任务的 info hash（十六进制），用于识别重复任务
+    #[serde(default)]
+    pub info_hash: Option<String>,
+
+    /// 缩略图地址（视频任务）
+    #[serde(default)]
+    pub thumbnail: Option<String>,
+    /// BitTorrent 任务只下载的文件序号，None 表示全部下载
+    #[serde(default)]
+    pub only_files: Option<Vec<usize>>,
+
+    /// 所属的父任务（播放列表中的条目）
+    #[serde(default)]
+    pub parent_id: Option<TaskId>,
+
+    /// 子任务（播放列表父任务）
+    #[serde(default)]
+    pub children: Vec<TaskId>,
+
+    /// 处理器报告的最终输出文件路径
+    #[serde(default)]
+    pub output_path: Option<PathBuf>,
 }
 
 impl DownloadTask {
@@ -344,6 +880,12 @@ impl DownloadTask {
             started_at: None,
             completed_at: None,
             priority: 5, // 默认中等优先级
+            info_hash: None,
+            thumbnail: None,
+            only_files: None,
+            parent_id: None,
+            children: Vec::new(),
+            output_path: None,
         }
     }
 
@@ -359,21 +901,319 @@ impl DownloadTask {
         self
     }
 
-    /// 标记任务开始
-    pub fn mark_started(&mut self) {
-        self.status = TaskStatus::Downloading;
-        self.started_at = Some(Utc::now());
+    /// 转移到新状态
+    ///
+    /// 所有状态变化都经过这里校验，状态确实改变时返回
+    /// [`DownloadEvent::StatusChanged`] 事件，由调用方负责发送
+    pub fn transition(&mut self, to: TaskStatus) -> Result<Option<DownloadEvent>> {
+        if !self.status.can_transition_to(&to) {
+            return Err(NebulaError::InvalidTaskState {
+                current: self.status.description(),
+                action: format!("切换为「{}」", to.description()),
+            });
+        }
+        if self.status == to {
+            return Ok(None);
+        }
+
+        match to {
+            TaskStatus::Downloading => {
+                self.started_at.get_or_insert_with(Utc::now);
+            }
+            TaskStatus::Completed => self.completed_at = Some(Utc::now()),
+            _ => {}
+        }
+        let from = std::mem::replace(&mut self.status, to.clone());
+        Ok(Some(DownloadEvent::StatusChanged {
+            task_id: self.id,
+            from,
+            to,
+        }))
+    }
+
+    /// 重新开始已完成或失败的任务，状态回到 [`TaskStatus::Pending`]
+    pub fn restart(&mut self) -> Result<Option<DownloadEvent>> {
+        if !matches!(self.status, TaskStatus::Completed | TaskStatus::Failed { .. }) {
+            return Err(NebulaError::InvalidTaskState {
+                current: self.status.description(),
+                action: "重新开始".to_string(),
+            });
+        }
+
+        let from = std::mem::replace(&mut self.status, TaskStatus::Pending);
+        self.progress = Progress::new(self.progress.total_size, 0);
+        self.completed_at = None;
+        Ok(Some(DownloadEvent::StatusChanged {
+            task_id: self.id,
+            from,
+            to: TaskStatus::Pending,
+        }))
+    }
+
+    /// 标记任务开始（保留首次开始的时间）
+    pub fn mark_started(&mut self) -> Result<Option<DownloadEvent>> {
+        self.transition(TaskStatus::Downloading)
     }
 
     /// 标记任务完成
-    pub fn mark_completed(&mut self) {
-        self.status = TaskStatus::Completed;
-        self.completed_at = Some(Utc::now());
+    pub fn mark_completed(&mut self) -> Result<Option<DownloadEvent>> {
+        self.transition(TaskStatus::Completed)
     }
 
     /// 标记任务失败
-    pub fn mark_failed(&mut self, error: String, retry_count: usize) {
-        self.status = TaskStatus::Failed { error, retry_count };
+    pub fn mark_failed(
+        &mut self,
+        error: String,
+        retry_count: usize,
+    ) -> Result<Option<DownloadEvent>> {
+        self.transition(TaskStatus::Failed { error, retry_count })
+    }
+
+    /// 将处理器发出的事件合并到任务中
+    ///
+    /// 状态变化经过 [`Self::transition`] 校验，无效的转移会被忽略；
+    /// 状态确实改变时返回对应的 [`DownloadEvent::StatusChanged`]
+    pub fn apply_event(&mut self, event: &DownloadEvent) -> Option<DownloadEvent> {
+        match event {
+            DownloadEvent::TaskStarted { .. } => {
+                if self.status == TaskStatus::Checking {
+                    self.started_at.get_or_insert_with(Utc::now);
+                    None
+                } else {
+                    self.try_transition(TaskStatus::Downloading)
+                }
+            }
+            DownloadEvent::ProgressUpdated { progress, .. } => {
+                if self.status.is_finished() {
+                    return None;
+                }
+                let total_size = self.progress.total_size;
+                self.progress = progress.clone();
+                if self.progress.total_size == 0 {
+                    self.progress.total_size = total_size;
+                }
+                if matches!(self.status, TaskStatus::Pending | TaskStatus::FetchingMetadata) {
+                    self.try_transition(TaskStatus::Downloading)
+                } else {
+                    None
+                }
+            }
+            DownloadEvent::TaskPaused { .. } => self.try_transition(TaskStatus::Paused),
+            DownloadEvent::TaskResumed { .. } => {
+                if self.status.can_resume() {
+                    self.try_transition(TaskStatus::Downloading)
+                } else {
+                    None
+                }
+            }
+            DownloadEvent::TaskCompleted { completed_at, .. } => {
+                if !self.status.can_transition_to(&TaskStatus::Completed) {
+                    return None;
+                }
+                let changed = self.try_transition(TaskStatus::Completed);
+                self.completed_at = Some(*completed_at);
+                if self.progress.total_size > 0 {
+                    self.progress.downloaded_size = self.progress.total_size;
+                }
+                self.progress.percentage = 100.0;
+                self.progress.download_speed = 0;
+                self.progress.eta_secs = None;
+                changed
+            }
+            DownloadEvent::TaskFailed { error, .. } => {
+                let retry_count = match &self.status {
+                    TaskStatus::Failed { retry_count, .. } => *retry_count,
+                    _ => 0,
+                };
+                self.try_transition(TaskStatus::Failed {
+                    error: error.clone(),
+                    retry_count,
+                })
+            }
+            DownloadEvent::TaskRemoved { .. } => self.try_transition(TaskStatus::Cancelled),
+            DownloadEvent::MetadataReceived {
+                name,
+                total_size,
+                thumbnail,
+                ..
+            } => {
+                if !name.is_empty() {
+                    self.name = name.clone();
+                }
+                if *total_size > 0 {
+                    self.progress.total_size = *total_size;
+                }
+                if thumbnail.is_some() {
+                    self.thumbnail = thumbnail.clone();
+                }
+                None
+            }
+            DownloadEvent::CheckProgress {
+                checked_bytes,
+                total_bytes,
+                ..
+            } => {
+                let changed = self.try_transition(TaskStatus::Checking);
+                if self.status == TaskStatus::Checking {
+                    self.progress = Progress::new(*total_bytes, *checked_bytes);
+                }
+                changed
+            }
+            DownloadEvent::CheckCompleted {
+                valid_bytes,
+                total_bytes,
+                ..
+            } => {
+                if self.status != TaskStatus::Checking {
+                    return None;
+                }
+                self.progress = Progress::new(*total_bytes, *valid_bytes);
+                if *total_bytes > 0 && valid_bytes >= total_bytes {
+                    self.try_transition(TaskStatus::Completed)
+                } else {
+                    self.try_transition(TaskStatus::Downloading)
+                }
+            }
+            DownloadEvent::TaskStalled { .. } => self.try_transition(TaskStatus::Stalled),
+            DownloadEvent::StallRecovered { phase, .. } => {
+                if self.status != TaskStatus::Stalled {
+                    return None;
+                }
+                self.try_transition(match phase {
+                    StallPhase::Metadata => TaskStatus::FetchingMetadata,
+                    StallPhase::Payload => TaskStatus::Downloading,
+                })
+            }
+            DownloadEvent::OutputFileResolved { path, .. } => {
+                if let Some(name) = path.file_name() {
+                    self.name = name.to_string_lossy().to_string();
+                }
+                self.output_path = Some(path.clone());
+                None
+            }
+            DownloadEvent::TaskAdded { .. }
+            | DownloadEvent::PeerUpdate { .. }
+            | DownloadEvent::VideoStageChanged { .. }
+            | DownloadEvent::StatusChanged { .. }
+            | DownloadEvent::BilibiliLoginExpired { .. } => None,
+        }
+    }
+
+    /// 根据子任务汇总播放列表父任务的进度和状态
+    ///
+    /// 有子任务在下载时为下载中，其余子任务都已暂停时为已暂停，
+    /// 全部结束后按是否有失败的条目决定完成或失败
+    pub fn refresh_from_children(&mut self, children: &[DownloadTask]) -> Option<DownloadEvent> {
+        let mut progress = Progress::new(
+            children.iter().map(|c| c.progress.total_size).sum(),
+            children.iter().map(|c| c.progress.downloaded_size).sum(),
+        );
+        progress.update_speed(children.iter().map(|c| c.progress.download_speed).sum(), 0);
+        self.progress = progress;
+
+        let active = children.iter().any(|c| {
+            matches!(
+                c.status,
+                TaskStatus::FetchingMetadata
+                    | TaskStatus::Checking
+                    | TaskStatus::Stalled
+                    | TaskStatus::Downloading
+            )
+        });
+        let failed = children
+            .iter()
+            .filter(|c| matches!(c.status, TaskStatus::Failed { .. }))
+            .count();
+
+        let to = if active {
+            TaskStatus::Downloading
+        } else if children.iter().any(|c| c.status == TaskStatus::Paused) {
+            TaskStatus::Paused
+        } else if children.iter().any(|c| !c.status.is_finished()) {
+            // 等待下一个条目开始
+            return None;
+        } else if failed > 0 {
+            TaskStatus::Failed {
+                error: format!("{} 个条目下载失败", failed),
+                retry_count: 0,
+            }
+        } else if children.iter().all(|c| c.status == TaskStatus::Cancelled) {
+            TaskStatus::Cancelled
+        } else {
+            TaskStatus::Completed
+        };
+
+        if to == self.status {
+            return None;
+        }
+        self.try_transition(to)
+    }
+
+    /// 尝试转移状态，无效的转移只记录日志
+    fn try_transition(&mut self, to: TaskStatus) -> Option<DownloadEvent> {
+        match self.transition(to) {
+            Ok(changed) => changed,
+            Err(e) => {
+                debug!("忽略事件引起的状态变化 ({}): {}", self.id, e);
+                None
+            }
+        }
+    }
+
+    /// 用于识别重复任务的键
+    ///
+    /// BitTorrent 任务使用 info hash，视频任务使用视频 ID，其他任务使用规范化后的 URL
+    pub fn duplicate_key(&self) -> Option<String> {
+        match &self.source {
+            DownloadSource::Magnet { .. } | DownloadSource::Torrent { .. } => {
+                self.info_hash.as_ref().map(|hash| format!("btih:{}", hash))
+            }
+            DownloadSource::Http { url } | DownloadSource::Ftp { url } => normalize_url(url),
+            DownloadSource::Video { url, .. } => video_id(url).or_else(|| normalize_url(url)),
+            DownloadSource::Playlist { url, .. } => {
+                normalize_url(url).map(|url| format!("playlist:{}", url))
+            }
+        }
+    }
+
+    /// HTTP/FTP 任务的目标文件路径
+    ///
+    /// 只在处理器确定实际文件后（[`DownloadEvent::OutputFileResolved`]）才有值，
+    /// 不根据保存目录和 URL 推测
+    pub fn target_file(&self) -> Option<PathBuf> {
+        match &self.source {
+            DownloadSource::Http { .. } | DownloadSource::Ftp { .. } => self.output_path.clone(),
+            _ => None,
+        }
+    }
+
+    /// 任务在本地的文件或目录路径
+    ///
+    /// 优先使用处理器报告的输出文件；视频任务在此之前只知道保存目录
+    pub fn local_path(&self) -> PathBuf {
+        if let Some(path) = &self.output_path {
+            return path.clone();
+        }
+        match &self.source {
+            DownloadSource::Video { .. } | DownloadSource::Playlist { .. } => {
+                self.save_path.clone()
+            }
+            _ if self.save_path.is_dir() => self.save_path.join(&self.name),
+            _ => self.save_path.clone(),
+        }
+    }
+
+    /// 判断是否与另一任务重复
+    pub fn is_duplicate_of(&self, other: &DownloadTask) -> bool {
+        let same_key = matches!(
+            (self.duplicate_key(), other.duplicate_key()),
+            (Some(a), Some(b)) if a == b
+        );
+        let same_target = matches!(
+            (self.target_file(), other.target_file()),
+            (Some(a), Some(b)) if a == b
+        );
+        same_key || same_target
     }
 
     /// 计算下载耗时（秒）
@@ -385,9 +1225,61 @@ impl DownloadTask {
     }
 }
 
+/// 规范化 URL：协议和主机名小写、去掉默认端口、末尾的 `/` 和片段
+fn normalize_url(url: &str) -> Option<String> {
+    let mut url = url::Url::parse(url.trim()).ok()?;
+    url.set_fragment(None);
+    if url.query() == Some("") {
+        url.set_query(None);
+    }
+    let mut normalized = url.to_string();
+    if url.path().len() > 1 && url.query().is_none() && normalized.ends_with('/') {
+        normalized.pop();
+    }
+    Some(normalized)
+}
+
+/// 提取视频 ID（Bilibili BV/av 号含分 P，YouTube 视频 ID）
+fn video_id(url: &str) -> Option<String> {
+    let parsed = url::Url::parse(url.trim()).ok()?;
+    let host = parsed.host_str()?.to_lowercase();
+    let query = |key: &str| {
+        parsed
+            .query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.into_owned())
+    };
+    let segments: Vec<&str> = parsed
+        .path_segments()
+        .map(|s| s.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+
+    if host.ends_with("bilibili.com") {
+        let id = segments.iter().find(|s| {
+            (s.starts_with("BV") && s.len() == 12)
+                || (s.starts_with("av")
+                    && s.len() > 2
+                    && s[2..].bytes().all(|b| b.is_ascii_digit()))
+        })?;
+        let page = query("p").unwrap_or_else(|| "1".to_string());
+        Some(format!("bilibili:{}:p{}", id, page))
+    } else if host == "youtu.be" {
+        segments.first().map(|id| format!("youtube:{}", id))
+    } else if host.ends_with("youtube.com") {
+        match segments.as_slice() {
+            ["watch", ..] => query("v").map(|id| format!("youtube:{}", id)),
+            ["shorts" | "embed" | "live", id, ..] => Some(format!("youtube:{}", id)),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_source_detection() {
@@ -396,18 +1288,70 @@ mod tests {
         assert!(matches!(source, DownloadSource::Http { .. }));
 
         // Magnet
-        let source = DownloadSource::detect("magnet:?xt=urn:btih:abc123&dn=测试文件");
+        let source = DownloadSource::detect(
+            "magnet:?xt=urn:btih:c12fe1c06bba254a9dc9f519b335aa7c1367a88a&dn=%E6%B5%8B%E8%AF%95%E6%96%87%E4%BB%B6",
+        );
         if let DownloadSource::Magnet { display_name, .. } = source {
-            assert!(display_name.is_some());
+            assert_eq!(display_name.as_deref(), Some("测试文件"));
         } else {
             panic!("应该识别为磁力链接");
         }
 
+        // 视频网站按主机名识别，不受路径和查询参数中的域名影响
+        let source = DownloadSource::detect("https://www.bilibili.com/video/BV1xx411c7mD");
+        assert!(matches!(source, DownloadSource::Video { .. }));
+        assert_eq!(source.display_name(), "Bilibili 视频");
+        let source = DownloadSource::detect("https://example.com/file.zip?ref=youtube.com");
+        assert!(matches!(source, DownloadSource::Http { .. }));
+
         // Torrent file
         let source = DownloadSource::detect("/path/to/file.torrent");
         assert!(matches!(source, DownloadSource::Torrent { .. }));
     }
 
+    #[test]
+    fn test_duplicate_detection() {
+        let task = |source: &str| {
+            DownloadTask::new(
+                DownloadSource::detect(source),
+                PathBuf::from("/nonexistent/a.zip"),
+            )
+        };
+
+        let a = DownloadTask::new(
+            DownloadSource::detect("HTTPS://Example.com:443/file.zip#top"),
+            PathBuf::from("/downloads/a.zip"),
+        );
+        let b = DownloadTask::new(
+            DownloadSource::detect("https://example.com/file.zip"),
+            PathBuf::from("/downloads/b.zip"),
+        );
+        assert!(a.is_duplicate_of(&b));
+
+        // 实际文件确定前只比较 URL
+        let x = task("https://example.com/x");
+        let mut y = task("https://mirror.example/y");
+        assert!(!x.is_duplicate_of(&y));
+
+        // 不同 URL 写入同一文件
+        y.output_path = Some(PathBuf::from("/nonexistent/a.zip"));
+        let mut x_resolved = x.clone();
+        x_resolved.output_path = y.output_path.clone();
+        assert!(!x.is_duplicate_of(&y));
+        assert!(x_resolved.is_duplicate_of(&y));
+
+        let v1 = task("https://www.bilibili.com/video/BV1xx411c7mD?spm_id_from=333&p=2");
+        let v2 = task("https://m.bilibili.com/video/BV1xx411c7mD/?p=2");
+        let v3 = task("https://www.bilibili.com/video/BV1xx411c7mD");
+        assert_eq!(v1.duplicate_key().as_deref(), Some("bilibili:BV1xx411c7mD:p2"));
+        assert!(v1.is_duplicate_of(&v2));
+        assert!(!v1.is_duplicate_of(&v3));
+
+        let y1 = task("https://youtu.be/dQw4w9WgXcQ");
+        let y2 = task("https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42");
+        assert!(y1.is_duplicate_of(&y2));
+    }
+
     #[test]
     fn test_task_status() {
         let status = TaskStatus::Downloading;
@@ -418,6 +1362,14 @@ mod tests {
         let status = TaskStatus::Paused;
         assert!(!status.is_active());
         assert!(status.can_resume());
+
+        let status = TaskStatus::Checking;
+        assert!(status.is_active());
+        assert!(!status.is_finished());
+
+        let status = TaskStatus::Stalled;
+        assert!(status.is_active());
+        assert!(status.can_pause());
     }
 
     #[test]
@@ -429,4 +1381,267 @@ mod tests {
         assert!(matches!(task.status, TaskStatus::Pending));
         assert_eq!(task.priority, 5);
     }
+
+    #[test]
+    fn test_refresh_from_children() {
+        let video = |status: TaskStatus, downloaded: u64| {
+            let source = DownloadSource::Video {
+                url: "https://www.youtube.com/watch?v=a".to_string(),
+                format_id: None,
+                options: VideoOptions::default(),
+            };
+            let mut task = DownloadTask::new(source, PathBuf::from("/downloads"));
+            task.status = status;
+            task.progress = Progress::new(100, downloaded);
+            task
+        };
+        let source = DownloadSource::Playlist {
+            url: "https://www.youtube.com/playlist?list=PL1".to_string(),
+            format_id: None,
+        };
+        let mut parent = DownloadTask::new(source, PathBuf::from("/downloads"));
+
+        let changed = parent.refresh_from_children(&[
+            video(TaskStatus::Completed, 100),
+            video(TaskStatus::Downloading, 30),
+            video(TaskStatus::Pending, 0),
+        ]);
+        assert!(matches!(
+            changed,
+            Some(DownloadEvent::StatusChanged { to: TaskStatus::Downloading, .. })
+        ));
+        assert_eq!(parent.progress.total_size, 300);
+        assert_eq!(parent.progress.downloaded_size, 130);
+
+        // 两个条目之间保持下载中
+        let children = [video(TaskStatus::Completed, 100), video(TaskStatus::Pending, 0)];
+        assert!(parent.refresh_from_children(&children).is_none());
+        assert_eq!(parent.status, TaskStatus::Downloading);
+
+        let children = [video(TaskStatus::Paused, 50), video(TaskStatus::Pending, 0)];
+        parent.refresh_from_children(&children);
+        assert_eq!(parent.status, TaskStatus::Paused);
+
+        let failed = TaskStatus::Failed {
+            error: "网络错误".to_string(),
+            retry_count: 0,
+        };
+        parent.refresh_from_children(&[video(TaskStatus::Completed, 100), video(failed, 0)]);
+        assert!(matches!(parent.status, TaskStatus::Failed { .. }));
+    }
+
+    #[test]
+    fn test_apply_events() {
+        let source = DownloadSource::detect("https://example.com/test.zip");
+        let mut task = DownloadTask::new(source, PathBuf::from("/downloads"));
+        let task_id = task.id;
+
+        task.apply_event(&DownloadEvent::MetadataReceived {
+            task_id,
+            name: "real.zip".to_string(),
+            total_size: 100,
+            file_count: 1,
+            mime_type: None,
+            supports_resume: true,
+            thumbnail: None,
+            duration_secs: None,
+        });
+        assert_eq!(task.name, "real.zip");
+
+        task.apply_event(&DownloadEvent::TaskStarted { task_id });
+        assert_eq!(task.status, TaskStatus::Downloading);
+        let started_at = task.started_at;
+        assert!(started_at.is_some());
+
+        task.apply_event(&DownloadEvent::ProgressUpdated {
+            task_id,
+            progress: Progress::new(0, 40),
+        });
+        assert_eq!(task.progress.total_size, 100);
+        assert_eq!(task.progress.downloaded_size, 40);
+
+        task.apply_event(&DownloadEvent::TaskPaused { task_id });
+        task.apply_event(&DownloadEvent::TaskResumed { task_id });
+        task.apply_event(&DownloadEvent::TaskStarted { task_id });
+        assert_eq!(task.started_at, started_at);
+
+        task.apply_event(&DownloadEvent::TaskCompleted {
+            task_id,
+            completed_at: Utc::now(),
+        });
+        assert_eq!(task.status, TaskStatus::Completed);
+        assert_eq!(task.progress.downloaded_size, 100);
+
+        // 完成后的进度和失败事件不再改变状态
+        task.apply_event(&DownloadEvent::ProgressUpdated {
+            task_id,
+            progress: Progress::new(100, 50),
+        });
+        assert_eq!(task.progress.downloaded_size, 100);
+        task.apply_event(&DownloadEvent::TaskFailed {
+            task_id,
+            error: "late".to_string(),
+        });
+        assert_eq!(task.status, TaskStatus::Completed);
+
+        let path = PathBuf::from("/downloads/real (1).zip");
+        task.apply_event(&DownloadEvent::OutputFileResolved {
+            task_id,
+            path: path.clone(),
+        });
+        assert_eq!(task.name, "real (1).zip");
+        assert_eq!(task.local_path(), path);
+    }
+
+    fn any_status() -> impl Strategy<Value = TaskStatus> {
+        prop_oneof![
+            Just(TaskStatus::Pending),
+            Just(TaskStatus::FetchingMetadata),
+            Just(TaskStatus::Checking),
+            Just(TaskStatus::Stalled),
+            Just(TaskStatus::Downloading),
+            Just(TaskStatus::Paused),
+            Just(TaskStatus::Completed),
+            Just(TaskStatus::Seeding),
+            ("[a-z]{0,4}", 0usize..3)
+                .prop_map(|(error, retry_count)| TaskStatus::Failed { error, retry_count }),
+            Just(TaskStatus::Cancelled),
+        ]
+    }
+
+    fn any_event(task_id: TaskId) -> impl Strategy<Value = DownloadEvent> {
+        prop_oneof![
+            Just(DownloadEvent::TaskStarted { task_id }),
+            (0u64..100, 0u64..100).prop_map(move |(total, done)| DownloadEvent::ProgressUpdated {
+                task_id,
+                progress: Progress::new(total, done),
+            }),
+            Just(DownloadEvent::TaskPaused { task_id }),
+            Just(DownloadEvent::TaskResumed { task_id }),
+            Just(DownloadEvent::TaskCompleted {
+                task_id,
+                completed_at: Utc::now(),
+            }),
+            Just(DownloadEvent::TaskFailed {
+                task_id,
+                error: "err".to_string(),
+            }),
+            Just(DownloadEvent::TaskRemoved { task_id }),
+            (0u64..100).prop_map(move |valid| DownloadEvent::CheckCompleted {
+                task_id,
+                valid_bytes: valid,
+                total_bytes: 50,
+            }),
+            Just(DownloadEvent::TaskStalled {
+                task_id,
+                phase: StallPhase::Payload,
+                diagnostics: Default::default(),
+            }),
+            Just(DownloadEvent::StallRecovered {
+                task_id,
+                phase: StallPhase::Metadata,
+            }),
+        ]
+    }
+
+    fn new_task(status: TaskStatus) -> DownloadTask {
+        let source = DownloadSource::detect("https://example.com/test.zip");
+        let mut task = DownloadTask::new(source, PathBuf::from("/downloads"));
+        task.status = status;
+        task
+    }
+
+    #[test]
+    fn test_transition_table() {
+        use TaskStatus::*;
+
+        assert!(Pending.can_transition_to(&Downloading));
+        assert!(Downloading.can_transition_to(&Paused));
+        assert!(Paused.can_transition_to(&Downloading));
+        assert!(Completed.can_transition_to(&Seeding));
+        assert!(!Completed.can_transition_to(&Downloading));
+        assert!(!Paused.can_transition_to(&Completed));
+        assert!(!Cancelled.can_transition_to(&Cancelled));
+
+        // 已完成的任务只能通过 restart 重新下载
+        let mut task = new_task(Completed);
+        assert!(matches!(
+            task.transition(Downloading),
+            Err(NebulaError::InvalidTaskState { .. })
+        ));
+        assert!(task.restart().unwrap().is_some());
+        assert_eq!(task.status, Pending);
+        assert!(task.completed_at.is_none());
+        assert!(task.transition(Downloading).unwrap().is_some());
+
+        assert!(new_task(Paused).restart().is_err());
+        assert!(new_task(Cancelled).restart().is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_transition_follows_table(from in any_status(), to in any_status()) {
+            let mut task = new_task(from.clone());
+            match task.transition(to.clone()) {
+                Ok(Some(DownloadEvent::StatusChanged { from: f, to: t, .. })) => {
+                    prop_assert!(from.can_transition_to(&to));
+                    prop_assert_ne!(&from, &to);
+                    prop_assert_eq!(f, from);
+                    prop_assert_eq!(&t, &to);
+                    prop_assert_eq!(task.status, to);
+                }
+                Ok(Some(_)) => prop_assert!(false, "只会返回 StatusChanged 事件"),
+                Ok(None) => {
+                    prop_assert_eq!(&from, &to);
+                    prop_assert_eq!(task.status, to);
+                }
+                Err(_) => {
+                    prop_assert!(!from.can_transition_to(&to));
+                    prop_assert_eq!(task.status, from);
+                }
+            }
+        }
+
+        #[test]
+        fn prop_finished_tasks_never_resume_downloading(to in any_status()) {
+            let downloading = matches!(
+                to,
+                TaskStatus::Pending
+                    | TaskStatus::FetchingMetadata
+                    | TaskStatus::Downloading
+                    | TaskStatus::Stalled
+            );
+            if downloading {
+                let failed = TaskStatus::Failed {
+                    error: String::new(),
+                    retry_count: 0,
+                };
+                prop_assert!(!TaskStatus::Completed.can_transition_to(&to));
+                prop_assert!(!failed.can_transition_to(&to));
+            }
+            prop_assert!(!TaskStatus::Cancelled.can_transition_to(&to));
+        }
+
+        #[test]
+        fn prop_events_only_make_valid_transitions(
+            events in proptest::collection::vec(any_event(TaskId::new()), 0..40)
+        ) {
+            let mut task = new_task(TaskStatus::Pending);
+            for event in &events {
+                let before = task.status.clone();
+                match task.apply_event(event) {
+                    Some(DownloadEvent::StatusChanged { from, to, .. }) => {
+                        prop_assert_eq!(&from, &before);
+                        prop_assert!(before.can_transition_to(&to));
+                        prop_assert_eq!(&task.status, &to);
+                    }
+                    Some(_) => prop_assert!(false, "只会返回 StatusChanged 事件"),
+                    None => prop_assert_eq!(&task.status, &before),
+                }
+                if before == TaskStatus::Cancelled {
+                    prop_assert_eq!(&task.status, &TaskStatus::Cancelled);
+                }
+            }
+        }
+    }
 }
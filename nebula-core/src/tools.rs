@@ -0,0 +1,554 @@
+//! 外部工具管理模块
+//!
+//! 视频下载依赖 yt-dlp 和 ffmpeg。本模块负责查找可执行文件、读取版本，
+//! 并从 GitHub Releases 下载经 SHA-256 校验的新版 yt-dlp 到数据目录。
+//!
+//! 查找顺序：配置中指定的路径 → 数据目录中自动更新的版本 → 应用内嵌版本 → 系统安装版本
+
+use crate::config::ToolsConfig;
+use crate::error::{NebulaError, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::fs;
+use tokio::process::Command;
+use tracing::{debug, info, warn};
+
+/// yt-dlp 最新版本查询地址
+const YT_DLP_LATEST_RELEASE_URL: &str =
+    "https://api.github.com/repos/yt-dlp/yt-dlp/releases/latest";
+
+/// yt-dlp 发布的校验文件名
+const YT_DLP_CHECKSUMS_ASSET: &str = "SHA2-256SUMS";
+
+/// 自动更新的工具存放目录（位于数据目录下）
+const MANAGED_DIR: &str = "bin";
+
+/// 查询版本的超时时间
+const VERSION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 下载新版本的超时时间
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// 常见的系统安装目录
+const SYSTEM_DIRS: &[&str] = &["/opt/homebrew/bin", "/usr/local/bin", "/usr/bin"];
+
+/// 外部工具
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Tool {
+    /// 视频解析和下载
+    YtDlp,
+    /// 音视频合并与转码
+    Ffmpeg,
+}
+
+impl Tool {
+    /// 所有外部工具
+    pub const ALL: [Tool; 2] = [Tool::YtDlp, Tool::Ffmpeg];
+
+    /// 工具名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tool::YtDlp => "yt-dlp",
+            Tool::Ffmpeg => "ffmpeg",
+        }
+    }
+
+    /// 当前平台的可执行文件名
+    pub fn executable(&self) -> String {
+        if cfg!(windows) {
+            format!("{}.exe", self.name())
+        } else {
+            self.name().to_string()
+        }
+    }
+
+    /// 输出版本号的命令行参数
+    fn version_arg(&self) -> &'static str {
+        match self {
+            Tool::YtDlp => "--version",
+            Tool::Ffmpeg => "-version",
+        }
+    }
+
+    /// 从版本命令的输出中解析版本号
+    ///
+    /// yt-dlp 输出 `2024.08.06`，ffmpeg 输出 `ffmpeg version 6.1.1 Copyright ...`
+    fn parse_version(&self, output: &str) -> Option<String> {
+        let line = output.lines().next()?.trim();
+        let version = match self {
+            Tool::YtDlp => line,
+            Tool::Ffmpeg => line
+                .strip_prefix("ffmpeg version ")?
+                .split_whitespace()
+                .next()?,
+        };
+        (!version.is_empty()).then(|| version.to_string())
+    }
+}
+
+/// 工具可执行文件的来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ToolSource {
+    /// 配置中指定的路径
+    Configured,
+    /// 数据目录中自动更新的版本
+    Managed,
+    /// 应用内嵌版本
+    Bundled,
+    /// 系统安装版本
+    System,
+}
+
+/// 已找到的工具信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolInfo {
+    /// 工具
+    pub tool: Tool,
+    /// 可执行文件路径
+    pub path: PathBuf,
+    /// 版本号（无法执行时为 None）
+    pub version: Option<String>,
+    /// 来源
+    pub source: ToolSource,
+}
+
+/// yt-dlp 更新检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolUpdate {
+    /// 当前版本（未安装时为 None）
+    pub current: Option<String>,
+    /// 最新版本
+    pub latest: String,
+    /// 是否有可用更新
+    pub update_available: bool,
+    /// 当前平台的下载地址
+    pub download_url: String,
+    /// 校验文件下载地址
+    checksums_url: String,
+}
+
+/// GitHub Release 信息
+#[derive(Debug, Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    #[serde(default)]
+    assets: Vec<GithubAsset>,
+}
+
+/// GitHub Release 附件
+#[derive(Debug, Deserialize)]
+struct GithubAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// 外部工具管理器
+pub struct ToolManager {
+    /// 配置
+    config: ToolsConfig,
+    /// 自动更新的工具存放目录
+    managed_dir: PathBuf,
+}
+
+impl ToolManager {
+    /// 创建新的工具管理器
+    ///
+    /// # 参数
+    /// - `config`: 工具配置
+    /// - `data_dir`: 数据目录，自动更新的工具保存在其中的 `bin` 子目录
+    pub fn new(config: ToolsConfig, data_dir: PathBuf) -> Self {
+        Self {
+            config,
+            managed_dir: data_dir.join(MANAGED_DIR),
+        }
+    }
+
+    /// 自动更新版本的保存路径
+    pub fn managed_path(&self, tool: Tool) -> PathBuf {
+        self.managed_dir.join(tool.executable())
+    }
+
+    /// 查找工具的可执行文件
+    pub fn locate(&self, tool: Tool) -> Option<(PathBuf, ToolSource)> {
+        let configured = match tool {
+            Tool::YtDlp => self.config.yt_dlp_path.as_ref(),
+            Tool::Ffmpeg => self.config.ffmpeg_path.as_ref(),
+        };
+        if let Some(path) = configured {
+            if path.exists() {
+                return Some((path.clone(), ToolSource::Configured));
+            }
+            warn!("配置的 {} 路径不存在: {:?}", tool.name(), path);
+        }
+
+        let managed = self.managed_path(tool);
+        if managed.exists() {
+            return Some((managed, ToolSource::Managed));
+        }
+
+        if let Some(path) = bundled_path(tool) {
+            return Some((path, ToolSource::Bundled));
+        }
+
+        system_path(tool).map(|path| (path, ToolSource::System))
+    }
+
+    /// 查找工具，未找到时返回 [`NebulaError::ToolNotFound`]
+    pub fn require(&self, tool: Tool) -> Result<PathBuf> {
+        self.locate(tool)
+            .map(|(path, _)| path)
+            .ok_or_else(|| NebulaError::ToolNotFound(tool.name().to_string()))
+    }
+
+    /// 查找工具并读取版本
+    pub async fn inspect(&self, tool: Tool) -> Option<ToolInfo> {
+        let (path, source) = self.locate(tool)?;
+        let version = version(tool, &path).await;
+        Some(ToolInfo {
+            tool,
+            path,
+            version,
+            source,
+        })
+    }
+
+    /// 检查 yt-dlp 是否有新版本
+    pub async fn check_yt_dlp_update(&self) -> Result<ToolUpdate> {
+        let asset_name = yt_dlp_asset_name().ok_or_else(|| {
+            NebulaError::ToolUpdateFailed("当前平台没有预编译的 yt-dlp".to_string())
+        })?;
+
+        let release: GithubRelease = http_client(Duration::from_secs(15))?
+            .get(YT_DLP_LATEST_RELEASE_URL)
+            .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+            .send()
+            .await
+            .map_err(|e| NebulaError::NetworkError(e.to_string()))?
+            .error_for_status()
+            .map_err(|e| NebulaError::ToolUpdateFailed(format!("查询 yt-dlp 版本失败: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| NebulaError::ToolUpdateFailed(format!("解析 yt-dlp 版本失败: {}", e)))?;
+
+        let asset_url = |name: &str| {
+            release
+                .assets
+                .iter()
+                .find(|asset| asset.name == name)
+                .map(|asset| asset.browser_download_url.clone())
+                .ok_or_else(|| {
+                    NebulaError::ToolUpdateFailed(format!(
+                        "yt-dlp {} 中缺少 {}",
+                        release.tag_name, name
+                    ))
+                })
+        };
+        let download_url = asset_url(asset_name)?;
+        let checksums_url = asset_url(YT_DLP_CHECKSUMS_ASSET)?;
+
+        let current = match self.locate(Tool::YtDlp) {
+            Some((path, _)) => version(Tool::YtDlp, &path).await,
+            None => None,
+        };
+        let update_available = current
+            .as_deref()
+            .is_none_or(|current| is_newer(&release.tag_name, current));
+
+        Ok(ToolUpdate {
+            current,
+            latest: release.tag_name,
+            update_available,
+            download_url,
+            checksums_url,
+        })
+    }
+
+    /// 下载最新版 yt-dlp 到数据目录
+    ///
+    /// 已是最新版本时不下载，直接返回当前使用的 yt-dlp。
+    /// 下载的文件经过 SHA-256 校验后才会替换旧版本；校验文件来自同一个 Release，
+    /// 只能发现下载损坏，不能证明文件由 yt-dlp 发布（未校验 GPG 签名 `SHA2-256SUMS.sig`）。
+    /// 配置中指定了 yt-dlp 路径时仍优先使用配置的路径
+    pub async fn update_yt_dlp(&self) -> Result<ToolInfo> {
+        let update = self.check_yt_dlp_update().await?;
+        if !update.update_available {
+            if let Some(current) = self.inspect(Tool::YtDlp).await {
+                info!("yt-dlp 已是最新版本: {}", update.latest);
+                return Ok(current);
+            }
+        }
+        let asset_name = yt_dlp_asset_name().unwrap_or_default();
+        info!("更新 yt-dlp: {:?} -> {}", update.current, update.latest);
+
+        let client = http_client(DOWNLOAD_TIMEOUT)?;
+        let checksums = download(&client, &update.checksums_url).await?;
+        let checksums = String::from_utf8_lossy(&checksums);
+        let expected = parse_checksums(&checksums, asset_name).ok_or_else(|| {
+            NebulaError::ToolUpdateFailed(format!("校验文件中缺少 {}", asset_name))
+        })?;
+
+        let bytes = download(&client, &update.download_url).await?;
+        let actual = sha256_hex(&bytes);
+        if !actual.eq_ignore_ascii_case(&expected) {
+            return Err(NebulaError::ToolUpdateFailed(format!(
+                "yt-dlp 校验失败: 期望 {}，实际 {}",
+                expected, actual
+            )));
+        }
+
+        // 先写入临时文件再重命名，避免留下不完整的可执行文件
+        fs::create_dir_all(&self.managed_dir).await?;
+        let path = self.managed_path(Tool::YtDlp);
+        let tmp_path = path.with_extension("download");
+        fs::write(&tmp_path, &bytes).await?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&tmp_path, std::fs::Permissions::from_mode(0o755)).await?;
+        }
+        if let Err(e) = fs::rename(&tmp_path, &path).await {
+            let _ = fs::remove_file(&tmp_path).await;
+            return Err(NebulaError::ToolUpdateFailed(format!(
+                "替换 yt-dlp 失败: {}",
+                e
+            )));
+        }
+
+        info!("yt-dlp 已更新到 {}: {:?}", update.latest, path);
+        Ok(ToolInfo {
+            tool: Tool::YtDlp,
+            path,
+            version: Some(update.latest),
+            source: ToolSource::Managed,
+        })
+    }
+}
+
+/// 执行工具的版本命令并解析版本号
+async fn version(tool: Tool, path: &Path) -> Option<String> {
+    let output = Command::new(path)
+        .arg(tool.version_arg())
+        .stdin(Stdio::null())
+        .output();
+    let output = match tokio::time::timeout(VERSION_TIMEOUT, output).await {
+        Ok(Ok(output)) if output.status.success() => output,
+        Ok(Ok(output)) => {
+            debug!("{} 版本查询失败: {}", tool.name(), output.status);
+            return None;
+        }
+        Ok(Err(e)) => {
+            debug!("执行 {} 失败: {}", tool.name(), e);
+            return None;
+        }
+        Err(_) => {
+            debug!("{} 版本查询超时", tool.name());
+            return None;
+        }
+    };
+    tool.parse_version(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// 查找应用内嵌的工具
+///
+/// macOS: xxx.app/Contents/MacOS/nebula_app -> ../Resources/yt-dlp；
+/// 其他平台：与可执行文件位于同一目录
+fn bundled_path(tool: Tool) -> Option<PathBuf> {
+    let exe_path = std::env::current_exe().ok()?;
+    let exe_dir = exe_path.parent()?;
+    let executable = tool.executable();
+
+    let mut candidates = vec![exe_dir.join(&executable)];
+    if cfg!(target_os = "macos") {
+        if let Some(contents) = exe_dir.parent() {
+            candidates.insert(0, contents.join("Resources").join(&executable));
+        }
+    }
+    candidates.into_iter().find(|p| p.is_file())
+}
+
+/// 查找系统安装的工具
+fn system_path(tool: Tool) -> Option<PathBuf> {
+    let executable = tool.executable();
+    let mut candidates: Vec<PathBuf> = SYSTEM_DIRS
+        .iter()
+        .map(|dir| Path::new(dir).join(&executable))
+        .collect();
+    if cfg!(windows) && tool == Tool::Ffmpeg {
+        candidates.push(PathBuf::from("C:\\Program Files\\ffmpeg\\bin\\ffmpeg.exe"));
+    }
+    if let Some(path) = candidates.into_iter().find(|p| p.is_file()) {
+        return Some(path);
+    }
+
+    // 尝试 which / where 命令，where 可能返回多行
+    let cmd = if cfg!(windows) { "where" } else { "which" };
+    let output = std::process::Command::new(cmd)
+        .arg(tool.name())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let path = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())?;
+    debug!("通过 {} 找到 {}: {}", cmd, tool.name(), path);
+    Some(PathBuf::from(path))
+}
+
+/// 当前平台对应的 yt-dlp 预编译文件名
+fn yt_dlp_asset_name() -> Option<&'static str> {
+    if cfg!(windows) {
+        Some("yt-dlp.exe")
+    } else if cfg!(target_os = "macos") {
+        Some("yt-dlp_macos")
+    } else if cfg!(all(target_os = "linux", target_arch = "x86_64")) {
+        Some("yt-dlp_linux")
+    } else if cfg!(all(target_os = "linux", target_arch = "aarch64")) {
+        Some("yt-dlp_linux_aarch64")
+    } else {
+        None
+    }
+}
+
+/// 创建 HTTP 客户端（GitHub API 要求携带 User-Agent）
+fn http_client(timeout: Duration) -> Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .timeout(timeout)
+        .user_agent(concat!("Nebula/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .map_err(|e| NebulaError::Internal(e.to_string()))
+}
+
+/// 下载文件内容
+async fn download(client: &reqwest::Client, url: &str) -> Result<Vec<u8>> {
+    debug!("下载: {}", url);
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| NebulaError::NetworkError(e.to_string()))?;
+    if !response.status().is_success() {
+        return Err(NebulaError::HttpError {
+            status_code: response.status().as_u16(),
+            message: format!("下载失败: {}", url),
+        });
+    }
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| NebulaError::NetworkError(e.to_string()))?;
+    Ok(bytes.to_vec())
+}
+
+/// 计算 SHA-256 并转为十六进制
+fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// 从 `sha256sum` 格式的校验文件中查找指定文件的哈希
+fn parse_checksums(text: &str, file_name: &str) -> Option<String> {
+    text.lines().find_map(|line| {
+        let (hash, name) = line.trim().split_once(char::is_whitespace)?;
+        // 二进制模式下文件名前带有 `*`
+        let name = name.trim_start().trim_start_matches('*');
+        (name == file_name).then(|| hash.to_lowercase())
+    })
+}
+
+/// 比较点分版本号，`latest` 比 `current` 新时返回 true
+///
+/// yt-dlp 使用日期作为版本号，如 `2024.08.06`，nightly 版本多一段时间戳
+fn is_newer(latest: &str, current: &str) -> bool {
+    let parse = |v: &str| -> Vec<u64> {
+        v.trim()
+            .trim_start_matches('v')
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect()
+    };
+    parse(latest) > parse(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(
+            Tool::YtDlp.parse_version("2024.08.06\n"),
+            Some("2024.08.06".to_string())
+        );
+        assert_eq!(
+            Tool::Ffmpeg.parse_version(
+                "ffmpeg version 6.1.1-3ubuntu5 Copyright (c) 2000-2023 the FFmpeg developers\n"
+            ),
+            Some("6.1.1-3ubuntu5".to_string())
+        );
+        assert_eq!(Tool::Ffmpeg.parse_version("unknown"), None);
+        assert_eq!(Tool::YtDlp.parse_version(""), None);
+    }
+
+    #[test]
+    fn test_checksums_and_versions() {
+        let sums = "\
+0123abcd  yt-dlp
+DEADBEEF *yt-dlp_linux
+9876fedc  yt-dlp_linux_aarch64
+";
+        assert_eq!(
+            parse_checksums(sums, "yt-dlp_linux"),
+            Some("deadbeef".to_string())
+        );
+        assert_eq!(
+            parse_checksums(sums, "yt-dlp"),
+            Some("0123abcd".to_string())
+        );
+        assert_eq!(parse_checksums(sums, "yt-dlp.exe"), None);
+
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        assert!(is_newer("2024.08.06", "2024.07.25"));
+        assert!(is_newer("2024.08.06.232828", "2024.08.06"));
+        assert!(!is_newer("2024.08.06", "2024.08.06"));
+        assert!(!is_newer("2023.12.30", "2024.01.01"));
+    }
+
+    #[test]
+    fn test_locate_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let configured = dir.path().join("custom-yt-dlp");
+        std::fs::write(&configured, b"").unwrap();
+
+        let manager = ToolManager::new(ToolsConfig::default(), dir.path().to_path_buf());
+        let managed = manager.managed_path(Tool::YtDlp);
+        std::fs::create_dir_all(managed.parent().unwrap()).unwrap();
+        std::fs::write(&managed, b"").unwrap();
+        assert_eq!(
+            manager.locate(Tool::YtDlp),
+            Some((managed, ToolSource::Managed))
+        );
+
+        let config = ToolsConfig {
+            yt_dlp_path: Some(configured.clone()),
+            ffmpeg_path: None,
+        };
+        let manager = ToolManager::new(config, dir.path().to_path_buf());
+        assert_eq!(
+            manager.locate(Tool::YtDlp),
+            Some((configured, ToolSource::Configured))
+        );
+    }
+}
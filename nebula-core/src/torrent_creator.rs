@@ -0,0 +1,412 @@
+//! 种子制作模块
+//!
+//! 从本地文件或目录生成 .torrent 文件和磁力链接，支持：
+//! - 自动或手动指定分块大小
+//! - 私有种子标记
+//! - Tracker 列表、Web Seed (BEP 19)、注释
+
+use crate::bencode::Value;
+use crate::error::{NebulaError, Result};
+
+use sha1::{Digest, Sha1};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// 最小分块大小 (16 KiB)
+pub const MIN_PIECE_SIZE: u64 = 16 * 1024;
+
+/// 最大分块大小 (16 MiB)
+pub const MAX_PIECE_SIZE: u64 = 16 * 1024 * 1024;
+
+/// 自动选择分块大小时的目标分块数量
+const TARGET_PIECE_COUNT: u64 = 1500;
+
+/// 种子制作选项
+#[derive(Debug, Clone, Default)]
+pub struct TorrentCreateOptions {
+    /// 分块大小（字节，必须是 2 的幂），None 表示按总大小自动选择
+    pub piece_size: Option<u64>,
+
+    /// 是否为私有种子（禁用 DHT / PEX，仅通过 Tracker 获取 Peer）
+    pub private: bool,
+
+    /// Tracker 列表（每个 Tracker 单独一层）
+    pub trackers: Vec<String>,
+
+    /// Web Seed 地址列表
+    pub web_seeds: Vec<String>,
+
+    /// 注释
+    pub comment: Option<String>,
+
+    /// 种子名称，None 表示使用文件或目录名
+    ///
+    /// 单文件种子指定其他名称后，需要将文件改为该名称才能做种
+    pub name: Option<String>,
+}
+
+/// 制作完成的种子
+#[derive(Debug, Clone)]
+pub struct CreatedTorrent {
+    /// 种子名称
+    pub name: String,
+    /// info hash (v1)
+    pub info_hash: [u8; 20],
+    /// 磁力链接
+    pub magnet: String,
+    /// .torrent 文件内容
+    pub torrent_bytes: Vec<u8>,
+    /// 内容总大小（字节）
+    pub total_size: u64,
+    /// 分块大小（字节）
+    pub piece_size: u64,
+    /// 分块数量
+    pub piece_count: usize,
+    /// 文件数量
+    pub file_count: usize,
+}
+
+impl CreatedTorrent {
+    /// info hash 的十六进制表示
+    pub fn info_hash_hex(&self) -> String {
+        hex(&self.info_hash)
+    }
+
+    /// 写入 .torrent 文件
+    pub async fn write_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(path, &self.torrent_bytes)
+            .await
+            .map_err(|e| NebulaError::IoError {
+                path: path.to_path_buf(),
+                message: e.to_string(),
+            })
+    }
+}
+
+/// 待哈希的文件
+struct SourceFile {
+    /// 磁盘路径
+    path: PathBuf,
+    /// 种子内的相对路径
+    components: Vec<String>,
+    /// 文件大小
+    length: u64,
+}
+
+/// 从本地文件或目录制作种子
+///
+/// 哈希计算在阻塞线程池中进行，不会阻塞异步运行时
+pub async fn create_torrent(input: &Path, options: TorrentCreateOptions) -> Result<CreatedTorrent> {
+    let input = input.to_path_buf();
+    tokio::task::spawn_blocking(move || create_torrent_blocking(&input, &options))
+        .await
+        .map_err(|e| NebulaError::Internal(format!("制作种子失败: {}", e)))?
+}
+
+fn create_torrent_blocking(input: &Path, options: &TorrentCreateOptions) -> Result<CreatedTorrent> {
+    let metadata = std::fs::metadata(input).map_err(|e| io_error(input, e))?;
+
+    let name = match &options.name {
+        Some(name) => name.clone(),
+        None => input
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(|n| n.to_string())
+            .ok_or_else(|| NebulaError::InvalidConfig(format!("无法确定种子名称: {:?}", input)))?,
+    };
+
+    let files = if metadata.is_dir() {
+        let mut files = Vec::new();
+        collect_files(input, &mut Vec::new(), &mut files)?;
+        if files.is_empty() {
+            return Err(NebulaError::InvalidConfig(format!("目录为空: {:?}", input)));
+        }
+        files
+    } else {
+        vec![SourceFile {
+            path: input.to_path_buf(),
+            components: vec![name.clone()],
+            length: metadata.len(),
+        }]
+    };
+
+    let total_size: u64 = files.iter().map(|f| f.length).sum();
+    let piece_size = match options.piece_size {
+        Some(size) => validate_piece_size(size)?,
+        None => auto_piece_size(total_size),
+    };
+    let pieces = hash_pieces(&files, piece_size)?;
+
+    // info 字典
+    let mut info = BTreeMap::new();
+    info.insert(b"name".to_vec(), Value::string(&name));
+    info.insert(b"piece length".to_vec(), Value::Int(piece_size as i64));
+    info.insert(b"pieces".to_vec(), Value::Bytes(pieces.concat()));
+    if options.private {
+        info.insert(b"private".to_vec(), Value::Int(1));
+    }
+    if metadata.is_dir() {
+        let list = files
+            .iter()
+            .map(|f| {
+                let mut entry = BTreeMap::new();
+                entry.insert(b"length".to_vec(), Value::Int(f.length as i64));
+                entry.insert(
+                    b"path".to_vec(),
+                    Value::List(f.components.iter().map(|c| Value::string(c)).collect()),
+                );
+                Value::Dict(entry)
+            })
+            .collect();
+        info.insert(b"files".to_vec(), Value::List(list));
+    } else {
+        info.insert(b"length".to_vec(), Value::Int(total_size as i64));
+    }
+    let info = Value::Dict(info);
+    let info_hash: [u8; 20] = Sha1::digest(info.encode()).into();
+
+    // 顶层字典
+    let mut torrent = BTreeMap::new();
+    if let Some(first) = options.trackers.first() {
+        torrent.insert(b"announce".to_vec(), Value::string(first));
+    }
+    if options.trackers.len() > 1 {
+        let tiers = options
+            .trackers
+            .iter()
+            .map(|t| Value::List(vec![Value::string(t)]))
+            .collect();
+        torrent.insert(b"announce-list".to_vec(), Value::List(tiers));
+    }
+    if !options.web_seeds.is_empty() {
+        let seeds = options.web_seeds.iter().map(|s| Value::string(s)).collect();
+        torrent.insert(b"url-list".to_vec(), Value::List(seeds));
+    }
+    if let Some(comment) = &options.comment {
+        torrent.insert(b"comment".to_vec(), Value::string(comment));
+    }
+    torrent.insert(
+        b"created by".to_vec(),
+        Value::string(&format!("Nebula/{}", env!("CARGO_PKG_VERSION"))),
+    );
+    torrent.insert(
+        b"creation date".to_vec(),
+        Value::Int(chrono::Utc::now().timestamp()),
+    );
+    torrent.insert(b"info".to_vec(), info);
+
+    Ok(CreatedTorrent {
+        magnet: build_magnet(&hex(&info_hash), &name, options),
+        name,
+        info_hash,
+        torrent_bytes: Value::Dict(torrent).encode(),
+        total_size,
+        piece_size,
+        piece_count: pieces.len(),
+        file_count: files.len(),
+    })
+}
+
+/// 递归收集目录下的文件，按路径排序保证结果稳定
+fn collect_files(dir: &Path, prefix: &mut Vec<String>, files: &mut Vec<SourceFile>) -> Result<()> {
+    let mut entries: Vec<_> = std::fs::read_dir(dir)
+        .map_err(|e| io_error(dir, e))?
+        .collect::<std::io::Result<_>>()
+        .map_err(|e| io_error(dir, e))?;
+    entries.sort_by_key(|e| e.file_name());
+
+    for entry in entries {
+        let path = entry.path();
+        let file_type = entry.file_type().map_err(|e| io_error(&path, e))?;
+        // 跳过符号链接，避免循环引用和越出目录
+        if file_type.is_symlink() {
+            continue;
+        }
+        let Some(name) = entry.file_name().to_str().map(|s| s.to_string()) else {
+            return Err(NebulaError::InvalidConfig(format!(
+                "文件名不是有效的 UTF-8: {:?}",
+                path
+            )));
+        };
+
+        prefix.push(name);
+        if file_type.is_dir() {
+            collect_files(&path, prefix, files)?;
+        } else {
+            let length = entry.metadata().map_err(|e| io_error(&path, e))?.len();
+            files.push(SourceFile {
+                path,
+                components: prefix.clone(),
+                length,
+            });
+        }
+        prefix.pop();
+    }
+    Ok(())
+}
+
+/// 计算所有分块的 SHA-1，分块可以跨越文件边界
+fn hash_pieces(files: &[SourceFile], piece_size: u64) -> Result<Vec<[u8; 20]>> {
+    let mut pieces = Vec::new();
+    let mut buffer = vec![0u8; piece_size as usize];
+    let mut filled = 0usize;
+
+    for file in files {
+        let mut reader = File::open(&file.path).map_err(|e| io_error(&file.path, e))?;
+        loop {
+            let n = reader
+                .read(&mut buffer[filled..])
+                .map_err(|e| io_error(&file.path, e))?;
+            if n == 0 {
+                break;
+            }
+            filled += n;
+            if filled == buffer.len() {
+                pieces.push(Sha1::digest(&buffer).into());
+                filled = 0;
+            }
+        }
+    }
+    if filled > 0 {
+        pieces.push(Sha1::digest(&buffer[..filled]).into());
+    }
+    Ok(pieces)
+}
+
+/// 按总大小选择分块大小，使分块数量接近目标值
+pub fn auto_piece_size(total_size: u64) -> u64 {
+    let ideal = (total_size / TARGET_PIECE_COUNT).max(1);
+    ideal
+        .checked_next_power_of_two()
+        .unwrap_or(MAX_PIECE_SIZE)
+        .clamp(MIN_PIECE_SIZE, MAX_PIECE_SIZE)
+}
+
+/// 检查分块大小是否有效
+fn validate_piece_size(size: u64) -> Result<u64> {
+    if size.is_power_of_two() && (MIN_PIECE_SIZE..=MAX_PIECE_SIZE).contains(&size) {
+        Ok(size)
+    } else {
+        Err(NebulaError::InvalidConfig(format!(
+            "分块大小必须是 {} 到 {} 之间的 2 的幂: {}",
+            MIN_PIECE_SIZE, MAX_PIECE_SIZE, size
+        )))
+    }
+}
+
+/// 生成磁力链接
+fn build_magnet(info_hash_hex: &str, name: &str, options: &TorrentCreateOptions) -> String {
+    let encode = |s: &str| url::form_urlencoded::byte_serialize(s.as_bytes()).collect::<String>();
+
+    let mut magnet = format!("magnet:?xt=urn:btih:{}&dn={}", info_hash_hex, encode(name));
+    for tracker in &options.trackers {
+        magnet.push_str("&tr=");
+        magnet.push_str(&encode(tracker));
+    }
+    for seed in &options.web_seeds {
+        magnet.push_str("&ws=");
+        magnet.push_str(&encode(seed));
+    }
+    magnet
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> NebulaError {
+    NebulaError::IoError {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bencode;
+
+    #[test]
+    fn test_auto_piece_size() {
+        assert_eq!(auto_piece_size(0), MIN_PIECE_SIZE);
+        assert_eq!(auto_piece_size(1024 * 1024 * 1024), 1024 * 1024);
+        assert_eq!(auto_piece_size(u64::MAX), MAX_PIECE_SIZE);
+        assert!(validate_piece_size(3 * MIN_PIECE_SIZE).is_err());
+        assert!(validate_piece_size(MIN_PIECE_SIZE / 2).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_create_single_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("build.bin");
+        let data: Vec<u8> = (0..40_000u32).map(|i| i as u8).collect();
+        std::fs::write(&path, &data).unwrap();
+
+        let options = TorrentCreateOptions {
+            piece_size: Some(MIN_PIECE_SIZE),
+            private: true,
+            trackers: vec!["udp://t1/announce".into(), "http://t2/announce".into()],
+            web_seeds: vec!["https://cdn.example/build.bin".into()],
+            comment: Some("nightly".into()),
+            ..Default::default()
+        };
+        let created = create_torrent(&path, options).await.unwrap();
+
+        assert_eq!(created.piece_count, 3);
+        assert_eq!(created.total_size, 40_000);
+
+        let torrent = bencode::decode(&created.torrent_bytes).unwrap();
+        assert_eq!(torrent.get("announce").and_then(|v| v.as_str()), Some("udp://t1/announce"));
+        assert_eq!(torrent.get("comment").and_then(|v| v.as_str()), Some("nightly"));
+        let info = torrent.get("info").unwrap();
+        assert_eq!(info.get("private").and_then(|v| v.as_int()), Some(1));
+        assert_eq!(info.get("length").and_then(|v| v.as_int()), Some(40_000));
+
+        let pieces = info.get("pieces").and_then(|v| v.as_bytes()).unwrap();
+        let first: [u8; 20] = Sha1::digest(&data[..MIN_PIECE_SIZE as usize]).into();
+        assert_eq!(&pieces[..20], &first);
+
+        let expected: [u8; 20] = Sha1::digest(info.encode()).into();
+        assert_eq!(created.info_hash, expected);
+        assert!(created
+            .magnet
+            .starts_with(&format!("magnet:?xt=urn:btih:{}&dn=build.bin&tr=", created.info_hash_hex())));
+    }
+
+    #[tokio::test]
+    async fn test_create_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("release");
+        std::fs::create_dir_all(root.join("sub")).unwrap();
+        std::fs::write(root.join("b.txt"), b"bbbb").unwrap();
+        std::fs::write(root.join("sub").join("a.txt"), b"aa").unwrap();
+
+        let created = create_torrent(&root, TorrentCreateOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(created.name, "release");
+        assert_eq!(created.file_count, 2);
+        assert_eq!(created.piece_count, 1);
+
+        let torrent = bencode::decode(&created.torrent_bytes).unwrap();
+        assert!(torrent.get("announce").is_none());
+        let files = torrent.get("info").and_then(|i| i.get("files")).and_then(|f| f.as_list()).unwrap();
+        let first_path: Vec<_> = files[0]
+            .get("path")
+            .and_then(|p| p.as_list())
+            .unwrap()
+            .iter()
+            .filter_map(|c| c.as_str())
+            .collect();
+        assert_eq!(first_path, vec!["b.txt"]);
+
+        let pieces = torrent.get("info").and_then(|i| i.get("pieces")).and_then(|p| p.as_bytes()).unwrap();
+        let expected: [u8; 20] = Sha1::digest(b"bbbbaa").into();
+        assert_eq!(pieces, &expected);
+    }
+}
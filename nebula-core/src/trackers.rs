@@ -1,10 +1,19 @@
 //! Tracker 列表管理模块
 //!
-//! 从远程获取最新的 BitTorrent Tracker 列表以提高下载速度
+//! 从远程获取最新的 BitTorrent Tracker 列表以提高下载速度，
+//! 并通过探测（UDP 使用 BEP 15 connect，HTTP 使用 announce 请求）
+//! 记录每个 Tracker 的延迟和失败次数，只向种子注入排名靠前的健康 Tracker。
 
-use crate::error::Result;
+use crate::bencode;
+use crate::error::{NebulaError, Result};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 use tokio::fs;
+use tokio::net::UdpSocket;
 use tracing::{debug, info, warn};
 
 /// 远程 Tracker 列表 URL
@@ -33,15 +42,235 @@ const CACHE_FILENAME: &str = "trackers.txt";
 /// 缓存有效期（7 天）
 const CACHE_TTL_SECS: u64 = 7 * 24 * 60 * 60;
 
+/// Tracker 健康状况缓存文件名
+const HEALTH_CACHE_FILENAME: &str = "tracker_health.json";
+
+/// 健康状况有效期（6 小时），过期后重新探测
+const HEALTH_TTL_SECS: i64 = 6 * 60 * 60;
+
+/// 连续失败达到该次数的 Tracker 视为失效，不再注入
+const MAX_CONSECUTIVE_FAILURES: u32 = 3;
+
+/// 默认探测超时时间
+const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 同时进行的探测数量上限
+const PROBE_CONCURRENCY: usize = 32;
+
+/// BEP 15 协议魔数
+const UDP_PROTOCOL_ID: u64 = 0x41727101980;
+
+/// BEP 15 connect 动作
+const UDP_ACTION_CONNECT: u32 = 0;
+
+/// BEP 15 announce 动作
+const UDP_ACTION_ANNOUNCE: u32 = 1;
+
+/// BEP 15 scrape 动作
+const UDP_ACTION_SCRAPE: u32 = 2;
+
+/// BEP 15 error 动作
+const UDP_ACTION_ERROR: u32 = 3;
+
+/// announce 请求的事件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnounceEvent {
+    /// 定期通告
+    None,
+    /// 下载完成
+    Completed,
+    /// 开始下载
+    Started,
+    /// 停止下载，Tracker 应移除该 Peer
+    Stopped,
+}
+
+impl AnnounceEvent {
+    /// BEP 15 中的事件编号
+    fn udp_code(self) -> u32 {
+        match self {
+            AnnounceEvent::None => 0,
+            AnnounceEvent::Completed => 1,
+            AnnounceEvent::Started => 2,
+            AnnounceEvent::Stopped => 3,
+        }
+    }
+
+    /// HTTP 请求的 `event` 参数
+    fn http_param(self) -> Option<&'static str> {
+        match self {
+            AnnounceEvent::None => None,
+            AnnounceEvent::Completed => Some("completed"),
+            AnnounceEvent::Started => Some("started"),
+            AnnounceEvent::Stopped => Some("stopped"),
+        }
+    }
+}
+
+/// announce 请求参数
+///
+/// `peer_id` 和 `port` 必须与引擎实际使用的一致，否则 Tracker 会登记一个无法连接的 Peer
+#[derive(Debug, Clone)]
+pub struct AnnounceRequest {
+    /// 种子 info hash
+    pub info_hash: [u8; 20],
+    /// 本地 peer_id
+    pub peer_id: [u8; 20],
+    /// 本地监听端口
+    pub port: u16,
+    /// 已上传字节数
+    pub uploaded: u64,
+    /// 已下载字节数
+    pub downloaded: u64,
+    /// 剩余字节数（0 表示做种）
+    pub left: u64,
+    /// 事件
+    pub event: AnnounceEvent,
+}
+
+/// 单个 Tracker 的健康状况
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TrackerHealth {
+    /// Tracker 地址
+    pub url: String,
+
+    /// 最近一次成功探测的延迟（毫秒）
+    pub latency_ms: Option<u64>,
+
+    /// 累计成功次数
+    pub successes: u32,
+
+    /// 累计失败次数
+    pub failures: u32,
+
+    /// 连续失败次数
+    pub consecutive_failures: u32,
+
+    /// 最近一次探测时间（Unix 时间戳，秒）
+    pub last_checked: Option<i64>,
+
+    /// 最近一次失败原因
+    pub last_error: Option<String>,
+}
+
+impl TrackerHealth {
+    /// 创建尚未探测过的记录
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// 是否已失效（连续失败次数过多）
+    pub fn is_dead(&self) -> bool {
+        self.consecutive_failures >= MAX_CONSECUTIVE_FAILURES
+    }
+
+    /// 探测结果是否仍在有效期内
+    fn is_fresh(&self, now: i64) -> bool {
+        self.last_checked
+            .map(|t| now - t < HEALTH_TTL_SECS)
+            .unwrap_or(false)
+    }
+
+    /// 记录一次探测结果
+    fn record(&mut self, result: &Result<Duration>, now: i64) {
+        self.last_checked = Some(now);
+        match result {
+            Ok(latency) => {
+                self.latency_ms = Some(latency.as_millis() as u64);
+                self.successes += 1;
+                self.consecutive_failures = 0;
+                self.last_error = None;
+            }
+            Err(e) => {
+                self.failures += 1;
+                self.consecutive_failures += 1;
+                self.last_error = Some(e.to_string());
+            }
+        }
+    }
+
+    /// 失败率 (0.0 - 1.0)
+    fn failure_ratio(&self) -> f64 {
+        let total = self.successes + self.failures;
+        if total == 0 {
+            0.0
+        } else {
+            self.failures as f64 / total as f64
+        }
+    }
+}
+
+/// Tracker scrape 结果
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScrapeStats {
+    /// 做种者数量
+    pub seeders: u32,
+    /// 下载者数量
+    pub leechers: u32,
+    /// 已完成下载次数
+    pub completed: u32,
+}
+
+/// 按健康度排序 Tracker，并剔除失效的 Tracker
+///
+/// 排序规则：最近一次探测成功的优先（按延迟升序，失败率作为次要条件），
+/// 其次是偶尔失败但尚未失效的
+pub fn rank_trackers(health: &[TrackerHealth]) -> Vec<TrackerHealth> {
+    let mut ranked: Vec<TrackerHealth> = health.iter().filter(|h| !h.is_dead()).cloned().collect();
+    ranked.sort_by(|a, b| {
+        a.consecutive_failures
+            .cmp(&b.consecutive_failures)
+            .then_with(|| {
+                a.latency_ms
+                    .unwrap_or(u64::MAX)
+                    .cmp(&b.latency_ms.unwrap_or(u64::MAX))
+            })
+            .then_with(|| a.failure_ratio().total_cmp(&b.failure_ratio()))
+    });
+    ranked
+}
+
+/// 从健康状况列表中选出排名前 `limit` 的 Tracker
+///
+/// 所有 Tracker 都已失效时（例如长时间离线）退回原始列表的前 `limit` 个，
+/// 避免种子完全没有 Tracker 可用
+fn select_ranked(trackers: Vec<String>, health: &[TrackerHealth], limit: usize) -> Vec<String> {
+    let ranked = rank_trackers(health);
+    if ranked.is_empty() {
+        warn!("没有健康的 Tracker，使用未排序的列表");
+        return trackers.into_iter().take(limit).collect();
+    }
+
+    info!(
+        "Tracker 健康检查: {} 个可用 / 共 {} 个",
+        ranked.len(),
+        trackers.len()
+    );
+    ranked.into_iter().take(limit).map(|h| h.url).collect()
+}
+
 /// Tracker 管理器
 pub struct TrackerManager {
     cache_dir: PathBuf,
+    probe_timeout: Duration,
 }
 
 impl TrackerManager {
     /// 创建新的 Tracker 管理器
     pub fn new(cache_dir: PathBuf) -> Self {
-        Self { cache_dir }
+        Self {
+            cache_dir,
+            probe_timeout: DEFAULT_PROBE_TIMEOUT,
+        }
+    }
+
+    /// 设置探测超时时间
+    pub fn with_probe_timeout(mut self, timeout: Duration) -> Self {
+        self.probe_timeout = timeout;
+        self
     }
 
     /// 获取 Tracker 列表
@@ -69,6 +298,168 @@ impl TrackerManager {
         }
     }
 
+    /// 获取排名前 `limit` 的健康 Tracker
+    ///
+    /// 健康状况过期或从未探测过的 Tracker 会先被探测一次。
+    /// 如果所有 Tracker 都探测失败（例如当前离线），则退回原始列表的前 `limit` 个，
+    /// 避免种子完全没有 Tracker 可用
+    pub async fn get_healthy_trackers(&self, limit: usize) -> Vec<String> {
+        let trackers = self.get_trackers().await;
+        let health = self.probe_stale(&trackers).await;
+        select_ranked(trackers, &health, limit)
+    }
+
+    /// 按上次探测的结果选出排名前 `limit` 的 Tracker，不发起任何网络请求
+    ///
+    /// 列表缓存即使已过期也会使用，没有缓存时使用内置列表；
+    /// 从未探测过的 Tracker 排在已知可用的之后。
+    /// 新的探测结果由 [`Self::refresh_health`] 写入缓存
+    pub async fn cached_healthy_trackers(&self, limit: usize) -> Vec<String> {
+        let trackers = match self.read_cache_file().await {
+            Some(trackers) => trackers,
+            None => FALLBACK_TRACKERS.iter().map(|s| s.to_string()).collect(),
+        };
+        let cache = self.read_health().await;
+        let health: Vec<TrackerHealth> = trackers
+            .iter()
+            .map(|url| {
+                cache
+                    .get(url)
+                    .cloned()
+                    .unwrap_or_else(|| TrackerHealth::new(url))
+            })
+            .collect();
+        select_ranked(trackers, &health, limit)
+    }
+
+    /// 更新 Tracker 列表（缓存过期时）并探测健康状况已过期的 Tracker
+    ///
+    /// 耗时较长，应在后台执行
+    pub async fn refresh_health(&self) {
+        let trackers = self.get_trackers().await;
+        self.probe_stale(&trackers).await;
+    }
+
+    /// 探测健康状况已过期的 Tracker，返回列表中所有 Tracker 的最新健康状况
+    pub async fn probe_stale(&self, trackers: &[String]) -> Vec<TrackerHealth> {
+        let mut cache = self.read_health().await;
+        let now = chrono::Utc::now().timestamp();
+
+        let stale: Vec<String> = trackers
+            .iter()
+            .filter(|url| !cache.get(*url).map(|h| h.is_fresh(now)).unwrap_or(false))
+            .cloned()
+            .collect();
+
+        if !stale.is_empty() {
+            debug!("探测 {} 个 Tracker", stale.len());
+            let results = self.probe_many(&stale).await;
+            for (url, result) in results {
+                cache
+                    .entry(url.clone())
+                    .or_insert_with(|| TrackerHealth::new(&url))
+                    .record(&result, now);
+            }
+        }
+
+        // 只保留当前列表中的 Tracker，避免缓存无限增长
+        cache.retain(|url, _| trackers.contains(url));
+        let _ = self.write_health(&cache).await;
+
+        trackers
+            .iter()
+            .filter_map(|url| cache.get(url).cloned())
+            .collect()
+    }
+
+    /// 并发探测多个 Tracker
+    async fn probe_many(&self, urls: &[String]) -> Vec<(String, Result<Duration>)> {
+        futures::stream::iter(urls.iter().cloned())
+            .map(|url| async move {
+                let result = self.probe(&url).await;
+                (url, result)
+            })
+            .buffer_unordered(PROBE_CONCURRENCY)
+            .collect()
+            .await
+    }
+
+    /// 探测单个 Tracker，返回往返延迟
+    pub async fn probe(&self, url: &str) -> Result<Duration> {
+        let start = Instant::now();
+        let probe = async {
+            if url.starts_with("udp://") {
+                probe_udp(url).await
+            } else if url.starts_with("http://") || url.starts_with("https://") {
+                probe_http(url, self.probe_timeout).await
+            } else {
+                Err(NebulaError::TrackerError(format!("不支持的 Tracker 协议: {}", url)))
+            }
+        };
+
+        match tokio::time::timeout(self.probe_timeout, probe).await {
+            Ok(Ok(())) => Ok(start.elapsed()),
+            Ok(Err(e)) => Err(e),
+            Err(_) => Err(NebulaError::Timeout(format!("Tracker 无响应: {}", url))),
+        }
+    }
+
+    /// 向 Tracker 查询指定种子的做种/下载人数
+    pub async fn scrape(&self, url: &str, info_hash: &[u8; 20]) -> Result<ScrapeStats> {
+        let scrape = async {
+            if url.starts_with("udp://") {
+                scrape_udp(url, info_hash).await
+            } else if url.starts_with("http://") || url.starts_with("https://") {
+                scrape_http(url, info_hash, self.probe_timeout).await
+            } else {
+                Err(NebulaError::TrackerError(format!("不支持的 Tracker 协议: {}", url)))
+            }
+        };
+
+        tokio::time::timeout(self.probe_timeout, scrape)
+            .await
+            .map_err(|_| NebulaError::Timeout(format!("Tracker 无响应: {}", url)))?
+    }
+
+    /// 向 Tracker 通告指定种子，返回 Tracker 提供的 Peer 地址
+    ///
+    /// 只解析紧凑格式的 Peer 列表
+    pub async fn announce(&self, url: &str, request: &AnnounceRequest) -> Result<Vec<SocketAddr>> {
+        let announce = async {
+            if url.starts_with("udp://") {
+                announce_udp(url, request).await
+            } else if url.starts_with("http://") || url.starts_with("https://") {
+                announce_http(url, request, self.probe_timeout).await
+            } else {
+                Err(NebulaError::TrackerError(format!("不支持的 Tracker 协议: {}", url)))
+            }
+        };
+
+        tokio::time::timeout(self.probe_timeout, announce)
+            .await
+            .map_err(|_| NebulaError::Timeout(format!("Tracker 无响应: {}", url)))?
+    }
+
+    /// 读取健康状况缓存
+    async fn read_health(&self) -> HashMap<String, TrackerHealth> {
+        let path = self.cache_dir.join(HEALTH_CACHE_FILENAME);
+        let Ok(content) = fs::read(&path).await else {
+            return HashMap::new();
+        };
+        let list: Vec<TrackerHealth> = serde_json::from_slice(&content).unwrap_or_default();
+        list.into_iter().map(|h| (h.url.clone(), h)).collect()
+    }
+
+    /// 写入健康状况缓存
+    async fn write_health(&self, cache: &HashMap<String, TrackerHealth>) -> Result<()> {
+        fs::create_dir_all(&self.cache_dir).await?;
+        let list: Vec<&TrackerHealth> = cache.values().collect();
+        let content = serde_json::to_vec_pretty(&list)
+            .map_err(|e| NebulaError::Internal(format!("序列化 Tracker 健康状况失败: {}", e)))?;
+        fs::write(self.cache_dir.join(HEALTH_CACHE_FILENAME), content).await?;
+        Ok(())
+    }
+
     /// 强制刷新 Tracker 列表
     pub async fn refresh(&self) -> Result<Vec<String>> {
         let trackers = self.fetch_remote().await?;
@@ -91,7 +482,12 @@ impl TrackerManager {
             return None;
         }
 
-        // 读取缓存内容
+        self.read_cache_file().await
+    }
+
+    /// 读取缓存内容，不检查是否过期
+    async fn read_cache_file(&self) -> Option<Vec<String>> {
+        let cache_path = self.cache_dir.join(CACHE_FILENAME);
         let content = fs::read_to_string(&cache_path).await.ok()?;
         let trackers: Vec<String> = content
             .lines()
@@ -158,9 +554,396 @@ impl TrackerManager {
     }
 }
 
+/// 通过 BEP 15 connect 请求探测 UDP Tracker
+async fn probe_udp(url: &str) -> Result<()> {
+    udp_connect(url).await.map(|_| ())
+}
+
+/// 与 UDP Tracker 建立 BEP 15 会话，返回已连接的套接字和 connection_id
+async fn udp_connect(url: &str) -> Result<(UdpSocket, u64)> {
+    let parsed = url::Url::parse(url)?;
+    let host = parsed
+        .host_str()
+        .ok_or_else(|| NebulaError::InvalidUrl(url.to_string()))?;
+    let port = parsed
+        .port()
+        .ok_or_else(|| NebulaError::InvalidUrl(format!("缺少端口: {}", url)))?;
+
+    let addr = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|e| NebulaError::NetworkError(format!("解析 {} 失败: {}", host, e)))?
+        .next()
+        .ok_or_else(|| NebulaError::NetworkError(format!("无法解析地址: {}", host)))?;
+
+    let bind_addr = if addr.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" };
+    let socket = UdpSocket::bind(bind_addr).await?;
+    socket.connect(addr).await?;
+
+    let transaction_id: u32 = rand::random();
+    let mut request = Vec::with_capacity(16);
+    request.extend_from_slice(&UDP_PROTOCOL_ID.to_be_bytes());
+    request.extend_from_slice(&UDP_ACTION_CONNECT.to_be_bytes());
+    request.extend_from_slice(&transaction_id.to_be_bytes());
+    socket.send(&request).await?;
+
+    let mut buf = [0u8; 64];
+    let len = socket.recv(&mut buf).await?;
+    let body = udp_response_body(&buf[..len], UDP_ACTION_CONNECT, transaction_id)?;
+    if body.len() < 8 {
+        return Err(NebulaError::TrackerError(format!("UDP 响应过短: {} 字节", len)));
+    }
+
+    let mut connection_id = [0u8; 8];
+    connection_id.copy_from_slice(&body[..8]);
+    Ok((socket, u64::from_be_bytes(connection_id)))
+}
+
+/// 通过 BEP 15 scrape 请求查询 UDP Tracker
+async fn scrape_udp(url: &str, info_hash: &[u8; 20]) -> Result<ScrapeStats> {
+    let (socket, connection_id) = udp_connect(url).await?;
+
+    let transaction_id: u32 = rand::random();
+    let mut request = Vec::with_capacity(36);
+    request.extend_from_slice(&connection_id.to_be_bytes());
+    request.extend_from_slice(&UDP_ACTION_SCRAPE.to_be_bytes());
+    request.extend_from_slice(&transaction_id.to_be_bytes());
+    request.extend_from_slice(info_hash);
+    socket.send(&request).await?;
+
+    let mut buf = [0u8; 512];
+    let len = socket.recv(&mut buf).await?;
+    let body = udp_response_body(&buf[..len], UDP_ACTION_SCRAPE, transaction_id)?;
+    if body.len() < 12 {
+        return Err(NebulaError::TrackerError(format!("UDP scrape 响应过短: {} 字节", len)));
+    }
+
+    let read_u32 = |offset: usize| {
+        u32::from_be_bytes([body[offset], body[offset + 1], body[offset + 2], body[offset + 3]])
+    };
+    Ok(ScrapeStats {
+        seeders: read_u32(0),
+        completed: read_u32(4),
+        leechers: read_u32(8),
+    })
+}
+
+/// 通过 BEP 15 announce 请求获取 UDP Tracker 上的 Peer
+async fn announce_udp(url: &str, announce: &AnnounceRequest) -> Result<Vec<SocketAddr>> {
+    let (socket, connection_id) = udp_connect(url).await?;
+
+    let transaction_id: u32 = rand::random();
+    let mut request = Vec::with_capacity(98);
+    request.extend_from_slice(&connection_id.to_be_bytes());
+    request.extend_from_slice(&UDP_ACTION_ANNOUNCE.to_be_bytes());
+    request.extend_from_slice(&transaction_id.to_be_bytes());
+    request.extend_from_slice(&announce.info_hash);
+    request.extend_from_slice(&announce.peer_id);
+    request.extend_from_slice(&announce.downloaded.to_be_bytes());
+    request.extend_from_slice(&announce.left.to_be_bytes());
+    request.extend_from_slice(&announce.uploaded.to_be_bytes());
+    // event / ip / key / num_want(-1 表示默认数量)
+    request.extend_from_slice(&announce.event.udp_code().to_be_bytes());
+    request.extend_from_slice(&0u32.to_be_bytes());
+    request.extend_from_slice(&rand::random::<u32>().to_be_bytes());
+    request.extend_from_slice(&(-1i32).to_be_bytes());
+    request.extend_from_slice(&announce.port.to_be_bytes());
+    socket.send(&request).await?;
+
+    let mut buf = vec![0u8; 4096];
+    let len = socket.recv(&mut buf).await?;
+    let body = udp_response_body(&buf[..len], UDP_ACTION_ANNOUNCE, transaction_id)?;
+    if body.len() < 12 {
+        return Err(NebulaError::TrackerError(format!("UDP announce 响应过短: {} 字节", len)));
+    }
+
+    // IPv6 Tracker 返回 18 字节的 Peer 地址
+    let ipv6 = socket.peer_addr()?.is_ipv6();
+    Ok(parse_compact_peers(&body[12..], ipv6))
+}
+
+/// 解析紧凑格式的 Peer 列表（IPv4 每项 6 字节，IPv6 每项 18 字节）
+fn parse_compact_peers(data: &[u8], ipv6: bool) -> Vec<SocketAddr> {
+    let ip_len = if ipv6 { 16 } else { 4 };
+    data.chunks_exact(ip_len + 2)
+        .map(|chunk| {
+            let ip = if ipv6 {
+                let mut octets = [0u8; 16];
+                octets.copy_from_slice(&chunk[..16]);
+                IpAddr::V6(Ipv6Addr::from(octets))
+            } else {
+                IpAddr::V4(Ipv4Addr::new(chunk[0], chunk[1], chunk[2], chunk[3]))
+            };
+            let port = u16::from_be_bytes([chunk[ip_len], chunk[ip_len + 1]]);
+            SocketAddr::new(ip, port)
+        })
+        .filter(|addr| addr.port() != 0)
+        .collect()
+}
+
+/// 校验 UDP 响应头（action + transaction_id），返回响应体
+fn udp_response_body(data: &[u8], expected_action: u32, transaction_id: u32) -> Result<&[u8]> {
+    if data.len() < 8 {
+        return Err(NebulaError::TrackerError(format!("UDP 响应过短: {} 字节", data.len())));
+    }
+
+    let action = u32::from_be_bytes([data[0], data[1], data[2], data[3]]);
+    let tid = u32::from_be_bytes([data[4], data[5], data[6], data[7]]);
+    if tid != transaction_id {
+        return Err(NebulaError::TrackerError("UDP 响应 transaction_id 不匹配".to_string()));
+    }
+    if action == UDP_ACTION_ERROR {
+        let message = String::from_utf8_lossy(&data[8..]).to_string();
+        return Err(NebulaError::TrackerError(message));
+    }
+    if action != expected_action {
+        return Err(NebulaError::TrackerError(format!("UDP 响应无效: action={}", action)));
+    }
+
+    Ok(&data[8..])
+}
+
+/// 通过 announce 请求探测 HTTP Tracker
+///
+/// 使用随机 info_hash 发起 announce，只要返回合法的 Bencode 字典即视为可用
+/// （即使是 "unregistered torrent" 之类的 failure reason 也说明 Tracker 在线）
+async fn probe_http(url: &str, timeout: Duration) -> Result<()> {
+    let info_hash: [u8; 20] = rand::random();
+    let peer_id: [u8; 20] = rand::random();
+    let separator = if url.contains('?') { '&' } else { '?' };
+    let request_url = format!(
+        "{}{}info_hash={}&peer_id={}&port=6881&uploaded=0&downloaded=0&left=0&compact=1",
+        url,
+        separator,
+        percent_encode_bytes(&info_hash),
+        percent_encode_bytes(&peer_id)
+    );
+
+    let client = reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(|e| NebulaError::Internal(e.to_string()))?;
+
+    let response = client.get(&request_url).send().await?;
+    if !response.status().is_success() {
+        return Err(NebulaError::HttpError {
+            status_code: response.status().as_u16(),
+            message: format!("Tracker 响应异常: {}", response.status()),
+        });
+    }
+
+    let body = response.bytes().await?;
+    match bencode::decode(&body) {
+        Some(bencode::Value::Dict(_)) => Ok(()),
+        _ => Err(NebulaError::TrackerError("响应不是有效的 Bencode 字典".to_string())),
+    }
+}
+
+/// 通过 HTTP announce 请求获取 Tracker 上的 Peer
+async fn announce_http(
+    url: &str,
+    announce: &AnnounceRequest,
+    timeout: Duration,
+) -> Result<Vec<SocketAddr>> {
+    let separator = if url.contains('?') { '&' } else { '?' };
+    let request_url = format!("{}{}{}", url, separator, announce_query(announce));
+
+    let client = reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(|e| NebulaError::Internal(e.to_string()))?;
+
+    let response = client.get(&request_url).send().await?;
+    if !response.status().is_success() {
+        return Err(NebulaError::HttpError {
+            status_code: response.status().as_u16(),
+            message: format!("Tracker 响应异常: {}", response.status()),
+        });
+    }
+
+    let body = response.bytes().await?;
+    let value = bencode::decode(&body)
+        .ok_or_else(|| NebulaError::TrackerError("响应不是有效的 Bencode 字典".to_string()))?;
+    if let Some(reason) = value.get("failure reason").and_then(bencode::Value::as_str) {
+        return Err(NebulaError::TrackerError(reason.to_string()));
+    }
+
+    let mut peers = Vec::new();
+    if let Some(data) = value.get("peers").and_then(bencode::Value::as_bytes) {
+        peers.extend(parse_compact_peers(data, false));
+    }
+    if let Some(data) = value.get("peers6").and_then(bencode::Value::as_bytes) {
+        peers.extend(parse_compact_peers(data, true));
+    }
+    Ok(peers)
+}
+
+/// HTTP announce 请求的查询参数
+fn announce_query(announce: &AnnounceRequest) -> String {
+    let mut query = format!(
+        "info_hash={}&peer_id={}&port={}&uploaded={}&downloaded={}&left={}&compact=1",
+        percent_encode_bytes(&announce.info_hash),
+        percent_encode_bytes(&announce.peer_id),
+        announce.port,
+        announce.uploaded,
+        announce.downloaded,
+        announce.left
+    );
+    if let Some(event) = announce.event.http_param() {
+        query.push_str("&event=");
+        query.push_str(event);
+    }
+    query
+}
+
+/// 根据 announce 地址推导 scrape 地址（BEP 48 约定）
+///
+/// 只有路径最后一段以 `announce` 开头的 Tracker 才支持 scrape
+pub fn scrape_url(announce_url: &str) -> Option<String> {
+    let (base, query) = match announce_url.split_once('?') {
+        Some((base, query)) => (base, Some(query)),
+        None => (announce_url, None),
+    };
+    let slash = base.rfind('/')?;
+    let last = &base[slash + 1..];
+    let rest = last.strip_prefix("announce")?;
+
+    let mut url = format!("{}/scrape{}", &base[..slash], rest);
+    if let Some(query) = query {
+        url.push('?');
+        url.push_str(query);
+    }
+    Some(url)
+}
+
+/// 通过 HTTP scrape 请求查询 Tracker
+async fn scrape_http(url: &str, info_hash: &[u8; 20], timeout: Duration) -> Result<ScrapeStats> {
+    let scrape = scrape_url(url)
+        .ok_or_else(|| NebulaError::TrackerError(format!("Tracker 不支持 scrape: {}", url)))?;
+    let separator = if scrape.contains('?') { '&' } else { '?' };
+    let request_url = format!("{}{}info_hash={}", scrape, separator, percent_encode_bytes(info_hash));
+
+    let client = reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(|e| NebulaError::Internal(e.to_string()))?;
+
+    let response = client.get(&request_url).send().await?;
+    if !response.status().is_success() {
+        return Err(NebulaError::HttpError {
+            status_code: response.status().as_u16(),
+            message: format!("Tracker 响应异常: {}", response.status()),
+        });
+    }
+
+    let body = response.bytes().await?;
+    let value = bencode::decode(&body)
+        .ok_or_else(|| NebulaError::TrackerError("响应不是有效的 Bencode 字典".to_string()))?;
+    if let Some(reason) = value.get("failure reason").and_then(bencode::Value::as_str) {
+        return Err(NebulaError::TrackerError(reason.to_string()));
+    }
+
+    let stats = value
+        .get("files")
+        .and_then(bencode::Value::as_dict)
+        .and_then(|files| files.get(info_hash.as_slice()))
+        .ok_or_else(|| NebulaError::TrackerError("Tracker 上没有该种子".to_string()))?;
+    let field = |key: &str| {
+        stats
+            .get(key)
+            .and_then(bencode::Value::as_int)
+            .unwrap_or(0)
+            .clamp(0, u32::MAX as i64) as u32
+    };
+
+    Ok(ScrapeStats {
+        seeders: field("complete"),
+        leechers: field("incomplete"),
+        completed: field("downloaded"),
+    })
+}
+
+/// 按 BitTorrent 约定对原始字节做百分号编码
+pub(crate) fn percent_encode_bytes(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 3);
+    for &b in bytes {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b'~') {
+            out.push(b as char);
+        } else {
+            out.push_str(&format!("%{:02X}", b));
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// 启动一个响应 BEP 15 connect/announce/scrape 请求的本地 UDP Tracker
+    async fn spawn_udp_tracker() -> String {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = socket.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = [0u8; 128];
+            while let Ok((len, from)) = socket.recv_from(&mut buf).await {
+                if len < 16 {
+                    continue;
+                }
+                let action = u32::from_be_bytes([buf[8], buf[9], buf[10], buf[11]]);
+                let mut response = Vec::new();
+                if action == UDP_ACTION_CONNECT {
+                    response.extend_from_slice(&UDP_ACTION_CONNECT.to_be_bytes());
+                    response.extend_from_slice(&buf[12..16]);
+                    response.extend_from_slice(&42u64.to_be_bytes());
+                } else if action == UDP_ACTION_ANNOUNCE && buf[..8] == 42u64.to_be_bytes() {
+                    response.extend_from_slice(&UDP_ACTION_ANNOUNCE.to_be_bytes());
+                    response.extend_from_slice(&buf[12..16]);
+                    for n in [1800u32, 3, 7] {
+                        response.extend_from_slice(&n.to_be_bytes());
+                    }
+                    response.extend_from_slice(&[10, 0, 0, 1, 0x1a, 0xe1]);
+                } else if action == UDP_ACTION_SCRAPE && buf[..8] == 42u64.to_be_bytes() {
+                    response.extend_from_slice(&UDP_ACTION_SCRAPE.to_be_bytes());
+                    response.extend_from_slice(&buf[12..16]);
+                    for n in [7u32, 100, 3] {
+                        response.extend_from_slice(&n.to_be_bytes());
+                    }
+                } else {
+                    continue;
+                }
+                let _ = socket.send_to(&response, from).await;
+            }
+        });
+        format!("udp://{}/announce", addr)
+    }
+
+    /// 启动一个返回固定 Bencode 响应的本地 HTTP Tracker
+    async fn spawn_http_tracker(body: &'static [u8]) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buf = [0u8; 2048];
+                let _ = stream.read(&mut buf).await;
+                let header = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                );
+                let _ = stream.write_all(header.as_bytes()).await;
+                let _ = stream.write_all(body).await;
+            }
+        });
+        format!("http://{}/announce", addr)
+    }
+
+    /// 绑定一个永不响应的 UDP 端口
+    async fn silent_udp_tracker() -> (UdpSocket, String) {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("udp://{}/announce", socket.local_addr().unwrap());
+        (socket, url)
+    }
 
     #[tokio::test]
     async fn test_fallback_trackers() {
@@ -168,4 +951,222 @@ mod tests {
         let trackers = manager.get_trackers().await;
         assert!(!trackers.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_probe_udp_tracker() {
+        let url = spawn_udp_tracker().await;
+        let manager = TrackerManager::new(PathBuf::from("/tmp/test_trackers"));
+        assert!(manager.probe(&url).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_probe_http_tracker() {
+        let url = spawn_http_tracker(b"d14:failure reason20:unregistered torrente").await;
+        let manager = TrackerManager::new(PathBuf::from("/tmp/test_trackers"));
+        assert!(manager.probe(&url).await.is_ok());
+
+        let url = spawn_http_tracker(b"<html>not a tracker</html>").await;
+        assert!(manager.probe(&url).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_probe_timeout() {
+        let (_socket, url) = silent_udp_tracker().await;
+        let manager = TrackerManager::new(PathBuf::from("/tmp/test_trackers"))
+            .with_probe_timeout(Duration::from_millis(200));
+        assert!(matches!(manager.probe(&url).await, Err(NebulaError::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_scrape_udp_tracker() {
+        let url = spawn_udp_tracker().await;
+        let manager = TrackerManager::new(PathBuf::from("/tmp/test_trackers"));
+        let stats = manager.scrape(&url, &[1u8; 20]).await.unwrap();
+        assert_eq!(
+            stats,
+            ScrapeStats {
+                seeders: 7,
+                leechers: 3,
+                completed: 100
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scrape_http_tracker() {
+        let url = spawn_http_tracker(
+            b"d5:filesd20:\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01d8:completei5e10:downloadedi9e10:incompletei2eeee",
+        )
+        .await;
+        let manager = TrackerManager::new(PathBuf::from("/tmp/test_trackers"));
+        let stats = manager.scrape(&url, &[1u8; 20]).await.unwrap();
+        assert_eq!(stats.seeders, 5);
+        assert_eq!(stats.leechers, 2);
+        assert_eq!(stats.completed, 9);
+    }
+
+    fn announce_request(event: AnnounceEvent) -> AnnounceRequest {
+        AnnounceRequest {
+            info_hash: [1u8; 20],
+            peer_id: *b"-NB0100-abcdefghijkl",
+            port: 51413,
+            uploaded: 5,
+            downloaded: 10,
+            left: 90,
+            event,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_announce_udp_tracker() {
+        let url = spawn_udp_tracker().await;
+        let manager = TrackerManager::new(PathBuf::from("/tmp/test_trackers"));
+        let request = announce_request(AnnounceEvent::None);
+        let peers = manager.announce(&url, &request).await.unwrap();
+        assert_eq!(peers, vec!["10.0.0.1:6881".parse().unwrap()]);
+    }
+
+    #[tokio::test]
+    async fn test_announce_http_tracker() {
+        let url = spawn_http_tracker(b"d8:intervali1800e5:peers6:\x0a\x00\x00\x02\x1a\xe2e").await;
+        let manager = TrackerManager::new(PathBuf::from("/tmp/test_trackers"));
+        let request = announce_request(AnnounceEvent::None);
+        let peers = manager.announce(&url, &request).await.unwrap();
+        assert_eq!(peers, vec!["10.0.0.2:6882".parse().unwrap()]);
+    }
+
+    #[test]
+    fn test_announce_query() {
+        let query = announce_query(&announce_request(AnnounceEvent::None));
+        assert!(query.contains("&peer_id=-NB0100-abcdefghijkl&"));
+        assert!(query.contains("&port=51413&uploaded=5&downloaded=10&left=90&"));
+        assert!(!query.contains("event="));
+
+        let query = announce_query(&announce_request(AnnounceEvent::Stopped));
+        assert!(query.ends_with("&event=stopped"));
+    }
+
+    #[test]
+    fn test_scrape_url() {
+        assert_eq!(
+            scrape_url("http://t.example/announce").as_deref(),
+            Some("http://t.example/scrape")
+        );
+        assert_eq!(
+            scrape_url("http://t.example/x/announce.php?passkey=1").as_deref(),
+            Some("http://t.example/x/scrape.php?passkey=1")
+        );
+        assert_eq!(scrape_url("http://t.example/a"), None);
+    }
+
+    #[test]
+    fn test_rank_trackers() {
+        let fast = TrackerHealth {
+            latency_ms: Some(20),
+            successes: 3,
+            ..TrackerHealth::new("udp://fast:1/announce")
+        };
+        let slow = TrackerHealth {
+            latency_ms: Some(300),
+            successes: 3,
+            ..TrackerHealth::new("udp://slow:1/announce")
+        };
+        let flaky = TrackerHealth {
+            latency_ms: Some(10),
+            successes: 2,
+            failures: 1,
+            consecutive_failures: 1,
+            ..TrackerHealth::new("udp://flaky:1/announce")
+        };
+        let dead = TrackerHealth {
+            failures: 5,
+            consecutive_failures: MAX_CONSECUTIVE_FAILURES,
+            ..TrackerHealth::new("udp://dead:1/announce")
+        };
+
+        let ranked = rank_trackers(&[dead, flaky, slow, fast]);
+        let urls: Vec<&str> = ranked.iter().map(|h| h.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "udp://fast:1/announce",
+                "udp://slow:1/announce",
+                "udp://flaky:1/announce"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_healthy_trackers_prunes_dead() {
+        let dir = tempfile::tempdir().unwrap();
+        let udp = spawn_udp_tracker().await;
+        let http = spawn_http_tracker(b"d8:intervali1800e5:peers0:e").await;
+        let (_socket, silent) = silent_udp_tracker().await;
+
+        let list = [silent.clone(), udp.clone(), http.clone()].join("\n");
+        fs::write(dir.path().join(CACHE_FILENAME), list).await.unwrap();
+
+        let manager = TrackerManager::new(dir.path().to_path_buf())
+            .with_probe_timeout(Duration::from_millis(200));
+
+        // 第一次探测：静默的 Tracker 失败一次，仍保留在末尾
+        let trackers = manager.get_healthy_trackers(10).await;
+        assert_eq!(trackers.len(), 3);
+        assert_eq!(trackers.last(), Some(&silent));
+
+        // 模拟多次连续失败后被剔除
+        let mut cache = manager.read_health().await;
+        let entry = cache.get_mut(&silent).unwrap();
+        entry.consecutive_failures = MAX_CONSECUTIVE_FAILURES;
+        manager.write_health(&cache).await.unwrap();
+
+        let trackers = manager.get_healthy_trackers(1).await;
+        assert_eq!(trackers.len(), 1);
+        assert_ne!(trackers[0], silent);
+    }
+
+    #[tokio::test]
+    async fn test_cached_trackers_do_not_probe() {
+        let dir = tempfile::tempdir().unwrap();
+        let (_socket, silent) = silent_udp_tracker().await;
+        let list = [
+            "udp://fast:1/announce",
+            "udp://unknown:1/announce",
+            silent.as_str(),
+        ]
+        .join("\n");
+        fs::write(dir.path().join(CACHE_FILENAME), list)
+            .await
+            .unwrap();
+
+        let manager = TrackerManager::new(dir.path().to_path_buf())
+            .with_probe_timeout(Duration::from_secs(30));
+        let mut cache = HashMap::new();
+        cache.insert(
+            "udp://fast:1/announce".to_string(),
+            TrackerHealth {
+                latency_ms: Some(20),
+                successes: 1,
+                ..TrackerHealth::new("udp://fast:1/announce")
+            },
+        );
+        cache.insert(
+            silent.clone(),
+            TrackerHealth {
+                consecutive_failures: MAX_CONSECUTIVE_FAILURES,
+                ..TrackerHealth::new(&silent)
+            },
+        );
+        manager.write_health(&cache).await.unwrap();
+
+        // 只使用缓存的健康状况：不会等待静默的 Tracker 超时
+        let trackers =
+            tokio::time::timeout(Duration::from_secs(1), manager.cached_healthy_trackers(10))
+                .await
+                .unwrap();
+        assert_eq!(
+            trackers,
+            vec!["udp://fast:1/announce", "udp://unknown:1/announce"]
+        );
+    }
 }
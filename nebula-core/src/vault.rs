@@ -0,0 +1,1033 @@
+//! 站点凭据保险库
+//!
+//! 按域名加密保存各站点的登录凭据（Cookie、Token、用户名密码、自定义请求头），
+//! 下载时自动注入到匹配主机的任务中：HTTP 任务添加请求头，yt-dlp 任务传入 cookies 文件
+//! 和账号配置，FTP 任务在链接不带账号时使用匹配的用户名密码登录。
+//!
+//! 凭据使用 AES-256-GCM 加密，主密钥可以：
+//! - 由用户口令通过 Argon2id 派生，不保存在任何地方，每次启动后需要解锁
+//! - 保存在系统钥匙串中（启用 `os-keyring` 特性），钥匙串不可用时回退到密钥文件
+//! - 保存在数据目录的密钥文件中
+
+use crate::error::{NebulaError, Result};
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Nonce,
+};
+use argon2::Argon2;
+use base64::Engine;
+use rand::RngCore;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, COOKIE};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+use url::Url;
+
+/// 主密钥长度（AES-256）
+pub const KEY_LEN: usize = 32;
+
+/// AES-GCM nonce 长度
+const NONCE_LEN: usize = 12;
+
+/// Argon2 盐长度
+const SALT_LEN: usize = 16;
+
+/// 凭据文件名
+const VAULT_FILENAME: &str = "credentials.enc";
+
+/// 导出给 yt-dlp 的临时凭据目录，每次导出使用一个子目录，用完即删除
+const YT_DLP_TEMP_DIR: &str = "yt-dlp-credentials";
+
+/// 凭据文件头：魔数 + 主密钥来源 + 盐
+const VAULT_MAGIC: &[u8; 4] = b"NBV1";
+
+/// 主密钥来自密钥存储
+const MODE_KEY_STORE: u8 = 0;
+
+/// 主密钥由口令派生
+const MODE_PASSPHRASE: u8 = 1;
+
+/// 系统钥匙串中的服务名
+#[cfg(feature = "os-keyring")]
+const KEYRING_SERVICE: &str = "nebula";
+
+/// 主密钥
+pub type MasterKey = [u8; KEY_LEN];
+
+// ===== 加密 =====
+
+/// 加密数据，返回 nonce + 密文
+pub(crate) fn seal(key: &MasterKey, plaintext: &[u8]) -> Result<Vec<u8>> {
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    rand::rng().fill_bytes(&mut nonce_bytes);
+
+    let cipher = Aes256Gcm::new_from_slice(key)
+        .map_err(|e| NebulaError::Internal(format!("创建加密器失败: {}", e)))?;
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce_bytes), plaintext)
+        .map_err(|e| NebulaError::Internal(format!("加密失败: {}", e)))?;
+
+    let mut data = nonce_bytes.to_vec();
+    data.extend(ciphertext);
+    Ok(data)
+}
+
+/// 解密 [`seal`] 生成的数据
+pub(crate) fn unseal(key: &MasterKey, data: &[u8]) -> Result<Vec<u8>> {
+    if data.len() < NONCE_LEN {
+        return Err(NebulaError::Internal("加密数据格式错误".to_string()));
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+
+    let cipher = Aes256Gcm::new_from_slice(key)
+        .map_err(|e| NebulaError::Internal(format!("创建解密器失败: {}", e)))?;
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|e| NebulaError::Internal(format!("解密失败: {}", e)))
+}
+
+/// 用 Argon2id 从口令派生主密钥
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<MasterKey> {
+    let mut key = [0u8; KEY_LEN];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| NebulaError::CredentialError(format!("派生密钥失败: {}", e)))?;
+    Ok(key)
+}
+
+/// 生成随机主密钥
+pub(crate) fn generate_key() -> MasterKey {
+    let mut key = [0u8; KEY_LEN];
+    rand::rng().fill_bytes(&mut key);
+    key
+}
+
+// ===== 密钥存储 =====
+
+/// 主密钥存储
+///
+/// 内置文件和系统钥匙串两种实现，移动端等环境可以实现该 trait 接入平台的安全存储
+pub trait KeyStore: Send + Sync {
+    /// 读取主密钥，不存在时返回 None
+    fn load(&self) -> Result<Option<MasterKey>>;
+
+    /// 保存主密钥
+    fn store(&self, key: &MasterKey) -> Result<()>;
+
+    /// 删除主密钥
+    fn delete(&self) -> Result<()>;
+}
+
+/// 把主密钥保存在文件中（Unix 上只有当前用户可读）
+pub struct FileKeyStore {
+    path: PathBuf,
+}
+
+impl FileKeyStore {
+    /// 使用指定的密钥文件
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl KeyStore for FileKeyStore {
+    fn load(&self) -> Result<Option<MasterKey>> {
+        match std::fs::read(&self.path) {
+            Ok(data) => match MasterKey::try_from(data.as_slice()) {
+                Ok(key) => Ok(Some(key)),
+                Err(_) => {
+                    warn!("密钥文件格式错误: {:?}", self.path);
+                    Ok(None)
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn store(&self, key: &MasterKey) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, key)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&self.path, std::fs::Permissions::from_mode(0o600))?;
+        }
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<()> {
+        match std::fs::remove_file(&self.path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// 把主密钥保存在系统钥匙串中
+#[cfg(feature = "os-keyring")]
+pub struct KeyringKeyStore {
+    entry: keyring::Entry,
+}
+
+#[cfg(feature = "os-keyring")]
+impl KeyringKeyStore {
+    /// 使用钥匙串中指定账户名的条目
+    pub fn new(account: &str) -> Result<Self> {
+        let entry = keyring::Entry::new(KEYRING_SERVICE, account).map_err(keyring_error)?;
+        Ok(Self { entry })
+    }
+}
+
+#[cfg(feature = "os-keyring")]
+impl KeyStore for KeyringKeyStore {
+    fn load(&self) -> Result<Option<MasterKey>> {
+        match self.entry.get_secret() {
+            Ok(secret) => match MasterKey::try_from(secret.as_slice()) {
+                Ok(key) => Ok(Some(key)),
+                Err(_) => {
+                    warn!("钥匙串中的密钥格式错误");
+                    Ok(None)
+                }
+            },
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(keyring_error(e)),
+        }
+    }
+
+    fn store(&self, key: &MasterKey) -> Result<()> {
+        self.entry.set_secret(key).map_err(keyring_error)
+    }
+
+    fn delete(&self) -> Result<()> {
+        match self.entry.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(keyring_error(e)),
+        }
+    }
+}
+
+#[cfg(feature = "os-keyring")]
+fn keyring_error(e: keyring::Error) -> NebulaError {
+    NebulaError::CredentialError(format!("系统钥匙串不可用: {}", e))
+}
+
+/// 优先使用主存储，不可用时回退到备用存储
+///
+/// 主存储中没有密钥而备用存储中有时，把密钥迁移到主存储并删除备用存储中的副本
+pub struct FallbackKeyStore {
+    primary: Box<dyn KeyStore>,
+    fallback: Box<dyn KeyStore>,
+}
+
+impl FallbackKeyStore {
+    /// 创建带回退的密钥存储
+    pub fn new(primary: Box<dyn KeyStore>, fallback: Box<dyn KeyStore>) -> Self {
+        Self { primary, fallback }
+    }
+}
+
+impl KeyStore for FallbackKeyStore {
+    fn load(&self) -> Result<Option<MasterKey>> {
+        match self.primary.load() {
+            Ok(Some(key)) => Ok(Some(key)),
+            Ok(None) => {
+                let Some(key) = self.fallback.load()? else {
+                    return Ok(None);
+                };
+                // 能从主存储读回后才删除备用存储中的副本
+                match self.primary.store(&key).and_then(|_| self.primary.load()) {
+                    Ok(Some(stored)) if stored == key => {
+                        self.fallback.delete()?;
+                        info!("已迁移主密钥");
+                    }
+                    Ok(_) => warn!("主密钥存储未能保存密钥，继续使用备用存储"),
+                    Err(e) => warn!("迁移主密钥失败，继续使用备用存储: {}", e),
+                }
+                Ok(Some(key))
+            }
+            Err(e) => {
+                warn!("主密钥存储不可用，使用备用存储: {}", e);
+                self.fallback.load()
+            }
+        }
+    }
+
+    fn store(&self, key: &MasterKey) -> Result<()> {
+        self.primary.store(key).or_else(|e| {
+            warn!("主密钥存储不可用，使用备用存储: {}", e);
+            self.fallback.store(key)
+        })
+    }
+
+    fn delete(&self) -> Result<()> {
+        let primary = self.primary.delete();
+        self.fallback.delete()?;
+        primary
+    }
+}
+
+/// 默认的密钥存储
+///
+/// 启用 `os-keyring` 特性时使用系统钥匙串，并以 `<data_dir>/<name>_key.bin` 作为回退
+/// （已有的密钥文件会被迁移到钥匙串）；否则只使用密钥文件，并在日志中警告
+/// 主密钥以明文保存在密文旁边
+pub fn default_key_store(data_dir: &Path, name: &str) -> Arc<dyn KeyStore> {
+    let file = FileKeyStore::new(data_dir.join(format!("{}_key.bin", name)));
+
+    #[cfg(feature = "os-keyring")]
+    match KeyringKeyStore::new(&format!("{}@{}", name, data_dir.display())) {
+        Ok(keyring) => return Arc::new(FallbackKeyStore::new(Box::new(keyring), Box::new(file))),
+        Err(e) => warn!("{}，主密钥将以明文保存在密钥文件中: {:?}", e, file.path),
+    }
+
+    #[cfg(not(feature = "os-keyring"))]
+    warn!(
+        "未启用 os-keyring 特性，主密钥以明文保存在密钥文件中: {:?}（可改用口令加密）",
+        file.path
+    );
+
+    Arc::new(file)
+}
+
+// ===== 凭据 =====
+
+/// 站点凭据
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Credential {
+    /// Cookie，格式与浏览器请求头相同（`name=value; name2=value2`）
+    Cookie { cookies: String },
+    /// Bearer Token
+    Bearer { token: String },
+    /// 用户名和密码（HTTP Basic 认证、FTP 登录、yt-dlp 账号登录）
+    Basic { username: String, password: String },
+    /// 自定义请求头（如 API Key）
+    Header { name: String, value: String },
+}
+
+/// 凭据类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CredentialKind {
+    Cookie,
+    Bearer,
+    Basic,
+    Header,
+}
+
+impl CredentialKind {
+    /// 类型名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Cookie => "cookie",
+            Self::Bearer => "bearer",
+            Self::Basic => "basic",
+            Self::Header => "header",
+        }
+    }
+
+    /// 按名称解析
+    pub fn from_name(name: &str) -> Option<Self> {
+        [Self::Cookie, Self::Bearer, Self::Basic, Self::Header]
+            .into_iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(name))
+    }
+}
+
+impl Credential {
+    /// 凭据类型
+    pub fn kind(&self) -> CredentialKind {
+        match self {
+            Self::Cookie { .. } => CredentialKind::Cookie,
+            Self::Bearer { .. } => CredentialKind::Bearer,
+            Self::Basic { .. } => CredentialKind::Basic,
+            Self::Header { .. } => CredentialKind::Header,
+        }
+    }
+}
+
+/// 调试输出时隐藏密码、Token 等敏感内容
+impl fmt::Debug for Credential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cookie { .. } => f.write_str("Cookie(***)"),
+            Self::Bearer { .. } => f.write_str("Bearer(***)"),
+            Self::Basic { username, .. } => write!(f, "Basic({}:***)", username),
+            Self::Header { name, .. } => write!(f, "Header({}: ***)", name),
+        }
+    }
+}
+
+/// 某个域名的凭据
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SiteCredential {
+    /// 域名（如 `example.com`）
+    pub domain: String,
+
+    /// 是否同时用于子域名
+    #[serde(default = "default_true")]
+    pub include_subdomains: bool,
+
+    /// 凭据内容
+    pub credential: Credential,
+
+    /// 更新时间（Unix 时间戳）
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl SiteCredential {
+    /// 创建域名及其子域名使用的凭据
+    pub fn new(domain: &str, credential: Credential) -> Self {
+        Self {
+            domain: normalize_domain(domain),
+            include_subdomains: true,
+            credential,
+            updated_at: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    /// 设置是否同时用于子域名
+    pub fn with_subdomains(mut self, include: bool) -> Self {
+        self.include_subdomains = include;
+        self
+    }
+
+    /// 检查主机名是否使用该凭据
+    pub fn matches_host(&self, host: &str) -> bool {
+        let host = normalize_domain(host);
+        host == self.domain
+            || (self.include_subdomains
+                && host
+                    .strip_suffix(self.domain.as_str())
+                    .is_some_and(|sub| sub.ends_with('.')))
+    }
+
+    /// 是否与另一条凭据占用同一位置（同一域名的同类凭据，自定义请求头按名称区分）
+    fn same_slot(&self, other: &SiteCredential) -> bool {
+        if self.domain != other.domain {
+            return false;
+        }
+        match (&self.credential, &other.credential) {
+            (Credential::Header { name: a, .. }, Credential::Header { name: b, .. }) => {
+                a.eq_ignore_ascii_case(b)
+            }
+            (a, b) => a.kind() == b.kind(),
+        }
+    }
+}
+
+/// 规范化域名：去掉首尾的点并转为小写
+fn normalize_domain(domain: &str) -> String {
+    domain.trim().trim_matches('.').to_ascii_lowercase()
+}
+
+/// 传给 yt-dlp 的凭据
+///
+/// 凭据写在临时目录中（仅当前用户可读），释放时删除，
+/// 调用方需要持有它直到 yt-dlp 进程退出
+#[derive(Debug, Default)]
+pub struct YtDlpCredentials {
+    /// Netscape 格式的 cookies 文件
+    pub cookies_file: Option<PathBuf>,
+    /// 其他参数（`--config-locations` 指向包含账号密码的配置文件）
+    pub args: Vec<String>,
+    /// 临时目录
+    temp_dir: Option<PathBuf>,
+}
+
+impl Drop for YtDlpCredentials {
+    fn drop(&mut self) {
+        if let Some(dir) = self.temp_dir.take() {
+            match std::fs::remove_dir_all(&dir) {
+                Ok(()) => debug!("已删除 yt-dlp 临时凭据: {:?}", dir),
+                Err(e) => warn!("删除 yt-dlp 临时凭据失败: {:?}: {}", dir, e),
+            }
+        }
+    }
+}
+
+// ===== 保险库 =====
+
+/// 主密钥来源
+pub enum VaultKey {
+    /// 由口令派生
+    Passphrase(String),
+    /// 从密钥存储读取，不存在时生成并保存
+    Store(Arc<dyn KeyStore>),
+}
+
+/// 已解锁的保险库
+struct VaultState {
+    key: MasterKey,
+    /// 口令派生密钥时使用的盐
+    salt: Option<[u8; SALT_LEN]>,
+    entries: Vec<SiteCredential>,
+}
+
+/// 站点凭据保险库
+///
+/// 创建后处于锁定状态，调用 [`unlock`](Self::unlock) 后才能读写凭据；
+/// 锁定时查询凭据返回空结果，下载任务按未登录处理
+pub struct CredentialVault {
+    /// 数据目录
+    dir: PathBuf,
+    /// 解锁后的状态
+    state: RwLock<Option<VaultState>>,
+}
+
+impl CredentialVault {
+    /// 创建保险库（锁定状态），凭据保存在 `<data_dir>/credentials.enc`
+    pub fn new(data_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: data_dir.into(),
+            state: RwLock::new(None),
+        }
+    }
+
+    fn path(&self) -> PathBuf {
+        self.dir.join(VAULT_FILENAME)
+    }
+
+    /// 是否已解锁
+    pub async fn is_unlocked(&self) -> bool {
+        self.state.read().await.is_some()
+    }
+
+    /// 已保存的凭据是否使用口令加密
+    pub async fn requires_passphrase(&self) -> bool {
+        match fs::read(self.path()).await {
+            Ok(data) => parse_header(&data).is_ok_and(|(mode, _, _)| mode == MODE_PASSPHRASE),
+            Err(_) => false,
+        }
+    }
+
+    /// 解锁保险库
+    ///
+    /// 凭据文件不存在时用给定的密钥来源新建；已存在时密钥来源必须与保存时一致
+    pub async fn unlock(&self, key: VaultKey) -> Result<()> {
+        let data = match fs::read(self.path()).await {
+            Ok(data) => Some(data),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        let state = match data {
+            None => {
+                let (key, salt) = new_master_key(key)?;
+                let state = VaultState {
+                    key,
+                    salt,
+                    entries: vec![],
+                };
+                self.save(&state).await?;
+                info!("已创建凭据保险库");
+                state
+            }
+            Some(data) => {
+                let (mode, salt, sealed) = parse_header(&data)?;
+                let (key, salt) = match (mode, key) {
+                    (MODE_PASSPHRASE, VaultKey::Passphrase(passphrase)) => {
+                        (derive_key(&passphrase, &salt)?, Some(salt))
+                    }
+                    (MODE_PASSPHRASE, VaultKey::Store(_)) => {
+                        return Err(NebulaError::CredentialError(
+                            "凭据使用口令加密，请输入口令解锁".to_string(),
+                        ))
+                    }
+                    (_, VaultKey::Store(store)) => {
+                        let key = store.load()?.ok_or_else(|| {
+                            NebulaError::CredentialError("找不到主密钥，无法解密凭据".to_string())
+                        })?;
+                        (key, None)
+                    }
+                    (_, VaultKey::Passphrase(_)) => {
+                        return Err(NebulaError::CredentialError(
+                            "凭据未使用口令加密".to_string(),
+                        ))
+                    }
+                };
+                let plaintext = unseal(&key, sealed).map_err(|_| {
+                    NebulaError::CredentialError("口令错误或凭据文件已损坏".to_string())
+                })?;
+                let entries = serde_json::from_slice(&plaintext)
+                    .map_err(|e| NebulaError::CredentialError(format!("解析凭据失败: {}", e)))?;
+                VaultState { key, salt, entries }
+            }
+        };
+
+        *self.state.write().await = Some(state);
+        debug!("凭据保险库已解锁");
+        Ok(())
+    }
+
+    /// 锁定保险库，清除内存中的密钥和凭据
+    pub async fn lock(&self) {
+        *self.state.write().await = None;
+    }
+
+    /// 更换主密钥（如设置或修改口令），用新密钥重新加密所有凭据
+    pub async fn change_key(&self, key: VaultKey) -> Result<()> {
+        let mut guard = self.state.write().await;
+        let state = guard.as_mut().ok_or(NebulaError::VaultLocked)?;
+        let (key, salt) = new_master_key(key)?;
+        state.key = key;
+        state.salt = salt;
+        self.save(state).await?;
+        info!("凭据保险库已更换主密钥");
+        Ok(())
+    }
+
+    /// 所有凭据
+    pub async fn list(&self) -> Result<Vec<SiteCredential>> {
+        let guard = self.state.read().await;
+        let state = guard.as_ref().ok_or(NebulaError::VaultLocked)?;
+        Ok(state.entries.clone())
+    }
+
+    /// 添加凭据，替换同一域名下的同类凭据
+    pub async fn set(&self, mut credential: SiteCredential) -> Result<()> {
+        credential.domain = normalize_domain(&credential.domain);
+        if credential.domain.is_empty() {
+            return Err(NebulaError::CredentialError("域名不能为空".to_string()));
+        }
+
+        let mut guard = self.state.write().await;
+        let state = guard.as_mut().ok_or(NebulaError::VaultLocked)?;
+        state.entries.retain(|entry| !entry.same_slot(&credential));
+        info!(
+            "保存凭据: {} {:?}",
+            credential.domain, credential.credential
+        );
+        state.entries.push(credential);
+        self.save(state).await
+    }
+
+    /// 删除域名的凭据，`kind` 为 None 时删除该域名的所有凭据
+    ///
+    /// # 返回
+    /// 删除的凭据数量
+    pub async fn remove(&self, domain: &str, kind: Option<CredentialKind>) -> Result<usize> {
+        let domain = normalize_domain(domain);
+        let mut guard = self.state.write().await;
+        let state = guard.as_mut().ok_or(NebulaError::VaultLocked)?;
+
+        let before = state.entries.len();
+        state.entries.retain(|entry| {
+            entry.domain != domain || kind.is_some_and(|kind| entry.credential.kind() != kind)
+        });
+        let removed = before - state.entries.len();
+        if removed > 0 {
+            self.save(state).await?;
+        }
+        Ok(removed)
+    }
+
+    /// 链接主机匹配的凭据，域名越具体越靠前；保险库锁定时返回空列表
+    pub async fn matching(&self, url: &str) -> Vec<SiteCredential> {
+        let Some(host) = Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+        else {
+            return vec![];
+        };
+
+        let guard = self.state.read().await;
+        let Some(state) = guard.as_ref() else {
+            return vec![];
+        };
+        let mut entries: Vec<SiteCredential> = state
+            .entries
+            .iter()
+            .filter(|entry| entry.matches_host(&host))
+            .cloned()
+            .collect();
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.domain.len()));
+        entries
+    }
+
+    /// HTTP 请求需要添加的请求头
+    ///
+    /// 所有匹配的 Cookie 合并为一个请求头；Authorization 和自定义请求头取最具体的域名
+    pub async fn http_headers(&self, url: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        let mut cookies = Vec::new();
+        let mut insert = |name: HeaderName, value: String| {
+            if headers.contains_key(&name) {
+                return;
+            }
+            if let Ok(mut value) = HeaderValue::from_str(&value) {
+                value.set_sensitive(true);
+                headers.insert(name, value);
+            }
+        };
+
+        for entry in self.matching(url).await {
+            match entry.credential {
+                Credential::Cookie { cookies: value } => {
+                    cookies.push(value.trim().trim_end_matches(';').to_string())
+                }
+                Credential::Bearer { token } => insert(AUTHORIZATION, format!("Bearer {}", token)),
+                Credential::Basic { username, password } => {
+                    let encoded = base64::engine::general_purpose::STANDARD
+                        .encode(format!("{}:{}", username, password));
+                    insert(AUTHORIZATION, format!("Basic {}", encoded));
+                }
+                Credential::Header { name, value } => {
+                    if let Ok(name) = HeaderName::from_bytes(name.trim().as_bytes()) {
+                        insert(name, value);
+                    }
+                }
+            }
+        }
+        if !cookies.is_empty() {
+            insert(COOKIE, cookies.join("; "));
+        }
+        headers
+    }
+
+    /// FTP 登录使用的用户名和密码，取最具体的域名
+    pub async fn ftp_login(&self, url: &str) -> Option<(String, String)> {
+        self.matching(url)
+            .await
+            .into_iter()
+            .find_map(|entry| match entry.credential {
+                Credential::Basic { username, password } => Some((username, password)),
+                _ => None,
+            })
+    }
+
+    /// yt-dlp 下载该链接时使用的凭据
+    ///
+    /// Cookie 导出为 Netscape 格式的 cookies 文件，用户名密码写入 yt-dlp 配置文件，
+    /// 都不出现在命令行中。yt-dlp 的 `--add-header` 会发往所有主机（包括 CDN），
+    /// 因此 Bearer Token 和自定义请求头不传给 yt-dlp
+    pub async fn yt_dlp_credentials(&self, url: &str) -> Result<YtDlpCredentials> {
+        let mut cookie_lines = Vec::new();
+        let mut login = None;
+
+        for entry in self.matching(url).await {
+            let domain = match entry.include_subdomains {
+                true => format!(".{}", entry.domain),
+                false => entry.domain.clone(),
+            };
+            match entry.credential {
+                Credential::Cookie { cookies } => {
+                    for pair in cookies.split(';') {
+                        if let Some((name, value)) = pair.split_once('=') {
+                            cookie_lines.push(format!(
+                                "{}\t{}\t/\tFALSE\t0\t{}\t{}",
+                                domain,
+                                if entry.include_subdomains {
+                                    "TRUE"
+                                } else {
+                                    "FALSE"
+                                },
+                                name.trim(),
+                                value.trim()
+                            ));
+                        }
+                    }
+                }
+                Credential::Basic { username, password } => {
+                    login.get_or_insert((username, password));
+                }
+                Credential::Bearer { .. } | Credential::Header { .. } => {
+                    debug!("yt-dlp 不支持按域名添加请求头，跳过 {} 的凭据", entry.domain)
+                }
+            }
+        }
+
+        let mut credentials = YtDlpCredentials::default();
+        if cookie_lines.is_empty() && login.is_none() {
+            return Ok(credentials);
+        }
+
+        let dir = self
+            .dir
+            .join(YT_DLP_TEMP_DIR)
+            .join(format!("{:016x}", rand::random::<u64>()));
+        fs::create_dir_all(&dir).await?;
+        credentials.temp_dir = Some(dir.clone());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o700)).await?;
+        }
+
+        if !cookie_lines.is_empty() {
+            let path = dir.join("cookies.txt");
+            let content = format!("# Netscape HTTP Cookie File\n{}\n", cookie_lines.join("\n"));
+            write_private(&path, content.as_bytes()).await?;
+            credentials.cookies_file = Some(path);
+        }
+        if let Some((username, password)) = login {
+            let path = dir.join("login.conf");
+            let content = format!(
+                "--username {}\n--password {}\n",
+                shell_quote(&username),
+                shell_quote(&password)
+            );
+            write_private(&path, content.as_bytes()).await?;
+            credentials.args.extend([
+                "--config-locations".to_string(),
+                path.to_string_lossy().to_string(),
+            ]);
+        }
+        debug!("站点凭据已导出到: {:?}", dir);
+        Ok(credentials)
+    }
+
+    /// 加密并保存凭据（先写临时文件再替换）
+    async fn save(&self, state: &VaultState) -> Result<()> {
+        let plaintext = serde_json::to_vec(&state.entries)
+            .map_err(|e| NebulaError::CredentialError(format!("序列化凭据失败: {}", e)))?;
+
+        let mut data = VAULT_MAGIC.to_vec();
+        match state.salt {
+            Some(salt) => {
+                data.push(MODE_PASSPHRASE);
+                data.extend(salt);
+            }
+            None => {
+                data.push(MODE_KEY_STORE);
+                data.extend([0u8; SALT_LEN]);
+            }
+        }
+        data.extend(seal(&state.key, &plaintext)?);
+
+        let path = self.path();
+        let temp = path.with_extension("enc.tmp");
+        write_private(&temp, &data).await?;
+        fs::rename(&temp, &path).await?;
+        Ok(())
+    }
+}
+
+/// 按密钥来源生成新的主密钥
+fn new_master_key(key: VaultKey) -> Result<(MasterKey, Option<[u8; SALT_LEN]>)> {
+    match key {
+        VaultKey::Passphrase(passphrase) => {
+            if passphrase.is_empty() {
+                return Err(NebulaError::CredentialError("口令不能为空".to_string()));
+            }
+            let mut salt = [0u8; SALT_LEN];
+            rand::rng().fill_bytes(&mut salt);
+            Ok((derive_key(&passphrase, &salt)?, Some(salt)))
+        }
+        VaultKey::Store(store) => {
+            let key = match store.load()? {
+                Some(key) => key,
+                None => {
+                    let key = generate_key();
+                    store.store(&key)?;
+                    key
+                }
+            };
+            Ok((key, None))
+        }
+    }
+}
+
+/// 解析凭据文件头，返回主密钥来源、盐和加密数据
+fn parse_header(data: &[u8]) -> Result<(u8, [u8; SALT_LEN], &[u8])> {
+    let header_len = VAULT_MAGIC.len() + 1 + SALT_LEN;
+    if data.len() < header_len || !data.starts_with(VAULT_MAGIC) {
+        return Err(NebulaError::CredentialError("凭据文件格式错误".to_string()));
+    }
+    let mode = data[VAULT_MAGIC.len()];
+    let mut salt = [0u8; SALT_LEN];
+    salt.copy_from_slice(&data[VAULT_MAGIC.len() + 1..header_len]);
+    Ok((mode, salt, &data[header_len..]))
+}
+
+/// 按 POSIX shell 规则加单引号（yt-dlp 用 shlex 解析配置文件）
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// 写入只有当前用户可读的文件
+async fn write_private(path: &Path, data: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    fs::write(path, data).await?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, std::fs::Permissions::from_mode(0o600)).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_vault_unlock() {
+        let dir = tempfile::tempdir().unwrap();
+
+        // 口令加密：错误口令无法解锁
+        let vault = CredentialVault::new(dir.path());
+        vault
+            .unlock(VaultKey::Passphrase("correct horse".to_string()))
+            .await
+            .unwrap();
+        vault
+            .set(SiteCredential::new(
+                ".Example.COM",
+                Credential::Bearer {
+                    token: "t0ken".to_string(),
+                },
+            ))
+            .await
+            .unwrap();
+
+        let reopened = CredentialVault::new(dir.path());
+        assert!(reopened.requires_passphrase().await);
+        assert!(reopened
+            .unlock(VaultKey::Passphrase("wrong".to_string()))
+            .await
+            .is_err());
+        assert!(reopened
+            .http_headers("https://example.com/")
+            .await
+            .is_empty());
+        reopened
+            .unlock(VaultKey::Passphrase("correct horse".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(reopened.list().await.unwrap()[0].domain, "example.com");
+
+        // 改为密钥存储后不再需要口令
+        let store: Arc<dyn KeyStore> = Arc::new(FileKeyStore::new(dir.path().join("k.bin")));
+        reopened
+            .change_key(VaultKey::Store(Arc::clone(&store)))
+            .await
+            .unwrap();
+        let reopened = CredentialVault::new(dir.path());
+        assert!(!reopened.requires_passphrase().await);
+        reopened.unlock(VaultKey::Store(store)).await.unwrap();
+        assert_eq!(reopened.list().await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_fallback_key_store_migrates() {
+        let dir = tempfile::tempdir().unwrap();
+        let legacy = FileKeyStore::new(dir.path().join("legacy.bin"));
+        let key = generate_key();
+        legacy.store(&key).unwrap();
+
+        let store = FallbackKeyStore::new(
+            Box::new(FileKeyStore::new(dir.path().join("primary.bin"))),
+            Box::new(FileKeyStore::new(dir.path().join("legacy.bin"))),
+        );
+        assert_eq!(store.load().unwrap(), Some(key));
+        assert!(!dir.path().join("legacy.bin").exists());
+        assert_eq!(
+            FileKeyStore::new(dir.path().join("primary.bin"))
+                .load()
+                .unwrap(),
+            Some(key)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_credential_injection() {
+        let dir = tempfile::tempdir().unwrap();
+        let vault = CredentialVault::new(dir.path());
+        let store: Arc<dyn KeyStore> = Arc::new(FileKeyStore::new(dir.path().join("k.bin")));
+        vault.unlock(VaultKey::Store(store)).await.unwrap();
+
+        let basic = |user: &str| Credential::Basic {
+            username: user.to_string(),
+            password: "p@ss".to_string(),
+        };
+        let cookie = |cookies: &str| Credential::Cookie {
+            cookies: cookies.to_string(),
+        };
+        vault
+            .set(SiteCredential::new("example.com", basic("outer")))
+            .await
+            .unwrap();
+        vault
+            .set(SiteCredential::new("files.example.com", basic("inner")))
+            .await
+            .unwrap();
+        vault
+            .set(SiteCredential::new("example.com", cookie("a=1; b=2;")))
+            .await
+            .unwrap();
+        vault
+            .set(SiteCredential::new("cdn.example.com", cookie("c=3")).with_subdomains(false))
+            .await
+            .unwrap();
+        // 同一域名的同类凭据被替换
+        vault
+            .set(SiteCredential::new("example.com", basic("user")))
+            .await
+            .unwrap();
+        assert_eq!(vault.list().await.unwrap().len(), 4);
+
+        let headers = vault.http_headers("https://files.example.com/a.zip").await;
+        assert_eq!(headers[AUTHORIZATION], "Basic aW5uZXI6cEBzcw==");
+        assert_eq!(headers[COOKIE], "a=1; b=2");
+        assert_eq!(
+            vault.ftp_login("ftp://files.example.com/pub/a.iso").await,
+            Some(("inner".to_string(), "p@ss".to_string()))
+        );
+        let headers = vault.http_headers("https://cdn.example.com/x").await;
+        assert_eq!(headers[COOKIE], "c=3; a=1; b=2");
+        assert!(vault
+            .http_headers("https://x.cdn.example.com/")
+            .await
+            .get(COOKIE)
+            .is_some());
+        assert!(vault
+            .http_headers("https://notexample.com/")
+            .await
+            .is_empty());
+
+        let yt_dlp = vault
+            .yt_dlp_credentials("https://www.example.com/v/1")
+            .await
+            .unwrap();
+        assert_eq!(yt_dlp.args[0], "--config-locations");
+        assert!(!yt_dlp.args.iter().any(|arg| arg.contains("p@ss")));
+        let config = std::fs::read_to_string(&yt_dlp.args[1]).unwrap();
+        assert_eq!(config, "--username 'user'\n--password 'p@ss'\n");
+        let cookies_file = yt_dlp.cookies_file.clone().unwrap();
+        let cookies = std::fs::read_to_string(&cookies_file).unwrap();
+        assert!(cookies.contains(".example.com\tTRUE\t/\tFALSE\t0\tb\t2"));
+        // 释放后删除临时文件
+        drop(yt_dlp);
+        assert!(!cookies_file.exists());
+        assert!(std::fs::read_dir(dir.path().join(YT_DLP_TEMP_DIR))
+            .unwrap()
+            .next()
+            .is_none());
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
+
+        assert_eq!(
+            vault
+                .remove("EXAMPLE.com", Some(CredentialKind::Cookie))
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(vault.remove("example.com", None).await.unwrap(), 1);
+        vault.lock().await;
+        assert!(matches!(vault.list().await, Err(NebulaError::VaultLocked)));
+    }
+}
@@ -235,7 +235,9 @@ class _DownloadsPageState extends State<DownloadsPage> {
             task: task,
             onPause: () => api.pauseDownload(taskId: task.id),
             onResume: () => api.resumeDownload(taskId: task.id),
-            onCancel: () => api.cancelDownload(taskId: task.id, deleteFiles: true),
+            onCancel: () => context
+                .read<DownloadProvider>()
+                .removeTask(task.id, deleteFile: true),
           ),
         );
       },
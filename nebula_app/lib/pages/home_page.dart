@@ -238,6 +238,12 @@ class _HomePageState extends State<HomePage> {
   Future<void> _cancelTask(String taskId) async {
     try {
       await cancelDownload(taskId: taskId, deleteFiles: false);
+    } catch (e) {
+      // 已结束的任务无法取消，直接移除
+      debugPrint('取消任务失败: $e');
+    }
+    try {
+      await removeDownload(taskId: taskId);
     } catch (e) {
       _showError(e.toString());
     }
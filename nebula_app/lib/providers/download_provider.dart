@@ -181,7 +181,14 @@ class DownloadProvider extends ChangeNotifier {
     }
   }
   
+  /// 取消任务并从列表中移除（移除后会收到 TaskRemoved 事件）
   Future<void> removeTask(String taskId, {bool deleteFile = false}) async {
-    await cancelDownload(taskId: taskId, deleteFiles: deleteFile);
+    try {
+      await cancelDownload(taskId: taskId, deleteFiles: deleteFile);
+    } catch (e) {
+      // 已结束的任务无法取消，直接移除
+      debugPrint('取消任务失败: $e');
+    }
+    await removeDownload(taskId: taskId);
   }
 }
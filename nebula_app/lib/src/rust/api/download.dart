@@ -29,19 +29,43 @@ Future<String> addDownload({
   savePath: savePath,
 );
 
-/// 添加视频下载任务（指定画质）
+/// 导入已有数据添加种子任务
+///
+/// 引擎会逐块校验 `data_dir` 中的已有文件，只下载缺失或损坏的部分
+Future<String> addDownloadWithExistingData({
+  required String source,
+  required String dataDir,
+}) => RustLib.instance.api.crateApiDownloadAddDownloadWithExistingData(
+  source: source,
+  dataDir: dataDir,
+);
+
+/// 添加下载任务，并指定遇到重复任务时的处理方式
+Future<String> addDownloadWithPolicy({
+  required String source,
+  required String savePath,
+  required DuplicateAction onDuplicate,
+}) => RustLib.instance.api.crateApiDownloadAddDownloadWithPolicy(
+  source: source,
+  savePath: savePath,
+  onDuplicate: onDuplicate,
+);
+
+/// 添加视频下载任务（指定画质和附加内容）
 Future<String> addVideoDownload({
   required String url,
   required String savePath,
   String? formatId,
   String? title,
   String? thumbnail,
+  VideoDownloadOptions? options,
 }) => RustLib.instance.api.crateApiDownloadAddVideoDownload(
   url: url,
   savePath: savePath,
   formatId: formatId,
   title: title,
   thumbnail: thumbnail,
+  options: options,
 );
 
 /// 暂停下载任务
@@ -61,6 +85,18 @@ Future<void> cancelDownload({
   deleteFiles: deleteFiles,
 );
 
+/// 重新开始已完成或失败的任务
+Future<void> restartDownload({required String taskId}) =>
+    RustLib.instance.api.crateApiDownloadRestartDownload(taskId: taskId);
+
+/// 从列表中移除已结束的任务
+Future<void> removeDownload({required String taskId}) =>
+    RustLib.instance.api.crateApiDownloadRemoveDownload(taskId: taskId);
+
+/// 强制重新校验种子任务的数据
+Future<void> recheckDownload({required String taskId}) =>
+    RustLib.instance.api.crateApiDownloadRecheckDownload(taskId: taskId);
+
 /// 订阅下载事件流
 ///
 /// 返回一个 Stream，用于接收下载进度和状态变化
@@ -68,15 +104,159 @@ Future<Stream<NebulaEvent>> subscribeEvents() =>
     RustLib.instance.api.crateApiDownloadSubscribeEvents();
 
 /// 检查 URL 是否为视频网站
+///
+/// 下载管理器初始化后使用配置的站点规则，否则使用内置规则
 Future<bool> isVideoUrl({required String url}) =>
     RustLib.instance.api.crateApiDownloadIsVideoUrl(url: url);
 
+/// 询问 yt-dlp 由哪个提取器处理该链接
+///
+/// 返回提取器名称，yt-dlp 无法识别时返回 None
+Future<String?> probeVideoExtractor({required String url}) =>
+    RustLib.instance.api.crateApiDownloadProbeVideoExtractor(url: url);
+
 /// 获取视频信息
 ///
-/// 需要系统已安装 yt-dlp
+/// 需要已安装 yt-dlp
 Future<VideoInfo> getVideoInfo({required String url}) =>
     RustLib.instance.api.crateApiDownloadGetVideoInfo(url: url);
 
+/// 展开播放列表，列出其中的条目
+Future<PlaylistInfo> getPlaylistInfo({required String url}) =>
+    RustLib.instance.api.crateApiDownloadGetPlaylistInfo(url: url);
+
+/// 添加播放列表下载任务
+///
+/// `items` 为要下载的条目，如 `1-3,5,8-`；`only_new` 时跳过以前下载过的条目
+Future<String> addPlaylistDownload({
+  required String url,
+  required String savePath,
+  String? items,
+  String? formatId,
+  required bool onlyNew,
+  VideoDownloadOptions? options,
+}) => RustLib.instance.api.crateApiDownloadAddPlaylistDownload(
+  url: url,
+  savePath: savePath,
+  items: items,
+  formatId: formatId,
+  onlyNew: onlyNew,
+  options: options,
+);
+
+/// 获取种子任务的 Tracker 列表
+///
+/// `refresh` 为 true 时会立即向所有 Tracker 重新查询
+Future<List<TrackerInfo>> getTorrentTrackers({
+  required String taskId,
+  required bool refresh,
+}) => RustLib.instance.api.crateApiDownloadGetTorrentTrackers(
+  taskId: taskId,
+  refresh: refresh,
+);
+
+/// 获取种子任务当前连接的 Peer 列表
+Future<List<PeerInfo>> getTorrentPeers({required String taskId}) =>
+    RustLib.instance.api.crateApiDownloadGetTorrentPeers(taskId: taskId);
+
+/// 为种子任务添加 Tracker
+Future<void> addTorrentTrackers({
+  required String taskId,
+  required List<String> urls,
+}) => RustLib.instance.api.crateApiDownloadAddTorrentTrackers(
+  taskId: taskId,
+  urls: urls,
+);
+
+/// 从种子任务移除 Tracker
+Future<void> removeTorrentTrackers({
+  required String taskId,
+  required List<String> urls,
+}) => RustLib.instance.api.crateApiDownloadRemoveTorrentTrackers(
+  taskId: taskId,
+  urls: urls,
+);
+
+/// 获取 IP 过滤统计（未启用时返回 None）
+Future<IpFilterInfo?> getIpFilterStats() =>
+    RustLib.instance.api.crateApiDownloadGetIpFilterStats();
+
+/// 立即更新 IP 过滤订阅列表
+Future<IpFilterInfo> updateIpFilter() =>
+    RustLib.instance.api.crateApiDownloadUpdateIpFilter();
+
+/// 获取已找到的外部工具（未找到的工具不包含在结果中）
+Future<List<ToolStatusInfo>> getToolsStatus() =>
+    RustLib.instance.api.crateApiDownloadGetToolsStatus();
+
+/// 检查 yt-dlp 是否有新版本
+Future<ToolUpdateInfo> checkYtDlpUpdate() =>
+    RustLib.instance.api.crateApiDownloadCheckYtDlpUpdate();
+
+/// 下载并安装最新版 yt-dlp
+Future<ToolStatusInfo> updateYtDlp() =>
+    RustLib.instance.api.crateApiDownloadUpdateYtDlp();
+
+/// 从本地文件或目录制作种子
+///
+/// `piece_size` 为 None 时自动选择；`seed` 为 true 时制作完成后立即做种
+Future<CreatedTorrentInfo> createTorrent({
+  required String input,
+  required String torrentPath,
+  BigInt? pieceSize,
+  required bool private,
+  required List<String> trackers,
+  required List<String> webSeeds,
+  String? comment,
+  required bool seed,
+}) => RustLib.instance.api.crateApiDownloadCreateTorrent(
+  input: input,
+  torrentPath: torrentPath,
+  pieceSize: pieceSize,
+  private: private,
+  trackers: trackers,
+  webSeeds: webSeeds,
+  comment: comment,
+  seed: seed,
+);
+
+/// 仅解析种子元数据，不开始下载
+///
+/// 磁力链接需要从 Peer 获取元数据，可能耗时较长
+Future<ResolvedTorrentInfo> resolveTorrent({required String source}) =>
+    RustLib.instance.api.crateApiDownloadResolveTorrent(source: source);
+
+/// 添加种子任务，只下载选中的文件
+///
+/// `file_indices` 为 [`resolve_torrent`] 返回的文件序号
+Future<String> addDownloadWithFiles({
+  required String source,
+  required String savePath,
+  required Uint64List fileIndices,
+}) => RustLib.instance.api.crateApiDownloadAddDownloadWithFiles(
+  source: source,
+  savePath: savePath,
+  fileIndices: fileIndices,
+);
+
+/// 将已解析的元数据导出为 .torrent 文件
+Future<void> exportResolvedTorrent({
+  required String source,
+  required String torrentPath,
+}) => RustLib.instance.api.crateApiDownloadExportResolvedTorrent(
+  source: source,
+  torrentPath: torrentPath,
+);
+
+/// 将种子任务导出为 .torrent 文件
+Future<void> exportTorrent({
+  required String taskId,
+  required String torrentPath,
+}) => RustLib.instance.api.crateApiDownloadExportTorrent(
+  taskId: taskId,
+  torrentPath: torrentPath,
+);
+
 /// 生成 Bilibili 登录二维码
 Future<BilibiliQrCode> generateBilibiliQrcode({required String dataDir}) =>
     RustLib.instance.api.crateApiDownloadGenerateBilibiliQrcode(
@@ -100,6 +280,54 @@ Future<bool> isBilibiliLoggedIn({required String dataDir}) =>
 Future<void> logoutBilibili({required String dataDir}) =>
     RustLib.instance.api.crateApiDownloadLogoutBilibili(dataDir: dataDir);
 
+/// 获取 Bilibili 账号信息（昵称、大会员状态、头像），未登录时返回 None
+Future<BilibiliAccountInfo?> getBilibiliAccount({required String dataDir}) =>
+    RustLib.instance.api.crateApiDownloadGetBilibiliAccount(dataDir: dataDir);
+
+/// 检查 Bilibili 登录状态，Cookie 临近过期时自动刷新
+///
+/// 下载管理器已初始化时，登录失效还会通过事件流发送 `BilibiliLoginExpired`
+Future<BilibiliSessionStatus> refreshBilibiliSession({
+  required String dataDir,
+}) => RustLib.instance.api.crateApiDownloadRefreshBilibiliSession(
+  dataDir: dataDir,
+);
+
+/// 获取凭据保险库状态
+Future<CredentialVaultStatus> getCredentialVaultStatus() =>
+    RustLib.instance.api.crateApiDownloadGetCredentialVaultStatus();
+
+/// 用口令解锁凭据保险库（还没有保存过凭据时用该口令新建）
+Future<void> unlockCredentialVault({required String passphrase}) => RustLib
+    .instance
+    .api
+    .crateApiDownloadUnlockCredentialVault(passphrase: passphrase);
+
+/// 列出已保存的站点凭据
+Future<List<SiteCredentialInfo>> listSiteCredentials() =>
+    RustLib.instance.api.crateApiDownloadListSiteCredentials();
+
+/// 保存站点凭据，替换同一域名下的同类凭据
+Future<void> setSiteCredential({
+  required String domain,
+  required bool includeSubdomains,
+  required SiteCredentialInput credential,
+}) => RustLib.instance.api.crateApiDownloadSetSiteCredential(
+  domain: domain,
+  includeSubdomains: includeSubdomains,
+  credential: credential,
+);
+
+/// 删除站点凭据，`kind` 为空时删除该域名的所有凭据
+///
+/// # 返回
+/// 删除的凭据数量
+Future<int> removeSiteCredential({required String domain, String? kind}) =>
+    RustLib.instance.api.crateApiDownloadRemoveSiteCredential(
+      domain: domain,
+      kind: kind,
+    );
+
 /// 打开任务对应的文件
 Future<void> openFile({required String taskId}) =>
     RustLib.instance.api.crateApiDownloadOpenFile(taskId: taskId);
@@ -108,6 +336,21 @@ Future<void> openFile({required String taskId}) =>
 Future<void> openFolder({required String taskId}) =>
     RustLib.instance.api.crateApiDownloadOpenFolder(taskId: taskId);
 
+/// Bilibili 账号信息
+@freezed
+sealed class BilibiliAccountInfo with _$BilibiliAccountInfo {
+  const factory BilibiliAccountInfo({
+    required BigInt mid,
+    required String username,
+    required String avatar,
+    required int level,
+    required bool vipActive,
+    required int vipType,
+    PlatformInt64? vipExpiresAt,
+    String? vipLabel,
+  }) = _BilibiliAccountInfo;
+}
+
 @freezed
 sealed class BilibiliLoginStatus with _$BilibiliLoginStatus {
   const BilibiliLoginStatus._();
@@ -140,6 +383,68 @@ sealed class BilibiliQrCode with _$BilibiliQrCode {
   }) = _BilibiliQrCode;
 }
 
+/// Bilibili 登录会话状态
+enum BilibiliSessionStatus {
+  /// 未登录
+  notLoggedIn,
+
+  /// Cookie 有效
+  valid,
+
+  /// Cookie 已刷新
+  refreshed,
+
+  /// 登录已失效，需要重新扫码登录
+  expired,
+}
+
+/// 制作完成的种子信息
+@freezed
+sealed class CreatedTorrentInfo with _$CreatedTorrentInfo {
+  const factory CreatedTorrentInfo({
+    required String name,
+    required String infoHash,
+    required String magnet,
+    required String torrentPath,
+    required BigInt totalSize,
+    required BigInt pieceSize,
+    required BigInt pieceCount,
+    required BigInt fileCount,
+    String? seedTaskId,
+  }) = _CreatedTorrentInfo;
+}
+
+/// 凭据保险库状态
+@freezed
+sealed class CredentialVaultStatus with _$CredentialVaultStatus {
+  const factory CredentialVaultStatus({
+    required bool unlocked,
+    required bool requiresPassphrase,
+  }) = _CredentialVaultStatus;
+}
+
+/// 重复任务处理策略
+enum DuplicateAction {
+  /// 拒绝添加，返回错误
+  reject,
+
+  /// 返回已有任务的 ID
+  returnExisting,
+
+  /// 取消已有任务后重新开始（保留已下载的文件）
+  restart,
+}
+
+/// IP 过滤统计
+@freezed
+sealed class IpFilterInfo with _$IpFilterInfo {
+  const factory IpFilterInfo({
+    required BigInt rangeCount,
+    required BigInt blockedHandoffPeers,
+    PlatformInt64? lastUpdated,
+  }) = _IpFilterInfo;
+}
+
 @freezed
 sealed class NebulaEvent with _$NebulaEvent {
   const NebulaEvent._();
@@ -172,12 +477,104 @@ sealed class NebulaEvent with _$NebulaEvent {
     required String name,
     required BigInt totalSize,
     required BigInt fileCount,
+    String? mimeType,
+    required bool supportsResume,
+    String? thumbnail,
+    BigInt? durationSecs,
   }) = NebulaEvent_MetadataReceived;
   const factory NebulaEvent.peerUpdate({
     required String taskId,
     required BigInt connectedPeers,
     required BigInt totalPeers,
   }) = NebulaEvent_PeerUpdate;
+  const factory NebulaEvent.checkProgress({
+    required String taskId,
+    required BigInt checkedBytes,
+    required BigInt totalBytes,
+  }) = NebulaEvent_CheckProgress;
+  const factory NebulaEvent.checkCompleted({
+    required String taskId,
+    required BigInt validBytes,
+    required BigInt totalBytes,
+  }) = NebulaEvent_CheckCompleted;
+
+  /// 长时间没有进展；`phase` 为 "metadata" 或 "payload"
+  const factory NebulaEvent.taskStalled({
+    required String taskId,
+    required String phase,
+    required BigInt stalledSecs,
+    BigInt? dhtNodes,
+    required BigInt trackersTotal,
+    required BigInt trackersReached,
+    required BigInt peersSeen,
+    required BigInt peersConnected,
+  }) = NebulaEvent_TaskStalled;
+  const factory NebulaEvent.stallRecovered({
+    required String taskId,
+    required String phase,
+  }) = NebulaEvent_StallRecovered;
+
+  /// 视频进入新阶段；`stage` 为 "video"、"audio"、"merging"、"extracting_audio" 或 "post_processing"
+  const factory NebulaEvent.videoStageChanged({
+    required String taskId,
+    required String stage,
+  }) = NebulaEvent_VideoStageChanged;
+
+  /// 最终输出文件已确定
+  const factory NebulaEvent.outputFileResolved({
+    required String taskId,
+    required String path,
+  }) = NebulaEvent_OutputFileResolved;
+
+  /// 任务状态变化，`from`/`to` 为状态名（如 "downloading"、"paused"）
+  const factory NebulaEvent.statusChanged({
+    required String taskId,
+    required String from,
+    required String to,
+  }) = NebulaEvent_StatusChanged;
+
+  /// Bilibili 登录已失效，需要重新扫码登录
+  const factory NebulaEvent.bilibiliLoginExpired({required String reason}) =
+      NebulaEvent_BilibiliLoginExpired;
+}
+
+/// 已连接 Peer 的信息
+@freezed
+sealed class PeerInfo with _$PeerInfo {
+  const factory PeerInfo({
+    required String address,
+    String? client,
+    required String flags,
+    required BigInt downloadSpeed,
+    required BigInt uploadSpeed,
+    required BigInt downloaded,
+    required BigInt uploaded,
+    double? progress,
+  }) = _PeerInfo;
+}
+
+/// 播放列表条目
+@freezed
+sealed class PlaylistEntryInfo with _$PlaylistEntryInfo {
+  const factory PlaylistEntryInfo({
+    required int index,
+    required String id,
+    required String title,
+    required String url,
+    BigInt? duration,
+    String? uploader,
+  }) = _PlaylistEntryInfo;
+}
+
+/// 播放列表信息（播放列表、频道、合集或多 P 视频）
+@freezed
+sealed class PlaylistInfo with _$PlaylistInfo {
+  const factory PlaylistInfo({
+    required String id,
+    required String title,
+    String? uploader,
+    required List<PlaylistEntryInfo> entries,
+  }) = _PlaylistInfo;
 }
 
 /// 进度事件（传递给 Dart）
@@ -193,6 +590,139 @@ sealed class ProgressEvent with _$ProgressEvent {
   }) = _ProgressEvent;
 }
 
+/// 解析出的种子元数据
+@freezed
+sealed class ResolvedTorrentInfo with _$ResolvedTorrentInfo {
+  const factory ResolvedTorrentInfo({
+    required String name,
+    required String infoHash,
+    required BigInt totalSize,
+    required BigInt pieceSize,
+    required bool private,
+    required List<TorrentFileInfo> files,
+  }) = _ResolvedTorrentInfo;
+}
+
+/// 已保存的站点凭据（不包含密码、Token 等敏感内容）
+@freezed
+sealed class SiteCredentialInfo with _$SiteCredentialInfo {
+  const factory SiteCredentialInfo({
+    required String domain,
+    required bool includeSubdomains,
+    required String kind,
+    String? username,
+    String? headerName,
+    required PlatformInt64 updatedAt,
+  }) = _SiteCredentialInfo;
+}
+
+@freezed
+sealed class SiteCredentialInput with _$SiteCredentialInput {
+  const SiteCredentialInput._();
+
+  /// Cookie，格式为 `name=value; name2=value2`
+  const factory SiteCredentialInput.cookie({required String cookies}) =
+      SiteCredentialInput_Cookie;
+
+  /// Bearer Token
+  const factory SiteCredentialInput.bearer({required String token}) =
+      SiteCredentialInput_Bearer;
+
+  /// 用户名和密码（HTTP Basic 认证、FTP 登录、yt-dlp 账号登录）
+  const factory SiteCredentialInput.basic({
+    required String username,
+    required String password,
+  }) = SiteCredentialInput_Basic;
+
+  /// 自定义请求头
+  const factory SiteCredentialInput.header({
+    required String name,
+    required String value,
+  }) = SiteCredentialInput_Header;
+}
+
+/// 外部工具（yt-dlp、ffmpeg）信息
+@freezed
+sealed class ToolStatusInfo with _$ToolStatusInfo {
+  const factory ToolStatusInfo({
+    required String name,
+    required String path,
+    String? version,
+    required String source,
+  }) = _ToolStatusInfo;
+}
+
+/// yt-dlp 更新检查结果
+@freezed
+sealed class ToolUpdateInfo with _$ToolUpdateInfo {
+  const factory ToolUpdateInfo({
+    String? current,
+    required String latest,
+    required bool updateAvailable,
+  }) = _ToolUpdateInfo;
+}
+
+/// 种子中的文件
+@freezed
+sealed class TorrentFileInfo with _$TorrentFileInfo {
+  const factory TorrentFileInfo({
+    required BigInt index,
+    required String path,
+    required BigInt size,
+  }) = _TorrentFileInfo;
+}
+
+/// 种子的 Tracker 信息
+@freezed
+sealed class TrackerInfo with _$TrackerInfo {
+  const factory TrackerInfo({
+    required String url,
+    required String source,
+    required TrackerStatusInfo status,
+    int? seeders,
+    int? leechers,
+    int? completed,
+    PlatformInt64? lastUpdated,
+  }) = _TrackerInfo;
+}
+
+@freezed
+sealed class TrackerStatusInfo with _$TrackerStatusInfo {
+  const TrackerStatusInfo._();
+
+  const factory TrackerStatusInfo.notContacted() =
+      TrackerStatusInfo_NotContacted;
+  const factory TrackerStatusInfo.updating() = TrackerStatusInfo_Updating;
+  const factory TrackerStatusInfo.working() = TrackerStatusInfo_Working;
+  const factory TrackerStatusInfo.error({required String message}) =
+      TrackerStatusInfo_Error;
+}
+
+/// 视频附加内容选项（字幕、弹幕、封面、元数据）
+@freezed
+sealed class VideoDownloadOptions with _$VideoDownloadOptions {
+  const factory VideoDownloadOptions({
+    required List<String> subtitleLangs,
+    required bool autoSubtitles,
+    required bool danmaku,
+    required bool writeThumbnail,
+    required bool writeInfoJson,
+    required bool embedMetadata,
+    required bool embedThumbnail,
+    required bool embedChapters,
+    String? audioFormat,
+    int? audioBitrateKbps,
+    required bool normalizeAudio,
+    int? maxHeight,
+    String? videoCodec,
+    String? container,
+    required bool preferHdr,
+    int? maxFps,
+    String? audioLanguage,
+    BigInt? maxFilesizeMb,
+  }) = _VideoDownloadOptions;
+}
+
 /// 视频格式选项
 @freezed
 sealed class VideoFormat with _$VideoFormat {
@@ -205,6 +735,11 @@ sealed class VideoFormat with _$VideoFormat {
     double? fps,
     String? vcodec,
     String? acodec,
+    double? tbr,
+    String? dynamicRange,
+    String? language,
+    required bool audioOnly,
+    required bool videoOnly,
   }) = _VideoFormat;
 }
 
@@ -11,6 +11,278 @@ part of 'download.dart';
 
 // dart format off
 T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$BilibiliAccountInfo {
+
+ BigInt get mid; String get username; String get avatar; int get level; bool get vipActive; int get vipType; PlatformInt64? get vipExpiresAt; String? get vipLabel;
+/// Create a copy of BilibiliAccountInfo
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$BilibiliAccountInfoCopyWith<BilibiliAccountInfo> get copyWith => _$BilibiliAccountInfoCopyWithImpl<BilibiliAccountInfo>(this as BilibiliAccountInfo, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is BilibiliAccountInfo&&(identical(other.mid, mid) || other.mid == mid)&&(identical(other.username, username) || other.username == username)&&(identical(other.avatar, avatar) || other.avatar == avatar)&&(identical(other.level, level) || other.level == level)&&(identical(other.vipActive, vipActive) || other.vipActive == vipActive)&&(identical(other.vipType, vipType) || other.vipType == vipType)&&(identical(other.vipExpiresAt, vipExpiresAt) || other.vipExpiresAt == vipExpiresAt)&&(identical(other.vipLabel, vipLabel) || other.vipLabel == vipLabel));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,mid,username,avatar,level,vipActive,vipType,vipExpiresAt,vipLabel);
+
+@override
+String toString() {
+  return 'BilibiliAccountInfo(mid: $mid, username: $username, avatar: $avatar, level: $level, vipActive: $vipActive, vipType: $vipType, vipExpiresAt: $vipExpiresAt, vipLabel: $vipLabel)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $BilibiliAccountInfoCopyWith<$Res>  {
+  factory $BilibiliAccountInfoCopyWith(BilibiliAccountInfo value, $Res Function(BilibiliAccountInfo) _then) = _$BilibiliAccountInfoCopyWithImpl;
+@useResult
+$Res call({
+ BigInt mid, String username, String avatar, int level, bool vipActive, int vipType, PlatformInt64? vipExpiresAt, String? vipLabel
+});
+
+
+
+
+}
+/// @nodoc
+class _$BilibiliAccountInfoCopyWithImpl<$Res>
+    implements $BilibiliAccountInfoCopyWith<$Res> {
+  _$BilibiliAccountInfoCopyWithImpl(this._self, this._then);
+
+  final BilibiliAccountInfo _self;
+  final $Res Function(BilibiliAccountInfo) _then;
+
+/// Create a copy of BilibiliAccountInfo
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? mid = null,Object? username = null,Object? avatar = null,Object? level = null,Object? vipActive = null,Object? vipType = null,Object? vipExpiresAt = freezed,Object? vipLabel = freezed,}) {
+  return _then(_self.copyWith(
+mid: null == mid ? _self.mid : mid // ignore: cast_nullable_to_non_nullable
+as BigInt,username: null == username ? _self.username : username // ignore: cast_nullable_to_non_nullable
+as String,avatar: null == avatar ? _self.avatar : avatar // ignore: cast_nullable_to_non_nullable
+as String,level: null == level ? _self.level : level // ignore: cast_nullable_to_non_nullable
+as int,vipActive: null == vipActive ? _self.vipActive : vipActive // ignore: cast_nullable_to_non_nullable
+as bool,vipType: null == vipType ? _self.vipType : vipType // ignore: cast_nullable_to_non_nullable
+as int,vipExpiresAt: freezed == vipExpiresAt ? _self.vipExpiresAt : vipExpiresAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,vipLabel: freezed == vipLabel ? _self.vipLabel : vipLabel // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [BilibiliAccountInfo].
+extension BilibiliAccountInfoPatterns on BilibiliAccountInfo {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _BilibiliAccountInfo value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _BilibiliAccountInfo() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _BilibiliAccountInfo value)  $default,){
+final _that = this;
+switch (_that) {
+case _BilibiliAccountInfo():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _BilibiliAccountInfo value)?  $default,){
+final _that = this;
+switch (_that) {
+case _BilibiliAccountInfo() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( BigInt mid,  String username,  String avatar,  int level,  bool vipActive,  int vipType,  PlatformInt64? vipExpiresAt,  String? vipLabel)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _BilibiliAccountInfo() when $default != null:
+return $default(_that.mid,_that.username,_that.avatar,_that.level,_that.vipActive,_that.vipType,_that.vipExpiresAt,_that.vipLabel);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( BigInt mid,  String username,  String avatar,  int level,  bool vipActive,  int vipType,  PlatformInt64? vipExpiresAt,  String? vipLabel)  $default,) {final _that = this;
+switch (_that) {
+case _BilibiliAccountInfo():
+return $default(_that.mid,_that.username,_that.avatar,_that.level,_that.vipActive,_that.vipType,_that.vipExpiresAt,_that.vipLabel);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( BigInt mid,  String username,  String avatar,  int level,  bool vipActive,  int vipType,  PlatformInt64? vipExpiresAt,  String? vipLabel)?  $default,) {final _that = this;
+switch (_that) {
+case _BilibiliAccountInfo() when $default != null:
+return $default(_that.mid,_that.username,_that.avatar,_that.level,_that.vipActive,_that.vipType,_that.vipExpiresAt,_that.vipLabel);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _BilibiliAccountInfo implements BilibiliAccountInfo {
+  const _BilibiliAccountInfo({required this.mid, required this.username, required this.avatar, required this.level, required this.vipActive, required this.vipType, this.vipExpiresAt, this.vipLabel});
+  
+
+@override final  BigInt mid;
+@override final  String username;
+@override final  String avatar;
+@override final  int level;
+@override final  bool vipActive;
+@override final  int vipType;
+@override final  PlatformInt64? vipExpiresAt;
+@override final  String? vipLabel;
+
+/// Create a copy of BilibiliAccountInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$BilibiliAccountInfoCopyWith<_BilibiliAccountInfo> get copyWith => __$BilibiliAccountInfoCopyWithImpl<_BilibiliAccountInfo>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _BilibiliAccountInfo&&(identical(other.mid, mid) || other.mid == mid)&&(identical(other.username, username) || other.username == username)&&(identical(other.avatar, avatar) || other.avatar == avatar)&&(identical(other.level, level) || other.level == level)&&(identical(other.vipActive, vipActive) || other.vipActive == vipActive)&&(identical(other.vipType, vipType) || other.vipType == vipType)&&(identical(other.vipExpiresAt, vipExpiresAt) || other.vipExpiresAt == vipExpiresAt)&&(identical(other.vipLabel, vipLabel) || other.vipLabel == vipLabel));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,mid,username,avatar,level,vipActive,vipType,vipExpiresAt,vipLabel);
+
+@override
+String toString() {
+  return 'BilibiliAccountInfo(mid: $mid, username: $username, avatar: $avatar, level: $level, vipActive: $vipActive, vipType: $vipType, vipExpiresAt: $vipExpiresAt, vipLabel: $vipLabel)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$BilibiliAccountInfoCopyWith<$Res> implements $BilibiliAccountInfoCopyWith<$Res> {
+  factory _$BilibiliAccountInfoCopyWith(_BilibiliAccountInfo value, $Res Function(_BilibiliAccountInfo) _then) = __$BilibiliAccountInfoCopyWithImpl;
+@override @useResult
+$Res call({
+ BigInt mid, String username, String avatar, int level, bool vipActive, int vipType, PlatformInt64? vipExpiresAt, String? vipLabel
+});
+
+
+
+
+}
+/// @nodoc
+class __$BilibiliAccountInfoCopyWithImpl<$Res>
+    implements _$BilibiliAccountInfoCopyWith<$Res> {
+  __$BilibiliAccountInfoCopyWithImpl(this._self, this._then);
+
+  final _BilibiliAccountInfo _self;
+  final $Res Function(_BilibiliAccountInfo) _then;
+
+/// Create a copy of BilibiliAccountInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? mid = null,Object? username = null,Object? avatar = null,Object? level = null,Object? vipActive = null,Object? vipType = null,Object? vipExpiresAt = freezed,Object? vipLabel = freezed,}) {
+  return _then(_BilibiliAccountInfo(
+mid: null == mid ? _self.mid : mid // ignore: cast_nullable_to_non_nullable
+as BigInt,username: null == username ? _self.username : username // ignore: cast_nullable_to_non_nullable
+as String,avatar: null == avatar ? _self.avatar : avatar // ignore: cast_nullable_to_non_nullable
+as String,level: null == level ? _self.level : level // ignore: cast_nullable_to_non_nullable
+as int,vipActive: null == vipActive ? _self.vipActive : vipActive // ignore: cast_nullable_to_non_nullable
+as bool,vipType: null == vipType ? _self.vipType : vipType // ignore: cast_nullable_to_non_nullable
+as int,vipExpiresAt: freezed == vipExpiresAt ? _self.vipExpiresAt : vipExpiresAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,vipLabel: freezed == vipLabel ? _self.vipLabel : vipLabel // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
 /// @nodoc
 mixin _$BilibiliLoginStatus {
 
@@ -638,40 +910,40 @@ as String,
 }
 
 /// @nodoc
-mixin _$NebulaEvent {
+mixin _$CreatedTorrentInfo {
 
- String get taskId;
-/// Create a copy of NebulaEvent
+ String get name; String get infoHash; String get magnet; String get torrentPath; BigInt get totalSize; BigInt get pieceSize; BigInt get pieceCount; BigInt get fileCount; String? get seedTaskId;
+/// Create a copy of CreatedTorrentInfo
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$NebulaEventCopyWith<NebulaEvent> get copyWith => _$NebulaEventCopyWithImpl<NebulaEvent>(this as NebulaEvent, _$identity);
+$CreatedTorrentInfoCopyWith<CreatedTorrentInfo> get copyWith => _$CreatedTorrentInfoCopyWithImpl<CreatedTorrentInfo>(this as CreatedTorrentInfo, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent&&(identical(other.taskId, taskId) || other.taskId == taskId));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CreatedTorrentInfo&&(identical(other.name, name) || other.name == name)&&(identical(other.infoHash, infoHash) || other.infoHash == infoHash)&&(identical(other.magnet, magnet) || other.magnet == magnet)&&(identical(other.torrentPath, torrentPath) || other.torrentPath == torrentPath)&&(identical(other.totalSize, totalSize) || other.totalSize == totalSize)&&(identical(other.pieceSize, pieceSize) || other.pieceSize == pieceSize)&&(identical(other.pieceCount, pieceCount) || other.pieceCount == pieceCount)&&(identical(other.fileCount, fileCount) || other.fileCount == fileCount)&&(identical(other.seedTaskId, seedTaskId) || other.seedTaskId == seedTaskId));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,taskId);
+int get hashCode => Object.hash(runtimeType,name,infoHash,magnet,torrentPath,totalSize,pieceSize,pieceCount,fileCount,seedTaskId);
 
 @override
 String toString() {
-  return 'NebulaEvent(taskId: $taskId)';
+  return 'CreatedTorrentInfo(name: $name, infoHash: $infoHash, magnet: $magnet, torrentPath: $torrentPath, totalSize: $totalSize, pieceSize: $pieceSize, pieceCount: $pieceCount, fileCount: $fileCount, seedTaskId: $seedTaskId)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $NebulaEventCopyWith<$Res>  {
-  factory $NebulaEventCopyWith(NebulaEvent value, $Res Function(NebulaEvent) _then) = _$NebulaEventCopyWithImpl;
+abstract mixin class $CreatedTorrentInfoCopyWith<$Res>  {
+  factory $CreatedTorrentInfoCopyWith(CreatedTorrentInfo value, $Res Function(CreatedTorrentInfo) _then) = _$CreatedTorrentInfoCopyWithImpl;
 @useResult
 $Res call({
- String taskId
+ String name, String infoHash, String magnet, String torrentPath, BigInt totalSize, BigInt pieceSize, BigInt pieceCount, BigInt fileCount, String? seedTaskId
 });
 
 
@@ -679,27 +951,35 @@ $Res call({
 
 }
 /// @nodoc
-class _$NebulaEventCopyWithImpl<$Res>
-    implements $NebulaEventCopyWith<$Res> {
-  _$NebulaEventCopyWithImpl(this._self, this._then);
+class _$CreatedTorrentInfoCopyWithImpl<$Res>
+    implements $CreatedTorrentInfoCopyWith<$Res> {
+  _$CreatedTorrentInfoCopyWithImpl(this._self, this._then);
 
-  final NebulaEvent _self;
-  final $Res Function(NebulaEvent) _then;
+  final CreatedTorrentInfo _self;
+  final $Res Function(CreatedTorrentInfo) _then;
 
-/// Create a copy of NebulaEvent
+/// Create a copy of CreatedTorrentInfo
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? taskId = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? name = null,Object? infoHash = null,Object? magnet = null,Object? torrentPath = null,Object? totalSize = null,Object? pieceSize = null,Object? pieceCount = null,Object? fileCount = null,Object? seedTaskId = freezed,}) {
   return _then(_self.copyWith(
-taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
-as String,
+name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,infoHash: null == infoHash ? _self.infoHash : infoHash // ignore: cast_nullable_to_non_nullable
+as String,magnet: null == magnet ? _self.magnet : magnet // ignore: cast_nullable_to_non_nullable
+as String,torrentPath: null == torrentPath ? _self.torrentPath : torrentPath // ignore: cast_nullable_to_non_nullable
+as String,totalSize: null == totalSize ? _self.totalSize : totalSize // ignore: cast_nullable_to_non_nullable
+as BigInt,pieceSize: null == pieceSize ? _self.pieceSize : pieceSize // ignore: cast_nullable_to_non_nullable
+as BigInt,pieceCount: null == pieceCount ? _self.pieceCount : pieceCount // ignore: cast_nullable_to_non_nullable
+as BigInt,fileCount: null == fileCount ? _self.fileCount : fileCount // ignore: cast_nullable_to_non_nullable
+as BigInt,seedTaskId: freezed == seedTaskId ? _self.seedTaskId : seedTaskId // ignore: cast_nullable_to_non_nullable
+as String?,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [NebulaEvent].
-extension NebulaEventPatterns on NebulaEvent {
+/// Adds pattern-matching-related methods to [CreatedTorrentInfo].
+extension CreatedTorrentInfoPatterns on CreatedTorrentInfo {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -712,20 +992,11 @@ extension NebulaEventPatterns on NebulaEvent {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( NebulaEvent_TaskAdded value)?  taskAdded,TResult Function( NebulaEvent_TaskStarted value)?  taskStarted,TResult Function( NebulaEvent_ProgressUpdated value)?  progressUpdated,TResult Function( NebulaEvent_TaskCompleted value)?  taskCompleted,TResult Function( NebulaEvent_TaskFailed value)?  taskFailed,TResult Function( NebulaEvent_TaskPaused value)?  taskPaused,TResult Function( NebulaEvent_TaskResumed value)?  taskResumed,TResult Function( NebulaEvent_TaskRemoved value)?  taskRemoved,TResult Function( NebulaEvent_MetadataReceived value)?  metadataReceived,TResult Function( NebulaEvent_PeerUpdate value)?  peerUpdate,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _CreatedTorrentInfo value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case NebulaEvent_TaskAdded() when taskAdded != null:
-return taskAdded(_that);case NebulaEvent_TaskStarted() when taskStarted != null:
-return taskStarted(_that);case NebulaEvent_ProgressUpdated() when progressUpdated != null:
-return progressUpdated(_that);case NebulaEvent_TaskCompleted() when taskCompleted != null:
-return taskCompleted(_that);case NebulaEvent_TaskFailed() when taskFailed != null:
-return taskFailed(_that);case NebulaEvent_TaskPaused() when taskPaused != null:
-return taskPaused(_that);case NebulaEvent_TaskResumed() when taskResumed != null:
-return taskResumed(_that);case NebulaEvent_TaskRemoved() when taskRemoved != null:
-return taskRemoved(_that);case NebulaEvent_MetadataReceived() when metadataReceived != null:
-return metadataReceived(_that);case NebulaEvent_PeerUpdate() when peerUpdate != null:
-return peerUpdate(_that);case _:
+case _CreatedTorrentInfo() when $default != null:
+return $default(_that);case _:
   return orElse();
 
 }
@@ -743,20 +1014,11 @@ return peerUpdate(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( NebulaEvent_TaskAdded value)  taskAdded,required TResult Function( NebulaEvent_TaskStarted value)  taskStarted,required TResult Function( NebulaEvent_ProgressUpdated value)  progressUpdated,required TResult Function( NebulaEvent_TaskCompleted value)  taskCompleted,required TResult Function( NebulaEvent_TaskFailed value)  taskFailed,required TResult Function( NebulaEvent_TaskPaused value)  taskPaused,required TResult Function( NebulaEvent_TaskResumed value)  taskResumed,required TResult Function( NebulaEvent_TaskRemoved value)  taskRemoved,required TResult Function( NebulaEvent_MetadataReceived value)  metadataReceived,required TResult Function( NebulaEvent_PeerUpdate value)  peerUpdate,}){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _CreatedTorrentInfo value)  $default,){
 final _that = this;
 switch (_that) {
-case NebulaEvent_TaskAdded():
-return taskAdded(_that);case NebulaEvent_TaskStarted():
-return taskStarted(_that);case NebulaEvent_ProgressUpdated():
-return progressUpdated(_that);case NebulaEvent_TaskCompleted():
-return taskCompleted(_that);case NebulaEvent_TaskFailed():
-return taskFailed(_that);case NebulaEvent_TaskPaused():
-return taskPaused(_that);case NebulaEvent_TaskResumed():
-return taskResumed(_that);case NebulaEvent_TaskRemoved():
-return taskRemoved(_that);case NebulaEvent_MetadataReceived():
-return metadataReceived(_that);case NebulaEvent_PeerUpdate():
-return peerUpdate(_that);}
+case _CreatedTorrentInfo():
+return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -770,20 +1032,11 @@ return peerUpdate(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( NebulaEvent_TaskAdded value)?  taskAdded,TResult? Function( NebulaEvent_TaskStarted value)?  taskStarted,TResult? Function( NebulaEvent_ProgressUpdated value)?  progressUpdated,TResult? Function( NebulaEvent_TaskCompleted value)?  taskCompleted,TResult? Function( NebulaEvent_TaskFailed value)?  taskFailed,TResult? Function( NebulaEvent_TaskPaused value)?  taskPaused,TResult? Function( NebulaEvent_TaskResumed value)?  taskResumed,TResult? Function( NebulaEvent_TaskRemoved value)?  taskRemoved,TResult? Function( NebulaEvent_MetadataReceived value)?  metadataReceived,TResult? Function( NebulaEvent_PeerUpdate value)?  peerUpdate,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _CreatedTorrentInfo value)?  $default,){
 final _that = this;
 switch (_that) {
-case NebulaEvent_TaskAdded() when taskAdded != null:
-return taskAdded(_that);case NebulaEvent_TaskStarted() when taskStarted != null:
-return taskStarted(_that);case NebulaEvent_ProgressUpdated() when progressUpdated != null:
-return progressUpdated(_that);case NebulaEvent_TaskCompleted() when taskCompleted != null:
-return taskCompleted(_that);case NebulaEvent_TaskFailed() when taskFailed != null:
-return taskFailed(_that);case NebulaEvent_TaskPaused() when taskPaused != null:
-return taskPaused(_that);case NebulaEvent_TaskResumed() when taskResumed != null:
-return taskResumed(_that);case NebulaEvent_TaskRemoved() when taskRemoved != null:
-return taskRemoved(_that);case NebulaEvent_MetadataReceived() when metadataReceived != null:
-return metadataReceived(_that);case NebulaEvent_PeerUpdate() when peerUpdate != null:
-return peerUpdate(_that);case _:
+case _CreatedTorrentInfo() when $default != null:
+return $default(_that);case _:
   return null;
 
 }
@@ -800,19 +1053,10 @@ return peerUpdate(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String taskId,  String name,  String? thumbnail)?  taskAdded,TResult Function( String taskId)?  taskStarted,TResult Function( String taskId,  ProgressEvent progress)?  progressUpdated,TResult Function( String taskId)?  taskCompleted,TResult Function( String taskId,  String error)?  taskFailed,TResult Function( String taskId)?  taskPaused,TResult Function( String taskId)?  taskResumed,TResult Function( String taskId)?  taskRemoved,TResult Function( String taskId,  String name,  BigInt totalSize,  BigInt fileCount)?  metadataReceived,TResult Function( String taskId,  BigInt connectedPeers,  BigInt totalPeers)?  peerUpdate,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String name,  String infoHash,  String magnet,  String torrentPath,  BigInt totalSize,  BigInt pieceSize,  BigInt pieceCount,  BigInt fileCount,  String? seedTaskId)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case NebulaEvent_TaskAdded() when taskAdded != null:
-return taskAdded(_that.taskId,_that.name,_that.thumbnail);case NebulaEvent_TaskStarted() when taskStarted != null:
-return taskStarted(_that.taskId);case NebulaEvent_ProgressUpdated() when progressUpdated != null:
-return progressUpdated(_that.taskId,_that.progress);case NebulaEvent_TaskCompleted() when taskCompleted != null:
-return taskCompleted(_that.taskId);case NebulaEvent_TaskFailed() when taskFailed != null:
-return taskFailed(_that.taskId,_that.error);case NebulaEvent_TaskPaused() when taskPaused != null:
-return taskPaused(_that.taskId);case NebulaEvent_TaskResumed() when taskResumed != null:
-return taskResumed(_that.taskId);case NebulaEvent_TaskRemoved() when taskRemoved != null:
-return taskRemoved(_that.taskId);case NebulaEvent_MetadataReceived() when metadataReceived != null:
-return metadataReceived(_that.taskId,_that.name,_that.totalSize,_that.fileCount);case NebulaEvent_PeerUpdate() when peerUpdate != null:
-return peerUpdate(_that.taskId,_that.connectedPeers,_that.totalPeers);case _:
+case _CreatedTorrentInfo() when $default != null:
+return $default(_that.name,_that.infoHash,_that.magnet,_that.torrentPath,_that.totalSize,_that.pieceSize,_that.pieceCount,_that.fileCount,_that.seedTaskId);case _:
   return orElse();
 
 }
@@ -830,19 +1074,10 @@ return peerUpdate(_that.taskId,_that.connectedPeers,_that.totalPeers);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String taskId,  String name,  String? thumbnail)  taskAdded,required TResult Function( String taskId)  taskStarted,required TResult Function( String taskId,  ProgressEvent progress)  progressUpdated,required TResult Function( String taskId)  taskCompleted,required TResult Function( String taskId,  String error)  taskFailed,required TResult Function( String taskId)  taskPaused,required TResult Function( String taskId)  taskResumed,required TResult Function( String taskId)  taskRemoved,required TResult Function( String taskId,  String name,  BigInt totalSize,  BigInt fileCount)  metadataReceived,required TResult Function( String taskId,  BigInt connectedPeers,  BigInt totalPeers)  peerUpdate,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String name,  String infoHash,  String magnet,  String torrentPath,  BigInt totalSize,  BigInt pieceSize,  BigInt pieceCount,  BigInt fileCount,  String? seedTaskId)  $default,) {final _that = this;
 switch (_that) {
-case NebulaEvent_TaskAdded():
-return taskAdded(_that.taskId,_that.name,_that.thumbnail);case NebulaEvent_TaskStarted():
-return taskStarted(_that.taskId);case NebulaEvent_ProgressUpdated():
-return progressUpdated(_that.taskId,_that.progress);case NebulaEvent_TaskCompleted():
-return taskCompleted(_that.taskId);case NebulaEvent_TaskFailed():
-return taskFailed(_that.taskId,_that.error);case NebulaEvent_TaskPaused():
-return taskPaused(_that.taskId);case NebulaEvent_TaskResumed():
-return taskResumed(_that.taskId);case NebulaEvent_TaskRemoved():
-return taskRemoved(_that.taskId);case NebulaEvent_MetadataReceived():
-return metadataReceived(_that.taskId,_that.name,_that.totalSize,_that.fileCount);case NebulaEvent_PeerUpdate():
-return peerUpdate(_that.taskId,_that.connectedPeers,_that.totalPeers);}
+case _CreatedTorrentInfo():
+return $default(_that.name,_that.infoHash,_that.magnet,_that.torrentPath,_that.totalSize,_that.pieceSize,_that.pieceCount,_that.fileCount,_that.seedTaskId);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -856,19 +1091,10 @@ return peerUpdate(_that.taskId,_that.connectedPeers,_that.totalPeers);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String taskId,  String name,  String? thumbnail)?  taskAdded,TResult? Function( String taskId)?  taskStarted,TResult? Function( String taskId,  ProgressEvent progress)?  progressUpdated,TResult? Function( String taskId)?  taskCompleted,TResult? Function( String taskId,  String error)?  taskFailed,TResult? Function( String taskId)?  taskPaused,TResult? Function( String taskId)?  taskResumed,TResult? Function( String taskId)?  taskRemoved,TResult? Function( String taskId,  String name,  BigInt totalSize,  BigInt fileCount)?  metadataReceived,TResult? Function( String taskId,  BigInt connectedPeers,  BigInt totalPeers)?  peerUpdate,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String name,  String infoHash,  String magnet,  String torrentPath,  BigInt totalSize,  BigInt pieceSize,  BigInt pieceCount,  BigInt fileCount,  String? seedTaskId)?  $default,) {final _that = this;
 switch (_that) {
-case NebulaEvent_TaskAdded() when taskAdded != null:
-return taskAdded(_that.taskId,_that.name,_that.thumbnail);case NebulaEvent_TaskStarted() when taskStarted != null:
-return taskStarted(_that.taskId);case NebulaEvent_ProgressUpdated() when progressUpdated != null:
-return progressUpdated(_that.taskId,_that.progress);case NebulaEvent_TaskCompleted() when taskCompleted != null:
-return taskCompleted(_that.taskId);case NebulaEvent_TaskFailed() when taskFailed != null:
-return taskFailed(_that.taskId,_that.error);case NebulaEvent_TaskPaused() when taskPaused != null:
-return taskPaused(_that.taskId);case NebulaEvent_TaskResumed() when taskResumed != null:
-return taskResumed(_that.taskId);case NebulaEvent_TaskRemoved() when taskRemoved != null:
-return taskRemoved(_that.taskId);case NebulaEvent_MetadataReceived() when metadataReceived != null:
-return metadataReceived(_that.taskId,_that.name,_that.totalSize,_that.fileCount);case NebulaEvent_PeerUpdate() when peerUpdate != null:
-return peerUpdate(_that.taskId,_that.connectedPeers,_that.totalPeers);case _:
+case _CreatedTorrentInfo() when $default != null:
+return $default(_that.name,_that.infoHash,_that.magnet,_that.torrentPath,_that.totalSize,_that.pieceSize,_that.pieceCount,_that.fileCount,_that.seedTaskId);case _:
   return null;
 
 }
@@ -879,45 +1105,51 @@ return peerUpdate(_that.taskId,_that.connectedPeers,_that.totalPeers);case _:
 /// @nodoc
 
 
-class NebulaEvent_TaskAdded extends NebulaEvent {
-  const NebulaEvent_TaskAdded({required this.taskId, required this.name, this.thumbnail}): super._();
+class _CreatedTorrentInfo implements CreatedTorrentInfo {
+  const _CreatedTorrentInfo({required this.name, required this.infoHash, required this.magnet, required this.torrentPath, required this.totalSize, required this.pieceSize, required this.pieceCount, required this.fileCount, this.seedTaskId});
   
 
-@override final  String taskId;
- final  String name;
- final  String? thumbnail;
+@override final  String name;
+@override final  String infoHash;
+@override final  String magnet;
+@override final  String torrentPath;
+@override final  BigInt totalSize;
+@override final  BigInt pieceSize;
+@override final  BigInt pieceCount;
+@override final  BigInt fileCount;
+@override final  String? seedTaskId;
 
-/// Create a copy of NebulaEvent
+/// Create a copy of CreatedTorrentInfo
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$NebulaEvent_TaskAddedCopyWith<NebulaEvent_TaskAdded> get copyWith => _$NebulaEvent_TaskAddedCopyWithImpl<NebulaEvent_TaskAdded>(this, _$identity);
+_$CreatedTorrentInfoCopyWith<_CreatedTorrentInfo> get copyWith => __$CreatedTorrentInfoCopyWithImpl<_CreatedTorrentInfo>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_TaskAdded&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.name, name) || other.name == name)&&(identical(other.thumbnail, thumbnail) || other.thumbnail == thumbnail));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _CreatedTorrentInfo&&(identical(other.name, name) || other.name == name)&&(identical(other.infoHash, infoHash) || other.infoHash == infoHash)&&(identical(other.magnet, magnet) || other.magnet == magnet)&&(identical(other.torrentPath, torrentPath) || other.torrentPath == torrentPath)&&(identical(other.totalSize, totalSize) || other.totalSize == totalSize)&&(identical(other.pieceSize, pieceSize) || other.pieceSize == pieceSize)&&(identical(other.pieceCount, pieceCount) || other.pieceCount == pieceCount)&&(identical(other.fileCount, fileCount) || other.fileCount == fileCount)&&(identical(other.seedTaskId, seedTaskId) || other.seedTaskId == seedTaskId));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,taskId,name,thumbnail);
+int get hashCode => Object.hash(runtimeType,name,infoHash,magnet,torrentPath,totalSize,pieceSize,pieceCount,fileCount,seedTaskId);
 
 @override
 String toString() {
-  return 'NebulaEvent.taskAdded(taskId: $taskId, name: $name, thumbnail: $thumbnail)';
+  return 'CreatedTorrentInfo(name: $name, infoHash: $infoHash, magnet: $magnet, torrentPath: $torrentPath, totalSize: $totalSize, pieceSize: $pieceSize, pieceCount: $pieceCount, fileCount: $fileCount, seedTaskId: $seedTaskId)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $NebulaEvent_TaskAddedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
-  factory $NebulaEvent_TaskAddedCopyWith(NebulaEvent_TaskAdded value, $Res Function(NebulaEvent_TaskAdded) _then) = _$NebulaEvent_TaskAddedCopyWithImpl;
+abstract mixin class _$CreatedTorrentInfoCopyWith<$Res> implements $CreatedTorrentInfoCopyWith<$Res> {
+  factory _$CreatedTorrentInfoCopyWith(_CreatedTorrentInfo value, $Res Function(_CreatedTorrentInfo) _then) = __$CreatedTorrentInfoCopyWithImpl;
 @override @useResult
 $Res call({
- String taskId, String name, String? thumbnail
+ String name, String infoHash, String magnet, String torrentPath, BigInt totalSize, BigInt pieceSize, BigInt pieceCount, BigInt fileCount, String? seedTaskId
 });
 
 
@@ -925,20 +1157,26 @@ $Res call({
 
 }
 /// @nodoc
-class _$NebulaEvent_TaskAddedCopyWithImpl<$Res>
-    implements $NebulaEvent_TaskAddedCopyWith<$Res> {
-  _$NebulaEvent_TaskAddedCopyWithImpl(this._self, this._then);
+class __$CreatedTorrentInfoCopyWithImpl<$Res>
+    implements _$CreatedTorrentInfoCopyWith<$Res> {
+  __$CreatedTorrentInfoCopyWithImpl(this._self, this._then);
 
-  final NebulaEvent_TaskAdded _self;
-  final $Res Function(NebulaEvent_TaskAdded) _then;
+  final _CreatedTorrentInfo _self;
+  final $Res Function(_CreatedTorrentInfo) _then;
 
-/// Create a copy of NebulaEvent
+/// Create a copy of CreatedTorrentInfo
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? name = null,Object? thumbnail = freezed,}) {
-  return _then(NebulaEvent_TaskAdded(
-taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
-as String,name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
-as String,thumbnail: freezed == thumbnail ? _self.thumbnail : thumbnail // ignore: cast_nullable_to_non_nullable
+@override @pragma('vm:prefer-inline') $Res call({Object? name = null,Object? infoHash = null,Object? magnet = null,Object? torrentPath = null,Object? totalSize = null,Object? pieceSize = null,Object? pieceCount = null,Object? fileCount = null,Object? seedTaskId = freezed,}) {
+  return _then(_CreatedTorrentInfo(
+name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,infoHash: null == infoHash ? _self.infoHash : infoHash // ignore: cast_nullable_to_non_nullable
+as String,magnet: null == magnet ? _self.magnet : magnet // ignore: cast_nullable_to_non_nullable
+as String,torrentPath: null == torrentPath ? _self.torrentPath : torrentPath // ignore: cast_nullable_to_non_nullable
+as String,totalSize: null == totalSize ? _self.totalSize : totalSize // ignore: cast_nullable_to_non_nullable
+as BigInt,pieceSize: null == pieceSize ? _self.pieceSize : pieceSize // ignore: cast_nullable_to_non_nullable
+as BigInt,pieceCount: null == pieceCount ? _self.pieceCount : pieceCount // ignore: cast_nullable_to_non_nullable
+as BigInt,fileCount: null == fileCount ? _self.fileCount : fileCount // ignore: cast_nullable_to_non_nullable
+as BigInt,seedTaskId: freezed == seedTaskId ? _self.seedTaskId : seedTaskId // ignore: cast_nullable_to_non_nullable
 as String?,
   ));
 }
@@ -947,45 +1185,40 @@ as String?,
 }
 
 /// @nodoc
+mixin _$CredentialVaultStatus {
 
-
-class NebulaEvent_TaskStarted extends NebulaEvent {
-  const NebulaEvent_TaskStarted({required this.taskId}): super._();
-  
-
-@override final  String taskId;
-
-/// Create a copy of NebulaEvent
+ bool get unlocked; bool get requiresPassphrase;
+/// Create a copy of CredentialVaultStatus
 /// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
+@JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$NebulaEvent_TaskStartedCopyWith<NebulaEvent_TaskStarted> get copyWith => _$NebulaEvent_TaskStartedCopyWithImpl<NebulaEvent_TaskStarted>(this, _$identity);
+$CredentialVaultStatusCopyWith<CredentialVaultStatus> get copyWith => _$CredentialVaultStatusCopyWithImpl<CredentialVaultStatus>(this as CredentialVaultStatus, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_TaskStarted&&(identical(other.taskId, taskId) || other.taskId == taskId));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CredentialVaultStatus&&(identical(other.unlocked, unlocked) || other.unlocked == unlocked)&&(identical(other.requiresPassphrase, requiresPassphrase) || other.requiresPassphrase == requiresPassphrase));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,taskId);
+int get hashCode => Object.hash(runtimeType,unlocked,requiresPassphrase);
 
 @override
 String toString() {
-  return 'NebulaEvent.taskStarted(taskId: $taskId)';
+  return 'CredentialVaultStatus(unlocked: $unlocked, requiresPassphrase: $requiresPassphrase)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $NebulaEvent_TaskStartedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
-  factory $NebulaEvent_TaskStartedCopyWith(NebulaEvent_TaskStarted value, $Res Function(NebulaEvent_TaskStarted) _then) = _$NebulaEvent_TaskStartedCopyWithImpl;
-@override @useResult
+abstract mixin class $CredentialVaultStatusCopyWith<$Res>  {
+  factory $CredentialVaultStatusCopyWith(CredentialVaultStatus value, $Res Function(CredentialVaultStatus) _then) = _$CredentialVaultStatusCopyWithImpl;
+@useResult
 $Res call({
- String taskId
+ bool unlocked, bool requiresPassphrase
 });
 
 
@@ -993,142 +1226,191 @@ $Res call({
 
 }
 /// @nodoc
-class _$NebulaEvent_TaskStartedCopyWithImpl<$Res>
-    implements $NebulaEvent_TaskStartedCopyWith<$Res> {
-  _$NebulaEvent_TaskStartedCopyWithImpl(this._self, this._then);
+class _$CredentialVaultStatusCopyWithImpl<$Res>
+    implements $CredentialVaultStatusCopyWith<$Res> {
+  _$CredentialVaultStatusCopyWithImpl(this._self, this._then);
 
-  final NebulaEvent_TaskStarted _self;
-  final $Res Function(NebulaEvent_TaskStarted) _then;
+  final CredentialVaultStatus _self;
+  final $Res Function(CredentialVaultStatus) _then;
 
-/// Create a copy of NebulaEvent
+/// Create a copy of CredentialVaultStatus
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? taskId = null,}) {
-  return _then(NebulaEvent_TaskStarted(
-taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
-as String,
+@pragma('vm:prefer-inline') @override $Res call({Object? unlocked = null,Object? requiresPassphrase = null,}) {
+  return _then(_self.copyWith(
+unlocked: null == unlocked ? _self.unlocked : unlocked // ignore: cast_nullable_to_non_nullable
+as bool,requiresPassphrase: null == requiresPassphrase ? _self.requiresPassphrase : requiresPassphrase // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
 
-
 }
 
-/// @nodoc
-
-
-class NebulaEvent_ProgressUpdated extends NebulaEvent {
-  const NebulaEvent_ProgressUpdated({required this.taskId, required this.progress}): super._();
-  
-
-@override final  String taskId;
- final  ProgressEvent progress;
-
-/// Create a copy of NebulaEvent
-/// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
-@pragma('vm:prefer-inline')
-$NebulaEvent_ProgressUpdatedCopyWith<NebulaEvent_ProgressUpdated> get copyWith => _$NebulaEvent_ProgressUpdatedCopyWithImpl<NebulaEvent_ProgressUpdated>(this, _$identity);
 
+/// Adds pattern-matching-related methods to [CredentialVaultStatus].
+extension CredentialVaultStatusPatterns on CredentialVaultStatus {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
 
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _CredentialVaultStatus value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _CredentialVaultStatus() when $default != null:
+return $default(_that);case _:
+  return orElse();
 
-@override
-bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_ProgressUpdated&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.progress, progress) || other.progress == progress));
 }
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
 
-
-@override
-int get hashCode => Object.hash(runtimeType,taskId,progress);
-
-@override
-String toString() {
-  return 'NebulaEvent.progressUpdated(taskId: $taskId, progress: $progress)';
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _CredentialVaultStatus value)  $default,){
+final _that = this;
+switch (_that) {
+case _CredentialVaultStatus():
+return $default(_that);}
 }
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
 
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _CredentialVaultStatus value)?  $default,){
+final _that = this;
+switch (_that) {
+case _CredentialVaultStatus() when $default != null:
+return $default(_that);case _:
+  return null;
 
 }
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
 
-/// @nodoc
-abstract mixin class $NebulaEvent_ProgressUpdatedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
-  factory $NebulaEvent_ProgressUpdatedCopyWith(NebulaEvent_ProgressUpdated value, $Res Function(NebulaEvent_ProgressUpdated) _then) = _$NebulaEvent_ProgressUpdatedCopyWithImpl;
-@override @useResult
-$Res call({
- String taskId, ProgressEvent progress
-});
-
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( bool unlocked,  bool requiresPassphrase)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _CredentialVaultStatus() when $default != null:
+return $default(_that.unlocked,_that.requiresPassphrase);case _:
+  return orElse();
 
-$ProgressEventCopyWith<$Res> get progress;
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
 
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( bool unlocked,  bool requiresPassphrase)  $default,) {final _that = this;
+switch (_that) {
+case _CredentialVaultStatus():
+return $default(_that.unlocked,_that.requiresPassphrase);}
 }
-/// @nodoc
-class _$NebulaEvent_ProgressUpdatedCopyWithImpl<$Res>
-    implements $NebulaEvent_ProgressUpdatedCopyWith<$Res> {
-  _$NebulaEvent_ProgressUpdatedCopyWithImpl(this._self, this._then);
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
 
-  final NebulaEvent_ProgressUpdated _self;
-  final $Res Function(NebulaEvent_ProgressUpdated) _then;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( bool unlocked,  bool requiresPassphrase)?  $default,) {final _that = this;
+switch (_that) {
+case _CredentialVaultStatus() when $default != null:
+return $default(_that.unlocked,_that.requiresPassphrase);case _:
+  return null;
 
-/// Create a copy of NebulaEvent
-/// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? progress = null,}) {
-  return _then(NebulaEvent_ProgressUpdated(
-taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
-as String,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
-as ProgressEvent,
-  ));
 }
-
-/// Create a copy of NebulaEvent
-/// with the given fields replaced by the non-null parameter values.
-@override
-@pragma('vm:prefer-inline')
-$ProgressEventCopyWith<$Res> get progress {
-  
-  return $ProgressEventCopyWith<$Res>(_self.progress, (value) {
-    return _then(_self.copyWith(progress: value));
-  });
 }
+
 }
 
 /// @nodoc
 
 
-class NebulaEvent_TaskCompleted extends NebulaEvent {
-  const NebulaEvent_TaskCompleted({required this.taskId}): super._();
+class _CredentialVaultStatus implements CredentialVaultStatus {
+  const _CredentialVaultStatus({required this.unlocked, required this.requiresPassphrase});
   
 
-@override final  String taskId;
+@override final  bool unlocked;
+@override final  bool requiresPassphrase;
 
-/// Create a copy of NebulaEvent
+/// Create a copy of CredentialVaultStatus
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$NebulaEvent_TaskCompletedCopyWith<NebulaEvent_TaskCompleted> get copyWith => _$NebulaEvent_TaskCompletedCopyWithImpl<NebulaEvent_TaskCompleted>(this, _$identity);
+_$CredentialVaultStatusCopyWith<_CredentialVaultStatus> get copyWith => __$CredentialVaultStatusCopyWithImpl<_CredentialVaultStatus>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_TaskCompleted&&(identical(other.taskId, taskId) || other.taskId == taskId));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _CredentialVaultStatus&&(identical(other.unlocked, unlocked) || other.unlocked == unlocked)&&(identical(other.requiresPassphrase, requiresPassphrase) || other.requiresPassphrase == requiresPassphrase));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,taskId);
+int get hashCode => Object.hash(runtimeType,unlocked,requiresPassphrase);
 
 @override
 String toString() {
-  return 'NebulaEvent.taskCompleted(taskId: $taskId)';
+  return 'CredentialVaultStatus(unlocked: $unlocked, requiresPassphrase: $requiresPassphrase)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $NebulaEvent_TaskCompletedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
-  factory $NebulaEvent_TaskCompletedCopyWith(NebulaEvent_TaskCompleted value, $Res Function(NebulaEvent_TaskCompleted) _then) = _$NebulaEvent_TaskCompletedCopyWithImpl;
+abstract mixin class _$CredentialVaultStatusCopyWith<$Res> implements $CredentialVaultStatusCopyWith<$Res> {
+  factory _$CredentialVaultStatusCopyWith(_CredentialVaultStatus value, $Res Function(_CredentialVaultStatus) _then) = __$CredentialVaultStatusCopyWithImpl;
 @override @useResult
 $Res call({
- String taskId
+ bool unlocked, bool requiresPassphrase
 });
 
 
@@ -1136,19 +1418,20 @@ $Res call({
 
 }
 /// @nodoc
-class _$NebulaEvent_TaskCompletedCopyWithImpl<$Res>
-    implements $NebulaEvent_TaskCompletedCopyWith<$Res> {
-  _$NebulaEvent_TaskCompletedCopyWithImpl(this._self, this._then);
+class __$CredentialVaultStatusCopyWithImpl<$Res>
+    implements _$CredentialVaultStatusCopyWith<$Res> {
+  __$CredentialVaultStatusCopyWithImpl(this._self, this._then);
 
-  final NebulaEvent_TaskCompleted _self;
-  final $Res Function(NebulaEvent_TaskCompleted) _then;
+  final _CredentialVaultStatus _self;
+  final $Res Function(_CredentialVaultStatus) _then;
 
-/// Create a copy of NebulaEvent
+/// Create a copy of CredentialVaultStatus
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? taskId = null,}) {
-  return _then(NebulaEvent_TaskCompleted(
-taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
-as String,
+@override @pragma('vm:prefer-inline') $Res call({Object? unlocked = null,Object? requiresPassphrase = null,}) {
+  return _then(_CredentialVaultStatus(
+unlocked: null == unlocked ? _self.unlocked : unlocked // ignore: cast_nullable_to_non_nullable
+as bool,requiresPassphrase: null == requiresPassphrase ? _self.requiresPassphrase : requiresPassphrase // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
 
@@ -1156,46 +1439,40 @@ as String,
 }
 
 /// @nodoc
+mixin _$IpFilterInfo {
 
-
-class NebulaEvent_TaskFailed extends NebulaEvent {
-  const NebulaEvent_TaskFailed({required this.taskId, required this.error}): super._();
-  
-
-@override final  String taskId;
- final  String error;
-
-/// Create a copy of NebulaEvent
+ BigInt get rangeCount; BigInt get blockedHandoffPeers; PlatformInt64? get lastUpdated;
+/// Create a copy of IpFilterInfo
 /// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
+@JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$NebulaEvent_TaskFailedCopyWith<NebulaEvent_TaskFailed> get copyWith => _$NebulaEvent_TaskFailedCopyWithImpl<NebulaEvent_TaskFailed>(this, _$identity);
+$IpFilterInfoCopyWith<IpFilterInfo> get copyWith => _$IpFilterInfoCopyWithImpl<IpFilterInfo>(this as IpFilterInfo, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_TaskFailed&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.error, error) || other.error == error));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is IpFilterInfo&&(identical(other.rangeCount, rangeCount) || other.rangeCount == rangeCount)&&(identical(other.blockedHandoffPeers, blockedHandoffPeers) || other.blockedHandoffPeers == blockedHandoffPeers)&&(identical(other.lastUpdated, lastUpdated) || other.lastUpdated == lastUpdated));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,taskId,error);
+int get hashCode => Object.hash(runtimeType,rangeCount,blockedHandoffPeers,lastUpdated);
 
 @override
 String toString() {
-  return 'NebulaEvent.taskFailed(taskId: $taskId, error: $error)';
+  return 'IpFilterInfo(rangeCount: $rangeCount, blockedHandoffPeers: $blockedHandoffPeers, lastUpdated: $lastUpdated)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $NebulaEvent_TaskFailedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
-  factory $NebulaEvent_TaskFailedCopyWith(NebulaEvent_TaskFailed value, $Res Function(NebulaEvent_TaskFailed) _then) = _$NebulaEvent_TaskFailedCopyWithImpl;
-@override @useResult
+abstract mixin class $IpFilterInfoCopyWith<$Res>  {
+  factory $IpFilterInfoCopyWith(IpFilterInfo value, $Res Function(IpFilterInfo) _then) = _$IpFilterInfoCopyWithImpl;
+@useResult
 $Res call({
- String taskId, String error
+ BigInt rangeCount, BigInt blockedHandoffPeers, PlatformInt64? lastUpdated
 });
 
 
@@ -1203,338 +1480,5149 @@ $Res call({
 
 }
 /// @nodoc
-class _$NebulaEvent_TaskFailedCopyWithImpl<$Res>
-    implements $NebulaEvent_TaskFailedCopyWith<$Res> {
-  _$NebulaEvent_TaskFailedCopyWithImpl(this._self, this._then);
+class _$IpFilterInfoCopyWithImpl<$Res>
+    implements $IpFilterInfoCopyWith<$Res> {
+  _$IpFilterInfoCopyWithImpl(this._self, this._then);
 
-  final NebulaEvent_TaskFailed _self;
-  final $Res Function(NebulaEvent_TaskFailed) _then;
+  final IpFilterInfo _self;
+  final $Res Function(IpFilterInfo) _then;
 
-/// Create a copy of NebulaEvent
+/// Create a copy of IpFilterInfo
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? error = null,}) {
-  return _then(NebulaEvent_TaskFailed(
-taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
-as String,error: null == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
-as String,
+@pragma('vm:prefer-inline') @override $Res call({Object? rangeCount = null,Object? blockedHandoffPeers = null,Object? lastUpdated = freezed,}) {
+  return _then(_self.copyWith(
+rangeCount: null == rangeCount ? _self.rangeCount : rangeCount // ignore: cast_nullable_to_non_nullable
+as BigInt,blockedHandoffPeers: null == blockedHandoffPeers ? _self.blockedHandoffPeers : blockedHandoffPeers // ignore: cast_nullable_to_non_nullable
+as BigInt,lastUpdated: freezed == lastUpdated ? _self.lastUpdated : lastUpdated // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,
   ));
 }
 
+}
+
+
+/// Adds pattern-matching-related methods to [IpFilterInfo].
+extension IpFilterInfoPatterns on IpFilterInfo {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _IpFilterInfo value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _IpFilterInfo() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _IpFilterInfo value)  $default,){
+final _that = this;
+switch (_that) {
+case _IpFilterInfo():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _IpFilterInfo value)?  $default,){
+final _that = this;
+switch (_that) {
+case _IpFilterInfo() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( BigInt rangeCount,  BigInt blockedHandoffPeers,  PlatformInt64? lastUpdated)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _IpFilterInfo() when $default != null:
+return $default(_that.rangeCount,_that.blockedHandoffPeers,_that.lastUpdated);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( BigInt rangeCount,  BigInt blockedHandoffPeers,  PlatformInt64? lastUpdated)  $default,) {final _that = this;
+switch (_that) {
+case _IpFilterInfo():
+return $default(_that.rangeCount,_that.blockedHandoffPeers,_that.lastUpdated);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( BigInt rangeCount,  BigInt blockedHandoffPeers,  PlatformInt64? lastUpdated)?  $default,) {final _that = this;
+switch (_that) {
+case _IpFilterInfo() when $default != null:
+return $default(_that.rangeCount,_that.blockedHandoffPeers,_that.lastUpdated);case _:
+  return null;
+
+}
+}
 
 }
 
 /// @nodoc
 
 
-class NebulaEvent_TaskPaused extends NebulaEvent {
-  const NebulaEvent_TaskPaused({required this.taskId}): super._();
+class _IpFilterInfo implements IpFilterInfo {
+  const _IpFilterInfo({required this.rangeCount, required this.blockedHandoffPeers, this.lastUpdated});
   
 
-@override final  String taskId;
+@override final  BigInt rangeCount;
+@override final  BigInt blockedHandoffPeers;
+@override final  PlatformInt64? lastUpdated;
 
-/// Create a copy of NebulaEvent
+/// Create a copy of IpFilterInfo
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$NebulaEvent_TaskPausedCopyWith<NebulaEvent_TaskPaused> get copyWith => _$NebulaEvent_TaskPausedCopyWithImpl<NebulaEvent_TaskPaused>(this, _$identity);
+_$IpFilterInfoCopyWith<_IpFilterInfo> get copyWith => __$IpFilterInfoCopyWithImpl<_IpFilterInfo>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_TaskPaused&&(identical(other.taskId, taskId) || other.taskId == taskId));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _IpFilterInfo&&(identical(other.rangeCount, rangeCount) || other.rangeCount == rangeCount)&&(identical(other.blockedHandoffPeers, blockedHandoffPeers) || other.blockedHandoffPeers == blockedHandoffPeers)&&(identical(other.lastUpdated, lastUpdated) || other.lastUpdated == lastUpdated));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,taskId);
+int get hashCode => Object.hash(runtimeType,rangeCount,blockedHandoffPeers,lastUpdated);
 
 @override
 String toString() {
-  return 'NebulaEvent.taskPaused(taskId: $taskId)';
+  return 'IpFilterInfo(rangeCount: $rangeCount, blockedHandoffPeers: $blockedHandoffPeers, lastUpdated: $lastUpdated)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $NebulaEvent_TaskPausedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
-  factory $NebulaEvent_TaskPausedCopyWith(NebulaEvent_TaskPaused value, $Res Function(NebulaEvent_TaskPaused) _then) = _$NebulaEvent_TaskPausedCopyWithImpl;
+abstract mixin class _$IpFilterInfoCopyWith<$Res> implements $IpFilterInfoCopyWith<$Res> {
+  factory _$IpFilterInfoCopyWith(_IpFilterInfo value, $Res Function(_IpFilterInfo) _then) = __$IpFilterInfoCopyWithImpl;
 @override @useResult
 $Res call({
- String taskId
+ BigInt rangeCount, BigInt blockedHandoffPeers, PlatformInt64? lastUpdated
 });
 
 
 
 
 }
-/// @nodoc
-class _$NebulaEvent_TaskPausedCopyWithImpl<$Res>
-    implements $NebulaEvent_TaskPausedCopyWith<$Res> {
-  _$NebulaEvent_TaskPausedCopyWithImpl(this._self, this._then);
+/// @nodoc
+class __$IpFilterInfoCopyWithImpl<$Res>
+    implements _$IpFilterInfoCopyWith<$Res> {
+  __$IpFilterInfoCopyWithImpl(this._self, this._then);
+
+  final _IpFilterInfo _self;
+  final $Res Function(_IpFilterInfo) _then;
+
+/// Create a copy of IpFilterInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? rangeCount = null,Object? blockedHandoffPeers = null,Object? lastUpdated = freezed,}) {
+  return _then(_IpFilterInfo(
+rangeCount: null == rangeCount ? _self.rangeCount : rangeCount // ignore: cast_nullable_to_non_nullable
+as BigInt,blockedHandoffPeers: null == blockedHandoffPeers ? _self.blockedHandoffPeers : blockedHandoffPeers // ignore: cast_nullable_to_non_nullable
+as BigInt,lastUpdated: freezed == lastUpdated ? _self.lastUpdated : lastUpdated // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$NebulaEvent {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'NebulaEvent()';
+}
+
+
+}
+
+/// @nodoc
+class $NebulaEventCopyWith<$Res>  {
+$NebulaEventCopyWith(NebulaEvent _, $Res Function(NebulaEvent) __);
+}
+
+
+/// Adds pattern-matching-related methods to [NebulaEvent].
+extension NebulaEventPatterns on NebulaEvent {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( NebulaEvent_TaskAdded value)?  taskAdded,TResult Function( NebulaEvent_TaskStarted value)?  taskStarted,TResult Function( NebulaEvent_ProgressUpdated value)?  progressUpdated,TResult Function( NebulaEvent_TaskCompleted value)?  taskCompleted,TResult Function( NebulaEvent_TaskFailed value)?  taskFailed,TResult Function( NebulaEvent_TaskPaused value)?  taskPaused,TResult Function( NebulaEvent_TaskResumed value)?  taskResumed,TResult Function( NebulaEvent_TaskRemoved value)?  taskRemoved,TResult Function( NebulaEvent_MetadataReceived value)?  metadataReceived,TResult Function( NebulaEvent_PeerUpdate value)?  peerUpdate,TResult Function( NebulaEvent_CheckProgress value)?  checkProgress,TResult Function( NebulaEvent_CheckCompleted value)?  checkCompleted,TResult Function( NebulaEvent_TaskStalled value)?  taskStalled,TResult Function( NebulaEvent_StallRecovered value)?  stallRecovered,TResult Function( NebulaEvent_VideoStageChanged value)?  videoStageChanged,TResult Function( NebulaEvent_OutputFileResolved value)?  outputFileResolved,TResult Function( NebulaEvent_StatusChanged value)?  statusChanged,TResult Function( NebulaEvent_BilibiliLoginExpired value)?  bilibiliLoginExpired,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case NebulaEvent_TaskAdded() when taskAdded != null:
+return taskAdded(_that);case NebulaEvent_TaskStarted() when taskStarted != null:
+return taskStarted(_that);case NebulaEvent_ProgressUpdated() when progressUpdated != null:
+return progressUpdated(_that);case NebulaEvent_TaskCompleted() when taskCompleted != null:
+return taskCompleted(_that);case NebulaEvent_TaskFailed() when taskFailed != null:
+return taskFailed(_that);case NebulaEvent_TaskPaused() when taskPaused != null:
+return taskPaused(_that);case NebulaEvent_TaskResumed() when taskResumed != null:
+return taskResumed(_that);case NebulaEvent_TaskRemoved() when taskRemoved != null:
+return taskRemoved(_that);case NebulaEvent_MetadataReceived() when metadataReceived != null:
+return metadataReceived(_that);case NebulaEvent_PeerUpdate() when peerUpdate != null:
+return peerUpdate(_that);case NebulaEvent_CheckProgress() when checkProgress != null:
+return checkProgress(_that);case NebulaEvent_CheckCompleted() when checkCompleted != null:
+return checkCompleted(_that);case NebulaEvent_TaskStalled() when taskStalled != null:
+return taskStalled(_that);case NebulaEvent_StallRecovered() when stallRecovered != null:
+return stallRecovered(_that);case NebulaEvent_VideoStageChanged() when videoStageChanged != null:
+return videoStageChanged(_that);case NebulaEvent_OutputFileResolved() when outputFileResolved != null:
+return outputFileResolved(_that);case NebulaEvent_StatusChanged() when statusChanged != null:
+return statusChanged(_that);case NebulaEvent_BilibiliLoginExpired() when bilibiliLoginExpired != null:
+return bilibiliLoginExpired(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( NebulaEvent_TaskAdded value)  taskAdded,required TResult Function( NebulaEvent_TaskStarted value)  taskStarted,required TResult Function( NebulaEvent_ProgressUpdated value)  progressUpdated,required TResult Function( NebulaEvent_TaskCompleted value)  taskCompleted,required TResult Function( NebulaEvent_TaskFailed value)  taskFailed,required TResult Function( NebulaEvent_TaskPaused value)  taskPaused,required TResult Function( NebulaEvent_TaskResumed value)  taskResumed,required TResult Function( NebulaEvent_TaskRemoved value)  taskRemoved,required TResult Function( NebulaEvent_MetadataReceived value)  metadataReceived,required TResult Function( NebulaEvent_PeerUpdate value)  peerUpdate,required TResult Function( NebulaEvent_CheckProgress value)  checkProgress,required TResult Function( NebulaEvent_CheckCompleted value)  checkCompleted,required TResult Function( NebulaEvent_TaskStalled value)  taskStalled,required TResult Function( NebulaEvent_StallRecovered value)  stallRecovered,required TResult Function( NebulaEvent_VideoStageChanged value)  videoStageChanged,required TResult Function( NebulaEvent_OutputFileResolved value)  outputFileResolved,required TResult Function( NebulaEvent_StatusChanged value)  statusChanged,required TResult Function( NebulaEvent_BilibiliLoginExpired value)  bilibiliLoginExpired,}){
+final _that = this;
+switch (_that) {
+case NebulaEvent_TaskAdded():
+return taskAdded(_that);case NebulaEvent_TaskStarted():
+return taskStarted(_that);case NebulaEvent_ProgressUpdated():
+return progressUpdated(_that);case NebulaEvent_TaskCompleted():
+return taskCompleted(_that);case NebulaEvent_TaskFailed():
+return taskFailed(_that);case NebulaEvent_TaskPaused():
+return taskPaused(_that);case NebulaEvent_TaskResumed():
+return taskResumed(_that);case NebulaEvent_TaskRemoved():
+return taskRemoved(_that);case NebulaEvent_MetadataReceived():
+return metadataReceived(_that);case NebulaEvent_PeerUpdate():
+return peerUpdate(_that);case NebulaEvent_CheckProgress():
+return checkProgress(_that);case NebulaEvent_CheckCompleted():
+return checkCompleted(_that);case NebulaEvent_TaskStalled():
+return taskStalled(_that);case NebulaEvent_StallRecovered():
+return stallRecovered(_that);case NebulaEvent_VideoStageChanged():
+return videoStageChanged(_that);case NebulaEvent_OutputFileResolved():
+return outputFileResolved(_that);case NebulaEvent_StatusChanged():
+return statusChanged(_that);case NebulaEvent_BilibiliLoginExpired():
+return bilibiliLoginExpired(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( NebulaEvent_TaskAdded value)?  taskAdded,TResult? Function( NebulaEvent_TaskStarted value)?  taskStarted,TResult? Function( NebulaEvent_ProgressUpdated value)?  progressUpdated,TResult? Function( NebulaEvent_TaskCompleted value)?  taskCompleted,TResult? Function( NebulaEvent_TaskFailed value)?  taskFailed,TResult? Function( NebulaEvent_TaskPaused value)?  taskPaused,TResult? Function( NebulaEvent_TaskResumed value)?  taskResumed,TResult? Function( NebulaEvent_TaskRemoved value)?  taskRemoved,TResult? Function( NebulaEvent_MetadataReceived value)?  metadataReceived,TResult? Function( NebulaEvent_PeerUpdate value)?  peerUpdate,TResult? Function( NebulaEvent_CheckProgress value)?  checkProgress,TResult? Function( NebulaEvent_CheckCompleted value)?  checkCompleted,TResult? Function( NebulaEvent_TaskStalled value)?  taskStalled,TResult? Function( NebulaEvent_StallRecovered value)?  stallRecovered,TResult? Function( NebulaEvent_VideoStageChanged value)?  videoStageChanged,TResult? Function( NebulaEvent_OutputFileResolved value)?  outputFileResolved,TResult? Function( NebulaEvent_StatusChanged value)?  statusChanged,TResult? Function( NebulaEvent_BilibiliLoginExpired value)?  bilibiliLoginExpired,}){
+final _that = this;
+switch (_that) {
+case NebulaEvent_TaskAdded() when taskAdded != null:
+return taskAdded(_that);case NebulaEvent_TaskStarted() when taskStarted != null:
+return taskStarted(_that);case NebulaEvent_ProgressUpdated() when progressUpdated != null:
+return progressUpdated(_that);case NebulaEvent_TaskCompleted() when taskCompleted != null:
+return taskCompleted(_that);case NebulaEvent_TaskFailed() when taskFailed != null:
+return taskFailed(_that);case NebulaEvent_TaskPaused() when taskPaused != null:
+return taskPaused(_that);case NebulaEvent_TaskResumed() when taskResumed != null:
+return taskResumed(_that);case NebulaEvent_TaskRemoved() when taskRemoved != null:
+return taskRemoved(_that);case NebulaEvent_MetadataReceived() when metadataReceived != null:
+return metadataReceived(_that);case NebulaEvent_PeerUpdate() when peerUpdate != null:
+return peerUpdate(_that);case NebulaEvent_CheckProgress() when checkProgress != null:
+return checkProgress(_that);case NebulaEvent_CheckCompleted() when checkCompleted != null:
+return checkCompleted(_that);case NebulaEvent_TaskStalled() when taskStalled != null:
+return taskStalled(_that);case NebulaEvent_StallRecovered() when stallRecovered != null:
+return stallRecovered(_that);case NebulaEvent_VideoStageChanged() when videoStageChanged != null:
+return videoStageChanged(_that);case NebulaEvent_OutputFileResolved() when outputFileResolved != null:
+return outputFileResolved(_that);case NebulaEvent_StatusChanged() when statusChanged != null:
+return statusChanged(_that);case NebulaEvent_BilibiliLoginExpired() when bilibiliLoginExpired != null:
+return bilibiliLoginExpired(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String taskId,  String name,  String? thumbnail)?  taskAdded,TResult Function( String taskId)?  taskStarted,TResult Function( String taskId,  ProgressEvent progress)?  progressUpdated,TResult Function( String taskId)?  taskCompleted,TResult Function( String taskId,  String error)?  taskFailed,TResult Function( String taskId)?  taskPaused,TResult Function( String taskId)?  taskResumed,TResult Function( String taskId)?  taskRemoved,TResult Function( String taskId,  String name,  BigInt totalSize,  BigInt fileCount,  String? mimeType,  bool supportsResume,  String? thumbnail,  BigInt? durationSecs)?  metadataReceived,TResult Function( String taskId,  BigInt connectedPeers,  BigInt totalPeers)?  peerUpdate,TResult Function( String taskId,  BigInt checkedBytes,  BigInt totalBytes)?  checkProgress,TResult Function( String taskId,  BigInt validBytes,  BigInt totalBytes)?  checkCompleted,TResult Function( String taskId,  String phase,  BigInt stalledSecs,  BigInt? dhtNodes,  BigInt trackersTotal,  BigInt trackersReached,  BigInt peersSeen,  BigInt peersConnected)?  taskStalled,TResult Function( String taskId,  String phase)?  stallRecovered,TResult Function( String taskId,  String stage)?  videoStageChanged,TResult Function( String taskId,  String path)?  outputFileResolved,TResult Function( String taskId,  String from,  String to)?  statusChanged,TResult Function( String reason)?  bilibiliLoginExpired,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case NebulaEvent_TaskAdded() when taskAdded != null:
+return taskAdded(_that.taskId,_that.name,_that.thumbnail);case NebulaEvent_TaskStarted() when taskStarted != null:
+return taskStarted(_that.taskId);case NebulaEvent_ProgressUpdated() when progressUpdated != null:
+return progressUpdated(_that.taskId,_that.progress);case NebulaEvent_TaskCompleted() when taskCompleted != null:
+return taskCompleted(_that.taskId);case NebulaEvent_TaskFailed() when taskFailed != null:
+return taskFailed(_that.taskId,_that.error);case NebulaEvent_TaskPaused() when taskPaused != null:
+return taskPaused(_that.taskId);case NebulaEvent_TaskResumed() when taskResumed != null:
+return taskResumed(_that.taskId);case NebulaEvent_TaskRemoved() when taskRemoved != null:
+return taskRemoved(_that.taskId);case NebulaEvent_MetadataReceived() when metadataReceived != null:
+return metadataReceived(_that.taskId,_that.name,_that.totalSize,_that.fileCount,_that.mimeType,_that.supportsResume,_that.thumbnail,_that.durationSecs);case NebulaEvent_PeerUpdate() when peerUpdate != null:
+return peerUpdate(_that.taskId,_that.connectedPeers,_that.totalPeers);case NebulaEvent_CheckProgress() when checkProgress != null:
+return checkProgress(_that.taskId,_that.checkedBytes,_that.totalBytes);case NebulaEvent_CheckCompleted() when checkCompleted != null:
+return checkCompleted(_that.taskId,_that.validBytes,_that.totalBytes);case NebulaEvent_TaskStalled() when taskStalled != null:
+return taskStalled(_that.taskId,_that.phase,_that.stalledSecs,_that.dhtNodes,_that.trackersTotal,_that.trackersReached,_that.peersSeen,_that.peersConnected);case NebulaEvent_StallRecovered() when stallRecovered != null:
+return stallRecovered(_that.taskId,_that.phase);case NebulaEvent_VideoStageChanged() when videoStageChanged != null:
+return videoStageChanged(_that.taskId,_that.stage);case NebulaEvent_OutputFileResolved() when outputFileResolved != null:
+return outputFileResolved(_that.taskId,_that.path);case NebulaEvent_StatusChanged() when statusChanged != null:
+return statusChanged(_that.taskId,_that.from,_that.to);case NebulaEvent_BilibiliLoginExpired() when bilibiliLoginExpired != null:
+return bilibiliLoginExpired(_that.reason);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String taskId,  String name,  String? thumbnail)  taskAdded,required TResult Function( String taskId)  taskStarted,required TResult Function( String taskId,  ProgressEvent progress)  progressUpdated,required TResult Function( String taskId)  taskCompleted,required TResult Function( String taskId,  String error)  taskFailed,required TResult Function( String taskId)  taskPaused,required TResult Function( String taskId)  taskResumed,required TResult Function( String taskId)  taskRemoved,required TResult Function( String taskId,  String name,  BigInt totalSize,  BigInt fileCount,  String? mimeType,  bool supportsResume,  String? thumbnail,  BigInt? durationSecs)  metadataReceived,required TResult Function( String taskId,  BigInt connectedPeers,  BigInt totalPeers)  peerUpdate,required TResult Function( String taskId,  BigInt checkedBytes,  BigInt totalBytes)  checkProgress,required TResult Function( String taskId,  BigInt validBytes,  BigInt totalBytes)  checkCompleted,required TResult Function( String taskId,  String phase,  BigInt stalledSecs,  BigInt? dhtNodes,  BigInt trackersTotal,  BigInt trackersReached,  BigInt peersSeen,  BigInt peersConnected)  taskStalled,required TResult Function( String taskId,  String phase)  stallRecovered,required TResult Function( String taskId,  String stage)  videoStageChanged,required TResult Function( String taskId,  String path)  outputFileResolved,required TResult Function( String taskId,  String from,  String to)  statusChanged,required TResult Function( String reason)  bilibiliLoginExpired,}) {final _that = this;
+switch (_that) {
+case NebulaEvent_TaskAdded():
+return taskAdded(_that.taskId,_that.name,_that.thumbnail);case NebulaEvent_TaskStarted():
+return taskStarted(_that.taskId);case NebulaEvent_ProgressUpdated():
+return progressUpdated(_that.taskId,_that.progress);case NebulaEvent_TaskCompleted():
+return taskCompleted(_that.taskId);case NebulaEvent_TaskFailed():
+return taskFailed(_that.taskId,_that.error);case NebulaEvent_TaskPaused():
+return taskPaused(_that.taskId);case NebulaEvent_TaskResumed():
+return taskResumed(_that.taskId);case NebulaEvent_TaskRemoved():
+return taskRemoved(_that.taskId);case NebulaEvent_MetadataReceived():
+return metadataReceived(_that.taskId,_that.name,_that.totalSize,_that.fileCount,_that.mimeType,_that.supportsResume,_that.thumbnail,_that.durationSecs);case NebulaEvent_PeerUpdate():
+return peerUpdate(_that.taskId,_that.connectedPeers,_that.totalPeers);case NebulaEvent_CheckProgress():
+return checkProgress(_that.taskId,_that.checkedBytes,_that.totalBytes);case NebulaEvent_CheckCompleted():
+return checkCompleted(_that.taskId,_that.validBytes,_that.totalBytes);case NebulaEvent_TaskStalled():
+return taskStalled(_that.taskId,_that.phase,_that.stalledSecs,_that.dhtNodes,_that.trackersTotal,_that.trackersReached,_that.peersSeen,_that.peersConnected);case NebulaEvent_StallRecovered():
+return stallRecovered(_that.taskId,_that.phase);case NebulaEvent_VideoStageChanged():
+return videoStageChanged(_that.taskId,_that.stage);case NebulaEvent_OutputFileResolved():
+return outputFileResolved(_that.taskId,_that.path);case NebulaEvent_StatusChanged():
+return statusChanged(_that.taskId,_that.from,_that.to);case NebulaEvent_BilibiliLoginExpired():
+return bilibiliLoginExpired(_that.reason);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String taskId,  String name,  String? thumbnail)?  taskAdded,TResult? Function( String taskId)?  taskStarted,TResult? Function( String taskId,  ProgressEvent progress)?  progressUpdated,TResult? Function( String taskId)?  taskCompleted,TResult? Function( String taskId,  String error)?  taskFailed,TResult? Function( String taskId)?  taskPaused,TResult? Function( String taskId)?  taskResumed,TResult? Function( String taskId)?  taskRemoved,TResult? Function( String taskId,  String name,  BigInt totalSize,  BigInt fileCount,  String? mimeType,  bool supportsResume,  String? thumbnail,  BigInt? durationSecs)?  metadataReceived,TResult? Function( String taskId,  BigInt connectedPeers,  BigInt totalPeers)?  peerUpdate,TResult? Function( String taskId,  BigInt checkedBytes,  BigInt totalBytes)?  checkProgress,TResult? Function( String taskId,  BigInt validBytes,  BigInt totalBytes)?  checkCompleted,TResult? Function( String taskId,  String phase,  BigInt stalledSecs,  BigInt? dhtNodes,  BigInt trackersTotal,  BigInt trackersReached,  BigInt peersSeen,  BigInt peersConnected)?  taskStalled,TResult? Function( String taskId,  String phase)?  stallRecovered,TResult? Function( String taskId,  String stage)?  videoStageChanged,TResult? Function( String taskId,  String path)?  outputFileResolved,TResult? Function( String taskId,  String from,  String to)?  statusChanged,TResult? Function( String reason)?  bilibiliLoginExpired,}) {final _that = this;
+switch (_that) {
+case NebulaEvent_TaskAdded() when taskAdded != null:
+return taskAdded(_that.taskId,_that.name,_that.thumbnail);case NebulaEvent_TaskStarted() when taskStarted != null:
+return taskStarted(_that.taskId);case NebulaEvent_ProgressUpdated() when progressUpdated != null:
+return progressUpdated(_that.taskId,_that.progress);case NebulaEvent_TaskCompleted() when taskCompleted != null:
+return taskCompleted(_that.taskId);case NebulaEvent_TaskFailed() when taskFailed != null:
+return taskFailed(_that.taskId,_that.error);case NebulaEvent_TaskPaused() when taskPaused != null:
+return taskPaused(_that.taskId);case NebulaEvent_TaskResumed() when taskResumed != null:
+return taskResumed(_that.taskId);case NebulaEvent_TaskRemoved() when taskRemoved != null:
+return taskRemoved(_that.taskId);case NebulaEvent_MetadataReceived() when metadataReceived != null:
+return metadataReceived(_that.taskId,_that.name,_that.totalSize,_that.fileCount,_that.mimeType,_that.supportsResume,_that.thumbnail,_that.durationSecs);case NebulaEvent_PeerUpdate() when peerUpdate != null:
+return peerUpdate(_that.taskId,_that.connectedPeers,_that.totalPeers);case NebulaEvent_CheckProgress() when checkProgress != null:
+return checkProgress(_that.taskId,_that.checkedBytes,_that.totalBytes);case NebulaEvent_CheckCompleted() when checkCompleted != null:
+return checkCompleted(_that.taskId,_that.validBytes,_that.totalBytes);case NebulaEvent_TaskStalled() when taskStalled != null:
+return taskStalled(_that.taskId,_that.phase,_that.stalledSecs,_that.dhtNodes,_that.trackersTotal,_that.trackersReached,_that.peersSeen,_that.peersConnected);case NebulaEvent_StallRecovered() when stallRecovered != null:
+return stallRecovered(_that.taskId,_that.phase);case NebulaEvent_VideoStageChanged() when videoStageChanged != null:
+return videoStageChanged(_that.taskId,_that.stage);case NebulaEvent_OutputFileResolved() when outputFileResolved != null:
+return outputFileResolved(_that.taskId,_that.path);case NebulaEvent_StatusChanged() when statusChanged != null:
+return statusChanged(_that.taskId,_that.from,_that.to);case NebulaEvent_BilibiliLoginExpired() when bilibiliLoginExpired != null:
+return bilibiliLoginExpired(_that.reason);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_TaskAdded extends NebulaEvent {
+  const NebulaEvent_TaskAdded({required this.taskId, required this.name, this.thumbnail}): super._();
+  
+
+ final  String taskId;
+ final  String name;
+ final  String? thumbnail;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_TaskAddedCopyWith<NebulaEvent_TaskAdded> get copyWith => _$NebulaEvent_TaskAddedCopyWithImpl<NebulaEvent_TaskAdded>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_TaskAdded&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.name, name) || other.name == name)&&(identical(other.thumbnail, thumbnail) || other.thumbnail == thumbnail));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId,name,thumbnail);
+
+@override
+String toString() {
+  return 'NebulaEvent.taskAdded(taskId: $taskId, name: $name, thumbnail: $thumbnail)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_TaskAddedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_TaskAddedCopyWith(NebulaEvent_TaskAdded value, $Res Function(NebulaEvent_TaskAdded) _then) = _$NebulaEvent_TaskAddedCopyWithImpl;
+@useResult
+$Res call({
+ String taskId, String name, String? thumbnail
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_TaskAddedCopyWithImpl<$Res>
+    implements $NebulaEvent_TaskAddedCopyWith<$Res> {
+  _$NebulaEvent_TaskAddedCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_TaskAdded _self;
+  final $Res Function(NebulaEvent_TaskAdded) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? name = null,Object? thumbnail = freezed,}) {
+  return _then(NebulaEvent_TaskAdded(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,thumbnail: freezed == thumbnail ? _self.thumbnail : thumbnail // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_TaskStarted extends NebulaEvent {
+  const NebulaEvent_TaskStarted({required this.taskId}): super._();
+  
+
+ final  String taskId;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_TaskStartedCopyWith<NebulaEvent_TaskStarted> get copyWith => _$NebulaEvent_TaskStartedCopyWithImpl<NebulaEvent_TaskStarted>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_TaskStarted&&(identical(other.taskId, taskId) || other.taskId == taskId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId);
+
+@override
+String toString() {
+  return 'NebulaEvent.taskStarted(taskId: $taskId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_TaskStartedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_TaskStartedCopyWith(NebulaEvent_TaskStarted value, $Res Function(NebulaEvent_TaskStarted) _then) = _$NebulaEvent_TaskStartedCopyWithImpl;
+@useResult
+$Res call({
+ String taskId
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_TaskStartedCopyWithImpl<$Res>
+    implements $NebulaEvent_TaskStartedCopyWith<$Res> {
+  _$NebulaEvent_TaskStartedCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_TaskStarted _self;
+  final $Res Function(NebulaEvent_TaskStarted) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,}) {
+  return _then(NebulaEvent_TaskStarted(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_ProgressUpdated extends NebulaEvent {
+  const NebulaEvent_ProgressUpdated({required this.taskId, required this.progress}): super._();
+  
+
+ final  String taskId;
+ final  ProgressEvent progress;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_ProgressUpdatedCopyWith<NebulaEvent_ProgressUpdated> get copyWith => _$NebulaEvent_ProgressUpdatedCopyWithImpl<NebulaEvent_ProgressUpdated>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_ProgressUpdated&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.progress, progress) || other.progress == progress));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId,progress);
+
+@override
+String toString() {
+  return 'NebulaEvent.progressUpdated(taskId: $taskId, progress: $progress)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_ProgressUpdatedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_ProgressUpdatedCopyWith(NebulaEvent_ProgressUpdated value, $Res Function(NebulaEvent_ProgressUpdated) _then) = _$NebulaEvent_ProgressUpdatedCopyWithImpl;
+@useResult
+$Res call({
+ String taskId, ProgressEvent progress
+});
+
+
+$ProgressEventCopyWith<$Res> get progress;
+
+}
+/// @nodoc
+class _$NebulaEvent_ProgressUpdatedCopyWithImpl<$Res>
+    implements $NebulaEvent_ProgressUpdatedCopyWith<$Res> {
+  _$NebulaEvent_ProgressUpdatedCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_ProgressUpdated _self;
+  final $Res Function(NebulaEvent_ProgressUpdated) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? progress = null,}) {
+  return _then(NebulaEvent_ProgressUpdated(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
+as ProgressEvent,
+  ));
+}
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$ProgressEventCopyWith<$Res> get progress {
+  
+  return $ProgressEventCopyWith<$Res>(_self.progress, (value) {
+    return _then(_self.copyWith(progress: value));
+  });
+}
+}
+
+/// @nodoc
+
+
+class NebulaEvent_TaskCompleted extends NebulaEvent {
+  const NebulaEvent_TaskCompleted({required this.taskId}): super._();
+  
+
+ final  String taskId;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_TaskCompletedCopyWith<NebulaEvent_TaskCompleted> get copyWith => _$NebulaEvent_TaskCompletedCopyWithImpl<NebulaEvent_TaskCompleted>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_TaskCompleted&&(identical(other.taskId, taskId) || other.taskId == taskId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId);
+
+@override
+String toString() {
+  return 'NebulaEvent.taskCompleted(taskId: $taskId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_TaskCompletedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_TaskCompletedCopyWith(NebulaEvent_TaskCompleted value, $Res Function(NebulaEvent_TaskCompleted) _then) = _$NebulaEvent_TaskCompletedCopyWithImpl;
+@useResult
+$Res call({
+ String taskId
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_TaskCompletedCopyWithImpl<$Res>
+    implements $NebulaEvent_TaskCompletedCopyWith<$Res> {
+  _$NebulaEvent_TaskCompletedCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_TaskCompleted _self;
+  final $Res Function(NebulaEvent_TaskCompleted) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,}) {
+  return _then(NebulaEvent_TaskCompleted(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_TaskFailed extends NebulaEvent {
+  const NebulaEvent_TaskFailed({required this.taskId, required this.error}): super._();
+  
+
+ final  String taskId;
+ final  String error;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_TaskFailedCopyWith<NebulaEvent_TaskFailed> get copyWith => _$NebulaEvent_TaskFailedCopyWithImpl<NebulaEvent_TaskFailed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_TaskFailed&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.error, error) || other.error == error));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId,error);
+
+@override
+String toString() {
+  return 'NebulaEvent.taskFailed(taskId: $taskId, error: $error)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_TaskFailedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_TaskFailedCopyWith(NebulaEvent_TaskFailed value, $Res Function(NebulaEvent_TaskFailed) _then) = _$NebulaEvent_TaskFailedCopyWithImpl;
+@useResult
+$Res call({
+ String taskId, String error
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_TaskFailedCopyWithImpl<$Res>
+    implements $NebulaEvent_TaskFailedCopyWith<$Res> {
+  _$NebulaEvent_TaskFailedCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_TaskFailed _self;
+  final $Res Function(NebulaEvent_TaskFailed) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? error = null,}) {
+  return _then(NebulaEvent_TaskFailed(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,error: null == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_TaskPaused extends NebulaEvent {
+  const NebulaEvent_TaskPaused({required this.taskId}): super._();
+  
+
+ final  String taskId;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_TaskPausedCopyWith<NebulaEvent_TaskPaused> get copyWith => _$NebulaEvent_TaskPausedCopyWithImpl<NebulaEvent_TaskPaused>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_TaskPaused&&(identical(other.taskId, taskId) || other.taskId == taskId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId);
+
+@override
+String toString() {
+  return 'NebulaEvent.taskPaused(taskId: $taskId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_TaskPausedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_TaskPausedCopyWith(NebulaEvent_TaskPaused value, $Res Function(NebulaEvent_TaskPaused) _then) = _$NebulaEvent_TaskPausedCopyWithImpl;
+@useResult
+$Res call({
+ String taskId
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_TaskPausedCopyWithImpl<$Res>
+    implements $NebulaEvent_TaskPausedCopyWith<$Res> {
+  _$NebulaEvent_TaskPausedCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_TaskPaused _self;
+  final $Res Function(NebulaEvent_TaskPaused) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,}) {
+  return _then(NebulaEvent_TaskPaused(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_TaskResumed extends NebulaEvent {
+  const NebulaEvent_TaskResumed({required this.taskId}): super._();
+  
+
+ final  String taskId;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_TaskResumedCopyWith<NebulaEvent_TaskResumed> get copyWith => _$NebulaEvent_TaskResumedCopyWithImpl<NebulaEvent_TaskResumed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_TaskResumed&&(identical(other.taskId, taskId) || other.taskId == taskId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId);
+
+@override
+String toString() {
+  return 'NebulaEvent.taskResumed(taskId: $taskId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_TaskResumedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_TaskResumedCopyWith(NebulaEvent_TaskResumed value, $Res Function(NebulaEvent_TaskResumed) _then) = _$NebulaEvent_TaskResumedCopyWithImpl;
+@useResult
+$Res call({
+ String taskId
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_TaskResumedCopyWithImpl<$Res>
+    implements $NebulaEvent_TaskResumedCopyWith<$Res> {
+  _$NebulaEvent_TaskResumedCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_TaskResumed _self;
+  final $Res Function(NebulaEvent_TaskResumed) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,}) {
+  return _then(NebulaEvent_TaskResumed(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_TaskRemoved extends NebulaEvent {
+  const NebulaEvent_TaskRemoved({required this.taskId}): super._();
+  
+
+ final  String taskId;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_TaskRemovedCopyWith<NebulaEvent_TaskRemoved> get copyWith => _$NebulaEvent_TaskRemovedCopyWithImpl<NebulaEvent_TaskRemoved>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_TaskRemoved&&(identical(other.taskId, taskId) || other.taskId == taskId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId);
+
+@override
+String toString() {
+  return 'NebulaEvent.taskRemoved(taskId: $taskId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_TaskRemovedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_TaskRemovedCopyWith(NebulaEvent_TaskRemoved value, $Res Function(NebulaEvent_TaskRemoved) _then) = _$NebulaEvent_TaskRemovedCopyWithImpl;
+@useResult
+$Res call({
+ String taskId
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_TaskRemovedCopyWithImpl<$Res>
+    implements $NebulaEvent_TaskRemovedCopyWith<$Res> {
+  _$NebulaEvent_TaskRemovedCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_TaskRemoved _self;
+  final $Res Function(NebulaEvent_TaskRemoved) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,}) {
+  return _then(NebulaEvent_TaskRemoved(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_MetadataReceived extends NebulaEvent {
+  const NebulaEvent_MetadataReceived({required this.taskId, required this.name, required this.totalSize, required this.fileCount, this.mimeType, required this.supportsResume, this.thumbnail, this.durationSecs}): super._();
+  
+
+ final  String taskId;
+ final  String name;
+ final  BigInt totalSize;
+ final  BigInt fileCount;
+ final  String? mimeType;
+ final  bool supportsResume;
+ final  String? thumbnail;
+ final  BigInt? durationSecs;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_MetadataReceivedCopyWith<NebulaEvent_MetadataReceived> get copyWith => _$NebulaEvent_MetadataReceivedCopyWithImpl<NebulaEvent_MetadataReceived>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_MetadataReceived&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.name, name) || other.name == name)&&(identical(other.totalSize, totalSize) || other.totalSize == totalSize)&&(identical(other.fileCount, fileCount) || other.fileCount == fileCount)&&(identical(other.mimeType, mimeType) || other.mimeType == mimeType)&&(identical(other.supportsResume, supportsResume) || other.supportsResume == supportsResume)&&(identical(other.thumbnail, thumbnail) || other.thumbnail == thumbnail)&&(identical(other.durationSecs, durationSecs) || other.durationSecs == durationSecs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId,name,totalSize,fileCount,mimeType,supportsResume,thumbnail,durationSecs);
+
+@override
+String toString() {
+  return 'NebulaEvent.metadataReceived(taskId: $taskId, name: $name, totalSize: $totalSize, fileCount: $fileCount, mimeType: $mimeType, supportsResume: $supportsResume, thumbnail: $thumbnail, durationSecs: $durationSecs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_MetadataReceivedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_MetadataReceivedCopyWith(NebulaEvent_MetadataReceived value, $Res Function(NebulaEvent_MetadataReceived) _then) = _$NebulaEvent_MetadataReceivedCopyWithImpl;
+@useResult
+$Res call({
+ String taskId, String name, BigInt totalSize, BigInt fileCount, String? mimeType, bool supportsResume, String? thumbnail, BigInt? durationSecs
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_MetadataReceivedCopyWithImpl<$Res>
+    implements $NebulaEvent_MetadataReceivedCopyWith<$Res> {
+  _$NebulaEvent_MetadataReceivedCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_MetadataReceived _self;
+  final $Res Function(NebulaEvent_MetadataReceived) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? name = null,Object? totalSize = null,Object? fileCount = null,Object? mimeType = freezed,Object? supportsResume = null,Object? thumbnail = freezed,Object? durationSecs = freezed,}) {
+  return _then(NebulaEvent_MetadataReceived(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,totalSize: null == totalSize ? _self.totalSize : totalSize // ignore: cast_nullable_to_non_nullable
+as BigInt,fileCount: null == fileCount ? _self.fileCount : fileCount // ignore: cast_nullable_to_non_nullable
+as BigInt,mimeType: freezed == mimeType ? _self.mimeType : mimeType // ignore: cast_nullable_to_non_nullable
+as String?,supportsResume: null == supportsResume ? _self.supportsResume : supportsResume // ignore: cast_nullable_to_non_nullable
+as bool,thumbnail: freezed == thumbnail ? _self.thumbnail : thumbnail // ignore: cast_nullable_to_non_nullable
+as String?,durationSecs: freezed == durationSecs ? _self.durationSecs : durationSecs // ignore: cast_nullable_to_non_nullable
+as BigInt?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_PeerUpdate extends NebulaEvent {
+  const NebulaEvent_PeerUpdate({required this.taskId, required this.connectedPeers, required this.totalPeers}): super._();
+  
+
+ final  String taskId;
+ final  BigInt connectedPeers;
+ final  BigInt totalPeers;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_PeerUpdateCopyWith<NebulaEvent_PeerUpdate> get copyWith => _$NebulaEvent_PeerUpdateCopyWithImpl<NebulaEvent_PeerUpdate>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_PeerUpdate&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.connectedPeers, connectedPeers) || other.connectedPeers == connectedPeers)&&(identical(other.totalPeers, totalPeers) || other.totalPeers == totalPeers));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId,connectedPeers,totalPeers);
+
+@override
+String toString() {
+  return 'NebulaEvent.peerUpdate(taskId: $taskId, connectedPeers: $connectedPeers, totalPeers: $totalPeers)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_PeerUpdateCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_PeerUpdateCopyWith(NebulaEvent_PeerUpdate value, $Res Function(NebulaEvent_PeerUpdate) _then) = _$NebulaEvent_PeerUpdateCopyWithImpl;
+@useResult
+$Res call({
+ String taskId, BigInt connectedPeers, BigInt totalPeers
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_PeerUpdateCopyWithImpl<$Res>
+    implements $NebulaEvent_PeerUpdateCopyWith<$Res> {
+  _$NebulaEvent_PeerUpdateCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_PeerUpdate _self;
+  final $Res Function(NebulaEvent_PeerUpdate) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? connectedPeers = null,Object? totalPeers = null,}) {
+  return _then(NebulaEvent_PeerUpdate(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,connectedPeers: null == connectedPeers ? _self.connectedPeers : connectedPeers // ignore: cast_nullable_to_non_nullable
+as BigInt,totalPeers: null == totalPeers ? _self.totalPeers : totalPeers // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_CheckProgress extends NebulaEvent {
+  const NebulaEvent_CheckProgress({required this.taskId, required this.checkedBytes, required this.totalBytes}): super._();
+  
+
+ final  String taskId;
+ final  BigInt checkedBytes;
+ final  BigInt totalBytes;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_CheckProgressCopyWith<NebulaEvent_CheckProgress> get copyWith => _$NebulaEvent_CheckProgressCopyWithImpl<NebulaEvent_CheckProgress>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_CheckProgress&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.checkedBytes, checkedBytes) || other.checkedBytes == checkedBytes)&&(identical(other.totalBytes, totalBytes) || other.totalBytes == totalBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId,checkedBytes,totalBytes);
+
+@override
+String toString() {
+  return 'NebulaEvent.checkProgress(taskId: $taskId, checkedBytes: $checkedBytes, totalBytes: $totalBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_CheckProgressCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_CheckProgressCopyWith(NebulaEvent_CheckProgress value, $Res Function(NebulaEvent_CheckProgress) _then) = _$NebulaEvent_CheckProgressCopyWithImpl;
+@useResult
+$Res call({
+ String taskId, BigInt checkedBytes, BigInt totalBytes
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_CheckProgressCopyWithImpl<$Res>
+    implements $NebulaEvent_CheckProgressCopyWith<$Res> {
+  _$NebulaEvent_CheckProgressCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_CheckProgress _self;
+  final $Res Function(NebulaEvent_CheckProgress) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? checkedBytes = null,Object? totalBytes = null,}) {
+  return _then(NebulaEvent_CheckProgress(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,checkedBytes: null == checkedBytes ? _self.checkedBytes : checkedBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,totalBytes: null == totalBytes ? _self.totalBytes : totalBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_CheckCompleted extends NebulaEvent {
+  const NebulaEvent_CheckCompleted({required this.taskId, required this.validBytes, required this.totalBytes}): super._();
+  
+
+ final  String taskId;
+ final  BigInt validBytes;
+ final  BigInt totalBytes;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_CheckCompletedCopyWith<NebulaEvent_CheckCompleted> get copyWith => _$NebulaEvent_CheckCompletedCopyWithImpl<NebulaEvent_CheckCompleted>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_CheckCompleted&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.validBytes, validBytes) || other.validBytes == validBytes)&&(identical(other.totalBytes, totalBytes) || other.totalBytes == totalBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId,validBytes,totalBytes);
+
+@override
+String toString() {
+  return 'NebulaEvent.checkCompleted(taskId: $taskId, validBytes: $validBytes, totalBytes: $totalBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_CheckCompletedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_CheckCompletedCopyWith(NebulaEvent_CheckCompleted value, $Res Function(NebulaEvent_CheckCompleted) _then) = _$NebulaEvent_CheckCompletedCopyWithImpl;
+@useResult
+$Res call({
+ String taskId, BigInt validBytes, BigInt totalBytes
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_CheckCompletedCopyWithImpl<$Res>
+    implements $NebulaEvent_CheckCompletedCopyWith<$Res> {
+  _$NebulaEvent_CheckCompletedCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_CheckCompleted _self;
+  final $Res Function(NebulaEvent_CheckCompleted) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? validBytes = null,Object? totalBytes = null,}) {
+  return _then(NebulaEvent_CheckCompleted(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,validBytes: null == validBytes ? _self.validBytes : validBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,totalBytes: null == totalBytes ? _self.totalBytes : totalBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_TaskStalled extends NebulaEvent {
+  const NebulaEvent_TaskStalled({required this.taskId, required this.phase, required this.stalledSecs, this.dhtNodes, required this.trackersTotal, required this.trackersReached, required this.peersSeen, required this.peersConnected}): super._();
+  
+
+ final  String taskId;
+ final  String phase;
+ final  BigInt stalledSecs;
+ final  BigInt? dhtNodes;
+ final  BigInt trackersTotal;
+ final  BigInt trackersReached;
+ final  BigInt peersSeen;
+ final  BigInt peersConnected;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_TaskStalledCopyWith<NebulaEvent_TaskStalled> get copyWith => _$NebulaEvent_TaskStalledCopyWithImpl<NebulaEvent_TaskStalled>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_TaskStalled&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.phase, phase) || other.phase == phase)&&(identical(other.stalledSecs, stalledSecs) || other.stalledSecs == stalledSecs)&&(identical(other.dhtNodes, dhtNodes) || other.dhtNodes == dhtNodes)&&(identical(other.trackersTotal, trackersTotal) || other.trackersTotal == trackersTotal)&&(identical(other.trackersReached, trackersReached) || other.trackersReached == trackersReached)&&(identical(other.peersSeen, peersSeen) || other.peersSeen == peersSeen)&&(identical(other.peersConnected, peersConnected) || other.peersConnected == peersConnected));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId,phase,stalledSecs,dhtNodes,trackersTotal,trackersReached,peersSeen,peersConnected);
+
+@override
+String toString() {
+  return 'NebulaEvent.taskStalled(taskId: $taskId, phase: $phase, stalledSecs: $stalledSecs, dhtNodes: $dhtNodes, trackersTotal: $trackersTotal, trackersReached: $trackersReached, peersSeen: $peersSeen, peersConnected: $peersConnected)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_TaskStalledCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_TaskStalledCopyWith(NebulaEvent_TaskStalled value, $Res Function(NebulaEvent_TaskStalled) _then) = _$NebulaEvent_TaskStalledCopyWithImpl;
+@useResult
+$Res call({
+ String taskId, String phase, BigInt stalledSecs, BigInt? dhtNodes, BigInt trackersTotal, BigInt trackersReached, BigInt peersSeen, BigInt peersConnected
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_TaskStalledCopyWithImpl<$Res>
+    implements $NebulaEvent_TaskStalledCopyWith<$Res> {
+  _$NebulaEvent_TaskStalledCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_TaskStalled _self;
+  final $Res Function(NebulaEvent_TaskStalled) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? phase = null,Object? stalledSecs = null,Object? dhtNodes = freezed,Object? trackersTotal = null,Object? trackersReached = null,Object? peersSeen = null,Object? peersConnected = null,}) {
+  return _then(NebulaEvent_TaskStalled(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,phase: null == phase ? _self.phase : phase // ignore: cast_nullable_to_non_nullable
+as String,stalledSecs: null == stalledSecs ? _self.stalledSecs : stalledSecs // ignore: cast_nullable_to_non_nullable
+as BigInt,dhtNodes: freezed == dhtNodes ? _self.dhtNodes : dhtNodes // ignore: cast_nullable_to_non_nullable
+as BigInt?,trackersTotal: null == trackersTotal ? _self.trackersTotal : trackersTotal // ignore: cast_nullable_to_non_nullable
+as BigInt,trackersReached: null == trackersReached ? _self.trackersReached : trackersReached // ignore: cast_nullable_to_non_nullable
+as BigInt,peersSeen: null == peersSeen ? _self.peersSeen : peersSeen // ignore: cast_nullable_to_non_nullable
+as BigInt,peersConnected: null == peersConnected ? _self.peersConnected : peersConnected // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_StallRecovered extends NebulaEvent {
+  const NebulaEvent_StallRecovered({required this.taskId, required this.phase}): super._();
+  
+
+ final  String taskId;
+ final  String phase;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_StallRecoveredCopyWith<NebulaEvent_StallRecovered> get copyWith => _$NebulaEvent_StallRecoveredCopyWithImpl<NebulaEvent_StallRecovered>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_StallRecovered&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.phase, phase) || other.phase == phase));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId,phase);
+
+@override
+String toString() {
+  return 'NebulaEvent.stallRecovered(taskId: $taskId, phase: $phase)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_StallRecoveredCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_StallRecoveredCopyWith(NebulaEvent_StallRecovered value, $Res Function(NebulaEvent_StallRecovered) _then) = _$NebulaEvent_StallRecoveredCopyWithImpl;
+@useResult
+$Res call({
+ String taskId, String phase
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_StallRecoveredCopyWithImpl<$Res>
+    implements $NebulaEvent_StallRecoveredCopyWith<$Res> {
+  _$NebulaEvent_StallRecoveredCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_StallRecovered _self;
+  final $Res Function(NebulaEvent_StallRecovered) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? phase = null,}) {
+  return _then(NebulaEvent_StallRecovered(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,phase: null == phase ? _self.phase : phase // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_VideoStageChanged extends NebulaEvent {
+  const NebulaEvent_VideoStageChanged({required this.taskId, required this.stage}): super._();
+  
+
+ final  String taskId;
+ final  String stage;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_VideoStageChangedCopyWith<NebulaEvent_VideoStageChanged> get copyWith => _$NebulaEvent_VideoStageChangedCopyWithImpl<NebulaEvent_VideoStageChanged>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_VideoStageChanged&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.stage, stage) || other.stage == stage));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId,stage);
+
+@override
+String toString() {
+  return 'NebulaEvent.videoStageChanged(taskId: $taskId, stage: $stage)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_VideoStageChangedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_VideoStageChangedCopyWith(NebulaEvent_VideoStageChanged value, $Res Function(NebulaEvent_VideoStageChanged) _then) = _$NebulaEvent_VideoStageChangedCopyWithImpl;
+@useResult
+$Res call({
+ String taskId, String stage
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_VideoStageChangedCopyWithImpl<$Res>
+    implements $NebulaEvent_VideoStageChangedCopyWith<$Res> {
+  _$NebulaEvent_VideoStageChangedCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_VideoStageChanged _self;
+  final $Res Function(NebulaEvent_VideoStageChanged) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? stage = null,}) {
+  return _then(NebulaEvent_VideoStageChanged(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,stage: null == stage ? _self.stage : stage // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_OutputFileResolved extends NebulaEvent {
+  const NebulaEvent_OutputFileResolved({required this.taskId, required this.path}): super._();
+  
+
+ final  String taskId;
+ final  String path;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_OutputFileResolvedCopyWith<NebulaEvent_OutputFileResolved> get copyWith => _$NebulaEvent_OutputFileResolvedCopyWithImpl<NebulaEvent_OutputFileResolved>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_OutputFileResolved&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.path, path) || other.path == path));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId,path);
+
+@override
+String toString() {
+  return 'NebulaEvent.outputFileResolved(taskId: $taskId, path: $path)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_OutputFileResolvedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_OutputFileResolvedCopyWith(NebulaEvent_OutputFileResolved value, $Res Function(NebulaEvent_OutputFileResolved) _then) = _$NebulaEvent_OutputFileResolvedCopyWithImpl;
+@useResult
+$Res call({
+ String taskId, String path
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_OutputFileResolvedCopyWithImpl<$Res>
+    implements $NebulaEvent_OutputFileResolvedCopyWith<$Res> {
+  _$NebulaEvent_OutputFileResolvedCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_OutputFileResolved _self;
+  final $Res Function(NebulaEvent_OutputFileResolved) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? path = null,}) {
+  return _then(NebulaEvent_OutputFileResolved(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_StatusChanged extends NebulaEvent {
+  const NebulaEvent_StatusChanged({required this.taskId, required this.from, required this.to}): super._();
+  
+
+ final  String taskId;
+ final  String from;
+ final  String to;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_StatusChangedCopyWith<NebulaEvent_StatusChanged> get copyWith => _$NebulaEvent_StatusChangedCopyWithImpl<NebulaEvent_StatusChanged>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_StatusChanged&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.from, from) || other.from == from)&&(identical(other.to, to) || other.to == to));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId,from,to);
+
+@override
+String toString() {
+  return 'NebulaEvent.statusChanged(taskId: $taskId, from: $from, to: $to)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_StatusChangedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_StatusChangedCopyWith(NebulaEvent_StatusChanged value, $Res Function(NebulaEvent_StatusChanged) _then) = _$NebulaEvent_StatusChangedCopyWithImpl;
+@useResult
+$Res call({
+ String taskId, String from, String to
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_StatusChangedCopyWithImpl<$Res>
+    implements $NebulaEvent_StatusChangedCopyWith<$Res> {
+  _$NebulaEvent_StatusChangedCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_StatusChanged _self;
+  final $Res Function(NebulaEvent_StatusChanged) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? from = null,Object? to = null,}) {
+  return _then(NebulaEvent_StatusChanged(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,from: null == from ? _self.from : from // ignore: cast_nullable_to_non_nullable
+as String,to: null == to ? _self.to : to // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NebulaEvent_BilibiliLoginExpired extends NebulaEvent {
+  const NebulaEvent_BilibiliLoginExpired({required this.reason}): super._();
+  
+
+ final  String reason;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NebulaEvent_BilibiliLoginExpiredCopyWith<NebulaEvent_BilibiliLoginExpired> get copyWith => _$NebulaEvent_BilibiliLoginExpiredCopyWithImpl<NebulaEvent_BilibiliLoginExpired>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_BilibiliLoginExpired&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,reason);
+
+@override
+String toString() {
+  return 'NebulaEvent.bilibiliLoginExpired(reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NebulaEvent_BilibiliLoginExpiredCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
+  factory $NebulaEvent_BilibiliLoginExpiredCopyWith(NebulaEvent_BilibiliLoginExpired value, $Res Function(NebulaEvent_BilibiliLoginExpired) _then) = _$NebulaEvent_BilibiliLoginExpiredCopyWithImpl;
+@useResult
+$Res call({
+ String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$NebulaEvent_BilibiliLoginExpiredCopyWithImpl<$Res>
+    implements $NebulaEvent_BilibiliLoginExpiredCopyWith<$Res> {
+  _$NebulaEvent_BilibiliLoginExpiredCopyWithImpl(this._self, this._then);
+
+  final NebulaEvent_BilibiliLoginExpired _self;
+  final $Res Function(NebulaEvent_BilibiliLoginExpired) _then;
+
+/// Create a copy of NebulaEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? reason = null,}) {
+  return _then(NebulaEvent_BilibiliLoginExpired(
+reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$PeerInfo {
+
+ String get address; String? get client; String get flags; BigInt get downloadSpeed; BigInt get uploadSpeed; BigInt get downloaded; BigInt get uploaded; double? get progress;
+/// Create a copy of PeerInfo
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PeerInfoCopyWith<PeerInfo> get copyWith => _$PeerInfoCopyWithImpl<PeerInfo>(this as PeerInfo, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PeerInfo&&(identical(other.address, address) || other.address == address)&&(identical(other.client, client) || other.client == client)&&(identical(other.flags, flags) || other.flags == flags)&&(identical(other.downloadSpeed, downloadSpeed) || other.downloadSpeed == downloadSpeed)&&(identical(other.uploadSpeed, uploadSpeed) || other.uploadSpeed == uploadSpeed)&&(identical(other.downloaded, downloaded) || other.downloaded == downloaded)&&(identical(other.uploaded, uploaded) || other.uploaded == uploaded)&&(identical(other.progress, progress) || other.progress == progress));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,address,client,flags,downloadSpeed,uploadSpeed,downloaded,uploaded,progress);
+
+@override
+String toString() {
+  return 'PeerInfo(address: $address, client: $client, flags: $flags, downloadSpeed: $downloadSpeed, uploadSpeed: $uploadSpeed, downloaded: $downloaded, uploaded: $uploaded, progress: $progress)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $PeerInfoCopyWith<$Res>  {
+  factory $PeerInfoCopyWith(PeerInfo value, $Res Function(PeerInfo) _then) = _$PeerInfoCopyWithImpl;
+@useResult
+$Res call({
+ String address, String? client, String flags, BigInt downloadSpeed, BigInt uploadSpeed, BigInt downloaded, BigInt uploaded, double? progress
+});
+
+
+
+
+}
+/// @nodoc
+class _$PeerInfoCopyWithImpl<$Res>
+    implements $PeerInfoCopyWith<$Res> {
+  _$PeerInfoCopyWithImpl(this._self, this._then);
+
+  final PeerInfo _self;
+  final $Res Function(PeerInfo) _then;
+
+/// Create a copy of PeerInfo
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? address = null,Object? client = freezed,Object? flags = null,Object? downloadSpeed = null,Object? uploadSpeed = null,Object? downloaded = null,Object? uploaded = null,Object? progress = freezed,}) {
+  return _then(_self.copyWith(
+address: null == address ? _self.address : address // ignore: cast_nullable_to_non_nullable
+as String,client: freezed == client ? _self.client : client // ignore: cast_nullable_to_non_nullable
+as String?,flags: null == flags ? _self.flags : flags // ignore: cast_nullable_to_non_nullable
+as String,downloadSpeed: null == downloadSpeed ? _self.downloadSpeed : downloadSpeed // ignore: cast_nullable_to_non_nullable
+as BigInt,uploadSpeed: null == uploadSpeed ? _self.uploadSpeed : uploadSpeed // ignore: cast_nullable_to_non_nullable
+as BigInt,downloaded: null == downloaded ? _self.downloaded : downloaded // ignore: cast_nullable_to_non_nullable
+as BigInt,uploaded: null == uploaded ? _self.uploaded : uploaded // ignore: cast_nullable_to_non_nullable
+as BigInt,progress: freezed == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
+as double?,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [PeerInfo].
+extension PeerInfoPatterns on PeerInfo {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _PeerInfo value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _PeerInfo() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _PeerInfo value)  $default,){
+final _that = this;
+switch (_that) {
+case _PeerInfo():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _PeerInfo value)?  $default,){
+final _that = this;
+switch (_that) {
+case _PeerInfo() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String address,  String? client,  String flags,  BigInt downloadSpeed,  BigInt uploadSpeed,  BigInt downloaded,  BigInt uploaded,  double? progress)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _PeerInfo() when $default != null:
+return $default(_that.address,_that.client,_that.flags,_that.downloadSpeed,_that.uploadSpeed,_that.downloaded,_that.uploaded,_that.progress);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String address,  String? client,  String flags,  BigInt downloadSpeed,  BigInt uploadSpeed,  BigInt downloaded,  BigInt uploaded,  double? progress)  $default,) {final _that = this;
+switch (_that) {
+case _PeerInfo():
+return $default(_that.address,_that.client,_that.flags,_that.downloadSpeed,_that.uploadSpeed,_that.downloaded,_that.uploaded,_that.progress);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String address,  String? client,  String flags,  BigInt downloadSpeed,  BigInt uploadSpeed,  BigInt downloaded,  BigInt uploaded,  double? progress)?  $default,) {final _that = this;
+switch (_that) {
+case _PeerInfo() when $default != null:
+return $default(_that.address,_that.client,_that.flags,_that.downloadSpeed,_that.uploadSpeed,_that.downloaded,_that.uploaded,_that.progress);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _PeerInfo implements PeerInfo {
+  const _PeerInfo({required this.address, this.client, required this.flags, required this.downloadSpeed, required this.uploadSpeed, required this.downloaded, required this.uploaded, this.progress});
+  
+
+@override final  String address;
+@override final  String? client;
+@override final  String flags;
+@override final  BigInt downloadSpeed;
+@override final  BigInt uploadSpeed;
+@override final  BigInt downloaded;
+@override final  BigInt uploaded;
+@override final  double? progress;
+
+/// Create a copy of PeerInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$PeerInfoCopyWith<_PeerInfo> get copyWith => __$PeerInfoCopyWithImpl<_PeerInfo>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _PeerInfo&&(identical(other.address, address) || other.address == address)&&(identical(other.client, client) || other.client == client)&&(identical(other.flags, flags) || other.flags == flags)&&(identical(other.downloadSpeed, downloadSpeed) || other.downloadSpeed == downloadSpeed)&&(identical(other.uploadSpeed, uploadSpeed) || other.uploadSpeed == uploadSpeed)&&(identical(other.downloaded, downloaded) || other.downloaded == downloaded)&&(identical(other.uploaded, uploaded) || other.uploaded == uploaded)&&(identical(other.progress, progress) || other.progress == progress));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,address,client,flags,downloadSpeed,uploadSpeed,downloaded,uploaded,progress);
+
+@override
+String toString() {
+  return 'PeerInfo(address: $address, client: $client, flags: $flags, downloadSpeed: $downloadSpeed, uploadSpeed: $uploadSpeed, downloaded: $downloaded, uploaded: $uploaded, progress: $progress)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$PeerInfoCopyWith<$Res> implements $PeerInfoCopyWith<$Res> {
+  factory _$PeerInfoCopyWith(_PeerInfo value, $Res Function(_PeerInfo) _then) = __$PeerInfoCopyWithImpl;
+@override @useResult
+$Res call({
+ String address, String? client, String flags, BigInt downloadSpeed, BigInt uploadSpeed, BigInt downloaded, BigInt uploaded, double? progress
+});
+
+
+
+
+}
+/// @nodoc
+class __$PeerInfoCopyWithImpl<$Res>
+    implements _$PeerInfoCopyWith<$Res> {
+  __$PeerInfoCopyWithImpl(this._self, this._then);
+
+  final _PeerInfo _self;
+  final $Res Function(_PeerInfo) _then;
+
+/// Create a copy of PeerInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? address = null,Object? client = freezed,Object? flags = null,Object? downloadSpeed = null,Object? uploadSpeed = null,Object? downloaded = null,Object? uploaded = null,Object? progress = freezed,}) {
+  return _then(_PeerInfo(
+address: null == address ? _self.address : address // ignore: cast_nullable_to_non_nullable
+as String,client: freezed == client ? _self.client : client // ignore: cast_nullable_to_non_nullable
+as String?,flags: null == flags ? _self.flags : flags // ignore: cast_nullable_to_non_nullable
+as String,downloadSpeed: null == downloadSpeed ? _self.downloadSpeed : downloadSpeed // ignore: cast_nullable_to_non_nullable
+as BigInt,uploadSpeed: null == uploadSpeed ? _self.uploadSpeed : uploadSpeed // ignore: cast_nullable_to_non_nullable
+as BigInt,downloaded: null == downloaded ? _self.downloaded : downloaded // ignore: cast_nullable_to_non_nullable
+as BigInt,uploaded: null == uploaded ? _self.uploaded : uploaded // ignore: cast_nullable_to_non_nullable
+as BigInt,progress: freezed == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
+as double?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$PlaylistEntryInfo {
+
+ int get index; String get id; String get title; String get url; BigInt? get duration; String? get uploader;
+/// Create a copy of PlaylistEntryInfo
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PlaylistEntryInfoCopyWith<PlaylistEntryInfo> get copyWith => _$PlaylistEntryInfoCopyWithImpl<PlaylistEntryInfo>(this as PlaylistEntryInfo, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PlaylistEntryInfo&&(identical(other.index, index) || other.index == index)&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&(identical(other.url, url) || other.url == url)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.uploader, uploader) || other.uploader == uploader));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,index,id,title,url,duration,uploader);
+
+@override
+String toString() {
+  return 'PlaylistEntryInfo(index: $index, id: $id, title: $title, url: $url, duration: $duration, uploader: $uploader)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $PlaylistEntryInfoCopyWith<$Res>  {
+  factory $PlaylistEntryInfoCopyWith(PlaylistEntryInfo value, $Res Function(PlaylistEntryInfo) _then) = _$PlaylistEntryInfoCopyWithImpl;
+@useResult
+$Res call({
+ int index, String id, String title, String url, BigInt? duration, String? uploader
+});
+
+
+
+
+}
+/// @nodoc
+class _$PlaylistEntryInfoCopyWithImpl<$Res>
+    implements $PlaylistEntryInfoCopyWith<$Res> {
+  _$PlaylistEntryInfoCopyWithImpl(this._self, this._then);
+
+  final PlaylistEntryInfo _self;
+  final $Res Function(PlaylistEntryInfo) _then;
+
+/// Create a copy of PlaylistEntryInfo
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? index = null,Object? id = null,Object? title = null,Object? url = null,Object? duration = freezed,Object? uploader = freezed,}) {
+  return _then(_self.copyWith(
+index: null == index ? _self.index : index // ignore: cast_nullable_to_non_nullable
+as int,id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,url: null == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
+as String,duration: freezed == duration ? _self.duration : duration // ignore: cast_nullable_to_non_nullable
+as BigInt?,uploader: freezed == uploader ? _self.uploader : uploader // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [PlaylistEntryInfo].
+extension PlaylistEntryInfoPatterns on PlaylistEntryInfo {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _PlaylistEntryInfo value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _PlaylistEntryInfo() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _PlaylistEntryInfo value)  $default,){
+final _that = this;
+switch (_that) {
+case _PlaylistEntryInfo():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _PlaylistEntryInfo value)?  $default,){
+final _that = this;
+switch (_that) {
+case _PlaylistEntryInfo() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( int index,  String id,  String title,  String url,  BigInt? duration,  String? uploader)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _PlaylistEntryInfo() when $default != null:
+return $default(_that.index,_that.id,_that.title,_that.url,_that.duration,_that.uploader);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( int index,  String id,  String title,  String url,  BigInt? duration,  String? uploader)  $default,) {final _that = this;
+switch (_that) {
+case _PlaylistEntryInfo():
+return $default(_that.index,_that.id,_that.title,_that.url,_that.duration,_that.uploader);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( int index,  String id,  String title,  String url,  BigInt? duration,  String? uploader)?  $default,) {final _that = this;
+switch (_that) {
+case _PlaylistEntryInfo() when $default != null:
+return $default(_that.index,_that.id,_that.title,_that.url,_that.duration,_that.uploader);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _PlaylistEntryInfo implements PlaylistEntryInfo {
+  const _PlaylistEntryInfo({required this.index, required this.id, required this.title, required this.url, this.duration, this.uploader});
+  
+
+@override final  int index;
+@override final  String id;
+@override final  String title;
+@override final  String url;
+@override final  BigInt? duration;
+@override final  String? uploader;
+
+/// Create a copy of PlaylistEntryInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$PlaylistEntryInfoCopyWith<_PlaylistEntryInfo> get copyWith => __$PlaylistEntryInfoCopyWithImpl<_PlaylistEntryInfo>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _PlaylistEntryInfo&&(identical(other.index, index) || other.index == index)&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&(identical(other.url, url) || other.url == url)&&(identical(other.duration, duration) || other.duration == duration)&&(identical(other.uploader, uploader) || other.uploader == uploader));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,index,id,title,url,duration,uploader);
+
+@override
+String toString() {
+  return 'PlaylistEntryInfo(index: $index, id: $id, title: $title, url: $url, duration: $duration, uploader: $uploader)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$PlaylistEntryInfoCopyWith<$Res> implements $PlaylistEntryInfoCopyWith<$Res> {
+  factory _$PlaylistEntryInfoCopyWith(_PlaylistEntryInfo value, $Res Function(_PlaylistEntryInfo) _then) = __$PlaylistEntryInfoCopyWithImpl;
+@override @useResult
+$Res call({
+ int index, String id, String title, String url, BigInt? duration, String? uploader
+});
+
+
+
+
+}
+/// @nodoc
+class __$PlaylistEntryInfoCopyWithImpl<$Res>
+    implements _$PlaylistEntryInfoCopyWith<$Res> {
+  __$PlaylistEntryInfoCopyWithImpl(this._self, this._then);
+
+  final _PlaylistEntryInfo _self;
+  final $Res Function(_PlaylistEntryInfo) _then;
+
+/// Create a copy of PlaylistEntryInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? index = null,Object? id = null,Object? title = null,Object? url = null,Object? duration = freezed,Object? uploader = freezed,}) {
+  return _then(_PlaylistEntryInfo(
+index: null == index ? _self.index : index // ignore: cast_nullable_to_non_nullable
+as int,id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,url: null == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
+as String,duration: freezed == duration ? _self.duration : duration // ignore: cast_nullable_to_non_nullable
+as BigInt?,uploader: freezed == uploader ? _self.uploader : uploader // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$PlaylistInfo {
+
+ String get id; String get title; String? get uploader; List<PlaylistEntryInfo> get entries;
+/// Create a copy of PlaylistInfo
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PlaylistInfoCopyWith<PlaylistInfo> get copyWith => _$PlaylistInfoCopyWithImpl<PlaylistInfo>(this as PlaylistInfo, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PlaylistInfo&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&(identical(other.uploader, uploader) || other.uploader == uploader)&&const DeepCollectionEquality().equals(other.entries, entries));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,title,uploader,const DeepCollectionEquality().hash(entries));
+
+@override
+String toString() {
+  return 'PlaylistInfo(id: $id, title: $title, uploader: $uploader, entries: $entries)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $PlaylistInfoCopyWith<$Res>  {
+  factory $PlaylistInfoCopyWith(PlaylistInfo value, $Res Function(PlaylistInfo) _then) = _$PlaylistInfoCopyWithImpl;
+@useResult
+$Res call({
+ String id, String title, String? uploader, List<PlaylistEntryInfo> entries
+});
+
+
+
+
+}
+/// @nodoc
+class _$PlaylistInfoCopyWithImpl<$Res>
+    implements $PlaylistInfoCopyWith<$Res> {
+  _$PlaylistInfoCopyWithImpl(this._self, this._then);
+
+  final PlaylistInfo _self;
+  final $Res Function(PlaylistInfo) _then;
+
+/// Create a copy of PlaylistInfo
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? title = null,Object? uploader = freezed,Object? entries = null,}) {
+  return _then(_self.copyWith(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,uploader: freezed == uploader ? _self.uploader : uploader // ignore: cast_nullable_to_non_nullable
+as String?,entries: null == entries ? _self.entries : entries // ignore: cast_nullable_to_non_nullable
+as List<PlaylistEntryInfo>,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [PlaylistInfo].
+extension PlaylistInfoPatterns on PlaylistInfo {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _PlaylistInfo value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _PlaylistInfo() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _PlaylistInfo value)  $default,){
+final _that = this;
+switch (_that) {
+case _PlaylistInfo():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _PlaylistInfo value)?  $default,){
+final _that = this;
+switch (_that) {
+case _PlaylistInfo() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String title,  String? uploader,  List<PlaylistEntryInfo> entries)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _PlaylistInfo() when $default != null:
+return $default(_that.id,_that.title,_that.uploader,_that.entries);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String title,  String? uploader,  List<PlaylistEntryInfo> entries)  $default,) {final _that = this;
+switch (_that) {
+case _PlaylistInfo():
+return $default(_that.id,_that.title,_that.uploader,_that.entries);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String title,  String? uploader,  List<PlaylistEntryInfo> entries)?  $default,) {final _that = this;
+switch (_that) {
+case _PlaylistInfo() when $default != null:
+return $default(_that.id,_that.title,_that.uploader,_that.entries);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _PlaylistInfo implements PlaylistInfo {
+  const _PlaylistInfo({required this.id, required this.title, this.uploader, required final  List<PlaylistEntryInfo> entries}): _entries = entries;
+  
+
+@override final  String id;
+@override final  String title;
+@override final  String? uploader;
+ final  List<PlaylistEntryInfo> _entries;
+@override List<PlaylistEntryInfo> get entries {
+  if (_entries is EqualUnmodifiableListView) return _entries;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_entries);
+}
+
+
+/// Create a copy of PlaylistInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$PlaylistInfoCopyWith<_PlaylistInfo> get copyWith => __$PlaylistInfoCopyWithImpl<_PlaylistInfo>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _PlaylistInfo&&(identical(other.id, id) || other.id == id)&&(identical(other.title, title) || other.title == title)&&(identical(other.uploader, uploader) || other.uploader == uploader)&&const DeepCollectionEquality().equals(other._entries, _entries));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,title,uploader,const DeepCollectionEquality().hash(_entries));
+
+@override
+String toString() {
+  return 'PlaylistInfo(id: $id, title: $title, uploader: $uploader, entries: $entries)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$PlaylistInfoCopyWith<$Res> implements $PlaylistInfoCopyWith<$Res> {
+  factory _$PlaylistInfoCopyWith(_PlaylistInfo value, $Res Function(_PlaylistInfo) _then) = __$PlaylistInfoCopyWithImpl;
+@override @useResult
+$Res call({
+ String id, String title, String? uploader, List<PlaylistEntryInfo> entries
+});
+
+
+
+
+}
+/// @nodoc
+class __$PlaylistInfoCopyWithImpl<$Res>
+    implements _$PlaylistInfoCopyWith<$Res> {
+  __$PlaylistInfoCopyWithImpl(this._self, this._then);
+
+  final _PlaylistInfo _self;
+  final $Res Function(_PlaylistInfo) _then;
+
+/// Create a copy of PlaylistInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? title = null,Object? uploader = freezed,Object? entries = null,}) {
+  return _then(_PlaylistInfo(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,title: null == title ? _self.title : title // ignore: cast_nullable_to_non_nullable
+as String,uploader: freezed == uploader ? _self.uploader : uploader // ignore: cast_nullable_to_non_nullable
+as String?,entries: null == entries ? _self._entries : entries // ignore: cast_nullable_to_non_nullable
+as List<PlaylistEntryInfo>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ProgressEvent {
+
+ String get taskId; BigInt get totalSize; BigInt get downloadedSize; BigInt get downloadSpeed; double get percentage; BigInt? get etaSecs;
+/// Create a copy of ProgressEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ProgressEventCopyWith<ProgressEvent> get copyWith => _$ProgressEventCopyWithImpl<ProgressEvent>(this as ProgressEvent, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ProgressEvent&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.totalSize, totalSize) || other.totalSize == totalSize)&&(identical(other.downloadedSize, downloadedSize) || other.downloadedSize == downloadedSize)&&(identical(other.downloadSpeed, downloadSpeed) || other.downloadSpeed == downloadSpeed)&&(identical(other.percentage, percentage) || other.percentage == percentage)&&(identical(other.etaSecs, etaSecs) || other.etaSecs == etaSecs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId,totalSize,downloadedSize,downloadSpeed,percentage,etaSecs);
+
+@override
+String toString() {
+  return 'ProgressEvent(taskId: $taskId, totalSize: $totalSize, downloadedSize: $downloadedSize, downloadSpeed: $downloadSpeed, percentage: $percentage, etaSecs: $etaSecs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ProgressEventCopyWith<$Res>  {
+  factory $ProgressEventCopyWith(ProgressEvent value, $Res Function(ProgressEvent) _then) = _$ProgressEventCopyWithImpl;
+@useResult
+$Res call({
+ String taskId, BigInt totalSize, BigInt downloadedSize, BigInt downloadSpeed, double percentage, BigInt? etaSecs
+});
+
+
+
+
+}
+/// @nodoc
+class _$ProgressEventCopyWithImpl<$Res>
+    implements $ProgressEventCopyWith<$Res> {
+  _$ProgressEventCopyWithImpl(this._self, this._then);
+
+  final ProgressEvent _self;
+  final $Res Function(ProgressEvent) _then;
+
+/// Create a copy of ProgressEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? taskId = null,Object? totalSize = null,Object? downloadedSize = null,Object? downloadSpeed = null,Object? percentage = null,Object? etaSecs = freezed,}) {
+  return _then(_self.copyWith(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,totalSize: null == totalSize ? _self.totalSize : totalSize // ignore: cast_nullable_to_non_nullable
+as BigInt,downloadedSize: null == downloadedSize ? _self.downloadedSize : downloadedSize // ignore: cast_nullable_to_non_nullable
+as BigInt,downloadSpeed: null == downloadSpeed ? _self.downloadSpeed : downloadSpeed // ignore: cast_nullable_to_non_nullable
+as BigInt,percentage: null == percentage ? _self.percentage : percentage // ignore: cast_nullable_to_non_nullable
+as double,etaSecs: freezed == etaSecs ? _self.etaSecs : etaSecs // ignore: cast_nullable_to_non_nullable
+as BigInt?,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ProgressEvent].
+extension ProgressEventPatterns on ProgressEvent {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ProgressEvent value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ProgressEvent() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ProgressEvent value)  $default,){
+final _that = this;
+switch (_that) {
+case _ProgressEvent():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ProgressEvent value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ProgressEvent() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String taskId,  BigInt totalSize,  BigInt downloadedSize,  BigInt downloadSpeed,  double percentage,  BigInt? etaSecs)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ProgressEvent() when $default != null:
+return $default(_that.taskId,_that.totalSize,_that.downloadedSize,_that.downloadSpeed,_that.percentage,_that.etaSecs);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String taskId,  BigInt totalSize,  BigInt downloadedSize,  BigInt downloadSpeed,  double percentage,  BigInt? etaSecs)  $default,) {final _that = this;
+switch (_that) {
+case _ProgressEvent():
+return $default(_that.taskId,_that.totalSize,_that.downloadedSize,_that.downloadSpeed,_that.percentage,_that.etaSecs);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String taskId,  BigInt totalSize,  BigInt downloadedSize,  BigInt downloadSpeed,  double percentage,  BigInt? etaSecs)?  $default,) {final _that = this;
+switch (_that) {
+case _ProgressEvent() when $default != null:
+return $default(_that.taskId,_that.totalSize,_that.downloadedSize,_that.downloadSpeed,_that.percentage,_that.etaSecs);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ProgressEvent implements ProgressEvent {
+  const _ProgressEvent({required this.taskId, required this.totalSize, required this.downloadedSize, required this.downloadSpeed, required this.percentage, this.etaSecs});
+  
+
+@override final  String taskId;
+@override final  BigInt totalSize;
+@override final  BigInt downloadedSize;
+@override final  BigInt downloadSpeed;
+@override final  double percentage;
+@override final  BigInt? etaSecs;
+
+/// Create a copy of ProgressEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ProgressEventCopyWith<_ProgressEvent> get copyWith => __$ProgressEventCopyWithImpl<_ProgressEvent>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ProgressEvent&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.totalSize, totalSize) || other.totalSize == totalSize)&&(identical(other.downloadedSize, downloadedSize) || other.downloadedSize == downloadedSize)&&(identical(other.downloadSpeed, downloadSpeed) || other.downloadSpeed == downloadSpeed)&&(identical(other.percentage, percentage) || other.percentage == percentage)&&(identical(other.etaSecs, etaSecs) || other.etaSecs == etaSecs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,taskId,totalSize,downloadedSize,downloadSpeed,percentage,etaSecs);
+
+@override
+String toString() {
+  return 'ProgressEvent(taskId: $taskId, totalSize: $totalSize, downloadedSize: $downloadedSize, downloadSpeed: $downloadSpeed, percentage: $percentage, etaSecs: $etaSecs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ProgressEventCopyWith<$Res> implements $ProgressEventCopyWith<$Res> {
+  factory _$ProgressEventCopyWith(_ProgressEvent value, $Res Function(_ProgressEvent) _then) = __$ProgressEventCopyWithImpl;
+@override @useResult
+$Res call({
+ String taskId, BigInt totalSize, BigInt downloadedSize, BigInt downloadSpeed, double percentage, BigInt? etaSecs
+});
+
+
+
+
+}
+/// @nodoc
+class __$ProgressEventCopyWithImpl<$Res>
+    implements _$ProgressEventCopyWith<$Res> {
+  __$ProgressEventCopyWithImpl(this._self, this._then);
+
+  final _ProgressEvent _self;
+  final $Res Function(_ProgressEvent) _then;
+
+/// Create a copy of ProgressEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? totalSize = null,Object? downloadedSize = null,Object? downloadSpeed = null,Object? percentage = null,Object? etaSecs = freezed,}) {
+  return _then(_ProgressEvent(
+taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
+as String,totalSize: null == totalSize ? _self.totalSize : totalSize // ignore: cast_nullable_to_non_nullable
+as BigInt,downloadedSize: null == downloadedSize ? _self.downloadedSize : downloadedSize // ignore: cast_nullable_to_non_nullable
+as BigInt,downloadSpeed: null == downloadSpeed ? _self.downloadSpeed : downloadSpeed // ignore: cast_nullable_to_non_nullable
+as BigInt,percentage: null == percentage ? _self.percentage : percentage // ignore: cast_nullable_to_non_nullable
+as double,etaSecs: freezed == etaSecs ? _self.etaSecs : etaSecs // ignore: cast_nullable_to_non_nullable
+as BigInt?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ResolvedTorrentInfo {
+
+ String get name; String get infoHash; BigInt get totalSize; BigInt get pieceSize; bool get private; List<TorrentFileInfo> get files;
+/// Create a copy of ResolvedTorrentInfo
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ResolvedTorrentInfoCopyWith<ResolvedTorrentInfo> get copyWith => _$ResolvedTorrentInfoCopyWithImpl<ResolvedTorrentInfo>(this as ResolvedTorrentInfo, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ResolvedTorrentInfo&&(identical(other.name, name) || other.name == name)&&(identical(other.infoHash, infoHash) || other.infoHash == infoHash)&&(identical(other.totalSize, totalSize) || other.totalSize == totalSize)&&(identical(other.pieceSize, pieceSize) || other.pieceSize == pieceSize)&&(identical(other.private, private) || other.private == private)&&const DeepCollectionEquality().equals(other.files, files));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,name,infoHash,totalSize,pieceSize,private,const DeepCollectionEquality().hash(files));
+
+@override
+String toString() {
+  return 'ResolvedTorrentInfo(name: $name, infoHash: $infoHash, totalSize: $totalSize, pieceSize: $pieceSize, private: $private, files: $files)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ResolvedTorrentInfoCopyWith<$Res>  {
+  factory $ResolvedTorrentInfoCopyWith(ResolvedTorrentInfo value, $Res Function(ResolvedTorrentInfo) _then) = _$ResolvedTorrentInfoCopyWithImpl;
+@useResult
+$Res call({
+ String name, String infoHash, BigInt totalSize, BigInt pieceSize, bool private, List<TorrentFileInfo> files
+});
+
+
+
+
+}
+/// @nodoc
+class _$ResolvedTorrentInfoCopyWithImpl<$Res>
+    implements $ResolvedTorrentInfoCopyWith<$Res> {
+  _$ResolvedTorrentInfoCopyWithImpl(this._self, this._then);
+
+  final ResolvedTorrentInfo _self;
+  final $Res Function(ResolvedTorrentInfo) _then;
+
+/// Create a copy of ResolvedTorrentInfo
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? name = null,Object? infoHash = null,Object? totalSize = null,Object? pieceSize = null,Object? private = null,Object? files = null,}) {
+  return _then(_self.copyWith(
+name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,infoHash: null == infoHash ? _self.infoHash : infoHash // ignore: cast_nullable_to_non_nullable
+as String,totalSize: null == totalSize ? _self.totalSize : totalSize // ignore: cast_nullable_to_non_nullable
+as BigInt,pieceSize: null == pieceSize ? _self.pieceSize : pieceSize // ignore: cast_nullable_to_non_nullable
+as BigInt,private: null == private ? _self.private : private // ignore: cast_nullable_to_non_nullable
+as bool,files: null == files ? _self.files : files // ignore: cast_nullable_to_non_nullable
+as List<TorrentFileInfo>,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ResolvedTorrentInfo].
+extension ResolvedTorrentInfoPatterns on ResolvedTorrentInfo {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ResolvedTorrentInfo value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ResolvedTorrentInfo() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ResolvedTorrentInfo value)  $default,){
+final _that = this;
+switch (_that) {
+case _ResolvedTorrentInfo():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ResolvedTorrentInfo value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ResolvedTorrentInfo() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String name,  String infoHash,  BigInt totalSize,  BigInt pieceSize,  bool private,  List<TorrentFileInfo> files)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ResolvedTorrentInfo() when $default != null:
+return $default(_that.name,_that.infoHash,_that.totalSize,_that.pieceSize,_that.private,_that.files);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String name,  String infoHash,  BigInt totalSize,  BigInt pieceSize,  bool private,  List<TorrentFileInfo> files)  $default,) {final _that = this;
+switch (_that) {
+case _ResolvedTorrentInfo():
+return $default(_that.name,_that.infoHash,_that.totalSize,_that.pieceSize,_that.private,_that.files);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String name,  String infoHash,  BigInt totalSize,  BigInt pieceSize,  bool private,  List<TorrentFileInfo> files)?  $default,) {final _that = this;
+switch (_that) {
+case _ResolvedTorrentInfo() when $default != null:
+return $default(_that.name,_that.infoHash,_that.totalSize,_that.pieceSize,_that.private,_that.files);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ResolvedTorrentInfo implements ResolvedTorrentInfo {
+  const _ResolvedTorrentInfo({required this.name, required this.infoHash, required this.totalSize, required this.pieceSize, required this.private, required final  List<TorrentFileInfo> files}): _files = files;
+  
+
+@override final  String name;
+@override final  String infoHash;
+@override final  BigInt totalSize;
+@override final  BigInt pieceSize;
+@override final  bool private;
+ final  List<TorrentFileInfo> _files;
+@override List<TorrentFileInfo> get files {
+  if (_files is EqualUnmodifiableListView) return _files;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_files);
+}
+
+
+/// Create a copy of ResolvedTorrentInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ResolvedTorrentInfoCopyWith<_ResolvedTorrentInfo> get copyWith => __$ResolvedTorrentInfoCopyWithImpl<_ResolvedTorrentInfo>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ResolvedTorrentInfo&&(identical(other.name, name) || other.name == name)&&(identical(other.infoHash, infoHash) || other.infoHash == infoHash)&&(identical(other.totalSize, totalSize) || other.totalSize == totalSize)&&(identical(other.pieceSize, pieceSize) || other.pieceSize == pieceSize)&&(identical(other.private, private) || other.private == private)&&const DeepCollectionEquality().equals(other._files, _files));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,name,infoHash,totalSize,pieceSize,private,const DeepCollectionEquality().hash(_files));
+
+@override
+String toString() {
+  return 'ResolvedTorrentInfo(name: $name, infoHash: $infoHash, totalSize: $totalSize, pieceSize: $pieceSize, private: $private, files: $files)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ResolvedTorrentInfoCopyWith<$Res> implements $ResolvedTorrentInfoCopyWith<$Res> {
+  factory _$ResolvedTorrentInfoCopyWith(_ResolvedTorrentInfo value, $Res Function(_ResolvedTorrentInfo) _then) = __$ResolvedTorrentInfoCopyWithImpl;
+@override @useResult
+$Res call({
+ String name, String infoHash, BigInt totalSize, BigInt pieceSize, bool private, List<TorrentFileInfo> files
+});
+
+
+
+
+}
+/// @nodoc
+class __$ResolvedTorrentInfoCopyWithImpl<$Res>
+    implements _$ResolvedTorrentInfoCopyWith<$Res> {
+  __$ResolvedTorrentInfoCopyWithImpl(this._self, this._then);
+
+  final _ResolvedTorrentInfo _self;
+  final $Res Function(_ResolvedTorrentInfo) _then;
+
+/// Create a copy of ResolvedTorrentInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? name = null,Object? infoHash = null,Object? totalSize = null,Object? pieceSize = null,Object? private = null,Object? files = null,}) {
+  return _then(_ResolvedTorrentInfo(
+name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,infoHash: null == infoHash ? _self.infoHash : infoHash // ignore: cast_nullable_to_non_nullable
+as String,totalSize: null == totalSize ? _self.totalSize : totalSize // ignore: cast_nullable_to_non_nullable
+as BigInt,pieceSize: null == pieceSize ? _self.pieceSize : pieceSize // ignore: cast_nullable_to_non_nullable
+as BigInt,private: null == private ? _self.private : private // ignore: cast_nullable_to_non_nullable
+as bool,files: null == files ? _self._files : files // ignore: cast_nullable_to_non_nullable
+as List<TorrentFileInfo>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$SiteCredentialInfo {
+
+ String get domain; bool get includeSubdomains; String get kind; String? get username; String? get headerName; PlatformInt64 get updatedAt;
+/// Create a copy of SiteCredentialInfo
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SiteCredentialInfoCopyWith<SiteCredentialInfo> get copyWith => _$SiteCredentialInfoCopyWithImpl<SiteCredentialInfo>(this as SiteCredentialInfo, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SiteCredentialInfo&&(identical(other.domain, domain) || other.domain == domain)&&(identical(other.includeSubdomains, includeSubdomains) || other.includeSubdomains == includeSubdomains)&&(identical(other.kind, kind) || other.kind == kind)&&(identical(other.username, username) || other.username == username)&&(identical(other.headerName, headerName) || other.headerName == headerName)&&(identical(other.updatedAt, updatedAt) || other.updatedAt == updatedAt));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,domain,includeSubdomains,kind,username,headerName,updatedAt);
+
+@override
+String toString() {
+  return 'SiteCredentialInfo(domain: $domain, includeSubdomains: $includeSubdomains, kind: $kind, username: $username, headerName: $headerName, updatedAt: $updatedAt)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SiteCredentialInfoCopyWith<$Res>  {
+  factory $SiteCredentialInfoCopyWith(SiteCredentialInfo value, $Res Function(SiteCredentialInfo) _then) = _$SiteCredentialInfoCopyWithImpl;
+@useResult
+$Res call({
+ String domain, bool includeSubdomains, String kind, String? username, String? headerName, PlatformInt64 updatedAt
+});
+
+
+
+
+}
+/// @nodoc
+class _$SiteCredentialInfoCopyWithImpl<$Res>
+    implements $SiteCredentialInfoCopyWith<$Res> {
+  _$SiteCredentialInfoCopyWithImpl(this._self, this._then);
+
+  final SiteCredentialInfo _self;
+  final $Res Function(SiteCredentialInfo) _then;
+
+/// Create a copy of SiteCredentialInfo
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? domain = null,Object? includeSubdomains = null,Object? kind = null,Object? username = freezed,Object? headerName = freezed,Object? updatedAt = null,}) {
+  return _then(_self.copyWith(
+domain: null == domain ? _self.domain : domain // ignore: cast_nullable_to_non_nullable
+as String,includeSubdomains: null == includeSubdomains ? _self.includeSubdomains : includeSubdomains // ignore: cast_nullable_to_non_nullable
+as bool,kind: null == kind ? _self.kind : kind // ignore: cast_nullable_to_non_nullable
+as String,username: freezed == username ? _self.username : username // ignore: cast_nullable_to_non_nullable
+as String?,headerName: freezed == headerName ? _self.headerName : headerName // ignore: cast_nullable_to_non_nullable
+as String?,updatedAt: null == updatedAt ? _self.updatedAt : updatedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [SiteCredentialInfo].
+extension SiteCredentialInfoPatterns on SiteCredentialInfo {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _SiteCredentialInfo value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _SiteCredentialInfo() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _SiteCredentialInfo value)  $default,){
+final _that = this;
+switch (_that) {
+case _SiteCredentialInfo():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _SiteCredentialInfo value)?  $default,){
+final _that = this;
+switch (_that) {
+case _SiteCredentialInfo() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String domain,  bool includeSubdomains,  String kind,  String? username,  String? headerName,  PlatformInt64 updatedAt)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _SiteCredentialInfo() when $default != null:
+return $default(_that.domain,_that.includeSubdomains,_that.kind,_that.username,_that.headerName,_that.updatedAt);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String domain,  bool includeSubdomains,  String kind,  String? username,  String? headerName,  PlatformInt64 updatedAt)  $default,) {final _that = this;
+switch (_that) {
+case _SiteCredentialInfo():
+return $default(_that.domain,_that.includeSubdomains,_that.kind,_that.username,_that.headerName,_that.updatedAt);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String domain,  bool includeSubdomains,  String kind,  String? username,  String? headerName,  PlatformInt64 updatedAt)?  $default,) {final _that = this;
+switch (_that) {
+case _SiteCredentialInfo() when $default != null:
+return $default(_that.domain,_that.includeSubdomains,_that.kind,_that.username,_that.headerName,_that.updatedAt);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _SiteCredentialInfo implements SiteCredentialInfo {
+  const _SiteCredentialInfo({required this.domain, required this.includeSubdomains, required this.kind, this.username, this.headerName, required this.updatedAt});
+  
+
+@override final  String domain;
+@override final  bool includeSubdomains;
+@override final  String kind;
+@override final  String? username;
+@override final  String? headerName;
+@override final  PlatformInt64 updatedAt;
+
+/// Create a copy of SiteCredentialInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$SiteCredentialInfoCopyWith<_SiteCredentialInfo> get copyWith => __$SiteCredentialInfoCopyWithImpl<_SiteCredentialInfo>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _SiteCredentialInfo&&(identical(other.domain, domain) || other.domain == domain)&&(identical(other.includeSubdomains, includeSubdomains) || other.includeSubdomains == includeSubdomains)&&(identical(other.kind, kind) || other.kind == kind)&&(identical(other.username, username) || other.username == username)&&(identical(other.headerName, headerName) || other.headerName == headerName)&&(identical(other.updatedAt, updatedAt) || other.updatedAt == updatedAt));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,domain,includeSubdomains,kind,username,headerName,updatedAt);
+
+@override
+String toString() {
+  return 'SiteCredentialInfo(domain: $domain, includeSubdomains: $includeSubdomains, kind: $kind, username: $username, headerName: $headerName, updatedAt: $updatedAt)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$SiteCredentialInfoCopyWith<$Res> implements $SiteCredentialInfoCopyWith<$Res> {
+  factory _$SiteCredentialInfoCopyWith(_SiteCredentialInfo value, $Res Function(_SiteCredentialInfo) _then) = __$SiteCredentialInfoCopyWithImpl;
+@override @useResult
+$Res call({
+ String domain, bool includeSubdomains, String kind, String? username, String? headerName, PlatformInt64 updatedAt
+});
+
+
+
+
+}
+/// @nodoc
+class __$SiteCredentialInfoCopyWithImpl<$Res>
+    implements _$SiteCredentialInfoCopyWith<$Res> {
+  __$SiteCredentialInfoCopyWithImpl(this._self, this._then);
+
+  final _SiteCredentialInfo _self;
+  final $Res Function(_SiteCredentialInfo) _then;
+
+/// Create a copy of SiteCredentialInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? domain = null,Object? includeSubdomains = null,Object? kind = null,Object? username = freezed,Object? headerName = freezed,Object? updatedAt = null,}) {
+  return _then(_SiteCredentialInfo(
+domain: null == domain ? _self.domain : domain // ignore: cast_nullable_to_non_nullable
+as String,includeSubdomains: null == includeSubdomains ? _self.includeSubdomains : includeSubdomains // ignore: cast_nullable_to_non_nullable
+as bool,kind: null == kind ? _self.kind : kind // ignore: cast_nullable_to_non_nullable
+as String,username: freezed == username ? _self.username : username // ignore: cast_nullable_to_non_nullable
+as String?,headerName: freezed == headerName ? _self.headerName : headerName // ignore: cast_nullable_to_non_nullable
+as String?,updatedAt: null == updatedAt ? _self.updatedAt : updatedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$SiteCredentialInput {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SiteCredentialInput);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SiteCredentialInput()';
+}
+
+
+}
+
+/// @nodoc
+class $SiteCredentialInputCopyWith<$Res>  {
+$SiteCredentialInputCopyWith(SiteCredentialInput _, $Res Function(SiteCredentialInput) __);
+}
+
+
+/// Adds pattern-matching-related methods to [SiteCredentialInput].
+extension SiteCredentialInputPatterns on SiteCredentialInput {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( SiteCredentialInput_Cookie value)?  cookie,TResult Function( SiteCredentialInput_Bearer value)?  bearer,TResult Function( SiteCredentialInput_Basic value)?  basic,TResult Function( SiteCredentialInput_Header value)?  header,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case SiteCredentialInput_Cookie() when cookie != null:
+return cookie(_that);case SiteCredentialInput_Bearer() when bearer != null:
+return bearer(_that);case SiteCredentialInput_Basic() when basic != null:
+return basic(_that);case SiteCredentialInput_Header() when header != null:
+return header(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( SiteCredentialInput_Cookie value)  cookie,required TResult Function( SiteCredentialInput_Bearer value)  bearer,required TResult Function( SiteCredentialInput_Basic value)  basic,required TResult Function( SiteCredentialInput_Header value)  header,}){
+final _that = this;
+switch (_that) {
+case SiteCredentialInput_Cookie():
+return cookie(_that);case SiteCredentialInput_Bearer():
+return bearer(_that);case SiteCredentialInput_Basic():
+return basic(_that);case SiteCredentialInput_Header():
+return header(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( SiteCredentialInput_Cookie value)?  cookie,TResult? Function( SiteCredentialInput_Bearer value)?  bearer,TResult? Function( SiteCredentialInput_Basic value)?  basic,TResult? Function( SiteCredentialInput_Header value)?  header,}){
+final _that = this;
+switch (_that) {
+case SiteCredentialInput_Cookie() when cookie != null:
+return cookie(_that);case SiteCredentialInput_Bearer() when bearer != null:
+return bearer(_that);case SiteCredentialInput_Basic() when basic != null:
+return basic(_that);case SiteCredentialInput_Header() when header != null:
+return header(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String cookies)?  cookie,TResult Function( String token)?  bearer,TResult Function( String username,  String password)?  basic,TResult Function( String name,  String value)?  header,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case SiteCredentialInput_Cookie() when cookie != null:
+return cookie(_that.cookies);case SiteCredentialInput_Bearer() when bearer != null:
+return bearer(_that.token);case SiteCredentialInput_Basic() when basic != null:
+return basic(_that.username,_that.password);case SiteCredentialInput_Header() when header != null:
+return header(_that.name,_that.value);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String cookies)  cookie,required TResult Function( String token)  bearer,required TResult Function( String username,  String password)  basic,required TResult Function( String name,  String value)  header,}) {final _that = this;
+switch (_that) {
+case SiteCredentialInput_Cookie():
+return cookie(_that.cookies);case SiteCredentialInput_Bearer():
+return bearer(_that.token);case SiteCredentialInput_Basic():
+return basic(_that.username,_that.password);case SiteCredentialInput_Header():
+return header(_that.name,_that.value);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String cookies)?  cookie,TResult? Function( String token)?  bearer,TResult? Function( String username,  String password)?  basic,TResult? Function( String name,  String value)?  header,}) {final _that = this;
+switch (_that) {
+case SiteCredentialInput_Cookie() when cookie != null:
+return cookie(_that.cookies);case SiteCredentialInput_Bearer() when bearer != null:
+return bearer(_that.token);case SiteCredentialInput_Basic() when basic != null:
+return basic(_that.username,_that.password);case SiteCredentialInput_Header() when header != null:
+return header(_that.name,_that.value);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class SiteCredentialInput_Cookie extends SiteCredentialInput {
+  const SiteCredentialInput_Cookie({required this.cookies}): super._();
+  
+
+ final  String cookies;
+
+/// Create a copy of SiteCredentialInput
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SiteCredentialInput_CookieCopyWith<SiteCredentialInput_Cookie> get copyWith => _$SiteCredentialInput_CookieCopyWithImpl<SiteCredentialInput_Cookie>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SiteCredentialInput_Cookie&&(identical(other.cookies, cookies) || other.cookies == cookies));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,cookies);
+
+@override
+String toString() {
+  return 'SiteCredentialInput.cookie(cookies: $cookies)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SiteCredentialInput_CookieCopyWith<$Res> implements $SiteCredentialInputCopyWith<$Res> {
+  factory $SiteCredentialInput_CookieCopyWith(SiteCredentialInput_Cookie value, $Res Function(SiteCredentialInput_Cookie) _then) = _$SiteCredentialInput_CookieCopyWithImpl;
+@useResult
+$Res call({
+ String cookies
+});
+
+
+
+
+}
+/// @nodoc
+class _$SiteCredentialInput_CookieCopyWithImpl<$Res>
+    implements $SiteCredentialInput_CookieCopyWith<$Res> {
+  _$SiteCredentialInput_CookieCopyWithImpl(this._self, this._then);
+
+  final SiteCredentialInput_Cookie _self;
+  final $Res Function(SiteCredentialInput_Cookie) _then;
+
+/// Create a copy of SiteCredentialInput
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? cookies = null,}) {
+  return _then(SiteCredentialInput_Cookie(
+cookies: null == cookies ? _self.cookies : cookies // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SiteCredentialInput_Bearer extends SiteCredentialInput {
+  const SiteCredentialInput_Bearer({required this.token}): super._();
+  
+
+ final  String token;
+
+/// Create a copy of SiteCredentialInput
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SiteCredentialInput_BearerCopyWith<SiteCredentialInput_Bearer> get copyWith => _$SiteCredentialInput_BearerCopyWithImpl<SiteCredentialInput_Bearer>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SiteCredentialInput_Bearer&&(identical(other.token, token) || other.token == token));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,token);
+
+@override
+String toString() {
+  return 'SiteCredentialInput.bearer(token: $token)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SiteCredentialInput_BearerCopyWith<$Res> implements $SiteCredentialInputCopyWith<$Res> {
+  factory $SiteCredentialInput_BearerCopyWith(SiteCredentialInput_Bearer value, $Res Function(SiteCredentialInput_Bearer) _then) = _$SiteCredentialInput_BearerCopyWithImpl;
+@useResult
+$Res call({
+ String token
+});
+
+
+
+
+}
+/// @nodoc
+class _$SiteCredentialInput_BearerCopyWithImpl<$Res>
+    implements $SiteCredentialInput_BearerCopyWith<$Res> {
+  _$SiteCredentialInput_BearerCopyWithImpl(this._self, this._then);
+
+  final SiteCredentialInput_Bearer _self;
+  final $Res Function(SiteCredentialInput_Bearer) _then;
+
+/// Create a copy of SiteCredentialInput
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? token = null,}) {
+  return _then(SiteCredentialInput_Bearer(
+token: null == token ? _self.token : token // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SiteCredentialInput_Basic extends SiteCredentialInput {
+  const SiteCredentialInput_Basic({required this.username, required this.password}): super._();
+  
+
+ final  String username;
+ final  String password;
+
+/// Create a copy of SiteCredentialInput
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SiteCredentialInput_BasicCopyWith<SiteCredentialInput_Basic> get copyWith => _$SiteCredentialInput_BasicCopyWithImpl<SiteCredentialInput_Basic>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SiteCredentialInput_Basic&&(identical(other.username, username) || other.username == username)&&(identical(other.password, password) || other.password == password));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,username,password);
+
+@override
+String toString() {
+  return 'SiteCredentialInput.basic(username: $username, password: $password)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SiteCredentialInput_BasicCopyWith<$Res> implements $SiteCredentialInputCopyWith<$Res> {
+  factory $SiteCredentialInput_BasicCopyWith(SiteCredentialInput_Basic value, $Res Function(SiteCredentialInput_Basic) _then) = _$SiteCredentialInput_BasicCopyWithImpl;
+@useResult
+$Res call({
+ String username, String password
+});
+
+
+
+
+}
+/// @nodoc
+class _$SiteCredentialInput_BasicCopyWithImpl<$Res>
+    implements $SiteCredentialInput_BasicCopyWith<$Res> {
+  _$SiteCredentialInput_BasicCopyWithImpl(this._self, this._then);
+
+  final SiteCredentialInput_Basic _self;
+  final $Res Function(SiteCredentialInput_Basic) _then;
+
+/// Create a copy of SiteCredentialInput
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? username = null,Object? password = null,}) {
+  return _then(SiteCredentialInput_Basic(
+username: null == username ? _self.username : username // ignore: cast_nullable_to_non_nullable
+as String,password: null == password ? _self.password : password // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SiteCredentialInput_Header extends SiteCredentialInput {
+  const SiteCredentialInput_Header({required this.name, required this.value}): super._();
+  
+
+ final  String name;
+ final  String value;
+
+/// Create a copy of SiteCredentialInput
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SiteCredentialInput_HeaderCopyWith<SiteCredentialInput_Header> get copyWith => _$SiteCredentialInput_HeaderCopyWithImpl<SiteCredentialInput_Header>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SiteCredentialInput_Header&&(identical(other.name, name) || other.name == name)&&(identical(other.value, value) || other.value == value));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,name,value);
+
+@override
+String toString() {
+  return 'SiteCredentialInput.header(name: $name, value: $value)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SiteCredentialInput_HeaderCopyWith<$Res> implements $SiteCredentialInputCopyWith<$Res> {
+  factory $SiteCredentialInput_HeaderCopyWith(SiteCredentialInput_Header value, $Res Function(SiteCredentialInput_Header) _then) = _$SiteCredentialInput_HeaderCopyWithImpl;
+@useResult
+$Res call({
+ String name, String value
+});
+
+
+
+
+}
+/// @nodoc
+class _$SiteCredentialInput_HeaderCopyWithImpl<$Res>
+    implements $SiteCredentialInput_HeaderCopyWith<$Res> {
+  _$SiteCredentialInput_HeaderCopyWithImpl(this._self, this._then);
+
+  final SiteCredentialInput_Header _self;
+  final $Res Function(SiteCredentialInput_Header) _then;
+
+/// Create a copy of SiteCredentialInput
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? name = null,Object? value = null,}) {
+  return _then(SiteCredentialInput_Header(
+name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,value: null == value ? _self.value : value // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ToolStatusInfo {
+
+ String get name; String get path; String? get version; String get source;
+/// Create a copy of ToolStatusInfo
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ToolStatusInfoCopyWith<ToolStatusInfo> get copyWith => _$ToolStatusInfoCopyWithImpl<ToolStatusInfo>(this as ToolStatusInfo, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ToolStatusInfo&&(identical(other.name, name) || other.name == name)&&(identical(other.path, path) || other.path == path)&&(identical(other.version, version) || other.version == version)&&(identical(other.source, source) || other.source == source));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,name,path,version,source);
+
+@override
+String toString() {
+  return 'ToolStatusInfo(name: $name, path: $path, version: $version, source: $source)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ToolStatusInfoCopyWith<$Res>  {
+  factory $ToolStatusInfoCopyWith(ToolStatusInfo value, $Res Function(ToolStatusInfo) _then) = _$ToolStatusInfoCopyWithImpl;
+@useResult
+$Res call({
+ String name, String path, String? version, String source
+});
+
+
+
+
+}
+/// @nodoc
+class _$ToolStatusInfoCopyWithImpl<$Res>
+    implements $ToolStatusInfoCopyWith<$Res> {
+  _$ToolStatusInfoCopyWithImpl(this._self, this._then);
+
+  final ToolStatusInfo _self;
+  final $Res Function(ToolStatusInfo) _then;
+
+/// Create a copy of ToolStatusInfo
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? name = null,Object? path = null,Object? version = freezed,Object? source = null,}) {
+  return _then(_self.copyWith(
+name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,version: freezed == version ? _self.version : version // ignore: cast_nullable_to_non_nullable
+as String?,source: null == source ? _self.source : source // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ToolStatusInfo].
+extension ToolStatusInfoPatterns on ToolStatusInfo {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ToolStatusInfo value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ToolStatusInfo() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ToolStatusInfo value)  $default,){
+final _that = this;
+switch (_that) {
+case _ToolStatusInfo():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ToolStatusInfo value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ToolStatusInfo() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String name,  String path,  String? version,  String source)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ToolStatusInfo() when $default != null:
+return $default(_that.name,_that.path,_that.version,_that.source);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String name,  String path,  String? version,  String source)  $default,) {final _that = this;
+switch (_that) {
+case _ToolStatusInfo():
+return $default(_that.name,_that.path,_that.version,_that.source);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String name,  String path,  String? version,  String source)?  $default,) {final _that = this;
+switch (_that) {
+case _ToolStatusInfo() when $default != null:
+return $default(_that.name,_that.path,_that.version,_that.source);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ToolStatusInfo implements ToolStatusInfo {
+  const _ToolStatusInfo({required this.name, required this.path, this.version, required this.source});
+  
+
+@override final  String name;
+@override final  String path;
+@override final  String? version;
+@override final  String source;
+
+/// Create a copy of ToolStatusInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ToolStatusInfoCopyWith<_ToolStatusInfo> get copyWith => __$ToolStatusInfoCopyWithImpl<_ToolStatusInfo>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ToolStatusInfo&&(identical(other.name, name) || other.name == name)&&(identical(other.path, path) || other.path == path)&&(identical(other.version, version) || other.version == version)&&(identical(other.source, source) || other.source == source));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,name,path,version,source);
+
+@override
+String toString() {
+  return 'ToolStatusInfo(name: $name, path: $path, version: $version, source: $source)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ToolStatusInfoCopyWith<$Res> implements $ToolStatusInfoCopyWith<$Res> {
+  factory _$ToolStatusInfoCopyWith(_ToolStatusInfo value, $Res Function(_ToolStatusInfo) _then) = __$ToolStatusInfoCopyWithImpl;
+@override @useResult
+$Res call({
+ String name, String path, String? version, String source
+});
+
+
+
+
+}
+/// @nodoc
+class __$ToolStatusInfoCopyWithImpl<$Res>
+    implements _$ToolStatusInfoCopyWith<$Res> {
+  __$ToolStatusInfoCopyWithImpl(this._self, this._then);
+
+  final _ToolStatusInfo _self;
+  final $Res Function(_ToolStatusInfo) _then;
+
+/// Create a copy of ToolStatusInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? name = null,Object? path = null,Object? version = freezed,Object? source = null,}) {
+  return _then(_ToolStatusInfo(
+name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,version: freezed == version ? _self.version : version // ignore: cast_nullable_to_non_nullable
+as String?,source: null == source ? _self.source : source // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ToolUpdateInfo {
+
+ String? get current; String get latest; bool get updateAvailable;
+/// Create a copy of ToolUpdateInfo
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ToolUpdateInfoCopyWith<ToolUpdateInfo> get copyWith => _$ToolUpdateInfoCopyWithImpl<ToolUpdateInfo>(this as ToolUpdateInfo, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ToolUpdateInfo&&(identical(other.current, current) || other.current == current)&&(identical(other.latest, latest) || other.latest == latest)&&(identical(other.updateAvailable, updateAvailable) || other.updateAvailable == updateAvailable));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,current,latest,updateAvailable);
+
+@override
+String toString() {
+  return 'ToolUpdateInfo(current: $current, latest: $latest, updateAvailable: $updateAvailable)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ToolUpdateInfoCopyWith<$Res>  {
+  factory $ToolUpdateInfoCopyWith(ToolUpdateInfo value, $Res Function(ToolUpdateInfo) _then) = _$ToolUpdateInfoCopyWithImpl;
+@useResult
+$Res call({
+ String? current, String latest, bool updateAvailable
+});
+
+
+
+
+}
+/// @nodoc
+class _$ToolUpdateInfoCopyWithImpl<$Res>
+    implements $ToolUpdateInfoCopyWith<$Res> {
+  _$ToolUpdateInfoCopyWithImpl(this._self, this._then);
+
+  final ToolUpdateInfo _self;
+  final $Res Function(ToolUpdateInfo) _then;
+
+/// Create a copy of ToolUpdateInfo
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? current = freezed,Object? latest = null,Object? updateAvailable = null,}) {
+  return _then(_self.copyWith(
+current: freezed == current ? _self.current : current // ignore: cast_nullable_to_non_nullable
+as String?,latest: null == latest ? _self.latest : latest // ignore: cast_nullable_to_non_nullable
+as String,updateAvailable: null == updateAvailable ? _self.updateAvailable : updateAvailable // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ToolUpdateInfo].
+extension ToolUpdateInfoPatterns on ToolUpdateInfo {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ToolUpdateInfo value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ToolUpdateInfo() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ToolUpdateInfo value)  $default,){
+final _that = this;
+switch (_that) {
+case _ToolUpdateInfo():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ToolUpdateInfo value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ToolUpdateInfo() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String? current,  String latest,  bool updateAvailable)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ToolUpdateInfo() when $default != null:
+return $default(_that.current,_that.latest,_that.updateAvailable);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String? current,  String latest,  bool updateAvailable)  $default,) {final _that = this;
+switch (_that) {
+case _ToolUpdateInfo():
+return $default(_that.current,_that.latest,_that.updateAvailable);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String? current,  String latest,  bool updateAvailable)?  $default,) {final _that = this;
+switch (_that) {
+case _ToolUpdateInfo() when $default != null:
+return $default(_that.current,_that.latest,_that.updateAvailable);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ToolUpdateInfo implements ToolUpdateInfo {
+  const _ToolUpdateInfo({this.current, required this.latest, required this.updateAvailable});
+  
+
+@override final  String? current;
+@override final  String latest;
+@override final  bool updateAvailable;
+
+/// Create a copy of ToolUpdateInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ToolUpdateInfoCopyWith<_ToolUpdateInfo> get copyWith => __$ToolUpdateInfoCopyWithImpl<_ToolUpdateInfo>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ToolUpdateInfo&&(identical(other.current, current) || other.current == current)&&(identical(other.latest, latest) || other.latest == latest)&&(identical(other.updateAvailable, updateAvailable) || other.updateAvailable == updateAvailable));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,current,latest,updateAvailable);
+
+@override
+String toString() {
+  return 'ToolUpdateInfo(current: $current, latest: $latest, updateAvailable: $updateAvailable)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ToolUpdateInfoCopyWith<$Res> implements $ToolUpdateInfoCopyWith<$Res> {
+  factory _$ToolUpdateInfoCopyWith(_ToolUpdateInfo value, $Res Function(_ToolUpdateInfo) _then) = __$ToolUpdateInfoCopyWithImpl;
+@override @useResult
+$Res call({
+ String? current, String latest, bool updateAvailable
+});
+
+
+
+
+}
+/// @nodoc
+class __$ToolUpdateInfoCopyWithImpl<$Res>
+    implements _$ToolUpdateInfoCopyWith<$Res> {
+  __$ToolUpdateInfoCopyWithImpl(this._self, this._then);
+
+  final _ToolUpdateInfo _self;
+  final $Res Function(_ToolUpdateInfo) _then;
+
+/// Create a copy of ToolUpdateInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? current = freezed,Object? latest = null,Object? updateAvailable = null,}) {
+  return _then(_ToolUpdateInfo(
+current: freezed == current ? _self.current : current // ignore: cast_nullable_to_non_nullable
+as String?,latest: null == latest ? _self.latest : latest // ignore: cast_nullable_to_non_nullable
+as String,updateAvailable: null == updateAvailable ? _self.updateAvailable : updateAvailable // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$TorrentFileInfo {
+
+ BigInt get index; String get path; BigInt get size;
+/// Create a copy of TorrentFileInfo
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$TorrentFileInfoCopyWith<TorrentFileInfo> get copyWith => _$TorrentFileInfoCopyWithImpl<TorrentFileInfo>(this as TorrentFileInfo, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TorrentFileInfo&&(identical(other.index, index) || other.index == index)&&(identical(other.path, path) || other.path == path)&&(identical(other.size, size) || other.size == size));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,index,path,size);
+
+@override
+String toString() {
+  return 'TorrentFileInfo(index: $index, path: $path, size: $size)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $TorrentFileInfoCopyWith<$Res>  {
+  factory $TorrentFileInfoCopyWith(TorrentFileInfo value, $Res Function(TorrentFileInfo) _then) = _$TorrentFileInfoCopyWithImpl;
+@useResult
+$Res call({
+ BigInt index, String path, BigInt size
+});
+
+
+
+
+}
+/// @nodoc
+class _$TorrentFileInfoCopyWithImpl<$Res>
+    implements $TorrentFileInfoCopyWith<$Res> {
+  _$TorrentFileInfoCopyWithImpl(this._self, this._then);
+
+  final TorrentFileInfo _self;
+  final $Res Function(TorrentFileInfo) _then;
+
+/// Create a copy of TorrentFileInfo
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? index = null,Object? path = null,Object? size = null,}) {
+  return _then(_self.copyWith(
+index: null == index ? _self.index : index // ignore: cast_nullable_to_non_nullable
+as BigInt,path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,size: null == size ? _self.size : size // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [TorrentFileInfo].
+extension TorrentFileInfoPatterns on TorrentFileInfo {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _TorrentFileInfo value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _TorrentFileInfo() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _TorrentFileInfo value)  $default,){
+final _that = this;
+switch (_that) {
+case _TorrentFileInfo():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _TorrentFileInfo value)?  $default,){
+final _that = this;
+switch (_that) {
+case _TorrentFileInfo() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( BigInt index,  String path,  BigInt size)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _TorrentFileInfo() when $default != null:
+return $default(_that.index,_that.path,_that.size);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( BigInt index,  String path,  BigInt size)  $default,) {final _that = this;
+switch (_that) {
+case _TorrentFileInfo():
+return $default(_that.index,_that.path,_that.size);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( BigInt index,  String path,  BigInt size)?  $default,) {final _that = this;
+switch (_that) {
+case _TorrentFileInfo() when $default != null:
+return $default(_that.index,_that.path,_that.size);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _TorrentFileInfo implements TorrentFileInfo {
+  const _TorrentFileInfo({required this.index, required this.path, required this.size});
+  
+
+@override final  BigInt index;
+@override final  String path;
+@override final  BigInt size;
+
+/// Create a copy of TorrentFileInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$TorrentFileInfoCopyWith<_TorrentFileInfo> get copyWith => __$TorrentFileInfoCopyWithImpl<_TorrentFileInfo>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _TorrentFileInfo&&(identical(other.index, index) || other.index == index)&&(identical(other.path, path) || other.path == path)&&(identical(other.size, size) || other.size == size));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,index,path,size);
+
+@override
+String toString() {
+  return 'TorrentFileInfo(index: $index, path: $path, size: $size)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$TorrentFileInfoCopyWith<$Res> implements $TorrentFileInfoCopyWith<$Res> {
+  factory _$TorrentFileInfoCopyWith(_TorrentFileInfo value, $Res Function(_TorrentFileInfo) _then) = __$TorrentFileInfoCopyWithImpl;
+@override @useResult
+$Res call({
+ BigInt index, String path, BigInt size
+});
+
+
+
+
+}
+/// @nodoc
+class __$TorrentFileInfoCopyWithImpl<$Res>
+    implements _$TorrentFileInfoCopyWith<$Res> {
+  __$TorrentFileInfoCopyWithImpl(this._self, this._then);
+
+  final _TorrentFileInfo _self;
+  final $Res Function(_TorrentFileInfo) _then;
+
+/// Create a copy of TorrentFileInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? index = null,Object? path = null,Object? size = null,}) {
+  return _then(_TorrentFileInfo(
+index: null == index ? _self.index : index // ignore: cast_nullable_to_non_nullable
+as BigInt,path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,size: null == size ? _self.size : size // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$TrackerInfo {
+
+ String get url; String get source; TrackerStatusInfo get status; int? get seeders; int? get leechers; int? get completed; PlatformInt64? get lastUpdated;
+/// Create a copy of TrackerInfo
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$TrackerInfoCopyWith<TrackerInfo> get copyWith => _$TrackerInfoCopyWithImpl<TrackerInfo>(this as TrackerInfo, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TrackerInfo&&(identical(other.url, url) || other.url == url)&&(identical(other.source, source) || other.source == source)&&(identical(other.status, status) || other.status == status)&&(identical(other.seeders, seeders) || other.seeders == seeders)&&(identical(other.leechers, leechers) || other.leechers == leechers)&&(identical(other.completed, completed) || other.completed == completed)&&(identical(other.lastUpdated, lastUpdated) || other.lastUpdated == lastUpdated));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,url,source,status,seeders,leechers,completed,lastUpdated);
+
+@override
+String toString() {
+  return 'TrackerInfo(url: $url, source: $source, status: $status, seeders: $seeders, leechers: $leechers, completed: $completed, lastUpdated: $lastUpdated)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $TrackerInfoCopyWith<$Res>  {
+  factory $TrackerInfoCopyWith(TrackerInfo value, $Res Function(TrackerInfo) _then) = _$TrackerInfoCopyWithImpl;
+@useResult
+$Res call({
+ String url, String source, TrackerStatusInfo status, int? seeders, int? leechers, int? completed, PlatformInt64? lastUpdated
+});
+
+
+$TrackerStatusInfoCopyWith<$Res> get status;
+
+}
+/// @nodoc
+class _$TrackerInfoCopyWithImpl<$Res>
+    implements $TrackerInfoCopyWith<$Res> {
+  _$TrackerInfoCopyWithImpl(this._self, this._then);
+
+  final TrackerInfo _self;
+  final $Res Function(TrackerInfo) _then;
+
+/// Create a copy of TrackerInfo
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? url = null,Object? source = null,Object? status = null,Object? seeders = freezed,Object? leechers = freezed,Object? completed = freezed,Object? lastUpdated = freezed,}) {
+  return _then(_self.copyWith(
+url: null == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
+as String,source: null == source ? _self.source : source // ignore: cast_nullable_to_non_nullable
+as String,status: null == status ? _self.status : status // ignore: cast_nullable_to_non_nullable
+as TrackerStatusInfo,seeders: freezed == seeders ? _self.seeders : seeders // ignore: cast_nullable_to_non_nullable
+as int?,leechers: freezed == leechers ? _self.leechers : leechers // ignore: cast_nullable_to_non_nullable
+as int?,completed: freezed == completed ? _self.completed : completed // ignore: cast_nullable_to_non_nullable
+as int?,lastUpdated: freezed == lastUpdated ? _self.lastUpdated : lastUpdated // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,
+  ));
+}
+/// Create a copy of TrackerInfo
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$TrackerStatusInfoCopyWith<$Res> get status {
+  
+  return $TrackerStatusInfoCopyWith<$Res>(_self.status, (value) {
+    return _then(_self.copyWith(status: value));
+  });
+}
+}
+
+
+/// Adds pattern-matching-related methods to [TrackerInfo].
+extension TrackerInfoPatterns on TrackerInfo {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _TrackerInfo value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _TrackerInfo() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _TrackerInfo value)  $default,){
+final _that = this;
+switch (_that) {
+case _TrackerInfo():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _TrackerInfo value)?  $default,){
+final _that = this;
+switch (_that) {
+case _TrackerInfo() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String url,  String source,  TrackerStatusInfo status,  int? seeders,  int? leechers,  int? completed,  PlatformInt64? lastUpdated)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _TrackerInfo() when $default != null:
+return $default(_that.url,_that.source,_that.status,_that.seeders,_that.leechers,_that.completed,_that.lastUpdated);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String url,  String source,  TrackerStatusInfo status,  int? seeders,  int? leechers,  int? completed,  PlatformInt64? lastUpdated)  $default,) {final _that = this;
+switch (_that) {
+case _TrackerInfo():
+return $default(_that.url,_that.source,_that.status,_that.seeders,_that.leechers,_that.completed,_that.lastUpdated);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String url,  String source,  TrackerStatusInfo status,  int? seeders,  int? leechers,  int? completed,  PlatformInt64? lastUpdated)?  $default,) {final _that = this;
+switch (_that) {
+case _TrackerInfo() when $default != null:
+return $default(_that.url,_that.source,_that.status,_that.seeders,_that.leechers,_that.completed,_that.lastUpdated);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _TrackerInfo implements TrackerInfo {
+  const _TrackerInfo({required this.url, required this.source, required this.status, this.seeders, this.leechers, this.completed, this.lastUpdated});
+  
+
+@override final  String url;
+@override final  String source;
+@override final  TrackerStatusInfo status;
+@override final  int? seeders;
+@override final  int? leechers;
+@override final  int? completed;
+@override final  PlatformInt64? lastUpdated;
+
+/// Create a copy of TrackerInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$TrackerInfoCopyWith<_TrackerInfo> get copyWith => __$TrackerInfoCopyWithImpl<_TrackerInfo>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _TrackerInfo&&(identical(other.url, url) || other.url == url)&&(identical(other.source, source) || other.source == source)&&(identical(other.status, status) || other.status == status)&&(identical(other.seeders, seeders) || other.seeders == seeders)&&(identical(other.leechers, leechers) || other.leechers == leechers)&&(identical(other.completed, completed) || other.completed == completed)&&(identical(other.lastUpdated, lastUpdated) || other.lastUpdated == lastUpdated));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,url,source,status,seeders,leechers,completed,lastUpdated);
+
+@override
+String toString() {
+  return 'TrackerInfo(url: $url, source: $source, status: $status, seeders: $seeders, leechers: $leechers, completed: $completed, lastUpdated: $lastUpdated)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$TrackerInfoCopyWith<$Res> implements $TrackerInfoCopyWith<$Res> {
+  factory _$TrackerInfoCopyWith(_TrackerInfo value, $Res Function(_TrackerInfo) _then) = __$TrackerInfoCopyWithImpl;
+@override @useResult
+$Res call({
+ String url, String source, TrackerStatusInfo status, int? seeders, int? leechers, int? completed, PlatformInt64? lastUpdated
+});
+
+
+@override $TrackerStatusInfoCopyWith<$Res> get status;
+
+}
+/// @nodoc
+class __$TrackerInfoCopyWithImpl<$Res>
+    implements _$TrackerInfoCopyWith<$Res> {
+  __$TrackerInfoCopyWithImpl(this._self, this._then);
+
+  final _TrackerInfo _self;
+  final $Res Function(_TrackerInfo) _then;
+
+/// Create a copy of TrackerInfo
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? url = null,Object? source = null,Object? status = null,Object? seeders = freezed,Object? leechers = freezed,Object? completed = freezed,Object? lastUpdated = freezed,}) {
+  return _then(_TrackerInfo(
+url: null == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
+as String,source: null == source ? _self.source : source // ignore: cast_nullable_to_non_nullable
+as String,status: null == status ? _self.status : status // ignore: cast_nullable_to_non_nullable
+as TrackerStatusInfo,seeders: freezed == seeders ? _self.seeders : seeders // ignore: cast_nullable_to_non_nullable
+as int?,leechers: freezed == leechers ? _self.leechers : leechers // ignore: cast_nullable_to_non_nullable
+as int?,completed: freezed == completed ? _self.completed : completed // ignore: cast_nullable_to_non_nullable
+as int?,lastUpdated: freezed == lastUpdated ? _self.lastUpdated : lastUpdated // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,
+  ));
+}
+
+/// Create a copy of TrackerInfo
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$TrackerStatusInfoCopyWith<$Res> get status {
+  
+  return $TrackerStatusInfoCopyWith<$Res>(_self.status, (value) {
+    return _then(_self.copyWith(status: value));
+  });
+}
+}
+
+/// @nodoc
+mixin _$TrackerStatusInfo {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TrackerStatusInfo);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'TrackerStatusInfo()';
+}
+
+
+}
+
+/// @nodoc
+class $TrackerStatusInfoCopyWith<$Res>  {
+$TrackerStatusInfoCopyWith(TrackerStatusInfo _, $Res Function(TrackerStatusInfo) __);
+}
+
+
+/// Adds pattern-matching-related methods to [TrackerStatusInfo].
+extension TrackerStatusInfoPatterns on TrackerStatusInfo {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( TrackerStatusInfo_NotContacted value)?  notContacted,TResult Function( TrackerStatusInfo_Updating value)?  updating,TResult Function( TrackerStatusInfo_Working value)?  working,TResult Function( TrackerStatusInfo_Error value)?  error,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case TrackerStatusInfo_NotContacted() when notContacted != null:
+return notContacted(_that);case TrackerStatusInfo_Updating() when updating != null:
+return updating(_that);case TrackerStatusInfo_Working() when working != null:
+return working(_that);case TrackerStatusInfo_Error() when error != null:
+return error(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( TrackerStatusInfo_NotContacted value)  notContacted,required TResult Function( TrackerStatusInfo_Updating value)  updating,required TResult Function( TrackerStatusInfo_Working value)  working,required TResult Function( TrackerStatusInfo_Error value)  error,}){
+final _that = this;
+switch (_that) {
+case TrackerStatusInfo_NotContacted():
+return notContacted(_that);case TrackerStatusInfo_Updating():
+return updating(_that);case TrackerStatusInfo_Working():
+return working(_that);case TrackerStatusInfo_Error():
+return error(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( TrackerStatusInfo_NotContacted value)?  notContacted,TResult? Function( TrackerStatusInfo_Updating value)?  updating,TResult? Function( TrackerStatusInfo_Working value)?  working,TResult? Function( TrackerStatusInfo_Error value)?  error,}){
+final _that = this;
+switch (_that) {
+case TrackerStatusInfo_NotContacted() when notContacted != null:
+return notContacted(_that);case TrackerStatusInfo_Updating() when updating != null:
+return updating(_that);case TrackerStatusInfo_Working() when working != null:
+return working(_that);case TrackerStatusInfo_Error() when error != null:
+return error(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  notContacted,TResult Function()?  updating,TResult Function()?  working,TResult Function( String message)?  error,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case TrackerStatusInfo_NotContacted() when notContacted != null:
+return notContacted();case TrackerStatusInfo_Updating() when updating != null:
+return updating();case TrackerStatusInfo_Working() when working != null:
+return working();case TrackerStatusInfo_Error() when error != null:
+return error(_that.message);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  notContacted,required TResult Function()  updating,required TResult Function()  working,required TResult Function( String message)  error,}) {final _that = this;
+switch (_that) {
+case TrackerStatusInfo_NotContacted():
+return notContacted();case TrackerStatusInfo_Updating():
+return updating();case TrackerStatusInfo_Working():
+return working();case TrackerStatusInfo_Error():
+return error(_that.message);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
 
-  final NebulaEvent_TaskPaused _self;
-  final $Res Function(NebulaEvent_TaskPaused) _then;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  notContacted,TResult? Function()?  updating,TResult? Function()?  working,TResult? Function( String message)?  error,}) {final _that = this;
+switch (_that) {
+case TrackerStatusInfo_NotContacted() when notContacted != null:
+return notContacted();case TrackerStatusInfo_Updating() when updating != null:
+return updating();case TrackerStatusInfo_Working() when working != null:
+return working();case TrackerStatusInfo_Error() when error != null:
+return error(_that.message);case _:
+  return null;
 
-/// Create a copy of NebulaEvent
-/// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? taskId = null,}) {
-  return _then(NebulaEvent_TaskPaused(
-taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
-as String,
-  ));
 }
-
+}
 
 }
 
 /// @nodoc
 
 
-class NebulaEvent_TaskResumed extends NebulaEvent {
-  const NebulaEvent_TaskResumed({required this.taskId}): super._();
+class TrackerStatusInfo_NotContacted extends TrackerStatusInfo {
+  const TrackerStatusInfo_NotContacted(): super._();
   
 
-@override final  String taskId;
 
-/// Create a copy of NebulaEvent
-/// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
-@pragma('vm:prefer-inline')
-$NebulaEvent_TaskResumedCopyWith<NebulaEvent_TaskResumed> get copyWith => _$NebulaEvent_TaskResumedCopyWithImpl<NebulaEvent_TaskResumed>(this, _$identity);
+
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_TaskResumed&&(identical(other.taskId, taskId) || other.taskId == taskId));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TrackerStatusInfo_NotContacted);
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,taskId);
+int get hashCode => runtimeType.hashCode;
 
 @override
 String toString() {
-  return 'NebulaEvent.taskResumed(taskId: $taskId)';
-}
-
-
+  return 'TrackerStatusInfo.notContacted()';
 }
 
-/// @nodoc
-abstract mixin class $NebulaEvent_TaskResumedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
-  factory $NebulaEvent_TaskResumedCopyWith(NebulaEvent_TaskResumed value, $Res Function(NebulaEvent_TaskResumed) _then) = _$NebulaEvent_TaskResumedCopyWithImpl;
-@override @useResult
-$Res call({
- String taskId
-});
-
-
-
 
 }
-/// @nodoc
-class _$NebulaEvent_TaskResumedCopyWithImpl<$Res>
-    implements $NebulaEvent_TaskResumedCopyWith<$Res> {
-  _$NebulaEvent_TaskResumedCopyWithImpl(this._self, this._then);
-
-  final NebulaEvent_TaskResumed _self;
-  final $Res Function(NebulaEvent_TaskResumed) _then;
 
-/// Create a copy of NebulaEvent
-/// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? taskId = null,}) {
-  return _then(NebulaEvent_TaskResumed(
-taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
-as String,
-  ));
-}
 
 
-}
 
 /// @nodoc
 
 
-class NebulaEvent_TaskRemoved extends NebulaEvent {
-  const NebulaEvent_TaskRemoved({required this.taskId}): super._();
+class TrackerStatusInfo_Updating extends TrackerStatusInfo {
+  const TrackerStatusInfo_Updating(): super._();
   
 
-@override final  String taskId;
 
-/// Create a copy of NebulaEvent
-/// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
-@pragma('vm:prefer-inline')
-$NebulaEvent_TaskRemovedCopyWith<NebulaEvent_TaskRemoved> get copyWith => _$NebulaEvent_TaskRemovedCopyWithImpl<NebulaEvent_TaskRemoved>(this, _$identity);
+
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_TaskRemoved&&(identical(other.taskId, taskId) || other.taskId == taskId));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TrackerStatusInfo_Updating);
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,taskId);
+int get hashCode => runtimeType.hashCode;
 
 @override
 String toString() {
-  return 'NebulaEvent.taskRemoved(taskId: $taskId)';
-}
-
-
+  return 'TrackerStatusInfo.updating()';
 }
 
-/// @nodoc
-abstract mixin class $NebulaEvent_TaskRemovedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
-  factory $NebulaEvent_TaskRemovedCopyWith(NebulaEvent_TaskRemoved value, $Res Function(NebulaEvent_TaskRemoved) _then) = _$NebulaEvent_TaskRemovedCopyWithImpl;
-@override @useResult
-$Res call({
- String taskId
-});
-
-
-
 
 }
-/// @nodoc
-class _$NebulaEvent_TaskRemovedCopyWithImpl<$Res>
-    implements $NebulaEvent_TaskRemovedCopyWith<$Res> {
-  _$NebulaEvent_TaskRemovedCopyWithImpl(this._self, this._then);
 
-  final NebulaEvent_TaskRemoved _self;
-  final $Res Function(NebulaEvent_TaskRemoved) _then;
-
-/// Create a copy of NebulaEvent
-/// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? taskId = null,}) {
-  return _then(NebulaEvent_TaskRemoved(
-taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
-as String,
-  ));
-}
 
 
-}
 
 /// @nodoc
 
 
-class NebulaEvent_MetadataReceived extends NebulaEvent {
-  const NebulaEvent_MetadataReceived({required this.taskId, required this.name, required this.totalSize, required this.fileCount}): super._();
+class TrackerStatusInfo_Working extends TrackerStatusInfo {
+  const TrackerStatusInfo_Working(): super._();
   
 
-@override final  String taskId;
- final  String name;
- final  BigInt totalSize;
- final  BigInt fileCount;
 
-/// Create a copy of NebulaEvent
-/// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
-@pragma('vm:prefer-inline')
-$NebulaEvent_MetadataReceivedCopyWith<NebulaEvent_MetadataReceived> get copyWith => _$NebulaEvent_MetadataReceivedCopyWithImpl<NebulaEvent_MetadataReceived>(this, _$identity);
+
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_MetadataReceived&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.name, name) || other.name == name)&&(identical(other.totalSize, totalSize) || other.totalSize == totalSize)&&(identical(other.fileCount, fileCount) || other.fileCount == fileCount));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TrackerStatusInfo_Working);
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,taskId,name,totalSize,fileCount);
+int get hashCode => runtimeType.hashCode;
 
 @override
 String toString() {
-  return 'NebulaEvent.metadataReceived(taskId: $taskId, name: $name, totalSize: $totalSize, fileCount: $fileCount)';
-}
-
-
+  return 'TrackerStatusInfo.working()';
 }
 
-/// @nodoc
-abstract mixin class $NebulaEvent_MetadataReceivedCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
-  factory $NebulaEvent_MetadataReceivedCopyWith(NebulaEvent_MetadataReceived value, $Res Function(NebulaEvent_MetadataReceived) _then) = _$NebulaEvent_MetadataReceivedCopyWithImpl;
-@override @useResult
-$Res call({
- String taskId, String name, BigInt totalSize, BigInt fileCount
-});
-
-
-
 
 }
-/// @nodoc
-class _$NebulaEvent_MetadataReceivedCopyWithImpl<$Res>
-    implements $NebulaEvent_MetadataReceivedCopyWith<$Res> {
-  _$NebulaEvent_MetadataReceivedCopyWithImpl(this._self, this._then);
-
-  final NebulaEvent_MetadataReceived _self;
-  final $Res Function(NebulaEvent_MetadataReceived) _then;
 
-/// Create a copy of NebulaEvent
-/// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? name = null,Object? totalSize = null,Object? fileCount = null,}) {
-  return _then(NebulaEvent_MetadataReceived(
-taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
-as String,name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
-as String,totalSize: null == totalSize ? _self.totalSize : totalSize // ignore: cast_nullable_to_non_nullable
-as BigInt,fileCount: null == fileCount ? _self.fileCount : fileCount // ignore: cast_nullable_to_non_nullable
-as BigInt,
-  ));
-}
 
 
-}
 
 /// @nodoc
 
 
-class NebulaEvent_PeerUpdate extends NebulaEvent {
-  const NebulaEvent_PeerUpdate({required this.taskId, required this.connectedPeers, required this.totalPeers}): super._();
+class TrackerStatusInfo_Error extends TrackerStatusInfo {
+  const TrackerStatusInfo_Error({required this.message}): super._();
   
 
-@override final  String taskId;
- final  BigInt connectedPeers;
- final  BigInt totalPeers;
+ final  String message;
 
-/// Create a copy of NebulaEvent
+/// Create a copy of TrackerStatusInfo
 /// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
+@JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$NebulaEvent_PeerUpdateCopyWith<NebulaEvent_PeerUpdate> get copyWith => _$NebulaEvent_PeerUpdateCopyWithImpl<NebulaEvent_PeerUpdate>(this, _$identity);
+$TrackerStatusInfo_ErrorCopyWith<TrackerStatusInfo_Error> get copyWith => _$TrackerStatusInfo_ErrorCopyWithImpl<TrackerStatusInfo_Error>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is NebulaEvent_PeerUpdate&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.connectedPeers, connectedPeers) || other.connectedPeers == connectedPeers)&&(identical(other.totalPeers, totalPeers) || other.totalPeers == totalPeers));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TrackerStatusInfo_Error&&(identical(other.message, message) || other.message == message));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,taskId,connectedPeers,totalPeers);
+int get hashCode => Object.hash(runtimeType,message);
 
 @override
 String toString() {
-  return 'NebulaEvent.peerUpdate(taskId: $taskId, connectedPeers: $connectedPeers, totalPeers: $totalPeers)';
+  return 'TrackerStatusInfo.error(message: $message)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $NebulaEvent_PeerUpdateCopyWith<$Res> implements $NebulaEventCopyWith<$Res> {
-  factory $NebulaEvent_PeerUpdateCopyWith(NebulaEvent_PeerUpdate value, $Res Function(NebulaEvent_PeerUpdate) _then) = _$NebulaEvent_PeerUpdateCopyWithImpl;
-@override @useResult
+abstract mixin class $TrackerStatusInfo_ErrorCopyWith<$Res> implements $TrackerStatusInfoCopyWith<$Res> {
+  factory $TrackerStatusInfo_ErrorCopyWith(TrackerStatusInfo_Error value, $Res Function(TrackerStatusInfo_Error) _then) = _$TrackerStatusInfo_ErrorCopyWithImpl;
+@useResult
 $Res call({
- String taskId, BigInt connectedPeers, BigInt totalPeers
+ String message
 });
 
 
@@ -1542,21 +6630,19 @@ $Res call({
 
 }
 /// @nodoc
-class _$NebulaEvent_PeerUpdateCopyWithImpl<$Res>
-    implements $NebulaEvent_PeerUpdateCopyWith<$Res> {
-  _$NebulaEvent_PeerUpdateCopyWithImpl(this._self, this._then);
+class _$TrackerStatusInfo_ErrorCopyWithImpl<$Res>
+    implements $TrackerStatusInfo_ErrorCopyWith<$Res> {
+  _$TrackerStatusInfo_ErrorCopyWithImpl(this._self, this._then);
 
-  final NebulaEvent_PeerUpdate _self;
-  final $Res Function(NebulaEvent_PeerUpdate) _then;
+  final TrackerStatusInfo_Error _self;
+  final $Res Function(TrackerStatusInfo_Error) _then;
 
-/// Create a copy of NebulaEvent
+/// Create a copy of TrackerStatusInfo
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? connectedPeers = null,Object? totalPeers = null,}) {
-  return _then(NebulaEvent_PeerUpdate(
-taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
-as String,connectedPeers: null == connectedPeers ? _self.connectedPeers : connectedPeers // ignore: cast_nullable_to_non_nullable
-as BigInt,totalPeers: null == totalPeers ? _self.totalPeers : totalPeers // ignore: cast_nullable_to_non_nullable
-as BigInt,
+@pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(TrackerStatusInfo_Error(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
   ));
 }
 
@@ -1564,40 +6650,40 @@ as BigInt,
 }
 
 /// @nodoc
-mixin _$ProgressEvent {
+mixin _$VideoDownloadOptions {
 
- String get taskId; BigInt get totalSize; BigInt get downloadedSize; BigInt get downloadSpeed; double get percentage; BigInt? get etaSecs;
-/// Create a copy of ProgressEvent
+ List<String> get subtitleLangs; bool get autoSubtitles; bool get danmaku; bool get writeThumbnail; bool get writeInfoJson; bool get embedMetadata; bool get embedThumbnail; bool get embedChapters; String? get audioFormat; int? get audioBitrateKbps; bool get normalizeAudio; int? get maxHeight; String? get videoCodec; String? get container; bool get preferHdr; int? get maxFps; String? get audioLanguage; BigInt? get maxFilesizeMb;
+/// Create a copy of VideoDownloadOptions
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ProgressEventCopyWith<ProgressEvent> get copyWith => _$ProgressEventCopyWithImpl<ProgressEvent>(this as ProgressEvent, _$identity);
+$VideoDownloadOptionsCopyWith<VideoDownloadOptions> get copyWith => _$VideoDownloadOptionsCopyWithImpl<VideoDownloadOptions>(this as VideoDownloadOptions, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ProgressEvent&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.totalSize, totalSize) || other.totalSize == totalSize)&&(identical(other.downloadedSize, downloadedSize) || other.downloadedSize == downloadedSize)&&(identical(other.downloadSpeed, downloadSpeed) || other.downloadSpeed == downloadSpeed)&&(identical(other.percentage, percentage) || other.percentage == percentage)&&(identical(other.etaSecs, etaSecs) || other.etaSecs == etaSecs));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is VideoDownloadOptions&&const DeepCollectionEquality().equals(other.subtitleLangs, subtitleLangs)&&(identical(other.autoSubtitles, autoSubtitles) || other.autoSubtitles == autoSubtitles)&&(identical(other.danmaku, danmaku) || other.danmaku == danmaku)&&(identical(other.writeThumbnail, writeThumbnail) || other.writeThumbnail == writeThumbnail)&&(identical(other.writeInfoJson, writeInfoJson) || other.writeInfoJson == writeInfoJson)&&(identical(other.embedMetadata, embedMetadata) || other.embedMetadata == embedMetadata)&&(identical(other.embedThumbnail, embedThumbnail) || other.embedThumbnail == embedThumbnail)&&(identical(other.embedChapters, embedChapters) || other.embedChapters == embedChapters)&&(identical(other.audioFormat, audioFormat) || other.audioFormat == audioFormat)&&(identical(other.audioBitrateKbps, audioBitrateKbps) || other.audioBitrateKbps == audioBitrateKbps)&&(identical(other.normalizeAudio, normalizeAudio) || other.normalizeAudio == normalizeAudio)&&(identical(other.maxHeight, maxHeight) || other.maxHeight == maxHeight)&&(identical(other.videoCodec, videoCodec) || other.videoCodec == videoCodec)&&(identical(other.container, container) || other.container == container)&&(identical(other.preferHdr, preferHdr) || other.preferHdr == preferHdr)&&(identical(other.maxFps, maxFps) || other.maxFps == maxFps)&&(identical(other.audioLanguage, audioLanguage) || other.audioLanguage == audioLanguage)&&(identical(other.maxFilesizeMb, maxFilesizeMb) || other.maxFilesizeMb == maxFilesizeMb));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,taskId,totalSize,downloadedSize,downloadSpeed,percentage,etaSecs);
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(subtitleLangs),autoSubtitles,danmaku,writeThumbnail,writeInfoJson,embedMetadata,embedThumbnail,embedChapters,audioFormat,audioBitrateKbps,normalizeAudio,maxHeight,videoCodec,container,preferHdr,maxFps,audioLanguage,maxFilesizeMb);
 
 @override
 String toString() {
-  return 'ProgressEvent(taskId: $taskId, totalSize: $totalSize, downloadedSize: $downloadedSize, downloadSpeed: $downloadSpeed, percentage: $percentage, etaSecs: $etaSecs)';
+  return 'VideoDownloadOptions(subtitleLangs: $subtitleLangs, autoSubtitles: $autoSubtitles, danmaku: $danmaku, writeThumbnail: $writeThumbnail, writeInfoJson: $writeInfoJson, embedMetadata: $embedMetadata, embedThumbnail: $embedThumbnail, embedChapters: $embedChapters, audioFormat: $audioFormat, audioBitrateKbps: $audioBitrateKbps, normalizeAudio: $normalizeAudio, maxHeight: $maxHeight, videoCodec: $videoCodec, container: $container, preferHdr: $preferHdr, maxFps: $maxFps, audioLanguage: $audioLanguage, maxFilesizeMb: $maxFilesizeMb)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ProgressEventCopyWith<$Res>  {
-  factory $ProgressEventCopyWith(ProgressEvent value, $Res Function(ProgressEvent) _then) = _$ProgressEventCopyWithImpl;
+abstract mixin class $VideoDownloadOptionsCopyWith<$Res>  {
+  factory $VideoDownloadOptionsCopyWith(VideoDownloadOptions value, $Res Function(VideoDownloadOptions) _then) = _$VideoDownloadOptionsCopyWithImpl;
 @useResult
 $Res call({
- String taskId, BigInt totalSize, BigInt downloadedSize, BigInt downloadSpeed, double percentage, BigInt? etaSecs
+ List<String> subtitleLangs, bool autoSubtitles, bool danmaku, bool writeThumbnail, bool writeInfoJson, bool embedMetadata, bool embedThumbnail, bool embedChapters, String? audioFormat, int? audioBitrateKbps, bool normalizeAudio, int? maxHeight, String? videoCodec, String? container, bool preferHdr, int? maxFps, String? audioLanguage, BigInt? maxFilesizeMb
 });
 
 
@@ -1605,23 +6691,35 @@ $Res call({
 
 }
 /// @nodoc
-class _$ProgressEventCopyWithImpl<$Res>
-    implements $ProgressEventCopyWith<$Res> {
-  _$ProgressEventCopyWithImpl(this._self, this._then);
+class _$VideoDownloadOptionsCopyWithImpl<$Res>
+    implements $VideoDownloadOptionsCopyWith<$Res> {
+  _$VideoDownloadOptionsCopyWithImpl(this._self, this._then);
 
-  final ProgressEvent _self;
-  final $Res Function(ProgressEvent) _then;
+  final VideoDownloadOptions _self;
+  final $Res Function(VideoDownloadOptions) _then;
 
-/// Create a copy of ProgressEvent
+/// Create a copy of VideoDownloadOptions
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? taskId = null,Object? totalSize = null,Object? downloadedSize = null,Object? downloadSpeed = null,Object? percentage = null,Object? etaSecs = freezed,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? subtitleLangs = null,Object? autoSubtitles = null,Object? danmaku = null,Object? writeThumbnail = null,Object? writeInfoJson = null,Object? embedMetadata = null,Object? embedThumbnail = null,Object? embedChapters = null,Object? audioFormat = freezed,Object? audioBitrateKbps = freezed,Object? normalizeAudio = null,Object? maxHeight = freezed,Object? videoCodec = freezed,Object? container = freezed,Object? preferHdr = null,Object? maxFps = freezed,Object? audioLanguage = freezed,Object? maxFilesizeMb = freezed,}) {
   return _then(_self.copyWith(
-taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
-as String,totalSize: null == totalSize ? _self.totalSize : totalSize // ignore: cast_nullable_to_non_nullable
-as BigInt,downloadedSize: null == downloadedSize ? _self.downloadedSize : downloadedSize // ignore: cast_nullable_to_non_nullable
-as BigInt,downloadSpeed: null == downloadSpeed ? _self.downloadSpeed : downloadSpeed // ignore: cast_nullable_to_non_nullable
-as BigInt,percentage: null == percentage ? _self.percentage : percentage // ignore: cast_nullable_to_non_nullable
-as double,etaSecs: freezed == etaSecs ? _self.etaSecs : etaSecs // ignore: cast_nullable_to_non_nullable
+subtitleLangs: null == subtitleLangs ? _self.subtitleLangs : subtitleLangs // ignore: cast_nullable_to_non_nullable
+as List<String>,autoSubtitles: null == autoSubtitles ? _self.autoSubtitles : autoSubtitles // ignore: cast_nullable_to_non_nullable
+as bool,danmaku: null == danmaku ? _self.danmaku : danmaku // ignore: cast_nullable_to_non_nullable
+as bool,writeThumbnail: null == writeThumbnail ? _self.writeThumbnail : writeThumbnail // ignore: cast_nullable_to_non_nullable
+as bool,writeInfoJson: null == writeInfoJson ? _self.writeInfoJson : writeInfoJson // ignore: cast_nullable_to_non_nullable
+as bool,embedMetadata: null == embedMetadata ? _self.embedMetadata : embedMetadata // ignore: cast_nullable_to_non_nullable
+as bool,embedThumbnail: null == embedThumbnail ? _self.embedThumbnail : embedThumbnail // ignore: cast_nullable_to_non_nullable
+as bool,embedChapters: null == embedChapters ? _self.embedChapters : embedChapters // ignore: cast_nullable_to_non_nullable
+as bool,audioFormat: freezed == audioFormat ? _self.audioFormat : audioFormat // ignore: cast_nullable_to_non_nullable
+as String?,audioBitrateKbps: freezed == audioBitrateKbps ? _self.audioBitrateKbps : audioBitrateKbps // ignore: cast_nullable_to_non_nullable
+as int?,normalizeAudio: null == normalizeAudio ? _self.normalizeAudio : normalizeAudio // ignore: cast_nullable_to_non_nullable
+as bool,maxHeight: freezed == maxHeight ? _self.maxHeight : maxHeight // ignore: cast_nullable_to_non_nullable
+as int?,videoCodec: freezed == videoCodec ? _self.videoCodec : videoCodec // ignore: cast_nullable_to_non_nullable
+as String?,container: freezed == container ? _self.container : container // ignore: cast_nullable_to_non_nullable
+as String?,preferHdr: null == preferHdr ? _self.preferHdr : preferHdr // ignore: cast_nullable_to_non_nullable
+as bool,maxFps: freezed == maxFps ? _self.maxFps : maxFps // ignore: cast_nullable_to_non_nullable
+as int?,audioLanguage: freezed == audioLanguage ? _self.audioLanguage : audioLanguage // ignore: cast_nullable_to_non_nullable
+as String?,maxFilesizeMb: freezed == maxFilesizeMb ? _self.maxFilesizeMb : maxFilesizeMb // ignore: cast_nullable_to_non_nullable
 as BigInt?,
   ));
 }
@@ -1629,8 +6727,8 @@ as BigInt?,
 }
 
 
-/// Adds pattern-matching-related methods to [ProgressEvent].
-extension ProgressEventPatterns on ProgressEvent {
+/// Adds pattern-matching-related methods to [VideoDownloadOptions].
+extension VideoDownloadOptionsPatterns on VideoDownloadOptions {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -1643,10 +6741,10 @@ extension ProgressEventPatterns on ProgressEvent {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ProgressEvent value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _VideoDownloadOptions value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _ProgressEvent() when $default != null:
+case _VideoDownloadOptions() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -1665,10 +6763,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ProgressEvent value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _VideoDownloadOptions value)  $default,){
 final _that = this;
 switch (_that) {
-case _ProgressEvent():
+case _VideoDownloadOptions():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -1683,10 +6781,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ProgressEvent value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _VideoDownloadOptions value)?  $default,){
 final _that = this;
 switch (_that) {
-case _ProgressEvent() when $default != null:
+case _VideoDownloadOptions() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -1704,10 +6802,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String taskId,  BigInt totalSize,  BigInt downloadedSize,  BigInt downloadSpeed,  double percentage,  BigInt? etaSecs)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<String> subtitleLangs,  bool autoSubtitles,  bool danmaku,  bool writeThumbnail,  bool writeInfoJson,  bool embedMetadata,  bool embedThumbnail,  bool embedChapters,  String? audioFormat,  int? audioBitrateKbps,  bool normalizeAudio,  int? maxHeight,  String? videoCodec,  String? container,  bool preferHdr,  int? maxFps,  String? audioLanguage,  BigInt? maxFilesizeMb)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _ProgressEvent() when $default != null:
-return $default(_that.taskId,_that.totalSize,_that.downloadedSize,_that.downloadSpeed,_that.percentage,_that.etaSecs);case _:
+case _VideoDownloadOptions() when $default != null:
+return $default(_that.subtitleLangs,_that.autoSubtitles,_that.danmaku,_that.writeThumbnail,_that.writeInfoJson,_that.embedMetadata,_that.embedThumbnail,_that.embedChapters,_that.audioFormat,_that.audioBitrateKbps,_that.normalizeAudio,_that.maxHeight,_that.videoCodec,_that.container,_that.preferHdr,_that.maxFps,_that.audioLanguage,_that.maxFilesizeMb);case _:
   return orElse();
 
 }
@@ -1725,10 +6823,10 @@ return $default(_that.taskId,_that.totalSize,_that.downloadedSize,_that.download
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String taskId,  BigInt totalSize,  BigInt downloadedSize,  BigInt downloadSpeed,  double percentage,  BigInt? etaSecs)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<String> subtitleLangs,  bool autoSubtitles,  bool danmaku,  bool writeThumbnail,  bool writeInfoJson,  bool embedMetadata,  bool embedThumbnail,  bool embedChapters,  String? audioFormat,  int? audioBitrateKbps,  bool normalizeAudio,  int? maxHeight,  String? videoCodec,  String? container,  bool preferHdr,  int? maxFps,  String? audioLanguage,  BigInt? maxFilesizeMb)  $default,) {final _that = this;
 switch (_that) {
-case _ProgressEvent():
-return $default(_that.taskId,_that.totalSize,_that.downloadedSize,_that.downloadSpeed,_that.percentage,_that.etaSecs);}
+case _VideoDownloadOptions():
+return $default(_that.subtitleLangs,_that.autoSubtitles,_that.danmaku,_that.writeThumbnail,_that.writeInfoJson,_that.embedMetadata,_that.embedThumbnail,_that.embedChapters,_that.audioFormat,_that.audioBitrateKbps,_that.normalizeAudio,_that.maxHeight,_that.videoCodec,_that.container,_that.preferHdr,_that.maxFps,_that.audioLanguage,_that.maxFilesizeMb);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -1742,10 +6840,10 @@ return $default(_that.taskId,_that.totalSize,_that.downloadedSize,_that.download
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String taskId,  BigInt totalSize,  BigInt downloadedSize,  BigInt downloadSpeed,  double percentage,  BigInt? etaSecs)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<String> subtitleLangs,  bool autoSubtitles,  bool danmaku,  bool writeThumbnail,  bool writeInfoJson,  bool embedMetadata,  bool embedThumbnail,  bool embedChapters,  String? audioFormat,  int? audioBitrateKbps,  bool normalizeAudio,  int? maxHeight,  String? videoCodec,  String? container,  bool preferHdr,  int? maxFps,  String? audioLanguage,  BigInt? maxFilesizeMb)?  $default,) {final _that = this;
 switch (_that) {
-case _ProgressEvent() when $default != null:
-return $default(_that.taskId,_that.totalSize,_that.downloadedSize,_that.downloadSpeed,_that.percentage,_that.etaSecs);case _:
+case _VideoDownloadOptions() when $default != null:
+return $default(_that.subtitleLangs,_that.autoSubtitles,_that.danmaku,_that.writeThumbnail,_that.writeInfoJson,_that.embedMetadata,_that.embedThumbnail,_that.embedChapters,_that.audioFormat,_that.audioBitrateKbps,_that.normalizeAudio,_that.maxHeight,_that.videoCodec,_that.container,_that.preferHdr,_that.maxFps,_that.audioLanguage,_that.maxFilesizeMb);case _:
   return null;
 
 }
@@ -1756,48 +6854,66 @@ return $default(_that.taskId,_that.totalSize,_that.downloadedSize,_that.download
 /// @nodoc
 
 
-class _ProgressEvent implements ProgressEvent {
-  const _ProgressEvent({required this.taskId, required this.totalSize, required this.downloadedSize, required this.downloadSpeed, required this.percentage, this.etaSecs});
+class _VideoDownloadOptions implements VideoDownloadOptions {
+  const _VideoDownloadOptions({required final  List<String> subtitleLangs, required this.autoSubtitles, required this.danmaku, required this.writeThumbnail, required this.writeInfoJson, required this.embedMetadata, required this.embedThumbnail, required this.embedChapters, this.audioFormat, this.audioBitrateKbps, required this.normalizeAudio, this.maxHeight, this.videoCodec, this.container, required this.preferHdr, this.maxFps, this.audioLanguage, this.maxFilesizeMb}): _subtitleLangs = subtitleLangs;
   
 
-@override final  String taskId;
-@override final  BigInt totalSize;
-@override final  BigInt downloadedSize;
-@override final  BigInt downloadSpeed;
-@override final  double percentage;
-@override final  BigInt? etaSecs;
-
-/// Create a copy of ProgressEvent
+ final  List<String> _subtitleLangs;
+@override List<String> get subtitleLangs {
+  if (_subtitleLangs is EqualUnmodifiableListView) return _subtitleLangs;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_subtitleLangs);
+}
+
+@override final  bool autoSubtitles;
+@override final  bool danmaku;
+@override final  bool writeThumbnail;
+@override final  bool writeInfoJson;
+@override final  bool embedMetadata;
+@override final  bool embedThumbnail;
+@override final  bool embedChapters;
+@override final  String? audioFormat;
+@override final  int? audioBitrateKbps;
+@override final  bool normalizeAudio;
+@override final  int? maxHeight;
+@override final  String? videoCodec;
+@override final  String? container;
+@override final  bool preferHdr;
+@override final  int? maxFps;
+@override final  String? audioLanguage;
+@override final  BigInt? maxFilesizeMb;
+
+/// Create a copy of VideoDownloadOptions
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$ProgressEventCopyWith<_ProgressEvent> get copyWith => __$ProgressEventCopyWithImpl<_ProgressEvent>(this, _$identity);
+_$VideoDownloadOptionsCopyWith<_VideoDownloadOptions> get copyWith => __$VideoDownloadOptionsCopyWithImpl<_VideoDownloadOptions>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ProgressEvent&&(identical(other.taskId, taskId) || other.taskId == taskId)&&(identical(other.totalSize, totalSize) || other.totalSize == totalSize)&&(identical(other.downloadedSize, downloadedSize) || other.downloadedSize == downloadedSize)&&(identical(other.downloadSpeed, downloadSpeed) || other.downloadSpeed == downloadSpeed)&&(identical(other.percentage, percentage) || other.percentage == percentage)&&(identical(other.etaSecs, etaSecs) || other.etaSecs == etaSecs));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _VideoDownloadOptions&&const DeepCollectionEquality().equals(other._subtitleLangs, _subtitleLangs)&&(identical(other.autoSubtitles, autoSubtitles) || other.autoSubtitles == autoSubtitles)&&(identical(other.danmaku, danmaku) || other.danmaku == danmaku)&&(identical(other.writeThumbnail, writeThumbnail) || other.writeThumbnail == writeThumbnail)&&(identical(other.writeInfoJson, writeInfoJson) || other.writeInfoJson == writeInfoJson)&&(identical(other.embedMetadata, embedMetadata) || other.embedMetadata == embedMetadata)&&(identical(other.embedThumbnail, embedThumbnail) || other.embedThumbnail == embedThumbnail)&&(identical(other.embedChapters, embedChapters) || other.embedChapters == embedChapters)&&(identical(other.audioFormat, audioFormat) || other.audioFormat == audioFormat)&&(identical(other.audioBitrateKbps, audioBitrateKbps) || other.audioBitrateKbps == audioBitrateKbps)&&(identical(other.normalizeAudio, normalizeAudio) || other.normalizeAudio == normalizeAudio)&&(identical(other.maxHeight, maxHeight) || other.maxHeight == maxHeight)&&(identical(other.videoCodec, videoCodec) || other.videoCodec == videoCodec)&&(identical(other.container, container) || other.container == container)&&(identical(other.preferHdr, preferHdr) || other.preferHdr == preferHdr)&&(identical(other.maxFps, maxFps) || other.maxFps == maxFps)&&(identical(other.audioLanguage, audioLanguage) || other.audioLanguage == audioLanguage)&&(identical(other.maxFilesizeMb, maxFilesizeMb) || other.maxFilesizeMb == maxFilesizeMb));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,taskId,totalSize,downloadedSize,downloadSpeed,percentage,etaSecs);
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_subtitleLangs),autoSubtitles,danmaku,writeThumbnail,writeInfoJson,embedMetadata,embedThumbnail,embedChapters,audioFormat,audioBitrateKbps,normalizeAudio,maxHeight,videoCodec,container,preferHdr,maxFps,audioLanguage,maxFilesizeMb);
 
 @override
 String toString() {
-  return 'ProgressEvent(taskId: $taskId, totalSize: $totalSize, downloadedSize: $downloadedSize, downloadSpeed: $downloadSpeed, percentage: $percentage, etaSecs: $etaSecs)';
+  return 'VideoDownloadOptions(subtitleLangs: $subtitleLangs, autoSubtitles: $autoSubtitles, danmaku: $danmaku, writeThumbnail: $writeThumbnail, writeInfoJson: $writeInfoJson, embedMetadata: $embedMetadata, embedThumbnail: $embedThumbnail, embedChapters: $embedChapters, audioFormat: $audioFormat, audioBitrateKbps: $audioBitrateKbps, normalizeAudio: $normalizeAudio, maxHeight: $maxHeight, videoCodec: $videoCodec, container: $container, preferHdr: $preferHdr, maxFps: $maxFps, audioLanguage: $audioLanguage, maxFilesizeMb: $maxFilesizeMb)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$ProgressEventCopyWith<$Res> implements $ProgressEventCopyWith<$Res> {
-  factory _$ProgressEventCopyWith(_ProgressEvent value, $Res Function(_ProgressEvent) _then) = __$ProgressEventCopyWithImpl;
+abstract mixin class _$VideoDownloadOptionsCopyWith<$Res> implements $VideoDownloadOptionsCopyWith<$Res> {
+  factory _$VideoDownloadOptionsCopyWith(_VideoDownloadOptions value, $Res Function(_VideoDownloadOptions) _then) = __$VideoDownloadOptionsCopyWithImpl;
 @override @useResult
 $Res call({
- String taskId, BigInt totalSize, BigInt downloadedSize, BigInt downloadSpeed, double percentage, BigInt? etaSecs
+ List<String> subtitleLangs, bool autoSubtitles, bool danmaku, bool writeThumbnail, bool writeInfoJson, bool embedMetadata, bool embedThumbnail, bool embedChapters, String? audioFormat, int? audioBitrateKbps, bool normalizeAudio, int? maxHeight, String? videoCodec, String? container, bool preferHdr, int? maxFps, String? audioLanguage, BigInt? maxFilesizeMb
 });
 
 
@@ -1805,23 +6921,35 @@ $Res call({
 
 }
 /// @nodoc
-class __$ProgressEventCopyWithImpl<$Res>
-    implements _$ProgressEventCopyWith<$Res> {
-  __$ProgressEventCopyWithImpl(this._self, this._then);
+class __$VideoDownloadOptionsCopyWithImpl<$Res>
+    implements _$VideoDownloadOptionsCopyWith<$Res> {
+  __$VideoDownloadOptionsCopyWithImpl(this._self, this._then);
 
-  final _ProgressEvent _self;
-  final $Res Function(_ProgressEvent) _then;
+  final _VideoDownloadOptions _self;
+  final $Res Function(_VideoDownloadOptions) _then;
 
-/// Create a copy of ProgressEvent
+/// Create a copy of VideoDownloadOptions
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? taskId = null,Object? totalSize = null,Object? downloadedSize = null,Object? downloadSpeed = null,Object? percentage = null,Object? etaSecs = freezed,}) {
-  return _then(_ProgressEvent(
-taskId: null == taskId ? _self.taskId : taskId // ignore: cast_nullable_to_non_nullable
-as String,totalSize: null == totalSize ? _self.totalSize : totalSize // ignore: cast_nullable_to_non_nullable
-as BigInt,downloadedSize: null == downloadedSize ? _self.downloadedSize : downloadedSize // ignore: cast_nullable_to_non_nullable
-as BigInt,downloadSpeed: null == downloadSpeed ? _self.downloadSpeed : downloadSpeed // ignore: cast_nullable_to_non_nullable
-as BigInt,percentage: null == percentage ? _self.percentage : percentage // ignore: cast_nullable_to_non_nullable
-as double,etaSecs: freezed == etaSecs ? _self.etaSecs : etaSecs // ignore: cast_nullable_to_non_nullable
+@override @pragma('vm:prefer-inline') $Res call({Object? subtitleLangs = null,Object? autoSubtitles = null,Object? danmaku = null,Object? writeThumbnail = null,Object? writeInfoJson = null,Object? embedMetadata = null,Object? embedThumbnail = null,Object? embedChapters = null,Object? audioFormat = freezed,Object? audioBitrateKbps = freezed,Object? normalizeAudio = null,Object? maxHeight = freezed,Object? videoCodec = freezed,Object? container = freezed,Object? preferHdr = null,Object? maxFps = freezed,Object? audioLanguage = freezed,Object? maxFilesizeMb = freezed,}) {
+  return _then(_VideoDownloadOptions(
+subtitleLangs: null == subtitleLangs ? _self._subtitleLangs : subtitleLangs // ignore: cast_nullable_to_non_nullable
+as List<String>,autoSubtitles: null == autoSubtitles ? _self.autoSubtitles : autoSubtitles // ignore: cast_nullable_to_non_nullable
+as bool,danmaku: null == danmaku ? _self.danmaku : danmaku // ignore: cast_nullable_to_non_nullable
+as bool,writeThumbnail: null == writeThumbnail ? _self.writeThumbnail : writeThumbnail // ignore: cast_nullable_to_non_nullable
+as bool,writeInfoJson: null == writeInfoJson ? _self.writeInfoJson : writeInfoJson // ignore: cast_nullable_to_non_nullable
+as bool,embedMetadata: null == embedMetadata ? _self.embedMetadata : embedMetadata // ignore: cast_nullable_to_non_nullable
+as bool,embedThumbnail: null == embedThumbnail ? _self.embedThumbnail : embedThumbnail // ignore: cast_nullable_to_non_nullable
+as bool,embedChapters: null == embedChapters ? _self.embedChapters : embedChapters // ignore: cast_nullable_to_non_nullable
+as bool,audioFormat: freezed == audioFormat ? _self.audioFormat : audioFormat // ignore: cast_nullable_to_non_nullable
+as String?,audioBitrateKbps: freezed == audioBitrateKbps ? _self.audioBitrateKbps : audioBitrateKbps // ignore: cast_nullable_to_non_nullable
+as int?,normalizeAudio: null == normalizeAudio ? _self.normalizeAudio : normalizeAudio // ignore: cast_nullable_to_non_nullable
+as bool,maxHeight: freezed == maxHeight ? _self.maxHeight : maxHeight // ignore: cast_nullable_to_non_nullable
+as int?,videoCodec: freezed == videoCodec ? _self.videoCodec : videoCodec // ignore: cast_nullable_to_non_nullable
+as String?,container: freezed == container ? _self.container : container // ignore: cast_nullable_to_non_nullable
+as String?,preferHdr: null == preferHdr ? _self.preferHdr : preferHdr // ignore: cast_nullable_to_non_nullable
+as bool,maxFps: freezed == maxFps ? _self.maxFps : maxFps // ignore: cast_nullable_to_non_nullable
+as int?,audioLanguage: freezed == audioLanguage ? _self.audioLanguage : audioLanguage // ignore: cast_nullable_to_non_nullable
+as String?,maxFilesizeMb: freezed == maxFilesizeMb ? _self.maxFilesizeMb : maxFilesizeMb // ignore: cast_nullable_to_non_nullable
 as BigInt?,
   ));
 }
@@ -1832,7 +6960,7 @@ as BigInt?,
 /// @nodoc
 mixin _$VideoFormat {
 
- String get formatId; String get ext; String? get resolution; BigInt? get filesize; String? get formatNote; double? get fps; String? get vcodec; String? get acodec;
+ String get formatId; String get ext; String? get resolution; BigInt? get filesize; String? get formatNote; double? get fps; String? get vcodec; String? get acodec; double? get tbr; String? get dynamicRange; String? get language; bool get audioOnly; bool get videoOnly;
 /// Create a copy of VideoFormat
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
@@ -1843,16 +6971,16 @@ $VideoFormatCopyWith<VideoFormat> get copyWith => _$VideoFormatCopyWithImpl<Vide
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is VideoFormat&&(identical(other.formatId, formatId) || other.formatId == formatId)&&(identical(other.ext, ext) || other.ext == ext)&&(identical(other.resolution, resolution) || other.resolution == resolution)&&(identical(other.filesize, filesize) || other.filesize == filesize)&&(identical(other.formatNote, formatNote) || other.formatNote == formatNote)&&(identical(other.fps, fps) || other.fps == fps)&&(identical(other.vcodec, vcodec) || other.vcodec == vcodec)&&(identical(other.acodec, acodec) || other.acodec == acodec));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is VideoFormat&&(identical(other.formatId, formatId) || other.formatId == formatId)&&(identical(other.ext, ext) || other.ext == ext)&&(identical(other.resolution, resolution) || other.resolution == resolution)&&(identical(other.filesize, filesize) || other.filesize == filesize)&&(identical(other.formatNote, formatNote) || other.formatNote == formatNote)&&(identical(other.fps, fps) || other.fps == fps)&&(identical(other.vcodec, vcodec) || other.vcodec == vcodec)&&(identical(other.acodec, acodec) || other.acodec == acodec)&&(identical(other.tbr, tbr) || other.tbr == tbr)&&(identical(other.dynamicRange, dynamicRange) || other.dynamicRange == dynamicRange)&&(identical(other.language, language) || other.language == language)&&(identical(other.audioOnly, audioOnly) || other.audioOnly == audioOnly)&&(identical(other.videoOnly, videoOnly) || other.videoOnly == videoOnly));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,formatId,ext,resolution,filesize,formatNote,fps,vcodec,acodec);
+int get hashCode => Object.hash(runtimeType,formatId,ext,resolution,filesize,formatNote,fps,vcodec,acodec,tbr,dynamicRange,language,audioOnly,videoOnly);
 
 @override
 String toString() {
-  return 'VideoFormat(formatId: $formatId, ext: $ext, resolution: $resolution, filesize: $filesize, formatNote: $formatNote, fps: $fps, vcodec: $vcodec, acodec: $acodec)';
+  return 'VideoFormat(formatId: $formatId, ext: $ext, resolution: $resolution, filesize: $filesize, formatNote: $formatNote, fps: $fps, vcodec: $vcodec, acodec: $acodec, tbr: $tbr, dynamicRange: $dynamicRange, language: $language, audioOnly: $audioOnly, videoOnly: $videoOnly)';
 }
 
 
@@ -1863,7 +6991,7 @@ abstract mixin class $VideoFormatCopyWith<$Res>  {
   factory $VideoFormatCopyWith(VideoFormat value, $Res Function(VideoFormat) _then) = _$VideoFormatCopyWithImpl;
 @useResult
 $Res call({
- String formatId, String ext, String? resolution, BigInt? filesize, String? formatNote, double? fps, String? vcodec, String? acodec
+ String formatId, String ext, String? resolution, BigInt? filesize, String? formatNote, double? fps, String? vcodec, String? acodec, double? tbr, String? dynamicRange, String? language, bool audioOnly, bool videoOnly
 });
 
 
@@ -1880,7 +7008,7 @@ class _$VideoFormatCopyWithImpl<$Res>
 
 /// Create a copy of VideoFormat
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? formatId = null,Object? ext = null,Object? resolution = freezed,Object? filesize = freezed,Object? formatNote = freezed,Object? fps = freezed,Object? vcodec = freezed,Object? acodec = freezed,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? formatId = null,Object? ext = null,Object? resolution = freezed,Object? filesize = freezed,Object? formatNote = freezed,Object? fps = freezed,Object? vcodec = freezed,Object? acodec = freezed,Object? tbr = freezed,Object? dynamicRange = freezed,Object? language = freezed,Object? audioOnly = null,Object? videoOnly = null,}) {
   return _then(_self.copyWith(
 formatId: null == formatId ? _self.formatId : formatId // ignore: cast_nullable_to_non_nullable
 as String,ext: null == ext ? _self.ext : ext // ignore: cast_nullable_to_non_nullable
@@ -1890,7 +7018,12 @@ as BigInt?,formatNote: freezed == formatNote ? _self.formatNote : formatNote //
 as String?,fps: freezed == fps ? _self.fps : fps // ignore: cast_nullable_to_non_nullable
 as double?,vcodec: freezed == vcodec ? _self.vcodec : vcodec // ignore: cast_nullable_to_non_nullable
 as String?,acodec: freezed == acodec ? _self.acodec : acodec // ignore: cast_nullable_to_non_nullable
-as String?,
+as String?,tbr: freezed == tbr ? _self.tbr : tbr // ignore: cast_nullable_to_non_nullable
+as double?,dynamicRange: freezed == dynamicRange ? _self.dynamicRange : dynamicRange // ignore: cast_nullable_to_non_nullable
+as String?,language: freezed == language ? _self.language : language // ignore: cast_nullable_to_non_nullable
+as String?,audioOnly: null == audioOnly ? _self.audioOnly : audioOnly // ignore: cast_nullable_to_non_nullable
+as bool,videoOnly: null == videoOnly ? _self.videoOnly : videoOnly // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
 
@@ -1972,10 +7105,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String formatId,  String ext,  String? resolution,  BigInt? filesize,  String? formatNote,  double? fps,  String? vcodec,  String? acodec)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String formatId,  String ext,  String? resolution,  BigInt? filesize,  String? formatNote,  double? fps,  String? vcodec,  String? acodec,  double? tbr,  String? dynamicRange,  String? language,  bool audioOnly,  bool videoOnly)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case _VideoFormat() when $default != null:
-return $default(_that.formatId,_that.ext,_that.resolution,_that.filesize,_that.formatNote,_that.fps,_that.vcodec,_that.acodec);case _:
+return $default(_that.formatId,_that.ext,_that.resolution,_that.filesize,_that.formatNote,_that.fps,_that.vcodec,_that.acodec,_that.tbr,_that.dynamicRange,_that.language,_that.audioOnly,_that.videoOnly);case _:
   return orElse();
 
 }
@@ -1993,10 +7126,10 @@ return $default(_that.formatId,_that.ext,_that.resolution,_that.filesize,_that.f
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String formatId,  String ext,  String? resolution,  BigInt? filesize,  String? formatNote,  double? fps,  String? vcodec,  String? acodec)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String formatId,  String ext,  String? resolution,  BigInt? filesize,  String? formatNote,  double? fps,  String? vcodec,  String? acodec,  double? tbr,  String? dynamicRange,  String? language,  bool audioOnly,  bool videoOnly)  $default,) {final _that = this;
 switch (_that) {
 case _VideoFormat():
-return $default(_that.formatId,_that.ext,_that.resolution,_that.filesize,_that.formatNote,_that.fps,_that.vcodec,_that.acodec);}
+return $default(_that.formatId,_that.ext,_that.resolution,_that.filesize,_that.formatNote,_that.fps,_that.vcodec,_that.acodec,_that.tbr,_that.dynamicRange,_that.language,_that.audioOnly,_that.videoOnly);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -2010,10 +7143,10 @@ return $default(_that.formatId,_that.ext,_that.resolution,_that.filesize,_that.f
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String formatId,  String ext,  String? resolution,  BigInt? filesize,  String? formatNote,  double? fps,  String? vcodec,  String? acodec)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String formatId,  String ext,  String? resolution,  BigInt? filesize,  String? formatNote,  double? fps,  String? vcodec,  String? acodec,  double? tbr,  String? dynamicRange,  String? language,  bool audioOnly,  bool videoOnly)?  $default,) {final _that = this;
 switch (_that) {
 case _VideoFormat() when $default != null:
-return $default(_that.formatId,_that.ext,_that.resolution,_that.filesize,_that.formatNote,_that.fps,_that.vcodec,_that.acodec);case _:
+return $default(_that.formatId,_that.ext,_that.resolution,_that.filesize,_that.formatNote,_that.fps,_that.vcodec,_that.acodec,_that.tbr,_that.dynamicRange,_that.language,_that.audioOnly,_that.videoOnly);case _:
   return null;
 
 }
@@ -2025,7 +7158,7 @@ return $default(_that.formatId,_that.ext,_that.resolution,_that.filesize,_that.f
 
 
 class _VideoFormat implements VideoFormat {
-  const _VideoFormat({required this.formatId, required this.ext, this.resolution, this.filesize, this.formatNote, this.fps, this.vcodec, this.acodec});
+  const _VideoFormat({required this.formatId, required this.ext, this.resolution, this.filesize, this.formatNote, this.fps, this.vcodec, this.acodec, this.tbr, this.dynamicRange, this.language, required this.audioOnly, required this.videoOnly});
   
 
 @override final  String formatId;
@@ -2036,6 +7169,11 @@ class _VideoFormat implements VideoFormat {
 @override final  double? fps;
 @override final  String? vcodec;
 @override final  String? acodec;
+@override final  double? tbr;
+@override final  String? dynamicRange;
+@override final  String? language;
+@override final  bool audioOnly;
+@override final  bool videoOnly;
 
 /// Create a copy of VideoFormat
 /// with the given fields replaced by the non-null parameter values.
@@ -2047,16 +7185,16 @@ _$VideoFormatCopyWith<_VideoFormat> get copyWith => __$VideoFormatCopyWithImpl<_
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _VideoFormat&&(identical(other.formatId, formatId) || other.formatId == formatId)&&(identical(other.ext, ext) || other.ext == ext)&&(identical(other.resolution, resolution) || other.resolution == resolution)&&(identical(other.filesize, filesize) || other.filesize == filesize)&&(identical(other.formatNote, formatNote) || other.formatNote == formatNote)&&(identical(other.fps, fps) || other.fps == fps)&&(identical(other.vcodec, vcodec) || other.vcodec == vcodec)&&(identical(other.acodec, acodec) || other.acodec == acodec));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _VideoFormat&&(identical(other.formatId, formatId) || other.formatId == formatId)&&(identical(other.ext, ext) || other.ext == ext)&&(identical(other.resolution, resolution) || other.resolution == resolution)&&(identical(other.filesize, filesize) || other.filesize == filesize)&&(identical(other.formatNote, formatNote) || other.formatNote == formatNote)&&(identical(other.fps, fps) || other.fps == fps)&&(identical(other.vcodec, vcodec) || other.vcodec == vcodec)&&(identical(other.acodec, acodec) || other.acodec == acodec)&&(identical(other.tbr, tbr) || other.tbr == tbr)&&(identical(other.dynamicRange, dynamicRange) || other.dynamicRange == dynamicRange)&&(identical(other.language, language) || other.language == language)&&(identical(other.audioOnly, audioOnly) || other.audioOnly == audioOnly)&&(identical(other.videoOnly, videoOnly) || other.videoOnly == videoOnly));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,formatId,ext,resolution,filesize,formatNote,fps,vcodec,acodec);
+int get hashCode => Object.hash(runtimeType,formatId,ext,resolution,filesize,formatNote,fps,vcodec,acodec,tbr,dynamicRange,language,audioOnly,videoOnly);
 
 @override
 String toString() {
-  return 'VideoFormat(formatId: $formatId, ext: $ext, resolution: $resolution, filesize: $filesize, formatNote: $formatNote, fps: $fps, vcodec: $vcodec, acodec: $acodec)';
+  return 'VideoFormat(formatId: $formatId, ext: $ext, resolution: $resolution, filesize: $filesize, formatNote: $formatNote, fps: $fps, vcodec: $vcodec, acodec: $acodec, tbr: $tbr, dynamicRange: $dynamicRange, language: $language, audioOnly: $audioOnly, videoOnly: $videoOnly)';
 }
 
 
@@ -2067,7 +7205,7 @@ abstract mixin class _$VideoFormatCopyWith<$Res> implements $VideoFormatCopyWith
   factory _$VideoFormatCopyWith(_VideoFormat value, $Res Function(_VideoFormat) _then) = __$VideoFormatCopyWithImpl;
 @override @useResult
 $Res call({
- String formatId, String ext, String? resolution, BigInt? filesize, String? formatNote, double? fps, String? vcodec, String? acodec
+ String formatId, String ext, String? resolution, BigInt? filesize, String? formatNote, double? fps, String? vcodec, String? acodec, double? tbr, String? dynamicRange, String? language, bool audioOnly, bool videoOnly
 });
 
 
@@ -2084,7 +7222,7 @@ class __$VideoFormatCopyWithImpl<$Res>
 
 /// Create a copy of VideoFormat
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? formatId = null,Object? ext = null,Object? resolution = freezed,Object? filesize = freezed,Object? formatNote = freezed,Object? fps = freezed,Object? vcodec = freezed,Object? acodec = freezed,}) {
+@override @pragma('vm:prefer-inline') $Res call({Object? formatId = null,Object? ext = null,Object? resolution = freezed,Object? filesize = freezed,Object? formatNote = freezed,Object? fps = freezed,Object? vcodec = freezed,Object? acodec = freezed,Object? tbr = freezed,Object? dynamicRange = freezed,Object? language = freezed,Object? audioOnly = null,Object? videoOnly = null,}) {
   return _then(_VideoFormat(
 formatId: null == formatId ? _self.formatId : formatId // ignore: cast_nullable_to_non_nullable
 as String,ext: null == ext ? _self.ext : ext // ignore: cast_nullable_to_non_nullable
@@ -2094,7 +7232,12 @@ as BigInt?,formatNote: freezed == formatNote ? _self.formatNote : formatNote //
 as String?,fps: freezed == fps ? _self.fps : fps // ignore: cast_nullable_to_non_nullable
 as double?,vcodec: freezed == vcodec ? _self.vcodec : vcodec // ignore: cast_nullable_to_non_nullable
 as String?,acodec: freezed == acodec ? _self.acodec : acodec // ignore: cast_nullable_to_non_nullable
-as String?,
+as String?,tbr: freezed == tbr ? _self.tbr : tbr // ignore: cast_nullable_to_non_nullable
+as double?,dynamicRange: freezed == dynamicRange ? _self.dynamicRange : dynamicRange // ignore: cast_nullable_to_non_nullable
+as String?,language: freezed == language ? _self.language : language // ignore: cast_nullable_to_non_nullable
+as String?,audioOnly: null == audioOnly ? _self.audioOnly : audioOnly // ignore: cast_nullable_to_non_nullable
+as bool,videoOnly: null == videoOnly ? _self.videoOnly : videoOnly // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
 
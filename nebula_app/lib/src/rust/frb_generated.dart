@@ -67,7 +67,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 222029928;
+  int get rustContentHash => -2087927618;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -83,12 +83,44 @@ abstract class RustLibApi extends BaseApi {
     required String savePath,
   });
 
+  Future<String> crateApiDownloadAddDownloadWithExistingData({
+    required String source,
+    required String dataDir,
+  });
+
+  Future<String> crateApiDownloadAddDownloadWithFiles({
+    required String source,
+    required String savePath,
+    required Uint64List fileIndices,
+  });
+
+  Future<String> crateApiDownloadAddDownloadWithPolicy({
+    required String source,
+    required String savePath,
+    required DuplicateAction onDuplicate,
+  });
+
+  Future<String> crateApiDownloadAddPlaylistDownload({
+    required String url,
+    required String savePath,
+    String? items,
+    String? formatId,
+    required bool onlyNew,
+    VideoDownloadOptions? options,
+  });
+
+  Future<void> crateApiDownloadAddTorrentTrackers({
+    required String taskId,
+    required List<String> urls,
+  });
+
   Future<String> crateApiDownloadAddVideoDownload({
     required String url,
     required String savePath,
     String? formatId,
     String? title,
     String? thumbnail,
+    VideoDownloadOptions? options,
   });
 
   Future<void> crateApiDownloadCancelDownload({
@@ -96,10 +128,54 @@ abstract class RustLibApi extends BaseApi {
     required bool deleteFiles,
   });
 
+  Future<ToolUpdateInfo> crateApiDownloadCheckYtDlpUpdate();
+
+  Future<CreatedTorrentInfo> crateApiDownloadCreateTorrent({
+    required String input,
+    required String torrentPath,
+    BigInt? pieceSize,
+    required bool private,
+    required List<String> trackers,
+    required List<String> webSeeds,
+    String? comment,
+    required bool seed,
+  });
+
+  Future<void> crateApiDownloadExportResolvedTorrent({
+    required String source,
+    required String torrentPath,
+  });
+
+  Future<void> crateApiDownloadExportTorrent({
+    required String taskId,
+    required String torrentPath,
+  });
+
   Future<BilibiliQrCode> crateApiDownloadGenerateBilibiliQrcode({
     required String dataDir,
   });
 
+  Future<BilibiliAccountInfo?> crateApiDownloadGetBilibiliAccount({
+    required String dataDir,
+  });
+
+  Future<CredentialVaultStatus> crateApiDownloadGetCredentialVaultStatus();
+
+  Future<IpFilterInfo?> crateApiDownloadGetIpFilterStats();
+
+  Future<PlaylistInfo> crateApiDownloadGetPlaylistInfo({required String url});
+
+  Future<List<ToolStatusInfo>> crateApiDownloadGetToolsStatus();
+
+  Future<List<PeerInfo>> crateApiDownloadGetTorrentPeers({
+    required String taskId,
+  });
+
+  Future<List<TrackerInfo>> crateApiDownloadGetTorrentTrackers({
+    required String taskId,
+    required bool refresh,
+  });
+
   Future<VideoInfo> crateApiDownloadGetVideoInfo({required String url});
 
   String crateApiSimpleGreet({required String name});
@@ -114,6 +190,8 @@ abstract class RustLibApi extends BaseApi {
 
   Future<bool> crateApiDownloadIsVideoUrl({required String url});
 
+  Future<List<SiteCredentialInfo>> crateApiDownloadListSiteCredentials();
+
   Future<void> crateApiDownloadLogoutBilibili({required String dataDir});
 
   Future<void> crateApiDownloadOpenFile({required String taskId});
@@ -127,9 +205,49 @@ abstract class RustLibApi extends BaseApi {
     required String qrcodeKey,
   });
 
+  Future<String?> crateApiDownloadProbeVideoExtractor({required String url});
+
+  Future<void> crateApiDownloadRecheckDownload({required String taskId});
+
+  Future<BilibiliSessionStatus> crateApiDownloadRefreshBilibiliSession({
+    required String dataDir,
+  });
+
+  Future<void> crateApiDownloadRemoveDownload({required String taskId});
+
+  Future<int> crateApiDownloadRemoveSiteCredential({
+    required String domain,
+    String? kind,
+  });
+
+  Future<void> crateApiDownloadRemoveTorrentTrackers({
+    required String taskId,
+    required List<String> urls,
+  });
+
+  Future<ResolvedTorrentInfo> crateApiDownloadResolveTorrent({
+    required String source,
+  });
+
+  Future<void> crateApiDownloadRestartDownload({required String taskId});
+
   Future<void> crateApiDownloadResumeDownload({required String taskId});
 
+  Future<void> crateApiDownloadSetSiteCredential({
+    required String domain,
+    required bool includeSubdomains,
+    required SiteCredentialInput credential,
+  });
+
   Future<Stream<NebulaEvent>> crateApiDownloadSubscribeEvents();
+
+  Future<void> crateApiDownloadUnlockCredentialVault({
+    required String passphrase,
+  });
+
+  Future<IpFilterInfo> crateApiDownloadUpdateIpFilter();
+
+  Future<ToolStatusInfo> crateApiDownloadUpdateYtDlp();
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -176,22 +294,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<String> crateApiDownloadAddVideoDownload({
-    required String url,
-    required String savePath,
-    String? formatId,
-    String? title,
-    String? thumbnail,
+  Future<String> crateApiDownloadAddDownloadWithExistingData({
+    required String source,
+    required String dataDir,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
-          sse_encode_String(savePath, serializer);
-          sse_encode_opt_String(formatId, serializer);
-          sse_encode_opt_String(title, serializer);
-          sse_encode_opt_String(thumbnail, serializer);
+          sse_encode_String(source, serializer);
+          sse_encode_String(dataDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -203,30 +315,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDownloadAddVideoDownloadConstMeta,
-        argValues: [url, savePath, formatId, title, thumbnail],
+        constMeta: kCrateApiDownloadAddDownloadWithExistingDataConstMeta,
+        argValues: [source, dataDir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadAddVideoDownloadConstMeta =>
+  TaskConstMeta get kCrateApiDownloadAddDownloadWithExistingDataConstMeta =>
       const TaskConstMeta(
-        debugName: "add_video_download",
-        argNames: ["url", "savePath", "formatId", "title", "thumbnail"],
+        debugName: "add_download_with_existing_data",
+        argNames: ["source", "dataDir"],
       );
 
   @override
-  Future<void> crateApiDownloadCancelDownload({
-    required String taskId,
-    required bool deleteFiles,
+  Future<String> crateApiDownloadAddDownloadWithFiles({
+    required String source,
+    required String savePath,
+    required Uint64List fileIndices,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(taskId, serializer);
-          sse_encode_bool(deleteFiles, serializer);
+          sse_encode_String(source, serializer);
+          sse_encode_String(savePath, serializer);
+          sse_encode_list_prim_u_64_strict(fileIndices, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -235,31 +349,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDownloadCancelDownloadConstMeta,
-        argValues: [taskId, deleteFiles],
+        constMeta: kCrateApiDownloadAddDownloadWithFilesConstMeta,
+        argValues: [source, savePath, fileIndices],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadCancelDownloadConstMeta =>
+  TaskConstMeta get kCrateApiDownloadAddDownloadWithFilesConstMeta =>
       const TaskConstMeta(
-        debugName: "cancel_download",
-        argNames: ["taskId", "deleteFiles"],
+        debugName: "add_download_with_files",
+        argNames: ["source", "savePath", "fileIndices"],
       );
 
   @override
-  Future<BilibiliQrCode> crateApiDownloadGenerateBilibiliQrcode({
-    required String dataDir,
+  Future<String> crateApiDownloadAddDownloadWithPolicy({
+    required String source,
+    required String savePath,
+    required DuplicateAction onDuplicate,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dataDir, serializer);
+          sse_encode_String(source, serializer);
+          sse_encode_String(savePath, serializer);
+          sse_encode_duplicate_action(onDuplicate, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -268,29 +386,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bilibili_qr_code,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDownloadGenerateBilibiliQrcodeConstMeta,
-        argValues: [dataDir],
+        constMeta: kCrateApiDownloadAddDownloadWithPolicyConstMeta,
+        argValues: [source, savePath, onDuplicate],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadGenerateBilibiliQrcodeConstMeta =>
+  TaskConstMeta get kCrateApiDownloadAddDownloadWithPolicyConstMeta =>
       const TaskConstMeta(
-        debugName: "generate_bilibili_qrcode",
-        argNames: ["dataDir"],
+        debugName: "add_download_with_policy",
+        argNames: ["source", "savePath", "onDuplicate"],
       );
 
   @override
-  Future<VideoInfo> crateApiDownloadGetVideoInfo({required String url}) {
+  Future<String> crateApiDownloadAddPlaylistDownload({
+    required String url,
+    required String savePath,
+    String? items,
+    String? formatId,
+    required bool onlyNew,
+    VideoDownloadOptions? options,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(url, serializer);
+          sse_encode_String(savePath, serializer);
+          sse_encode_opt_String(items, serializer);
+          sse_encode_opt_String(formatId, serializer);
+          sse_encode_bool(onlyNew, serializer);
+          sse_encode_opt_box_autoadd_video_download_options(
+            options,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -299,48 +432,86 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_video_info,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDownloadGetVideoInfoConstMeta,
-        argValues: [url],
+        constMeta: kCrateApiDownloadAddPlaylistDownloadConstMeta,
+        argValues: [url, savePath, items, formatId, onlyNew, options],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadGetVideoInfoConstMeta =>
-      const TaskConstMeta(debugName: "get_video_info", argNames: ["url"]);
+  TaskConstMeta get kCrateApiDownloadAddPlaylistDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_playlist_download",
+        argNames: [
+          "url",
+          "savePath",
+          "items",
+          "formatId",
+          "onlyNew",
+          "options",
+        ],
+      );
 
   @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<void> crateApiDownloadAddTorrentTrackers({
+    required String taskId,
+    required List<String> urls,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+          sse_encode_String(taskId, serializer);
+          sse_encode_list_String(urls, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiDownloadAddTorrentTrackersConstMeta,
+        argValues: [taskId, urls],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta get kCrateApiDownloadAddTorrentTrackersConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_torrent_trackers",
+        argNames: ["taskId", "urls"],
+      );
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<String> crateApiDownloadAddVideoDownload({
+    required String url,
+    required String savePath,
+    String? formatId,
+    String? title,
+    String? thumbnail,
+    VideoDownloadOptions? options,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          sse_encode_String(savePath, serializer);
+          sse_encode_opt_String(formatId, serializer);
+          sse_encode_opt_String(title, serializer);
+          sse_encode_opt_String(thumbnail, serializer);
+          sse_encode_opt_box_autoadd_video_download_options(
+            options,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -349,28 +520,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiDownloadAddVideoDownloadConstMeta,
+        argValues: [url, savePath, formatId, title, thumbnail, options],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiDownloadAddVideoDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_video_download",
+        argNames: [
+          "url",
+          "savePath",
+          "formatId",
+          "title",
+          "thumbnail",
+          "options",
+        ],
+      );
 
   @override
-  Future<void> crateApiDownloadInitDownloadManager({
-    required String downloadDir,
+  Future<void> crateApiDownloadCancelDownload({
+    required String taskId,
+    required bool deleteFiles,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(downloadDir, serializer);
+          sse_encode_String(taskId, serializer);
+          sse_encode_bool(deleteFiles, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -382,26 +565,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDownloadInitDownloadManagerConstMeta,
-        argValues: [downloadDir],
+        constMeta: kCrateApiDownloadCancelDownloadConstMeta,
+        argValues: [taskId, deleteFiles],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadInitDownloadManagerConstMeta =>
+  TaskConstMeta get kCrateApiDownloadCancelDownloadConstMeta =>
       const TaskConstMeta(
-        debugName: "init_download_manager",
-        argNames: ["downloadDir"],
+        debugName: "cancel_download",
+        argNames: ["taskId", "deleteFiles"],
       );
 
   @override
-  Future<bool> crateApiDownloadIsBilibiliLoggedIn({required String dataDir}) {
+  Future<ToolUpdateInfo> crateApiDownloadCheckYtDlpUpdate() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dataDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -410,29 +592,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_tool_update_info,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDownloadIsBilibiliLoggedInConstMeta,
-        argValues: [dataDir],
+        constMeta: kCrateApiDownloadCheckYtDlpUpdateConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadIsBilibiliLoggedInConstMeta =>
-      const TaskConstMeta(
-        debugName: "is_bilibili_logged_in",
-        argNames: ["dataDir"],
-      );
+  TaskConstMeta get kCrateApiDownloadCheckYtDlpUpdateConstMeta =>
+      const TaskConstMeta(debugName: "check_yt_dlp_update", argNames: []);
 
   @override
-  Future<bool> crateApiDownloadIsVideoUrl({required String url}) {
+  Future<CreatedTorrentInfo> crateApiDownloadCreateTorrent({
+    required String input,
+    required String torrentPath,
+    BigInt? pieceSize,
+    required bool private,
+    required List<String> trackers,
+    required List<String> webSeeds,
+    String? comment,
+    required bool seed,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
+          sse_encode_String(input, serializer);
+          sse_encode_String(torrentPath, serializer);
+          sse_encode_opt_box_autoadd_u_64(pieceSize, serializer);
+          sse_encode_bool(private, serializer);
+          sse_encode_list_String(trackers, serializer);
+          sse_encode_list_String(webSeeds, serializer);
+          sse_encode_opt_String(comment, serializer);
+          sse_encode_bool(seed, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -441,26 +636,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_created_torrent_info,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDownloadIsVideoUrlConstMeta,
-        argValues: [url],
+        constMeta: kCrateApiDownloadCreateTorrentConstMeta,
+        argValues: [
+          input,
+          torrentPath,
+          pieceSize,
+          private,
+          trackers,
+          webSeeds,
+          comment,
+          seed,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadIsVideoUrlConstMeta =>
-      const TaskConstMeta(debugName: "is_video_url", argNames: ["url"]);
+  TaskConstMeta get kCrateApiDownloadCreateTorrentConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_torrent",
+        argNames: [
+          "input",
+          "torrentPath",
+          "pieceSize",
+          "private",
+          "trackers",
+          "webSeeds",
+          "comment",
+          "seed",
+        ],
+      );
 
   @override
-  Future<void> crateApiDownloadLogoutBilibili({required String dataDir}) {
+  Future<void> crateApiDownloadExportResolvedTorrent({
+    required String source,
+    required String torrentPath,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dataDir, serializer);
+          sse_encode_String(source, serializer);
+          sse_encode_String(torrentPath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -472,23 +692,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDownloadLogoutBilibiliConstMeta,
-        argValues: [dataDir],
+        constMeta: kCrateApiDownloadExportResolvedTorrentConstMeta,
+        argValues: [source, torrentPath],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadLogoutBilibiliConstMeta =>
-      const TaskConstMeta(debugName: "logout_bilibili", argNames: ["dataDir"]);
+  TaskConstMeta get kCrateApiDownloadExportResolvedTorrentConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_resolved_torrent",
+        argNames: ["source", "torrentPath"],
+      );
 
   @override
-  Future<void> crateApiDownloadOpenFile({required String taskId}) {
+  Future<void> crateApiDownloadExportTorrent({
+    required String taskId,
+    required String torrentPath,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(taskId, serializer);
+          sse_encode_String(torrentPath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -500,23 +727,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDownloadOpenFileConstMeta,
-        argValues: [taskId],
+        constMeta: kCrateApiDownloadExportTorrentConstMeta,
+        argValues: [taskId, torrentPath],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadOpenFileConstMeta =>
-      const TaskConstMeta(debugName: "open_file", argNames: ["taskId"]);
+  TaskConstMeta get kCrateApiDownloadExportTorrentConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_torrent",
+        argNames: ["taskId", "torrentPath"],
+      );
 
   @override
-  Future<void> crateApiDownloadOpenFolder({required String taskId}) {
+  Future<BilibiliQrCode> crateApiDownloadGenerateBilibiliQrcode({
+    required String dataDir,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(taskId, serializer);
+          sse_encode_String(dataDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -525,26 +757,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_bilibili_qr_code,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDownloadOpenFolderConstMeta,
-        argValues: [taskId],
-        apiImpl: this,
+        constMeta: kCrateApiDownloadGenerateBilibiliQrcodeConstMeta,
+        argValues: [dataDir],
+        apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadOpenFolderConstMeta =>
-      const TaskConstMeta(debugName: "open_folder", argNames: ["taskId"]);
+  TaskConstMeta get kCrateApiDownloadGenerateBilibiliQrcodeConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_bilibili_qrcode",
+        argNames: ["dataDir"],
+      );
 
   @override
-  Future<void> crateApiDownloadPauseDownload({required String taskId}) {
+  Future<BilibiliAccountInfo?> crateApiDownloadGetBilibiliAccount({
+    required String dataDir,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(taskId, serializer);
+          sse_encode_String(dataDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -553,30 +790,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_opt_box_autoadd_bilibili_account_info,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDownloadPauseDownloadConstMeta,
-        argValues: [taskId],
+        constMeta: kCrateApiDownloadGetBilibiliAccountConstMeta,
+        argValues: [dataDir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadPauseDownloadConstMeta =>
-      const TaskConstMeta(debugName: "pause_download", argNames: ["taskId"]);
+  TaskConstMeta get kCrateApiDownloadGetBilibiliAccountConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_bilibili_account",
+        argNames: ["dataDir"],
+      );
 
   @override
-  Future<BilibiliLoginStatus> crateApiDownloadPollBilibiliLogin({
-    required String dataDir,
-    required String qrcodeKey,
-  }) {
+  Future<CredentialVaultStatus> crateApiDownloadGetCredentialVaultStatus() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dataDir, serializer);
-          sse_encode_String(qrcodeKey, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -585,29 +820,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bilibili_login_status,
+          decodeSuccessData: sse_decode_credential_vault_status,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDownloadPollBilibiliLoginConstMeta,
-        argValues: [dataDir, qrcodeKey],
+        constMeta: kCrateApiDownloadGetCredentialVaultStatusConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadPollBilibiliLoginConstMeta =>
+  TaskConstMeta get kCrateApiDownloadGetCredentialVaultStatusConstMeta =>
       const TaskConstMeta(
-        debugName: "poll_bilibili_login",
-        argNames: ["dataDir", "qrcodeKey"],
+        debugName: "get_credential_vault_status",
+        argNames: [],
       );
 
   @override
-  Future<void> crateApiDownloadResumeDownload({required String taskId}) {
+  Future<IpFilterInfo?> crateApiDownloadGetIpFilterStats() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(taskId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -616,27 +850,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_opt_box_autoadd_ip_filter_info,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDownloadResumeDownloadConstMeta,
-        argValues: [taskId],
+        constMeta: kCrateApiDownloadGetIpFilterStatsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDownloadResumeDownloadConstMeta =>
-      const TaskConstMeta(debugName: "resume_download", argNames: ["taskId"]);
+  TaskConstMeta get kCrateApiDownloadGetIpFilterStatsConstMeta =>
+      const TaskConstMeta(debugName: "get_ip_filter_stats", argNames: []);
 
   @override
-  Future<Stream<NebulaEvent>> crateApiDownloadSubscribeEvents() async {
-    final sink = RustStreamSink<NebulaEvent>();
-    await handler.executeNormal(
+  Future<PlaylistInfo> crateApiDownloadGetPlaylistInfo({required String url}) {
+    return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_StreamSink_nebula_event_Sse(sink, serializer);
+          sse_encode_String(url, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -645,474 +878,2407 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_playlist_info,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDownloadSubscribeEventsConstMeta,
-        argValues: [sink],
+        constMeta: kCrateApiDownloadGetPlaylistInfoConstMeta,
+        argValues: [url],
         apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiDownloadSubscribeEventsConstMeta =>
-      const TaskConstMeta(debugName: "subscribe_events", argNames: ["sink"]);
+  TaskConstMeta get kCrateApiDownloadGetPlaylistInfoConstMeta =>
+      const TaskConstMeta(debugName: "get_playlist_info", argNames: ["url"]);
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  @override
+  Future<List<ToolStatusInfo>> crateApiDownloadGetToolsStatus() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_tool_status_info,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadGetToolsStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  RustStreamSink<NebulaEvent> dco_decode_StreamSink_nebula_event_Sse(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
+  TaskConstMeta get kCrateApiDownloadGetToolsStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_tools_status", argNames: []);
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  @override
+  Future<List<PeerInfo>> crateApiDownloadGetTorrentPeers({
+    required String taskId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_peer_info,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadGetTorrentPeersConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  BilibiliLoginStatus dco_decode_bilibili_login_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return BilibiliLoginStatus_WaitingScan();
-      case 1:
-        return BilibiliLoginStatus_WaitingConfirm();
-      case 2:
-        return BilibiliLoginStatus_Success();
-      case 3:
-        return BilibiliLoginStatus_Expired();
-      case 4:
-        return BilibiliLoginStatus_Failed(error: dco_decode_String(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
-  }
+  TaskConstMeta get kCrateApiDownloadGetTorrentPeersConstMeta =>
+      const TaskConstMeta(debugName: "get_torrent_peers", argNames: ["taskId"]);
 
-  @protected
-  BilibiliQrCode dco_decode_bilibili_qr_code(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return BilibiliQrCode(
-      url: dco_decode_String(arr[0]),
-      qrcodeKey: dco_decode_String(arr[1]),
+  @override
+  Future<List<TrackerInfo>> crateApiDownloadGetTorrentTrackers({
+    required String taskId,
+    required bool refresh,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          sse_encode_bool(refresh, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_tracker_info,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadGetTorrentTrackersConstMeta,
+        argValues: [taskId, refresh],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
+  TaskConstMeta get kCrateApiDownloadGetTorrentTrackersConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_torrent_trackers",
+        argNames: ["taskId", "refresh"],
+      );
 
-  @protected
-  double dco_decode_box_autoadd_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  @override
+  Future<VideoInfo> crateApiDownloadGetVideoInfo({required String url}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_video_info,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadGetVideoInfoConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  ProgressEvent dco_decode_box_autoadd_progress_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_progress_event(raw);
-  }
+  TaskConstMeta get kCrateApiDownloadGetVideoInfoConstMeta =>
+      const TaskConstMeta(debugName: "get_video_info", argNames: ["url"]);
 
-  @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_u_64(raw);
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  List<VideoFormat> dco_decode_list_video_format(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_video_format).toList();
-  }
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<void> crateApiDownloadInitDownloadManager({
+    required String downloadDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(downloadDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadInitDownloadManagerConstMeta,
+        argValues: [downloadDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadInitDownloadManagerConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_download_manager",
+        argNames: ["downloadDir"],
+      );
+
+  @override
+  Future<bool> crateApiDownloadIsBilibiliLoggedIn({required String dataDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDownloadIsBilibiliLoggedInConstMeta,
+        argValues: [dataDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadIsBilibiliLoggedInConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_bilibili_logged_in",
+        argNames: ["dataDir"],
+      );
+
+  @override
+  Future<bool> crateApiDownloadIsVideoUrl({required String url}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDownloadIsVideoUrlConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadIsVideoUrlConstMeta =>
+      const TaskConstMeta(debugName: "is_video_url", argNames: ["url"]);
+
+  @override
+  Future<List<SiteCredentialInfo>> crateApiDownloadListSiteCredentials() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_site_credential_info,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadListSiteCredentialsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadListSiteCredentialsConstMeta =>
+      const TaskConstMeta(debugName: "list_site_credentials", argNames: []);
+
+  @override
+  Future<void> crateApiDownloadLogoutBilibili({required String dataDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadLogoutBilibiliConstMeta,
+        argValues: [dataDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadLogoutBilibiliConstMeta =>
+      const TaskConstMeta(debugName: "logout_bilibili", argNames: ["dataDir"]);
+
+  @override
+  Future<void> crateApiDownloadOpenFile({required String taskId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadOpenFileConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadOpenFileConstMeta =>
+      const TaskConstMeta(debugName: "open_file", argNames: ["taskId"]);
+
+  @override
+  Future<void> crateApiDownloadOpenFolder({required String taskId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadOpenFolderConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadOpenFolderConstMeta =>
+      const TaskConstMeta(debugName: "open_folder", argNames: ["taskId"]);
+
+  @override
+  Future<void> crateApiDownloadPauseDownload({required String taskId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadPauseDownloadConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadPauseDownloadConstMeta =>
+      const TaskConstMeta(debugName: "pause_download", argNames: ["taskId"]);
+
+  @override
+  Future<BilibiliLoginStatus> crateApiDownloadPollBilibiliLogin({
+    required String dataDir,
+    required String qrcodeKey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_String(qrcodeKey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bilibili_login_status,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadPollBilibiliLoginConstMeta,
+        argValues: [dataDir, qrcodeKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadPollBilibiliLoginConstMeta =>
+      const TaskConstMeta(
+        debugName: "poll_bilibili_login",
+        argNames: ["dataDir", "qrcodeKey"],
+      );
+
+  @override
+  Future<String?> crateApiDownloadProbeVideoExtractor({required String url}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadProbeVideoExtractorConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadProbeVideoExtractorConstMeta =>
+      const TaskConstMeta(
+        debugName: "probe_video_extractor",
+        argNames: ["url"],
+      );
+
+  @override
+  Future<void> crateApiDownloadRecheckDownload({required String taskId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadRecheckDownloadConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadRecheckDownloadConstMeta =>
+      const TaskConstMeta(debugName: "recheck_download", argNames: ["taskId"]);
+
+  @override
+  Future<BilibiliSessionStatus> crateApiDownloadRefreshBilibiliSession({
+    required String dataDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bilibili_session_status,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadRefreshBilibiliSessionConstMeta,
+        argValues: [dataDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadRefreshBilibiliSessionConstMeta =>
+      const TaskConstMeta(
+        debugName: "refresh_bilibili_session",
+        argNames: ["dataDir"],
+      );
+
+  @override
+  Future<void> crateApiDownloadRemoveDownload({required String taskId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadRemoveDownloadConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadRemoveDownloadConstMeta =>
+      const TaskConstMeta(debugName: "remove_download", argNames: ["taskId"]);
+
+  @override
+  Future<int> crateApiDownloadRemoveSiteCredential({
+    required String domain,
+    String? kind,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(domain, serializer);
+          sse_encode_opt_String(kind, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadRemoveSiteCredentialConstMeta,
+        argValues: [domain, kind],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadRemoveSiteCredentialConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_site_credential",
+        argNames: ["domain", "kind"],
+      );
+
+  @override
+  Future<void> crateApiDownloadRemoveTorrentTrackers({
+    required String taskId,
+    required List<String> urls,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          sse_encode_list_String(urls, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadRemoveTorrentTrackersConstMeta,
+        argValues: [taskId, urls],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadRemoveTorrentTrackersConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_torrent_trackers",
+        argNames: ["taskId", "urls"],
+      );
+
+  @override
+  Future<ResolvedTorrentInfo> crateApiDownloadResolveTorrent({
+    required String source,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(source, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_resolved_torrent_info,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadResolveTorrentConstMeta,
+        argValues: [source],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadResolveTorrentConstMeta =>
+      const TaskConstMeta(debugName: "resolve_torrent", argNames: ["source"]);
+
+  @override
+  Future<void> crateApiDownloadRestartDownload({required String taskId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadRestartDownloadConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadRestartDownloadConstMeta =>
+      const TaskConstMeta(debugName: "restart_download", argNames: ["taskId"]);
+
+  @override
+  Future<void> crateApiDownloadResumeDownload({required String taskId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadResumeDownloadConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadResumeDownloadConstMeta =>
+      const TaskConstMeta(debugName: "resume_download", argNames: ["taskId"]);
+
+  @override
+  Future<void> crateApiDownloadSetSiteCredential({
+    required String domain,
+    required bool includeSubdomains,
+    required SiteCredentialInput credential,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(domain, serializer);
+          sse_encode_bool(includeSubdomains, serializer);
+          sse_encode_box_autoadd_site_credential_input(credential, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadSetSiteCredentialConstMeta,
+        argValues: [domain, includeSubdomains, credential],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadSetSiteCredentialConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_site_credential",
+        argNames: ["domain", "includeSubdomains", "credential"],
+      );
+
+  @override
+  Future<Stream<NebulaEvent>> crateApiDownloadSubscribeEvents() async {
+    final sink = RustStreamSink<NebulaEvent>();
+    await handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_StreamSink_nebula_event_Sse(sink, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadSubscribeEventsConstMeta,
+        argValues: [sink],
+        apiImpl: this,
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiDownloadSubscribeEventsConstMeta =>
+      const TaskConstMeta(debugName: "subscribe_events", argNames: ["sink"]);
+
+  @override
+  Future<void> crateApiDownloadUnlockCredentialVault({
+    required String passphrase,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(passphrase, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadUnlockCredentialVaultConstMeta,
+        argValues: [passphrase],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadUnlockCredentialVaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "unlock_credential_vault",
+        argNames: ["passphrase"],
+      );
+
+  @override
+  Future<IpFilterInfo> crateApiDownloadUpdateIpFilter() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ip_filter_info,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadUpdateIpFilterConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadUpdateIpFilterConstMeta =>
+      const TaskConstMeta(debugName: "update_ip_filter", argNames: []);
+
+  @override
+  Future<ToolStatusInfo> crateApiDownloadUpdateYtDlp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tool_status_info,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDownloadUpdateYtDlpConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadUpdateYtDlpConstMeta =>
+      const TaskConstMeta(debugName: "update_yt_dlp", argNames: []);
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  RustStreamSink<NebulaEvent> dco_decode_StreamSink_nebula_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  BilibiliAccountInfo dco_decode_bilibili_account_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return BilibiliAccountInfo(
+      mid: dco_decode_u_64(arr[0]),
+      username: dco_decode_String(arr[1]),
+      avatar: dco_decode_String(arr[2]),
+      level: dco_decode_u_32(arr[3]),
+      vipActive: dco_decode_bool(arr[4]),
+      vipType: dco_decode_u_32(arr[5]),
+      vipExpiresAt: dco_decode_opt_box_autoadd_i_64(arr[6]),
+      vipLabel: dco_decode_opt_String(arr[7]),
+    );
+  }
+
+  @protected
+  BilibiliLoginStatus dco_decode_bilibili_login_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return BilibiliLoginStatus_WaitingScan();
+      case 1:
+        return BilibiliLoginStatus_WaitingConfirm();
+      case 2:
+        return BilibiliLoginStatus_Success();
+      case 3:
+        return BilibiliLoginStatus_Expired();
+      case 4:
+        return BilibiliLoginStatus_Failed(error: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  BilibiliQrCode dco_decode_bilibili_qr_code(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BilibiliQrCode(
+      url: dco_decode_String(arr[0]),
+      qrcodeKey: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  BilibiliSessionStatus dco_decode_bilibili_session_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BilibiliSessionStatus.values[raw as int];
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  BilibiliAccountInfo dco_decode_box_autoadd_bilibili_account_info(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_bilibili_account_info(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  IpFilterInfo dco_decode_box_autoadd_ip_filter_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_ip_filter_info(raw);
+  }
+
+  @protected
+  ProgressEvent dco_decode_box_autoadd_progress_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_progress_event(raw);
+  }
+
+  @protected
+  SiteCredentialInput dco_decode_box_autoadd_site_credential_input(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_site_credential_input(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  VideoDownloadOptions dco_decode_box_autoadd_video_download_options(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_video_download_options(raw);
+  }
+
+  @protected
+  CreatedTorrentInfo dco_decode_created_torrent_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return CreatedTorrentInfo(
+      name: dco_decode_String(arr[0]),
+      infoHash: dco_decode_String(arr[1]),
+      magnet: dco_decode_String(arr[2]),
+      torrentPath: dco_decode_String(arr[3]),
+      totalSize: dco_decode_u_64(arr[4]),
+      pieceSize: dco_decode_u_64(arr[5]),
+      pieceCount: dco_decode_u_64(arr[6]),
+      fileCount: dco_decode_u_64(arr[7]),
+      seedTaskId: dco_decode_opt_String(arr[8]),
+    );
+  }
+
+  @protected
+  CredentialVaultStatus dco_decode_credential_vault_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CredentialVaultStatus(
+      unlocked: dco_decode_bool(arr[0]),
+      requiresPassphrase: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  DuplicateAction dco_decode_duplicate_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DuplicateAction.values[raw as int];
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  IpFilterInfo dco_decode_ip_filter_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return IpFilterInfo(
+      rangeCount: dco_decode_u_64(arr[0]),
+      blockedHandoffPeers: dco_decode_u_64(arr[1]),
+      lastUpdated: dco_decode_opt_box_autoadd_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<PeerInfo> dco_decode_list_peer_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_peer_info).toList();
+  }
+
+  @protected
+  List<PlaylistEntryInfo> dco_decode_list_playlist_entry_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_playlist_entry_info).toList();
+  }
+
+  @protected
+  Uint64List dco_decode_list_prim_u_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint64List;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<SiteCredentialInfo> dco_decode_list_site_credential_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_site_credential_info).toList();
+  }
+
+  @protected
+  List<ToolStatusInfo> dco_decode_list_tool_status_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_tool_status_info).toList();
+  }
+
+  @protected
+  List<TorrentFileInfo> dco_decode_list_torrent_file_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_torrent_file_info).toList();
+  }
+
+  @protected
+  List<TrackerInfo> dco_decode_list_tracker_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_tracker_info).toList();
+  }
+
+  @protected
+  List<VideoFormat> dco_decode_list_video_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_video_format).toList();
+  }
+
+  @protected
+  NebulaEvent dco_decode_nebula_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return NebulaEvent_TaskAdded(
+          taskId: dco_decode_String(raw[1]),
+          name: dco_decode_String(raw[2]),
+          thumbnail: dco_decode_opt_String(raw[3]),
+        );
+      case 1:
+        return NebulaEvent_TaskStarted(taskId: dco_decode_String(raw[1]));
+      case 2:
+        return NebulaEvent_ProgressUpdated(
+          taskId: dco_decode_String(raw[1]),
+          progress: dco_decode_box_autoadd_progress_event(raw[2]),
+        );
+      case 3:
+        return NebulaEvent_TaskCompleted(taskId: dco_decode_String(raw[1]));
+      case 4:
+        return NebulaEvent_TaskFailed(
+          taskId: dco_decode_String(raw[1]),
+          error: dco_decode_String(raw[2]),
+        );
+      case 5:
+        return NebulaEvent_TaskPaused(taskId: dco_decode_String(raw[1]));
+      case 6:
+        return NebulaEvent_TaskResumed(taskId: dco_decode_String(raw[1]));
+      case 7:
+        return NebulaEvent_TaskRemoved(taskId: dco_decode_String(raw[1]));
+      case 8:
+        return NebulaEvent_MetadataReceived(
+          taskId: dco_decode_String(raw[1]),
+          name: dco_decode_String(raw[2]),
+          totalSize: dco_decode_u_64(raw[3]),
+          fileCount: dco_decode_usize(raw[4]),
+          mimeType: dco_decode_opt_String(raw[5]),
+          supportsResume: dco_decode_bool(raw[6]),
+          thumbnail: dco_decode_opt_String(raw[7]),
+          durationSecs: dco_decode_opt_box_autoadd_u_64(raw[8]),
+        );
+      case 9:
+        return NebulaEvent_PeerUpdate(
+          taskId: dco_decode_String(raw[1]),
+          connectedPeers: dco_decode_usize(raw[2]),
+          totalPeers: dco_decode_usize(raw[3]),
+        );
+      case 10:
+        return NebulaEvent_CheckProgress(
+          taskId: dco_decode_String(raw[1]),
+          checkedBytes: dco_decode_u_64(raw[2]),
+          totalBytes: dco_decode_u_64(raw[3]),
+        );
+      case 11:
+        return NebulaEvent_CheckCompleted(
+          taskId: dco_decode_String(raw[1]),
+          validBytes: dco_decode_u_64(raw[2]),
+          totalBytes: dco_decode_u_64(raw[3]),
+        );
+      case 12:
+        return NebulaEvent_TaskStalled(
+          taskId: dco_decode_String(raw[1]),
+          phase: dco_decode_String(raw[2]),
+          stalledSecs: dco_decode_u_64(raw[3]),
+          dhtNodes: dco_decode_opt_box_autoadd_u_64(raw[4]),
+          trackersTotal: dco_decode_u_64(raw[5]),
+          trackersReached: dco_decode_u_64(raw[6]),
+          peersSeen: dco_decode_u_64(raw[7]),
+          peersConnected: dco_decode_u_64(raw[8]),
+        );
+      case 13:
+        return NebulaEvent_StallRecovered(
+          taskId: dco_decode_String(raw[1]),
+          phase: dco_decode_String(raw[2]),
+        );
+      case 14:
+        return NebulaEvent_VideoStageChanged(
+          taskId: dco_decode_String(raw[1]),
+          stage: dco_decode_String(raw[2]),
+        );
+      case 15:
+        return NebulaEvent_OutputFileResolved(
+          taskId: dco_decode_String(raw[1]),
+          path: dco_decode_String(raw[2]),
+        );
+      case 16:
+        return NebulaEvent_StatusChanged(
+          taskId: dco_decode_String(raw[1]),
+          from: dco_decode_String(raw[2]),
+          to: dco_decode_String(raw[3]),
+        );
+      case 17:
+        return NebulaEvent_BilibiliLoginExpired(
+          reason: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  BilibiliAccountInfo? dco_decode_opt_box_autoadd_bilibili_account_info(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bilibili_account_info(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  IpFilterInfo? dco_decode_opt_box_autoadd_ip_filter_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_ip_filter_info(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  VideoDownloadOptions? dco_decode_opt_box_autoadd_video_download_options(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_video_download_options(raw);
+  }
+
+  @protected
+  PeerInfo dco_decode_peer_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return PeerInfo(
+      address: dco_decode_String(arr[0]),
+      client: dco_decode_opt_String(arr[1]),
+      flags: dco_decode_String(arr[2]),
+      downloadSpeed: dco_decode_u_64(arr[3]),
+      uploadSpeed: dco_decode_u_64(arr[4]),
+      downloaded: dco_decode_u_64(arr[5]),
+      uploaded: dco_decode_u_64(arr[6]),
+      progress: dco_decode_opt_box_autoadd_f_64(arr[7]),
+    );
+  }
+
+  @protected
+  PlaylistEntryInfo dco_decode_playlist_entry_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PlaylistEntryInfo(
+      index: dco_decode_u_32(arr[0]),
+      id: dco_decode_String(arr[1]),
+      title: dco_decode_String(arr[2]),
+      url: dco_decode_String(arr[3]),
+      duration: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      uploader: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  PlaylistInfo dco_decode_playlist_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PlaylistInfo(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      uploader: dco_decode_opt_String(arr[2]),
+      entries: dco_decode_list_playlist_entry_info(arr[3]),
+    );
+  }
+
+  @protected
+  ProgressEvent dco_decode_progress_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ProgressEvent(
+      taskId: dco_decode_String(arr[0]),
+      totalSize: dco_decode_u_64(arr[1]),
+      downloadedSize: dco_decode_u_64(arr[2]),
+      downloadSpeed: dco_decode_u_64(arr[3]),
+      percentage: dco_decode_f_64(arr[4]),
+      etaSecs: dco_decode_opt_box_autoadd_u_64(arr[5]),
+    );
+  }
+
+  @protected
+  ResolvedTorrentInfo dco_decode_resolved_torrent_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ResolvedTorrentInfo(
+      name: dco_decode_String(arr[0]),
+      infoHash: dco_decode_String(arr[1]),
+      totalSize: dco_decode_u_64(arr[2]),
+      pieceSize: dco_decode_u_64(arr[3]),
+      private: dco_decode_bool(arr[4]),
+      files: dco_decode_list_torrent_file_info(arr[5]),
+    );
+  }
+
+  @protected
+  SiteCredentialInfo dco_decode_site_credential_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return SiteCredentialInfo(
+      domain: dco_decode_String(arr[0]),
+      includeSubdomains: dco_decode_bool(arr[1]),
+      kind: dco_decode_String(arr[2]),
+      username: dco_decode_opt_String(arr[3]),
+      headerName: dco_decode_opt_String(arr[4]),
+      updatedAt: dco_decode_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  SiteCredentialInput dco_decode_site_credential_input(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SiteCredentialInput_Cookie(cookies: dco_decode_String(raw[1]));
+      case 1:
+        return SiteCredentialInput_Bearer(token: dco_decode_String(raw[1]));
+      case 2:
+        return SiteCredentialInput_Basic(
+          username: dco_decode_String(raw[1]),
+          password: dco_decode_String(raw[2]),
+        );
+      case 3:
+        return SiteCredentialInput_Header(
+          name: dco_decode_String(raw[1]),
+          value: dco_decode_String(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ToolStatusInfo dco_decode_tool_status_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ToolStatusInfo(
+      name: dco_decode_String(arr[0]),
+      path: dco_decode_String(arr[1]),
+      version: dco_decode_opt_String(arr[2]),
+      source: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  ToolUpdateInfo dco_decode_tool_update_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ToolUpdateInfo(
+      current: dco_decode_opt_String(arr[0]),
+      latest: dco_decode_String(arr[1]),
+      updateAvailable: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  TorrentFileInfo dco_decode_torrent_file_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TorrentFileInfo(
+      index: dco_decode_u_64(arr[0]),
+      path: dco_decode_String(arr[1]),
+      size: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  TrackerInfo dco_decode_tracker_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return TrackerInfo(
+      url: dco_decode_String(arr[0]),
+      source: dco_decode_String(arr[1]),
+      status: dco_decode_tracker_status_info(arr[2]),
+      seeders: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      leechers: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      completed: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      lastUpdated: dco_decode_opt_box_autoadd_i_64(arr[6]),
+    );
+  }
+
+  @protected
+  TrackerStatusInfo dco_decode_tracker_status_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return TrackerStatusInfo_NotContacted();
+      case 1:
+        return TrackerStatusInfo_Updating();
+      case 2:
+        return TrackerStatusInfo_Working();
+      case 3:
+        return TrackerStatusInfo_Error(message: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  VideoDownloadOptions dco_decode_video_download_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 18)
+      throw Exception('unexpected arr length: expect 18 but see ${arr.length}');
+    return VideoDownloadOptions(
+      subtitleLangs: dco_decode_list_String(arr[0]),
+      autoSubtitles: dco_decode_bool(arr[1]),
+      danmaku: dco_decode_bool(arr[2]),
+      writeThumbnail: dco_decode_bool(arr[3]),
+      writeInfoJson: dco_decode_bool(arr[4]),
+      embedMetadata: dco_decode_bool(arr[5]),
+      embedThumbnail: dco_decode_bool(arr[6]),
+      embedChapters: dco_decode_bool(arr[7]),
+      audioFormat: dco_decode_opt_String(arr[8]),
+      audioBitrateKbps: dco_decode_opt_box_autoadd_u_32(arr[9]),
+      normalizeAudio: dco_decode_bool(arr[10]),
+      maxHeight: dco_decode_opt_box_autoadd_u_32(arr[11]),
+      videoCodec: dco_decode_opt_String(arr[12]),
+      container: dco_decode_opt_String(arr[13]),
+      preferHdr: dco_decode_bool(arr[14]),
+      maxFps: dco_decode_opt_box_autoadd_u_32(arr[15]),
+      audioLanguage: dco_decode_opt_String(arr[16]),
+      maxFilesizeMb: dco_decode_opt_box_autoadd_u_64(arr[17]),
+    );
+  }
+
+  @protected
+  VideoFormat dco_decode_video_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    return VideoFormat(
+      formatId: dco_decode_String(arr[0]),
+      ext: dco_decode_String(arr[1]),
+      resolution: dco_decode_opt_String(arr[2]),
+      filesize: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      formatNote: dco_decode_opt_String(arr[4]),
+      fps: dco_decode_opt_box_autoadd_f_64(arr[5]),
+      vcodec: dco_decode_opt_String(arr[6]),
+      acodec: dco_decode_opt_String(arr[7]),
+      tbr: dco_decode_opt_box_autoadd_f_64(arr[8]),
+      dynamicRange: dco_decode_opt_String(arr[9]),
+      language: dco_decode_opt_String(arr[10]),
+      audioOnly: dco_decode_bool(arr[11]),
+      videoOnly: dco_decode_bool(arr[12]),
+    );
+  }
+
+  @protected
+  VideoInfo dco_decode_video_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return VideoInfo(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      thumbnail: dco_decode_opt_String(arr[2]),
+      duration: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      uploader: dco_decode_opt_String(arr[4]),
+      formats: dco_decode_list_video_format(arr[5]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  RustStreamSink<NebulaEvent> sse_decode_StreamSink_nebula_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  BilibiliAccountInfo sse_decode_bilibili_account_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mid = sse_decode_u_64(deserializer);
+    var var_username = sse_decode_String(deserializer);
+    var var_avatar = sse_decode_String(deserializer);
+    var var_level = sse_decode_u_32(deserializer);
+    var var_vipActive = sse_decode_bool(deserializer);
+    var var_vipType = sse_decode_u_32(deserializer);
+    var var_vipExpiresAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_vipLabel = sse_decode_opt_String(deserializer);
+    return BilibiliAccountInfo(
+      mid: var_mid,
+      username: var_username,
+      avatar: var_avatar,
+      level: var_level,
+      vipActive: var_vipActive,
+      vipType: var_vipType,
+      vipExpiresAt: var_vipExpiresAt,
+      vipLabel: var_vipLabel,
+    );
+  }
+
+  @protected
+  BilibiliLoginStatus sse_decode_bilibili_login_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return BilibiliLoginStatus_WaitingScan();
+      case 1:
+        return BilibiliLoginStatus_WaitingConfirm();
+      case 2:
+        return BilibiliLoginStatus_Success();
+      case 3:
+        return BilibiliLoginStatus_Expired();
+      case 4:
+        var var_error = sse_decode_String(deserializer);
+        return BilibiliLoginStatus_Failed(error: var_error);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  BilibiliQrCode sse_decode_bilibili_qr_code(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_qrcodeKey = sse_decode_String(deserializer);
+    return BilibiliQrCode(url: var_url, qrcodeKey: var_qrcodeKey);
+  }
+
+  @protected
+  BilibiliSessionStatus sse_decode_bilibili_session_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BilibiliSessionStatus.values[inner];
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  BilibiliAccountInfo sse_decode_box_autoadd_bilibili_account_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bilibili_account_info(deserializer));
+  }
 
   @protected
-  NebulaEvent dco_decode_nebula_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  IpFilterInfo sse_decode_box_autoadd_ip_filter_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_ip_filter_info(deserializer));
+  }
+
+  @protected
+  ProgressEvent sse_decode_box_autoadd_progress_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_progress_event(deserializer));
+  }
+
+  @protected
+  SiteCredentialInput sse_decode_box_autoadd_site_credential_input(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_site_credential_input(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  VideoDownloadOptions sse_decode_box_autoadd_video_download_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_video_download_options(deserializer));
+  }
+
+  @protected
+  CreatedTorrentInfo sse_decode_created_torrent_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_infoHash = sse_decode_String(deserializer);
+    var var_magnet = sse_decode_String(deserializer);
+    var var_torrentPath = sse_decode_String(deserializer);
+    var var_totalSize = sse_decode_u_64(deserializer);
+    var var_pieceSize = sse_decode_u_64(deserializer);
+    var var_pieceCount = sse_decode_u_64(deserializer);
+    var var_fileCount = sse_decode_u_64(deserializer);
+    var var_seedTaskId = sse_decode_opt_String(deserializer);
+    return CreatedTorrentInfo(
+      name: var_name,
+      infoHash: var_infoHash,
+      magnet: var_magnet,
+      torrentPath: var_torrentPath,
+      totalSize: var_totalSize,
+      pieceSize: var_pieceSize,
+      pieceCount: var_pieceCount,
+      fileCount: var_fileCount,
+      seedTaskId: var_seedTaskId,
+    );
+  }
+
+  @protected
+  CredentialVaultStatus sse_decode_credential_vault_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_unlocked = sse_decode_bool(deserializer);
+    var var_requiresPassphrase = sse_decode_bool(deserializer);
+    return CredentialVaultStatus(
+      unlocked: var_unlocked,
+      requiresPassphrase: var_requiresPassphrase,
+    );
+  }
+
+  @protected
+  DuplicateAction sse_decode_duplicate_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DuplicateAction.values[inner];
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  IpFilterInfo sse_decode_ip_filter_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rangeCount = sse_decode_u_64(deserializer);
+    var var_blockedHandoffPeers = sse_decode_u_64(deserializer);
+    var var_lastUpdated = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return IpFilterInfo(
+      rangeCount: var_rangeCount,
+      blockedHandoffPeers: var_blockedHandoffPeers,
+      lastUpdated: var_lastUpdated,
+    );
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PeerInfo> sse_decode_list_peer_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PeerInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_peer_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PlaylistEntryInfo> sse_decode_list_playlist_entry_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PlaylistEntryInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_playlist_entry_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint64List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<SiteCredentialInfo> sse_decode_list_site_credential_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SiteCredentialInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_site_credential_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ToolStatusInfo> sse_decode_list_tool_status_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ToolStatusInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_tool_status_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TorrentFileInfo> sse_decode_list_torrent_file_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TorrentFileInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_torrent_file_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TrackerInfo> sse_decode_list_tracker_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TrackerInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_tracker_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<VideoFormat> sse_decode_list_video_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <VideoFormat>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_video_format(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  NebulaEvent sse_decode_nebula_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
       case 0:
+        var var_taskId = sse_decode_String(deserializer);
+        var var_name = sse_decode_String(deserializer);
+        var var_thumbnail = sse_decode_opt_String(deserializer);
         return NebulaEvent_TaskAdded(
-          taskId: dco_decode_String(raw[1]),
-          name: dco_decode_String(raw[2]),
-          thumbnail: dco_decode_opt_String(raw[3]),
+          taskId: var_taskId,
+          name: var_name,
+          thumbnail: var_thumbnail,
         );
       case 1:
-        return NebulaEvent_TaskStarted(taskId: dco_decode_String(raw[1]));
+        var var_taskId = sse_decode_String(deserializer);
+        return NebulaEvent_TaskStarted(taskId: var_taskId);
       case 2:
+        var var_taskId = sse_decode_String(deserializer);
+        var var_progress = sse_decode_box_autoadd_progress_event(deserializer);
         return NebulaEvent_ProgressUpdated(
-          taskId: dco_decode_String(raw[1]),
-          progress: dco_decode_box_autoadd_progress_event(raw[2]),
+          taskId: var_taskId,
+          progress: var_progress,
         );
       case 3:
-        return NebulaEvent_TaskCompleted(taskId: dco_decode_String(raw[1]));
+        var var_taskId = sse_decode_String(deserializer);
+        return NebulaEvent_TaskCompleted(taskId: var_taskId);
       case 4:
-        return NebulaEvent_TaskFailed(
-          taskId: dco_decode_String(raw[1]),
-          error: dco_decode_String(raw[2]),
-        );
+        var var_taskId = sse_decode_String(deserializer);
+        var var_error = sse_decode_String(deserializer);
+        return NebulaEvent_TaskFailed(taskId: var_taskId, error: var_error);
       case 5:
-        return NebulaEvent_TaskPaused(taskId: dco_decode_String(raw[1]));
+        var var_taskId = sse_decode_String(deserializer);
+        return NebulaEvent_TaskPaused(taskId: var_taskId);
       case 6:
-        return NebulaEvent_TaskResumed(taskId: dco_decode_String(raw[1]));
+        var var_taskId = sse_decode_String(deserializer);
+        return NebulaEvent_TaskResumed(taskId: var_taskId);
       case 7:
-        return NebulaEvent_TaskRemoved(taskId: dco_decode_String(raw[1]));
+        var var_taskId = sse_decode_String(deserializer);
+        return NebulaEvent_TaskRemoved(taskId: var_taskId);
       case 8:
+        var var_taskId = sse_decode_String(deserializer);
+        var var_name = sse_decode_String(deserializer);
+        var var_totalSize = sse_decode_u_64(deserializer);
+        var var_fileCount = sse_decode_usize(deserializer);
+        var var_mimeType = sse_decode_opt_String(deserializer);
+        var var_supportsResume = sse_decode_bool(deserializer);
+        var var_thumbnail = sse_decode_opt_String(deserializer);
+        var var_durationSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
         return NebulaEvent_MetadataReceived(
-          taskId: dco_decode_String(raw[1]),
-          name: dco_decode_String(raw[2]),
-          totalSize: dco_decode_u_64(raw[3]),
-          fileCount: dco_decode_usize(raw[4]),
+          taskId: var_taskId,
+          name: var_name,
+          totalSize: var_totalSize,
+          fileCount: var_fileCount,
+          mimeType: var_mimeType,
+          supportsResume: var_supportsResume,
+          thumbnail: var_thumbnail,
+          durationSecs: var_durationSecs,
+        );
+      case 9:
+        var var_taskId = sse_decode_String(deserializer);
+        var var_connectedPeers = sse_decode_usize(deserializer);
+        var var_totalPeers = sse_decode_usize(deserializer);
+        return NebulaEvent_PeerUpdate(
+          taskId: var_taskId,
+          connectedPeers: var_connectedPeers,
+          totalPeers: var_totalPeers,
+        );
+      case 10:
+        var var_taskId = sse_decode_String(deserializer);
+        var var_checkedBytes = sse_decode_u_64(deserializer);
+        var var_totalBytes = sse_decode_u_64(deserializer);
+        return NebulaEvent_CheckProgress(
+          taskId: var_taskId,
+          checkedBytes: var_checkedBytes,
+          totalBytes: var_totalBytes,
+        );
+      case 11:
+        var var_taskId = sse_decode_String(deserializer);
+        var var_validBytes = sse_decode_u_64(deserializer);
+        var var_totalBytes = sse_decode_u_64(deserializer);
+        return NebulaEvent_CheckCompleted(
+          taskId: var_taskId,
+          validBytes: var_validBytes,
+          totalBytes: var_totalBytes,
+        );
+      case 12:
+        var var_taskId = sse_decode_String(deserializer);
+        var var_phase = sse_decode_String(deserializer);
+        var var_stalledSecs = sse_decode_u_64(deserializer);
+        var var_dhtNodes = sse_decode_opt_box_autoadd_u_64(deserializer);
+        var var_trackersTotal = sse_decode_u_64(deserializer);
+        var var_trackersReached = sse_decode_u_64(deserializer);
+        var var_peersSeen = sse_decode_u_64(deserializer);
+        var var_peersConnected = sse_decode_u_64(deserializer);
+        return NebulaEvent_TaskStalled(
+          taskId: var_taskId,
+          phase: var_phase,
+          stalledSecs: var_stalledSecs,
+          dhtNodes: var_dhtNodes,
+          trackersTotal: var_trackersTotal,
+          trackersReached: var_trackersReached,
+          peersSeen: var_peersSeen,
+          peersConnected: var_peersConnected,
         );
-      case 9:
-        return NebulaEvent_PeerUpdate(
-          taskId: dco_decode_String(raw[1]),
-          connectedPeers: dco_decode_usize(raw[2]),
-          totalPeers: dco_decode_usize(raw[3]),
+      case 13:
+        var var_taskId = sse_decode_String(deserializer);
+        var var_phase = sse_decode_String(deserializer);
+        return NebulaEvent_StallRecovered(taskId: var_taskId, phase: var_phase);
+      case 14:
+        var var_taskId = sse_decode_String(deserializer);
+        var var_stage = sse_decode_String(deserializer);
+        return NebulaEvent_VideoStageChanged(
+          taskId: var_taskId,
+          stage: var_stage,
+        );
+      case 15:
+        var var_taskId = sse_decode_String(deserializer);
+        var var_path = sse_decode_String(deserializer);
+        return NebulaEvent_OutputFileResolved(
+          taskId: var_taskId,
+          path: var_path,
+        );
+      case 16:
+        var var_taskId = sse_decode_String(deserializer);
+        var var_from = sse_decode_String(deserializer);
+        var var_to = sse_decode_String(deserializer);
+        return NebulaEvent_StatusChanged(
+          taskId: var_taskId,
+          from: var_from,
+          to: var_to,
         );
+      case 17:
+        var var_reason = sse_decode_String(deserializer);
+        return NebulaEvent_BilibiliLoginExpired(reason: var_reason);
       default:
-        throw Exception("unreachable");
+        throw UnimplementedError('');
     }
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
-  }
-
-  @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
-  }
-
-  @protected
-  ProgressEvent dco_decode_progress_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return ProgressEvent(
-      taskId: dco_decode_String(arr[0]),
-      totalSize: dco_decode_u_64(arr[1]),
-      downloadedSize: dco_decode_u_64(arr[2]),
-      downloadSpeed: dco_decode_u_64(arr[3]),
-      percentage: dco_decode_f_64(arr[4]),
-      etaSecs: dco_decode_opt_box_autoadd_u_64(arr[5]),
-    );
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
 
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
+  BilibiliAccountInfo? sse_decode_opt_box_autoadd_bilibili_account_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bilibili_account_info(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  VideoFormat dco_decode_video_format(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return VideoFormat(
-      formatId: dco_decode_String(arr[0]),
-      ext: dco_decode_String(arr[1]),
-      resolution: dco_decode_opt_String(arr[2]),
-      filesize: dco_decode_opt_box_autoadd_u_64(arr[3]),
-      formatNote: dco_decode_opt_String(arr[4]),
-      fps: dco_decode_opt_box_autoadd_f_64(arr[5]),
-      vcodec: dco_decode_opt_String(arr[6]),
-      acodec: dco_decode_opt_String(arr[7]),
-    );
-  }
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
 
-  @protected
-  VideoInfo dco_decode_video_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return VideoInfo(
-      id: dco_decode_String(arr[0]),
-      title: dco_decode_String(arr[1]),
-      thumbnail: dco_decode_opt_String(arr[2]),
-      duration: dco_decode_opt_box_autoadd_u_64(arr[3]),
-      uploader: dco_decode_opt_String(arr[4]),
-      formats: dco_decode_list_video_format(arr[5]),
-    );
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  RustStreamSink<NebulaEvent> sse_decode_StreamSink_nebula_event_Sse(
+  IpFilterInfo? sse_decode_opt_box_autoadd_ip_filter_info(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_ip_filter_info(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  BilibiliLoginStatus sse_decode_bilibili_login_status(
-    SseDeserializer deserializer,
-  ) {
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        return BilibiliLoginStatus_WaitingScan();
-      case 1:
-        return BilibiliLoginStatus_WaitingConfirm();
-      case 2:
-        return BilibiliLoginStatus_Success();
-      case 3:
-        return BilibiliLoginStatus_Expired();
-      case 4:
-        var var_error = sse_decode_String(deserializer);
-        return BilibiliLoginStatus_Failed(error: var_error);
-      default:
-        throw UnimplementedError('');
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
     }
   }
 
   @protected
-  BilibiliQrCode sse_decode_bilibili_qr_code(SseDeserializer deserializer) {
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_url = sse_decode_String(deserializer);
-    var var_qrcodeKey = sse_decode_String(deserializer);
-    return BilibiliQrCode(url: var_url, qrcodeKey: var_qrcodeKey);
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  VideoDownloadOptions? sse_decode_opt_box_autoadd_video_download_options(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_video_download_options(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+  PeerInfo sse_decode_peer_info(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_f_64(deserializer));
+    var var_address = sse_decode_String(deserializer);
+    var var_client = sse_decode_opt_String(deserializer);
+    var var_flags = sse_decode_String(deserializer);
+    var var_downloadSpeed = sse_decode_u_64(deserializer);
+    var var_uploadSpeed = sse_decode_u_64(deserializer);
+    var var_downloaded = sse_decode_u_64(deserializer);
+    var var_uploaded = sse_decode_u_64(deserializer);
+    var var_progress = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return PeerInfo(
+      address: var_address,
+      client: var_client,
+      flags: var_flags,
+      downloadSpeed: var_downloadSpeed,
+      uploadSpeed: var_uploadSpeed,
+      downloaded: var_downloaded,
+      uploaded: var_uploaded,
+      progress: var_progress,
+    );
   }
 
   @protected
-  ProgressEvent sse_decode_box_autoadd_progress_event(
+  PlaylistEntryInfo sse_decode_playlist_entry_info(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_progress_event(deserializer));
+    var var_index = sse_decode_u_32(deserializer);
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    var var_duration = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_uploader = sse_decode_opt_String(deserializer);
+    return PlaylistEntryInfo(
+      index: var_index,
+      id: var_id,
+      title: var_title,
+      url: var_url,
+      duration: var_duration,
+      uploader: var_uploader,
+    );
   }
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+  PlaylistInfo sse_decode_playlist_info(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_64(deserializer));
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_uploader = sse_decode_opt_String(deserializer);
+    var var_entries = sse_decode_list_playlist_entry_info(deserializer);
+    return PlaylistInfo(
+      id: var_id,
+      title: var_title,
+      uploader: var_uploader,
+      entries: var_entries,
+    );
   }
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
+  ProgressEvent sse_decode_progress_event(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
+    var var_taskId = sse_decode_String(deserializer);
+    var var_totalSize = sse_decode_u_64(deserializer);
+    var var_downloadedSize = sse_decode_u_64(deserializer);
+    var var_downloadSpeed = sse_decode_u_64(deserializer);
+    var var_percentage = sse_decode_f_64(deserializer);
+    var var_etaSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return ProgressEvent(
+      taskId: var_taskId,
+      totalSize: var_totalSize,
+      downloadedSize: var_downloadedSize,
+      downloadSpeed: var_downloadSpeed,
+      percentage: var_percentage,
+      etaSecs: var_etaSecs,
+    );
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  ResolvedTorrentInfo sse_decode_resolved_torrent_info(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    var var_name = sse_decode_String(deserializer);
+    var var_infoHash = sse_decode_String(deserializer);
+    var var_totalSize = sse_decode_u_64(deserializer);
+    var var_pieceSize = sse_decode_u_64(deserializer);
+    var var_private = sse_decode_bool(deserializer);
+    var var_files = sse_decode_list_torrent_file_info(deserializer);
+    return ResolvedTorrentInfo(
+      name: var_name,
+      infoHash: var_infoHash,
+      totalSize: var_totalSize,
+      pieceSize: var_pieceSize,
+      private: var_private,
+      files: var_files,
+    );
   }
 
   @protected
-  List<VideoFormat> sse_decode_list_video_format(SseDeserializer deserializer) {
+  SiteCredentialInfo sse_decode_site_credential_info(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <VideoFormat>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_video_format(deserializer));
-    }
-    return ans_;
+    var var_domain = sse_decode_String(deserializer);
+    var var_includeSubdomains = sse_decode_bool(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_username = sse_decode_opt_String(deserializer);
+    var var_headerName = sse_decode_opt_String(deserializer);
+    var var_updatedAt = sse_decode_i_64(deserializer);
+    return SiteCredentialInfo(
+      domain: var_domain,
+      includeSubdomains: var_includeSubdomains,
+      kind: var_kind,
+      username: var_username,
+      headerName: var_headerName,
+      updatedAt: var_updatedAt,
+    );
   }
 
   @protected
-  NebulaEvent sse_decode_nebula_event(SseDeserializer deserializer) {
+  SiteCredentialInput sse_decode_site_credential_input(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var tag_ = sse_decode_i_32(deserializer);
     switch (tag_) {
       case 0:
-        var var_taskId = sse_decode_String(deserializer);
-        var var_name = sse_decode_String(deserializer);
-        var var_thumbnail = sse_decode_opt_String(deserializer);
-        return NebulaEvent_TaskAdded(
-          taskId: var_taskId,
-          name: var_name,
-          thumbnail: var_thumbnail,
-        );
-      case 1:
-        var var_taskId = sse_decode_String(deserializer);
-        return NebulaEvent_TaskStarted(taskId: var_taskId);
+        var var_cookies = sse_decode_String(deserializer);
+        return SiteCredentialInput_Cookie(cookies: var_cookies);
+      case 1:
+        var var_token = sse_decode_String(deserializer);
+        return SiteCredentialInput_Bearer(token: var_token);
       case 2:
-        var var_taskId = sse_decode_String(deserializer);
-        var var_progress = sse_decode_box_autoadd_progress_event(deserializer);
-        return NebulaEvent_ProgressUpdated(
-          taskId: var_taskId,
-          progress: var_progress,
+        var var_username = sse_decode_String(deserializer);
+        var var_password = sse_decode_String(deserializer);
+        return SiteCredentialInput_Basic(
+          username: var_username,
+          password: var_password,
         );
       case 3:
-        var var_taskId = sse_decode_String(deserializer);
-        return NebulaEvent_TaskCompleted(taskId: var_taskId);
-      case 4:
-        var var_taskId = sse_decode_String(deserializer);
-        var var_error = sse_decode_String(deserializer);
-        return NebulaEvent_TaskFailed(taskId: var_taskId, error: var_error);
-      case 5:
-        var var_taskId = sse_decode_String(deserializer);
-        return NebulaEvent_TaskPaused(taskId: var_taskId);
-      case 6:
-        var var_taskId = sse_decode_String(deserializer);
-        return NebulaEvent_TaskResumed(taskId: var_taskId);
-      case 7:
-        var var_taskId = sse_decode_String(deserializer);
-        return NebulaEvent_TaskRemoved(taskId: var_taskId);
-      case 8:
-        var var_taskId = sse_decode_String(deserializer);
         var var_name = sse_decode_String(deserializer);
-        var var_totalSize = sse_decode_u_64(deserializer);
-        var var_fileCount = sse_decode_usize(deserializer);
-        return NebulaEvent_MetadataReceived(
-          taskId: var_taskId,
-          name: var_name,
-          totalSize: var_totalSize,
-          fileCount: var_fileCount,
-        );
-      case 9:
-        var var_taskId = sse_decode_String(deserializer);
-        var var_connectedPeers = sse_decode_usize(deserializer);
-        var var_totalPeers = sse_decode_usize(deserializer);
-        return NebulaEvent_PeerUpdate(
-          taskId: var_taskId,
-          connectedPeers: var_connectedPeers,
-          totalPeers: var_totalPeers,
-        );
+        var var_value = sse_decode_String(deserializer);
+        return SiteCredentialInput_Header(name: var_name, value: var_value);
       default:
         throw UnimplementedError('');
     }
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  ToolStatusInfo sse_decode_tool_status_info(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_version = sse_decode_opt_String(deserializer);
+    var var_source = sse_decode_String(deserializer);
+    return ToolStatusInfo(
+      name: var_name,
+      path: var_path,
+      version: var_version,
+      source: var_source,
+    );
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  ToolUpdateInfo sse_decode_tool_update_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_current = sse_decode_opt_String(deserializer);
+    var var_latest = sse_decode_String(deserializer);
+    var var_updateAvailable = sse_decode_bool(deserializer);
+    return ToolUpdateInfo(
+      current: var_current,
+      latest: var_latest,
+      updateAvailable: var_updateAvailable,
+    );
   }
 
   @protected
-  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+  TorrentFileInfo sse_decode_torrent_file_info(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_64(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    return TorrentFileInfo(index: var_index, path: var_path, size: var_size);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_64(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  TrackerInfo sse_decode_tracker_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_source = sse_decode_String(deserializer);
+    var var_status = sse_decode_tracker_status_info(deserializer);
+    var var_seeders = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_leechers = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_completed = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_lastUpdated = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return TrackerInfo(
+      url: var_url,
+      source: var_source,
+      status: var_status,
+      seeders: var_seeders,
+      leechers: var_leechers,
+      completed: var_completed,
+      lastUpdated: var_lastUpdated,
+    );
   }
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+  TrackerStatusInfo sse_decode_tracker_status_info(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return TrackerStatusInfo_NotContacted();
+      case 1:
+        return TrackerStatusInfo_Updating();
+      case 2:
+        return TrackerStatusInfo_Working();
+      case 3:
+        var var_message = sse_decode_String(deserializer);
+        return TrackerStatusInfo_Error(message: var_message);
+      default:
+        throw UnimplementedError('');
     }
   }
 
   @protected
-  ProgressEvent sse_decode_progress_event(SseDeserializer deserializer) {
+  int sse_decode_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_taskId = sse_decode_String(deserializer);
-    var var_totalSize = sse_decode_u_64(deserializer);
-    var var_downloadedSize = sse_decode_u_64(deserializer);
-    var var_downloadSpeed = sse_decode_u_64(deserializer);
-    var var_percentage = sse_decode_f_64(deserializer);
-    var var_etaSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
-    return ProgressEvent(
-      taskId: var_taskId,
-      totalSize: var_totalSize,
-      downloadedSize: var_downloadedSize,
-      downloadSpeed: var_downloadSpeed,
-      percentage: var_percentage,
-      etaSecs: var_etaSecs,
-    );
+    return deserializer.buffer.getUint32();
   }
 
   @protected
@@ -1138,6 +3304,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getBigUint64();
   }
 
+  @protected
+  VideoDownloadOptions sse_decode_video_download_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_subtitleLangs = sse_decode_list_String(deserializer);
+    var var_autoSubtitles = sse_decode_bool(deserializer);
+    var var_danmaku = sse_decode_bool(deserializer);
+    var var_writeThumbnail = sse_decode_bool(deserializer);
+    var var_writeInfoJson = sse_decode_bool(deserializer);
+    var var_embedMetadata = sse_decode_bool(deserializer);
+    var var_embedThumbnail = sse_decode_bool(deserializer);
+    var var_embedChapters = sse_decode_bool(deserializer);
+    var var_audioFormat = sse_decode_opt_String(deserializer);
+    var var_audioBitrateKbps = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_normalizeAudio = sse_decode_bool(deserializer);
+    var var_maxHeight = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_videoCodec = sse_decode_opt_String(deserializer);
+    var var_container = sse_decode_opt_String(deserializer);
+    var var_preferHdr = sse_decode_bool(deserializer);
+    var var_maxFps = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_audioLanguage = sse_decode_opt_String(deserializer);
+    var var_maxFilesizeMb = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return VideoDownloadOptions(
+      subtitleLangs: var_subtitleLangs,
+      autoSubtitles: var_autoSubtitles,
+      danmaku: var_danmaku,
+      writeThumbnail: var_writeThumbnail,
+      writeInfoJson: var_writeInfoJson,
+      embedMetadata: var_embedMetadata,
+      embedThumbnail: var_embedThumbnail,
+      embedChapters: var_embedChapters,
+      audioFormat: var_audioFormat,
+      audioBitrateKbps: var_audioBitrateKbps,
+      normalizeAudio: var_normalizeAudio,
+      maxHeight: var_maxHeight,
+      videoCodec: var_videoCodec,
+      container: var_container,
+      preferHdr: var_preferHdr,
+      maxFps: var_maxFps,
+      audioLanguage: var_audioLanguage,
+      maxFilesizeMb: var_maxFilesizeMb,
+    );
+  }
+
   @protected
   VideoFormat sse_decode_video_format(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1149,6 +3360,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_fps = sse_decode_opt_box_autoadd_f_64(deserializer);
     var var_vcodec = sse_decode_opt_String(deserializer);
     var var_acodec = sse_decode_opt_String(deserializer);
+    var var_tbr = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_dynamicRange = sse_decode_opt_String(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    var var_audioOnly = sse_decode_bool(deserializer);
+    var var_videoOnly = sse_decode_bool(deserializer);
     return VideoFormat(
       formatId: var_formatId,
       ext: var_ext,
@@ -1158,6 +3374,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       fps: var_fps,
       vcodec: var_vcodec,
       acodec: var_acodec,
+      tbr: var_tbr,
+      dynamicRange: var_dynamicRange,
+      language: var_language,
+      audioOnly: var_audioOnly,
+      videoOnly: var_videoOnly,
     );
   }
 
@@ -1181,115 +3402,332 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_nebula_event_Sse(
+    RustStreamSink<NebulaEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_nebula_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_bilibili_account_info(
+    BilibiliAccountInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.mid, serializer);
+    sse_encode_String(self.username, serializer);
+    sse_encode_String(self.avatar, serializer);
+    sse_encode_u_32(self.level, serializer);
+    sse_encode_bool(self.vipActive, serializer);
+    sse_encode_u_32(self.vipType, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.vipExpiresAt, serializer);
+    sse_encode_opt_String(self.vipLabel, serializer);
+  }
+
+  @protected
+  void sse_encode_bilibili_login_status(
+    BilibiliLoginStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case BilibiliLoginStatus_WaitingScan():
+        sse_encode_i_32(0, serializer);
+      case BilibiliLoginStatus_WaitingConfirm():
+        sse_encode_i_32(1, serializer);
+      case BilibiliLoginStatus_Success():
+        sse_encode_i_32(2, serializer);
+      case BilibiliLoginStatus_Expired():
+        sse_encode_i_32(3, serializer);
+      case BilibiliLoginStatus_Failed(error: final error):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(error, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_bilibili_qr_code(
+    BilibiliQrCode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.qrcodeKey, serializer);
+  }
+
+  @protected
+  void sse_encode_bilibili_session_status(
+    BilibiliSessionStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bilibili_account_info(
+    BilibiliAccountInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bilibili_account_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_ip_filter_info(
+    IpFilterInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_ip_filter_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_progress_event(
+    ProgressEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_progress_event(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_site_credential_input(
+    SiteCredentialInput self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_site_credential_input(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_video_download_options(
+    VideoDownloadOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_video_download_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_created_torrent_info(
+    CreatedTorrentInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.infoHash, serializer);
+    sse_encode_String(self.magnet, serializer);
+    sse_encode_String(self.torrentPath, serializer);
+    sse_encode_u_64(self.totalSize, serializer);
+    sse_encode_u_64(self.pieceSize, serializer);
+    sse_encode_u_64(self.pieceCount, serializer);
+    sse_encode_u_64(self.fileCount, serializer);
+    sse_encode_opt_String(self.seedTaskId, serializer);
+  }
+
+  @protected
+  void sse_encode_credential_vault_status(
+    CredentialVaultStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.unlocked, serializer);
+    sse_encode_bool(self.requiresPassphrase, serializer);
+  }
+
+  @protected
+  void sse_encode_duplicate_action(
+    DuplicateAction self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  void sse_encode_StreamSink_nebula_event_Sse(
-    RustStreamSink<NebulaEvent> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_ip_filter_info(IpFilterInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_nebula_event,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+    sse_encode_u_64(self.rangeCount, serializer);
+    sse_encode_u_64(self.blockedHandoffPeers, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastUpdated, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bilibili_login_status(
-    BilibiliLoginStatus self,
+  void sse_encode_list_peer_info(
+    List<PeerInfo> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case BilibiliLoginStatus_WaitingScan():
-        sse_encode_i_32(0, serializer);
-      case BilibiliLoginStatus_WaitingConfirm():
-        sse_encode_i_32(1, serializer);
-      case BilibiliLoginStatus_Success():
-        sse_encode_i_32(2, serializer);
-      case BilibiliLoginStatus_Expired():
-        sse_encode_i_32(3, serializer);
-      case BilibiliLoginStatus_Failed(error: final error):
-        sse_encode_i_32(4, serializer);
-        sse_encode_String(error, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_peer_info(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_bilibili_qr_code(
-    BilibiliQrCode self,
+  void sse_encode_list_playlist_entry_info(
+    List<PlaylistEntryInfo> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.url, serializer);
-    sse_encode_String(self.qrcodeKey, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_playlist_entry_info(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_list_prim_u_64_strict(
+    Uint64List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint64List(self);
   }
 
   @protected
-  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  void sse_encode_box_autoadd_progress_event(
-    ProgressEvent self,
+  void sse_encode_list_site_credential_info(
+    List<SiteCredentialInfo> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_progress_event(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_site_credential_info(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+  void sse_encode_list_tool_status_info(
+    List<ToolStatusInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_tool_status_info(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
+  void sse_encode_list_torrent_file_info(
+    List<TorrentFileInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_torrent_file_info(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_list_tracker_info(
+    List<TrackerInfo> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    for (final item in self) {
+      sse_encode_tracker_info(item, serializer);
+    }
   }
 
   @protected
@@ -1348,12 +3786,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         name: final name,
         totalSize: final totalSize,
         fileCount: final fileCount,
+        mimeType: final mimeType,
+        supportsResume: final supportsResume,
+        thumbnail: final thumbnail,
+        durationSecs: final durationSecs,
       ):
         sse_encode_i_32(8, serializer);
         sse_encode_String(taskId, serializer);
         sse_encode_String(name, serializer);
         sse_encode_u_64(totalSize, serializer);
         sse_encode_usize(fileCount, serializer);
+        sse_encode_opt_String(mimeType, serializer);
+        sse_encode_bool(supportsResume, serializer);
+        sse_encode_opt_String(thumbnail, serializer);
+        sse_encode_opt_box_autoadd_u_64(durationSecs, serializer);
       case NebulaEvent_PeerUpdate(
         taskId: final taskId,
         connectedPeers: final connectedPeers,
@@ -1363,6 +3809,73 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(taskId, serializer);
         sse_encode_usize(connectedPeers, serializer);
         sse_encode_usize(totalPeers, serializer);
+      case NebulaEvent_CheckProgress(
+        taskId: final taskId,
+        checkedBytes: final checkedBytes,
+        totalBytes: final totalBytes,
+      ):
+        sse_encode_i_32(10, serializer);
+        sse_encode_String(taskId, serializer);
+        sse_encode_u_64(checkedBytes, serializer);
+        sse_encode_u_64(totalBytes, serializer);
+      case NebulaEvent_CheckCompleted(
+        taskId: final taskId,
+        validBytes: final validBytes,
+        totalBytes: final totalBytes,
+      ):
+        sse_encode_i_32(11, serializer);
+        sse_encode_String(taskId, serializer);
+        sse_encode_u_64(validBytes, serializer);
+        sse_encode_u_64(totalBytes, serializer);
+      case NebulaEvent_TaskStalled(
+        taskId: final taskId,
+        phase: final phase,
+        stalledSecs: final stalledSecs,
+        dhtNodes: final dhtNodes,
+        trackersTotal: final trackersTotal,
+        trackersReached: final trackersReached,
+        peersSeen: final peersSeen,
+        peersConnected: final peersConnected,
+      ):
+        sse_encode_i_32(12, serializer);
+        sse_encode_String(taskId, serializer);
+        sse_encode_String(phase, serializer);
+        sse_encode_u_64(stalledSecs, serializer);
+        sse_encode_opt_box_autoadd_u_64(dhtNodes, serializer);
+        sse_encode_u_64(trackersTotal, serializer);
+        sse_encode_u_64(trackersReached, serializer);
+        sse_encode_u_64(peersSeen, serializer);
+        sse_encode_u_64(peersConnected, serializer);
+      case NebulaEvent_StallRecovered(taskId: final taskId, phase: final phase):
+        sse_encode_i_32(13, serializer);
+        sse_encode_String(taskId, serializer);
+        sse_encode_String(phase, serializer);
+      case NebulaEvent_VideoStageChanged(
+        taskId: final taskId,
+        stage: final stage,
+      ):
+        sse_encode_i_32(14, serializer);
+        sse_encode_String(taskId, serializer);
+        sse_encode_String(stage, serializer);
+      case NebulaEvent_OutputFileResolved(
+        taskId: final taskId,
+        path: final path,
+      ):
+        sse_encode_i_32(15, serializer);
+        sse_encode_String(taskId, serializer);
+        sse_encode_String(path, serializer);
+      case NebulaEvent_StatusChanged(
+        taskId: final taskId,
+        from: final from,
+        to: final to,
+      ):
+        sse_encode_i_32(16, serializer);
+        sse_encode_String(taskId, serializer);
+        sse_encode_String(from, serializer);
+        sse_encode_String(to, serializer);
+      case NebulaEvent_BilibiliLoginExpired(reason: final reason):
+        sse_encode_i_32(17, serializer);
+        sse_encode_String(reason, serializer);
     }
   }
 
@@ -1376,6 +3889,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_bilibili_account_info(
+    BilibiliAccountInfo? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bilibili_account_info(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1386,6 +3912,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_ip_filter_info(
+    IpFilterInfo? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_ip_filter_info(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1396,6 +3958,55 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_video_download_options(
+    VideoDownloadOptions? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_video_download_options(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_peer_info(PeerInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.address, serializer);
+    sse_encode_opt_String(self.client, serializer);
+    sse_encode_String(self.flags, serializer);
+    sse_encode_u_64(self.downloadSpeed, serializer);
+    sse_encode_u_64(self.uploadSpeed, serializer);
+    sse_encode_u_64(self.downloaded, serializer);
+    sse_encode_u_64(self.uploaded, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.progress, serializer);
+  }
+
+  @protected
+  void sse_encode_playlist_entry_info(
+    PlaylistEntryInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.duration, serializer);
+    sse_encode_opt_String(self.uploader, serializer);
+  }
+
+  @protected
+  void sse_encode_playlist_info(PlaylistInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_opt_String(self.uploader, serializer);
+    sse_encode_list_playlist_entry_info(self.entries, serializer);
+  }
+
   @protected
   void sse_encode_progress_event(ProgressEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1407,6 +4018,132 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_u_64(self.etaSecs, serializer);
   }
 
+  @protected
+  void sse_encode_resolved_torrent_info(
+    ResolvedTorrentInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.infoHash, serializer);
+    sse_encode_u_64(self.totalSize, serializer);
+    sse_encode_u_64(self.pieceSize, serializer);
+    sse_encode_bool(self.private, serializer);
+    sse_encode_list_torrent_file_info(self.files, serializer);
+  }
+
+  @protected
+  void sse_encode_site_credential_info(
+    SiteCredentialInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.domain, serializer);
+    sse_encode_bool(self.includeSubdomains, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_opt_String(self.username, serializer);
+    sse_encode_opt_String(self.headerName, serializer);
+    sse_encode_i_64(self.updatedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_site_credential_input(
+    SiteCredentialInput self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case SiteCredentialInput_Cookie(cookies: final cookies):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(cookies, serializer);
+      case SiteCredentialInput_Bearer(token: final token):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(token, serializer);
+      case SiteCredentialInput_Basic(
+        username: final username,
+        password: final password,
+      ):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(username, serializer);
+        sse_encode_String(password, serializer);
+      case SiteCredentialInput_Header(name: final name, value: final value):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(name, serializer);
+        sse_encode_String(value, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_tool_status_info(
+    ToolStatusInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_opt_String(self.version, serializer);
+    sse_encode_String(self.source, serializer);
+  }
+
+  @protected
+  void sse_encode_tool_update_info(
+    ToolUpdateInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.current, serializer);
+    sse_encode_String(self.latest, serializer);
+    sse_encode_bool(self.updateAvailable, serializer);
+  }
+
+  @protected
+  void sse_encode_torrent_file_info(
+    TorrentFileInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.index, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_64(self.size, serializer);
+  }
+
+  @protected
+  void sse_encode_tracker_info(TrackerInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.source, serializer);
+    sse_encode_tracker_status_info(self.status, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.seeders, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.leechers, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.completed, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastUpdated, serializer);
+  }
+
+  @protected
+  void sse_encode_tracker_status_info(
+    TrackerStatusInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case TrackerStatusInfo_NotContacted():
+        sse_encode_i_32(0, serializer);
+      case TrackerStatusInfo_Updating():
+        sse_encode_i_32(1, serializer);
+      case TrackerStatusInfo_Working():
+        sse_encode_i_32(2, serializer);
+      case TrackerStatusInfo_Error(message: final message):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(message, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
   @protected
   void sse_encode_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1430,6 +4167,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putBigUint64(self);
   }
 
+  @protected
+  void sse_encode_video_download_options(
+    VideoDownloadOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.subtitleLangs, serializer);
+    sse_encode_bool(self.autoSubtitles, serializer);
+    sse_encode_bool(self.danmaku, serializer);
+    sse_encode_bool(self.writeThumbnail, serializer);
+    sse_encode_bool(self.writeInfoJson, serializer);
+    sse_encode_bool(self.embedMetadata, serializer);
+    sse_encode_bool(self.embedThumbnail, serializer);
+    sse_encode_bool(self.embedChapters, serializer);
+    sse_encode_opt_String(self.audioFormat, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.audioBitrateKbps, serializer);
+    sse_encode_bool(self.normalizeAudio, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxHeight, serializer);
+    sse_encode_opt_String(self.videoCodec, serializer);
+    sse_encode_opt_String(self.container, serializer);
+    sse_encode_bool(self.preferHdr, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxFps, serializer);
+    sse_encode_opt_String(self.audioLanguage, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxFilesizeMb, serializer);
+  }
+
   @protected
   void sse_encode_video_format(VideoFormat self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1441,6 +4204,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_f_64(self.fps, serializer);
     sse_encode_opt_String(self.vcodec, serializer);
     sse_encode_opt_String(self.acodec, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.tbr, serializer);
+    sse_encode_opt_String(self.dynamicRange, serializer);
+    sse_encode_opt_String(self.language, serializer);
+    sse_encode_bool(self.audioOnly, serializer);
+    sse_encode_bool(self.videoOnly, serializer);
   }
 
   @protected
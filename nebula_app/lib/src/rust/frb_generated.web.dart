@@ -32,30 +32,95 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  BilibiliAccountInfo dco_decode_bilibili_account_info(dynamic raw);
+
   @protected
   BilibiliLoginStatus dco_decode_bilibili_login_status(dynamic raw);
 
   @protected
   BilibiliQrCode dco_decode_bilibili_qr_code(dynamic raw);
 
+  @protected
+  BilibiliSessionStatus dco_decode_bilibili_session_status(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  BilibiliAccountInfo dco_decode_box_autoadd_bilibili_account_info(dynamic raw);
+
   @protected
   double dco_decode_box_autoadd_f_64(dynamic raw);
 
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  IpFilterInfo dco_decode_box_autoadd_ip_filter_info(dynamic raw);
+
   @protected
   ProgressEvent dco_decode_box_autoadd_progress_event(dynamic raw);
 
+  @protected
+  SiteCredentialInput dco_decode_box_autoadd_site_credential_input(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  VideoDownloadOptions dco_decode_box_autoadd_video_download_options(
+    dynamic raw,
+  );
+
+  @protected
+  CreatedTorrentInfo dco_decode_created_torrent_info(dynamic raw);
+
+  @protected
+  CredentialVaultStatus dco_decode_credential_vault_status(dynamic raw);
+
+  @protected
+  DuplicateAction dco_decode_duplicate_action(dynamic raw);
+
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  IpFilterInfo dco_decode_ip_filter_info(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<PeerInfo> dco_decode_list_peer_info(dynamic raw);
+
+  @protected
+  List<PlaylistEntryInfo> dco_decode_list_playlist_entry_info(dynamic raw);
+
+  @protected
+  Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<SiteCredentialInfo> dco_decode_list_site_credential_info(dynamic raw);
+
+  @protected
+  List<ToolStatusInfo> dco_decode_list_tool_status_info(dynamic raw);
+
+  @protected
+  List<TorrentFileInfo> dco_decode_list_torrent_file_info(dynamic raw);
+
+  @protected
+  List<TrackerInfo> dco_decode_list_tracker_info(dynamic raw);
+
   @protected
   List<VideoFormat> dco_decode_list_video_format(dynamic raw);
 
@@ -65,15 +130,70 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  BilibiliAccountInfo? dco_decode_opt_box_autoadd_bilibili_account_info(
+    dynamic raw,
+  );
+
   @protected
   double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
 
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  IpFilterInfo? dco_decode_opt_box_autoadd_ip_filter_info(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  VideoDownloadOptions? dco_decode_opt_box_autoadd_video_download_options(
+    dynamic raw,
+  );
+
+  @protected
+  PeerInfo dco_decode_peer_info(dynamic raw);
+
+  @protected
+  PlaylistEntryInfo dco_decode_playlist_entry_info(dynamic raw);
+
+  @protected
+  PlaylistInfo dco_decode_playlist_info(dynamic raw);
+
   @protected
   ProgressEvent dco_decode_progress_event(dynamic raw);
 
+  @protected
+  ResolvedTorrentInfo dco_decode_resolved_torrent_info(dynamic raw);
+
+  @protected
+  SiteCredentialInfo dco_decode_site_credential_info(dynamic raw);
+
+  @protected
+  SiteCredentialInput dco_decode_site_credential_input(dynamic raw);
+
+  @protected
+  ToolStatusInfo dco_decode_tool_status_info(dynamic raw);
+
+  @protected
+  ToolUpdateInfo dco_decode_tool_update_info(dynamic raw);
+
+  @protected
+  TorrentFileInfo dco_decode_torrent_file_info(dynamic raw);
+
+  @protected
+  TrackerInfo dco_decode_tracker_info(dynamic raw);
+
+  @protected
+  TrackerStatusInfo dco_decode_tracker_status_info(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
   @protected
   BigInt dco_decode_u_64(dynamic raw);
 
@@ -86,6 +206,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt dco_decode_usize(dynamic raw);
 
+  @protected
+  VideoDownloadOptions dco_decode_video_download_options(dynamic raw);
+
   @protected
   VideoFormat dco_decode_video_format(dynamic raw);
 
@@ -103,6 +226,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  BilibiliAccountInfo sse_decode_bilibili_account_info(
+    SseDeserializer deserializer,
+  );
+
   @protected
   BilibiliLoginStatus sse_decode_bilibili_login_status(
     SseDeserializer deserializer,
@@ -111,26 +239,108 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BilibiliQrCode sse_decode_bilibili_qr_code(SseDeserializer deserializer);
 
+  @protected
+  BilibiliSessionStatus sse_decode_bilibili_session_status(
+    SseDeserializer deserializer,
+  );
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  BilibiliAccountInfo sse_decode_box_autoadd_bilibili_account_info(
+    SseDeserializer deserializer,
+  );
+
   @protected
   double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
 
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  IpFilterInfo sse_decode_box_autoadd_ip_filter_info(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ProgressEvent sse_decode_box_autoadd_progress_event(
     SseDeserializer deserializer,
   );
 
+  @protected
+  SiteCredentialInput sse_decode_box_autoadd_site_credential_input(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  VideoDownloadOptions sse_decode_box_autoadd_video_download_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CreatedTorrentInfo sse_decode_created_torrent_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CredentialVaultStatus sse_decode_credential_vault_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DuplicateAction sse_decode_duplicate_action(SseDeserializer deserializer);
+
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  IpFilterInfo sse_decode_ip_filter_info(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<PeerInfo> sse_decode_list_peer_info(SseDeserializer deserializer);
+
+  @protected
+  List<PlaylistEntryInfo> sse_decode_list_playlist_entry_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<SiteCredentialInfo> sse_decode_list_site_credential_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ToolStatusInfo> sse_decode_list_tool_status_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TorrentFileInfo> sse_decode_list_torrent_file_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TrackerInfo> sse_decode_list_tracker_info(SseDeserializer deserializer);
+
   @protected
   List<VideoFormat> sse_decode_list_video_format(SseDeserializer deserializer);
 
@@ -140,15 +350,82 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
+  @protected
+  BilibiliAccountInfo? sse_decode_opt_box_autoadd_bilibili_account_info(
+    SseDeserializer deserializer,
+  );
+
   @protected
   double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
 
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  IpFilterInfo? sse_decode_opt_box_autoadd_ip_filter_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  VideoDownloadOptions? sse_decode_opt_box_autoadd_video_download_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PeerInfo sse_decode_peer_info(SseDeserializer deserializer);
+
+  @protected
+  PlaylistEntryInfo sse_decode_playlist_entry_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlaylistInfo sse_decode_playlist_info(SseDeserializer deserializer);
+
   @protected
   ProgressEvent sse_decode_progress_event(SseDeserializer deserializer);
 
+  @protected
+  ResolvedTorrentInfo sse_decode_resolved_torrent_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SiteCredentialInfo sse_decode_site_credential_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SiteCredentialInput sse_decode_site_credential_input(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ToolStatusInfo sse_decode_tool_status_info(SseDeserializer deserializer);
+
+  @protected
+  ToolUpdateInfo sse_decode_tool_update_info(SseDeserializer deserializer);
+
+  @protected
+  TorrentFileInfo sse_decode_torrent_file_info(SseDeserializer deserializer);
+
+  @protected
+  TrackerInfo sse_decode_tracker_info(SseDeserializer deserializer);
+
+  @protected
+  TrackerStatusInfo sse_decode_tracker_status_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_u_64(SseDeserializer deserializer);
 
@@ -161,6 +438,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer);
 
+  @protected
+  VideoDownloadOptions sse_decode_video_download_options(
+    SseDeserializer deserializer,
+  );
+
   @protected
   VideoFormat sse_decode_video_format(SseDeserializer deserializer);
 
@@ -185,6 +467,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_bilibili_account_info(
+    BilibiliAccountInfo self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_bilibili_login_status(
     BilibiliLoginStatus self,
@@ -197,30 +485,135 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_bilibili_session_status(
+    BilibiliSessionStatus self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_bilibili_account_info(
+    BilibiliAccountInfo self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_ip_filter_info(
+    IpFilterInfo self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_progress_event(
     ProgressEvent self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_site_credential_input(
+    SiteCredentialInput self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_video_download_options(
+    VideoDownloadOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_created_torrent_info(
+    CreatedTorrentInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_credential_vault_status(
+    CredentialVaultStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_duplicate_action(
+    DuplicateAction self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_ip_filter_info(IpFilterInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_peer_info(List<PeerInfo> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_playlist_entry_info(
+    List<PlaylistEntryInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_64_strict(
+    Uint64List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_site_credential_info(
+    List<SiteCredentialInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_tool_status_info(
+    List<ToolStatusInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_torrent_file_info(
+    List<TorrentFileInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_tracker_info(
+    List<TrackerInfo> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_video_format(
     List<VideoFormat> self,
@@ -233,15 +626,102 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_bilibili_account_info(
+    BilibiliAccountInfo? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_ip_filter_info(
+    IpFilterInfo? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_video_download_options(
+    VideoDownloadOptions? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_peer_info(PeerInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_playlist_entry_info(
+    PlaylistEntryInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_playlist_info(PlaylistInfo self, SseSerializer serializer);
+
   @protected
   void sse_encode_progress_event(ProgressEvent self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_resolved_torrent_info(
+    ResolvedTorrentInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_site_credential_info(
+    SiteCredentialInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_site_credential_input(
+    SiteCredentialInput self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_tool_status_info(
+    ToolStatusInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_tool_update_info(
+    ToolUpdateInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_torrent_file_info(
+    TorrentFileInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_tracker_info(TrackerInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tracker_status_info(
+    TrackerStatusInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
@@ -254,6 +734,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_video_download_options(
+    VideoDownloadOptions self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_video_format(VideoFormat self, SseSerializer serializer);
 
@@ -4,7 +4,12 @@
 
 use crate::frb_generated::StreamSink;
 use flutter_rust_bridge::frb;
-use nebula_core::{DownloadEvent, DownloadManager, ManagerConfig, Progress};
+use nebula_core::{
+    AudioExtraction, AudioFormat, DownloadEvent, DownloadManager, FormatPreference, ManagerConfig,
+    PlaylistOptions, Progress, VideoCodec, VideoContainer, VideoOptions,
+};
+use nebula_core::tools::{ToolInfo, ToolSource};
+use nebula_core::vault::{Credential, CredentialKind, SiteCredential};
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -47,8 +52,75 @@ pub enum NebulaEvent {
     TaskPaused { task_id: String },
     TaskResumed { task_id: String },
     TaskRemoved { task_id: String },
-    MetadataReceived { task_id: String, name: String, total_size: u64, file_count: usize },
+    MetadataReceived {
+        task_id: String,
+        name: String,
+        total_size: u64,
+        file_count: usize,
+        mime_type: Option<String>,
+        supports_resume: bool,
+        thumbnail: Option<String>,
+        duration_secs: Option<u64>,
+    },
     PeerUpdate { task_id: String, connected_peers: usize, total_peers: usize },
+    CheckProgress { task_id: String, checked_bytes: u64, total_bytes: u64 },
+    CheckCompleted { task_id: String, valid_bytes: u64, total_bytes: u64 },
+    /// 长时间没有进展；`phase` 为 "metadata" 或 "payload"
+    TaskStalled {
+        task_id: String,
+        phase: String,
+        stalled_secs: u64,
+        dht_nodes: Option<u64>,
+        trackers_total: u64,
+        trackers_reached: u64,
+        peers_seen: u64,
+        peers_connected: u64,
+    },
+    StallRecovered { task_id: String, phase: String },
+    /// 视频进入新阶段；`stage` 为 "video"、"audio"、"merging"、"extracting_audio" 或 "post_processing"
+    VideoStageChanged { task_id: String, stage: String },
+    /// 最终输出文件已确定
+    OutputFileResolved { task_id: String, path: String },
+    /// 任务状态变化，`from`/`to` 为状态名（如 "downloading"、"paused"）
+    StatusChanged { task_id: String, from: String, to: String },
+    /// Bilibili 登录已失效，需要重新扫码登录
+    BilibiliLoginExpired { reason: String },
+}
+
+fn video_stage_name(stage: nebula_core::VideoStage) -> String {
+    match stage {
+        nebula_core::VideoStage::Video => "video",
+        nebula_core::VideoStage::Audio => "audio",
+        nebula_core::VideoStage::Merging => "merging",
+        nebula_core::VideoStage::ExtractingAudio => "extracting_audio",
+        nebula_core::VideoStage::PostProcessing => "post_processing",
+    }
+    .to_string()
+}
+
+fn task_status_name(status: &nebula_core::TaskStatus) -> String {
+    use nebula_core::TaskStatus;
+    match status {
+        TaskStatus::Pending => "pending",
+        TaskStatus::FetchingMetadata => "fetching_metadata",
+        TaskStatus::Checking => "checking",
+        TaskStatus::Stalled => "stalled",
+        TaskStatus::Downloading => "downloading",
+        TaskStatus::Paused => "paused",
+        TaskStatus::Completed => "completed",
+        TaskStatus::Seeding => "seeding",
+        TaskStatus::Failed { .. } => "failed",
+        TaskStatus::Cancelled => "cancelled",
+    }
+    .to_string()
+}
+
+fn stall_phase_name(phase: nebula_core::StallPhase) -> String {
+    match phase {
+        nebula_core::StallPhase::Metadata => "metadata",
+        nebula_core::StallPhase::Payload => "payload",
+    }
+    .to_string()
 }
 
 /// 初始化下载管理器
@@ -88,20 +160,146 @@ pub async fn add_download(source: String, save_path: String) -> Result<String, S
     Ok(task_id.to_string())
 }
 
-/// 添加视频下载任务（指定画质）
+/// 导入已有数据添加种子任务
+///
+/// 引擎会逐块校验 `data_dir` 中的已有文件，只下载缺失或损坏的部分
+#[frb]
+pub async fn add_download_with_existing_data(source: String, data_dir: String) -> Result<String, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let options = nebula_core::AddTaskOptions::new().with_existing_data(PathBuf::from(&data_dir));
+    let task_id = manager
+        .add_task_with_options(&source, PathBuf::new(), options)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(task_id.to_string())
+}
+
+/// 重复任务处理策略
+pub enum DuplicateAction {
+    /// 拒绝添加，返回错误
+    Reject,
+    /// 返回已有任务的 ID
+    ReturnExisting,
+    /// 取消已有任务后重新开始（保留已下载的文件）
+    Restart,
+}
+
+/// 添加下载任务，并指定遇到重复任务时的处理方式
+#[frb]
+pub async fn add_download_with_policy(
+    source: String,
+    save_path: String,
+    on_duplicate: DuplicateAction,
+) -> Result<String, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let policy = match on_duplicate {
+        DuplicateAction::Reject => nebula_core::DuplicatePolicy::Reject,
+        DuplicateAction::ReturnExisting => nebula_core::DuplicatePolicy::ReturnExisting,
+        DuplicateAction::Restart => nebula_core::DuplicatePolicy::Restart,
+    };
+    let options = nebula_core::AddTaskOptions::new().with_duplicate_policy(policy);
+    let task_id = manager
+        .add_task_with_options(&source, PathBuf::from(&save_path), options)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(task_id.to_string())
+}
+
+/// 视频附加内容选项（字幕、弹幕、封面、元数据）
+#[frb(dart_metadata = ("freezed"))]
+pub struct VideoDownloadOptions {
+    /// 字幕语言（如 "zh-Hans"、"en"，"all" 表示全部），为空时不下载字幕
+    pub subtitle_langs: Vec<String>,
+    /// 没有人工字幕时下载自动生成的字幕
+    pub auto_subtitles: bool,
+    /// 下载 Bilibili 弹幕并转换为 ASS
+    pub danmaku: bool,
+    pub write_thumbnail: bool,
+    pub write_info_json: bool,
+    pub embed_metadata: bool,
+    pub embed_thumbnail: bool,
+    pub embed_chapters: bool,
+    /// 只提取音频："mp3"、"m4a"、"opus" 或 "flac"（也可以直接选择画质列表中的 "audio:mp3" 等）
+    pub audio_format: Option<String>,
+    /// 音频码率（kbps），None 表示最佳质量
+    pub audio_bitrate_kbps: Option<u32>,
+    /// 音频响度标准化
+    pub normalize_audio: bool,
+    /// 最大高度（如 1080），未指定 format_id 时生效
+    pub max_height: Option<u32>,
+    /// 优先的视频编码："h264"、"h265"、"vp9" 或 "av1"，None 时默认 H.264
+    pub video_codec: Option<String>,
+    /// 输出容器："mp4"、"mkv" 或 "webm"
+    pub container: Option<String>,
+    pub prefer_hdr: bool,
+    pub max_fps: Option<u32>,
+    /// 音轨语言（如 "ja"）
+    pub audio_language: Option<String>,
+    /// 单个流的最大大小（MB）
+    pub max_filesize_mb: Option<u64>,
+}
+
+impl From<VideoDownloadOptions> for VideoOptions {
+    fn from(o: VideoDownloadOptions) -> Self {
+        let mut options = VideoOptions::new()
+            .with_subtitles(o.subtitle_langs, o.auto_subtitles)
+            .with_danmaku(o.danmaku)
+            .with_thumbnail(o.write_thumbnail)
+            .with_info_json(o.write_info_json)
+            .with_embed(o.embed_metadata, o.embed_thumbnail, o.embed_chapters);
+        if let Some(format) = o.audio_format.as_deref().and_then(AudioFormat::from_extension) {
+            let mut audio = AudioExtraction::new(format).with_normalize(o.normalize_audio);
+            if let Some(kbps) = o.audio_bitrate_kbps {
+                audio = audio.with_bitrate(kbps);
+            }
+            options = options.with_audio(audio);
+        }
+
+        let mut format = FormatPreference::new().with_hdr(o.prefer_hdr);
+        if let Some(height) = o.max_height {
+            format = format.with_max_height(height);
+        }
+        if let Some(codec) = o.video_codec.as_deref() {
+            format = format.with_video_codec(VideoCodec::from_name(codec));
+        }
+        if let Some(container) = o.container.as_deref().and_then(VideoContainer::from_extension) {
+            format = format.with_container(container);
+        }
+        if let Some(fps) = o.max_fps {
+            format = format.with_max_fps(fps);
+        }
+        if let Some(language) = o.audio_language {
+            format = format.with_audio_language(language);
+        }
+        if let Some(mb) = o.max_filesize_mb {
+            format = format.with_max_filesize(mb * 1024 * 1024);
+        }
+        options.with_format(format)
+    }
+}
+
+/// 添加视频下载任务（指定画质和附加内容）
 #[frb]
 pub async fn add_video_download(
     url: String, 
     save_path: String, 
     format_id: Option<String>,
     title: Option<String>,
-    thumbnail: Option<String>
+    thumbnail: Option<String>,
+    options: Option<VideoDownloadOptions>,
 ) -> Result<String, String> {
     let guard = MANAGER.read().await;
     let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
 
+    let options = options.map(VideoOptions::from).unwrap_or_default();
     let task_id = manager
-        .add_video_task(&url, format_id, PathBuf::from(&save_path), title, thumbnail)
+        .add_video_task(&url, format_id, PathBuf::from(&save_path), title, thumbnail, options)
         .await
         .map_err(|e| e.to_string())?;
 
@@ -144,6 +342,42 @@ pub async fn cancel_download(task_id: String, delete_files: bool) -> Result<(),
     manager.cancel(id, delete_files).await.map_err(|e| e.to_string())
 }
 
+/// 重新开始已完成或失败的任务
+#[frb]
+pub async fn restart_download(task_id: String) -> Result<(), String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let id = nebula_core::TaskId::from_string(&task_id)
+        .map_err(|e| format!("无效的任务 ID: {}", e))?;
+
+    manager.restart(id).await.map_err(|e| e.to_string())
+}
+
+/// 从列表中移除已结束的任务
+#[frb]
+pub async fn remove_download(task_id: String) -> Result<(), String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let id = nebula_core::TaskId::from_string(&task_id)
+        .map_err(|e| format!("无效的任务 ID: {}", e))?;
+
+    manager.remove_task(id).await.map_err(|e| e.to_string())
+}
+
+/// 强制重新校验种子任务的数据
+#[frb]
+pub async fn recheck_download(task_id: String) -> Result<(), String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let id = nebula_core::TaskId::from_string(&task_id)
+        .map_err(|e| format!("无效的任务 ID: {}", e))?;
+
+    manager.recheck(id).await.map_err(|e| e.to_string())
+}
+
 /// 订阅下载事件流
 ///
 /// 返回一个 Stream，用于接收下载进度和状态变化
@@ -204,14 +438,25 @@ pub async fn subscribe_events(sink: StreamSink<NebulaEvent>) -> Result<(), Strin
                         task_id: task_id.to_string(),
                     }
                 }
-                DownloadEvent::MetadataReceived { task_id, name, total_size, file_count } => {
-                    NebulaEvent::MetadataReceived {
-                        task_id: task_id.to_string(),
-                        name,
-                        total_size,
-                        file_count,
-                    }
-                }
+                DownloadEvent::MetadataReceived {
+                    task_id,
+                    name,
+                    total_size,
+                    file_count,
+                    mime_type,
+                    supports_resume,
+                    thumbnail,
+                    duration_secs,
+                } => NebulaEvent::MetadataReceived {
+                    task_id: task_id.to_string(),
+                    name,
+                    total_size,
+                    file_count,
+                    mime_type,
+                    supports_resume,
+                    thumbnail,
+                    duration_secs,
+                },
                 DownloadEvent::PeerUpdate { task_id, connected_peers, total_peers } => {
                      NebulaEvent::PeerUpdate {
                          task_id: task_id.to_string(),
@@ -219,6 +464,60 @@ pub async fn subscribe_events(sink: StreamSink<NebulaEvent>) -> Result<(), Strin
                          total_peers,
                      }
                 }
+                DownloadEvent::CheckProgress { task_id, checked_bytes, total_bytes } => {
+                    NebulaEvent::CheckProgress {
+                        task_id: task_id.to_string(),
+                        checked_bytes,
+                        total_bytes,
+                    }
+                }
+                DownloadEvent::CheckCompleted { task_id, valid_bytes, total_bytes } => {
+                    NebulaEvent::CheckCompleted {
+                        task_id: task_id.to_string(),
+                        valid_bytes,
+                        total_bytes,
+                    }
+                }
+                DownloadEvent::TaskStalled { task_id, phase, diagnostics } => {
+                    NebulaEvent::TaskStalled {
+                        task_id: task_id.to_string(),
+                        phase: stall_phase_name(phase),
+                        stalled_secs: diagnostics.stalled_secs,
+                        dht_nodes: diagnostics.dht_nodes.map(|n| n as u64),
+                        trackers_total: diagnostics.trackers_total as u64,
+                        trackers_reached: diagnostics.trackers_reached as u64,
+                        peers_seen: diagnostics.peers_seen as u64,
+                        peers_connected: diagnostics.peers_connected as u64,
+                    }
+                }
+                DownloadEvent::StallRecovered { task_id, phase } => {
+                    NebulaEvent::StallRecovered {
+                        task_id: task_id.to_string(),
+                        phase: stall_phase_name(phase),
+                    }
+                }
+                DownloadEvent::VideoStageChanged { task_id, stage } => {
+                    NebulaEvent::VideoStageChanged {
+                        task_id: task_id.to_string(),
+                        stage: video_stage_name(stage),
+                    }
+                }
+                DownloadEvent::OutputFileResolved { task_id, path } => {
+                    NebulaEvent::OutputFileResolved {
+                        task_id: task_id.to_string(),
+                        path: path.to_string_lossy().to_string(),
+                    }
+                }
+                DownloadEvent::StatusChanged { task_id, from, to } => {
+                    NebulaEvent::StatusChanged {
+                        task_id: task_id.to_string(),
+                        from: task_status_name(&from),
+                        to: task_status_name(&to),
+                    }
+                }
+                DownloadEvent::BilibiliLoginExpired { reason } => {
+                    NebulaEvent::BilibiliLoginExpired { reason }
+                }
             };
 
             if sink.add(nebula_event).is_err() {
@@ -252,26 +551,48 @@ pub struct VideoFormat {
     pub fps: Option<f64>,
     pub vcodec: Option<String>,
     pub acodec: Option<String>,
+    /// 总码率（kbps）
+    pub tbr: Option<f64>,
+    /// 动态范围（SDR、HDR10、DV 等）
+    pub dynamic_range: Option<String>,
+    /// 音轨语言
+    pub language: Option<String>,
+    pub audio_only: bool,
+    pub video_only: bool,
 }
 
 /// 检查 URL 是否为视频网站
+///
+/// 下载管理器初始化后使用配置的站点规则，否则使用内置规则
+#[frb]
+pub async fn is_video_url(url: String) -> bool {
+    let guard = MANAGER.read().await;
+    match guard.as_ref() {
+        Some(manager) => manager.is_video_url(&url),
+        None => nebula_core::extractor::ExtractorRegistry::builtin().is_video_url(&url),
+    }
+}
+
+/// 询问 yt-dlp 由哪个提取器处理该链接
+///
+/// 返回提取器名称，yt-dlp 无法识别时返回 None
 #[frb]
-pub fn is_video_url(url: String) -> bool {
-    nebula_core::protocol::video::VideoHandler::is_video_url(&url)
+pub async fn probe_video_extractor(url: String) -> Result<Option<String>, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    manager.probe_extractor(&url).await.map_err(|e| e.to_string())
 }
 
 /// 获取视频信息
 ///
-/// 需要系统已安装 yt-dlp
+/// 需要已安装 yt-dlp
 #[frb]
 pub async fn get_video_info(url: String) -> Result<VideoInfo, String> {
     let guard = MANAGER.read().await;
     let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
 
-    let handler = nebula_core::protocol::video::VideoHandler::new(manager.download_dir().clone())
-        .map_err(|e| e.to_string())?;
-
-    let info = handler.get_video_info(&url).await.map_err(|e| e.to_string())?;
+    let info = manager.get_video_info(&url).await.map_err(|e| e.to_string())?;
 
     Ok(VideoInfo {
         id: info.id,
@@ -291,11 +612,536 @@ pub async fn get_video_info(url: String) -> Result<VideoInfo, String> {
                 fps: f.fps,
                 vcodec: f.vcodec,
                 acodec: f.acodec,
+                tbr: f.tbr,
+                dynamic_range: f.dynamic_range,
+                language: f.language,
+                audio_only: f.audio_only,
+                video_only: f.video_only,
+            })
+            .collect(),
+    })
+}
+
+/// 播放列表条目
+#[frb(dart_metadata = ("freezed"))]
+pub struct PlaylistEntryInfo {
+    /// 序号（从 1 开始）
+    pub index: u32,
+    pub id: String,
+    pub title: String,
+    pub url: String,
+    pub duration: Option<u64>,
+    pub uploader: Option<String>,
+}
+
+/// 播放列表信息（播放列表、频道、合集或多 P 视频）
+#[frb(dart_metadata = ("freezed"))]
+pub struct PlaylistInfo {
+    pub id: String,
+    pub title: String,
+    pub uploader: Option<String>,
+    pub entries: Vec<PlaylistEntryInfo>,
+}
+
+/// 展开播放列表，列出其中的条目
+#[frb]
+pub async fn get_playlist_info(url: String) -> Result<PlaylistInfo, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let info = manager.expand_playlist(&url).await.map_err(|e| e.to_string())?;
+
+    Ok(PlaylistInfo {
+        id: info.id,
+        title: info.title,
+        uploader: info.uploader,
+        entries: info
+            .entries
+            .into_iter()
+            .map(|e| PlaylistEntryInfo {
+                index: e.index as u32,
+                id: e.id,
+                title: e.title,
+                url: e.url,
+                duration: e.duration,
+                uploader: e.uploader,
             })
             .collect(),
     })
 }
 
+/// 添加播放列表下载任务
+///
+/// `items` 为要下载的条目，如 `1-3,5,8-`；`only_new` 时跳过以前下载过的条目
+#[frb]
+pub async fn add_playlist_download(
+    url: String,
+    save_path: String,
+    items: Option<String>,
+    format_id: Option<String>,
+    only_new: bool,
+    options: Option<VideoDownloadOptions>,
+) -> Result<String, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let video_options = options.map(VideoOptions::from).unwrap_or_default();
+    let mut options = PlaylistOptions::new()
+        .with_only_new(only_new)
+        .with_video_options(video_options);
+    if let Some(items) = items {
+        options = options.with_items(items);
+    }
+    if let Some(format_id) = format_id {
+        options = options.with_format_id(format_id);
+    }
+
+    let task_id = manager
+        .add_playlist_task(&url, PathBuf::from(&save_path), options)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(task_id.to_string())
+}
+
+// ===== BitTorrent Tracker / Peer 相关 API =====
+
+/// Tracker 状态
+#[frb(dart_metadata = ("freezed"))]
+pub enum TrackerStatusInfo {
+    NotContacted,
+    Updating,
+    Working,
+    Error { message: String },
+}
+
+/// 种子的 Tracker 信息
+#[frb(dart_metadata = ("freezed"))]
+pub struct TrackerInfo {
+    pub url: String,
+    /// 来源: torrent / injected / user
+    pub source: String,
+    pub status: TrackerStatusInfo,
+    pub seeders: Option<u32>,
+    pub leechers: Option<u32>,
+    pub completed: Option<u32>,
+    /// 最近一次查询时间（Unix 时间戳，秒）
+    pub last_updated: Option<i64>,
+}
+
+/// 已连接 Peer 的信息
+#[frb(dart_metadata = ("freezed"))]
+pub struct PeerInfo {
+    pub address: String,
+    pub client: Option<String>,
+    pub flags: String,
+    pub download_speed: u64,
+    pub upload_speed: u64,
+    pub downloaded: u64,
+    pub uploaded: u64,
+    pub progress: Option<f64>,
+}
+
+/// 获取种子任务的 Tracker 列表
+///
+/// `refresh` 为 true 时会立即向所有 Tracker 重新查询
+#[frb]
+pub async fn get_torrent_trackers(task_id: String, refresh: bool) -> Result<Vec<TrackerInfo>, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let id = nebula_core::TaskId::from_string(&task_id)
+        .map_err(|e| format!("无效的任务 ID: {}", e))?;
+
+    let trackers = manager
+        .get_torrent_trackers(id, refresh)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(trackers
+        .into_iter()
+        .map(|t| {
+            use nebula_core::protocol::torrent::{TrackerSource, TrackerStatus};
+            TrackerInfo {
+                url: t.url,
+                source: match t.source {
+                    TrackerSource::Torrent => "torrent",
+                    TrackerSource::Injected => "injected",
+                    TrackerSource::User => "user",
+                }
+                .to_string(),
+                status: match t.status {
+                    TrackerStatus::NotContacted => TrackerStatusInfo::NotContacted,
+                    TrackerStatus::Updating => TrackerStatusInfo::Updating,
+                    TrackerStatus::Working => TrackerStatusInfo::Working,
+                    TrackerStatus::Error(message) => TrackerStatusInfo::Error { message },
+                },
+                seeders: t.seeders,
+                leechers: t.leechers,
+                completed: t.completed,
+                last_updated: t.last_updated.map(|at| at.timestamp()),
+            }
+        })
+        .collect())
+}
+
+/// 获取种子任务当前连接的 Peer 列表
+#[frb]
+pub async fn get_torrent_peers(task_id: String) -> Result<Vec<PeerInfo>, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let id = nebula_core::TaskId::from_string(&task_id)
+        .map_err(|e| format!("无效的任务 ID: {}", e))?;
+
+    let peers = manager.get_torrent_peers(id).await.map_err(|e| e.to_string())?;
+
+    Ok(peers
+        .into_iter()
+        .map(|p| PeerInfo {
+            address: p.address,
+            client: p.client,
+            flags: p.flags,
+            download_speed: p.download_speed,
+            upload_speed: p.upload_speed,
+            downloaded: p.downloaded,
+            uploaded: p.uploaded,
+            progress: p.progress,
+        })
+        .collect())
+}
+
+/// 为种子任务添加 Tracker
+#[frb]
+pub async fn add_torrent_trackers(task_id: String, urls: Vec<String>) -> Result<(), String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let id = nebula_core::TaskId::from_string(&task_id)
+        .map_err(|e| format!("无效的任务 ID: {}", e))?;
+
+    manager
+        .add_torrent_trackers(id, urls)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 从种子任务移除 Tracker
+#[frb]
+pub async fn remove_torrent_trackers(task_id: String, urls: Vec<String>) -> Result<(), String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let id = nebula_core::TaskId::from_string(&task_id)
+        .map_err(|e| format!("无效的任务 ID: {}", e))?;
+
+    manager
+        .remove_torrent_trackers(id, urls)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// IP 过滤统计
+#[frb(dart_metadata = ("freezed"))]
+pub struct IpFilterInfo {
+    /// 合并后的 IP 范围数量
+    pub range_count: u64,
+    /// Nebula 交给引擎前拒绝的 Peer 数量（不含引擎按黑名单拒绝的连接）
+    pub blocked_handoff_peers: u64,
+    /// 订阅列表最近一次更新时间（Unix 时间戳，秒）
+    pub last_updated: Option<i64>,
+}
+
+impl From<nebula_core::ipfilter::IpFilterStats> for IpFilterInfo {
+    fn from(stats: nebula_core::ipfilter::IpFilterStats) -> Self {
+        Self {
+            range_count: stats.range_count as u64,
+            blocked_handoff_peers: stats.blocked_handoff_peers,
+            last_updated: stats.last_updated.map(|at| at.timestamp()),
+        }
+    }
+}
+
+/// 获取 IP 过滤统计（未启用时返回 None）
+#[frb]
+pub async fn get_ip_filter_stats() -> Result<Option<IpFilterInfo>, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    Ok(manager.ip_filter_stats().await.map(IpFilterInfo::from))
+}
+
+/// 立即更新 IP 过滤订阅列表
+#[frb]
+pub async fn update_ip_filter() -> Result<IpFilterInfo, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    manager
+        .update_ip_filter()
+        .await
+        .map(IpFilterInfo::from)
+        .map_err(|e| e.to_string())
+}
+
+// ===== 外部工具相关 API =====
+
+/// 外部工具（yt-dlp、ffmpeg）信息
+#[frb(dart_metadata = ("freezed"))]
+pub struct ToolStatusInfo {
+    /// 工具名称
+    pub name: String,
+    /// 可执行文件路径
+    pub path: String,
+    /// 版本号
+    pub version: Option<String>,
+    /// 来源：configured / managed / bundled / system
+    pub source: String,
+}
+
+impl From<ToolInfo> for ToolStatusInfo {
+    fn from(info: ToolInfo) -> Self {
+        let source = match info.source {
+            ToolSource::Configured => "configured",
+            ToolSource::Managed => "managed",
+            ToolSource::Bundled => "bundled",
+            ToolSource::System => "system",
+        };
+        Self {
+            name: info.tool.name().to_string(),
+            path: info.path.to_string_lossy().to_string(),
+            version: info.version,
+            source: source.to_string(),
+        }
+    }
+}
+
+/// yt-dlp 更新检查结果
+#[frb(dart_metadata = ("freezed"))]
+pub struct ToolUpdateInfo {
+    pub current: Option<String>,
+    pub latest: String,
+    pub update_available: bool,
+}
+
+/// 获取已找到的外部工具（未找到的工具不包含在结果中）
+#[frb]
+pub async fn get_tools_status() -> Result<Vec<ToolStatusInfo>, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    Ok(manager
+        .tool_status()
+        .await
+        .into_iter()
+        .map(ToolStatusInfo::from)
+        .collect())
+}
+
+/// 检查 yt-dlp 是否有新版本
+#[frb]
+pub async fn check_yt_dlp_update() -> Result<ToolUpdateInfo, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let update = manager
+        .check_yt_dlp_update()
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(ToolUpdateInfo {
+        current: update.current,
+        latest: update.latest,
+        update_available: update.update_available,
+    })
+}
+
+/// 下载并安装最新版 yt-dlp
+#[frb]
+pub async fn update_yt_dlp() -> Result<ToolStatusInfo, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    manager
+        .update_yt_dlp()
+        .await
+        .map(ToolStatusInfo::from)
+        .map_err(|e| e.to_string())
+}
+
+// ===== 种子制作相关 API =====
+
+/// 制作完成的种子信息
+#[frb(dart_metadata = ("freezed"))]
+pub struct CreatedTorrentInfo {
+    pub name: String,
+    pub info_hash: String,
+    pub magnet: String,
+    pub torrent_path: String,
+    pub total_size: u64,
+    pub piece_size: u64,
+    pub piece_count: u64,
+    pub file_count: u64,
+    /// 做种任务 ID（未做种时为 None）
+    pub seed_task_id: Option<String>,
+}
+
+/// 从本地文件或目录制作种子
+///
+/// `piece_size` 为 None 时自动选择；`seed` 为 true 时制作完成后立即做种
+#[frb]
+pub async fn create_torrent(
+    input: String,
+    torrent_path: String,
+    piece_size: Option<u64>,
+    private: bool,
+    trackers: Vec<String>,
+    web_seeds: Vec<String>,
+    comment: Option<String>,
+    seed: bool,
+) -> Result<CreatedTorrentInfo, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let options = nebula_core::torrent_creator::TorrentCreateOptions {
+        piece_size,
+        private,
+        trackers,
+        web_seeds,
+        comment,
+        ..Default::default()
+    };
+
+    let input = PathBuf::from(&input);
+    let torrent_path = PathBuf::from(&torrent_path);
+    let created = manager
+        .create_torrent(&input, &torrent_path, options)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let seed_task_id = if seed {
+        let task_id = manager
+            .seed_torrent(torrent_path.clone(), &input)
+            .await
+            .map_err(|e| e.to_string())?;
+        Some(task_id.to_string())
+    } else {
+        None
+    };
+
+    Ok(CreatedTorrentInfo {
+        info_hash: created.info_hash_hex(),
+        name: created.name,
+        magnet: created.magnet,
+        torrent_path: torrent_path.to_string_lossy().to_string(),
+        total_size: created.total_size,
+        piece_size: created.piece_size,
+        piece_count: created.piece_count as u64,
+        file_count: created.file_count as u64,
+        seed_task_id,
+    })
+}
+
+// ===== 种子元数据相关 API =====
+
+/// 种子中的文件
+#[frb(dart_metadata = ("freezed"))]
+pub struct TorrentFileInfo {
+    pub index: u64,
+    pub path: String,
+    pub size: u64,
+}
+
+/// 解析出的种子元数据
+#[frb(dart_metadata = ("freezed"))]
+pub struct ResolvedTorrentInfo {
+    pub name: String,
+    pub info_hash: String,
+    pub total_size: u64,
+    pub piece_size: u64,
+    pub private: bool,
+    pub files: Vec<TorrentFileInfo>,
+}
+
+/// 仅解析种子元数据，不开始下载
+///
+/// 磁力链接需要从 Peer 获取元数据，可能耗时较长
+#[frb]
+pub async fn resolve_torrent(source: String) -> Result<ResolvedTorrentInfo, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let meta = manager
+        .resolve_torrent(&source)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(ResolvedTorrentInfo {
+        info_hash: meta.info_hash_hex(),
+        name: meta.name,
+        total_size: meta.total_size,
+        piece_size: meta.piece_length,
+        private: meta.private,
+        files: meta
+            .files
+            .into_iter()
+            .map(|f| TorrentFileInfo {
+                index: f.index as u64,
+                path: f.path,
+                size: f.length,
+            })
+            .collect(),
+    })
+}
+
+/// 添加种子任务，只下载选中的文件
+///
+/// `file_indices` 为 [`resolve_torrent`] 返回的文件序号
+#[frb]
+pub async fn add_download_with_files(
+    source: String,
+    save_path: String,
+    file_indices: Vec<u64>,
+) -> Result<String, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let options = nebula_core::AddTaskOptions::new()
+        .with_only_files(file_indices.into_iter().map(|i| i as usize).collect());
+    let task_id = manager
+        .add_task_with_options(&source, PathBuf::from(&save_path), options)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(task_id.to_string())
+}
+
+/// 将已解析的元数据导出为 .torrent 文件
+#[frb]
+pub async fn export_resolved_torrent(source: String, torrent_path: String) -> Result<(), String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    manager
+        .export_resolved_torrent(&source, &PathBuf::from(&torrent_path))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 将种子任务导出为 .torrent 文件
+#[frb]
+pub async fn export_torrent(task_id: String, torrent_path: String) -> Result<(), String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let id = nebula_core::TaskId::from_string(&task_id)
+        .map_err(|e| format!("无效的任务 ID: {}", e))?;
+
+    manager
+        .export_torrent(id, &PathBuf::from(&torrent_path))
+        .await
+        .map_err(|e| e.to_string())
+}
+
 // ===== Bilibili 登录相关 API =====
 
 /// Bilibili 二维码数据
@@ -371,6 +1217,225 @@ pub async fn logout_bilibili(data_dir: String) -> Result<(), String> {
     auth.logout().await.map_err(|e| e.to_string())
 }
 
+/// Bilibili 账号信息
+#[frb(dart_metadata = ("freezed"))]
+pub struct BilibiliAccountInfo {
+    pub mid: u64,
+    /// 昵称
+    pub username: String,
+    /// 头像 URL
+    pub avatar: String,
+    pub level: u32,
+    /// 是否为有效的大会员
+    pub vip_active: bool,
+    /// 大会员类型：0 无、1 月度大会员、2 年度及以上大会员
+    pub vip_type: u32,
+    /// 大会员到期时间（Unix 时间戳，秒）
+    pub vip_expires_at: Option<i64>,
+    /// 大会员标签（如「年度大会员」）
+    pub vip_label: Option<String>,
+}
+
+/// Bilibili 登录会话状态
+pub enum BilibiliSessionStatus {
+    /// 未登录
+    NotLoggedIn,
+    /// Cookie 有效
+    Valid,
+    /// Cookie 已刷新
+    Refreshed,
+    /// 登录已失效，需要重新扫码登录
+    Expired,
+}
+
+/// 获取 Bilibili 账号信息（昵称、大会员状态、头像），未登录时返回 None
+#[frb]
+pub async fn get_bilibili_account(data_dir: String) -> Result<Option<BilibiliAccountInfo>, String> {
+    use nebula_core::protocol::bilibili::BilibiliAuth;
+
+    let auth = BilibiliAuth::new(PathBuf::from(data_dir));
+    let account = auth.account_info().await.map_err(|e| e.to_string())?;
+    Ok(account.map(|a| BilibiliAccountInfo {
+        mid: a.mid,
+        username: a.username,
+        avatar: a.avatar,
+        level: a.level,
+        vip_active: a.vip_active,
+        vip_type: a.vip_type,
+        vip_expires_at: a.vip_expires_at,
+        vip_label: a.vip_label,
+    }))
+}
+
+/// 检查 Bilibili 登录状态，Cookie 临近过期时自动刷新
+///
+/// 下载管理器已初始化时，登录失效还会通过事件流发送 `BilibiliLoginExpired`
+#[frb]
+pub async fn refresh_bilibili_session(data_dir: String) -> Result<BilibiliSessionStatus, String> {
+    use nebula_core::protocol::bilibili::{BilibiliAuth, SessionStatus};
+
+    let status = {
+        let guard = MANAGER.read().await;
+        match guard.as_ref() {
+            Some(manager) => manager.check_bilibili_session().await,
+            None => BilibiliAuth::new(PathBuf::from(data_dir)).refresh_if_needed().await,
+        }
+    }
+    .map_err(|e| e.to_string())?;
+
+    Ok(match status {
+        SessionStatus::NotLoggedIn => BilibiliSessionStatus::NotLoggedIn,
+        SessionStatus::Valid => BilibiliSessionStatus::Valid,
+        SessionStatus::Refreshed => BilibiliSessionStatus::Refreshed,
+        SessionStatus::Expired => BilibiliSessionStatus::Expired,
+    })
+}
+
+// ===== 站点凭据相关 API =====
+
+/// 凭据保险库状态
+#[frb(dart_metadata = ("freezed"))]
+pub struct CredentialVaultStatus {
+    /// 是否已解锁（未解锁时下载不会注入站点凭据）
+    pub unlocked: bool,
+    /// 已保存的凭据是否使用口令加密
+    pub requires_passphrase: bool,
+}
+
+/// 站点凭据内容（保存时使用）
+#[frb(dart_metadata = ("freezed"))]
+pub enum SiteCredentialInput {
+    /// Cookie，格式为 `name=value; name2=value2`
+    Cookie { cookies: String },
+    /// Bearer Token
+    Bearer { token: String },
+    /// 用户名和密码（HTTP Basic 认证、FTP 登录、yt-dlp 账号登录）
+    Basic { username: String, password: String },
+    /// 自定义请求头
+    Header { name: String, value: String },
+}
+
+/// 已保存的站点凭据（不包含密码、Token 等敏感内容）
+#[frb(dart_metadata = ("freezed"))]
+pub struct SiteCredentialInfo {
+    pub domain: String,
+    /// 是否同时用于子域名
+    pub include_subdomains: bool,
+    /// 类型：cookie / bearer / basic / header
+    pub kind: String,
+    /// 用户名（仅 basic）
+    pub username: Option<String>,
+    /// 请求头名称（仅 header）
+    pub header_name: Option<String>,
+    /// 更新时间（Unix 时间戳，秒）
+    pub updated_at: i64,
+}
+
+impl From<SiteCredential> for SiteCredentialInfo {
+    fn from(entry: SiteCredential) -> Self {
+        let (username, header_name) = match &entry.credential {
+            Credential::Basic { username, .. } => (Some(username.clone()), None),
+            Credential::Header { name, .. } => (None, Some(name.clone())),
+            _ => (None, None),
+        };
+        Self {
+            kind: entry.credential.kind().name().to_string(),
+            domain: entry.domain,
+            include_subdomains: entry.include_subdomains,
+            username,
+            header_name,
+            updated_at: entry.updated_at,
+        }
+    }
+}
+
+/// 获取凭据保险库状态
+#[frb]
+pub async fn get_credential_vault_status() -> Result<CredentialVaultStatus, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let vault = manager.credential_vault();
+    Ok(CredentialVaultStatus {
+        unlocked: vault.is_unlocked().await,
+        requires_passphrase: vault.requires_passphrase().await,
+    })
+}
+
+/// 用口令解锁凭据保险库（还没有保存过凭据时用该口令新建）
+#[frb]
+pub async fn unlock_credential_vault(passphrase: String) -> Result<(), String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    manager
+        .unlock_credentials(&passphrase)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 列出已保存的站点凭据
+#[frb]
+pub async fn list_site_credentials() -> Result<Vec<SiteCredentialInfo>, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let entries = manager
+        .credential_vault()
+        .list()
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(entries.into_iter().map(SiteCredentialInfo::from).collect())
+}
+
+/// 保存站点凭据，替换同一域名下的同类凭据
+#[frb]
+pub async fn set_site_credential(
+    domain: String,
+    include_subdomains: bool,
+    credential: SiteCredentialInput,
+) -> Result<(), String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let credential = match credential {
+        SiteCredentialInput::Cookie { cookies } => Credential::Cookie { cookies },
+        SiteCredentialInput::Bearer { token } => Credential::Bearer { token },
+        SiteCredentialInput::Basic { username, password } => {
+            Credential::Basic { username, password }
+        }
+        SiteCredentialInput::Header { name, value } => Credential::Header { name, value },
+    };
+    manager
+        .credential_vault()
+        .set(SiteCredential::new(&domain, credential).with_subdomains(include_subdomains))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 删除站点凭据，`kind` 为空时删除该域名的所有凭据
+///
+/// # 返回
+/// 删除的凭据数量
+#[frb]
+pub async fn remove_site_credential(domain: String, kind: Option<String>) -> Result<u32, String> {
+    let guard = MANAGER.read().await;
+    let manager = guard.as_ref().ok_or("下载管理器未初始化")?;
+
+    let kind = match kind {
+        Some(name) => {
+            Some(CredentialKind::from_name(&name).ok_or(format!("未知的凭据类型: {}", name))?)
+        }
+        None => None,
+    };
+    manager
+        .credential_vault()
+        .remove(&domain, kind)
+        .await
+        .map(|removed| removed as u32)
+        .map_err(|e| e.to_string())
+}
+
 /// 打开任务对应的文件
 #[frb]
 pub async fn open_file(task_id: String) -> Result<(), String> {
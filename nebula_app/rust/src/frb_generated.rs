@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 222029928;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -2087927618;
 
 // Section: executor
 
@@ -83,7 +83,7 @@ fn wire__crate__api__download__add_download_impl(
         },
     )
 }
-fn wire__crate__api__download__add_video_download_impl(
+fn wire__crate__api__download__add_download_with_existing_data_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -91,7 +91,7 @@ fn wire__crate__api__download__add_video_download_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "add_video_download",
+            debug_name: "add_download_with_existing_data",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -105,21 +105,58 @@ fn wire__crate__api__download__add_video_download_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_source = <String>::sse_decode(&mut deserializer);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::add_download_with_existing_data(
+                            api_source,
+                            api_data_dir,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__add_download_with_files_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_download_with_files",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source = <String>::sse_decode(&mut deserializer);
             let api_save_path = <String>::sse_decode(&mut deserializer);
-            let api_format_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_title = <Option<String>>::sse_decode(&mut deserializer);
-            let api_thumbnail = <Option<String>>::sse_decode(&mut deserializer);
+            let api_file_indices = <Vec<u64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::download::add_video_download(
-                            api_url,
+                        let output_ok = crate::api::download::add_download_with_files(
+                            api_source,
                             api_save_path,
-                            api_format_id,
-                            api_title,
-                            api_thumbnail,
+                            api_file_indices,
                         )
                         .await?;
                         Ok(output_ok)
@@ -130,7 +167,7 @@ fn wire__crate__api__download__add_video_download_impl(
         },
     )
 }
-fn wire__crate__api__download__cancel_download_impl(
+fn wire__crate__api__download__add_download_with_policy_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -138,7 +175,7 @@ fn wire__crate__api__download__cancel_download_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cancel_download",
+            debug_name: "add_download_with_policy",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -152,15 +189,20 @@ fn wire__crate__api__download__cancel_download_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_task_id = <String>::sse_decode(&mut deserializer);
-            let api_delete_files = <bool>::sse_decode(&mut deserializer);
+            let api_source = <String>::sse_decode(&mut deserializer);
+            let api_save_path = <String>::sse_decode(&mut deserializer);
+            let api_on_duplicate =
+                <crate::api::download::DuplicateAction>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::download::cancel_download(api_task_id, api_delete_files)
-                                .await?;
+                        let output_ok = crate::api::download::add_download_with_policy(
+                            api_source,
+                            api_save_path,
+                            api_on_duplicate,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -169,7 +211,7 @@ fn wire__crate__api__download__cancel_download_impl(
         },
     )
 }
-fn wire__crate__api__download__generate_bilibili_qrcode_impl(
+fn wire__crate__api__download__add_playlist_download_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -177,7 +219,7 @@ fn wire__crate__api__download__generate_bilibili_qrcode_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "generate_bilibili_qrcode",
+            debug_name: "add_playlist_download",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -191,13 +233,65 @@ fn wire__crate__api__download__generate_bilibili_qrcode_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_save_path = <String>::sse_decode(&mut deserializer);
+            let api_items = <Option<String>>::sse_decode(&mut deserializer);
+            let api_format_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_only_new = <bool>::sse_decode(&mut deserializer);
+            let api_options =
+                <Option<crate::api::download::VideoDownloadOptions>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::add_playlist_download(
+                            api_url,
+                            api_save_path,
+                            api_items,
+                            api_format_id,
+                            api_only_new,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__add_torrent_trackers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_torrent_trackers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            let api_urls = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::download::generate_bilibili_qrcode(api_data_dir).await?;
+                            crate::api::download::add_torrent_trackers(api_task_id, api_urls)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -206,7 +300,7 @@ fn wire__crate__api__download__generate_bilibili_qrcode_impl(
         },
     )
 }
-fn wire__crate__api__download__get_video_info_impl(
+fn wire__crate__api__download__add_video_download_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -214,7 +308,7 @@ fn wire__crate__api__download__get_video_info_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_video_info",
+            debug_name: "add_video_download",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -229,11 +323,25 @@ fn wire__crate__api__download__get_video_info_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_url = <String>::sse_decode(&mut deserializer);
+            let api_save_path = <String>::sse_decode(&mut deserializer);
+            let api_format_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_title = <Option<String>>::sse_decode(&mut deserializer);
+            let api_thumbnail = <Option<String>>::sse_decode(&mut deserializer);
+            let api_options =
+                <Option<crate::api::download::VideoDownloadOptions>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::download::get_video_info(api_url).await?;
+                        let output_ok = crate::api::download::add_video_download(
+                            api_url,
+                            api_save_path,
+                            api_format_id,
+                            api_title,
+                            api_thumbnail,
+                            api_options,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -242,16 +350,17 @@ fn wire__crate__api__download__get_video_info_impl(
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__download__cancel_download_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "cancel_download",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -263,24 +372,32 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            let api_delete_files = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download::cancel_download(api_task_id, api_delete_files)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__download__check_yt_dlp_update_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "check_yt_dlp_update",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -295,18 +412,19 @@ fn wire__crate__api__simple__init_app_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::check_yt_dlp_update().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__download__init_download_manager_impl(
+fn wire__crate__api__download__create_torrent_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -314,7 +432,7 @@ fn wire__crate__api__download__init_download_manager_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_download_manager",
+            debug_name: "create_torrent",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -328,13 +446,29 @@ fn wire__crate__api__download__init_download_manager_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_download_dir = <String>::sse_decode(&mut deserializer);
+            let api_input = <String>::sse_decode(&mut deserializer);
+            let api_torrent_path = <String>::sse_decode(&mut deserializer);
+            let api_piece_size = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_private = <bool>::sse_decode(&mut deserializer);
+            let api_trackers = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_web_seeds = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_comment = <Option<String>>::sse_decode(&mut deserializer);
+            let api_seed = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::download::init_download_manager(api_download_dir).await?;
+                        let output_ok = crate::api::download::create_torrent(
+                            api_input,
+                            api_torrent_path,
+                            api_piece_size,
+                            api_private,
+                            api_trackers,
+                            api_web_seeds,
+                            api_comment,
+                            api_seed,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -343,7 +477,7 @@ fn wire__crate__api__download__init_download_manager_impl(
         },
     )
 }
-fn wire__crate__api__download__is_bilibili_logged_in_impl(
+fn wire__crate__api__download__export_resolved_torrent_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -351,7 +485,7 @@ fn wire__crate__api__download__is_bilibili_logged_in_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_bilibili_logged_in",
+            debug_name: "export_resolved_torrent",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -365,14 +499,17 @@ fn wire__crate__api__download__is_bilibili_logged_in_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_source = <String>::sse_decode(&mut deserializer);
+            let api_torrent_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::download::is_bilibili_logged_in(api_data_dir).await,
-                        )?;
+                        let output_ok = crate::api::download::export_resolved_torrent(
+                            api_source,
+                            api_torrent_path,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -381,15 +518,15 @@ fn wire__crate__api__download__is_bilibili_logged_in_impl(
         },
     )
 }
-fn wire__crate__api__download__is_video_url_impl(
+fn wire__crate__api__download__export_torrent_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_video_url",
+            debug_name: "export_torrent",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -403,19 +540,24 @@ fn wire__crate__api__download__is_video_url_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            let api_torrent_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::download::is_video_url(api_url))?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download::export_torrent(api_task_id, api_torrent_path)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__download__logout_bilibili_impl(
+fn wire__crate__api__download__generate_bilibili_qrcode_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -423,7 +565,7 @@ fn wire__crate__api__download__logout_bilibili_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "logout_bilibili",
+            debug_name: "generate_bilibili_qrcode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -442,7 +584,8 @@ fn wire__crate__api__download__logout_bilibili_impl(
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::download::logout_bilibili(api_data_dir).await?;
+                        let output_ok =
+                            crate::api::download::generate_bilibili_qrcode(api_data_dir).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -451,7 +594,7 @@ fn wire__crate__api__download__logout_bilibili_impl(
         },
     )
 }
-fn wire__crate__api__download__open_file_impl(
+fn wire__crate__api__download__get_bilibili_account_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -459,7 +602,7 @@ fn wire__crate__api__download__open_file_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "open_file",
+            debug_name: "get_bilibili_account",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -473,12 +616,13 @@ fn wire__crate__api__download__open_file_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_task_id = <String>::sse_decode(&mut deserializer);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::download::open_file(api_task_id).await?;
+                        let output_ok =
+                            crate::api::download::get_bilibili_account(api_data_dir).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -487,7 +631,7 @@ fn wire__crate__api__download__open_file_impl(
         },
     )
 }
-fn wire__crate__api__download__open_folder_impl(
+fn wire__crate__api__download__get_credential_vault_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -495,7 +639,7 @@ fn wire__crate__api__download__open_folder_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "open_folder",
+            debug_name: "get_credential_vault_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -509,12 +653,11 @@ fn wire__crate__api__download__open_folder_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_task_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::download::open_folder(api_task_id).await?;
+                        let output_ok = crate::api::download::get_credential_vault_status().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -523,7 +666,7 @@ fn wire__crate__api__download__open_folder_impl(
         },
     )
 }
-fn wire__crate__api__download__pause_download_impl(
+fn wire__crate__api__download__get_ip_filter_stats_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -531,7 +674,7 @@ fn wire__crate__api__download__pause_download_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "pause_download",
+            debug_name: "get_ip_filter_stats",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -545,12 +688,11 @@ fn wire__crate__api__download__pause_download_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_task_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::download::pause_download(api_task_id).await?;
+                        let output_ok = crate::api::download::get_ip_filter_stats().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -559,7 +701,7 @@ fn wire__crate__api__download__pause_download_impl(
         },
     )
 }
-fn wire__crate__api__download__poll_bilibili_login_impl(
+fn wire__crate__api__download__get_playlist_info_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -567,7 +709,7 @@ fn wire__crate__api__download__poll_bilibili_login_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "poll_bilibili_login",
+            debug_name: "get_playlist_info",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -581,15 +723,12 @@ fn wire__crate__api__download__poll_bilibili_login_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data_dir = <String>::sse_decode(&mut deserializer);
-            let api_qrcode_key = <String>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::download::poll_bilibili_login(api_data_dir, api_qrcode_key)
-                                .await?;
+                        let output_ok = crate::api::download::get_playlist_info(api_url).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -598,7 +737,7 @@ fn wire__crate__api__download__poll_bilibili_login_impl(
         },
     )
 }
-fn wire__crate__api__download__resume_download_impl(
+fn wire__crate__api__download__get_tools_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -606,7 +745,7 @@ fn wire__crate__api__download__resume_download_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "resume_download",
+            debug_name: "get_tools_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -620,12 +759,11 @@ fn wire__crate__api__download__resume_download_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_task_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::download::resume_download(api_task_id).await?;
+                        let output_ok = crate::api::download::get_tools_status().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -634,7 +772,7 @@ fn wire__crate__api__download__resume_download_impl(
         },
     )
 }
-fn wire__crate__api__download__subscribe_events_impl(
+fn wire__crate__api__download__get_torrent_peers_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -642,7 +780,7 @@ fn wire__crate__api__download__subscribe_events_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "subscribe_events",
+            debug_name: "get_torrent_peers",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -656,15 +794,13 @@ fn wire__crate__api__download__subscribe_events_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink = <StreamSink<
-                crate::api::download::NebulaEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::download::subscribe_events(api_sink).await?;
+                        let output_ok =
+                            crate::api::download::get_torrent_peers(api_task_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -673,55 +809,2186 @@ fn wire__crate__api__download__subscribe_events_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
+fn wire__crate__api__download__get_torrent_trackers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_torrent_trackers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            let api_refresh = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download::get_torrent_trackers(api_task_id, api_refresh)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__get_video_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_video_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::get_video_info(api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__download__init_download_manager_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_download_manager",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_download_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download::init_download_manager(api_download_dir).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__is_bilibili_logged_in_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_bilibili_logged_in",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::download::is_bilibili_logged_in(api_data_dir).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__is_video_url_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_video_url",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::download::is_video_url(api_url).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__list_site_credentials_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_site_credentials",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::list_site_credentials().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__logout_bilibili_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "logout_bilibili",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::logout_bilibili(api_data_dir).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__open_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::open_file(api_task_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__open_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::open_folder(api_task_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__pause_download_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pause_download",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::pause_download(api_task_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__poll_bilibili_login_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "poll_bilibili_login",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_qrcode_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download::poll_bilibili_login(api_data_dir, api_qrcode_key)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__probe_video_extractor_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "probe_video_extractor",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download::probe_video_extractor(api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__recheck_download_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "recheck_download",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::recheck_download(api_task_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__refresh_bilibili_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "refresh_bilibili_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download::refresh_bilibili_session(api_data_dir).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__remove_download_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_download",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::remove_download(api_task_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__remove_site_credential_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_site_credential",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_domain = <String>::sse_decode(&mut deserializer);
+            let api_kind = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download::remove_site_credential(api_domain, api_kind)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__remove_torrent_trackers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_torrent_trackers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            let api_urls = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download::remove_torrent_trackers(api_task_id, api_urls)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__resolve_torrent_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resolve_torrent",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::resolve_torrent(api_source).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__restart_download_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "restart_download",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::restart_download(api_task_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__resume_download_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_download",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::resume_download(api_task_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__set_site_credential_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_site_credential",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_domain = <String>::sse_decode(&mut deserializer);
+            let api_include_subdomains = <bool>::sse_decode(&mut deserializer);
+            let api_credential =
+                <crate::api::download::SiteCredentialInput>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::set_site_credential(
+                            api_domain,
+                            api_include_subdomains,
+                            api_credential,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__subscribe_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::download::NebulaEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::subscribe_events(api_sink).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__unlock_credential_vault_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unlock_credential_vault",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::download::unlock_credential_vault(api_passphrase).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__update_ip_filter_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_ip_filter",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::update_ip_filter().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__download__update_yt_dlp_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_yt_dlp",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::download::update_yt_dlp().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::download::NebulaEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::download::BilibiliAccountInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mid = <u64>::sse_decode(deserializer);
+        let mut var_username = <String>::sse_decode(deserializer);
+        let mut var_avatar = <String>::sse_decode(deserializer);
+        let mut var_level = <u32>::sse_decode(deserializer);
+        let mut var_vipActive = <bool>::sse_decode(deserializer);
+        let mut var_vipType = <u32>::sse_decode(deserializer);
+        let mut var_vipExpiresAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_vipLabel = <Option<String>>::sse_decode(deserializer);
+        return crate::api::download::BilibiliAccountInfo {
+            mid: var_mid,
+            username: var_username,
+            avatar: var_avatar,
+            level: var_level,
+            vip_active: var_vipActive,
+            vip_type: var_vipType,
+            vip_expires_at: var_vipExpiresAt,
+            vip_label: var_vipLabel,
+        };
+    }
+}
+
+impl SseDecode for crate::api::download::BilibiliLoginStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::download::BilibiliLoginStatus::WaitingScan;
+            }
+            1 => {
+                return crate::api::download::BilibiliLoginStatus::WaitingConfirm;
+            }
+            2 => {
+                return crate::api::download::BilibiliLoginStatus::Success;
+            }
+            3 => {
+                return crate::api::download::BilibiliLoginStatus::Expired;
+            }
+            4 => {
+                let mut var_error = <String>::sse_decode(deserializer);
+                return crate::api::download::BilibiliLoginStatus::Failed { error: var_error };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::download::BilibiliQrCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_qrcodeKey = <String>::sse_decode(deserializer);
+        return crate::api::download::BilibiliQrCode {
+            url: var_url,
+            qrcode_key: var_qrcodeKey,
+        };
+    }
+}
+
+impl SseDecode for crate::api::download::BilibiliSessionStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::download::BilibiliSessionStatus::NotLoggedIn,
+            1 => crate::api::download::BilibiliSessionStatus::Valid,
+            2 => crate::api::download::BilibiliSessionStatus::Refreshed,
+            3 => crate::api::download::BilibiliSessionStatus::Expired,
+            _ => unreachable!("Invalid variant for BilibiliSessionStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::download::CreatedTorrentInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_infoHash = <String>::sse_decode(deserializer);
+        let mut var_magnet = <String>::sse_decode(deserializer);
+        let mut var_torrentPath = <String>::sse_decode(deserializer);
+        let mut var_totalSize = <u64>::sse_decode(deserializer);
+        let mut var_pieceSize = <u64>::sse_decode(deserializer);
+        let mut var_pieceCount = <u64>::sse_decode(deserializer);
+        let mut var_fileCount = <u64>::sse_decode(deserializer);
+        let mut var_seedTaskId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::download::CreatedTorrentInfo {
+            name: var_name,
+            info_hash: var_infoHash,
+            magnet: var_magnet,
+            torrent_path: var_torrentPath,
+            total_size: var_totalSize,
+            piece_size: var_pieceSize,
+            piece_count: var_pieceCount,
+            file_count: var_fileCount,
+            seed_task_id: var_seedTaskId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::download::CredentialVaultStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_unlocked = <bool>::sse_decode(deserializer);
+        let mut var_requiresPassphrase = <bool>::sse_decode(deserializer);
+        return crate::api::download::CredentialVaultStatus {
+            unlocked: var_unlocked,
+            requires_passphrase: var_requiresPassphrase,
+        };
+    }
+}
+
+impl SseDecode for crate::api::download::DuplicateAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::download::DuplicateAction::Reject,
+            1 => crate::api::download::DuplicateAction::ReturnExisting,
+            2 => crate::api::download::DuplicateAction::Restart,
+            _ => unreachable!("Invalid variant for DuplicateAction: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::download::IpFilterInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rangeCount = <u64>::sse_decode(deserializer);
+        let mut var_blockedHandoffPeers = <u64>::sse_decode(deserializer);
+        let mut var_lastUpdated = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::download::IpFilterInfo {
+            range_count: var_rangeCount,
+            blocked_handoff_peers: var_blockedHandoffPeers,
+            last_updated: var_lastUpdated,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::download::PeerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::download::PeerInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::download::PlaylistEntryInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::download::PlaylistEntryInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::download::SiteCredentialInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::download::SiteCredentialInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::download::ToolStatusInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::download::ToolStatusInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::download::TorrentFileInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::download::TorrentFileInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::download::TrackerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::download::TrackerInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::download::VideoFormat> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::download::VideoFormat>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::download::NebulaEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                let mut var_name = <String>::sse_decode(deserializer);
+                let mut var_thumbnail = <Option<String>>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::TaskAdded {
+                    task_id: var_taskId,
+                    name: var_name,
+                    thumbnail: var_thumbnail,
+                };
+            }
+            1 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::TaskStarted {
+                    task_id: var_taskId,
+                };
+            }
+            2 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                let mut var_progress =
+                    <crate::api::download::ProgressEvent>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::ProgressUpdated {
+                    task_id: var_taskId,
+                    progress: var_progress,
+                };
+            }
+            3 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::TaskCompleted {
+                    task_id: var_taskId,
+                };
+            }
+            4 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                let mut var_error = <String>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::TaskFailed {
+                    task_id: var_taskId,
+                    error: var_error,
+                };
+            }
+            5 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::TaskPaused {
+                    task_id: var_taskId,
+                };
+            }
+            6 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::TaskResumed {
+                    task_id: var_taskId,
+                };
+            }
+            7 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::TaskRemoved {
+                    task_id: var_taskId,
+                };
+            }
+            8 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                let mut var_name = <String>::sse_decode(deserializer);
+                let mut var_totalSize = <u64>::sse_decode(deserializer);
+                let mut var_fileCount = <usize>::sse_decode(deserializer);
+                let mut var_mimeType = <Option<String>>::sse_decode(deserializer);
+                let mut var_supportsResume = <bool>::sse_decode(deserializer);
+                let mut var_thumbnail = <Option<String>>::sse_decode(deserializer);
+                let mut var_durationSecs = <Option<u64>>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::MetadataReceived {
+                    task_id: var_taskId,
+                    name: var_name,
+                    total_size: var_totalSize,
+                    file_count: var_fileCount,
+                    mime_type: var_mimeType,
+                    supports_resume: var_supportsResume,
+                    thumbnail: var_thumbnail,
+                    duration_secs: var_durationSecs,
+                };
+            }
+            9 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                let mut var_connectedPeers = <usize>::sse_decode(deserializer);
+                let mut var_totalPeers = <usize>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::PeerUpdate {
+                    task_id: var_taskId,
+                    connected_peers: var_connectedPeers,
+                    total_peers: var_totalPeers,
+                };
+            }
+            10 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                let mut var_checkedBytes = <u64>::sse_decode(deserializer);
+                let mut var_totalBytes = <u64>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::CheckProgress {
+                    task_id: var_taskId,
+                    checked_bytes: var_checkedBytes,
+                    total_bytes: var_totalBytes,
+                };
+            }
+            11 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                let mut var_validBytes = <u64>::sse_decode(deserializer);
+                let mut var_totalBytes = <u64>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::CheckCompleted {
+                    task_id: var_taskId,
+                    valid_bytes: var_validBytes,
+                    total_bytes: var_totalBytes,
+                };
+            }
+            12 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                let mut var_phase = <String>::sse_decode(deserializer);
+                let mut var_stalledSecs = <u64>::sse_decode(deserializer);
+                let mut var_dhtNodes = <Option<u64>>::sse_decode(deserializer);
+                let mut var_trackersTotal = <u64>::sse_decode(deserializer);
+                let mut var_trackersReached = <u64>::sse_decode(deserializer);
+                let mut var_peersSeen = <u64>::sse_decode(deserializer);
+                let mut var_peersConnected = <u64>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::TaskStalled {
+                    task_id: var_taskId,
+                    phase: var_phase,
+                    stalled_secs: var_stalledSecs,
+                    dht_nodes: var_dhtNodes,
+                    trackers_total: var_trackersTotal,
+                    trackers_reached: var_trackersReached,
+                    peers_seen: var_peersSeen,
+                    peers_connected: var_peersConnected,
+                };
+            }
+            13 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                let mut var_phase = <String>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::StallRecovered {
+                    task_id: var_taskId,
+                    phase: var_phase,
+                };
+            }
+            14 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                let mut var_stage = <String>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::VideoStageChanged {
+                    task_id: var_taskId,
+                    stage: var_stage,
+                };
+            }
+            15 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                let mut var_path = <String>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::OutputFileResolved {
+                    task_id: var_taskId,
+                    path: var_path,
+                };
+            }
+            16 => {
+                let mut var_taskId = <String>::sse_decode(deserializer);
+                let mut var_from = <String>::sse_decode(deserializer);
+                let mut var_to = <String>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::StatusChanged {
+                    task_id: var_taskId,
+                    from: var_from,
+                    to: var_to,
+                };
+            }
+            17 => {
+                let mut var_reason = <String>::sse_decode(deserializer);
+                return crate::api::download::NebulaEvent::BilibiliLoginExpired {
+                    reason: var_reason,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::download::BilibiliAccountInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::download::BilibiliAccountInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::download::IpFilterInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::download::IpFilterInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::download::VideoDownloadOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::download::VideoDownloadOptions>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::download::PeerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_client = <Option<String>>::sse_decode(deserializer);
+        let mut var_flags = <String>::sse_decode(deserializer);
+        let mut var_downloadSpeed = <u64>::sse_decode(deserializer);
+        let mut var_uploadSpeed = <u64>::sse_decode(deserializer);
+        let mut var_downloaded = <u64>::sse_decode(deserializer);
+        let mut var_uploaded = <u64>::sse_decode(deserializer);
+        let mut var_progress = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::download::PeerInfo {
+            address: var_address,
+            client: var_client,
+            flags: var_flags,
+            download_speed: var_downloadSpeed,
+            upload_speed: var_uploadSpeed,
+            downloaded: var_downloaded,
+            uploaded: var_uploaded,
+            progress: var_progress,
+        };
+    }
+}
+
+impl SseDecode for crate::api::download::PlaylistEntryInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <u32>::sse_decode(deserializer);
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_duration = <Option<u64>>::sse_decode(deserializer);
+        let mut var_uploader = <Option<String>>::sse_decode(deserializer);
+        return crate::api::download::PlaylistEntryInfo {
+            index: var_index,
+            id: var_id,
+            title: var_title,
+            url: var_url,
+            duration: var_duration,
+            uploader: var_uploader,
+        };
+    }
+}
+
+impl SseDecode for crate::api::download::PlaylistInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_uploader = <Option<String>>::sse_decode(deserializer);
+        let mut var_entries =
+            <Vec<crate::api::download::PlaylistEntryInfo>>::sse_decode(deserializer);
+        return crate::api::download::PlaylistInfo {
+            id: var_id,
+            title: var_title,
+            uploader: var_uploader,
+            entries: var_entries,
+        };
+    }
+}
+
+impl SseDecode for crate::api::download::ProgressEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_taskId = <String>::sse_decode(deserializer);
+        let mut var_totalSize = <u64>::sse_decode(deserializer);
+        let mut var_downloadedSize = <u64>::sse_decode(deserializer);
+        let mut var_downloadSpeed = <u64>::sse_decode(deserializer);
+        let mut var_percentage = <f64>::sse_decode(deserializer);
+        let mut var_etaSecs = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::download::ProgressEvent {
+            task_id: var_taskId,
+            total_size: var_totalSize,
+            downloaded_size: var_downloadedSize,
+            download_speed: var_downloadSpeed,
+            percentage: var_percentage,
+            eta_secs: var_etaSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::download::ResolvedTorrentInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_infoHash = <String>::sse_decode(deserializer);
+        let mut var_totalSize = <u64>::sse_decode(deserializer);
+        let mut var_pieceSize = <u64>::sse_decode(deserializer);
+        let mut var_private = <bool>::sse_decode(deserializer);
+        let mut var_files = <Vec<crate::api::download::TorrentFileInfo>>::sse_decode(deserializer);
+        return crate::api::download::ResolvedTorrentInfo {
+            name: var_name,
+            info_hash: var_infoHash,
+            total_size: var_totalSize,
+            piece_size: var_pieceSize,
+            private: var_private,
+            files: var_files,
+        };
+    }
+}
+
+impl SseDecode for crate::api::download::SiteCredentialInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_domain = <String>::sse_decode(deserializer);
+        let mut var_includeSubdomains = <bool>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_username = <Option<String>>::sse_decode(deserializer);
+        let mut var_headerName = <Option<String>>::sse_decode(deserializer);
+        let mut var_updatedAt = <i64>::sse_decode(deserializer);
+        return crate::api::download::SiteCredentialInfo {
+            domain: var_domain,
+            include_subdomains: var_includeSubdomains,
+            kind: var_kind,
+            username: var_username,
+            header_name: var_headerName,
+            updated_at: var_updatedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::download::SiteCredentialInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_cookies = <String>::sse_decode(deserializer);
+                return crate::api::download::SiteCredentialInput::Cookie {
+                    cookies: var_cookies,
+                };
+            }
+            1 => {
+                let mut var_token = <String>::sse_decode(deserializer);
+                return crate::api::download::SiteCredentialInput::Bearer { token: var_token };
+            }
+            2 => {
+                let mut var_username = <String>::sse_decode(deserializer);
+                let mut var_password = <String>::sse_decode(deserializer);
+                return crate::api::download::SiteCredentialInput::Basic {
+                    username: var_username,
+                    password: var_password,
+                };
+            }
+            3 => {
+                let mut var_name = <String>::sse_decode(deserializer);
+                let mut var_value = <String>::sse_decode(deserializer);
+                return crate::api::download::SiteCredentialInput::Header {
+                    name: var_name,
+                    value: var_value,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::download::ToolStatusInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_version = <Option<String>>::sse_decode(deserializer);
+        let mut var_source = <String>::sse_decode(deserializer);
+        return crate::api::download::ToolStatusInfo {
+            name: var_name,
+            path: var_path,
+            version: var_version,
+            source: var_source,
+        };
+    }
+}
+
+impl SseDecode for crate::api::download::ToolUpdateInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_current = <Option<String>>::sse_decode(deserializer);
+        let mut var_latest = <String>::sse_decode(deserializer);
+        let mut var_updateAvailable = <bool>::sse_decode(deserializer);
+        return crate::api::download::ToolUpdateInfo {
+            current: var_current,
+            latest: var_latest,
+            update_available: var_updateAvailable,
+        };
+    }
+}
+
+impl SseDecode for crate::api::download::TorrentFileInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <u64>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        return crate::api::download::TorrentFileInfo {
+            index: var_index,
+            path: var_path,
+            size: var_size,
+        };
+    }
+}
+
+impl SseDecode for crate::api::download::TrackerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_source = <String>::sse_decode(deserializer);
+        let mut var_status = <crate::api::download::TrackerStatusInfo>::sse_decode(deserializer);
+        let mut var_seeders = <Option<u32>>::sse_decode(deserializer);
+        let mut var_leechers = <Option<u32>>::sse_decode(deserializer);
+        let mut var_completed = <Option<u32>>::sse_decode(deserializer);
+        let mut var_lastUpdated = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::download::TrackerInfo {
+            url: var_url,
+            source: var_source,
+            status: var_status,
+            seeders: var_seeders,
+            leechers: var_leechers,
+            completed: var_completed,
+            last_updated: var_lastUpdated,
+        };
+    }
+}
+
+impl SseDecode for crate::api::download::TrackerStatusInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::download::TrackerStatusInfo::NotContacted;
+            }
+            1 => {
+                return crate::api::download::TrackerStatusInfo::Updating;
+            }
+            2 => {
+                return crate::api::download::TrackerStatusInfo::Working;
+            }
+            3 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::download::TrackerStatusInfo::Error {
+                    message: var_message,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::download::VideoDownloadOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_subtitleLangs = <Vec<String>>::sse_decode(deserializer);
+        let mut var_autoSubtitles = <bool>::sse_decode(deserializer);
+        let mut var_danmaku = <bool>::sse_decode(deserializer);
+        let mut var_writeThumbnail = <bool>::sse_decode(deserializer);
+        let mut var_writeInfoJson = <bool>::sse_decode(deserializer);
+        let mut var_embedMetadata = <bool>::sse_decode(deserializer);
+        let mut var_embedThumbnail = <bool>::sse_decode(deserializer);
+        let mut var_embedChapters = <bool>::sse_decode(deserializer);
+        let mut var_audioFormat = <Option<String>>::sse_decode(deserializer);
+        let mut var_audioBitrateKbps = <Option<u32>>::sse_decode(deserializer);
+        let mut var_normalizeAudio = <bool>::sse_decode(deserializer);
+        let mut var_maxHeight = <Option<u32>>::sse_decode(deserializer);
+        let mut var_videoCodec = <Option<String>>::sse_decode(deserializer);
+        let mut var_container = <Option<String>>::sse_decode(deserializer);
+        let mut var_preferHdr = <bool>::sse_decode(deserializer);
+        let mut var_maxFps = <Option<u32>>::sse_decode(deserializer);
+        let mut var_audioLanguage = <Option<String>>::sse_decode(deserializer);
+        let mut var_maxFilesizeMb = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::download::VideoDownloadOptions {
+            subtitle_langs: var_subtitleLangs,
+            auto_subtitles: var_autoSubtitles,
+            danmaku: var_danmaku,
+            write_thumbnail: var_writeThumbnail,
+            write_info_json: var_writeInfoJson,
+            embed_metadata: var_embedMetadata,
+            embed_thumbnail: var_embedThumbnail,
+            embed_chapters: var_embedChapters,
+            audio_format: var_audioFormat,
+            audio_bitrate_kbps: var_audioBitrateKbps,
+            normalize_audio: var_normalizeAudio,
+            max_height: var_maxHeight,
+            video_codec: var_videoCodec,
+            container: var_container,
+            prefer_hdr: var_preferHdr,
+            max_fps: var_maxFps,
+            audio_language: var_audioLanguage,
+            max_filesize_mb: var_maxFilesizeMb,
+        };
+    }
+}
+
+impl SseDecode for crate::api::download::VideoFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_formatId = <String>::sse_decode(deserializer);
+        let mut var_ext = <String>::sse_decode(deserializer);
+        let mut var_resolution = <Option<String>>::sse_decode(deserializer);
+        let mut var_filesize = <Option<u64>>::sse_decode(deserializer);
+        let mut var_formatNote = <Option<String>>::sse_decode(deserializer);
+        let mut var_fps = <Option<f64>>::sse_decode(deserializer);
+        let mut var_vcodec = <Option<String>>::sse_decode(deserializer);
+        let mut var_acodec = <Option<String>>::sse_decode(deserializer);
+        let mut var_tbr = <Option<f64>>::sse_decode(deserializer);
+        let mut var_dynamicRange = <Option<String>>::sse_decode(deserializer);
+        let mut var_language = <Option<String>>::sse_decode(deserializer);
+        let mut var_audioOnly = <bool>::sse_decode(deserializer);
+        let mut var_videoOnly = <bool>::sse_decode(deserializer);
+        return crate::api::download::VideoFormat {
+            format_id: var_formatId,
+            ext: var_ext,
+            resolution: var_resolution,
+            filesize: var_filesize,
+            format_note: var_formatNote,
+            fps: var_fps,
+            vcodec: var_vcodec,
+            acodec: var_acodec,
+            tbr: var_tbr,
+            dynamic_range: var_dynamicRange,
+            language: var_language,
+            audio_only: var_audioOnly,
+            video_only: var_videoOnly,
+        };
     }
 }
 
-impl SseDecode
-    for StreamSink<crate::api::download::NebulaEvent, flutter_rust_bridge::for_generated::SseCodec>
-{
+impl SseDecode for crate::api::download::VideoInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_thumbnail = <Option<String>>::sse_decode(deserializer);
+        let mut var_duration = <Option<u64>>::sse_decode(deserializer);
+        let mut var_uploader = <Option<String>>::sse_decode(deserializer);
+        let mut var_formats = <Vec<crate::api::download::VideoFormat>>::sse_decode(deserializer);
+        return crate::api::download::VideoInfo {
+            id: var_id,
+            title: var_title,
+            thumbnail: var_thumbnail,
+            duration: var_duration,
+            uploader: var_uploader,
+            formats: var_formats,
+        };
     }
 }
 
-impl SseDecode for String {
+impl SseDecode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
     }
 }
 
-impl SseDecode for crate::api::download::BilibiliLoginStatus {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                return crate::api::download::BilibiliLoginStatus::WaitingScan;
-            }
-            1 => {
-                return crate::api::download::BilibiliLoginStatus::WaitingConfirm;
-            }
-            2 => {
-                return crate::api::download::BilibiliLoginStatus::Success;
-            }
-            3 => {
-                return crate::api::download::BilibiliLoginStatus::Expired;
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__download__add_download_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__download__add_download_with_existing_data_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        3 => wire__crate__api__download__add_download_with_files_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        4 => wire__crate__api__download__add_download_with_policy_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => wire__crate__api__download__add_playlist_download_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => {
+            wire__crate__api__download__add_torrent_trackers_impl(port, ptr, rust_vec_len, data_len)
+        }
+        7 => wire__crate__api__download__add_video_download_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__download__cancel_download_impl(port, ptr, rust_vec_len, data_len),
+        9 => {
+            wire__crate__api__download__check_yt_dlp_update_impl(port, ptr, rust_vec_len, data_len)
+        }
+        10 => wire__crate__api__download__create_torrent_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__download__export_resolved_torrent_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__download__export_torrent_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__download__generate_bilibili_qrcode_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => {
+            wire__crate__api__download__get_bilibili_account_impl(port, ptr, rust_vec_len, data_len)
+        }
+        15 => wire__crate__api__download__get_credential_vault_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        16 => {
+            wire__crate__api__download__get_ip_filter_stats_impl(port, ptr, rust_vec_len, data_len)
+        }
+        17 => wire__crate__api__download__get_playlist_info_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__download__get_tools_status_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__download__get_torrent_peers_impl(port, ptr, rust_vec_len, data_len),
+        20 => {
+            wire__crate__api__download__get_torrent_trackers_impl(port, ptr, rust_vec_len, data_len)
+        }
+        21 => wire__crate__api__download__get_video_info_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__download__init_download_manager_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        25 => wire__crate__api__download__is_bilibili_logged_in_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        26 => wire__crate__api__download__is_video_url_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__download__list_site_credentials_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        28 => wire__crate__api__download__logout_bilibili_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__download__open_file_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__download__open_folder_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__download__pause_download_impl(port, ptr, rust_vec_len, data_len),
+        32 => {
+            wire__crate__api__download__poll_bilibili_login_impl(port, ptr, rust_vec_len, data_len)
+        }
+        33 => wire__crate__api__download__probe_video_extractor_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        34 => wire__crate__api__download__recheck_download_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__download__refresh_bilibili_session_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        36 => wire__crate__api__download__remove_download_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__download__remove_site_credential_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => wire__crate__api__download__remove_torrent_trackers_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        39 => wire__crate__api__download__resolve_torrent_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__download__restart_download_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__download__resume_download_impl(port, ptr, rust_vec_len, data_len),
+        42 => {
+            wire__crate__api__download__set_site_credential_impl(port, ptr, rust_vec_len, data_len)
+        }
+        43 => wire__crate__api__download__subscribe_events_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__download__unlock_credential_vault_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        45 => wire__crate__api__download__update_ip_filter_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__download__update_yt_dlp_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        22 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::BilibiliAccountInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mid.into_into_dart().into_dart(),
+            self.username.into_into_dart().into_dart(),
+            self.avatar.into_into_dart().into_dart(),
+            self.level.into_into_dart().into_dart(),
+            self.vip_active.into_into_dart().into_dart(),
+            self.vip_type.into_into_dart().into_dart(),
+            self.vip_expires_at.into_into_dart().into_dart(),
+            self.vip_label.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::BilibiliAccountInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::BilibiliAccountInfo>
+    for crate::api::download::BilibiliAccountInfo
+{
+    fn into_into_dart(self) -> crate::api::download::BilibiliAccountInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::BilibiliLoginStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::download::BilibiliLoginStatus::WaitingScan => [0.into_dart()].into_dart(),
+            crate::api::download::BilibiliLoginStatus::WaitingConfirm => {
+                [1.into_dart()].into_dart()
             }
-            4 => {
-                let mut var_error = <String>::sse_decode(deserializer);
-                return crate::api::download::BilibiliLoginStatus::Failed { error: var_error };
+            crate::api::download::BilibiliLoginStatus::Success => [2.into_dart()].into_dart(),
+            crate::api::download::BilibiliLoginStatus::Expired => [3.into_dart()].into_dart(),
+            crate::api::download::BilibiliLoginStatus::Failed { error } => {
+                [4.into_dart(), error.into_into_dart().into_dart()].into_dart()
             }
             _ => {
                 unimplemented!("");
@@ -729,142 +2996,298 @@ impl SseDecode for crate::api::download::BilibiliLoginStatus {
         }
     }
 }
-
-impl SseDecode for crate::api::download::BilibiliQrCode {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_url = <String>::sse_decode(deserializer);
-        let mut var_qrcodeKey = <String>::sse_decode(deserializer);
-        return crate::api::download::BilibiliQrCode {
-            url: var_url,
-            qrcode_key: var_qrcodeKey,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::BilibiliLoginStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::BilibiliLoginStatus>
+    for crate::api::download::BilibiliLoginStatus
+{
+    fn into_into_dart(self) -> crate::api::download::BilibiliLoginStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::BilibiliQrCode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.qrcode_key.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::BilibiliQrCode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::BilibiliQrCode>
+    for crate::api::download::BilibiliQrCode
+{
+    fn into_into_dart(self) -> crate::api::download::BilibiliQrCode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::BilibiliSessionStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::NotLoggedIn => 0.into_dart(),
+            Self::Valid => 1.into_dart(),
+            Self::Refreshed => 2.into_dart(),
+            Self::Expired => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::BilibiliSessionStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::BilibiliSessionStatus>
+    for crate::api::download::BilibiliSessionStatus
+{
+    fn into_into_dart(self) -> crate::api::download::BilibiliSessionStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::CreatedTorrentInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.info_hash.into_into_dart().into_dart(),
+            self.magnet.into_into_dart().into_dart(),
+            self.torrent_path.into_into_dart().into_dart(),
+            self.total_size.into_into_dart().into_dart(),
+            self.piece_size.into_into_dart().into_dart(),
+            self.piece_count.into_into_dart().into_dart(),
+            self.file_count.into_into_dart().into_dart(),
+            self.seed_task_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::CreatedTorrentInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::CreatedTorrentInfo>
+    for crate::api::download::CreatedTorrentInfo
+{
+    fn into_into_dart(self) -> crate::api::download::CreatedTorrentInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::CredentialVaultStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.unlocked.into_into_dart().into_dart(),
+            self.requires_passphrase.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::CredentialVaultStatus
+{
 }
-
-impl SseDecode for f64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::CredentialVaultStatus>
+    for crate::api::download::CredentialVaultStatus
+{
+    fn into_into_dart(self) -> crate::api::download::CredentialVaultStatus {
+        self
     }
 }
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::DuplicateAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Reject => 0.into_dart(),
+            Self::ReturnExisting => 1.into_dart(),
+            Self::Restart => 2.into_dart(),
+            _ => unreachable!(),
         }
-        return ans_;
     }
 }
-
-impl SseDecode for Vec<crate::api::download::VideoFormat> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::download::VideoFormat>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::DuplicateAction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::DuplicateAction>
+    for crate::api::download::DuplicateAction
+{
+    fn into_into_dart(self) -> crate::api::download::DuplicateAction {
+        self
     }
 }
-
-impl SseDecode for crate::api::download::NebulaEvent {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_taskId = <String>::sse_decode(deserializer);
-                let mut var_name = <String>::sse_decode(deserializer);
-                let mut var_thumbnail = <Option<String>>::sse_decode(deserializer);
-                return crate::api::download::NebulaEvent::TaskAdded {
-                    task_id: var_taskId,
-                    name: var_name,
-                    thumbnail: var_thumbnail,
-                };
-            }
-            1 => {
-                let mut var_taskId = <String>::sse_decode(deserializer);
-                return crate::api::download::NebulaEvent::TaskStarted {
-                    task_id: var_taskId,
-                };
-            }
-            2 => {
-                let mut var_taskId = <String>::sse_decode(deserializer);
-                let mut var_progress =
-                    <crate::api::download::ProgressEvent>::sse_decode(deserializer);
-                return crate::api::download::NebulaEvent::ProgressUpdated {
-                    task_id: var_taskId,
-                    progress: var_progress,
-                };
-            }
-            3 => {
-                let mut var_taskId = <String>::sse_decode(deserializer);
-                return crate::api::download::NebulaEvent::TaskCompleted {
-                    task_id: var_taskId,
-                };
-            }
-            4 => {
-                let mut var_taskId = <String>::sse_decode(deserializer);
-                let mut var_error = <String>::sse_decode(deserializer);
-                return crate::api::download::NebulaEvent::TaskFailed {
-                    task_id: var_taskId,
-                    error: var_error,
-                };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::IpFilterInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.range_count.into_into_dart().into_dart(),
+            self.blocked_handoff_peers.into_into_dart().into_dart(),
+            self.last_updated.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::IpFilterInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::IpFilterInfo>
+    for crate::api::download::IpFilterInfo
+{
+    fn into_into_dart(self) -> crate::api::download::IpFilterInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::NebulaEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::download::NebulaEvent::TaskAdded {
+                task_id,
+                name,
+                thumbnail,
+            } => [
+                0.into_dart(),
+                task_id.into_into_dart().into_dart(),
+                name.into_into_dart().into_dart(),
+                thumbnail.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::download::NebulaEvent::TaskStarted { task_id } => {
+                [1.into_dart(), task_id.into_into_dart().into_dart()].into_dart()
             }
-            5 => {
-                let mut var_taskId = <String>::sse_decode(deserializer);
-                return crate::api::download::NebulaEvent::TaskPaused {
-                    task_id: var_taskId,
-                };
+            crate::api::download::NebulaEvent::ProgressUpdated { task_id, progress } => [
+                2.into_dart(),
+                task_id.into_into_dart().into_dart(),
+                progress.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::download::NebulaEvent::TaskCompleted { task_id } => {
+                [3.into_dart(), task_id.into_into_dart().into_dart()].into_dart()
             }
-            6 => {
-                let mut var_taskId = <String>::sse_decode(deserializer);
-                return crate::api::download::NebulaEvent::TaskResumed {
-                    task_id: var_taskId,
-                };
+            crate::api::download::NebulaEvent::TaskFailed { task_id, error } => [
+                4.into_dart(),
+                task_id.into_into_dart().into_dart(),
+                error.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::download::NebulaEvent::TaskPaused { task_id } => {
+                [5.into_dart(), task_id.into_into_dart().into_dart()].into_dart()
             }
-            7 => {
-                let mut var_taskId = <String>::sse_decode(deserializer);
-                return crate::api::download::NebulaEvent::TaskRemoved {
-                    task_id: var_taskId,
-                };
+            crate::api::download::NebulaEvent::TaskResumed { task_id } => {
+                [6.into_dart(), task_id.into_into_dart().into_dart()].into_dart()
             }
-            8 => {
-                let mut var_taskId = <String>::sse_decode(deserializer);
-                let mut var_name = <String>::sse_decode(deserializer);
-                let mut var_totalSize = <u64>::sse_decode(deserializer);
-                let mut var_fileCount = <usize>::sse_decode(deserializer);
-                return crate::api::download::NebulaEvent::MetadataReceived {
-                    task_id: var_taskId,
-                    name: var_name,
-                    total_size: var_totalSize,
-                    file_count: var_fileCount,
-                };
+            crate::api::download::NebulaEvent::TaskRemoved { task_id } => {
+                [7.into_dart(), task_id.into_into_dart().into_dart()].into_dart()
             }
-            9 => {
-                let mut var_taskId = <String>::sse_decode(deserializer);
-                let mut var_connectedPeers = <usize>::sse_decode(deserializer);
-                let mut var_totalPeers = <usize>::sse_decode(deserializer);
-                return crate::api::download::NebulaEvent::PeerUpdate {
-                    task_id: var_taskId,
-                    connected_peers: var_connectedPeers,
-                    total_peers: var_totalPeers,
-                };
+            crate::api::download::NebulaEvent::MetadataReceived {
+                task_id,
+                name,
+                total_size,
+                file_count,
+                mime_type,
+                supports_resume,
+                thumbnail,
+                duration_secs,
+            } => [
+                8.into_dart(),
+                task_id.into_into_dart().into_dart(),
+                name.into_into_dart().into_dart(),
+                total_size.into_into_dart().into_dart(),
+                file_count.into_into_dart().into_dart(),
+                mime_type.into_into_dart().into_dart(),
+                supports_resume.into_into_dart().into_dart(),
+                thumbnail.into_into_dart().into_dart(),
+                duration_secs.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::download::NebulaEvent::PeerUpdate {
+                task_id,
+                connected_peers,
+                total_peers,
+            } => [
+                9.into_dart(),
+                task_id.into_into_dart().into_dart(),
+                connected_peers.into_into_dart().into_dart(),
+                total_peers.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::download::NebulaEvent::CheckProgress {
+                task_id,
+                checked_bytes,
+                total_bytes,
+            } => [
+                10.into_dart(),
+                task_id.into_into_dart().into_dart(),
+                checked_bytes.into_into_dart().into_dart(),
+                total_bytes.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::download::NebulaEvent::CheckCompleted {
+                task_id,
+                valid_bytes,
+                total_bytes,
+            } => [
+                11.into_dart(),
+                task_id.into_into_dart().into_dart(),
+                valid_bytes.into_into_dart().into_dart(),
+                total_bytes.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::download::NebulaEvent::TaskStalled {
+                task_id,
+                phase,
+                stalled_secs,
+                dht_nodes,
+                trackers_total,
+                trackers_reached,
+                peers_seen,
+                peers_connected,
+            } => [
+                12.into_dart(),
+                task_id.into_into_dart().into_dart(),
+                phase.into_into_dart().into_dart(),
+                stalled_secs.into_into_dart().into_dart(),
+                dht_nodes.into_into_dart().into_dart(),
+                trackers_total.into_into_dart().into_dart(),
+                trackers_reached.into_into_dart().into_dart(),
+                peers_seen.into_into_dart().into_dart(),
+                peers_connected.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::download::NebulaEvent::StallRecovered { task_id, phase } => [
+                13.into_dart(),
+                task_id.into_into_dart().into_dart(),
+                phase.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::download::NebulaEvent::VideoStageChanged { task_id, stage } => [
+                14.into_dart(),
+                task_id.into_into_dart().into_dart(),
+                stage.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::download::NebulaEvent::OutputFileResolved { task_id, path } => [
+                15.into_dart(),
+                task_id.into_into_dart().into_dart(),
+                path.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::download::NebulaEvent::StatusChanged { task_id, from, to } => [
+                16.into_dart(),
+                task_id.into_into_dart().into_dart(),
+                from.into_into_dart().into_dart(),
+                to.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::download::NebulaEvent::BilibiliLoginExpired { reason } => {
+                [17.into_dart(), reason.into_into_dart().into_dart()].into_dart()
             }
             _ => {
                 unimplemented!("");
@@ -872,315 +3295,309 @@ impl SseDecode for crate::api::download::NebulaEvent {
         }
     }
 }
-
-impl SseDecode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::NebulaEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::NebulaEvent>
+    for crate::api::download::NebulaEvent
+{
+    fn into_into_dart(self) -> crate::api::download::NebulaEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::PeerInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.client.into_into_dart().into_dart(),
+            self.flags.into_into_dart().into_dart(),
+            self.download_speed.into_into_dart().into_dart(),
+            self.upload_speed.into_into_dart().into_dart(),
+            self.downloaded.into_into_dart().into_dart(),
+            self.uploaded.into_into_dart().into_dart(),
+            self.progress.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::PeerInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::PeerInfo>
+    for crate::api::download::PeerInfo
+{
+    fn into_into_dart(self) -> crate::api::download::PeerInfo {
+        self
     }
 }
-
-impl SseDecode for Option<f64> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<f64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::PlaylistEntryInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.index.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
+            self.duration.into_into_dart().into_dart(),
+            self.uploader.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Option<u64> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::PlaylistEntryInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::PlaylistEntryInfo>
+    for crate::api::download::PlaylistEntryInfo
+{
+    fn into_into_dart(self) -> crate::api::download::PlaylistEntryInfo {
+        self
     }
 }
-
-impl SseDecode for crate::api::download::ProgressEvent {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_taskId = <String>::sse_decode(deserializer);
-        let mut var_totalSize = <u64>::sse_decode(deserializer);
-        let mut var_downloadedSize = <u64>::sse_decode(deserializer);
-        let mut var_downloadSpeed = <u64>::sse_decode(deserializer);
-        let mut var_percentage = <f64>::sse_decode(deserializer);
-        let mut var_etaSecs = <Option<u64>>::sse_decode(deserializer);
-        return crate::api::download::ProgressEvent {
-            task_id: var_taskId,
-            total_size: var_totalSize,
-            downloaded_size: var_downloadedSize,
-            download_speed: var_downloadSpeed,
-            percentage: var_percentage,
-            eta_secs: var_etaSecs,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::PlaylistInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.uploader.into_into_dart().into_dart(),
+            self.entries.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for u64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::PlaylistInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::PlaylistInfo>
+    for crate::api::download::PlaylistInfo
+{
+    fn into_into_dart(self) -> crate::api::download::PlaylistInfo {
+        self
     }
 }
-
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::ProgressEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.task_id.into_into_dart().into_dart(),
+            self.total_size.into_into_dart().into_dart(),
+            self.downloaded_size.into_into_dart().into_dart(),
+            self.download_speed.into_into_dart().into_dart(),
+            self.percentage.into_into_dart().into_dart(),
+            self.eta_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::ProgressEvent
+{
 }
-
-impl SseDecode for usize {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::ProgressEvent>
+    for crate::api::download::ProgressEvent
+{
+    fn into_into_dart(self) -> crate::api::download::ProgressEvent {
+        self
     }
 }
-
-impl SseDecode for crate::api::download::VideoFormat {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_formatId = <String>::sse_decode(deserializer);
-        let mut var_ext = <String>::sse_decode(deserializer);
-        let mut var_resolution = <Option<String>>::sse_decode(deserializer);
-        let mut var_filesize = <Option<u64>>::sse_decode(deserializer);
-        let mut var_formatNote = <Option<String>>::sse_decode(deserializer);
-        let mut var_fps = <Option<f64>>::sse_decode(deserializer);
-        let mut var_vcodec = <Option<String>>::sse_decode(deserializer);
-        let mut var_acodec = <Option<String>>::sse_decode(deserializer);
-        return crate::api::download::VideoFormat {
-            format_id: var_formatId,
-            ext: var_ext,
-            resolution: var_resolution,
-            filesize: var_filesize,
-            format_note: var_formatNote,
-            fps: var_fps,
-            vcodec: var_vcodec,
-            acodec: var_acodec,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::ResolvedTorrentInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.info_hash.into_into_dart().into_dart(),
+            self.total_size.into_into_dart().into_dart(),
+            self.piece_size.into_into_dart().into_dart(),
+            self.private.into_into_dart().into_dart(),
+            self.files.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::download::VideoInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_title = <String>::sse_decode(deserializer);
-        let mut var_thumbnail = <Option<String>>::sse_decode(deserializer);
-        let mut var_duration = <Option<u64>>::sse_decode(deserializer);
-        let mut var_uploader = <Option<String>>::sse_decode(deserializer);
-        let mut var_formats = <Vec<crate::api::download::VideoFormat>>::sse_decode(deserializer);
-        return crate::api::download::VideoInfo {
-            id: var_id,
-            title: var_title,
-            thumbnail: var_thumbnail,
-            duration: var_duration,
-            uploader: var_uploader,
-            formats: var_formats,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::ResolvedTorrentInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::ResolvedTorrentInfo>
+    for crate::api::download::ResolvedTorrentInfo
+{
+    fn into_into_dart(self) -> crate::api::download::ResolvedTorrentInfo {
+        self
     }
 }
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::SiteCredentialInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.domain.into_into_dart().into_dart(),
+            self.include_subdomains.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.username.into_into_dart().into_dart(),
+            self.header_name.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__download__add_download_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__download__add_video_download_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__download__cancel_download_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__download__generate_bilibili_qrcode_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        5 => wire__crate__api__download__get_video_info_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__download__init_download_manager_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        9 => wire__crate__api__download__is_bilibili_logged_in_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        10 => wire__crate__api__download__is_video_url_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__download__logout_bilibili_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__download__open_file_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__download__open_folder_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__download__pause_download_impl(port, ptr, rust_vec_len, data_len),
-        15 => {
-            wire__crate__api__download__poll_bilibili_login_impl(port, ptr, rust_vec_len, data_len)
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::SiteCredentialInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::SiteCredentialInfo>
+    for crate::api::download::SiteCredentialInfo
+{
+    fn into_into_dart(self) -> crate::api::download::SiteCredentialInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::SiteCredentialInput {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::download::SiteCredentialInput::Cookie { cookies } => {
+                [0.into_dart(), cookies.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::download::SiteCredentialInput::Bearer { token } => {
+                [1.into_dart(), token.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::download::SiteCredentialInput::Basic { username, password } => [
+                2.into_dart(),
+                username.into_into_dart().into_dart(),
+                password.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::download::SiteCredentialInput::Header { name, value } => [
+                3.into_dart(),
+                name.into_into_dart().into_dart(),
+                value.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
         }
-        16 => wire__crate__api__download__resume_download_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__download__subscribe_events_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
     }
 }
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        6 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::SiteCredentialInput
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::SiteCredentialInput>
+    for crate::api::download::SiteCredentialInput
+{
+    fn into_into_dart(self) -> crate::api::download::SiteCredentialInput {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::ToolStatusInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::ToolStatusInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::ToolStatusInfo>
+    for crate::api::download::ToolStatusInfo
+{
+    fn into_into_dart(self) -> crate::api::download::ToolStatusInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::ToolUpdateInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.current.into_into_dart().into_dart(),
+            self.latest.into_into_dart().into_dart(),
+            self.update_available.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::ToolUpdateInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::ToolUpdateInfo>
+    for crate::api::download::ToolUpdateInfo
+{
+    fn into_into_dart(self) -> crate::api::download::ToolUpdateInfo {
+        self
     }
 }
-
-// Section: rust2dart
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::download::BilibiliLoginStatus {
+impl flutter_rust_bridge::IntoDart for crate::api::download::TorrentFileInfo {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::download::BilibiliLoginStatus::WaitingScan => [0.into_dart()].into_dart(),
-            crate::api::download::BilibiliLoginStatus::WaitingConfirm => {
-                [1.into_dart()].into_dart()
-            }
-            crate::api::download::BilibiliLoginStatus::Success => [2.into_dart()].into_dart(),
-            crate::api::download::BilibiliLoginStatus::Expired => [3.into_dart()].into_dart(),
-            crate::api::download::BilibiliLoginStatus::Failed { error } => {
-                [4.into_dart(), error.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+        [
+            self.index.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::download::BilibiliLoginStatus
+    for crate::api::download::TorrentFileInfo
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::download::BilibiliLoginStatus>
-    for crate::api::download::BilibiliLoginStatus
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::TorrentFileInfo>
+    for crate::api::download::TorrentFileInfo
 {
-    fn into_into_dart(self) -> crate::api::download::BilibiliLoginStatus {
+    fn into_into_dart(self) -> crate::api::download::TorrentFileInfo {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::download::BilibiliQrCode {
+impl flutter_rust_bridge::IntoDart for crate::api::download::TrackerInfo {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.url.into_into_dart().into_dart(),
-            self.qrcode_key.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.seeders.into_into_dart().into_dart(),
+            self.leechers.into_into_dart().into_dart(),
+            self.completed.into_into_dart().into_dart(),
+            self.last_updated.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::download::BilibiliQrCode
+    for crate::api::download::TrackerInfo
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::download::BilibiliQrCode>
-    for crate::api::download::BilibiliQrCode
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::TrackerInfo>
+    for crate::api::download::TrackerInfo
 {
-    fn into_into_dart(self) -> crate::api::download::BilibiliQrCode {
+    fn into_into_dart(self) -> crate::api::download::TrackerInfo {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::download::NebulaEvent {
+impl flutter_rust_bridge::IntoDart for crate::api::download::TrackerStatusInfo {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
-            crate::api::download::NebulaEvent::TaskAdded {
-                task_id,
-                name,
-                thumbnail,
-            } => [
-                0.into_dart(),
-                task_id.into_into_dart().into_dart(),
-                name.into_into_dart().into_dart(),
-                thumbnail.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::download::NebulaEvent::TaskStarted { task_id } => {
-                [1.into_dart(), task_id.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::download::NebulaEvent::ProgressUpdated { task_id, progress } => [
-                2.into_dart(),
-                task_id.into_into_dart().into_dart(),
-                progress.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::download::NebulaEvent::TaskCompleted { task_id } => {
-                [3.into_dart(), task_id.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::download::NebulaEvent::TaskFailed { task_id, error } => [
-                4.into_dart(),
-                task_id.into_into_dart().into_dart(),
-                error.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::download::NebulaEvent::TaskPaused { task_id } => {
-                [5.into_dart(), task_id.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::download::NebulaEvent::TaskResumed { task_id } => {
-                [6.into_dart(), task_id.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::download::NebulaEvent::TaskRemoved { task_id } => {
-                [7.into_dart(), task_id.into_into_dart().into_dart()].into_dart()
+            crate::api::download::TrackerStatusInfo::NotContacted => [0.into_dart()].into_dart(),
+            crate::api::download::TrackerStatusInfo::Updating => [1.into_dart()].into_dart(),
+            crate::api::download::TrackerStatusInfo::Working => [2.into_dart()].into_dart(),
+            crate::api::download::TrackerStatusInfo::Error { message } => {
+                [3.into_dart(), message.into_into_dart().into_dart()].into_dart()
             }
-            crate::api::download::NebulaEvent::MetadataReceived {
-                task_id,
-                name,
-                total_size,
-                file_count,
-            } => [
-                8.into_dart(),
-                task_id.into_into_dart().into_dart(),
-                name.into_into_dart().into_dart(),
-                total_size.into_into_dart().into_dart(),
-                file_count.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::download::NebulaEvent::PeerUpdate {
-                task_id,
-                connected_peers,
-                total_peers,
-            } => [
-                9.into_dart(),
-                task_id.into_into_dart().into_dart(),
-                connected_peers.into_into_dart().into_dart(),
-                total_peers.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
             _ => {
                 unimplemented!("");
             }
@@ -1188,38 +3605,50 @@ impl flutter_rust_bridge::IntoDart for crate::api::download::NebulaEvent {
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::download::NebulaEvent
+    for crate::api::download::TrackerStatusInfo
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::download::NebulaEvent>
-    for crate::api::download::NebulaEvent
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::TrackerStatusInfo>
+    for crate::api::download::TrackerStatusInfo
 {
-    fn into_into_dart(self) -> crate::api::download::NebulaEvent {
+    fn into_into_dart(self) -> crate::api::download::TrackerStatusInfo {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::download::ProgressEvent {
+impl flutter_rust_bridge::IntoDart for crate::api::download::VideoDownloadOptions {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.task_id.into_into_dart().into_dart(),
-            self.total_size.into_into_dart().into_dart(),
-            self.downloaded_size.into_into_dart().into_dart(),
-            self.download_speed.into_into_dart().into_dart(),
-            self.percentage.into_into_dart().into_dart(),
-            self.eta_secs.into_into_dart().into_dart(),
+            self.subtitle_langs.into_into_dart().into_dart(),
+            self.auto_subtitles.into_into_dart().into_dart(),
+            self.danmaku.into_into_dart().into_dart(),
+            self.write_thumbnail.into_into_dart().into_dart(),
+            self.write_info_json.into_into_dart().into_dart(),
+            self.embed_metadata.into_into_dart().into_dart(),
+            self.embed_thumbnail.into_into_dart().into_dart(),
+            self.embed_chapters.into_into_dart().into_dart(),
+            self.audio_format.into_into_dart().into_dart(),
+            self.audio_bitrate_kbps.into_into_dart().into_dart(),
+            self.normalize_audio.into_into_dart().into_dart(),
+            self.max_height.into_into_dart().into_dart(),
+            self.video_codec.into_into_dart().into_dart(),
+            self.container.into_into_dart().into_dart(),
+            self.prefer_hdr.into_into_dart().into_dart(),
+            self.max_fps.into_into_dart().into_dart(),
+            self.audio_language.into_into_dart().into_dart(),
+            self.max_filesize_mb.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::download::ProgressEvent
+    for crate::api::download::VideoDownloadOptions
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::download::ProgressEvent>
-    for crate::api::download::ProgressEvent
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::VideoDownloadOptions>
+    for crate::api::download::VideoDownloadOptions
 {
-    fn into_into_dart(self) -> crate::api::download::ProgressEvent {
+    fn into_into_dart(self) -> crate::api::download::VideoDownloadOptions {
         self
     }
 }
@@ -1235,6 +3664,11 @@ impl flutter_rust_bridge::IntoDart for crate::api::download::VideoFormat {
             self.fps.into_into_dart().into_dart(),
             self.vcodec.into_into_dart().into_dart(),
             self.acodec.into_into_dart().into_dart(),
+            self.tbr.into_into_dart().into_dart(),
+            self.dynamic_range.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+            self.audio_only.into_into_dart().into_dart(),
+            self.video_only.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -1250,110 +3684,278 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::download::VideoFormat>
         self
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::download::VideoInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.thumbnail.into_into_dart().into_dart(),
-            self.duration.into_into_dart().into_dart(),
-            self.uploader.into_into_dart().into_dart(),
-            self.formats.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::download::VideoInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.thumbnail.into_into_dart().into_dart(),
+            self.duration.into_into_dart().into_dart(),
+            self.uploader.into_into_dart().into_dart(),
+            self.formats.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::download::VideoInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::download::VideoInfo>
+    for crate::api::download::VideoInfo
+{
+    fn into_into_dart(self) -> crate::api::download::VideoInfo {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::download::NebulaEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::download::BilibiliAccountInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.mid, serializer);
+        <String>::sse_encode(self.username, serializer);
+        <String>::sse_encode(self.avatar, serializer);
+        <u32>::sse_encode(self.level, serializer);
+        <bool>::sse_encode(self.vip_active, serializer);
+        <u32>::sse_encode(self.vip_type, serializer);
+        <Option<i64>>::sse_encode(self.vip_expires_at, serializer);
+        <Option<String>>::sse_encode(self.vip_label, serializer);
+    }
+}
+
+impl SseEncode for crate::api::download::BilibiliLoginStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::download::BilibiliLoginStatus::WaitingScan => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::download::BilibiliLoginStatus::WaitingConfirm => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::download::BilibiliLoginStatus::Success => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::download::BilibiliLoginStatus::Expired => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::api::download::BilibiliLoginStatus::Failed { error } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(error, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::download::BilibiliQrCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.qrcode_key, serializer);
+    }
+}
+
+impl SseEncode for crate::api::download::BilibiliSessionStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::download::BilibiliSessionStatus::NotLoggedIn => 0,
+                crate::api::download::BilibiliSessionStatus::Valid => 1,
+                crate::api::download::BilibiliSessionStatus::Refreshed => 2,
+                crate::api::download::BilibiliSessionStatus::Expired => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::download::CreatedTorrentInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.info_hash, serializer);
+        <String>::sse_encode(self.magnet, serializer);
+        <String>::sse_encode(self.torrent_path, serializer);
+        <u64>::sse_encode(self.total_size, serializer);
+        <u64>::sse_encode(self.piece_size, serializer);
+        <u64>::sse_encode(self.piece_count, serializer);
+        <u64>::sse_encode(self.file_count, serializer);
+        <Option<String>>::sse_encode(self.seed_task_id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::download::CredentialVaultStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.unlocked, serializer);
+        <bool>::sse_encode(self.requires_passphrase, serializer);
+    }
+}
+
+impl SseEncode for crate::api::download::DuplicateAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::download::DuplicateAction::Reject => 0,
+                crate::api::download::DuplicateAction::ReturnExisting => 1,
+                crate::api::download::DuplicateAction::Restart => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::download::IpFilterInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.range_count, serializer);
+        <u64>::sse_encode(self.blocked_handoff_peers, serializer);
+        <Option<i64>>::sse_encode(self.last_updated, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::download::VideoInfo
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::download::VideoInfo>
-    for crate::api::download::VideoInfo
-{
-    fn into_into_dart(self) -> crate::api::download::VideoInfo {
-        self
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for Vec<crate::api::download::PeerInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::download::PeerInfo>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for StreamSink<crate::api::download::NebulaEvent, flutter_rust_bridge::for_generated::SseCodec>
-{
+impl SseEncode for Vec<crate::api::download::PlaylistEntryInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::download::PlaylistEntryInfo>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Vec<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u64>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::download::BilibiliLoginStatus {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        match self {
-            crate::api::download::BilibiliLoginStatus::WaitingScan => {
-                <i32>::sse_encode(0, serializer);
-            }
-            crate::api::download::BilibiliLoginStatus::WaitingConfirm => {
-                <i32>::sse_encode(1, serializer);
-            }
-            crate::api::download::BilibiliLoginStatus::Success => {
-                <i32>::sse_encode(2, serializer);
-            }
-            crate::api::download::BilibiliLoginStatus::Expired => {
-                <i32>::sse_encode(3, serializer);
-            }
-            crate::api::download::BilibiliLoginStatus::Failed { error } => {
-                <i32>::sse_encode(4, serializer);
-                <String>::sse_encode(error, serializer);
-            }
-            _ => {
-                unimplemented!("");
-            }
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for crate::api::download::BilibiliQrCode {
+impl SseEncode for Vec<crate::api::download::SiteCredentialInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.url, serializer);
-        <String>::sse_encode(self.qrcode_key, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::download::SiteCredentialInfo>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for Vec<crate::api::download::ToolStatusInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::download::ToolStatusInfo>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for Vec<crate::api::download::TorrentFileInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::download::TorrentFileInfo>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Vec<crate::api::download::TrackerInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <u8>::sse_encode(item, serializer);
+            <crate::api::download::TrackerInfo>::sse_encode(item, serializer);
         }
     }
 }
@@ -1417,12 +4019,20 @@ impl SseEncode for crate::api::download::NebulaEvent {
                 name,
                 total_size,
                 file_count,
+                mime_type,
+                supports_resume,
+                thumbnail,
+                duration_secs,
             } => {
                 <i32>::sse_encode(8, serializer);
                 <String>::sse_encode(task_id, serializer);
                 <String>::sse_encode(name, serializer);
                 <u64>::sse_encode(total_size, serializer);
                 <usize>::sse_encode(file_count, serializer);
+                <Option<String>>::sse_encode(mime_type, serializer);
+                <bool>::sse_encode(supports_resume, serializer);
+                <Option<String>>::sse_encode(thumbnail, serializer);
+                <Option<u64>>::sse_encode(duration_secs, serializer);
             }
             crate::api::download::NebulaEvent::PeerUpdate {
                 task_id,
@@ -1434,6 +4044,71 @@ impl SseEncode for crate::api::download::NebulaEvent {
                 <usize>::sse_encode(connected_peers, serializer);
                 <usize>::sse_encode(total_peers, serializer);
             }
+            crate::api::download::NebulaEvent::CheckProgress {
+                task_id,
+                checked_bytes,
+                total_bytes,
+            } => {
+                <i32>::sse_encode(10, serializer);
+                <String>::sse_encode(task_id, serializer);
+                <u64>::sse_encode(checked_bytes, serializer);
+                <u64>::sse_encode(total_bytes, serializer);
+            }
+            crate::api::download::NebulaEvent::CheckCompleted {
+                task_id,
+                valid_bytes,
+                total_bytes,
+            } => {
+                <i32>::sse_encode(11, serializer);
+                <String>::sse_encode(task_id, serializer);
+                <u64>::sse_encode(valid_bytes, serializer);
+                <u64>::sse_encode(total_bytes, serializer);
+            }
+            crate::api::download::NebulaEvent::TaskStalled {
+                task_id,
+                phase,
+                stalled_secs,
+                dht_nodes,
+                trackers_total,
+                trackers_reached,
+                peers_seen,
+                peers_connected,
+            } => {
+                <i32>::sse_encode(12, serializer);
+                <String>::sse_encode(task_id, serializer);
+                <String>::sse_encode(phase, serializer);
+                <u64>::sse_encode(stalled_secs, serializer);
+                <Option<u64>>::sse_encode(dht_nodes, serializer);
+                <u64>::sse_encode(trackers_total, serializer);
+                <u64>::sse_encode(trackers_reached, serializer);
+                <u64>::sse_encode(peers_seen, serializer);
+                <u64>::sse_encode(peers_connected, serializer);
+            }
+            crate::api::download::NebulaEvent::StallRecovered { task_id, phase } => {
+                <i32>::sse_encode(13, serializer);
+                <String>::sse_encode(task_id, serializer);
+                <String>::sse_encode(phase, serializer);
+            }
+            crate::api::download::NebulaEvent::VideoStageChanged { task_id, stage } => {
+                <i32>::sse_encode(14, serializer);
+                <String>::sse_encode(task_id, serializer);
+                <String>::sse_encode(stage, serializer);
+            }
+            crate::api::download::NebulaEvent::OutputFileResolved { task_id, path } => {
+                <i32>::sse_encode(15, serializer);
+                <String>::sse_encode(task_id, serializer);
+                <String>::sse_encode(path, serializer);
+            }
+            crate::api::download::NebulaEvent::StatusChanged { task_id, from, to } => {
+                <i32>::sse_encode(16, serializer);
+                <String>::sse_encode(task_id, serializer);
+                <String>::sse_encode(from, serializer);
+                <String>::sse_encode(to, serializer);
+            }
+            crate::api::download::NebulaEvent::BilibiliLoginExpired { reason } => {
+                <i32>::sse_encode(17, serializer);
+                <String>::sse_encode(reason, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -1451,6 +4126,16 @@ impl SseEncode for Option<String> {
     }
 }
 
+impl SseEncode for Option<crate::api::download::BilibiliAccountInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::download::BilibiliAccountInfo>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1461,6 +4146,36 @@ impl SseEncode for Option<f64> {
     }
 }
 
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::download::IpFilterInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::download::IpFilterInfo>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1471,6 +4186,52 @@ impl SseEncode for Option<u64> {
     }
 }
 
+impl SseEncode for Option<crate::api::download::VideoDownloadOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::download::VideoDownloadOptions>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::download::PeerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <Option<String>>::sse_encode(self.client, serializer);
+        <String>::sse_encode(self.flags, serializer);
+        <u64>::sse_encode(self.download_speed, serializer);
+        <u64>::sse_encode(self.upload_speed, serializer);
+        <u64>::sse_encode(self.downloaded, serializer);
+        <u64>::sse_encode(self.uploaded, serializer);
+        <Option<f64>>::sse_encode(self.progress, serializer);
+    }
+}
+
+impl SseEncode for crate::api::download::PlaylistEntryInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.index, serializer);
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <Option<u64>>::sse_encode(self.duration, serializer);
+        <Option<String>>::sse_encode(self.uploader, serializer);
+    }
+}
+
+impl SseEncode for crate::api::download::PlaylistInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.uploader, serializer);
+        <Vec<crate::api::download::PlaylistEntryInfo>>::sse_encode(self.entries, serializer);
+    }
+}
+
 impl SseEncode for crate::api::download::ProgressEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1483,6 +4244,131 @@ impl SseEncode for crate::api::download::ProgressEvent {
     }
 }
 
+impl SseEncode for crate::api::download::ResolvedTorrentInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.info_hash, serializer);
+        <u64>::sse_encode(self.total_size, serializer);
+        <u64>::sse_encode(self.piece_size, serializer);
+        <bool>::sse_encode(self.private, serializer);
+        <Vec<crate::api::download::TorrentFileInfo>>::sse_encode(self.files, serializer);
+    }
+}
+
+impl SseEncode for crate::api::download::SiteCredentialInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.domain, serializer);
+        <bool>::sse_encode(self.include_subdomains, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.username, serializer);
+        <Option<String>>::sse_encode(self.header_name, serializer);
+        <i64>::sse_encode(self.updated_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::download::SiteCredentialInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::download::SiteCredentialInput::Cookie { cookies } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(cookies, serializer);
+            }
+            crate::api::download::SiteCredentialInput::Bearer { token } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(token, serializer);
+            }
+            crate::api::download::SiteCredentialInput::Basic { username, password } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(username, serializer);
+                <String>::sse_encode(password, serializer);
+            }
+            crate::api::download::SiteCredentialInput::Header { name, value } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(name, serializer);
+                <String>::sse_encode(value, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::download::ToolStatusInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <Option<String>>::sse_encode(self.version, serializer);
+        <String>::sse_encode(self.source, serializer);
+    }
+}
+
+impl SseEncode for crate::api::download::ToolUpdateInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.current, serializer);
+        <String>::sse_encode(self.latest, serializer);
+        <bool>::sse_encode(self.update_available, serializer);
+    }
+}
+
+impl SseEncode for crate::api::download::TorrentFileInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.index, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <u64>::sse_encode(self.size, serializer);
+    }
+}
+
+impl SseEncode for crate::api::download::TrackerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.source, serializer);
+        <crate::api::download::TrackerStatusInfo>::sse_encode(self.status, serializer);
+        <Option<u32>>::sse_encode(self.seeders, serializer);
+        <Option<u32>>::sse_encode(self.leechers, serializer);
+        <Option<u32>>::sse_encode(self.completed, serializer);
+        <Option<i64>>::sse_encode(self.last_updated, serializer);
+    }
+}
+
+impl SseEncode for crate::api::download::TrackerStatusInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::download::TrackerStatusInfo::NotContacted => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::download::TrackerStatusInfo::Updating => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::download::TrackerStatusInfo::Working => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::download::TrackerStatusInfo::Error { message } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1512,6 +4398,30 @@ impl SseEncode for usize {
     }
 }
 
+impl SseEncode for crate::api::download::VideoDownloadOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.subtitle_langs, serializer);
+        <bool>::sse_encode(self.auto_subtitles, serializer);
+        <bool>::sse_encode(self.danmaku, serializer);
+        <bool>::sse_encode(self.write_thumbnail, serializer);
+        <bool>::sse_encode(self.write_info_json, serializer);
+        <bool>::sse_encode(self.embed_metadata, serializer);
+        <bool>::sse_encode(self.embed_thumbnail, serializer);
+        <bool>::sse_encode(self.embed_chapters, serializer);
+        <Option<String>>::sse_encode(self.audio_format, serializer);
+        <Option<u32>>::sse_encode(self.audio_bitrate_kbps, serializer);
+        <bool>::sse_encode(self.normalize_audio, serializer);
+        <Option<u32>>::sse_encode(self.max_height, serializer);
+        <Option<String>>::sse_encode(self.video_codec, serializer);
+        <Option<String>>::sse_encode(self.container, serializer);
+        <bool>::sse_encode(self.prefer_hdr, serializer);
+        <Option<u32>>::sse_encode(self.max_fps, serializer);
+        <Option<String>>::sse_encode(self.audio_language, serializer);
+        <Option<u64>>::sse_encode(self.max_filesize_mb, serializer);
+    }
+}
+
 impl SseEncode for crate::api::download::VideoFormat {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1523,6 +4433,11 @@ impl SseEncode for crate::api::download::VideoFormat {
         <Option<f64>>::sse_encode(self.fps, serializer);
         <Option<String>>::sse_encode(self.vcodec, serializer);
         <Option<String>>::sse_encode(self.acodec, serializer);
+        <Option<f64>>::sse_encode(self.tbr, serializer);
+        <Option<String>>::sse_encode(self.dynamic_range, serializer);
+        <Option<String>>::sse_encode(self.language, serializer);
+        <bool>::sse_encode(self.audio_only, serializer);
+        <bool>::sse_encode(self.video_only, serializer);
     }
 }
 